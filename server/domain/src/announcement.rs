@@ -0,0 +1,284 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use types::non_empty_string::NonEmptyString;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::{is_administrator, models::AllowedUserGroups},
+    types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
+};
+
+pub type AnnouncementId = types::Id<Announcement>;
+
+/// お知らせを公開する期間。`publish_until` を省略した場合は、削除するまで公開し続ける。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnnouncementPublishPeriod {
+    publish_from: DateTime<Utc>,
+    publish_until: Option<DateTime<Utc>>,
+}
+
+impl AnnouncementPublishPeriod {
+    pub fn try_new(
+        publish_from: DateTime<Utc>,
+        publish_until: Option<DateTime<Utc>>,
+    ) -> Result<Self, DomainError> {
+        match publish_until {
+            Some(publish_until) if publish_until <= publish_from => {
+                Err(DomainError::InvalidAnnouncementPublishPeriod)
+            }
+            _ => Ok(Self {
+                publish_from,
+                publish_until,
+            }),
+        }
+    }
+
+    pub fn publish_from(&self) -> DateTime<Utc> {
+        self.publish_from
+    }
+
+    pub fn publish_until(&self) -> Option<DateTime<Utc>> {
+        self.publish_until
+    }
+
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        self.publish_from <= now && self.publish_until.is_none_or(|until| now < until)
+    }
+}
+
+/// 管理者が全ユーザー、または指定したユーザーグループに向けて掲載するお知らせ。
+///
+/// 本文はコメントやメッセージと同じ制限された Markdown で書く。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct Announcement {
+    id: AnnouncementId,
+    title: NonEmptyString,
+    body: NonEmptyString,
+    publish_period: AnnouncementPublishPeriod,
+    pinned: bool,
+    /// 空の場合は全ユーザー (未ログインを含む) に公開する。
+    target_groups: AllowedUserGroups,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Announcement {
+    pub fn new(
+        title: NonEmptyString,
+        body: NonEmptyString,
+        publish_period: AnnouncementPublishPeriod,
+        pinned: bool,
+        target_groups: AllowedUserGroups,
+        created_by: UserId,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: AnnouncementId::new(),
+            title,
+            body,
+            publish_period,
+            pinned,
+            target_groups,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// `publish_until` は `None` で変更なし、`Some(None)` で終了日時の解除を表す。
+    pub fn try_edited(
+        self,
+        title: Option<NonEmptyString>,
+        body: Option<NonEmptyString>,
+        publish_from: Option<DateTime<Utc>>,
+        publish_until: Option<Option<DateTime<Utc>>>,
+        pinned: Option<bool>,
+        target_groups: Option<AllowedUserGroups>,
+    ) -> Result<Self, DomainError> {
+        let publish_period = AnnouncementPublishPeriod::try_new(
+            publish_from.unwrap_or(self.publish_period.publish_from),
+            publish_until.unwrap_or(self.publish_period.publish_until),
+        )?;
+
+        Ok(Self {
+            title: title.unwrap_or(self.title),
+            body: body.unwrap_or(self.body),
+            publish_period,
+            pinned: pinned.unwrap_or(self.pinned),
+            target_groups: target_groups.unwrap_or(self.target_groups),
+            updated_at: Utc::now(),
+            ..self
+        })
+    }
+
+    pub fn is_published_at(&self, now: DateTime<Utc>) -> bool {
+        self.publish_period.contains(now)
+    }
+
+    /// `actor` が `now` の時点でこのお知らせを掲載対象として受け取るか。
+    pub fn is_visible_to(&self, actor: &Actor, now: DateTime<Utc>) -> bool {
+        self.is_published_at(now) && self.target_groups.allows(actor)
+    }
+}
+
+impl AuthorizationRole for Announcement {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for Announcement {
+    fn can_create(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// 管理者とシステムは公開期間外のお知らせも読める。
+    /// それ以外は、公開期間中で、対象のユーザーグループに所属している場合だけ読める。
+    fn can_read(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::System)
+            || is_administrator(actor)
+            || self.is_visible_to(actor, Utc::now())
+    }
+
+    fn can_update(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    fn can_delete(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::account::models::{AccountUser, Role, UserGroup, UserGroupName};
+
+    fn announcement(
+        publish_period: AnnouncementPublishPeriod,
+        target_groups: AllowedUserGroups,
+    ) -> Announcement {
+        Announcement::new(
+            NonEmptyString::try_new("メンテナンスのお知らせ".to_string()).unwrap(),
+            NonEmptyString::try_new("明日の 10 時から停止します。".to_string()).unwrap(),
+            publish_period,
+            false,
+            target_groups,
+            Uuid::from_u128(1).into(),
+        )
+    }
+
+    fn user_in(groups: Vec<UserGroup>) -> Actor {
+        Actor::from(AccountUser::with_groups(
+            "player".to_string(),
+            Uuid::from_u128(2).into(),
+            Role::StandardUser,
+            groups,
+        ))
+    }
+
+    #[test]
+    fn publish_period_must_end_after_it_starts() {
+        let now = Utc::now();
+
+        assert_eq!(
+            AnnouncementPublishPeriod::try_new(now, Some(now)),
+            Err(DomainError::InvalidAnnouncementPublishPeriod)
+        );
+        assert!(AnnouncementPublishPeriod::try_new(now, None).is_ok());
+    }
+
+    #[test]
+    fn editing_keeps_the_publish_period_valid() {
+        let now = Utc::now();
+        let announcement = announcement(
+            AnnouncementPublishPeriod::try_new(now, Some(now + Duration::days(1))).unwrap(),
+            AllowedUserGroups::unrestricted(),
+        );
+
+        assert_eq!(
+            announcement.clone().try_edited(
+                None,
+                None,
+                Some(now + Duration::days(2)),
+                None,
+                None,
+                None
+            ),
+            Err(DomainError::InvalidAnnouncementPublishPeriod)
+        );
+
+        let edited = announcement
+            .try_edited(
+                None,
+                None,
+                Some(now + Duration::days(2)),
+                Some(None),
+                Some(true),
+                None,
+            )
+            .unwrap();
+        assert_eq!(edited.publish_period().publish_until(), None);
+        assert!(*edited.pinned());
+    }
+
+    #[test]
+    fn announcement_is_visible_only_within_its_publish_period() {
+        let now = Utc::now();
+        let announcement = announcement(
+            AnnouncementPublishPeriod::try_new(now, Some(now + Duration::days(1))).unwrap(),
+            AllowedUserGroups::unrestricted(),
+        );
+
+        assert!(!announcement.is_visible_to(&Actor::Anonymous, now - Duration::seconds(1)));
+        assert!(announcement.is_visible_to(&Actor::Anonymous, now));
+        assert!(!announcement.is_visible_to(&Actor::Anonymous, now + Duration::days(1)));
+    }
+
+    #[test]
+    fn targeted_announcement_is_visible_only_to_group_members_and_administrators() {
+        let group = UserGroup::new(UserGroupName::new(
+            "builders".to_string().try_into().unwrap(),
+        ));
+        let other_group =
+            UserGroup::new(UserGroupName::new("other".to_string().try_into().unwrap()));
+        let announcement = announcement(
+            AnnouncementPublishPeriod::try_new(Utc::now() - Duration::hours(1), None).unwrap(),
+            AllowedUserGroups::new(vec![*group.id()]),
+        );
+        let administrator = Actor::from(AccountUser::new(
+            "admin".to_string(),
+            Uuid::from_u128(3).into(),
+            Role::Administrator,
+        ));
+
+        assert!(announcement.can_read(&user_in(vec![group])));
+        assert!(!announcement.can_read(&user_in(vec![other_group])));
+        assert!(!announcement.can_read(&Actor::Anonymous));
+        assert!(announcement.can_read(&administrator));
+    }
+
+    #[test]
+    fn administrators_can_read_scheduled_announcements() {
+        let scheduled = announcement(
+            AnnouncementPublishPeriod::try_new(Utc::now() + Duration::days(1), None).unwrap(),
+            AllowedUserGroups::unrestricted(),
+        );
+        let administrator = Actor::from(AccountUser::new(
+            "admin".to_string(),
+            Uuid::from_u128(3).into(),
+            Role::Administrator,
+        ));
+
+        assert!(!scheduled.can_read(&user_in(vec![])));
+        assert!(scheduled.can_read(&administrator));
+        assert!(!scheduled.can_update(&user_in(vec![])));
+        assert!(scheduled.can_update(&administrator));
+    }
+}
@@ -1,15 +1,24 @@
 pub mod answer;
 pub mod comment;
 pub mod comment_thread;
+pub mod content_filter;
+pub mod draw;
 pub mod label;
+pub mod mention;
 pub mod message;
+pub mod message_read;
+pub mod message_template;
 pub mod message_thread;
 pub mod models;
+pub mod public_feed;
 pub mod question;
 pub mod service;
 pub mod settings;
 pub mod submission_restriction;
 pub mod submitter;
+pub mod temporary_answer_abuse;
+pub mod temporary_answer_access;
+pub mod temporary_answer_claim;
 
 pub use submission_restriction::{
     FormSubmissionRestriction, FormSubmissionRestrictionHistory, FormSubmissionRestrictionId,
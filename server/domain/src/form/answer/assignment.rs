@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+
+use crate::{
+    account::models::{UserId, UserSnapshot},
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        is_administrator,
+    },
+    types::authorization_guard::{AuthorizationRole, BelongsTo, GuardedBy, ParentGuarded, Read},
+};
+
+pub type AnswerAssignmentHistoryId = types::Id<AnswerAssignmentHistoryEntry>;
+
+/// 回答一覧を担当者で絞り込む条件です。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnswerAssigneeFilter {
+    /// 指定したユーザーが担当している回答だけを対象にします。
+    AssignedTo(UserId),
+    /// 担当者が割り当てられていない回答だけを対象にします。
+    Unassigned,
+}
+
+impl AnswerAssigneeFilter {
+    pub fn matches(self, assignee: Option<UserId>) -> bool {
+        match self {
+            Self::AssignedTo(user_id) => assignee == Some(user_id),
+            Self::Unassigned => assignee.is_none(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnswerAssignmentHistoryPagePosition(AnswerAssignmentHistoryId);
+
+impl AnswerAssignmentHistoryPagePosition {
+    pub fn new(id: AnswerAssignmentHistoryId) -> Self {
+        Self(id)
+    }
+
+    pub fn id(self) -> AnswerAssignmentHistoryId {
+        self.0
+    }
+}
+
+/// 回答の担当者が変更された記録です。`None` は担当者なしを表します。
+#[derive(UnsafeFromRawParts, Clone, Debug, PartialEq, Getters)]
+pub struct AnswerAssignmentHistoryEntry {
+    id: AnswerAssignmentHistoryId,
+    #[getter(skip)]
+    answer_id: AnswerId,
+    from_assignee: Option<UserId>,
+    to_assignee: Option<UserId>,
+    changed_by: UserSnapshot,
+    changed_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for AnswerAssignmentHistoryEntry {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for AnswerAssignmentHistoryEntry {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+    }
+}
+
+/// 担当割り当ては運営内部の情報なので、回答者には公開しません。
+impl GuardedBy<AnswerEntry, Read> for AnswerAssignmentHistoryEntry {
+    fn is_allowed_for(&self, _parent: &AnswerEntry, actor: &Actor) -> bool {
+        matches!(actor, Actor::System) || is_administrator(actor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn assignee_filter_matches_only_the_requested_assignee() {
+        let assignee: UserId = Uuid::from_u128(1).into();
+        let other: UserId = Uuid::from_u128(2).into();
+
+        assert!(AnswerAssigneeFilter::AssignedTo(assignee).matches(Some(assignee)));
+        assert!(!AnswerAssigneeFilter::AssignedTo(assignee).matches(Some(other)));
+        assert!(!AnswerAssigneeFilter::AssignedTo(assignee).matches(None));
+        assert!(AnswerAssigneeFilter::Unassigned.matches(None));
+        assert!(!AnswerAssigneeFilter::Unassigned.matches(Some(assignee)));
+    }
+}
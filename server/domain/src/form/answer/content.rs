@@ -22,67 +22,7 @@ impl PostedAnswerContents {
         questions: &[Question],
         contents: Vec<FormAnswerContent>,
     ) -> Result<Self, DomainError> {
-        let questions_by_id = questions
-            .iter()
-            .map(|question| (question.id(), question))
-            .collect::<HashMap<_, _>>();
-        let answered_question_ids = contents
-            .iter()
-            .map(|answer| answer.question_id)
-            .collect::<BTreeSet<_>>();
-
-        if answered_question_ids.len() != contents.len() {
-            return Err(DomainError::InvalidEntity {
-                message: "duplicate answer for the same question".to_string(),
-            });
-        }
-
-        if let Some(error) = contents.iter().find_map(|answer| {
-            let question = questions_by_id.get(&answer.question_id).ok_or_else(|| {
-                DomainError::InvalidEntity {
-                    message: format!(
-                        "question {} does not belong to the form",
-                        answer.question_id
-                    ),
-                }
-            });
-
-            question
-                .and_then(|question| match question {
-                    Question::Text(_) => Ok(()),
-                    Question::SingleChoice(choice_question) => choice_question
-                        .choices()
-                        .iter()
-                        .any(|choice| choice.label.as_str() == answer.answer.as_str())
-                        .then_some(())
-                        .ok_or_else(|| DomainError::InvalidEntity {
-                            message: format!(
-                                "answer for question {} must match one of the available choices",
-                                question.template_key().as_str()
-                            ),
-                        }),
-                    Question::MultipleChoice(choice_question) => {
-                        let values = parse_multiple_choice_answer(&answer.answer);
-                        (!values.is_empty()
-                            && values.iter().all(|value| {
-                                choice_question
-                                    .choices()
-                                    .iter()
-                                    .any(|choice| choice.label.as_str() == value.as_str())
-                            }))
-                        .then_some(())
-                        .ok_or_else(|| DomainError::InvalidEntity {
-                            message: format!(
-                                "answer for question {} must reference only existing choices",
-                                question.template_key().as_str()
-                            ),
-                        })
-                    }
-                })
-                .err()
-        }) {
-            return Err(error);
-        }
+        let answered_question_ids = validate_answered_questions(questions, &contents)?;
 
         if let Some(missing_question) = questions
             .iter()
@@ -109,7 +49,80 @@ impl PostedAnswerContents {
     }
 }
 
-fn parse_multiple_choice_answer(answer: &str) -> Vec<String> {
+/// `contents` が `questions` の質問に対する妥当な回答であるかを検証し、回答済みの質問 ID を返します。
+///
+/// 必須の質問が回答されているかは検証しません。
+pub(super) fn validate_answered_questions(
+    questions: &[Question],
+    contents: &[FormAnswerContent],
+) -> Result<BTreeSet<QuestionId>, DomainError> {
+    let questions_by_id = questions
+        .iter()
+        .map(|question| (question.id(), question))
+        .collect::<HashMap<_, _>>();
+    let answered_question_ids = contents
+        .iter()
+        .map(|answer| answer.question_id)
+        .collect::<BTreeSet<_>>();
+
+    if answered_question_ids.len() != contents.len() {
+        return Err(DomainError::InvalidEntity {
+            message: "duplicate answer for the same question".to_string(),
+        });
+    }
+
+    if let Some(error) = contents.iter().find_map(|answer| {
+        let question =
+            questions_by_id
+                .get(&answer.question_id)
+                .ok_or_else(|| DomainError::InvalidEntity {
+                    message: format!(
+                        "question {} does not belong to the form",
+                        answer.question_id
+                    ),
+                });
+
+        question
+            .and_then(|question| match question {
+                Question::Text(_) => Ok(()),
+                Question::SingleChoice(choice_question) => choice_question
+                    .choices()
+                    .iter()
+                    .any(|choice| choice.label.as_str() == answer.answer.as_str())
+                    .then_some(())
+                    .ok_or_else(|| DomainError::InvalidEntity {
+                        message: format!(
+                            "answer for question {} must match one of the available choices",
+                            question.template_key().as_str()
+                        ),
+                    }),
+                Question::MultipleChoice(choice_question) => {
+                    let values = parse_multiple_choice_answer(&answer.answer);
+                    (!values.is_empty()
+                        && values.iter().all(|value| {
+                            choice_question
+                                .choices()
+                                .iter()
+                                .any(|choice| choice.label.as_str() == value.as_str())
+                        }))
+                    .then_some(())
+                    .ok_or_else(|| DomainError::InvalidEntity {
+                        message: format!(
+                            "answer for question {} must reference only existing choices",
+                            question.template_key().as_str()
+                        ),
+                    })
+                }
+            })
+            .err()
+    }) {
+        return Err(error);
+    }
+
+    Ok(answered_question_ids)
+}
+
+pub(super) fn parse_multiple_choice_answer(answer: &str) -> Vec<String> {
     let trimmed = answer.trim();
     if trimmed.starts_with('[')
         && trimmed.ends_with(']')
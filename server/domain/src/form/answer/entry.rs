@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use chrono::{DateTime, Utc};
 use derive_getters::Getters;
 use domain_derive::UnsafeFromRawParts;
@@ -6,14 +8,17 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
 use crate::{
-    account::models::Role,
+    account::models::{AccountUser, Role, UserId},
     auth::Actor,
     form::{
         answer::{
-            AnswerAuthor, AnswerStatus, AnswerStatusHistoryEntry, AnswerTitle,
-            AnswerTitleHistoryEntry, FormAnswerContent, PostedAnswerContents,
-            RedmineImportedAnswerReference,
+            AnswerAssignmentHistoryEntry, AnswerAuthor, AnswerListOrder, AnswerModerationDecision,
+            AnswerModerationStatus, AnswerMoveHistoryEntry, AnswerQuestionMapping, AnswerQuizScore,
+            AnswerStatus, AnswerStatusHistoryEntry, AnswerTitle, AnswerTitleHistoryEntry,
+            FormAnswerContent, PostedAnswerContents, RedmineImportedAnswerReference,
+            content::validate_answered_questions,
         },
+        is_administrator,
         models::{ActiveForm, ArchivedForm, FormId},
     },
     types::authorization_guard::{
@@ -70,6 +75,7 @@ impl TryFrom<String> for AnswerPublication {
 pub struct AnswerPagePosition {
     last_timestamp: DateTime<Utc>,
     last_answer_id: AnswerId,
+    last_score: Option<u32>,
 }
 
 impl AnswerPagePosition {
@@ -77,9 +83,25 @@ impl AnswerPagePosition {
         Self {
             last_timestamp,
             last_answer_id,
+            last_score: None,
         }
     }
 
+    /// `entry` を最後の回答とする位置を返します。
+    pub fn of(entry: &AnswerEntry) -> Self {
+        Self::new(*entry.timestamp(), *entry.id())
+            .with_last_score(entry.quiz_score().map(|score| *score.score()))
+    }
+
+    /// 点数順の一覧で使う、最後の回答のクイズの点数を設定します。
+    pub fn with_last_score(self, last_score: Option<u32>) -> Self {
+        Self { last_score, ..self }
+    }
+
+    pub fn last_score(self) -> Option<u32> {
+        self.last_score
+    }
+
     pub fn last_timestamp(self) -> DateTime<Utc> {
         self.last_timestamp
     }
@@ -93,6 +115,11 @@ impl AnswerPagePosition {
         timestamp < self.last_timestamp
             || (timestamp == self.last_timestamp && answer_id < self.last_answer_id)
     }
+
+    /// この位置の後に `entry` が続くかを、`order` の並び順で判定します。
+    pub fn is_followed_in(self, order: AnswerListOrder, entry: &AnswerEntry) -> bool {
+        order.compare_positions(self, Self::of(entry)) == Ordering::Less
+    }
 }
 
 #[derive(Serialize, Deserialize, Getters, Clone, PartialEq, Debug)]
@@ -106,6 +133,14 @@ pub struct AnswerEntry {
     status: AnswerStatus,
     contents: Vec<FormAnswerContent>,
     redmine_reference: Option<RedmineImportedAnswerReference>,
+    #[serde(default)]
+    assignee: Option<UserId>,
+    #[serde(default)]
+    due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    quiz_score: Option<AnswerQuizScore>,
+    #[serde(default)]
+    moderation_status: AnswerModerationStatus,
 }
 
 impl AnswerEntry {
@@ -197,6 +232,52 @@ impl AnswerEntry {
             status,
             contents,
             redmine_reference,
+            assignee: None,
+            due_at: None,
+            quiz_score: None,
+            moderation_status: AnswerModerationStatus::default(),
+        }
+    }
+
+    /// 永続層に保存されている担当者を復元します。
+    ///
+    /// # Safety
+    ///
+    /// 呼び出し元は、`assignee` が割り当て時に検証済みの担当者であることを保証しなければなりません。
+    pub unsafe fn with_raw_assignee(self, assignee: Option<UserId>) -> Self {
+        Self { assignee, ..self }
+    }
+
+    /// 永続層に保存されている対応期限を復元します。
+    ///
+    /// # Safety
+    ///
+    /// 呼び出し元は、`due_at` がフォームの設定または管理者の操作で決まった期限であることを保証しなければなりません。
+    pub unsafe fn with_raw_due_at(self, due_at: Option<DateTime<Utc>>) -> Self {
+        Self { due_at, ..self }
+    }
+
+    /// 永続層に保存されているクイズの点数を復元します。
+    ///
+    /// # Safety
+    ///
+    /// 呼び出し元は、`quiz_score` が回答の受付時に採点した点数であることを保証しなければなりません。
+    pub unsafe fn with_raw_quiz_score(self, quiz_score: Option<AnswerQuizScore>) -> Self {
+        Self { quiz_score, ..self }
+    }
+
+    /// 永続層に保存されているモデレーションの状態を復元します。
+    ///
+    /// # Safety
+    ///
+    /// 呼び出し元は、`moderation_status` が受付時のフォームの設定またはモデレーターの判断で決まった状態であることを保証しなければなりません。
+    pub unsafe fn with_raw_moderation_status(
+        self,
+        moderation_status: AnswerModerationStatus,
+    ) -> Self {
+        Self {
+            moderation_status,
+            ..self
         }
     }
 
@@ -217,6 +298,10 @@ impl AnswerEntry {
             status: AnswerStatus::default(),
             contents: contents.into_inner(),
             redmine_reference: None,
+            assignee: None,
+            due_at: None,
+            quiz_score: None,
+            moderation_status: AnswerModerationStatus::default(),
         }
     }
 
@@ -235,6 +320,97 @@ impl AnswerEntry {
         Self { status, ..self }
     }
 
+    /// 回答の担当者を変更します。担当者になれるのは管理者だけです。
+    pub fn assign(self, assignee: Option<&AccountUser>) -> Result<Self, DomainError> {
+        match assignee {
+            Some(user) if user.role() != &Role::Administrator => {
+                Err(DomainError::InvalidAnswerAssignee)
+            }
+            assignee => Ok(Self {
+                assignee: assignee.map(|user| *user.id()),
+                ..self
+            }),
+        }
+    }
+
+    /// 担当者は運営内部の情報なので、管理者以外には公開しません。
+    pub fn assignee_visible_to(&self, actor: &Actor) -> Option<UserId> {
+        if is_staff(actor) { self.assignee } else { None }
+    }
+
+    pub(crate) fn change_due_at(self, due_at: Option<DateTime<Utc>>) -> Self {
+        Self { due_at, ..self }
+    }
+
+    pub(crate) fn change_quiz_score(self, quiz_score: Option<AnswerQuizScore>) -> Self {
+        Self { quiz_score, ..self }
+    }
+
+    pub(crate) fn change_moderation_status(
+        self,
+        moderation_status: AnswerModerationStatus,
+    ) -> Self {
+        Self {
+            moderation_status,
+            ..self
+        }
+    }
+
+    /// 確認待ちの回答を承認または却下します。確認待ちでない回答には判断を下せません。
+    pub(crate) fn moderate(self, decision: AnswerModerationDecision) -> Result<Self, DomainError> {
+        if self.moderation_status != AnswerModerationStatus::PENDING {
+            return Err(DomainError::AnswerNotPendingModeration);
+        }
+
+        Ok(self.change_moderation_status(decision.status()))
+    }
+
+    /// 回答を `destination` のフォームへ移動し、回答内容の質問を `mapping` に従って付け替えます。
+    ///
+    /// 回答済みの質問はすべて移動先の質問に対応付けられている必要があります。
+    /// 移動先で必須の質問が回答されているかは検証しません。
+    pub(crate) fn move_to(
+        self,
+        destination: &ActiveForm,
+        mapping: &AnswerQuestionMapping,
+    ) -> Result<Self, DomainError> {
+        let contents = self
+            .contents
+            .into_iter()
+            .map(
+                |content| match mapping.destination_of(content.question_id) {
+                    Some(question_id) => Ok(FormAnswerContent {
+                        question_id,
+                        ..content
+                    }),
+                    None => Err(DomainError::InvalidEntity {
+                        message: format!(
+                            "answered question {} is not mapped to the destination form",
+                            content.question_id
+                        ),
+                    }),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_answered_questions(destination.questions().as_slice(), &contents)?;
+
+        Ok(Self {
+            form_id: *destination.id(),
+            contents,
+            ..self
+        })
+    }
+
+    /// 対応期限も運営内部の情報なので、管理者以外には公開しません。
+    pub fn due_at_visible_to(&self, actor: &Actor) -> Option<DateTime<Utc>> {
+        if is_staff(actor) { self.due_at } else { None }
+    }
+
+    /// 対応が完了しないまま、`now` の時点で対応期限を過ぎているかを判定します。
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.status != AnswerStatus::COMPLETED && self.due_at.is_some_and(|due_at| due_at <= now)
+    }
+
     pub fn transition_status(
         self,
         status: AnswerStatus,
@@ -257,9 +433,34 @@ impl AnswerEntry {
                         if user.role() == &Role::Administrator
                             || self.author.authenticated_user_id() == Some(*user.id())
                 ) || matches!(actor, Actor::System)
+                    || self.is_temporary_author(actor)
             }
         }
     }
+
+    /// 承認されていない回答は、管理者と回答者本人にしか見せません。
+    pub(crate) fn moderation_allows_read(&self, actor: &Actor) -> bool {
+        self.moderation_status.is_approved()
+            || is_staff(actor)
+            || matches!(
+                actor,
+                Actor::AccountUser(user) if self.author.authenticated_user_id() == Some(*user.id())
+            )
+            || self.is_temporary_author(actor)
+    }
+
+    /// `actor` が、この回答をログインせずに書いた一時回答者本人かどうか。
+    pub(crate) fn is_temporary_author(&self, actor: &Actor) -> bool {
+        matches!(
+            (&self.author, actor),
+            (AnswerAuthor::Temporary(author), Actor::TemporaryAnswerAuthor(actor))
+                if author.id() == actor.id()
+        )
+    }
+}
+
+fn is_staff(actor: &Actor) -> bool {
+    matches!(actor, Actor::System) || is_administrator(actor)
 }
 
 impl Allowed<AnswerEntry, Read> {
@@ -276,6 +477,20 @@ impl Allowed<AnswerEntry, Read> {
     ) -> Result<Allowed<AnswerTitleHistoryEntry, Read>, DomainError> {
         self.authorize_read(entry)
     }
+
+    pub fn authorize_assignment_history_entry(
+        &self,
+        entry: AnswerAssignmentHistoryEntry,
+    ) -> Result<Allowed<AnswerAssignmentHistoryEntry, Read>, DomainError> {
+        self.authorize_read(entry)
+    }
+
+    pub fn authorize_move_history_entry(
+        &self,
+        entry: AnswerMoveHistoryEntry,
+    ) -> Result<Allowed<AnswerMoveHistoryEntry, Read>, DomainError> {
+        self.authorize_read(entry)
+    }
 }
 
 impl AuthorizationRole for AnswerEntry {
@@ -375,6 +590,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn only_administrators_can_be_assigned() {
+        let administrator = AccountUser::new(
+            "admin".to_string(),
+            Uuid::from_u128(1).into(),
+            Role::Administrator,
+        );
+        let standard_user = AccountUser::new(
+            "user".to_string(),
+            Uuid::from_u128(2).into(),
+            Role::StandardUser,
+        );
+
+        let assigned = empty_answer().assign(Some(&administrator)).unwrap();
+        assert_eq!(*assigned.assignee(), Some(*administrator.id()));
+        assert_eq!(*assigned.assign(None).unwrap().assignee(), None);
+        assert_eq!(
+            empty_answer().assign(Some(&standard_user)),
+            Err(DomainError::InvalidAnswerAssignee)
+        );
+    }
+
+    #[test]
+    fn only_unfinished_answers_past_the_due_date_are_overdue() {
+        let due_at = Utc.with_ymd_and_hms(2026, 8, 3, 12, 0, 0).unwrap();
+        let answer = empty_answer().change_due_at(Some(due_at));
+
+        assert!(!answer.is_overdue(due_at - chrono::Duration::seconds(1)));
+        assert!(answer.is_overdue(due_at));
+        assert!(
+            !answer
+                .clone()
+                .change_status(AnswerStatus::COMPLETED)
+                .is_overdue(due_at)
+        );
+        assert!(!empty_answer().is_overdue(due_at));
+    }
+
     #[test]
     fn page_position_follows_timestamp_desc_then_answer_id_desc() {
         let timestamp = Utc.with_ymd_and_hms(2026, 8, 3, 12, 0, 0).unwrap();
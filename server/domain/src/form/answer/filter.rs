@@ -0,0 +1,266 @@
+use std::cmp::Ordering;
+
+use chrono::Utc;
+
+use crate::{
+    account::models::{Role, UserId},
+    auth::Actor,
+    form::answer::{
+        AnswerAssigneeFilter, AnswerEntry, AnswerModerationStatus, AnswerPagePosition, AnswerStatus,
+    },
+};
+
+/// 回答一覧の並び順です。
+///
+/// 点数順では、点数が同じ回答を新しい順に並べ、点数のない回答は最も低い点数として扱います。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AnswerListOrder {
+    /// 回答日時の新しい順
+    #[default]
+    Newest,
+    /// クイズの点数の高い順
+    ScoreDescending,
+    /// クイズの点数の低い順
+    ScoreAscending,
+}
+
+impl AnswerListOrder {
+    pub fn is_by_score(self) -> bool {
+        self != Self::Newest
+    }
+
+    /// 回答一覧で `left` が `right` より前に並ぶ場合に [`Ordering::Less`] を返します。
+    pub fn compare(self, left: &AnswerEntry, right: &AnswerEntry) -> Ordering {
+        self.compare_positions(AnswerPagePosition::of(left), AnswerPagePosition::of(right))
+    }
+
+    pub(super) fn compare_positions(
+        self,
+        left: AnswerPagePosition,
+        right: AnswerPagePosition,
+    ) -> Ordering {
+        let newest_first = right
+            .last_timestamp()
+            .cmp(&left.last_timestamp())
+            .then_with(|| right.last_answer_id().cmp(&left.last_answer_id()));
+
+        match self {
+            Self::Newest => newest_first,
+            Self::ScoreDescending => right
+                .last_score()
+                .cmp(&left.last_score())
+                .then(newest_first),
+            Self::ScoreAscending => left
+                .last_score()
+                .cmp(&right.last_score())
+                .then(newest_first),
+        }
+    }
+}
+
+/// 回答一覧の絞り込み条件と並び順です。未指定の条件は絞り込みに使いません。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AnswerListFilter {
+    status: Option<AnswerStatus>,
+    assignee: Option<AnswerAssigneeFilter>,
+    overdue_only: bool,
+    unnotified_overdue_only: bool,
+    min_score: Option<u32>,
+    max_score: Option<u32>,
+    unread_author_messages_for: Option<UserId>,
+    moderation_status: Option<AnswerModerationStatus>,
+    order: AnswerListOrder,
+}
+
+impl AnswerListFilter {
+    pub fn with_status(self, status: Option<AnswerStatus>) -> Self {
+        Self { status, ..self }
+    }
+
+    pub fn with_assignee(self, assignee: Option<AnswerAssigneeFilter>) -> Self {
+        Self { assignee, ..self }
+    }
+
+    /// `true` を指定すると、対応期限を過ぎた未完了の回答だけを対象にします。
+    pub fn with_overdue_only(self, overdue_only: bool) -> Self {
+        Self {
+            overdue_only,
+            ..self
+        }
+    }
+
+    /// `true` を指定すると、期限超過をまだ通知していない回答だけを対象にします。
+    ///
+    /// 期限超過の通知のための条件のため、[`Actor::System`] だけが使えます。
+    pub fn with_unnotified_overdue_only(self, unnotified_overdue_only: bool) -> Self {
+        Self {
+            unnotified_overdue_only,
+            ..self
+        }
+    }
+
+    /// クイズの点数が `min_score` 以上 `max_score` 以下の回答だけを対象にします。
+    ///
+    /// どちらかを指定すると、採点されていない回答は対象外になります。
+    pub fn with_score_range(self, min_score: Option<u32>, max_score: Option<u32>) -> Self {
+        Self {
+            min_score,
+            max_score,
+            ..self
+        }
+    }
+
+    /// `reader` が未読の、回答者からのメッセージがある回答だけを対象にします。
+    pub fn with_unread_author_messages_for(self, reader: Option<UserId>) -> Self {
+        Self {
+            unread_author_messages_for: reader,
+            ..self
+        }
+    }
+
+    /// モデレーションの状態で絞り込みます。確認待ちの回答の一覧に使います。
+    pub fn with_moderation_status(self, moderation_status: Option<AnswerModerationStatus>) -> Self {
+        Self {
+            moderation_status,
+            ..self
+        }
+    }
+
+    pub fn with_order(self, order: AnswerListOrder) -> Self {
+        Self { order, ..self }
+    }
+
+    pub fn status(&self) -> Option<AnswerStatus> {
+        self.status
+    }
+
+    pub fn assignee(&self) -> Option<AnswerAssigneeFilter> {
+        self.assignee
+    }
+
+    pub fn overdue_only(&self) -> bool {
+        self.overdue_only
+    }
+
+    pub fn unnotified_overdue_only(&self) -> bool {
+        self.unnotified_overdue_only
+    }
+
+    pub fn min_score(&self) -> Option<u32> {
+        self.min_score
+    }
+
+    pub fn max_score(&self) -> Option<u32> {
+        self.max_score
+    }
+
+    pub fn unread_author_messages_for(&self) -> Option<UserId> {
+        self.unread_author_messages_for
+    }
+
+    pub fn moderation_status(&self) -> Option<AnswerModerationStatus> {
+        self.moderation_status
+    }
+
+    pub fn order(&self) -> AnswerListOrder {
+        self.order
+    }
+
+    /// クイズの点数で絞り込むか並べ替えるかを返します。
+    pub fn uses_score(&self) -> bool {
+        self.min_score.is_some() || self.max_score.is_some() || self.order.is_by_score()
+    }
+
+    /// `actor` がこの条件で回答一覧を取得できるかを返します。
+    ///
+    /// クイズの点数による絞り込みと並べ替え、担当者と対応期限による絞り込みは管理者だけが使えます。
+    /// 未読メッセージによる絞り込みは、管理者が自分の既読状況に対してだけ使えます。
+    pub fn can_be_used_by(&self, actor: &Actor) -> bool {
+        match actor {
+            Actor::System => true,
+            _ if self.unnotified_overdue_only => false,
+            Actor::AccountUser(user) if user.role() == &Role::Administrator => self
+                .unread_author_messages_for
+                .is_none_or(|reader| &reader == user.id()),
+            _ => {
+                !self.uses_score()
+                    && self.assignee.is_none()
+                    && !self.overdue_only
+                    && self.unread_author_messages_for.is_none()
+            }
+        }
+    }
+
+    /// メッセージの既読状況と期限超過の通知状況による絞り込みは判定しません。
+    pub fn matches(&self, entry: &AnswerEntry) -> bool {
+        let score = entry.quiz_score().map(|score| *score.score());
+
+        self.status.is_none_or(|status| *entry.status() == status)
+            && self
+                .assignee
+                .is_none_or(|assignee| assignee.matches(*entry.assignee()))
+            && (!self.overdue_only || entry.is_overdue(Utc::now()))
+            && self
+                .min_score
+                .is_none_or(|min_score| score.is_some_and(|score| score >= min_score))
+            && self
+                .max_score
+                .is_none_or(|max_score| score.is_some_and(|score| score <= max_score))
+            && self
+                .moderation_status
+                .is_none_or(|moderation_status| *entry.moderation_status() == moderation_status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::account::models::AccountUser;
+
+    fn actor(role: Role) -> Actor {
+        Actor::from(AccountUser::new(
+            "user".to_string(),
+            Uuid::from_u128(1).into(),
+            role,
+        ))
+    }
+
+    #[test]
+    fn only_administrators_can_filter_by_assignee() {
+        let assigned = AnswerListFilter::default().with_assignee(Some(
+            AnswerAssigneeFilter::AssignedTo(Uuid::from_u128(2).into()),
+        ));
+        let unassigned =
+            AnswerListFilter::default().with_assignee(Some(AnswerAssigneeFilter::Unassigned));
+
+        assert!(assigned.can_be_used_by(&actor(Role::Administrator)));
+        assert!(unassigned.can_be_used_by(&actor(Role::Administrator)));
+        assert!(!assigned.can_be_used_by(&actor(Role::StandardUser)));
+        assert!(!unassigned.can_be_used_by(&actor(Role::StandardUser)));
+        assert!(!unassigned.can_be_used_by(&Actor::Anonymous));
+        assert!(AnswerListFilter::default().can_be_used_by(&actor(Role::StandardUser)));
+    }
+
+    #[test]
+    fn only_administrators_can_filter_overdue_answers() {
+        let overdue = AnswerListFilter::default().with_overdue_only(true);
+
+        assert!(overdue.can_be_used_by(&actor(Role::Administrator)));
+        assert!(overdue.can_be_used_by(&Actor::System));
+        assert!(!overdue.can_be_used_by(&actor(Role::StandardUser)));
+        assert!(!overdue.can_be_used_by(&Actor::Anonymous));
+    }
+
+    #[test]
+    fn only_the_system_can_filter_unnotified_overdue_answers() {
+        let unnotified = AnswerListFilter::default()
+            .with_overdue_only(true)
+            .with_unnotified_overdue_only(true);
+
+        assert!(unnotified.can_be_used_by(&Actor::System));
+        assert!(!unnotified.can_be_used_by(&actor(Role::Administrator)));
+        assert!(!unnotified.can_be_used_by(&actor(Role::StandardUser)));
+    }
+}
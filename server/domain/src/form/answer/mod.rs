@@ -1,12 +1,22 @@
+mod assignment;
 mod author;
 mod content;
 mod entry;
+mod filter;
 mod label;
+mod moderation;
+mod quiz;
 mod relation;
 mod settings;
+mod statistics;
 mod status;
 mod title;
+mod transfer;
 
+pub use assignment::{
+    AnswerAssigneeFilter, AnswerAssignmentHistoryEntry, AnswerAssignmentHistoryId,
+    AnswerAssignmentHistoryPagePosition,
+};
 pub use author::{
     AnswerAuthor, RedmineImportedAnswerReference, RedmineIssueId, RedmineUserSnapshot,
     TemporaryAnswerAuthor, TemporaryAnswerAuthorId,
@@ -15,13 +25,22 @@ pub use content::{FormAnswerContent, FormAnswerContentId, PostedAnswerContents};
 pub use entry::{
     AnswerEntry, AnswerId, AnswerPagePosition, AnswerPublication, ArchivedAnswerEntry,
 };
+pub use filter::{AnswerListFilter, AnswerListOrder};
 pub use label::{AnswerLabel, AnswerLabelId};
+pub use moderation::{AnswerModerationDecision, AnswerModerationStatus};
+pub use quiz::{AnswerQuizScore, AnswerQuizSettings};
 pub use relation::{
-    AnswerReference, AnswerRelation, AnswerRelationEndpoint, ReadableAnswerRelation,
+    AnswerReference, AnswerRelation, AnswerRelationDirection, AnswerRelationEndpoint,
+    AnswerRelationKind, ReadableAnswerRelation, RelatedAnswer,
 };
 pub use settings::{
-    AnswerAcceptancePeriod, AnswerAuthorDisclosure, AnswerAuthorPublicationPolicy, AnswerSettings,
-    AnswerVisibility, DefaultAnswerTitle,
+    AnswerAcceptancePeriod, AnswerAuthorDisclosure, AnswerAuthorPublicationPolicy,
+    AnswerResponseDeadline, AnswerSettings, AnswerVisibility, DefaultAnswerTitle,
+    MessageThreadLockPolicy, ResponseDeadlineHours, TemporaryAnswerModerationPolicy,
+};
+pub use statistics::{
+    AnswerContentCount, AnswerStatistics, AnswerStatisticsScope, ChoiceAnswerCount,
+    DailyAnswerCount, LabelAnswerCount, StatusAnswerCount,
 };
 pub use status::{
     AnswerStatus, AnswerStatusChange, AnswerStatusHistoryEntry, AnswerStatusHistoryId,
@@ -30,3 +49,7 @@ pub use status::{
 pub use title::{
     AnswerTitle, AnswerTitleHistoryEntry, AnswerTitleHistoryId, AnswerTitleHistoryPagePosition,
 };
+pub use transfer::{
+    AnswerMoveHistoryEntry, AnswerMoveHistoryId, AnswerMoveHistoryPagePosition,
+    AnswerQuestionMapping,
+};
@@ -0,0 +1,48 @@
+use errors::domain::DomainError;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// 回答の公開前の確認 (モデレーション) の状態です。
+///
+/// 確認待ちと却下された回答は、管理者と回答者本人にしか見えず、検索や通知の対象にもなりません。
+#[allow(non_camel_case_types)]
+#[derive(
+    Serialize, Deserialize, Debug, EnumString, Display, Copy, Clone, Default, PartialEq, Eq,
+)]
+pub enum AnswerModerationStatus {
+    #[default]
+    APPROVED,
+    PENDING,
+    REJECTED,
+}
+
+impl AnswerModerationStatus {
+    pub fn is_approved(self) -> bool {
+        self == Self::APPROVED
+    }
+}
+
+impl TryFrom<String> for AnswerModerationStatus {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        use std::str::FromStr;
+        Self::from_str(&value).map_err(Into::into)
+    }
+}
+
+/// 確認待ちの回答に対するモデレーターの判断です。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnswerModerationDecision {
+    Approve,
+    Reject,
+}
+
+impl AnswerModerationDecision {
+    pub fn status(self) -> AnswerModerationStatus {
+        match self {
+            Self::Approve => AnswerModerationStatus::APPROVED,
+            Self::Reject => AnswerModerationStatus::REJECTED,
+        }
+    }
+}
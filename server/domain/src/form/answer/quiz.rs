@@ -0,0 +1,244 @@
+use std::collections::BTreeSet;
+
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+#[cfg(test)]
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::form::{
+    answer::{FormAnswerContent, content::parse_multiple_choice_answer},
+    question::{Choice, Question},
+};
+
+/// フォームをクイズとして採点するかどうかと、採点結果を回答者に見せるかどうかの設定です。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AnswerQuizSettings {
+    enabled: bool,
+    score_visible_to_author: bool,
+}
+
+impl AnswerQuizSettings {
+    pub fn new(enabled: bool, score_visible_to_author: bool) -> Self {
+        Self {
+            enabled,
+            score_visible_to_author,
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_score_visible_to_author(self) -> bool {
+        self.score_visible_to_author
+    }
+
+    pub fn change_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    pub fn change_score_visible_to_author(self, score_visible_to_author: bool) -> Self {
+        Self {
+            score_visible_to_author,
+            ..self
+        }
+    }
+
+    /// 回答者に自分の点数を見せるかを返します。クイズが無効なフォームでは見せません。
+    pub fn shows_score_to_author(self) -> bool {
+        self.enabled && self.score_visible_to_author
+    }
+}
+
+/// クイズとして採点した回答の点数です。
+///
+/// 点数は回答を受け付けた時点の正解で決まり、あとから正解や点数を変えても採点し直しません。
+#[derive(
+    UnsafeFromRawParts, Serialize, Deserialize, Getters, Debug, Copy, Clone, PartialEq, Eq,
+)]
+pub struct AnswerQuizScore {
+    score: u32,
+    max_score: u32,
+}
+
+impl AnswerQuizScore {
+    /// `questions` の正解の選択肢をもとに `contents` を採点します。
+    ///
+    /// - 単一選択の質問は、選んだ選択肢が正解ならその点数を与えます
+    /// - 複数選択の質問は、選んだ正解の選択肢の点数の合計を与えます。
+    ///   ただし不正解の選択肢を 1 つでも選んだ場合、その質問は 0 点です
+    ///
+    /// 正解の選択肢を持つ質問がない場合は `None` を返します。
+    pub fn grade(questions: &[Question], contents: &[FormAnswerContent]) -> Option<Self> {
+        let graded = questions
+            .iter()
+            .filter_map(|question| {
+                let answer = contents
+                    .iter()
+                    .find(|content| content.question_id == question.id())
+                    .map(|content| content.answer.as_str());
+
+                match question {
+                    Question::Text(_) => None,
+                    Question::SingleChoice(question) => {
+                        grade_single_choice(question.choices(), answer)
+                    }
+                    Question::MultipleChoice(question) => {
+                        grade_multiple_choice(question.choices(), answer)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (!graded.is_empty()).then(|| Self {
+            score: graded.iter().map(|(score, _)| score).sum(),
+            max_score: graded.iter().map(|(_, max_score)| max_score).sum(),
+        })
+    }
+}
+
+fn points_of(choice: &Choice) -> Option<u32> {
+    choice.quiz_points.map(|points| points.value())
+}
+
+/// 単一選択の質問の `(得点, 満点)` を返します。正解の選択肢がない質問は採点しません。
+fn grade_single_choice(choices: &[Choice], answer: Option<&str>) -> Option<(u32, u32)> {
+    let max_score = choices.iter().filter_map(points_of).max()?;
+    let score = answer
+        .and_then(|answer| {
+            choices
+                .iter()
+                .find(|choice| choice.label.as_str() == answer)
+        })
+        .and_then(points_of)
+        .unwrap_or_default();
+
+    Some((score, max_score))
+}
+
+/// 複数選択の質問の `(得点, 満点)` を返します。正解の選択肢がない質問は採点しません。
+fn grade_multiple_choice(choices: &[Choice], answer: Option<&str>) -> Option<(u32, u32)> {
+    let max_score = choices
+        .iter()
+        .filter_map(points_of)
+        .reduce(|sum, points| sum + points)?;
+    let selected = answer
+        .map(parse_multiple_choice_answer)
+        .unwrap_or_default()
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    let selected_choices = choices
+        .iter()
+        .filter(|choice| selected.contains(choice.label.as_str()))
+        .collect::<Vec<_>>();
+    let score = if selected_choices.iter().all(|choice| choice.is_correct()) {
+        selected_choices.into_iter().filter_map(points_of).sum()
+    } else {
+        0
+    };
+
+    Some((score, max_score))
+}
+
+#[cfg(test)]
+mod tests {
+    use types::non_empty_vec::NonEmptyVec;
+
+    use super::*;
+    use crate::form::{
+        answer::FormAnswerContentId,
+        question::{QuestionType, QuizPoints},
+    };
+
+    fn choice(position: u16, label: &str, quiz_points: Option<u32>) -> Choice {
+        Choice::new(None, position, label.to_string().try_into().unwrap())
+            .with_quiz_points(quiz_points.map(|points| QuizPoints::try_from(points).unwrap()))
+    }
+
+    fn question(position: u16, question_type: QuestionType, choices: Vec<Choice>) -> Question {
+        unsafe {
+            Question::from_raw_parts(
+                uuid::Uuid::new_v4().into(),
+                format!("q{position}").try_into().unwrap(),
+                position,
+                "Question".to_string().try_into().unwrap(),
+                None,
+                question_type,
+                NonEmptyVec::try_new(choices).ok(),
+                false,
+            )
+            .unwrap()
+        }
+    }
+
+    fn answer(question: &Question, answer: &str) -> FormAnswerContent {
+        FormAnswerContent {
+            id: FormAnswerContentId::new(),
+            question_id: question.id(),
+            answer: answer.to_string(),
+        }
+    }
+
+    fn quiz() -> Vec<Question> {
+        vec![
+            question(
+                0,
+                QuestionType::SingleChoice,
+                vec![choice(0, "Creeper", Some(10)), choice(1, "Zombie", None)],
+            ),
+            question(
+                1,
+                QuestionType::MultipleChoice,
+                vec![
+                    choice(0, "Diamond", Some(5)),
+                    choice(1, "Netherite", Some(5)),
+                    choice(2, "Dirt", None),
+                ],
+            ),
+            question(2, QuestionType::Text, Vec::new()),
+        ]
+    }
+
+    #[test]
+    fn correct_answers_earn_the_points_of_their_choices() {
+        let questions = quiz();
+        let contents = vec![
+            answer(&questions[0], "Creeper"),
+            answer(&questions[1], r#"["Diamond"]"#),
+            answer(&questions[2], "free text"),
+        ];
+
+        let score = AnswerQuizScore::grade(&questions, &contents).unwrap();
+
+        assert_eq!((*score.score(), *score.max_score()), (15, 20));
+    }
+
+    #[test]
+    fn choosing_an_incorrect_choice_in_a_multiple_choice_question_earns_nothing_for_it() {
+        let questions = quiz();
+        let contents = vec![
+            answer(&questions[0], "Zombie"),
+            answer(&questions[1], "Diamond, Netherite, Dirt"),
+        ];
+
+        let score = AnswerQuizScore::grade(&questions, &contents).unwrap();
+
+        assert_eq!((*score.score(), *score.max_score()), (0, 20));
+    }
+
+    #[test]
+    fn forms_without_correct_choices_are_not_graded() {
+        let questions = vec![question(
+            0,
+            QuestionType::SingleChoice,
+            vec![choice(0, "Yes", None), choice(1, "No", None)],
+        )];
+
+        assert_eq!(
+            AnswerQuizScore::grade(&questions, &[answer(&questions[0], "Yes")]),
+            None
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use errors::domain::DomainError;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
 use uuid::Uuid;
 
 use crate::{
@@ -58,14 +60,71 @@ impl AnswerRelationEndpoint for ArchivedAnswerEntry {
     }
 }
 
-/// 二つの回答間の直接的な、対称な関連です。
+/// 回答間の関連の種類です。
 ///
-/// 端点は生成時に決定的な順序へ正規化されます。したがって A-B と B-A は同じ値であり、
-/// この型から別の関連を推移的に導く API は提供しません。
+/// `Related` 以外は向きを持ち、関連元の回答から見て「関連先の重複である」
+/// 「関連先の続報である」「関連先の対応を妨げている」ことを表します。
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnswerRelationKind {
+    #[default]
+    Related,
+    DuplicateOf,
+    FollowUpOf,
+    Blocks,
+}
+
+impl AnswerRelationKind {
+    pub fn is_directional(self) -> bool {
+        self != Self::Related
+    }
+}
+
+/// ある回答から見た、向きを持つ関連の向きです。
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnswerRelationDirection {
+    /// 見ている回答が関連元です。
+    Outgoing,
+    /// 見ている回答が関連先です。
+    Incoming,
+}
+
+/// ある回答から見た、直接関連する回答とその関連の種類です。
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RelatedAnswer {
+    pub reference: AnswerReference,
+    pub kind: AnswerRelationKind,
+    /// `Related` のように向きを持たない関連では `None` です。
+    pub direction: Option<AnswerRelationDirection>,
+}
+
+/// 二つの回答間の直接的な関連です。
+///
+/// 端点は種類によらず生成時に決定的な順序へ正規化され、同じ二つの回答の間には
+/// 一つの関連だけが存在します。向きを持つ種類では、関連元が正規化後の `second` 側に
+/// なった場合に `reversed` で向きを保持します。したがって `Related` の A-B と B-A は
+/// 同じ値であり、この型から別の関連を推移的に導く API は提供しません。
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct AnswerRelation {
     first: AnswerReference,
     second: AnswerReference,
+    kind: AnswerRelationKind,
+    reversed: bool,
 }
 
 /// 両端点の閲覧認可を同じ利用者について確認済みである回答関連です。
@@ -97,8 +156,16 @@ impl ReadableAnswerRelation {
         &self,
         source: AnswerReference,
     ) -> Result<AnswerReference, DomainError> {
+        self.related_answer_for(source)
+            .map(|related| related.reference)
+    }
+
+    pub fn related_answer_for(
+        &self,
+        source: AnswerReference,
+    ) -> Result<RelatedAnswer, DomainError> {
         self.relation
-            .other_endpoint(source)
+            .related_answer_for(source)
             .ok_or_else(|| DomainError::InvalidEntity {
                 message: "source answer is not an endpoint of the relation".to_string(),
             })
@@ -128,20 +195,52 @@ impl AuthorizationGuardDefinitions for ReadableAnswerRelation {
 }
 
 impl AnswerRelation {
+    /// 向きを持たない `Related` の関連を作ります。
     pub fn new(first: AnswerReference, second: AnswerReference) -> Result<Self, DomainError> {
-        if first == second {
+        Self::with_kind(first, second, AnswerRelationKind::Related)
+    }
+
+    /// `source` から `target` への `kind` の関連を作ります。
+    pub fn with_kind(
+        source: AnswerReference,
+        target: AnswerReference,
+        kind: AnswerRelationKind,
+    ) -> Result<Self, DomainError> {
+        if source == target {
             return Err(DomainError::InvalidEntity {
                 message: "an answer cannot be related to itself".to_string(),
             });
         }
 
-        let (first, second) = if first.ordering_key() < second.ordering_key() {
-            (first, second)
+        let reversed = source.ordering_key() > target.ordering_key();
+        let (first, second) = if reversed {
+            (target, source)
         } else {
-            (second, first)
+            (source, target)
         };
 
-        Ok(Self { first, second })
+        Ok(Self {
+            first,
+            second,
+            kind,
+            reversed: reversed && kind.is_directional(),
+        })
+    }
+
+    /// 正規化済みの端点と保存された向きから関連を復元します。
+    ///
+    /// `first` と `second` が正規化順でない場合は入れ替えたうえで向きを補正します。
+    pub fn from_normalized(
+        first: AnswerReference,
+        second: AnswerReference,
+        kind: AnswerRelationKind,
+        reversed: bool,
+    ) -> Result<Self, DomainError> {
+        if reversed {
+            Self::with_kind(second, first, kind)
+        } else {
+            Self::with_kind(first, second, kind)
+        }
     }
 
     pub fn first(self) -> AnswerReference {
@@ -152,10 +251,67 @@ impl AnswerRelation {
         self.second
     }
 
+    pub fn kind(self) -> AnswerRelationKind {
+        self.kind
+    }
+
+    /// 向きを持つ関連で、関連元が `second` 側であるかを返します。
+    pub fn is_reversed(self) -> bool {
+        self.reversed
+    }
+
     pub fn endpoints(self) -> [AnswerReference; 2] {
         [self.first, self.second]
     }
 
+    /// 種類や向きによらず、同じ二つの回答を結ぶ関連であるかを返します。
+    pub fn same_endpoints(self, other: Self) -> bool {
+        self.endpoints() == other.endpoints()
+    }
+
+    /// 向きを持つ関連の関連元です。`Related` では `None` です。
+    pub fn source(self) -> Option<AnswerReference> {
+        self.kind.is_directional().then_some(if self.reversed {
+            self.second
+        } else {
+            self.first
+        })
+    }
+
+    /// `endpoint` から見た反対側の回答と関連の種類・向きを返します。
+    pub fn related_answer_for(self, endpoint: AnswerReference) -> Option<RelatedAnswer> {
+        let reference = self.other_endpoint(endpoint)?;
+        let direction = self.source().map(|source| {
+            if source == endpoint {
+                AnswerRelationDirection::Outgoing
+            } else {
+                AnswerRelationDirection::Incoming
+            }
+        });
+
+        Some(RelatedAnswer {
+            reference,
+            kind: self.kind,
+            direction,
+        })
+    }
+
+    /// 端点 `from` を `to` に置き換えた関連を返します。種類と向きは保たれます。
+    pub fn replace_endpoint(
+        self,
+        from: AnswerReference,
+        to: AnswerReference,
+    ) -> Result<Self, DomainError> {
+        let replace = |endpoint| if endpoint == from { to } else { endpoint };
+        let (source, target) = if self.reversed {
+            (self.second, self.first)
+        } else {
+            (self.first, self.second)
+        };
+
+        Self::with_kind(replace(source), replace(target), self.kind)
+    }
+
     pub fn other_endpoint(self, endpoint: AnswerReference) -> Option<AnswerReference> {
         match self.endpoints() {
             [first, second] if first == endpoint => Some(second),
@@ -247,6 +403,14 @@ impl Allowed<ReadableAnswerRelation, Read> {
     ) -> Result<AnswerReference, DomainError> {
         self.value().opposite_endpoint_for(source)
     }
+
+    /// 認可済み関係の反対側を、関連の種類と `source` から見た向きとともに取り出します。
+    pub fn related_answer_for(
+        &self,
+        source: AnswerReference,
+    ) -> Result<RelatedAnswer, DomainError> {
+        self.value().related_answer_for(source)
+    }
 }
 
 #[cfg(test)]
@@ -322,6 +486,55 @@ mod tests {
         assert_ne!(AnswerRelation::new(a, b), AnswerRelation::new(a, c));
     }
 
+    #[test]
+    fn directional_relations_keep_their_direction_after_normalization() {
+        let original = reference(1, 1);
+        let duplicate = reference(2, 1);
+        let relation =
+            AnswerRelation::with_kind(duplicate, original, AnswerRelationKind::DuplicateOf)
+                .unwrap();
+
+        assert_eq!(relation.endpoints(), [original, duplicate]);
+        assert!(relation.is_reversed());
+        assert_eq!(relation.source(), Some(duplicate));
+        assert_eq!(
+            relation.related_answer_for(duplicate),
+            Some(RelatedAnswer {
+                reference: original,
+                kind: AnswerRelationKind::DuplicateOf,
+                direction: Some(AnswerRelationDirection::Outgoing),
+            })
+        );
+        assert_eq!(
+            relation
+                .related_answer_for(original)
+                .and_then(|related| related.direction),
+            Some(AnswerRelationDirection::Incoming)
+        );
+        assert_eq!(
+            AnswerRelation::from_normalized(
+                original,
+                duplicate,
+                AnswerRelationKind::DuplicateOf,
+                true
+            ),
+            Ok(relation)
+        );
+        assert!(relation.same_endpoints(AnswerRelation::new(original, duplicate).unwrap()));
+        assert_ne!(
+            relation,
+            AnswerRelation::with_kind(original, duplicate, AnswerRelationKind::DuplicateOf)
+                .unwrap()
+        );
+        assert_eq!(
+            AnswerRelation::new(duplicate, original)
+                .unwrap()
+                .related_answer_for(duplicate)
+                .and_then(|related| related.direction),
+            None
+        );
+    }
+
     #[test]
     fn allows_answers_from_different_forms() {
         let first_form_answer = reference(1, 1);
@@ -339,6 +552,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replacing_an_endpoint_keeps_the_direction() {
+        let duplicate = reference(1, 2);
+        let original = reference(2, 1);
+        let moved_duplicate = reference(3, 2);
+        let relation =
+            AnswerRelation::with_kind(duplicate, original, AnswerRelationKind::DuplicateOf)
+                .unwrap();
+
+        let replaced = relation
+            .replace_endpoint(duplicate, moved_duplicate)
+            .unwrap();
+
+        assert_eq!(replaced.endpoints(), [original, moved_duplicate]);
+        assert_eq!(replaced.source(), Some(moved_duplicate));
+        assert_eq!(replaced.kind(), AnswerRelationKind::DuplicateOf);
+    }
+
     #[test]
     fn authorize_read_rejects_different_actor_or_endpoint_proofs() {
         let source_reference = reference(1, 1);
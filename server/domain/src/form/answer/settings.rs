@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 #[cfg(test)]
 use common::test_utils::arbitrary_opt_date_time;
 use derive_getters::Getters;
@@ -15,7 +15,10 @@ use types::non_empty_string::NonEmptyString;
 use crate::{
     account::models::{Role, UserGroupId},
     auth::Actor,
-    form::answer::{AnswerAuthor, AnswerEntry},
+    form::answer::{
+        AnswerAuthor, AnswerEntry, AnswerModerationStatus, AnswerQuizScore, AnswerQuizSettings,
+        AnswerStatisticsScope, AnswerStatus,
+    },
     form::settings::AllowedUserGroups,
 };
 
@@ -60,6 +63,66 @@ impl AnswerAuthorPublicationPolicy {
     }
 }
 
+/// 回答が完了したときに、メッセージスレッドへの投稿を締め切るかどうかです。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum MessageThreadLockPolicy {
+    #[default]
+    KeepOpen,
+    LockOnCompletion,
+}
+
+impl MessageThreadLockPolicy {
+    pub fn from_lock_on_completion(lock_on_completion: bool) -> Self {
+        if lock_on_completion {
+            Self::LockOnCompletion
+        } else {
+            Self::KeepOpen
+        }
+    }
+
+    pub fn locks_on_completion(self) -> bool {
+        self == Self::LockOnCompletion
+    }
+
+    /// `status` の回答のメッセージスレッドを締め切るかを返します。
+    pub fn locks(self, status: AnswerStatus) -> bool {
+        self.locks_on_completion() && status == AnswerStatus::COMPLETED
+    }
+}
+
+/// ログインせずに送られた回答を、モデレーターが確認するまで保留するかどうかです。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TemporaryAnswerModerationPolicy {
+    #[default]
+    Publish,
+    HoldForReview,
+}
+
+impl TemporaryAnswerModerationPolicy {
+    pub fn from_hold_for_review(hold_for_review: bool) -> Self {
+        if hold_for_review {
+            Self::HoldForReview
+        } else {
+            Self::Publish
+        }
+    }
+
+    pub fn holds_for_review(self) -> bool {
+        self == Self::HoldForReview
+    }
+
+    /// 受け付けた一時回答の、最初のモデレーションの状態を返します。
+    pub fn initial_status(self) -> AnswerModerationStatus {
+        if self.holds_for_review() {
+            AnswerModerationStatus::PENDING
+        } else {
+            AnswerModerationStatus::APPROVED
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum AnswerAuthorDisclosure {
     Disclosed,
@@ -78,6 +141,59 @@ impl DefaultAnswerTitle {
     }
 }
 
+/// 回答日時から対応期限までの時間数です。1 時間から 1 年までを指定できます。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct ResponseDeadlineHours(
+    #[cfg_attr(test, proptest(strategy = "1..=ResponseDeadlineHours::MAX"))] u32,
+);
+
+impl ResponseDeadlineHours {
+    const MAX: u32 = 24 * 365;
+
+    pub fn hours(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for ResponseDeadlineHours {
+    type Error = DomainError;
+
+    fn try_from(hours: u32) -> Result<Self, Self::Error> {
+        if (1..=Self::MAX).contains(&hours) {
+            Ok(Self(hours))
+        } else {
+            Err(DomainError::InvalidAnswerResponseDeadline)
+        }
+    }
+}
+
+impl From<ResponseDeadlineHours> for u32 {
+    fn from(hours: ResponseDeadlineHours) -> Self {
+        hours.0
+    }
+}
+
+/// 新しい回答に設定する対応期限 (SLA) です。`None` は期限を設けないことを表します。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Clone, Copy, DerivingVia, Default, Debug, PartialEq, Eq)]
+#[deriving(From, Into, IntoInner, Serialize(via: Option::<ResponseDeadlineHours>), Deserialize(via: Option::<ResponseDeadlineHours>
+))]
+pub struct AnswerResponseDeadline(Option<ResponseDeadlineHours>);
+
+impl AnswerResponseDeadline {
+    pub fn new(hours: Option<ResponseDeadlineHours>) -> Self {
+        Self(hours)
+    }
+
+    /// `answered_at` に受け付けた回答の対応期限を返します。
+    pub fn due_at(self, answered_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.0
+            .map(|hours| answered_at + TimeDelta::hours(i64::from(hours.hours())))
+    }
+}
+
 #[cfg_attr(test, derive(Arbitrary))]
 #[derive(
     Serialize, Deserialize, Debug, EnumString, Display, Copy, Clone, Default, PartialOrd, PartialEq,
@@ -275,6 +391,14 @@ pub struct AnswerSettings {
     audience: AnswerAudience,
     #[serde(default)]
     author_publication_policy: AnswerAuthorPublicationPolicy,
+    #[serde(default)]
+    response_deadline: AnswerResponseDeadline,
+    #[serde(default)]
+    quiz: AnswerQuizSettings,
+    #[serde(default)]
+    message_thread_lock_policy: MessageThreadLockPolicy,
+    #[serde(default)]
+    temporary_answer_moderation_policy: TemporaryAnswerModerationPolicy,
 }
 
 #[cfg(test)]
@@ -289,6 +413,10 @@ impl Arbitrary for AnswerSettings {
             any::<AnswerAcceptancePeriod>(),
             any::<AnswerAudience>(),
             any::<AnswerAuthorPublicationPolicy>(),
+            any::<AnswerResponseDeadline>(),
+            any::<AnswerQuizSettings>(),
+            any::<MessageThreadLockPolicy>(),
+            any::<TemporaryAnswerModerationPolicy>(),
         )
             .prop_map(
                 |(
@@ -297,12 +425,20 @@ impl Arbitrary for AnswerSettings {
                     acceptance_period,
                     audience,
                     author_publication_policy,
+                    response_deadline,
+                    quiz,
+                    message_thread_lock_policy,
+                    temporary_answer_moderation_policy,
                 )| Self {
                     default_answer_title,
                     visibility,
                     acceptance_period,
                     audience,
                     author_publication_policy,
+                    response_deadline,
+                    quiz,
+                    message_thread_lock_policy,
+                    temporary_answer_moderation_policy,
                 },
             )
             .boxed()
@@ -326,6 +462,10 @@ impl AnswerSettings {
             )
             .expect("an unrestricted answer audience must be valid"),
             author_publication_policy: AnswerAuthorPublicationPolicy::default(),
+            response_deadline: AnswerResponseDeadline::default(),
+            quiz: AnswerQuizSettings::default(),
+            message_thread_lock_policy: MessageThreadLockPolicy::default(),
+            temporary_answer_moderation_policy: TemporaryAnswerModerationPolicy::default(),
         }
     }
 
@@ -342,6 +482,10 @@ impl AnswerSettings {
             acceptance_period,
             audience: AnswerAudience::try_new(allow_temporary_answers, answer_groups)?,
             author_publication_policy: AnswerAuthorPublicationPolicy::default(),
+            response_deadline: AnswerResponseDeadline::default(),
+            quiz: AnswerQuizSettings::default(),
+            message_thread_lock_policy: MessageThreadLockPolicy::default(),
+            temporary_answer_moderation_policy: TemporaryAnswerModerationPolicy::default(),
         })
     }
 
@@ -396,6 +540,37 @@ impl AnswerSettings {
         }
     }
 
+    pub fn change_response_deadline(self, response_deadline: AnswerResponseDeadline) -> Self {
+        Self {
+            response_deadline,
+            ..self
+        }
+    }
+
+    pub fn change_quiz(self, quiz: AnswerQuizSettings) -> Self {
+        Self { quiz, ..self }
+    }
+
+    pub fn change_message_thread_lock_policy(
+        self,
+        message_thread_lock_policy: MessageThreadLockPolicy,
+    ) -> Self {
+        Self {
+            message_thread_lock_policy,
+            ..self
+        }
+    }
+
+    pub fn change_temporary_answer_moderation_policy(
+        self,
+        temporary_answer_moderation_policy: TemporaryAnswerModerationPolicy,
+    ) -> Self {
+        Self {
+            temporary_answer_moderation_policy,
+            ..self
+        }
+    }
+
     pub fn author_disclosure_for(&self, actor: &Actor) -> AnswerAuthorDisclosure {
         self.author_publication_policy.disclosure_for(actor)
     }
@@ -420,7 +595,7 @@ impl AnswerSettings {
 
     /// `actor` が `entry` を閲覧できるかどうかを、回答の公開範囲をもとに判断します。
     pub fn can_read_entry(&self, entry: &AnswerEntry, actor: &Actor) -> bool {
-        if !entry.publication_allows_read(actor) {
+        if !entry.publication_allows_read(actor) || !entry.moderation_allows_read(actor) {
             return false;
         }
 
@@ -431,8 +606,55 @@ impl AnswerSettings {
                         && self.audience.allows_authenticated_user(actor))
                     || user.role() == &Role::Administrator
             }
+            Actor::TemporaryAnswerAuthor(_) => entry.is_temporary_author(actor),
             Actor::System => true,
+            Actor::Anonymous => false,
+        }
+    }
+
+    /// `actor` に見せてよい `entry` のクイズの点数を返します。
+    ///
+    /// 管理者にはいつでも見せます。回答者本人には、フォームが点数を見せる設定の場合に限り見せます。
+    pub fn quiz_score_visible_to(
+        &self,
+        entry: &AnswerEntry,
+        actor: &Actor,
+    ) -> Option<AnswerQuizScore> {
+        let is_author = match (entry.author(), actor) {
+            (AnswerAuthor::AuthenticatedUser(user_id), Actor::AccountUser(user)) => {
+                user_id == user.id()
+            }
+            (AnswerAuthor::Temporary(author), Actor::TemporaryAnswerAuthor(actor)) => {
+                author.id() == actor.id()
+            }
             _ => false,
+        };
+
+        match actor {
+            Actor::System => *entry.quiz_score(),
+            Actor::AccountUser(user) if user.role() == &Role::Administrator => *entry.quiz_score(),
+            _ if is_author && self.quiz.shows_score_to_author() => *entry.quiz_score(),
+            _ => None,
+        }
+    }
+
+    /// `actor` に見せてよい回答の集計範囲を返します。集計を見せられない場合は `None` です。
+    ///
+    /// 管理者は非公開の回答も含めて集計できます。それ以外の利用者には、回答一覧が
+    /// 公開されているフォームに限り、公開されている回答だけの集計を見せます。
+    pub fn statistics_scope_for(&self, actor: &Actor) -> Option<AnswerStatisticsScope> {
+        match actor {
+            Actor::System => Some(AnswerStatisticsScope::All),
+            Actor::AccountUser(user) if user.role() == &Role::Administrator => {
+                Some(AnswerStatisticsScope::All)
+            }
+            Actor::AccountUser(_)
+                if self.visibility == AnswerVisibility::PUBLIC
+                    && self.audience.allows_authenticated_user(actor) =>
+            {
+                Some(AnswerStatisticsScope::PublicOnly)
+            }
+            _ => None,
         }
     }
 }
@@ -753,6 +975,19 @@ mod tests {
         assert!(settings.can_read_entry(&entry, &Actor::System));
     }
 
+    #[test]
+    fn temporary_entry_is_readable_only_by_its_temporary_author() {
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let other = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let entry = answer_entry(AnswerAuthor::Temporary(author.clone()))
+            .change_publication(crate::form::answer::AnswerPublication::PRIVATE);
+        let settings = answer_settings(false, AnswerAcceptancePeriod::try_new(None, None).unwrap());
+
+        assert!(settings.can_read_entry(&entry, &Actor::from(author)));
+        assert!(!settings.can_read_entry(&entry, &Actor::from(other)));
+        assert!(!settings.can_read_entry(&entry, &Actor::Anonymous));
+    }
+
     #[test]
     fn public_entry_with_group_restriction_is_readable_by_group_member_only() {
         let observer = user_group(10, "Observer");
@@ -774,6 +1009,24 @@ mod tests {
         assert!(settings.can_read_entry(&entry, &Actor::from(active_user(Role::Administrator))));
     }
 
+    #[test]
+    fn statistics_are_shown_to_standard_users_only_when_answers_are_public() {
+        let private_settings = AnswerSettings::default();
+        let public_settings = AnswerSettings::default().change_visibility(AnswerVisibility::PUBLIC);
+        let standard_user = Actor::from(active_user(Role::StandardUser));
+        let administrator = Actor::from(active_user(Role::Administrator));
+
+        assert_eq!(private_settings.statistics_scope_for(&standard_user), None);
+        assert_eq!(
+            private_settings.statistics_scope_for(&administrator),
+            Some(AnswerStatisticsScope::All)
+        );
+        assert_eq!(
+            public_settings.statistics_scope_for(&standard_user),
+            Some(AnswerStatisticsScope::PublicOnly)
+        );
+    }
+
     #[test]
     fn hidden_author_is_anonymous_to_standard_users_but_disclosed_to_administrators() {
         let settings = AnswerSettings::default()
@@ -789,6 +1042,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn response_deadline_hours_must_be_between_one_hour_and_one_year() {
+        assert_eq!(
+            ResponseDeadlineHours::try_from(0),
+            Err(DomainError::InvalidAnswerResponseDeadline)
+        );
+        assert_eq!(ResponseDeadlineHours::try_from(1).unwrap().hours(), 1);
+        assert_eq!(
+            ResponseDeadlineHours::try_from(24 * 365).unwrap().hours(),
+            24 * 365
+        );
+        assert_eq!(
+            ResponseDeadlineHours::try_from(24 * 365 + 1),
+            Err(DomainError::InvalidAnswerResponseDeadline)
+        );
+    }
+
+    #[test]
+    fn quiz_score_is_shown_to_its_author_only_when_the_form_allows_it() {
+        let author = active_user(Role::StandardUser);
+        let entry = answer_entry(AnswerAuthor::AuthenticatedUser(*author.id()))
+            .change_quiz_score(Some(unsafe { AnswerQuizScore::from_raw_parts(3, 5) }));
+        let hidden = AnswerSettings::default().change_quiz(AnswerQuizSettings::new(true, false));
+        let shown = AnswerSettings::default().change_quiz(AnswerQuizSettings::new(true, true));
+        let disabled = AnswerSettings::default().change_quiz(AnswerQuizSettings::new(false, true));
+
+        assert_eq!(
+            hidden.quiz_score_visible_to(&entry, &Actor::from(author.clone())),
+            None
+        );
+        assert_eq!(
+            disabled.quiz_score_visible_to(&entry, &Actor::from(author.clone())),
+            None
+        );
+        assert_eq!(
+            shown.quiz_score_visible_to(&entry, &Actor::from(author)),
+            *entry.quiz_score()
+        );
+        assert_eq!(
+            shown.quiz_score_visible_to(&entry, &Actor::from(active_user(Role::StandardUser))),
+            None
+        );
+        assert_eq!(
+            hidden.quiz_score_visible_to(&entry, &Actor::from(active_user(Role::Administrator))),
+            *entry.quiz_score()
+        );
+    }
+
     #[test]
     fn published_author_is_the_default_policy() {
         assert_eq!(
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use derive_getters::Getters;
+use types::non_empty_string::NonEmptyString;
+
+use crate::form::{
+    answer::{AnswerLabelId, AnswerStatus, content::parse_multiple_choice_answer},
+    question::{ChoiceId, Question, QuestionId},
+};
+
+/// 回答の集計に含める回答の範囲です。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnswerStatisticsScope {
+    /// 非公開の回答も含めたすべての回答
+    All,
+    /// 公開されている回答のみ
+    PublicOnly,
+}
+
+/// 選択式の質問について、同じ回答本文を持つ回答の件数です。
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnswerContentCount {
+    pub question_id: QuestionId,
+    pub answer: String,
+    pub count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChoiceAnswerCount {
+    pub question_id: QuestionId,
+    pub choice_id: Option<ChoiceId>,
+    pub label: NonEmptyString,
+    pub count: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DailyAnswerCount {
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StatusAnswerCount {
+    pub status: AnswerStatus,
+    pub count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelAnswerCount {
+    pub label_id: AnswerLabelId,
+    pub name: NonEmptyString,
+    pub count: u32,
+}
+
+/// フォームに寄せられた回答の集計結果です。
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct AnswerStatistics {
+    total: u32,
+    choices: Vec<ChoiceAnswerCount>,
+    daily: Vec<DailyAnswerCount>,
+    statuses: Vec<StatusAnswerCount>,
+    labels: Vec<LabelAnswerCount>,
+}
+
+impl AnswerStatistics {
+    /// 回答本文ごとの件数を `questions` の選択肢ごとの件数へ振り分けて集計結果を作ります。
+    ///
+    /// 選択肢は回答が 1 件もなくても 0 件として含め、状態も 3 種類すべてを並べます。
+    /// 複数選択の回答は、回答の検証と同じ規則で選択肢ごとに分解して数えます。
+    pub fn new(
+        questions: &[Question],
+        total: u32,
+        contents: Vec<AnswerContentCount>,
+        daily: Vec<DailyAnswerCount>,
+        statuses: Vec<StatusAnswerCount>,
+        labels: Vec<LabelAnswerCount>,
+    ) -> Self {
+        let questions_by_id = questions
+            .iter()
+            .map(|question| (question.id(), question))
+            .collect::<HashMap<_, _>>();
+        let mut counts_by_choice = HashMap::<(QuestionId, &str), u32>::new();
+
+        for content in &contents {
+            let Some(question) = questions_by_id.get(&content.question_id) else {
+                continue;
+            };
+            let values = match question {
+                Question::MultipleChoice(_) => parse_multiple_choice_answer(&content.answer),
+                _ => vec![content.answer.to_owned()],
+            };
+
+            for value in values {
+                if let Some(choice) = question.choices().and_then(|choices| {
+                    choices.iter().find(|choice| choice.label.as_str() == value)
+                }) {
+                    *counts_by_choice
+                        .entry((question.id(), choice.label.as_str()))
+                        .or_default() += content.count;
+                }
+            }
+        }
+
+        let choices = questions
+            .iter()
+            .flat_map(|question| {
+                let counts_by_choice = &counts_by_choice;
+
+                question
+                    .choices()
+                    .into_iter()
+                    .flat_map(|choices| choices.iter())
+                    .map(move |choice| ChoiceAnswerCount {
+                        question_id: question.id(),
+                        choice_id: choice.id,
+                        label: choice.label.to_owned(),
+                        count: counts_by_choice
+                            .get(&(question.id(), choice.label.as_str()))
+                            .copied()
+                            .unwrap_or_default(),
+                    })
+            })
+            .collect();
+
+        let statuses = [
+            AnswerStatus::UNADDRESSED,
+            AnswerStatus::IN_PROGRESS,
+            AnswerStatus::COMPLETED,
+        ]
+        .into_iter()
+        .map(|status| StatusAnswerCount {
+            status,
+            count: statuses
+                .iter()
+                .filter(|count| count.status == status)
+                .map(|count| count.count)
+                .sum(),
+        })
+        .collect();
+
+        Self {
+            total,
+            choices,
+            daily,
+            statuses,
+            labels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::form::question::{Choice, QuestionType};
+
+    fn choice_question(seed: &str, question_type: QuestionType, labels: &[&str]) -> Question {
+        let choices = labels
+            .iter()
+            .enumerate()
+            .map(|(position, label)| {
+                Choice::new(
+                    Some((position as i32 + 1).into()),
+                    position as u16,
+                    label.to_string().try_into().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Question::from_raw_parts(
+                Uuid::parse_str(seed).unwrap().into(),
+                "choice".to_string().try_into().unwrap(),
+                0,
+                "Choice".to_string().try_into().unwrap(),
+                None,
+                question_type,
+                NonEmptyVec::try_new(choices).unwrap().into(),
+                false,
+            )
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn choice_counts_split_multiple_choice_answers_and_keep_unchosen_choices() {
+        let single = choice_question(
+            "00000000-0000-7000-8000-000000000001",
+            QuestionType::SingleChoice,
+            &["Yes", "No"],
+        );
+        let multiple = choice_question(
+            "00000000-0000-7000-8000-000000000002",
+            QuestionType::MultipleChoice,
+            &["Admin, Owner", "User"],
+        );
+        let count = |question: &Question, answer: &str, count: u32| AnswerContentCount {
+            question_id: question.id(),
+            answer: answer.to_owned(),
+            count,
+        };
+
+        let statistics = AnswerStatistics::new(
+            &[single.to_owned(), multiple.to_owned()],
+            5,
+            vec![
+                count(&single, "Yes", 3),
+                count(&multiple, r#"["Admin, Owner","User"]"#, 2),
+                count(&multiple, "User", 1),
+            ],
+            Vec::new(),
+            vec![StatusAnswerCount {
+                status: AnswerStatus::COMPLETED,
+                count: 5,
+            }],
+            Vec::new(),
+        );
+
+        assert_eq!(
+            statistics
+                .choices()
+                .iter()
+                .map(|choice| (choice.label.as_str(), choice.count))
+                .collect::<Vec<_>>(),
+            vec![("Yes", 3), ("No", 0), ("Admin, Owner", 2), ("User", 3)]
+        );
+        assert_eq!(
+            statistics
+                .statuses()
+                .iter()
+                .map(|status| status.count)
+                .collect::<Vec<_>>(),
+            vec![0, 0, 5]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+
+use crate::{
+    account::models::UserSnapshot,
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        models::FormId,
+        question::{Question, QuestionId},
+    },
+    types::authorization_guard::{AuthorizationRole, BelongsTo, GuardedBy, ParentGuarded, Read},
+};
+
+pub type AnswerMoveHistoryId = types::Id<AnswerMoveHistoryEntry>;
+
+/// 回答を別のフォームへ移動するときの、移動元の質問から移動先の質問への対応です。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnswerQuestionMapping(HashMap<QuestionId, QuestionId>);
+
+impl AnswerQuestionMapping {
+    /// `manual` で指定されなかった移動元の質問は、同じ `TemplateKey` を持つ移動先の質問に対応付けます。
+    /// ただし、`manual` で対応先に指定された移動先の質問には自動で対応付けません。
+    ///
+    /// 複数の移動元の質問を同じ移動先の質問に対応付けることはできません。
+    pub fn resolve(
+        source: &[Question],
+        destination: &[Question],
+        manual: HashMap<QuestionId, QuestionId>,
+    ) -> Result<Self, DomainError> {
+        let source_ids = source.iter().map(Question::id).collect::<HashSet<_>>();
+        let destination_ids = destination.iter().map(Question::id).collect::<HashSet<_>>();
+
+        if let Some((from, to)) = manual
+            .iter()
+            .find(|(from, to)| !source_ids.contains(from) || !destination_ids.contains(to))
+        {
+            return Err(DomainError::InvalidEntity {
+                message: format!(
+                    "question mapping {from} -> {to} must map a question of the source form to a question of the destination form"
+                ),
+            });
+        }
+
+        let manual_targets = manual.values().collect::<HashSet<_>>();
+        let mapping = source
+            .iter()
+            .filter_map(|question| {
+                let target = manual.get(&question.id()).copied().or_else(|| {
+                    destination
+                        .iter()
+                        .find(|target| target.template_key() == question.template_key())
+                        .map(Question::id)
+                        .filter(|target| !manual_targets.contains(target))
+                })?;
+                Some((question.id(), target))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut targets = HashSet::new();
+        if let Some(target) = mapping.values().find(|target| !targets.insert(**target)) {
+            return Err(DomainError::InvalidEntity {
+                message: format!("destination question {target} is mapped more than once"),
+            });
+        }
+
+        Ok(Self(mapping))
+    }
+
+    pub fn destination_of(&self, question_id: QuestionId) -> Option<QuestionId> {
+        self.0.get(&question_id).copied()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnswerMoveHistoryPagePosition(AnswerMoveHistoryId);
+
+impl AnswerMoveHistoryPagePosition {
+    pub fn new(id: AnswerMoveHistoryId) -> Self {
+        Self(id)
+    }
+
+    pub fn id(self) -> AnswerMoveHistoryId {
+        self.0
+    }
+}
+
+/// 回答が別のフォームへ移動された記録です。
+#[derive(UnsafeFromRawParts, Clone, Debug, PartialEq, Getters)]
+pub struct AnswerMoveHistoryEntry {
+    id: AnswerMoveHistoryId,
+    #[getter(skip)]
+    answer_id: AnswerId,
+    from_form_id: FormId,
+    to_form_id: FormId,
+    changed_by: UserSnapshot,
+    changed_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for AnswerMoveHistoryEntry {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for AnswerMoveHistoryEntry {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+    }
+}
+
+impl GuardedBy<AnswerEntry, Read> for AnswerMoveHistoryEntry {
+    fn is_allowed_for(&self, _parent: &AnswerEntry, _actor: &Actor) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_question(template_key: &str, position: u16) -> Question {
+        Question::new_text(
+            template_key.to_string().try_into().unwrap(),
+            position,
+            template_key.to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn questions_are_mapped_by_template_key_unless_mapped_manually() {
+        let source = [
+            text_question("title", 0),
+            text_question("body", 1),
+            text_question("detail", 2),
+        ];
+        let destination = [text_question("title", 0), text_question("description", 1)];
+
+        let mapping = AnswerQuestionMapping::resolve(
+            &source,
+            &destination,
+            HashMap::from([(source[1].id(), destination[1].id())]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            mapping.destination_of(source[0].id()),
+            Some(destination[0].id())
+        );
+        assert_eq!(
+            mapping.destination_of(source[1].id()),
+            Some(destination[1].id())
+        );
+        assert_eq!(mapping.destination_of(source[2].id()), None);
+    }
+
+    #[test]
+    fn mapping_rejects_unknown_questions_and_shared_destinations() {
+        let source = [text_question("title", 0), text_question("body", 1)];
+        let destination = [text_question("title", 0)];
+
+        assert!(matches!(
+            AnswerQuestionMapping::resolve(
+                &source,
+                &destination,
+                HashMap::from([(destination[0].id(), destination[0].id())]),
+            ),
+            Err(DomainError::InvalidEntity { .. })
+        ));
+        assert!(matches!(
+            AnswerQuestionMapping::resolve(
+                &source,
+                &destination,
+                HashMap::from([
+                    (source[0].id(), destination[0].id()),
+                    (source[1].id(), destination[0].id()),
+                ]),
+            ),
+            Err(DomainError::InvalidEntity { .. })
+        ));
+    }
+
+    #[test]
+    fn manual_mapping_takes_precedence_over_template_key() {
+        let source = [text_question("title", 0), text_question("body", 1)];
+        let destination = [text_question("title", 0)];
+
+        let mapping = AnswerQuestionMapping::resolve(
+            &source,
+            &destination,
+            HashMap::from([(source[1].id(), destination[0].id())]),
+        )
+        .unwrap();
+
+        assert_eq!(mapping.destination_of(source[0].id()), None);
+        assert_eq!(
+            mapping.destination_of(source[1].id()),
+            Some(destination[0].id())
+        );
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
     form::{
         answer::{AnswerId, RedmineUserSnapshot},
         comment_thread::CommentThread,
+        is_administrator,
     },
     types::authorization_guard::{
         AuthorizationRole, BelongsTo, Create, Delete, DeleteTransition, GuardedBy, ParentGuarded,
@@ -43,6 +44,24 @@ pub enum CommentHistoryAction {
     Delete,
 }
 
+/// コメントを閲覧できる範囲を表す。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CommentVisibility {
+    /// Thread を閲覧できるユーザー (回答者を含む) に公開されるコメント。
+    #[default]
+    Public,
+    /// 運営のみが閲覧できる内部コメント。
+    Internal,
+}
+
+impl CommentVisibility {
+    /// `actor` がこの公開範囲のコメントを閲覧できるかを返します。
+    fn is_visible_to(&self, actor: &Actor) -> bool {
+        matches!(self, Self::Public) || is_administrator(actor) || matches!(actor, Actor::System)
+    }
+}
+
 #[derive(UnsafeFromRawParts, Clone, Debug, PartialEq, Getters)]
 pub struct CommentHistoryEntry {
     id: CommentHistoryId,
@@ -55,6 +74,7 @@ pub struct CommentHistoryEntry {
     content: CommentContent,
     operated_by: UserSnapshot,
     operated_at: DateTime<Utc>,
+    visibility: CommentVisibility,
 }
 
 impl AuthorizationRole for CommentHistoryEntry {
@@ -68,9 +88,11 @@ impl BelongsTo<CommentThread> for CommentHistoryEntry {
 }
 
 impl GuardedBy<CommentThread, Read> for CommentHistoryEntry {
+    /// 削除の履歴と内部コメントの履歴は [`Administrator`](Role::Administrator) のみが閲覧できます。
     fn is_allowed_for(&self, _parent: &CommentThread, actor: &Actor) -> bool {
-        !matches!(self.action, CommentHistoryAction::Delete)
-            || matches!(actor, Actor::AccountUser(user) if user.role() == &Role::Administrator)
+        (!matches!(self.action, CommentHistoryAction::Delete)
+            || matches!(actor, Actor::AccountUser(user) if user.role() == &Role::Administrator))
+            && self.visibility.is_visible_to(actor)
     }
 }
 
@@ -108,16 +130,24 @@ pub struct Comment {
     content: CommentContent,
     timestamp: DateTime<Utc>,
     source: CommentSource,
+    #[serde(default)]
+    visibility: CommentVisibility,
 }
 
 impl Comment {
-    pub(crate) fn new(answer_id: AnswerId, content: CommentContent, commented_by: UserId) -> Self {
+    pub(crate) fn new(
+        answer_id: AnswerId,
+        content: CommentContent,
+        commented_by: UserId,
+        visibility: CommentVisibility,
+    ) -> Self {
         Self {
             answer_id,
             comment_id: CommentId::new(),
             content,
             timestamp: Utc::now(),
             source: CommentSource::Portal { commented_by },
+            visibility,
         }
     }
 
@@ -138,6 +168,7 @@ impl Comment {
                 redmine_journal_id,
                 author,
             },
+            visibility: CommentVisibility::Public,
         }
     }
 
@@ -152,6 +183,7 @@ impl Comment {
         content: CommentContent,
         timestamp: DateTime<Utc>,
         commented_by: UserId,
+        visibility: CommentVisibility,
     ) -> Self {
         Self {
             answer_id,
@@ -159,6 +191,7 @@ impl Comment {
             content,
             timestamp,
             source: CommentSource::Portal { commented_by },
+            visibility,
         }
     }
 
@@ -209,18 +242,20 @@ impl BelongsTo<CommentThread> for Comment {
 }
 
 impl GuardedBy<CommentThread, Read> for Comment {
-    fn is_allowed_for(&self, _parent: &CommentThread, _actor: &Actor) -> bool {
-        true
+    /// 内部コメントは [`Administrator`](Role::Administrator) と [`Actor::System`] のみが閲覧できます。
+    fn is_allowed_for(&self, _parent: &CommentThread, actor: &Actor) -> bool {
+        self.visibility.is_visible_to(actor)
     }
 }
 
 impl GuardedBy<CommentThread, Create> for Comment {
+    /// 内部コメントを投稿できるのは [`Administrator`](Role::Administrator) のみです。
     fn is_allowed_for(&self, _parent: &CommentThread, actor: &Actor) -> bool {
         matches!(
             (&self.source, actor),
             (CommentSource::Portal { commented_by }, Actor::AccountUser(user))
                 if user.id() == commented_by
-        )
+        ) && self.visibility.is_visible_to(actor)
     }
 }
 
@@ -230,7 +265,7 @@ impl GuardedBy<CommentThread, Update> for Comment {
             (&self.source, actor),
             (CommentSource::Portal { commented_by }, Actor::AccountUser(user))
                 if user.id() == commented_by || user.role() == &Role::Administrator
-        )
+        ) && self.visibility.is_visible_to(actor)
     }
 }
 
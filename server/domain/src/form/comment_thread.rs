@@ -11,7 +11,8 @@ use crate::{
             AnswerVisibility,
         },
         comment::{
-            Comment, CommentContent, CommentHistoryEntry, CommentId, CommentSource, DeletedComment,
+            Comment, CommentContent, CommentHistoryEntry, CommentId, CommentSource,
+            CommentVisibility, DeletedComment,
         },
         models::ActiveForm,
     },
@@ -320,6 +321,7 @@ mod tests {
             answer_id,
             CommentContent::new("comment".to_string().try_into().unwrap()),
             *author.id(),
+            CommentVisibility::Public,
         );
         let comment_id = *comment.comment_id();
         let thread = unsafe {
@@ -363,6 +365,7 @@ mod tests {
             answer_id,
             CommentContent::new("before".to_string().try_into().unwrap()),
             *commenter.id(),
+            CommentVisibility::Public,
         );
         let existing_comment_id = *existing_comment.comment_id();
         let thread = unsafe {
@@ -379,7 +382,10 @@ mod tests {
             .unwrap();
         assert!(
             commenter_thread
-                .create_comment(CommentContent::new("new".to_string().try_into().unwrap()))
+                .create_comment(
+                    CommentContent::new("new".to_string().try_into().unwrap()),
+                    CommentVisibility::Public,
+                )
                 .is_ok()
         );
         assert!(
@@ -451,6 +457,7 @@ mod tests {
             AnswerId::new(),
             CommentContent::new("foreign".to_string().try_into().unwrap()),
             UserId::from(Uuid::new_v4()),
+            CommentVisibility::Public,
         );
 
         assert!(matches!(
@@ -532,6 +539,7 @@ mod tests {
                 CommentContent::new("portal a".to_string().try_into().unwrap()),
                 timestamp,
                 UserId::from(Uuid::from_u128(1)),
+                CommentVisibility::Public,
             )
         };
         let portal_b = unsafe {
@@ -541,6 +549,7 @@ mod tests {
                 CommentContent::new("portal b".to_string().try_into().unwrap()),
                 timestamp,
                 UserId::from(Uuid::from_u128(1)),
+                CommentVisibility::Public,
             )
         };
         let imported_10 = Comment::imported_from_redmine(
@@ -635,13 +644,78 @@ mod tests {
         let created = AuthorizationGuard::<_, Update>::from(thread)
             .try_update(standard)
             .unwrap()
-            .create_comment(CommentContent::new(
-                "portal".to_string().try_into().unwrap(),
-            ))
+            .create_comment(
+                CommentContent::new("portal".to_string().try_into().unwrap()),
+                CommentVisibility::Public,
+            )
             .unwrap();
         assert!(created.value().commented_by().is_some());
         assert!(created.value().redmine_journal_id().is_none());
     }
+
+    #[test]
+    fn internal_comments_are_hidden_from_non_staff_and_created_only_by_administrators() {
+        let answer_id = AnswerId::new();
+        let administrator = AccountUser::new(
+            "administrator".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let public_comment = Comment::new(
+            answer_id,
+            CommentContent::new("public".to_string().try_into().unwrap()),
+            *administrator.id(),
+            CommentVisibility::Public,
+        );
+        let internal_comment = Comment::new(
+            answer_id,
+            CommentContent::new("internal".to_string().try_into().unwrap()),
+            *administrator.id(),
+            CommentVisibility::Internal,
+        );
+        let thread = unsafe {
+            CommentThread::from_raw_parts(
+                answer_id,
+                AnswerPublication::PUBLIC,
+                AnswerSettings::default().change_visibility(AnswerVisibility::PUBLIC),
+                vec![public_comment.clone(), internal_comment.clone()],
+            )
+        };
+        let standard = user(Role::StandardUser);
+        let administrator = Actor::from(administrator);
+
+        let readable_by = |actor: Actor| {
+            AuthorizationGuard::<_, Read>::from(thread.clone())
+                .try_read(actor)
+                .unwrap()
+                .readable_comments()
+        };
+        assert_eq!(readable_by(standard.clone()), vec![public_comment.clone()]);
+        assert_eq!(
+            readable_by(administrator.clone()),
+            vec![public_comment.clone(), internal_comment.clone()]
+        );
+        assert_eq!(
+            readable_by(Actor::System),
+            vec![public_comment, internal_comment]
+        );
+
+        let content = || CommentContent::new("note".to_string().try_into().unwrap());
+        assert!(matches!(
+            AuthorizationGuard::<_, Update>::from(thread.clone())
+                .try_update(standard)
+                .unwrap()
+                .create_comment(content(), CommentVisibility::Internal),
+            Err(DomainError::Forbidden)
+        ));
+        assert!(
+            AuthorizationGuard::<_, Update>::from(thread)
+                .try_update(administrator)
+                .unwrap()
+                .create_comment(content(), CommentVisibility::Internal)
+                .is_ok()
+        );
+    }
 }
 
 impl AuthorizationRole for CommentThread {
@@ -702,10 +776,21 @@ impl Allowed<ActiveForm, Read> {
 }
 
 impl Allowed<CommentThread, Read> {
-    pub fn can_read_deleted_comment_history(&self) -> bool {
+    /// 削除や内部コメントなど、運営のみが閲覧できる履歴を含めてよいかを返します。
+    pub fn can_read_staff_only_comment_history(&self) -> bool {
         matches!(self.actor(), Actor::AccountUser(user) if user.role() == &Administrator)
     }
 
+    /// actor が閲覧できるコメントだけを返します。内部コメントは運営以外には含まれません。
+    pub fn readable_comments(&self) -> Vec<Comment> {
+        self.comments()
+            .iter()
+            .cloned()
+            .filter_map(|comment| self.authorize_read(comment).ok())
+            .map(Allowed::into_inner)
+            .collect()
+    }
+
     pub fn authorize_comment_history_entry(
         &self,
         history_entry: CommentHistoryEntry,
@@ -729,12 +814,18 @@ impl Allowed<CommentThread, Update> {
     pub fn create_comment(
         &self,
         content: CommentContent,
+        visibility: CommentVisibility,
     ) -> Result<Allowed<Comment, Create>, DomainError> {
         let commented_by = match self.actor() {
             Actor::AccountUser(user) => *user.id(),
             _ => return Err(DomainError::Forbidden),
         };
-        self.authorize_create(Comment::new(*self.answer_id(), content, commented_by))
+        self.authorize_create(Comment::new(
+            *self.answer_id(),
+            content,
+            commented_by,
+            visibility,
+        ))
     }
 
     pub fn authorize_comment_update(
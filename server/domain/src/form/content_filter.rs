@@ -0,0 +1,486 @@
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use regex::{Regex, RegexBuilder};
+use types::non_empty_string::NonEmptyString;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId, AnswerLabelId, AnswerModerationStatus},
+        comment::CommentId,
+        is_administrator,
+        message::MessageId,
+        models::FormId,
+    },
+    types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
+};
+
+/// 正規表現をコンパイルしたときの大きさの上限です。
+///
+/// 管理者が登録した過大な正規表現で、投稿の受付が遅くならないようにします。
+const PATTERN_SIZE_LIMIT: usize = 1 << 20;
+
+/// 管理者が登録した、投稿の内容と照合する正規表現です。
+#[derive(Clone, Debug)]
+pub struct ContentFilterPattern(Regex);
+
+impl ContentFilterPattern {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl TryFrom<String> for ContentFilterPattern {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        RegexBuilder::new(&value)
+            .size_limit(PATTERN_SIZE_LIMIT)
+            .build()
+            .map(Self)
+            .map_err(|_| DomainError::InvalidContentFilterRule)
+    }
+}
+
+impl PartialEq for ContentFilterPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ContentFilterPattern {}
+
+/// 投稿の内容が規則に当てはまる条件です。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContentFilterCondition {
+    /// 大文字と小文字を区別せずに、語句を含む。
+    BlockedWord(NonEmptyString),
+    /// 正規表現に一致する部分を含む。
+    Pattern(ContentFilterPattern),
+    /// `http://` または `https://` で始まるリンクを、指定した数より多く含む。
+    MaxLinks(u32),
+    /// 空白以外の同じ文字が、指定した数以上続く。
+    RepeatedCharacters(u32),
+}
+
+impl ContentFilterCondition {
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::RepeatedCharacters(run_length) => *run_length >= 2,
+            Self::BlockedWord(_) | Self::Pattern(_) | Self::MaxLinks(_) => true,
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::BlockedWord(word) => text.to_lowercase().contains(&word.as_str().to_lowercase()),
+            Self::Pattern(pattern) => pattern.0.is_match(text),
+            Self::MaxLinks(max_links) => link_regex().find_iter(text).count() > *max_links as usize,
+            Self::RepeatedCharacters(run_length) => {
+                has_repeated_characters(text, *run_length as usize)
+            }
+        }
+    }
+}
+
+fn link_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"(?i)https?://").unwrap())
+}
+
+fn has_repeated_characters(text: &str, run_length: usize) -> bool {
+    let mut previous = None;
+    let mut run = 0;
+
+    for character in text.chars() {
+        if character.is_whitespace() {
+            previous = None;
+            run = 0;
+            continue;
+        }
+        if previous == Some(character) {
+            run += 1;
+        } else {
+            previous = Some(character);
+            run = 1;
+        }
+        if run >= run_length {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// 規則に当てはまった投稿に対する処置です。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentFilterAction {
+    /// 投稿を受け付けない。
+    Reject,
+    /// 投稿は受け付け、管理者の確認待ちとして記録する。回答はモデレーターが確認するまで公開しない。
+    FlagForReview,
+    /// 投稿は受け付け、投稿先の回答にラベルを付ける。
+    AddLabel(AnswerLabelId),
+}
+
+pub type ContentFilterRuleId = types::Id<ContentFilterRule>;
+
+/// フォームへの回答、コメント、メッセージの内容を確かめる、管理者が登録した規則です。
+///
+/// 規則は投稿を受け付けるたびに読み込むため、変更はすぐに反映されます。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct ContentFilterRule {
+    id: ContentFilterRuleId,
+    form_id: FormId,
+    condition: ContentFilterCondition,
+    action: ContentFilterAction,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl ContentFilterRule {
+    pub fn try_new(
+        form_id: FormId,
+        condition: ContentFilterCondition,
+        action: ContentFilterAction,
+        created_by: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if !condition.is_valid() {
+            return Err(DomainError::InvalidContentFilterRule);
+        }
+
+        Ok(Self {
+            id: ContentFilterRuleId::new(),
+            form_id,
+            condition,
+            action,
+            created_by,
+            created_at,
+        })
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        self.condition.matches(text)
+    }
+}
+
+impl AuthorizationRole for ContentFilterRule {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for ContentFilterRule {
+    fn can_create(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// 投稿を受け付ける前の判定のため、システムも読める。
+    fn can_read(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::System) || is_administrator(actor)
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+/// コンテンツフィルターが確かめた投稿です。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentFilterTarget {
+    Answer,
+    Comment(CommentId),
+    Message(MessageId),
+}
+
+/// 投稿の内容に当てはまった規則の一覧です。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContentFilterVerdict {
+    matched: Vec<(ContentFilterRuleId, ContentFilterAction)>,
+}
+
+impl ContentFilterVerdict {
+    /// `texts` を改行でつないだ内容を、`rules` で確かめます。
+    ///
+    /// 複数の欄に分けて書かれたリンクも数えられるよう、欄ごとではなくまとめて確かめます。
+    pub fn evaluate<'a>(
+        rules: &[ContentFilterRule],
+        texts: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let text = texts.into_iter().collect::<Vec<_>>().join("\n");
+
+        Self {
+            matched: rules
+                .iter()
+                .filter(|rule| rule.matches(&text))
+                .map(|rule| (*rule.id(), *rule.action()))
+                .collect(),
+        }
+    }
+
+    pub fn is_rejected(&self) -> bool {
+        self.matched
+            .iter()
+            .any(|(_, action)| *action == ContentFilterAction::Reject)
+    }
+
+    pub fn is_flagged(&self) -> bool {
+        self.matched
+            .iter()
+            .any(|(_, action)| *action == ContentFilterAction::FlagForReview)
+    }
+
+    /// 受け付けない規則に当てはまった場合はエラーを返します。
+    pub fn ensure_accepted(&self) -> Result<(), DomainError> {
+        if self.is_rejected() {
+            return Err(DomainError::ContentRejectedByFilter);
+        }
+
+        Ok(())
+    }
+
+    /// 受け付けた投稿に対して行う処置を返します。行う処置がない場合は `None` を返します。
+    pub fn enforcement(
+        &self,
+        form_id: FormId,
+        answer_id: AnswerId,
+        target: ContentFilterTarget,
+        flagged_at: DateTime<Utc>,
+    ) -> Option<ContentFilterEnforcement> {
+        let flags = self
+            .matched
+            .iter()
+            .filter(|(_, action)| *action == ContentFilterAction::FlagForReview)
+            .map(|(rule_id, _)| ContentFilterFlag {
+                id: ContentFilterFlagId::new(),
+                form_id,
+                answer_id,
+                target,
+                rule_id: Some(*rule_id),
+                flagged_at,
+            })
+            .collect::<Vec<_>>();
+        let mut label_ids = self
+            .matched
+            .iter()
+            .filter_map(|(_, action)| match action {
+                ContentFilterAction::AddLabel(label_id) => Some(*label_id),
+                ContentFilterAction::Reject | ContentFilterAction::FlagForReview => None,
+            })
+            .collect::<Vec<_>>();
+        label_ids.sort_by_key(|label_id| label_id.into_inner());
+        label_ids.dedup();
+
+        (!flags.is_empty() || !label_ids.is_empty()).then_some(ContentFilterEnforcement {
+            answer_id,
+            flags,
+            label_ids,
+        })
+    }
+}
+
+impl AnswerEntry {
+    /// コンテンツフィルターの判定に従い、確認を求められた回答をモデレーターの確認待ちにします。
+    pub(crate) fn screen(self, verdict: &ContentFilterVerdict) -> Result<Self, DomainError> {
+        verdict.ensure_accepted()?;
+
+        Ok(if verdict.is_flagged() {
+            self.change_moderation_status(AnswerModerationStatus::PENDING)
+        } else {
+            self
+        })
+    }
+}
+
+pub type ContentFilterFlagId = types::Id<ContentFilterFlag>;
+
+/// 確認を求める規則に当てはまった投稿の記録です。管理者が確認したら削除します。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct ContentFilterFlag {
+    id: ContentFilterFlagId,
+    form_id: FormId,
+    answer_id: AnswerId,
+    target: ContentFilterTarget,
+    /// 規則が削除された場合は `None` になる。
+    rule_id: Option<ContentFilterRuleId>,
+    flagged_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for ContentFilterFlag {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for ContentFilterFlag {
+    fn can_create(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::System)
+    }
+
+    fn can_read(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+/// 受け付けた投稿に対して、当てはまった規則の処置を行うことを表します。
+///
+/// ラベルは投稿者の権限に関係なく付けるため、システムだけが作成できます。
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct ContentFilterEnforcement {
+    answer_id: AnswerId,
+    flags: Vec<ContentFilterFlag>,
+    label_ids: Vec<AnswerLabelId>,
+}
+
+impl AuthorizationRole for ContentFilterEnforcement {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for ContentFilterEnforcement {
+    fn can_create(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::System)
+    }
+
+    fn can_read(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, _actor: &Actor) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::answer::{AnswerAuthor, AnswerTitle, PostedAnswerContents};
+    use uuid::Uuid;
+
+    fn rule(condition: ContentFilterCondition, action: ContentFilterAction) -> ContentFilterRule {
+        ContentFilterRule::try_new(
+            FormId::new(),
+            condition,
+            action,
+            UserId::from(Uuid::new_v4()),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    fn word(value: &str) -> ContentFilterCondition {
+        ContentFilterCondition::BlockedWord(value.parse().unwrap())
+    }
+
+    #[test]
+    fn conditions_match_content() {
+        let pattern = ContentFilterCondition::Pattern(
+            ContentFilterPattern::try_from(r"\d{3}-\d{4}".to_string()).unwrap(),
+        );
+
+        assert!(word("Spam").matches("this is SPAM"));
+        assert!(!word("spam").matches("this is fine"));
+        assert!(pattern.matches("call 123-4567"));
+        assert!(!pattern.matches("call me"));
+        assert!(ContentFilterCondition::MaxLinks(1).matches("http://a.example HTTPS://b.example"));
+        assert!(!ContentFilterCondition::MaxLinks(2).matches("http://a.example https://b.example"));
+        assert!(ContentFilterCondition::RepeatedCharacters(4).matches("やったあああああ"));
+        assert!(!ContentFilterCondition::RepeatedCharacters(4).matches("aaa aaa"));
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        assert_eq!(
+            ContentFilterPattern::try_from("(unclosed".to_string()).unwrap_err(),
+            DomainError::InvalidContentFilterRule
+        );
+        assert_eq!(
+            ContentFilterRule::try_new(
+                FormId::new(),
+                ContentFilterCondition::RepeatedCharacters(1),
+                ContentFilterAction::Reject,
+                UserId::from(Uuid::new_v4()),
+                Utc::now(),
+            )
+            .unwrap_err(),
+            DomainError::InvalidContentFilterRule
+        );
+    }
+
+    #[test]
+    fn flagged_answer_is_held_for_review() {
+        let rules = vec![rule(word("casino"), ContentFilterAction::FlagForReview)];
+        let entry = || {
+            AnswerEntry::new(
+                FormId::new(),
+                AnswerAuthor::AuthenticatedUser(UserId::from(Uuid::new_v4())),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+            )
+        };
+
+        let held = entry()
+            .screen(&ContentFilterVerdict::evaluate(&rules, ["casino"]))
+            .unwrap();
+        let published = entry()
+            .screen(&ContentFilterVerdict::evaluate(&rules, ["hello"]))
+            .unwrap();
+
+        assert_eq!(held.moderation_status(), &AnswerModerationStatus::PENDING);
+        assert_eq!(
+            published.moderation_status(),
+            &AnswerModerationStatus::APPROVED
+        );
+    }
+
+    #[test]
+    fn verdict_collects_actions_of_matched_rules() {
+        let label_id = AnswerLabelId::new();
+        let flag_rule = rule(word("casino"), ContentFilterAction::FlagForReview);
+        let rules = vec![
+            flag_rule.clone(),
+            rule(word("casino"), ContentFilterAction::AddLabel(label_id)),
+            rule(word("bonus"), ContentFilterAction::AddLabel(label_id)),
+            rule(word("scam"), ContentFilterAction::Reject),
+        ];
+        let form_id = FormId::new();
+        let answer_id = AnswerId::new();
+
+        let verdict = ContentFilterVerdict::evaluate(&rules, ["online", "casino bonus"]);
+        let enforcement = verdict
+            .enforcement(form_id, answer_id, ContentFilterTarget::Answer, Utc::now())
+            .unwrap();
+
+        assert!(verdict.ensure_accepted().is_ok());
+        assert!(verdict.is_flagged());
+        assert_eq!(enforcement.label_ids(), &vec![label_id]);
+        assert_eq!(enforcement.flags().len(), 1);
+        assert_eq!(enforcement.flags()[0].rule_id(), &Some(*flag_rule.id()));
+        assert_eq!(
+            ContentFilterVerdict::evaluate(&rules, ["a scam"]).ensure_accepted(),
+            Err(DomainError::ContentRejectedByFilter)
+        );
+        assert!(
+            ContentFilterVerdict::evaluate(&rules, ["hello"])
+                .enforcement(form_id, answer_id, ContentFilterTarget::Answer, Utc::now())
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use uuid::Uuid;
+
+use crate::{
+    account::models::{AccountUser, UserSnapshot},
+    auth::Actor,
+    form::{
+        answer::{AnswerId, AnswerLabelId, AnswerStatus},
+        is_administrator,
+        models::{ActiveForm, FormId},
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Read, Update,
+    },
+};
+
+pub type AnswerDrawId = types::Id<AnswerDraw>;
+
+/// 抽選に使う乱数のシードです。
+///
+/// 同じシードと同じ候補からは、常に同じ当選者が選ばれます。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnswerDrawSeed(u64);
+
+impl AnswerDrawSeed {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// 指定がない場合に使う、予測できないシードを作ります。
+    pub fn random() -> Self {
+        Self(Uuid::new_v4().as_u64_pair().0)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for AnswerDrawSeed {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .parse()
+            .map(Self)
+            .map_err(|_| DomainError::InvalidEntity {
+                message: format!("draw seed must be an unsigned 64-bit integer: {value}"),
+            })
+    }
+}
+
+impl fmt::Display for AnswerDrawSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 抽選で選ぶ当選者の人数です。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnswerDrawWinnerCount(u32);
+
+impl AnswerDrawWinnerCount {
+    pub const MAX: u32 = 100;
+
+    pub fn try_new(value: u32) -> Result<Self, DomainError> {
+        if !(1..=Self::MAX).contains(&value) {
+            return Err(DomainError::InvalidEntity {
+                message: format!("winner count must be between 1 and {}", Self::MAX),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+/// 抽選の対象にする回答の条件です。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Getters)]
+pub struct AnswerDrawCriteria {
+    status: Option<AnswerStatus>,
+    label_id: Option<AnswerLabelId>,
+    exclude_restricted_submitters: bool,
+}
+
+impl AnswerDrawCriteria {
+    pub fn new(
+        status: Option<AnswerStatus>,
+        label_id: Option<AnswerLabelId>,
+        exclude_restricted_submitters: bool,
+    ) -> Self {
+        Self {
+            status,
+            label_id,
+            exclude_restricted_submitters,
+        }
+    }
+}
+
+/// フォームの回答の中から当選者を選んだ抽選の記録です。
+///
+/// 当選者は次の手順で選ばれるため、記録されたシードと候補から誰でも同じ結果を再現できます。
+///
+/// 1. 候補の回答 ID を昇順に並べる
+/// 2. シードで初期化した SplitMix64 を乱数源として、Fisher–Yates シャッフルを先頭から
+///    当選者数の分だけ行う。`n` 未満の乱数は、`2^64 mod n` 未満の出力を捨てて偏りなく得る
+/// 3. シャッフル後の先頭から順に、当選順位 1 位からの当選者とする
+#[derive(UnsafeFromRawParts, Clone, Debug, PartialEq, Getters)]
+pub struct AnswerDraw {
+    id: AnswerDrawId,
+    form_id: FormId,
+    seed: AnswerDrawSeed,
+    winner_count: AnswerDrawWinnerCount,
+    criteria: AnswerDrawCriteria,
+    candidate_ids: Vec<AnswerId>,
+    winner_ids: Vec<AnswerId>,
+    drawn_by: UserSnapshot,
+    drawn_at: DateTime<Utc>,
+}
+
+impl AnswerDraw {
+    /// `candidates` の中から当選者を選びます。
+    ///
+    /// 候補が当選者数より少ない場合は、候補の全員が当選します。
+    fn conduct(
+        form_id: FormId,
+        seed: AnswerDrawSeed,
+        winner_count: AnswerDrawWinnerCount,
+        criteria: AnswerDrawCriteria,
+        candidates: Vec<AnswerId>,
+        drawn_by: &AccountUser,
+    ) -> Result<Self, DomainError> {
+        let mut candidate_ids = candidates;
+        candidate_ids.sort();
+        candidate_ids.dedup();
+        if candidate_ids.is_empty() {
+            return Err(DomainError::InvalidEntity {
+                message: "no answers match the draw criteria".to_string(),
+            });
+        }
+
+        let winner_ids = select_winners(seed, &candidate_ids, winner_count);
+
+        Ok(Self {
+            id: AnswerDrawId::new(),
+            form_id,
+            seed,
+            winner_count,
+            criteria,
+            candidate_ids,
+            winner_ids,
+            drawn_by: UserSnapshot::from(drawn_by),
+            drawn_at: Utc::now(),
+        })
+    }
+
+    /// 記録されたシードと候補から抽選をやり直し、記録された当選者と一致するかを返します。
+    pub fn verify(&self) -> bool {
+        let mut candidate_ids = self.candidate_ids.clone();
+        candidate_ids.sort();
+        candidate_ids.dedup();
+
+        candidate_ids == self.candidate_ids
+            && select_winners(self.seed, &candidate_ids, self.winner_count) == self.winner_ids
+    }
+}
+
+fn select_winners(
+    seed: AnswerDrawSeed,
+    sorted_candidates: &[AnswerId],
+    winner_count: AnswerDrawWinnerCount,
+) -> Vec<AnswerId> {
+    let mut rng = SplitMix64::new(seed.value());
+    let mut shuffled = sorted_candidates.to_vec();
+    let winners = shuffled.len().min(winner_count.value() as usize);
+
+    for index in 0..winners {
+        let remaining = (shuffled.len() - index) as u64;
+        let picked = index + rng.next_below(remaining) as usize;
+        shuffled.swap(index, picked);
+    }
+
+    shuffled.truncate(winners);
+    shuffled
+}
+
+/// 抽選の再現に使う擬似乱数生成器です。
+///
+/// 他の実装でも再現しやすいよう、外部クレートに依存せず定義どおりに実装しています。
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `0..bound` の範囲の値を偏りなく返します。
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let value = self.next_u64();
+            if value >= threshold {
+                return value % bound;
+            }
+        }
+    }
+}
+
+impl AuthorizationRole for AnswerDraw {
+    type Role = ParentGuarded<ActiveForm>;
+}
+
+impl BelongsTo<ActiveForm> for AnswerDraw {
+    fn belongs_to(&self, parent: &ActiveForm) -> bool {
+        &self.form_id == parent.id()
+    }
+}
+
+impl GuardedBy<ActiveForm, Read> for AnswerDraw {
+    fn is_allowed_for(&self, _parent: &ActiveForm, _actor: &Actor) -> bool {
+        true
+    }
+}
+
+impl GuardedBy<ActiveForm, Create> for AnswerDraw {
+    fn is_allowed_for(&self, _parent: &ActiveForm, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+impl Allowed<ActiveForm, Read> {
+    /// フォームに所属する抽選の記録を、閲覧認可済みで返します。
+    pub fn read_draw(&self, draw: AnswerDraw) -> Result<Allowed<AnswerDraw, Read>, DomainError> {
+        self.authorize_read(draw)
+    }
+}
+
+impl Allowed<ActiveForm, Update> {
+    /// このフォームの回答 `candidates` の中から当選者を選び、作成認可済みの抽選の記録を返します。
+    pub fn conduct_draw(
+        &self,
+        seed: AnswerDrawSeed,
+        winner_count: AnswerDrawWinnerCount,
+        criteria: AnswerDrawCriteria,
+        candidates: Vec<AnswerId>,
+        drawn_by: &AccountUser,
+    ) -> Result<Allowed<AnswerDraw, Create>, DomainError> {
+        let draw = AnswerDraw::conduct(
+            *self.value().id(),
+            seed,
+            winner_count,
+            criteria,
+            candidates,
+            drawn_by,
+        )?;
+        self.authorize_create(draw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::models::Role;
+
+    use super::*;
+
+    fn answer_ids(count: u128) -> Vec<AnswerId> {
+        (0..count)
+            .map(|index| AnswerId::from(Uuid::from_u128(index)))
+            .collect()
+    }
+
+    fn drawer() -> AccountUser {
+        AccountUser::new(
+            "admin".to_string(),
+            Uuid::new_v4().into(),
+            Role::Administrator,
+        )
+    }
+
+    #[test]
+    fn split_mix_64_matches_reference_output() {
+        let mut rng = SplitMix64::new(0);
+
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+    }
+
+    #[test]
+    fn draw_is_reproducible_from_seed_regardless_of_candidate_order() {
+        let ids = answer_ids(10);
+        let mut reversed = ids.clone();
+        reversed.reverse();
+
+        let draw = AnswerDraw::conduct(
+            FormId::new(),
+            AnswerDrawSeed::new(42),
+            AnswerDrawWinnerCount::try_new(3).unwrap(),
+            AnswerDrawCriteria::default(),
+            reversed,
+            &drawer(),
+        )
+        .unwrap();
+
+        assert_eq!(draw.candidate_ids(), &ids);
+        assert_eq!(draw.winner_ids(), &vec![ids[3], ids[2], ids[4]]);
+        assert!(draw.verify());
+    }
+
+    #[test]
+    fn every_candidate_wins_when_winner_count_exceeds_candidates() {
+        let ids = answer_ids(2);
+
+        let draw = AnswerDraw::conduct(
+            FormId::new(),
+            AnswerDrawSeed::random(),
+            AnswerDrawWinnerCount::try_new(5).unwrap(),
+            AnswerDrawCriteria::default(),
+            ids.clone(),
+            &drawer(),
+        )
+        .unwrap();
+
+        let mut winners = draw.winner_ids().clone();
+        winners.sort();
+        assert_eq!(winners, ids);
+    }
+
+    #[test]
+    fn tampered_draw_fails_verification() {
+        let ids = answer_ids(5);
+        let draw = AnswerDraw::conduct(
+            FormId::new(),
+            AnswerDrawSeed::new(7),
+            AnswerDrawWinnerCount::try_new(1).unwrap(),
+            AnswerDrawCriteria::default(),
+            ids.clone(),
+            &drawer(),
+        )
+        .unwrap();
+        let loser = *ids
+            .iter()
+            .find(|id| !draw.winner_ids().contains(id))
+            .unwrap();
+
+        let tampered = AnswerDraw {
+            winner_ids: vec![loser],
+            ..draw
+        };
+
+        assert!(!tampered.verify());
+    }
+
+    #[test]
+    fn draw_without_candidates_is_rejected() {
+        assert!(matches!(
+            AnswerDraw::conduct(
+                FormId::new(),
+                AnswerDrawSeed::new(1),
+                AnswerDrawWinnerCount::try_new(1).unwrap(),
+                AnswerDrawCriteria::default(),
+                Vec::new(),
+                &drawer(),
+            ),
+            Err(DomainError::InvalidEntity { .. })
+        ));
+        assert!(AnswerDrawWinnerCount::try_new(0).is_err());
+        assert!(AnswerDrawWinnerCount::try_new(AnswerDrawWinnerCount::MAX + 1).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+
+use crate::{
+    account::models::{AccountUser, UserId},
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        comment::{Comment, CommentId},
+        is_administrator,
+        message::{Message, MessageId},
+        models::ActiveForm,
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationGuard, AuthorizationGuardDefinitions, AuthorizationRole, Create,
+        Read, SelfGuarded,
+    },
+};
+
+pub type MentionId = types::Id<Mention>;
+
+/// Minecraft ID の最大長。これより長い `@` 以降の文字列はメンションとみなしません。
+const MAX_USERNAME_LENGTH: usize = 16;
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// 本文中の `@username` から、メンションされたユーザー名を出現順に重複なく取り出します。
+///
+/// メールアドレスのように直前が英数字の `@` はメンションとみなしません。
+/// Minecraft ID と同様に、大文字と小文字は区別しません。
+pub fn mentioned_usernames(text: &str) -> Vec<String> {
+    let mut usernames: Vec<String> = Vec::new();
+    let mut previous = None;
+
+    for (index, c) in text.char_indices() {
+        if c == '@' && !previous.is_some_and(is_username_char) {
+            let username = text[index + 1..]
+                .chars()
+                .take_while(|c| is_username_char(*c))
+                .collect::<String>();
+
+            if (1..=MAX_USERNAME_LENGTH).contains(&username.len())
+                && !usernames
+                    .iter()
+                    .any(|known| known.eq_ignore_ascii_case(&username))
+            {
+                usernames.push(username);
+            }
+        }
+        previous = Some(c);
+    }
+
+    usernames
+}
+
+/// メンションが書かれた投稿を表す。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MentionSource {
+    Comment(CommentId),
+    Message(MessageId),
+}
+
+/// 回答のコメントやメッセージで、ユーザーがメンションされたことを表す。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct Mention {
+    id: MentionId,
+    answer_id: AnswerId,
+    source: MentionSource,
+    mentioned_user_id: UserId,
+    mentioned_by: UserId,
+    mentioned_at: DateTime<Utc>,
+}
+
+impl Mention {
+    fn new(
+        answer_id: AnswerId,
+        source: MentionSource,
+        mentioned_user_id: UserId,
+        mentioned_by: UserId,
+    ) -> Self {
+        Self {
+            id: MentionId::new(),
+            answer_id,
+            source,
+            mentioned_user_id,
+            mentioned_by,
+            mentioned_at: Utc::now(),
+        }
+    }
+}
+
+impl AuthorizationRole for Mention {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for Mention {
+    /// [`Mention`] は投稿者本人としてのみ作成できます。
+    fn can_create(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::AccountUser(user) if user.id() == &self.mentioned_by)
+    }
+
+    /// [`Mention`] はメンションされたユーザーと [`Administrator`](crate::account::models::Role::Administrator) が閲覧できます。
+    fn can_read(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::AccountUser(user) if user.id() == &self.mentioned_user_id)
+            || is_administrator(actor)
+            || matches!(actor, Actor::System)
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, _actor: &Actor) -> bool {
+        false
+    }
+}
+
+impl Allowed<ActiveForm, Read> {
+    /// コメントでメンションされたユーザーへの [`Mention`] を作成します。
+    ///
+    /// 投稿者自身と、そのコメントを閲覧できないユーザー (内部コメントを見られない
+    /// 一般ユーザーなど) へのメンションは、存在を知らせないよう黙って取り除きます。
+    pub fn comment_mentions(
+        &self,
+        answer: &AnswerEntry,
+        comment: &Comment,
+        mentioned_users: Vec<AccountUser>,
+    ) -> Result<Vec<Allowed<Mention, Create>>, DomainError> {
+        self.readable_mentions(
+            *answer.id(),
+            MentionSource::Comment(*comment.comment_id()),
+            mentioned_users,
+            |form| {
+                form.comment_thread_with_comments(answer.clone(), vec![comment.clone()])
+                    .is_ok_and(|thread| !thread.readable_comments().is_empty())
+            },
+        )
+    }
+
+    /// メッセージでメンションされたユーザーへの [`Mention`] を作成します。
+    ///
+    /// 投稿者自身と、その回答のメッセージを閲覧できないユーザーへのメンションは黙って取り除きます。
+    pub fn message_mentions(
+        &self,
+        answer: &AnswerEntry,
+        message: &Message,
+        mentioned_users: Vec<AccountUser>,
+    ) -> Result<Vec<Allowed<Mention, Create>>, DomainError> {
+        self.readable_mentions(
+            *answer.id(),
+            MentionSource::Message(*message.id()),
+            mentioned_users,
+            |form| {
+                form.authorize_read(answer.clone())
+                    .and_then(|answer| answer.message_thread(Vec::new()))
+                    .is_ok()
+            },
+        )
+    }
+
+    fn readable_mentions(
+        &self,
+        answer_id: AnswerId,
+        source: MentionSource,
+        mentioned_users: Vec<AccountUser>,
+        can_read_source: impl Fn(&Allowed<ActiveForm, Read>) -> bool,
+    ) -> Result<Vec<Allowed<Mention, Create>>, DomainError> {
+        let mentioned_by = match self.actor() {
+            Actor::AccountUser(user) => *user.id(),
+            _ => return Err(DomainError::Forbidden),
+        };
+
+        mentioned_users
+            .into_iter()
+            .filter(|user| user.id() != &mentioned_by)
+            .filter(|user| {
+                AuthorizationGuard::<_, Read>::from(self.value().clone())
+                    .try_read(Actor::from(user.clone()))
+                    .is_ok_and(|form| can_read_source(&form))
+            })
+            .map(|user| {
+                AuthorizationGuard::<_, Create>::from(Mention::new(
+                    answer_id,
+                    source,
+                    *user.id(),
+                    mentioned_by,
+                ))
+                .try_create(self.actor().clone())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        account::models::Role,
+        form::{
+            answer::{AnswerAuthor, AnswerPublication, AnswerSettings, AnswerTitle},
+            comment::{CommentContent, CommentVisibility},
+            message::MessageBody,
+            models::{FormDescription, FormTitle, QuestionSet},
+            question::Question,
+            settings::{FormSettings, Visibility},
+        },
+    };
+
+    #[test]
+    fn mentioned_usernames_are_unique_and_ignore_email_addresses() {
+        assert_eq!(
+            mentioned_usernames("@Steve と @alex_01、@steve さん。mail@example.com @ @"),
+            vec!["Steve".to_string(), "alex_01".to_string()]
+        );
+        assert!(mentioned_usernames("@abcdefghijklmnopq").is_empty());
+    }
+
+    fn user(name: &str, role: Role) -> AccountUser {
+        AccountUser::new(name.to_string(), UserId::from(Uuid::new_v4()), role)
+    }
+
+    fn form_and_answer(author: &AccountUser) -> (ActiveForm, AnswerEntry) {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap();
+        let form = ActiveForm::new(
+            FormTitle::new("Form".to_string().try_into().unwrap()),
+            FormDescription::new(String::new()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+        .change_settings(FormSettings::new().change_visibility(Visibility::PUBLIC))
+        .change_answer_settings(AnswerSettings::default());
+        let answer = unsafe {
+            AnswerEntry::from_raw_parts(
+                AnswerId::new(),
+                *form.id(),
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                Utc::now(),
+                AnswerTitle::new(None),
+                AnswerPublication::PUBLIC,
+                Vec::new(),
+            )
+        };
+
+        (form, answer)
+    }
+
+    #[test]
+    fn mentions_of_users_who_cannot_read_the_post_are_dropped() {
+        let administrator = user("admin", Role::Administrator);
+        let colleague = user("colleague", Role::Administrator);
+        let author = user("author", Role::StandardUser);
+        let outsider = user("outsider", Role::StandardUser);
+        let (form, answer) = form_and_answer(&author);
+        let form = AuthorizationGuard::<_, Read>::from(form)
+            .try_read(Actor::from(administrator.clone()))
+            .unwrap();
+        let mentioned_user_ids = |mentions: Vec<Allowed<Mention, Create>>| {
+            mentions
+                .into_iter()
+                .map(|mention| *mention.mentioned_user_id())
+                .collect::<Vec<_>>()
+        };
+
+        let internal_comment = unsafe {
+            Comment::from_raw_parts(
+                *answer.id(),
+                CommentId::new(),
+                CommentContent::new("@colleague @author".to_string().try_into().unwrap()),
+                Utc::now(),
+                *administrator.id(),
+                CommentVisibility::Internal,
+            )
+        };
+        let mentions = form
+            .comment_mentions(
+                &answer,
+                &internal_comment,
+                vec![
+                    administrator.clone(),
+                    colleague.clone(),
+                    author.clone(),
+                    outsider.clone(),
+                ],
+            )
+            .unwrap();
+        assert_eq!(mentioned_user_ids(mentions), vec![*colleague.id()]);
+
+        let message = Message::new(
+            *administrator.id(),
+            MessageBody::new("@author @outsider".to_string().try_into().unwrap()),
+        );
+        let mentions = form
+            .message_mentions(&answer, &message, vec![author.clone(), outsider])
+            .unwrap();
+        assert_eq!(mentioned_user_ids(mentions), vec![*author.id()]);
+    }
+}
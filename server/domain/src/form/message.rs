@@ -2,13 +2,18 @@ use chrono::{DateTime, Utc};
 use derive_getters::Getters;
 use deriving_via::DerivingVia;
 use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
 use serde::{Deserialize, Serialize};
 use types::non_empty_string::NonEmptyString;
 
 use crate::{
     account::models::{UserId, UserSnapshot},
     auth::Actor,
-    form::{answer::AnswerId, is_administrator, message_thread::MessageThread},
+    form::{
+        answer::{AnswerId, TemporaryAnswerAuthorId},
+        is_administrator,
+        message_thread::MessageThread,
+    },
     types::authorization_guard::{
         AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Read, Update,
     },
@@ -16,6 +21,22 @@ use crate::{
 
 pub type MessageId = types::Id<Message>;
 pub type MessageHistoryId = types::Id<MessageHistoryEntry>;
+pub type MessageAttachmentId = types::Id<MessageAttachment>;
+
+/// 添付ファイル 1 件あたりの最大サイズ(バイト)
+pub const MAX_MESSAGE_ATTACHMENT_SIZE: u64 = 10 * 1024 * 1024;
+/// 1 つのメッセージに添付できるファイルの最大数
+pub const MAX_MESSAGE_ATTACHMENTS: usize = 5;
+/// 添付を許可する Content-Type
+pub const ALLOWED_MESSAGE_ATTACHMENT_CONTENT_TYPES: [&str; 6] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+];
+const MAX_MESSAGE_ATTACHMENT_FILE_NAME_LENGTH: usize = 255;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct MessageHistoryPagePosition(MessageHistoryId);
@@ -50,6 +71,7 @@ pub struct MessageHistoryEntry {
     body: MessageBody,
     operated_by: UserSnapshot,
     operated_at: DateTime<Utc>,
+    attachments: Vec<MessageAttachment>,
 }
 
 impl AuthorizationRole for MessageHistoryEntry {
@@ -93,24 +115,115 @@ impl AuthorizationRole for Message {
     type Role = ParentGuarded<MessageThread>;
 }
 
+/// メッセージの送信者。
+///
+/// ログインせずに回答した人は、回答のアクセスリンクを通じて一時回答者として送信する。
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum MessageSender {
+    User(UserId),
+    TemporaryAnswerAuthor(TemporaryAnswerAuthorId),
+}
+
+impl MessageSender {
+    pub fn user_id(&self) -> Option<&UserId> {
+        match self {
+            Self::User(user_id) => Some(user_id),
+            Self::TemporaryAnswerAuthor(_) => None,
+        }
+    }
+
+    fn is(&self, actor: &Actor) -> bool {
+        match (self, actor) {
+            (Self::User(user_id), Actor::AccountUser(user)) => user.id() == user_id,
+            (Self::TemporaryAnswerAuthor(author_id), Actor::TemporaryAnswerAuthor(author)) => {
+                author.id() == author_id
+            }
+            _ => false,
+        }
+    }
+}
+
 #[derive(UnsafeFromRawParts, Getters, Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Message {
     id: MessageId,
-    sender_id: UserId,
+    sender: MessageSender,
     body: MessageBody,
     timestamp: DateTime<Utc>,
+    #[serde(default)]
+    attachments: Vec<MessageAttachment>,
+}
+
+/// メッセージに添付されたファイルのメタデータを表す。
+///
+/// ファイルの中身は [`MessageAttachmentStorage`] に保存される。
+///
+/// [`MessageAttachmentStorage`]: crate::repository::form::message_attachment_storage::MessageAttachmentStorage
+#[derive(UnsafeFromRawParts, Getters, Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    id: MessageAttachmentId,
+    file_name: String,
+    content_type: String,
+    size: u64,
+}
+
+impl MessageAttachment {
+    pub fn try_new(
+        file_name: String,
+        content_type: String,
+        size: u64,
+    ) -> Result<Self, DomainError> {
+        let file_name = file_name.trim().to_owned();
+
+        if file_name.is_empty()
+            || file_name.chars().count() > MAX_MESSAGE_ATTACHMENT_FILE_NAME_LENGTH
+            || file_name.contains(['/', '\\'])
+            || file_name.chars().any(char::is_control)
+        {
+            return Err(DomainError::InvalidEntity {
+                message: "Invalid attachment file name.".to_string(),
+            });
+        }
+
+        if size == 0 || size > MAX_MESSAGE_ATTACHMENT_SIZE {
+            return Err(DomainError::InvalidEntity {
+                message: format!(
+                    "Attachment size must be between 1 and {MAX_MESSAGE_ATTACHMENT_SIZE} bytes."
+                ),
+            });
+        }
+
+        let content_type = content_type.trim().to_ascii_lowercase();
+        if !ALLOWED_MESSAGE_ATTACHMENT_CONTENT_TYPES.contains(&content_type.as_str()) {
+            return Err(DomainError::InvalidEntity {
+                message: format!("Attachment content type {content_type} is not allowed."),
+            });
+        }
+
+        Ok(Self {
+            id: MessageAttachmentId::new(),
+            file_name,
+            content_type,
+            size,
+        })
+    }
 }
 
 /// 回答のメッセージスレッドへの、認可済みの投稿要求を表す。
+///
+/// `answer_author_id` は通知先となる回答者で、一時回答の場合はアカウントがないため `None` になる。
 #[derive(Getters, Debug)]
 pub struct MessagePost {
     answer_id: AnswerId,
-    answer_author_id: UserId,
+    answer_author_id: Option<UserId>,
     message: Message,
 }
 
 impl MessagePost {
-    pub(crate) fn new(answer_id: AnswerId, answer_author_id: UserId, message: Message) -> Self {
+    pub(crate) fn new(
+        answer_id: AnswerId,
+        answer_author_id: Option<UserId>,
+        message: Message,
+    ) -> Self {
         Self {
             answer_id,
             answer_author_id,
@@ -130,26 +243,70 @@ impl AuthorizationRole for MessagePost {
 impl BelongsTo<MessageThread> for MessagePost {
     fn belongs_to(&self, parent: &MessageThread) -> bool {
         self.answer_id() == parent.answer_id()
-            && parent.answer_author().authenticated_user_id() == Some(*self.answer_author_id())
+            && parent.answer_author().authenticated_user_id() == *self.answer_author_id()
     }
 }
 
 impl GuardedBy<MessageThread, Create> for MessagePost {
     fn is_allowed_for(&self, _parent: &MessageThread, actor: &Actor) -> bool {
-        matches!(actor, Actor::AccountUser(user) if user.id() == self.message.sender_id())
+        self.message.sender.is(actor)
     }
 }
 
 impl Message {
     pub fn new(sender_id: UserId, body: MessageBody) -> Self {
+        Self::new_from(MessageSender::User(sender_id), body)
+    }
+
+    /// 回答のアクセスリンクを通じて、一時回答者が送るメッセージを作る。
+    pub fn new_from_temporary_answer_author(
+        author_id: TemporaryAnswerAuthorId,
+        body: MessageBody,
+    ) -> Self {
+        Self::new_from(MessageSender::TemporaryAnswerAuthor(author_id), body)
+    }
+
+    fn new_from(sender: MessageSender, body: MessageBody) -> Self {
         Self {
             id: MessageId::new(),
-            sender_id,
+            sender,
             body,
             timestamp: Utc::now(),
+            attachments: Vec::new(),
         }
     }
 
+    /// 送信者のユーザー ID。一時回答者が送ったメッセージでは `None` になる。
+    pub fn sender_id(&self) -> Option<&UserId> {
+        self.sender.user_id()
+    }
+
+    /// 添付ファイルを付与する。添付数が上限を超える場合はエラーを返す。
+    pub fn attach(self, attachments: Vec<MessageAttachment>) -> Result<Self, DomainError> {
+        if attachments.len() > MAX_MESSAGE_ATTACHMENTS {
+            return Err(DomainError::InvalidEntity {
+                message: format!(
+                    "A message can have at most {MAX_MESSAGE_ATTACHMENTS} attachments."
+                ),
+            });
+        }
+
+        Ok(Self {
+            attachments,
+            ..self
+        })
+    }
+
+    pub fn find_attachment(
+        &self,
+        attachment_id: MessageAttachmentId,
+    ) -> Option<&MessageAttachment> {
+        self.attachments
+            .iter()
+            .find(|attachment| *attachment.id() == attachment_id)
+    }
+
+    /// 本文を更新する。添付ファイルは編集後も引き継がれる。
     pub fn update_body(self, body: MessageBody) -> Self {
         Self { body, ..self }
     }
@@ -188,8 +345,9 @@ impl BelongsTo<MessageThread> for Message {
 }
 
 impl GuardedBy<MessageThread, Update> for Message {
+    /// 一時回答者のメッセージは履歴を残せないため、編集できない。
     fn is_allowed_for(&self, _parent: &MessageThread, actor: &Actor) -> bool {
-        matches!(actor, Actor::AccountUser(user) if self.sender_id() == user.id())
+        matches!(actor, Actor::AccountUser(_)) && self.sender.is(actor)
     }
 }
 
@@ -201,7 +359,6 @@ mod tests {
         form::answer::{AnswerAuthor, AnswerId},
         types::authorization_guard::{AuthorizationGuard, Read, Update},
     };
-    use errors::domain::DomainError;
     use uuid::Uuid;
 
     #[test]
@@ -221,6 +378,8 @@ mod tests {
                     user_id,
                     MessageBody::new("owned message".to_string().try_into().unwrap()),
                 )],
+                false,
+                false,
             )
         };
         let foreign_message = Message::new(
@@ -254,6 +413,8 @@ mod tests {
                 answer_id,
                 AnswerAuthor::AuthenticatedUser(*author.id()),
                 Vec::new(),
+                false,
+                false,
             )
         };
         let standard_readable_thread = AuthorizationGuard::<_, Read>::from(thread.clone())
@@ -278,6 +439,7 @@ mod tests {
                 MessageBody::new("state".to_string().try_into().unwrap()),
                 snapshot.clone(),
                 Utc::now(),
+                Vec::new(),
             )
         };
 
@@ -300,4 +462,52 @@ mod tests {
         assert!(admin_update.is_ok());
         assert!(admin_delete.is_ok());
     }
+
+    #[test]
+    fn message_attachment_rejects_invalid_metadata() {
+        let png = |name: &str, size| {
+            MessageAttachment::try_new(name.to_string(), "image/png".to_string(), size)
+        };
+
+        assert!(png("evidence.png", 1024).is_ok());
+        assert!(png("evidence.png", MAX_MESSAGE_ATTACHMENT_SIZE).is_ok());
+        assert!(png("evidence.png", MAX_MESSAGE_ATTACHMENT_SIZE + 1).is_err());
+        assert!(png("evidence.png", 0).is_err());
+        assert!(png("  ", 1024).is_err());
+        assert!(png("../evidence.png", 1024).is_err());
+        assert!(
+            MessageAttachment::try_new("script.html".to_string(), "text/html".to_string(), 1024)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn attachments_are_limited_per_message_and_survive_body_update() {
+        let sender_id: UserId = Uuid::new_v4().into();
+        let attachment = || {
+            MessageAttachment::try_new("log.txt".to_string(), "text/plain".to_string(), 16).unwrap()
+        };
+        let message = Message::new(
+            sender_id,
+            MessageBody::new("original".to_string().try_into().unwrap()),
+        );
+
+        assert!(
+            message
+                .clone()
+                .attach(
+                    (0..=MAX_MESSAGE_ATTACHMENTS)
+                        .map(|_| attachment())
+                        .collect()
+                )
+                .is_err()
+        );
+
+        let attached = message.attach(vec![attachment()]).unwrap();
+        let updated = attached
+            .clone()
+            .update_body(MessageBody::new("edited".to_string().try_into().unwrap()));
+
+        assert_eq!(updated.attachments(), attached.attachments());
+    }
 }
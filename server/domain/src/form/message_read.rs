@@ -0,0 +1,208 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        message::{Message, MessageId},
+        message_thread::MessageThread,
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Read,
+    },
+};
+
+/// メッセージスレッドの参加者が、どのメッセージまで読んだかを表す。
+///
+/// メッセージ ID は UUID v7 で投稿順に並ぶため、`last_read_message_id` 以前に
+/// 投稿されたメッセージはすべて既読とみなす。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct MessageReadReceipt {
+    answer_id: AnswerId,
+    reader_id: UserId,
+    last_read_message_id: MessageId,
+    read_at: DateTime<Utc>,
+}
+
+impl MessageReadReceipt {
+    pub fn has_read(&self, message: &Message) -> bool {
+        message.id() <= &self.last_read_message_id
+    }
+}
+
+impl AuthorizationRole for MessageReadReceipt {
+    type Role = ParentGuarded<MessageThread>;
+}
+
+impl BelongsTo<MessageThread> for MessageReadReceipt {
+    fn belongs_to(&self, parent: &MessageThread) -> bool {
+        &self.answer_id == parent.answer_id()
+    }
+}
+
+impl GuardedBy<MessageThread, Create> for MessageReadReceipt {
+    /// 既読にできるのは読んだ本人だけです。
+    fn is_allowed_for(&self, _parent: &MessageThread, actor: &Actor) -> bool {
+        matches!(actor, Actor::AccountUser(user) if user.id() == &self.reader_id)
+    }
+}
+
+impl GuardedBy<MessageThread, Read> for MessageReadReceipt {
+    /// スレッドを閲覧できれば、相手の既読状況も閲覧できます。
+    fn is_allowed_for(&self, _parent: &MessageThread, _actor: &Actor) -> bool {
+        true
+    }
+}
+
+/// 未読メッセージ数を数える回答と閲覧者の組。
+///
+/// メッセージスレッドを閲覧できる場合にだけ [`Allowed<AnswerEntry, Read>`] から作られる。
+#[derive(Getters, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnreadMessageCountTarget {
+    answer_id: AnswerId,
+    reader_id: UserId,
+}
+
+impl Allowed<AnswerEntry, Read> {
+    /// 閲覧者がこの回答のメッセージスレッドを閲覧できる場合に、未読数を数える対象を返す。
+    pub fn unread_message_count_target(&self) -> Option<UnreadMessageCountTarget> {
+        let Actor::AccountUser(user) = self.actor() else {
+            return None;
+        };
+
+        self.message_thread(Vec::new())
+            .is_ok()
+            .then(|| UnreadMessageCountTarget {
+                answer_id: *self.id(),
+                reader_id: *user.id(),
+            })
+    }
+}
+
+impl Allowed<MessageThread, Read> {
+    /// 閲覧者が `up_to` までのメッセージを読んだことを記録する既読情報を作成する。
+    ///
+    /// `up_to` を省略した場合は、スレッドの最新のメッセージまでを既読にする。
+    pub fn mark_as_read(
+        &self,
+        up_to: Option<MessageId>,
+        read_at: DateTime<Utc>,
+    ) -> Result<Allowed<MessageReadReceipt, Create>, DomainError> {
+        let Actor::AccountUser(user) = self.actor() else {
+            return Err(DomainError::Forbidden);
+        };
+        let messages = self.value().messages();
+        let last_read_message_id = match up_to {
+            Some(message_id) => messages
+                .iter()
+                .map(Message::id)
+                .find(|id| **id == message_id),
+            None => messages.iter().map(Message::id).max(),
+        }
+        .copied()
+        .ok_or(DomainError::NotFound)?;
+
+        self.authorize_create(MessageReadReceipt {
+            answer_id: *self.answer_id(),
+            reader_id: *user.id(),
+            last_read_message_id,
+            read_at,
+        })
+    }
+
+    pub fn authorize_read_receipt(
+        &self,
+        receipt: MessageReadReceipt,
+    ) -> Result<Allowed<MessageReadReceipt, Read>, DomainError> {
+        self.authorize_read(receipt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::models::{AccountUser, Role},
+        form::{answer::AnswerAuthor, message::MessageBody},
+        types::authorization_guard::AuthorizationGuard,
+    };
+    use uuid::Uuid;
+
+    fn message_from(sender_id: UserId, body: &str) -> Message {
+        Message::new(
+            sender_id,
+            MessageBody::new(body.to_string().try_into().unwrap()),
+        )
+    }
+
+    #[test]
+    fn mark_as_read_defaults_to_latest_message_and_rejects_unknown_message() {
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let administrator_id = UserId::from(Uuid::new_v4());
+        let first = message_from(administrator_id, "first");
+        let second = message_from(administrator_id, "second");
+        let thread = unsafe {
+            MessageThread::from_raw_parts(
+                AnswerId::new(),
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                vec![first.clone(), second.clone()],
+                false,
+                false,
+            )
+        };
+        let thread = AuthorizationGuard::<_, Read>::from(thread)
+            .try_read(Actor::from(author.clone()))
+            .unwrap();
+
+        let latest = thread.mark_as_read(None, Utc::now()).unwrap().into_inner();
+        assert_eq!(latest.last_read_message_id(), second.id());
+        assert_eq!(latest.reader_id(), author.id());
+        assert!(latest.has_read(&first) && latest.has_read(&second));
+
+        let partial = thread
+            .mark_as_read(Some(*first.id()), Utc::now())
+            .unwrap()
+            .into_inner();
+        assert!(partial.has_read(&first));
+        assert!(!partial.has_read(&second));
+
+        assert!(matches!(
+            thread.mark_as_read(Some(MessageId::new()), Utc::now()),
+            Err(DomainError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn mark_as_read_fails_for_empty_thread() {
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let thread = unsafe {
+            MessageThread::from_raw_parts(
+                AnswerId::new(),
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                Vec::new(),
+                false,
+                false,
+            )
+        };
+        let thread = AuthorizationGuard::<_, Read>::from(thread)
+            .try_read(Actor::from(author))
+            .unwrap();
+
+        assert!(matches!(
+            thread.mark_as_read(None, Utc::now()),
+            Err(DomainError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use derive_getters::Getters;
+use deriving_via::DerivingVia;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use serde::{Deserialize, Serialize};
+use types::non_empty_string::NonEmptyString;
+
+use crate::{
+    auth::Actor,
+    form::{
+        answer::AnswerEntry, is_administrator, message::MessageBody, models::ActiveForm,
+        service::replace_template_placeholders,
+    },
+    types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
+};
+
+pub type MessageTemplateId = types::Id<MessageTemplate>;
+
+#[derive(Clone, DerivingVia, Debug, PartialEq)]
+#[deriving(From, Into, IntoInner, Serialize(via: NonEmptyString), Deserialize(via: NonEmptyString))]
+pub struct MessageTemplateName(NonEmptyString);
+
+impl MessageTemplateName {
+    pub fn new(name: NonEmptyString) -> Self {
+        Self(name)
+    }
+}
+
+/// メッセージテンプレートの本文。
+///
+/// `$username`・`$form_name`・`$answer_title` と、質問の [`TemplateKey`](crate::form::question::TemplateKey)
+/// を `$key` の形で埋め込むことができます。
+#[derive(Clone, DerivingVia, Debug, PartialEq)]
+#[deriving(From, Into, IntoInner, Serialize(via: NonEmptyString), Deserialize(via: NonEmptyString))]
+pub struct MessageTemplateBody(NonEmptyString);
+
+impl MessageTemplateBody {
+    pub fn new(body: NonEmptyString) -> Self {
+        Self(body)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// 運営がメッセージスレッドで繰り返し使う定型文を表す。
+#[derive(UnsafeFromRawParts, Serialize, Deserialize, Getters, Clone, Debug, PartialEq)]
+pub struct MessageTemplate {
+    id: MessageTemplateId,
+    name: MessageTemplateName,
+    body: MessageTemplateBody,
+}
+
+impl MessageTemplate {
+    pub fn new(name: MessageTemplateName, body: MessageTemplateBody) -> Self {
+        Self {
+            id: MessageTemplateId::new(),
+            name,
+            body,
+        }
+    }
+
+    pub fn edited(
+        &self,
+        name: Option<MessageTemplateName>,
+        body: Option<MessageTemplateBody>,
+    ) -> Self {
+        Self {
+            id: self.id,
+            name: name.unwrap_or_else(|| self.name.clone()),
+            body: body.unwrap_or_else(|| self.body.clone()),
+        }
+    }
+
+    /// 回答の内容でプレースホルダーを置き換え、メッセージ本文を組み立てます。
+    pub fn render(
+        &self,
+        form: &ActiveForm,
+        answer: &AnswerEntry,
+        author_name: &str,
+    ) -> Result<MessageBody, DomainError> {
+        let question_template_key_by_id = form
+            .questions()
+            .iter()
+            .map(|question| (question.id(), question.template_key().as_str()))
+            .collect::<HashMap<_, _>>();
+        let answers_by_template_key = answer
+            .contents()
+            .iter()
+            .filter_map(|content| {
+                question_template_key_by_id
+                    .get(&content.question_id)
+                    .map(|template_key| (*template_key, content.answer.as_str()))
+            })
+            .collect::<HashMap<_, _>>();
+        let answer_title = answer
+            .title()
+            .clone()
+            .into_inner()
+            .map(|title| title.into_inner())
+            .unwrap_or_default();
+
+        let rendered =
+            replace_template_placeholders(self.body.as_str(), |placeholder| match placeholder {
+                "form_name" => Some(form.title().as_str()),
+                "username" => Some(author_name),
+                "answer_title" => Some(answer_title.as_str()),
+                template_key => answers_by_template_key.get(template_key).copied(),
+            });
+
+        NonEmptyString::try_new(rendered)
+            .map(MessageBody::new)
+            .map_err(|_| DomainError::InvalidEntity {
+                message: "rendered message template must not be empty".to_string(),
+            })
+    }
+}
+
+impl AuthorizationRole for MessageTemplate {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for MessageTemplate {
+    /// [`MessageTemplate`] の作成権限は [`Administrator`](crate::account::models::Role::Administrator) のみに与えられます。
+    fn can_create(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// [`MessageTemplate`] は運営向けの定型文のため、読み取り権限も
+    /// [`Administrator`](crate::account::models::Role::Administrator) のみに与えられます。
+    fn can_read(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// [`MessageTemplate`] の更新権限は [`Administrator`](crate::account::models::Role::Administrator) のみに与えられます。
+    fn can_update(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// [`MessageTemplate`] の削除権限は [`Administrator`](crate::account::models::Role::Administrator) のみに与えられます。
+    fn can_delete(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        account::models::{AccountUser, Role, UserId},
+        form::{
+            answer::{
+                AnswerAuthor, AnswerTitle, FormAnswerContent, FormAnswerContentId,
+                PostedAnswerContents,
+            },
+            models::{FormDescription, FormTitle},
+            question::{Question, QuestionSet},
+        },
+        types::authorization_guard::{AuthorizationGuard, Create, Read},
+    };
+
+    fn template(body: &str) -> MessageTemplate {
+        MessageTemplate::new(
+            MessageTemplateName::new("template".to_string().try_into().unwrap()),
+            MessageTemplateBody::new(body.to_string().try_into().unwrap()),
+        )
+    }
+
+    fn form_and_answer(answer_title: Option<&str>) -> (ActiveForm, AnswerEntry) {
+        let question = Question::new_text(
+            "server".to_string().try_into().unwrap(),
+            0,
+            "Server".to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap();
+        let question_id = question.id();
+        let form = ActiveForm::new(
+            FormTitle::new("不具合報告".to_string().try_into().unwrap()),
+            FormDescription::new(String::new()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        );
+        let contents = vec![FormAnswerContent {
+            id: FormAnswerContentId::new(),
+            question_id,
+            answer: "s1".to_string(),
+        }];
+        let answer = AnswerEntry::new(
+            *form.id(),
+            AnswerAuthor::AuthenticatedUser(UserId::from(Uuid::new_v4())),
+            AnswerTitle::new(answer_title.map(|title| title.to_string().try_into().unwrap())),
+            PostedAnswerContents::try_new(form.questions().as_slice(), contents).unwrap(),
+        );
+
+        (form, answer)
+    }
+
+    #[test]
+    fn render_replaces_reserved_placeholders_and_question_answers() {
+        let (form, answer) = form_and_answer(Some("ログインできない"));
+
+        let rendered = template(
+            "$username さん、$form_name の「$answer_title」について ($server) スクリーンショットを送ってください。$unknown",
+        )
+        .render(&form, &answer, "steve")
+        .unwrap();
+
+        assert_eq!(
+            rendered.as_str(),
+            "steve さん、不具合報告 の「ログインできない」について (s1) スクリーンショットを送ってください。"
+        );
+    }
+
+    #[test]
+    fn render_rejects_templates_that_become_empty() {
+        let (form, answer) = form_and_answer(None);
+
+        let result = template("$answer_title").render(&form, &answer, "steve");
+
+        assert!(matches!(result, Err(DomainError::InvalidEntity { .. })));
+    }
+
+    #[test]
+    fn only_administrators_can_manage_and_read_templates() {
+        let standard_user = Actor::from(AccountUser::new(
+            "user".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        ));
+        let administrator = Actor::from(AccountUser::new(
+            "administrator".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        ));
+
+        assert!(
+            AuthorizationGuard::<_, Read>::from(template("body"))
+                .try_read(standard_user.clone())
+                .is_err()
+        );
+        assert!(
+            AuthorizationGuard::<_, Create>::from(template("body"))
+                .try_create(standard_user)
+                .is_err()
+        );
+        assert!(
+            AuthorizationGuard::<_, Read>::from(template("body"))
+                .try_read(administrator.clone())
+                .is_ok()
+        );
+        assert!(
+            AuthorizationGuard::<_, Create>::from(template("body"))
+                .try_create(administrator)
+                .is_ok()
+        );
+    }
+}
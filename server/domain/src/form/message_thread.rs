@@ -1,16 +1,19 @@
 use chrono::{DateTime, Utc};
+use derive_getters::Getters;
 use domain_derive::UnsafeFromRawParts;
 use errors::domain::DomainError;
 
 use crate::{
-    account::models::{Role::Administrator, UserSnapshot},
+    account::models::{Role::Administrator, UserId, UserSnapshot},
     auth::Actor,
     form::{
         answer::{AnswerAuthor, AnswerEntry, AnswerId},
+        is_administrator,
         message::{
-            DeletedMessage, Message, MessageBody, MessageHistoryEntry, MessageId, MessagePost,
-            can_read_deleted_message_history,
+            DeletedMessage, Message, MessageAttachment, MessageAttachmentId, MessageBody,
+            MessageHistoryEntry, MessageId, MessagePost, can_read_deleted_message_history,
         },
+        models::ActiveForm,
     },
     types::authorization_guard::{
         Allowed, AuthorizationGuard, AuthorizationGuardDefinitions, AuthorizationRole, BelongsTo,
@@ -23,6 +26,50 @@ pub struct MessageThread {
     answer_id: AnswerId,
     answer_author: AnswerAuthor,
     messages: Vec<Message>,
+    /// 回答の完了によって投稿が締め切られているか
+    locked: bool,
+    /// 一時回答の回答者が、有効なアクセスリンクを持っているか
+    temporary_access_active: bool,
+}
+
+/// 締め切られたメッセージスレッドを、管理者が再開したことを表す。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct MessageThreadReopening {
+    answer_id: AnswerId,
+    reopened_by: UserId,
+    reopened_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for MessageThreadReopening {
+    type Role = ParentGuarded<MessageThread>;
+}
+
+impl BelongsTo<MessageThread> for MessageThreadReopening {
+    fn belongs_to(&self, parent: &MessageThread) -> bool {
+        &self.answer_id == parent.answer_id()
+    }
+}
+
+impl GuardedBy<MessageThread, Create> for MessageThreadReopening {
+    /// 再開できるのは管理者本人だけです。
+    fn is_allowed_for(&self, _parent: &MessageThread, actor: &Actor) -> bool {
+        is_administrator(actor)
+            && matches!(actor, Actor::AccountUser(user) if user.id() == &self.reopened_by)
+    }
+}
+
+/// 管理者による再開が、回答を最後に完了にした時点以降のものかを判定する。
+///
+/// 再開した後で回答を完了にし直した場合は、以前の再開を引き継がずにスレッドを再び締め切る。
+pub fn is_reopened_since_completion(
+    reopened_at: Option<DateTime<Utc>>,
+    last_completed_at: Option<DateTime<Utc>>,
+) -> bool {
+    match (reopened_at, last_completed_at) {
+        (Some(reopened_at), Some(last_completed_at)) => reopened_at >= last_completed_at,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
 }
 
 struct MessageDeletionTarget {
@@ -42,10 +89,12 @@ impl BelongsTo<MessageThread> for MessageDeletionTarget {
 
 impl GuardedBy<MessageThread, Delete> for MessageDeletionTarget {
     fn is_allowed_for(&self, _parent: &MessageThread, actor: &Actor) -> bool {
+        // 一時回答者のメッセージは削除履歴に送信者を残せないため、削除できない。
         matches!(
             actor,
             Actor::AccountUser(user)
-                if self.message.sender_id() == user.id() || user.role() == &Administrator
+                if self.message.sender_id().is_some_and(|sender_id| sender_id == user.id()
+                    || user.role() == &Administrator)
         )
     }
 }
@@ -81,6 +130,10 @@ impl MessageThread {
         &self.messages
     }
 
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
     #[cfg(test)]
     pub(crate) fn add_message(self, message: Message) -> Self {
         Self {
@@ -103,19 +156,59 @@ impl Allowed<AnswerEntry, Read> {
             answer_id: *self.id(),
             answer_author: self.author().clone(),
             messages,
+            locked: false,
+            temporary_access_active: false,
         };
 
         AuthorizationGuard::from(thread).try_read(self.actor().clone())
     }
 }
 
+impl Allowed<ActiveForm, Read> {
+    /// 回答のメッセージスレッドを、フォームの締め切り設定を反映して組み立てる。
+    ///
+    /// `reopened` は、締め切られたスレッドを管理者が再開しているかを表す。
+    /// `temporary_access_active` は、一時回答の回答者が有効なアクセスリンクを持っているかを表す。
+    pub fn message_thread(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+        messages: Vec<Message>,
+        reopened: bool,
+        temporary_access_active: bool,
+    ) -> Result<Allowed<MessageThread, Read>, DomainError> {
+        if answer.form_id() != self.id() {
+            return Err(DomainError::NotFound);
+        }
+
+        let thread = MessageThread {
+            answer_id: *answer.id(),
+            answer_author: answer.author().clone(),
+            messages,
+            locked: !reopened
+                && self
+                    .answer_settings()
+                    .message_thread_lock_policy()
+                    .locks(*answer.status()),
+            temporary_access_active,
+        };
+
+        AuthorizationGuard::from(thread).try_read(answer.actor().clone())
+    }
+}
+
 impl Allowed<MessageThread, Update> {
     pub fn try_post_message(
         &self,
         message: Message,
     ) -> Result<Allowed<MessagePost, Create>, DomainError> {
+        if self.is_locked() {
+            return Err(DomainError::MessageThreadLocked);
+        }
+
         let answer_author_id = match self.answer_author() {
-            AnswerAuthor::AuthenticatedUser(answer_author_id) => *answer_author_id,
+            AnswerAuthor::AuthenticatedUser(answer_author_id) => Some(*answer_author_id),
+            // アクセスリンクがなければ回答者はメッセージを読めない
+            AnswerAuthor::Temporary(_) if self.temporary_access_active => None,
             AnswerAuthor::Temporary(_) => {
                 return Err(DomainError::MessagePostingNotSupportedForTemporaryAnswer);
             }
@@ -162,6 +255,27 @@ impl Allowed<MessageThread, Update> {
 
         self.authorize_delete(target)?.delete(deleted_at)
     }
+
+    /// 締め切られたスレッドを再開し、再び投稿できるようにする。
+    pub fn reopen(
+        &self,
+        reopened_at: DateTime<Utc>,
+    ) -> Result<Allowed<MessageThreadReopening, Create>, DomainError> {
+        if !self.is_locked() {
+            return Err(DomainError::InvalidEntity {
+                message: "The message thread is not locked.".to_string(),
+            });
+        }
+        let Actor::AccountUser(user) = self.actor() else {
+            return Err(DomainError::Forbidden);
+        };
+
+        self.authorize_create(MessageThreadReopening {
+            answer_id: *self.answer_id(),
+            reopened_by: *user.id(),
+            reopened_at,
+        })
+    }
 }
 
 impl Allowed<MessageThread, Read> {
@@ -175,18 +289,29 @@ impl Allowed<MessageThread, Read> {
     ) -> Result<Allowed<MessageHistoryEntry, Read>, DomainError> {
         self.authorize_read(history_entry)
     }
+
+    /// スレッド内のメッセージに添付されたファイルを探す。
+    ///
+    /// 閲覧が認可されたスレッドからのみ引けるため、ダウンロードの認可を兼ねる。
+    pub fn attachment(&self, attachment_id: MessageAttachmentId) -> Option<&MessageAttachment> {
+        self.value()
+            .messages()
+            .iter()
+            .find_map(|message| message.find_attachment(attachment_id))
+    }
 }
 
 fn is_answer_author_or_administrator(actor: &Actor, answer_author: &AnswerAuthor) -> bool {
-    matches!(
-        (actor, answer_author),
-        (Actor::AccountUser(user), _)
-            if user.role() == &Administrator
-    ) || matches!(
-        (actor, answer_author),
-        (Actor::AccountUser(user), AnswerAuthor::AuthenticatedUser(answer_author_id))
-            if user.id() == answer_author_id
-    )
+    match (actor, answer_author) {
+        (Actor::AccountUser(user), _) if user.role() == &Administrator => true,
+        (Actor::AccountUser(user), AnswerAuthor::AuthenticatedUser(answer_author_id)) => {
+            user.id() == answer_author_id
+        }
+        (Actor::TemporaryAnswerAuthor(actor), AnswerAuthor::Temporary(answer_author)) => {
+            actor.id() == answer_author.id()
+        }
+        _ => false,
+    }
 }
 
 impl AuthorizationRole for MessageThread {
@@ -239,6 +364,8 @@ mod tests {
                 answer_id("00000000-0000-7000-8000-000000000001"),
                 AnswerAuthor::AuthenticatedUser(answer_author_id),
                 Vec::new(),
+                false,
+                false,
             )
         }
     }
@@ -426,6 +553,8 @@ mod tests {
                 answer_id("00000000-0000-7000-8000-000000000562"),
                 temporary_author,
                 Vec::new(),
+                false,
+                false,
             )
         };
         let standard_user = Actor::from(active_user(
@@ -453,6 +582,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn temporary_author_with_access_link_and_administrators_can_exchange_messages() {
+        let admin_id = user_id("00000000-0000-7000-8000-000000000581");
+        let admin = Actor::from(active_user("admin", admin_id, Role::Administrator));
+        let temporary_user =
+            TemporaryAnswerAuthor::new("temporary_user".to_string(), "contact".to_string());
+        let another_temporary_user =
+            TemporaryAnswerAuthor::new("temporary_user".to_string(), "contact".to_string());
+        let thread = unsafe {
+            MessageThread::from_raw_parts(
+                answer_id("00000000-0000-7000-8000-000000000582"),
+                AnswerAuthor::Temporary(temporary_user.clone()),
+                Vec::new(),
+                false,
+                true,
+            )
+        };
+
+        assert!(
+            AuthorizationGuard::<_, Read>::from(thread.clone())
+                .try_read(Actor::from(another_temporary_user))
+                .is_err()
+        );
+
+        let admin_post = AuthorizationGuard::<_, Update>::from(thread.clone())
+            .try_update(admin)
+            .unwrap()
+            .try_post_message(message_from(admin_id, "follow-up"))
+            .unwrap();
+        assert_eq!(*admin_post.answer_author_id(), None);
+
+        let author_thread = AuthorizationGuard::<_, Update>::from(thread)
+            .try_update(Actor::from(temporary_user.clone()))
+            .unwrap();
+        let reply = author_thread
+            .try_post_message(Message::new_from_temporary_answer_author(
+                *temporary_user.id(),
+                message_body("reply"),
+            ))
+            .unwrap();
+        let impersonation = author_thread.try_post_message(message_from(admin_id, "spoofed"));
+
+        assert_eq!(reply.message().sender_id(), None);
+        assert!(matches!(impersonation, Err(DomainError::Forbidden)));
+    }
+
     #[test]
     fn imported_answer_thread_is_admin_readable_but_rejects_message_posts() {
         let admin_id = user_id("00000000-0000-7000-8000-000000000571");
@@ -466,6 +641,8 @@ mod tests {
                 answer_id("00000000-0000-7000-8000-000000000572"),
                 imported_author,
                 Vec::new(),
+                false,
+                false,
             )
         };
 
@@ -626,6 +803,7 @@ mod tests {
                 message_body("state"),
                 snapshot,
                 chrono::Utc::now(),
+                Vec::new(),
             )
         };
 
@@ -633,4 +811,106 @@ mod tests {
 
         assert!(matches!(result, Err(DomainError::NotFound)));
     }
+
+    #[test]
+    fn attachment_is_found_only_within_readable_thread_messages() {
+        let answer_author_id = user_id("00000000-0000-7000-8000-000000000951");
+        let answer_author = Actor::from(active_user(
+            "answer_author",
+            answer_author_id,
+            Role::StandardUser,
+        ));
+        let attachment =
+            MessageAttachment::try_new("evidence.png".to_string(), "image/png".to_string(), 1024)
+                .unwrap();
+        let attachment_id = *attachment.id();
+        let message = message_from(answer_author_id, "see attached")
+            .attach(vec![attachment])
+            .unwrap();
+        let thread = thread_for_answer_author(answer_author_id).add_message(message);
+
+        let readable_thread = AuthorizationGuard::<_, Read>::from(thread)
+            .try_read(answer_author)
+            .unwrap();
+
+        assert_eq!(
+            readable_thread
+                .attachment(attachment_id)
+                .map(|attachment| attachment.file_name().as_str()),
+            Some("evidence.png")
+        );
+        assert!(
+            readable_thread
+                .attachment(MessageAttachmentId::new())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn locked_thread_rejects_posts_until_an_administrator_reopens_it() {
+        let answer_author_id = user_id("00000000-0000-7000-8000-000000000961");
+        let administrator_id = user_id("00000000-0000-7000-8000-000000000962");
+        let answer_author = Actor::from(active_user(
+            "answer_author",
+            answer_author_id,
+            Role::StandardUser,
+        ));
+        let administrator =
+            Actor::from(active_user("admin", administrator_id, Role::Administrator));
+        let locked_thread = unsafe {
+            MessageThread::from_raw_parts(
+                answer_id("00000000-0000-7000-8000-000000000963"),
+                AnswerAuthor::AuthenticatedUser(answer_author_id),
+                Vec::new(),
+                true,
+                false,
+            )
+        };
+
+        let by_author = AuthorizationGuard::<_, Update>::from(locked_thread.clone())
+            .try_update(answer_author.clone())
+            .unwrap();
+        let by_administrator = AuthorizationGuard::<_, Update>::from(locked_thread)
+            .try_update(administrator)
+            .unwrap();
+
+        assert!(matches!(
+            by_author.try_post_message(message_from(answer_author_id, "reply")),
+            Err(DomainError::MessageThreadLocked)
+        ));
+        assert!(matches!(
+            by_administrator.try_post_message(message_from(administrator_id, "reply")),
+            Err(DomainError::MessageThreadLocked)
+        ));
+        assert!(matches!(
+            by_author.reopen(Utc::now()),
+            Err(DomainError::Forbidden)
+        ));
+        let reopening = by_administrator.reopen(Utc::now()).unwrap();
+        assert_eq!(reopening.reopened_by(), &administrator_id);
+        assert!(matches!(
+            AuthorizationGuard::<_, Update>::from(thread_for_answer_author(answer_author_id))
+                .try_update(answer_author)
+                .unwrap()
+                .reopen(Utc::now()),
+            Err(DomainError::InvalidEntity { .. })
+        ));
+    }
+
+    #[test]
+    fn completing_the_answer_again_after_reopening_locks_the_thread() {
+        let completed_at = Utc::now();
+        let reopened_at = completed_at + chrono::Duration::hours(1);
+        let completed_again_at = reopened_at + chrono::Duration::hours(1);
+
+        assert!(!is_reopened_since_completion(None, Some(completed_at)));
+        assert!(is_reopened_since_completion(
+            Some(reopened_at),
+            Some(completed_at)
+        ));
+        assert!(!is_reopened_since_completion(
+            Some(reopened_at),
+            Some(completed_again_at)
+        ));
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 #[cfg(test)]
 use common::test_utils::arbitrary_date_time;
@@ -13,7 +15,9 @@ use types::non_empty_string::NonEmptyString;
 pub use crate::form::{
     answer::{
         AnswerAcceptancePeriod, AnswerAuthorDisclosure, AnswerAuthorPublicationPolicy,
-        AnswerSettings, AnswerVisibility, DefaultAnswerTitle,
+        AnswerQuizSettings, AnswerResponseDeadline, AnswerSettings, AnswerVisibility,
+        DefaultAnswerTitle, MessageThreadLockPolicy, ResponseDeadlineHours,
+        TemporaryAnswerModerationPolicy,
     },
     label::{FormLabel, FormLabelAssignment, FormLabelId, FormLabelName},
     question::{Question, QuestionSet},
@@ -21,14 +25,17 @@ pub use crate::form::{
 };
 
 use crate::{
-    account::models::UserId,
+    account::models::{AccountUser, UserId},
     auth::Actor,
     form::{answer::TemporaryAnswerAuthor, submitter::FormSubmitter},
     form::{
         answer::{
-            AnswerAuthor, AnswerEntry, AnswerTitle, ArchivedAnswerEntry, PostedAnswerContents,
+            AnswerAuthor, AnswerEntry, AnswerModerationDecision, AnswerQuestionMapping,
+            AnswerQuizScore, AnswerTitle, ArchivedAnswerEntry, PostedAnswerContents,
         },
+        content_filter::ContentFilterVerdict,
         is_administrator,
+        question::QuestionId,
     },
     types::authorization_guard::{
         Allowed, AuthorizationGuardDefinitions, AuthorizationRole, Create, Read, SelfGuarded,
@@ -213,7 +220,12 @@ impl ActiveForm {
         if !self.answer_settings.can_accept_answer(&author, &actor) {
             return Err(DomainError::Forbidden);
         }
-        Ok(AnswerEntry::new(*self.id(), author, title, posted_answers))
+        Ok(self.prepare_accepted_answer(AnswerEntry::new(
+            *self.id(),
+            author,
+            title,
+            posted_answers,
+        )))
     }
 
     fn try_accept_temporary_answer(
@@ -228,7 +240,30 @@ impl ActiveForm {
         if !self.answer_settings.can_accept_answer(&author, &actor) {
             return Err(DomainError::Forbidden);
         }
-        Ok(AnswerEntry::new(*self.id(), author, title, posted_answers))
+        let moderation_status = self
+            .answer_settings
+            .temporary_answer_moderation_policy()
+            .initial_status();
+
+        Ok(self
+            .prepare_accepted_answer(AnswerEntry::new(*self.id(), author, title, posted_answers))
+            .change_moderation_status(moderation_status))
+    }
+
+    /// 受け付けた回答に、フォームの対応期限設定から求めた期限と、クイズの点数を設定します。
+    fn prepare_accepted_answer(&self, entry: AnswerEntry) -> AnswerEntry {
+        let due_at = self
+            .answer_settings
+            .response_deadline()
+            .due_at(*entry.timestamp());
+        let quiz_score = self
+            .answer_settings
+            .quiz()
+            .is_enabled()
+            .then(|| AnswerQuizScore::grade(self.questions.as_slice(), entry.contents()))
+            .flatten();
+
+        entry.change_due_at(due_at).change_quiz_score(quiz_score)
     }
 
     pub fn archive(self, archived_at: DateTime<Utc>, archived_by: UserId) -> ArchivedForm {
@@ -237,15 +272,35 @@ impl ActiveForm {
 }
 
 impl Allowed<ActiveForm, Read> {
+    /// 閲覧者に見せてよい内容のフォームを返します。
+    ///
+    /// クイズの正解と点数は、管理者以外には取り除きます。
+    pub fn into_visible_form(self) -> ActiveForm {
+        let show_quiz_points =
+            matches!(self.actor(), Actor::System) || is_administrator(self.actor());
+        let form = self.into_inner();
+
+        if show_quiz_points {
+            form
+        } else {
+            ActiveForm {
+                questions: form.questions.without_quiz_points(),
+                ..form
+            }
+        }
+    }
+
     pub fn try_accept_answer(
         &self,
         submitter: FormSubmitter,
         title: AnswerTitle,
         posted_answers: PostedAnswerContents,
+        verdict: &ContentFilterVerdict,
     ) -> Result<Allowed<AnswerEntry, Create>, DomainError> {
-        let entry =
-            self.value()
-                .try_accept_answer_from_submitter(submitter, title, posted_answers)?;
+        let entry = self
+            .value()
+            .try_accept_answer_from_submitter(submitter, title, posted_answers)?
+            .screen(verdict)?;
         self.authorize_create(entry)
     }
 
@@ -254,10 +309,12 @@ impl Allowed<ActiveForm, Read> {
         temporary_user: TemporaryAnswerAuthor,
         title: AnswerTitle,
         posted_answers: PostedAnswerContents,
+        verdict: &ContentFilterVerdict,
     ) -> Result<Allowed<AnswerEntry, Create>, DomainError> {
-        let entry =
-            self.value()
-                .try_accept_temporary_answer(temporary_user, title, posted_answers)?;
+        let entry = self
+            .value()
+            .try_accept_temporary_answer(temporary_user, title, posted_answers)?
+            .screen(verdict)?;
         self.authorize_create(entry)
     }
 
@@ -304,6 +361,60 @@ impl Allowed<ActiveForm, Update> {
         };
         self.authorize_update(entry)
     }
+
+    /// `entry` の担当者を変更し、更新認可済みで返します。`None` を指定すると担当者を外します。
+    pub fn assign_entry(
+        &self,
+        entry: AnswerEntry,
+        assignee: Option<&AccountUser>,
+    ) -> Result<Allowed<AnswerEntry, Update>, DomainError> {
+        self.authorize_update(entry.assign(assignee)?)
+    }
+
+    /// 確認待ちの `entry` を承認または却下し、更新認可済みで返します。
+    pub fn moderate_entry(
+        &self,
+        entry: AnswerEntry,
+        decision: AnswerModerationDecision,
+    ) -> Result<Allowed<AnswerEntry, Update>, DomainError> {
+        self.authorize_update(entry.moderate(decision)?)
+    }
+
+    /// `entry` の対応期限を手動で変更し、更新認可済みで返します。`None` を指定すると期限を外します。
+    pub fn change_entry_due_at(
+        &self,
+        entry: AnswerEntry,
+        due_at: Option<DateTime<Utc>>,
+    ) -> Result<Allowed<AnswerEntry, Update>, DomainError> {
+        self.authorize_update(entry.change_due_at(due_at))
+    }
+
+    /// `source` のフォームに所属する `entry` をこのフォームへ移動し、更新認可済みで返します。
+    ///
+    /// 質問の対応は `manual_mapping` を優先し、残りは同じ `TemplateKey` を持つ質問に対応付けます。
+    pub fn move_entry_into(
+        &self,
+        source: &Allowed<ActiveForm, Update>,
+        entry: Allowed<AnswerEntry, Update>,
+        manual_mapping: HashMap<QuestionId, QuestionId>,
+    ) -> Result<Allowed<AnswerEntry, Update>, DomainError> {
+        let entry = entry.into_inner();
+        if entry.form_id() != source.value().id() {
+            return Err(DomainError::Forbidden);
+        }
+        if source.value().id() == self.value().id() {
+            return Err(DomainError::InvalidEntity {
+                message: "answer is already in the destination form".to_string(),
+            });
+        }
+
+        let mapping = AnswerQuestionMapping::resolve(
+            source.value().questions().as_slice(),
+            self.value().questions().as_slice(),
+            manual_mapping,
+        )?;
+        self.authorize_update(entry.move_to(self.value(), &mapping)?)
+    }
 }
 
 impl Allowed<ArchivedForm, Read> {
@@ -405,11 +516,11 @@ mod tests {
     use super::*;
     use crate::{
         account::models::{AccountUser, Role, UserGroup, UserGroupId, UserGroupName},
-        form::answer::TemporaryAnswerAuthor,
+        form::answer::{AnswerModerationStatus, TemporaryAnswerAuthor},
         form::{
             FormSubmitter,
             answer::{FormAnswerContent, FormAnswerContentId},
-            question::{Question, QuestionId, QuestionType},
+            question::{Choice, Question, QuestionId, QuestionType, QuizPoints},
         },
         types::authorization_guard::{AuthorizationGuard, Read},
     };
@@ -496,6 +607,7 @@ mod tests {
                 temporary_user.clone(),
                 AnswerTitle::new(None),
                 sample_posted_answers(&form),
+                &ContentFilterVerdict::default(),
             );
 
         assert!(matches!(denied_result, Err(DomainError::Forbidden)));
@@ -509,11 +621,69 @@ mod tests {
             temporary_user,
             AnswerTitle::new(None),
             sample_posted_answers(&form),
+            &ContentFilterVerdict::default(),
         );
 
         assert!(accepted_result.is_ok());
     }
 
+    #[test]
+    fn held_temporary_answer_is_hidden_until_approved() {
+        let temporary_user = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let other_user = active_user(Role::StandardUser);
+        let administrator = Actor::from(active_user(Role::Administrator));
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .try_change_audience(true, AllowedUserGroups::unrestricted())
+                .unwrap()
+                .change_visibility(AnswerVisibility::PUBLIC)
+                .change_temporary_answer_moderation_policy(
+                    TemporaryAnswerModerationPolicy::HoldForReview,
+                ),
+        );
+
+        let entry = public_form_read_by(form.clone(), Actor::from(temporary_user.clone()))
+            .try_accept_temporary_answer(
+                temporary_user.clone(),
+                AnswerTitle::new(None),
+                sample_posted_answers(&form),
+                &ContentFilterVerdict::default(),
+            )
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(entry.moderation_status(), &AnswerModerationStatus::PENDING);
+        assert!(
+            public_form_read_by(form.clone(), Actor::from(temporary_user))
+                .read_entry(entry.clone())
+                .is_ok()
+        );
+        assert!(matches!(
+            public_form_read_by(form.clone(), Actor::from(other_user.clone()))
+                .read_entry(entry.clone()),
+            Err(DomainError::Forbidden)
+        ));
+
+        let form_update = AuthorizationGuard::<_, Read>::from(form.clone())
+            .into_update()
+            .try_update(administrator)
+            .unwrap();
+        let approved = form_update
+            .moderate_entry(entry, AnswerModerationDecision::Approve)
+            .unwrap()
+            .into_inner();
+
+        assert!(
+            public_form_read_by(form, Actor::from(other_user))
+                .read_entry(approved.clone())
+                .is_ok()
+        );
+        assert!(matches!(
+            form_update.moderate_entry(approved, AnswerModerationDecision::Reject),
+            Err(DomainError::AnswerNotPendingModeration)
+        ));
+    }
+
     #[test]
     fn form_readability_does_not_imply_private_answer_readability() {
         let answer_author = active_user(Role::StandardUser);
@@ -581,8 +751,79 @@ mod tests {
             submitter,
             AnswerTitle::new(None),
             sample_posted_answers(&form),
+            &ContentFilterVerdict::default(),
         );
 
         assert!(matches!(result, Err(DomainError::Forbidden)));
     }
+
+    #[test]
+    fn accepted_answer_is_due_after_the_response_deadline() {
+        let user = active_user(Role::StandardUser);
+        let actor = Actor::from(user.clone());
+        let submitter = FormSubmitter::try_new(user, None, Utc::now()).unwrap();
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default().change_response_deadline(AnswerResponseDeadline::new(Some(
+                ResponseDeadlineHours::try_from(72).unwrap(),
+            ))),
+        );
+
+        let entry = public_form_read_by(form.clone(), actor.clone())
+            .try_accept_answer(
+                submitter,
+                AnswerTitle::new(None),
+                sample_posted_answers(&form),
+                &ContentFilterVerdict::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            entry.due_at_visible_to(&Actor::System),
+            Some(*entry.timestamp() + Duration::hours(72))
+        );
+        assert_eq!(entry.due_at_visible_to(&actor), None);
+    }
+
+    #[test]
+    fn quiz_points_are_visible_to_administrators_only() {
+        let question = Question::new_single_choice(
+            "mob".to_string().try_into().unwrap(),
+            0,
+            "Mob".to_string().try_into().unwrap(),
+            None,
+            NonEmptyVec::try_new(vec![
+                Choice::new(None, 0, "Creeper".to_string().try_into().unwrap())
+                    .with_quiz_points(Some(QuizPoints::try_from(10).unwrap())),
+            ])
+            .unwrap(),
+            true,
+        )
+        .unwrap();
+        let form = ActiveForm::new(
+            FormTitle::new("Quiz".to_string().try_into().unwrap()),
+            FormDescription::new("description".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        );
+        let quiz_points = |form: ActiveForm| {
+            form.questions().as_slice()[0]
+                .choices()
+                .unwrap()
+                .iter()
+                .map(|choice| choice.quiz_points)
+                .collect::<Vec<_>>()
+        };
+
+        let for_administrator =
+            public_form_read_by(form.clone(), Actor::from(active_user(Role::Administrator)))
+                .into_visible_form();
+        let for_standard_user =
+            public_form_read_by(form, Actor::from(active_user(Role::StandardUser)))
+                .into_visible_form();
+
+        assert_eq!(
+            quiz_points(for_administrator),
+            vec![Some(QuizPoints::try_from(10).unwrap())]
+        );
+        assert_eq!(quiz_points(for_standard_user), vec![None]);
+    }
 }
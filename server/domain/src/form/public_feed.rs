@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::form::models::ActiveForm;
+
+/// 未ログインで閲覧でき、回答を受け付けているフォームのフィード。
+///
+/// 回答の受付期間は時間の経過だけで開始・終了するため、フォーム自体が更新されていなくても
+/// 受付が始まったときにエントリーが更新され、終わったときにフィードから外れる。
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct PublicFormFeed {
+    entries: Vec<PublicFormFeedEntry>,
+    /// フィードの内容が最後に変わった日時。掲載したことのあるフォームがなければ `None`。
+    updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct PublicFormFeedEntry {
+    form: ActiveForm,
+    /// フォームの更新日時と受付開始日時のうち、遅いほう。
+    updated_at: DateTime<Utc>,
+}
+
+impl PublicFormFeed {
+    /// `now` の時点のフィードを組み立てる。
+    ///
+    /// `forms` には未ログインの利用者が読めるフォームだけを渡すこと。
+    pub fn new(forms: Vec<ActiveForm>, now: DateTime<Utc>) -> Self {
+        let mut updated_at = None;
+        let mut entries = Vec::new();
+
+        for form in forms {
+            let period = form.answer_settings().acceptance_period();
+
+            if period.is_within_period(now) {
+                let entry_updated_at = period
+                    .start_at()
+                    .map_or(form.metadata().updated_at, |start_at| {
+                        start_at.max(form.metadata().updated_at)
+                    });
+                updated_at = updated_at.max(Some(entry_updated_at));
+                entries.push(PublicFormFeedEntry {
+                    form,
+                    updated_at: entry_updated_at,
+                });
+            } else if let Some(end_at) = period.end_at().filter(|end_at| *end_at < now) {
+                // 受付を終えたフォームはフィードから外れるため、その時刻もフィードの更新として扱う。
+                updated_at = updated_at.max(Some(end_at));
+            }
+        }
+
+        entries.sort_by(|a, b| {
+            b.updated_at
+                .cmp(&a.updated_at)
+                .then_with(|| a.form.id().into_inner().cmp(&b.form.id().into_inner()))
+        });
+
+        Self {
+            entries,
+            updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use types::non_empty_vec::NonEmptyVec;
+
+    use super::*;
+    use crate::form::models::{
+        AnswerAcceptancePeriod, AnswerSettings, FormDescription, FormTitle, Question, QuestionSet,
+    };
+
+    fn form_accepting(title: &str, period: AnswerAcceptancePeriod) -> ActiveForm {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap();
+        ActiveForm::new(
+            FormTitle::new(title.to_string().try_into().unwrap()),
+            FormDescription::new("説明".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+        .change_answer_settings(AnswerSettings::default().change_acceptance_period(period))
+    }
+
+    fn titles(feed: &PublicFormFeed) -> Vec<&str> {
+        feed.entries()
+            .iter()
+            .map(|entry| entry.form().title().as_str())
+            .collect()
+    }
+
+    #[test]
+    fn only_forms_within_their_acceptance_period_are_listed() {
+        let now = Utc::now();
+        let feed = PublicFormFeed::new(
+            vec![
+                form_accepting(
+                    "受付中",
+                    AnswerAcceptancePeriod::try_new(None, None).unwrap(),
+                ),
+                form_accepting(
+                    "受付前",
+                    AnswerAcceptancePeriod::try_new(Some(now + Duration::days(1)), None).unwrap(),
+                ),
+                form_accepting(
+                    "受付終了",
+                    AnswerAcceptancePeriod::try_new(None, Some(now - Duration::days(1))).unwrap(),
+                ),
+            ],
+            now,
+        );
+
+        assert_eq!(titles(&feed), vec!["受付中"]);
+    }
+
+    #[test]
+    fn opening_and_closing_acceptance_update_the_feed() {
+        let now = Utc::now();
+        let opened_at = now + Duration::days(1);
+        let closed_at = now + Duration::days(2);
+        let forms = vec![
+            form_accepting(
+                "これから開始",
+                AnswerAcceptancePeriod::try_new(Some(opened_at), None).unwrap(),
+            ),
+            form_accepting(
+                "これから終了",
+                AnswerAcceptancePeriod::try_new(None, Some(closed_at)).unwrap(),
+            ),
+        ];
+
+        let after_opening = PublicFormFeed::new(forms.clone(), opened_at + Duration::hours(1));
+        assert_eq!(titles(&after_opening), vec!["これから開始", "これから終了"]);
+        assert_eq!(*after_opening.entries()[0].updated_at(), opened_at);
+        assert_eq!(*after_opening.updated_at(), Some(opened_at));
+
+        let after_closing = PublicFormFeed::new(forms, closed_at + Duration::hours(1));
+        assert_eq!(titles(&after_closing), vec!["これから開始"]);
+        assert_eq!(*after_closing.updated_at(), Some(closed_at));
+    }
+}
@@ -112,6 +112,40 @@ impl proptest::arbitrary::Arbitrary for TemplateKey {
     }
 }
 
+/// クイズで正解の選択肢を選んだときに与える点数です。1 点から 1000 点までを指定できます。
+#[cfg_attr(test, derive(Arbitrary))]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct QuizPoints(#[cfg_attr(test, proptest(strategy = "1..=QuizPoints::MAX"))] u32);
+
+impl QuizPoints {
+    pub const MAX: u32 = 1000;
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for QuizPoints {
+    type Error = DomainError;
+
+    fn try_from(points: u32) -> Result<Self, Self::Error> {
+        if (1..=Self::MAX).contains(&points) {
+            Ok(Self(points))
+        } else {
+            Err(DomainError::InvalidEntity {
+                message: format!("choice.quiz_points must be between 1 and {}", Self::MAX),
+            })
+        }
+    }
+}
+
+impl From<QuizPoints> for u32 {
+    fn from(points: QuizPoints) -> Self {
+        points.0
+    }
+}
+
 #[cfg_attr(test, derive(Arbitrary))]
 #[derive(Serialize, Deserialize, Clone, Getters, Debug, PartialEq)]
 pub struct Choice {
@@ -119,6 +153,9 @@ pub struct Choice {
     pub id: Option<ChoiceId>,
     pub position: u16,
     pub label: NonEmptyString,
+    /// クイズで正解とする選択肢の点数です。`None` の選択肢は不正解として扱います。
+    #[serde(default)]
+    pub quiz_points: Option<QuizPoints>,
 }
 
 impl Choice {
@@ -127,9 +164,22 @@ impl Choice {
             id,
             position,
             label,
+            quiz_points: None,
         }
     }
 
+    /// この選択肢をクイズの正解として `quiz_points` 点を与えるか、`None` で不正解にします。
+    pub fn with_quiz_points(self, quiz_points: Option<QuizPoints>) -> Self {
+        Self {
+            quiz_points,
+            ..self
+        }
+    }
+
+    pub fn is_correct(&self) -> bool {
+        self.quiz_points.is_some()
+    }
+
     /// [`Choice`] を永続化済みのフィールド値から復元します。
     ///
     /// # Safety
@@ -138,8 +188,9 @@ impl Choice {
         id: Option<ChoiceId>,
         position: u16,
         label: NonEmptyString,
+        quiz_points: Option<QuizPoints>,
     ) -> Result<Self, DomainError> {
-        Ok(Self::new(id, position, label))
+        Ok(Self::new(id, position, label).with_quiz_points(quiz_points))
     }
 }
 
@@ -224,6 +275,21 @@ impl SelectQuestion {
             choices,
         })
     }
+
+    fn without_quiz_points(self) -> Self {
+        let choices = self
+            .choices
+            .into_inner()
+            .into_iter()
+            .map(|choice| choice.with_quiz_points(None))
+            .collect();
+
+        Self {
+            choices: NonEmptyVec::try_new(choices)
+                .expect("removing quiz points must not change the number of choices"),
+            ..self
+        }
+    }
 }
 
 #[cfg_attr(test, derive(Arbitrary))]
@@ -279,6 +345,21 @@ impl QuestionSet {
     pub fn into_inner(self) -> NonEmptyVec<Question> {
         self.0
     }
+
+    /// クイズの正解と点数を取り除いた質問を返します。
+    pub fn without_quiz_points(self) -> Self {
+        let questions = self
+            .0
+            .into_inner()
+            .into_iter()
+            .map(Question::without_quiz_points)
+            .collect();
+
+        Self(
+            NonEmptyVec::try_new(questions)
+                .expect("removing quiz points must not change the number of questions"),
+        )
+    }
 }
 
 impl Question {
@@ -441,6 +522,15 @@ impl Question {
         }
     }
 
+    /// クイズの正解と点数を取り除いた質問を返します。
+    pub fn without_quiz_points(self) -> Self {
+        match self {
+            Self::Text(question) => Self::Text(question),
+            Self::SingleChoice(question) => Self::SingleChoice(question.without_quiz_points()),
+            Self::MultipleChoice(question) => Self::MultipleChoice(question.without_quiz_points()),
+        }
+    }
+
     pub fn update_preserving_id(self, updated: Question) -> Result<Self, DomainError> {
         let definition = QuestionDefinition::new(
             self.id(),
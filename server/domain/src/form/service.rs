@@ -35,19 +35,14 @@ impl DefaultAnswerTitleDomainService {
                     })
                     .collect::<HashMap<_, _>>();
 
-                let replaced_title = template_placeholder_regex()
-                    .replace_all(
-                        default_answer_title.as_str(),
-                        |caps: &regex::Captures| match &caps[1] {
-                            "form_name" => form_title.as_str(),
-                            "username" => author_name,
-                            template_key => answers_by_template_key
-                                .get(template_key)
-                                .copied()
-                                .unwrap_or_default(),
-                        },
-                    )
-                    .into_owned();
+                let replaced_title =
+                    replace_template_placeholders(default_answer_title.as_str(), |placeholder| {
+                        match placeholder {
+                            "form_name" => Some(form_title.as_str()),
+                            "username" => Some(author_name),
+                            template_key => answers_by_template_key.get(template_key).copied(),
+                        }
+                    });
 
                 Ok(AnswerTitle::new(Some(replaced_title.try_into()?)))
             }
@@ -56,6 +51,20 @@ impl DefaultAnswerTitleDomainService {
     }
 }
 
+/// `template` 中の `$key` 形式のプレースホルダーを `resolve` が返す値で置き換えます。
+///
+/// 値が見つからないプレースホルダーは空文字列に置き換えます。
+pub(crate) fn replace_template_placeholders<'a>(
+    template: &str,
+    resolve: impl Fn(&str) -> Option<&'a str>,
+) -> String {
+    template_placeholder_regex()
+        .replace_all(template, |caps: &regex::Captures| {
+            resolve(&caps[1]).unwrap_or_default()
+        })
+        .into_owned()
+}
+
 fn template_placeholder_regex() -> &'static Regex {
     static REGEX: OnceLock<Regex> = OnceLock::new();
     REGEX.get_or_init(|| Regex::new(r"\$([A-Za-z0-9_-]+)").unwrap())
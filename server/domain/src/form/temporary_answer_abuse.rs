@@ -0,0 +1,187 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+
+use crate::{
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        is_administrator,
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Read,
+    },
+};
+
+/// 一時回答を送ってきたリクエストの送信元。
+///
+/// 荒らしへの対応に使うため回答と一緒に記録するが、IP アドレスと User-Agent は
+/// そのまま保存せず、infra 層で salt 付きのハッシュにしてから保存する。
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct TemporaryAnswerSubmissionSource {
+    client_ip: Option<IpAddr>,
+    user_agent: Option<String>,
+}
+
+impl TemporaryAnswerSubmissionSource {
+    pub fn new(client_ip: Option<IpAddr>, user_agent: Option<String>) -> Self {
+        Self {
+            client_ip: client_ip.map(|ip| ip.to_canonical()),
+            user_agent: user_agent.filter(|user_agent| !user_agent.trim().is_empty()),
+        }
+    }
+
+    pub fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+}
+
+impl std::fmt::Debug for TemporaryAnswerSubmissionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TemporaryAnswerSubmissionSource(<redacted>)")
+    }
+}
+
+/// 受け付けた一時回答に、送信元を記録することを表す。
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerSubmissionRecord {
+    answer_id: AnswerId,
+    source: TemporaryAnswerSubmissionSource,
+    recorded_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for TemporaryAnswerSubmissionRecord {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerSubmissionRecord {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id() && parent.author().temporary_user().is_some()
+    }
+}
+
+impl GuardedBy<AnswerEntry, Create> for TemporaryAnswerSubmissionRecord {
+    /// 記録できるのは、回答を送った一時回答者本人のリクエストだけです。
+    fn is_allowed_for(&self, parent: &AnswerEntry, actor: &Actor) -> bool {
+        parent.is_temporary_author(actor)
+    }
+}
+
+/// 一時回答の送信元をハッシュにした値。
+///
+/// 同じ値を持つ回答は、同じ送信元から送られた可能性が高い。送信元の推測につながるため、
+/// 管理者にだけ見せる。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerAbuseMetadata {
+    answer_id: AnswerId,
+    client_ip_hash: Option<String>,
+    user_agent_fingerprint: Option<String>,
+    recorded_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for TemporaryAnswerAbuseMetadata {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerAbuseMetadata {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+    }
+}
+
+impl GuardedBy<AnswerEntry, Read> for TemporaryAnswerAbuseMetadata {
+    /// 回答を読める人のうち、管理者だけが読めます。
+    fn is_allowed_for(&self, _parent: &AnswerEntry, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+impl Allowed<AnswerEntry, Create> {
+    /// 受け付けた一時回答に、送信元を記録する。
+    pub fn record_temporary_submission_source(
+        &self,
+        source: TemporaryAnswerSubmissionSource,
+        recorded_at: DateTime<Utc>,
+    ) -> Result<Allowed<TemporaryAnswerSubmissionRecord, Create>, DomainError> {
+        self.authorize(TemporaryAnswerSubmissionRecord {
+            answer_id: *self.id(),
+            source,
+            recorded_at,
+        })
+    }
+}
+
+impl Allowed<AnswerEntry, Read> {
+    pub fn read_temporary_abuse_metadata(
+        &self,
+        metadata: TemporaryAnswerAbuseMetadata,
+    ) -> Result<Allowed<TemporaryAnswerAbuseMetadata, Read>, DomainError> {
+        self.authorize_read(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::models::{AccountUser, Role, UserId},
+        form::{
+            answer::{AnswerAuthor, AnswerTitle, PostedAnswerContents, TemporaryAnswerAuthor},
+            models::FormId,
+        },
+    };
+    use uuid::Uuid;
+
+    fn temporary_entry(author: TemporaryAnswerAuthor) -> AnswerEntry {
+        AnswerEntry::new(
+            FormId::new(),
+            AnswerAuthor::Temporary(author),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn submission_source_is_redacted() {
+        let source = TemporaryAnswerSubmissionSource::new(
+            Some("203.0.113.5".parse().unwrap()),
+            Some("Mozilla/5.0".to_string()),
+        );
+
+        assert!(!format!("{source:?}").contains("203.0.113.5"));
+        assert!(!format!("{source:?}").contains("Mozilla"));
+    }
+
+    #[test]
+    fn only_administrators_can_read_abuse_metadata() {
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let entry = temporary_entry(author.clone());
+        let metadata = unsafe {
+            TemporaryAnswerAbuseMetadata::from_raw_parts(
+                *entry.id(),
+                Some("hash".to_string()),
+                None,
+                Utc::now(),
+            )
+        };
+        let user = |role| {
+            Actor::from(AccountUser::new(
+                "user".to_string(),
+                UserId::from(Uuid::new_v4()),
+                role,
+            ))
+        };
+
+        assert!(metadata.belongs_to(&entry));
+        assert!(metadata.is_allowed_for(&entry, &user(Role::Administrator)));
+        assert!(!metadata.is_allowed_for(&entry, &user(Role::StandardUser)));
+        assert!(!metadata.is_allowed_for(&entry, &Actor::from(author)));
+    }
+}
@@ -0,0 +1,276 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use uuid::Uuid;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId, TemporaryAnswerAuthor},
+        is_administrator,
+        models::FormId,
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Update,
+    },
+};
+
+/// 推測できないよう 244 bit の乱数から、64 文字の小文字の 16 進数の秘密の値を作る。
+pub(crate) fn generate_secret() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// [`generate_secret`] で作れる形式かどうかを確かめる。
+pub(crate) fn is_secret_format(value: &str) -> bool {
+    value.len() == 64
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+}
+
+/// ログインせずに回答した人へ渡す、回答のアクセスリンク用の秘密トークン。
+///
+/// 平文は発行時のレスポンスでだけ回答者に渡し、保存時はハッシュだけを残す。
+#[derive(Clone, PartialEq, Eq)]
+pub struct TemporaryAnswerAccessToken(String);
+
+impl TemporaryAnswerAccessToken {
+    fn generate() -> Self {
+        Self(generate_secret())
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TemporaryAnswerAccessToken {
+    type Error = DomainError;
+
+    /// 形式が正しくないトークンは、存在しないトークンと区別しない。
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if is_secret_format(&value) {
+            Ok(Self(value))
+        } else {
+            Err(DomainError::NotFound)
+        }
+    }
+}
+
+impl std::fmt::Debug for TemporaryAnswerAccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TemporaryAnswerAccessToken(<redacted>)")
+    }
+}
+
+/// 一時回答の回答者が、アクセスリンクから回答とメッセージスレッドを扱えることを表す。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerAccess {
+    answer_id: AnswerId,
+    form_id: FormId,
+    author: TemporaryAnswerAuthor,
+    issued_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TemporaryAnswerAccess {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// アクセスリンクの持ち主を、回答を書いた一時回答者として扱う。
+    ///
+    /// 取り消されたアクセスリンクでは何も操作できない。
+    pub fn actor(&self) -> Result<Actor, DomainError> {
+        if self.is_revoked() {
+            return Err(DomainError::Forbidden);
+        }
+
+        Ok(Actor::from(self.author.clone()))
+    }
+}
+
+impl AuthorizationRole for TemporaryAnswerAccess {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerAccess {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+            && &self.form_id == parent.form_id()
+            && parent.author().temporary_user() == Some(&self.author)
+    }
+}
+
+impl GuardedBy<AnswerEntry, Create> for TemporaryAnswerAccess {
+    /// 発行できるのは、回答を書いた一時回答者本人だけです。
+    fn is_allowed_for(&self, parent: &AnswerEntry, actor: &Actor) -> bool {
+        parent.is_temporary_author(actor)
+    }
+}
+
+/// 管理者が回答のアクセスリンクを取り消したことを表す。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerAccessRevocation {
+    answer_id: AnswerId,
+    revoked_by: UserId,
+    revoked_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for TemporaryAnswerAccessRevocation {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerAccessRevocation {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id() && parent.author().temporary_user().is_some()
+    }
+}
+
+impl GuardedBy<AnswerEntry, Update> for TemporaryAnswerAccessRevocation {
+    /// 取り消せるのは管理者本人だけです。
+    fn is_allowed_for(&self, _parent: &AnswerEntry, actor: &Actor) -> bool {
+        is_administrator(actor)
+            && matches!(actor, Actor::AccountUser(user) if user.id() == &self.revoked_by)
+    }
+}
+
+impl Allowed<AnswerEntry, Create> {
+    /// 受け付けた一時回答に、回答者へ渡すアクセスリンクのトークンを発行する。
+    pub fn issue_temporary_access(
+        &self,
+        issued_at: DateTime<Utc>,
+    ) -> Result<
+        (
+            Allowed<TemporaryAnswerAccess, Create>,
+            TemporaryAnswerAccessToken,
+        ),
+        DomainError,
+    > {
+        let author = self
+            .author()
+            .temporary_user()
+            .cloned()
+            .ok_or(DomainError::Forbidden)?;
+        let access = self.authorize(TemporaryAnswerAccess {
+            answer_id: *self.id(),
+            form_id: *self.form_id(),
+            author,
+            issued_at,
+            revoked_at: None,
+        })?;
+
+        Ok((access, TemporaryAnswerAccessToken::generate()))
+    }
+}
+
+impl Allowed<AnswerEntry, Update> {
+    /// 回答に発行したアクセスリンクを取り消す。
+    pub fn revoke_temporary_access(
+        &self,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<Allowed<TemporaryAnswerAccessRevocation, Update>, DomainError> {
+        let Actor::AccountUser(user) = self.actor() else {
+            return Err(DomainError::Forbidden);
+        };
+
+        self.authorize_update(TemporaryAnswerAccessRevocation {
+            answer_id: *self.id(),
+            revoked_by: *user.id(),
+            revoked_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::models::{AccountUser, Role},
+        form::answer::{AnswerAuthor, AnswerTitle, PostedAnswerContents},
+    };
+
+    fn temporary_entry(author: TemporaryAnswerAuthor) -> AnswerEntry {
+        AnswerEntry::new(
+            FormId::new(),
+            AnswerAuthor::Temporary(author),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        )
+    }
+
+    fn access_for(entry: &AnswerEntry, revoked_at: Option<DateTime<Utc>>) -> TemporaryAnswerAccess {
+        unsafe {
+            TemporaryAnswerAccess::from_raw_parts(
+                *entry.id(),
+                *entry.form_id(),
+                entry.author().temporary_user().unwrap().clone(),
+                Utc::now(),
+                revoked_at,
+            )
+        }
+    }
+
+    #[test]
+    fn token_is_high_entropy_hex_and_is_redacted_in_debug_output() {
+        let token = TemporaryAnswerAccessToken::generate();
+
+        assert_eq!(token.expose_secret().len(), 64);
+        assert_ne!(token, TemporaryAnswerAccessToken::generate());
+        assert_eq!(
+            TemporaryAnswerAccessToken::try_from(token.expose_secret().to_owned()).unwrap(),
+            token
+        );
+        assert!(!format!("{token:?}").contains(token.expose_secret()));
+        assert!(TemporaryAnswerAccessToken::try_from("not-a-token".to_string()).is_err());
+        assert!(TemporaryAnswerAccessToken::try_from("A".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn access_is_issued_only_to_the_temporary_author_and_stops_working_once_revoked() {
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let entry = temporary_entry(author.clone());
+        let other = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let access = access_for(&entry, None);
+
+        assert!(access.belongs_to(&entry));
+        assert!(!access.belongs_to(&temporary_entry(other.clone())));
+        assert!(access.is_allowed_for(&entry, &Actor::from(author.clone())));
+        assert!(!access.is_allowed_for(&entry, &Actor::from(other)));
+        assert_eq!(access.actor().unwrap(), Actor::from(author));
+        assert!(matches!(
+            access_for(&entry, Some(Utc::now())).actor(),
+            Err(DomainError::Forbidden)
+        ));
+    }
+
+    #[test]
+    fn only_administrators_can_revoke_temporary_access() {
+        let entry = temporary_entry(TemporaryAnswerAuthor::new(
+            "guest".to_string(),
+            "contact".to_string(),
+        ));
+        let administrator = AccountUser::new(
+            "admin".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let standard_user = AccountUser::new(
+            "user".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let revocation = |user: &AccountUser| TemporaryAnswerAccessRevocation {
+            answer_id: *entry.id(),
+            revoked_by: *user.id(),
+            revoked_at: Utc::now(),
+        };
+
+        assert!(
+            revocation(&administrator).is_allowed_for(&entry, &Actor::from(administrator.clone()))
+        );
+        assert!(!revocation(&standard_user).is_allowed_for(&entry, &Actor::from(standard_user)));
+    }
+}
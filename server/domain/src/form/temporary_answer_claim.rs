@@ -0,0 +1,252 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+
+use crate::{
+    account::models::{AccountUser, UserId},
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId, TemporaryAnswerAuthor},
+        models::FormId,
+        temporary_answer_access::{generate_secret, is_secret_format},
+    },
+    types::authorization_guard::{
+        Allowed, AuthorizationRole, BelongsTo, Create, GuardedBy, ParentGuarded, Read,
+    },
+};
+
+/// ログインせずに回答した人が、後でその回答を自分のアカウントに引き継ぐための一度だけ使えるコード。
+///
+/// 平文は発行時のレスポンスでだけ回答者に渡し、保存時はハッシュだけを残す。
+#[derive(Clone, PartialEq, Eq)]
+pub struct TemporaryAnswerClaimCode(String);
+
+impl TemporaryAnswerClaimCode {
+    fn generate() -> Self {
+        Self(generate_secret())
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TemporaryAnswerClaimCode {
+    type Error = DomainError;
+
+    /// 形式が正しくないコードは、存在しないコードと区別しない。
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if is_secret_format(&value) {
+            Ok(Self(value))
+        } else {
+            Err(DomainError::NotFound)
+        }
+    }
+}
+
+impl std::fmt::Debug for TemporaryAnswerClaimCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TemporaryAnswerClaimCode(<redacted>)")
+    }
+}
+
+/// 一時回答に発行した引き継ぎコードの状態を表す。
+///
+/// `author` は発行時点の一時回答者で、引き継いだ後も監査のために残る。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerClaimTicket {
+    answer_id: AnswerId,
+    form_id: FormId,
+    author: TemporaryAnswerAuthor,
+    issued_at: DateTime<Utc>,
+    claimed_at: Option<DateTime<Utc>>,
+}
+
+impl TemporaryAnswerClaimTicket {
+    pub fn is_claimed(&self) -> bool {
+        self.claimed_at.is_some()
+    }
+
+    /// コードの持ち主を、回答を書いた一時回答者として扱う。
+    ///
+    /// 使用済みのコードでは何も操作できない。
+    pub fn actor(&self) -> Result<Actor, DomainError> {
+        if self.is_claimed() {
+            return Err(DomainError::Forbidden);
+        }
+
+        Ok(Actor::from(self.author.clone()))
+    }
+}
+
+impl AuthorizationRole for TemporaryAnswerClaimTicket {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerClaimTicket {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+            && &self.form_id == parent.form_id()
+            && parent.author().temporary_user() == Some(&self.author)
+    }
+}
+
+impl GuardedBy<AnswerEntry, Create> for TemporaryAnswerClaimTicket {
+    /// 発行できるのは、回答を書いた一時回答者本人だけです。
+    fn is_allowed_for(&self, parent: &AnswerEntry, actor: &Actor) -> bool {
+        parent.is_temporary_author(actor)
+    }
+}
+
+/// 一時回答の回答者を、ログインしたユーザーに切り替えることを表す。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct TemporaryAnswerClaim {
+    answer_id: AnswerId,
+    temporary_author: TemporaryAnswerAuthor,
+    claimed_by: UserId,
+    claimed_at: DateTime<Utc>,
+}
+
+impl AuthorizationRole for TemporaryAnswerClaim {
+    type Role = ParentGuarded<AnswerEntry>;
+}
+
+impl BelongsTo<AnswerEntry> for TemporaryAnswerClaim {
+    fn belongs_to(&self, parent: &AnswerEntry) -> bool {
+        &self.answer_id == parent.id()
+            && parent.author().temporary_user() == Some(&self.temporary_author)
+    }
+}
+
+impl GuardedBy<AnswerEntry, Create> for TemporaryAnswerClaim {
+    /// 引き継ぎコードで一時回答者本人であることを示した場合だけ引き継げます。
+    fn is_allowed_for(&self, parent: &AnswerEntry, actor: &Actor) -> bool {
+        parent.is_temporary_author(actor)
+    }
+}
+
+impl Allowed<AnswerEntry, Create> {
+    /// 受け付けた一時回答に、アカウントへ引き継ぐためのコードを発行する。
+    pub fn issue_temporary_claim_code(
+        &self,
+        issued_at: DateTime<Utc>,
+    ) -> Result<
+        (
+            Allowed<TemporaryAnswerClaimTicket, Create>,
+            TemporaryAnswerClaimCode,
+        ),
+        DomainError,
+    > {
+        let author = self
+            .author()
+            .temporary_user()
+            .cloned()
+            .ok_or(DomainError::Forbidden)?;
+        let ticket = self.authorize(TemporaryAnswerClaimTicket {
+            answer_id: *self.id(),
+            form_id: *self.form_id(),
+            author,
+            issued_at,
+            claimed_at: None,
+        })?;
+
+        Ok((ticket, TemporaryAnswerClaimCode::generate()))
+    }
+}
+
+impl Allowed<AnswerEntry, Read> {
+    /// 引き継ぎコードの持ち主として読んだ一時回答を、`claimed_by` のアカウントに引き継ぐ。
+    pub fn claim_temporary_answer(
+        &self,
+        claimed_by: &AccountUser,
+        claimed_at: DateTime<Utc>,
+    ) -> Result<Allowed<TemporaryAnswerClaim, Create>, DomainError> {
+        let temporary_author = self
+            .author()
+            .temporary_user()
+            .cloned()
+            .ok_or(DomainError::NotFound)?;
+
+        self.authorize_create(TemporaryAnswerClaim {
+            answer_id: *self.id(),
+            temporary_author,
+            claimed_by: *claimed_by.id(),
+            claimed_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::models::Role,
+        form::answer::{AnswerAuthor, AnswerTitle, PostedAnswerContents},
+    };
+    use uuid::Uuid;
+
+    fn temporary_entry(author: TemporaryAnswerAuthor) -> AnswerEntry {
+        AnswerEntry::new(
+            FormId::new(),
+            AnswerAuthor::Temporary(author),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn claim_code_is_redacted_and_used_codes_grant_nothing() {
+        let code = TemporaryAnswerClaimCode::generate();
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let entry = temporary_entry(author.clone());
+        let ticket = |claimed_at| unsafe {
+            TemporaryAnswerClaimTicket::from_raw_parts(
+                *entry.id(),
+                *entry.form_id(),
+                author.clone(),
+                Utc::now(),
+                claimed_at,
+            )
+        };
+
+        assert!(!format!("{code:?}").contains(code.expose_secret()));
+        assert_eq!(
+            TemporaryAnswerClaimCode::try_from(code.expose_secret().to_owned()).unwrap(),
+            code
+        );
+        assert!(ticket(None).belongs_to(&entry));
+        assert_eq!(ticket(None).actor().unwrap(), Actor::from(author.clone()));
+        assert!(matches!(
+            ticket(Some(Utc::now())).actor(),
+            Err(DomainError::Forbidden)
+        ));
+    }
+
+    #[test]
+    fn only_the_temporary_author_can_hand_the_answer_over() {
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let entry = temporary_entry(author.clone());
+        let user = AccountUser::new(
+            "player".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let claim = TemporaryAnswerClaim {
+            answer_id: *entry.id(),
+            temporary_author: author.clone(),
+            claimed_by: *user.id(),
+            claimed_at: Utc::now(),
+        };
+
+        assert!(claim.belongs_to(&entry));
+        assert!(claim.is_allowed_for(&entry, &Actor::from(author)));
+        assert!(!claim.is_allowed_for(&entry, &Actor::from(user)));
+        assert!(
+            !claim.belongs_to(&temporary_entry(TemporaryAnswerAuthor::new(
+                "guest".to_string(),
+                "contact".to_string(),
+            )))
+        );
+    }
+}
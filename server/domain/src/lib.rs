@@ -1,10 +1,14 @@
 pub mod account;
+pub mod announcement;
 pub mod auth;
 pub mod form;
 pub mod global_discord_webhook;
+pub mod markdown;
 pub mod minecraft_ban;
 pub mod notification;
 pub mod pagination;
 pub mod repository;
 pub mod search;
+pub mod submission_ip_blocklist;
+pub mod support_metrics;
 pub mod types;
@@ -0,0 +1,486 @@
+//! メッセージやコメントの本文で使える、制限された Markdown を扱います。
+//!
+//! 対応する記法は段落と改行、箇条書き (`-` / `*` / `+` と `1.`)、引用 (`>`)、
+//! コードブロック (```` ``` ````)、インラインコード、強調 (`**` / `__`)、
+//! 斜体 (`*` / `_`)、取り消し線 (`~~`)、`http(s)` のリンクです。
+//! 生の HTML タグは取り除き、それ以外の記法は文字どおりのテキストとして扱います。
+
+#[derive(Debug, PartialEq)]
+enum Block {
+    Paragraph(Vec<Inline>),
+    Quote(Vec<Inline>),
+    List {
+        ordered: bool,
+        items: Vec<Vec<Inline>>,
+    },
+    Code(String),
+}
+
+#[derive(Debug, PartialEq)]
+enum Inline {
+    Text(String),
+    Code(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Strikethrough(Vec<Inline>),
+    Link { label: Vec<Inline>, url: String },
+    LineBreak,
+}
+
+/// 本文を、そのまま埋め込める安全な HTML に変換します。
+///
+/// リンクには `rel="nofollow"` を付与し、生の HTML は取り除きます。
+pub fn render_html(source: &str) -> String {
+    parse_blocks(source)
+        .iter()
+        .map(|block| match block {
+            Block::Paragraph(inlines) => format!("<p>{}</p>", html_inlines(inlines)),
+            Block::Quote(inlines) => {
+                format!("<blockquote><p>{}</p></blockquote>", html_inlines(inlines))
+            }
+            Block::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                let items = items
+                    .iter()
+                    .map(|item| format!("<li>{}</li>", html_inlines(item)))
+                    .collect::<String>();
+                format!("<{tag}>{items}</{tag}>")
+            }
+            Block::Code(code) => format!("<pre><code>{}</code></pre>", escape_html(code)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 本文を、[`render_html`] と同じ解釈で Discord の Markdown に変換します。
+///
+/// Discord が独自に解釈する記法 (メンションやスポイラーなど) は、文字どおりに表示されるようエスケープします。
+pub fn render_discord(source: &str) -> String {
+    parse_blocks(source)
+        .iter()
+        .map(|block| match block {
+            Block::Paragraph(inlines) => discord_inlines(inlines),
+            Block::Quote(inlines) => discord_inlines(inlines)
+                .lines()
+                .map(|line| format!("> {line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Block::List { ordered, items } => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let marker = if *ordered {
+                        format!("{}.", index + 1)
+                    } else {
+                        "-".to_string()
+                    };
+                    format!("{marker} {}", discord_inlines(item))
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Block::Code(code) => format!("```\n{}\n```", code.replace("```", "``\u{200b}`")),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(item) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+    {
+        return Some((false, item));
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if !(1..=9).contains(&digits) {
+        return None;
+    }
+    line[digits..].strip_prefix(". ").map(|item| (true, item))
+}
+
+fn parse_blocks(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = source.lines();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_lines(paragraph)));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            let code = lines
+                .by_ref()
+                .take_while(|line| !line.trim_start().starts_with("```"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            blocks.push(Block::Code(code));
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if let Some(quoted) = trimmed.strip_prefix('>') {
+            flush(&mut paragraph, &mut blocks);
+            let quoted = quoted.strip_prefix(' ').unwrap_or(quoted);
+            match blocks.last_mut() {
+                Some(Block::Quote(inlines)) => {
+                    inlines.push(Inline::LineBreak);
+                    inlines.extend(parse_inlines(quoted));
+                }
+                _ => blocks.push(Block::Quote(parse_inlines(quoted))),
+            }
+        } else if let Some((ordered, item)) = list_item(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            match blocks.last_mut() {
+                Some(Block::List {
+                    ordered: list_ordered,
+                    items,
+                }) if *list_ordered == ordered => items.push(parse_inlines(item)),
+                _ => blocks.push(Block::List {
+                    ordered,
+                    items: vec![parse_inlines(item)],
+                }),
+            }
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+fn parse_lines(lines: &[&str]) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            inlines.push(Inline::LineBreak);
+        }
+        inlines.extend(parse_inlines(line));
+    }
+    inlines
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let lowercase = url.to_ascii_lowercase();
+    (lowercase.starts_with("https://") || lowercase.starts_with("http://"))
+        && url.len() > "https://".len()
+        && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// `<` から始まる生の HTML タグ (またはコメント) の長さを返します。
+fn html_tag_length(text: &str) -> Option<usize> {
+    if let Some(comment) = text.strip_prefix("<!--") {
+        return comment
+            .find("-->")
+            .map(|end| "<!--".len() + end + "-->".len());
+    }
+
+    let name = text[1..].strip_prefix('/').unwrap_or(&text[1..]);
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    text.find('>')
+        .filter(|end| !text[1..*end].contains('<'))
+        .map(|end| end + 1)
+}
+
+/// 閉じ区切りを探します。中身が空白で始まる・終わる場合は区切りとみなしません。
+fn closing_delimiter(rest: &str, delimiter: &str) -> Option<usize> {
+    let end = rest.find(delimiter)?;
+    let inner = &rest[..end];
+    (!inner.is_empty()
+        && !inner.starts_with(char::is_whitespace)
+        && !inner.ends_with(char::is_whitespace))
+    .then_some(end)
+}
+
+/// リンク先を閉じる `)` の位置を返します。リンク先の中の括弧は対応が取れている限り含めます。
+fn link_destination_end(destination: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (index, c) in destination.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(index),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn flush_text(inlines: &mut Vec<Inline>, plain: &mut String) {
+    if !plain.is_empty() {
+        inlines.push(Inline::Text(std::mem::take(plain)));
+    }
+}
+
+fn push(inlines: &mut Vec<Inline>, plain: &mut String, inline: Inline) {
+    flush_text(inlines, plain);
+    inlines.push(inline);
+}
+
+type InlineConstructor = fn(Vec<Inline>) -> Inline;
+
+/// 強調などの区切り文字と、その中身から作るインライン要素の組。長い区切りを先に試します。
+const DELIMITERS: [(&str, InlineConstructor); 5] = [
+    ("**", Inline::Strong),
+    ("__", Inline::Strong),
+    ("~~", Inline::Strikethrough),
+    ("*", Inline::Emphasis),
+    ("_", Inline::Emphasis),
+];
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut plain = String::new();
+    let mut index = 0;
+
+    while let Some(c) = text[index..].chars().next() {
+        let rest = &text[index..];
+        let previous = text[..index].chars().next_back();
+
+        if c == '\\'
+            && let Some(escaped) = rest[1..].chars().next().filter(char::is_ascii_punctuation)
+        {
+            plain.push(escaped);
+            index += 1 + escaped.len_utf8();
+            continue;
+        }
+
+        if c == '`'
+            && let Some(end) = rest[1..].find('`').filter(|end| *end > 0)
+        {
+            push(
+                &mut inlines,
+                &mut plain,
+                Inline::Code(rest[1..=end].to_string()),
+            );
+            index += end + 2;
+            continue;
+        }
+
+        if c == '['
+            && let Some(label_end) = rest.find("](")
+            && let Some(url_end) = link_destination_end(&rest[label_end + 2..])
+        {
+            let label = parse_inlines(&rest[1..label_end]);
+            let url = rest[label_end + 2..label_end + 2 + url_end].trim();
+            if is_safe_url(url) {
+                push(
+                    &mut inlines,
+                    &mut plain,
+                    Inline::Link {
+                        label,
+                        url: url.to_string(),
+                    },
+                );
+            } else {
+                // 危険なスキームのリンクは、表示テキストだけを残す
+                flush_text(&mut inlines, &mut plain);
+                inlines.extend(label);
+            }
+            index += label_end + 2 + url_end + 1;
+            continue;
+        }
+
+        if c == '<' {
+            if let Some(end) = rest[1..].find('>')
+                && is_safe_url(&rest[1..=end])
+            {
+                let url = rest[1..=end].to_string();
+                push(
+                    &mut inlines,
+                    &mut plain,
+                    Inline::Link {
+                        label: vec![Inline::Text(url.clone())],
+                        url,
+                    },
+                );
+                index += end + 2;
+                continue;
+            }
+            if let Some(length) = html_tag_length(rest) {
+                index += length;
+                continue;
+            }
+        }
+
+        if !previous.is_some_and(|previous| previous.is_alphanumeric())
+            && (rest.starts_with("https://") || rest.starts_with("http://"))
+        {
+            let candidate = rest
+                .find(|c: char| c.is_whitespace() || c == '<')
+                .map_or(rest, |end| &rest[..end]);
+            let url = candidate.trim_end_matches(['.', ',', ':', ';', '!', '?', ')', '\'', '"']);
+            if is_safe_url(url) {
+                push(
+                    &mut inlines,
+                    &mut plain,
+                    Inline::Link {
+                        label: vec![Inline::Text(url.to_string())],
+                        url: url.to_string(),
+                    },
+                );
+                index += url.len();
+                continue;
+            }
+        }
+
+        let delimited = DELIMITERS
+            .into_iter()
+            .find(|(delimiter, _)| rest.starts_with(delimiter))
+            .and_then(|(delimiter, inline)| {
+                // snake_case のような単語内の `_` は斜体とみなさない
+                if delimiter.starts_with('_') && previous.is_some_and(char::is_alphanumeric) {
+                    return None;
+                }
+                let end = closing_delimiter(&rest[delimiter.len()..], delimiter)?;
+                let after = &rest[delimiter.len() + end + delimiter.len()..];
+                if delimiter.starts_with('_') && after.starts_with(char::is_alphanumeric) {
+                    return None;
+                }
+                let inner = parse_inlines(&rest[delimiter.len()..delimiter.len() + end]);
+                Some((inline(inner), end + delimiter.len() * 2))
+            });
+        if let Some((inline, length)) = delimited {
+            push(&mut inlines, &mut plain, inline);
+            index += length;
+            continue;
+        }
+
+        plain.push(c);
+        index += c.len_utf8();
+    }
+
+    flush_text(&mut inlines, &mut plain);
+    inlines
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn html_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape_html(text),
+            Inline::Code(code) => format!("<code>{}</code>", escape_html(code)),
+            Inline::Strong(inner) => format!("<strong>{}</strong>", html_inlines(inner)),
+            Inline::Emphasis(inner) => format!("<em>{}</em>", html_inlines(inner)),
+            Inline::Strikethrough(inner) => format!("<del>{}</del>", html_inlines(inner)),
+            Inline::Link { label, url } => format!(
+                r#"<a href="{}" rel="nofollow">{}</a>"#,
+                escape_html(url),
+                html_inlines(label)
+            ),
+            Inline::LineBreak => "<br>".to_string(),
+        })
+        .collect()
+}
+
+fn escape_discord(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*'
+                | '_'
+                | '~'
+                | '`'
+                | '|'
+                | '<'
+                | '>'
+                | '#'
+                | '-'
+                | '['
+                | ']'
+                | '('
+                | ')'
+                | '@'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn discord_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape_discord(text),
+            Inline::Code(code) if code.contains('`') => format!("`` {code} ``"),
+            Inline::Code(code) => format!("`{code}`"),
+            Inline::Strong(inner) => format!("**{}**", discord_inlines(inner)),
+            Inline::Emphasis(inner) => format!("*{}*", discord_inlines(inner)),
+            Inline::Strikethrough(inner) => format!("~~{}~~", discord_inlines(inner)),
+            Inline::Link { label, url } => {
+                let url = url.replace('(', "%28").replace(')', "%29");
+                match label.as_slice() {
+                    [Inline::Text(text)] if *text == url => url,
+                    _ => format!("[{}]({url})", discord_inlines(label)),
+                }
+            }
+            Inline::LineBreak => "\n".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("**bold** and *em* and ~~del~~" => "<p><strong>bold</strong> and <em>em</em> and <del>del</del></p>"; "emphasis")]
+    #[test_case("snake_case_name and 2*3*4" => "<p>snake_case_name and 2<em>3</em>4</p>"; "underscores inside words")]
+    #[test_case("line1\nline2\n\nnext" => "<p>line1<br>line2</p>\n<p>next</p>"; "paragraphs")]
+    #[test_case("- a\n- b\n1. c" => "<ul><li>a</li><li>b</li></ul>\n<ol><li>c</li></ol>"; "lists")]
+    #[test_case("> quoted\n> more" => "<blockquote><p>quoted<br>more</p></blockquote>"; "quote")]
+    #[test_case("```rust\nlet a = 1 < 2;\n```" => "<pre><code>let a = 1 &lt; 2;</code></pre>"; "code block")]
+    #[test_case("`<b>` \\*not em\\*" => "<p><code>&lt;b&gt;</code> *not em*</p>"; "inline code and escapes")]
+    fn renders_the_supported_subset(source: &str) -> String {
+        render_html(source)
+    }
+
+    #[test_case("[site](https://example.com/a?b=1&c=\"2\")" => r#"<p><a href="https://example.com/a?b=1&amp;c=&quot;2&quot;" rel="nofollow">site</a></p>"#; "markdown link")]
+    #[test_case("see https://example.com." => r#"<p>see <a href="https://example.com" rel="nofollow">https://example.com</a>.</p>"#; "bare url")]
+    #[test_case("[wiki](https://example.com/Rust_(language)) end" => r#"<p><a href="https://example.com/Rust_(language)" rel="nofollow">wiki</a> end</p>"#; "parentheses in link")]
+    #[test_case("[click](javascript:alert(1))" => "<p>click</p>"; "unsafe scheme")]
+    #[test_case("<script>alert(1)</script><b onclick=\"x\">hi</b> 1 < 2 <!-- c -->" => "<p>alert(1)hi 1 &lt; 2 </p>"; "raw html")]
+    fn sanitizes_links_and_raw_html(source: &str) -> String {
+        render_html(source)
+    }
+
+    #[test]
+    fn discord_rendering_matches_html_interpretation() {
+        assert_eq!(
+            render_discord(
+                "**bold** <b>x</b> @everyone ||spoiler||\n[site](https://example.com) https://example.com/a_b\n\n- item\n> quote"
+            ),
+            "**bold** x \\@everyone \\|\\|spoiler\\|\\|\n[site](https://example.com) https://example.com/a_b\n\n- item\n\n> quote"
+        );
+    }
+}
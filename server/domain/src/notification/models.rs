@@ -7,9 +7,12 @@ use crate::{
     types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
 };
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum NotificationType {
     MessageReceived,
+    AnswerAssigned,
+    DrawWon,
+    Mentioned,
 }
 
 #[derive(Debug)]
@@ -52,7 +55,11 @@ impl NotificationPreference {
 impl NotificationPreference {
     pub fn is_enabled(&self, notification_type: &NotificationType) -> bool {
         match notification_type {
-            NotificationType::MessageReceived => self.is_send_message_notification,
+            // 担当割り当て・抽選の当選・メンションの通知も、メッセージ通知と同じ DM 通知設定に従う。
+            NotificationType::MessageReceived
+            | NotificationType::AnswerAssigned
+            | NotificationType::DrawWon
+            | NotificationType::Mentioned => self.is_send_message_notification,
         }
     }
 }
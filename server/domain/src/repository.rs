@@ -1,3 +1,4 @@
+pub mod announcement_repository;
 pub mod form;
 pub mod form_submission_restriction_repository;
 pub mod global_discord_webhook_repository;
@@ -5,6 +6,8 @@ pub mod health_check_repository;
 pub mod minecraft_ban_repository;
 pub mod notification_repository;
 pub mod search_repository;
+pub mod submission_ip_blocklist_repository;
+pub mod support_metrics_repository;
 pub mod user_repository;
 
 pub trait Repositories: Send + Sync {
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    announcement::{Announcement, AnnouncementId},
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read, Update},
+};
+
+#[automock]
+#[async_trait]
+pub trait AnnouncementRepository: Send + Sync + 'static {
+    /// お知らせを固定表示のものから順に、それぞれ公開開始日時の新しい順で返す。
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<Announcement, Read>>, Error>;
+    async fn get(
+        &self,
+        id: AnnouncementId,
+    ) -> Result<Option<AuthorizationGuard<Announcement, Read>>, Error>;
+    async fn create(&self, announcement: Allowed<Announcement, Create>) -> Result<(), Error>;
+    async fn update(&self, announcement: Allowed<Announcement, Update>) -> Result<(), Error>;
+    async fn delete(&self, announcement: Allowed<Announcement, Delete>) -> Result<(), Error>;
+    async fn size(&self) -> Result<u32, Error>;
+}
@@ -1,8 +1,17 @@
 pub mod active_form_repository;
+pub mod answer_draw_repository;
 pub mod answer_entry_repository;
 pub mod answer_label_repository;
 pub mod answer_relation_repository;
 pub mod archived_form_repository;
 pub mod comment_thread_repository;
+pub mod content_filter_repository;
 pub mod form_label_repository;
+pub mod mention_repository;
+pub mod message_attachment_storage;
+pub mod message_read_repository;
+pub mod message_template_repository;
 pub mod message_thread_repository;
+pub mod temporary_answer_abuse_repository;
+pub mod temporary_answer_access_repository;
+pub mod temporary_answer_claim_repository;
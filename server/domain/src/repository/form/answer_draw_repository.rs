@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::{
+        draw::{AnswerDraw, AnswerDrawId},
+        models::ActiveForm,
+    },
+    types::authorization_guard::{Allowed, Create, Read},
+};
+
+#[automock]
+#[async_trait]
+pub trait AnswerDrawRepository: Send + Sync + 'static {
+    /// 抽選の条件・シード・候補・当選者をまとめて保存する。
+    async fn save(&self, draw: &Allowed<AnswerDraw, Create>) -> Result<(), Error>;
+    /// フォームで行われた抽選を、新しいものから順に返す。
+    async fn list_by_form(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+    ) -> Result<Vec<Allowed<AnswerDraw, Read>>, Error>;
+    async fn get(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        draw_id: AnswerDrawId,
+    ) -> Result<Option<Allowed<AnswerDraw, Read>>, Error>;
+}
@@ -5,11 +5,15 @@ use mockall::automock;
 use crate::{
     form::{
         answer::{
-            AnswerEntry, AnswerId, AnswerPagePosition, AnswerStatus, AnswerStatusChange,
-            AnswerStatusHistoryEntry, AnswerStatusHistoryPagePosition, AnswerTitleHistoryEntry,
-            AnswerTitleHistoryPagePosition,
+            AnswerAssignmentHistoryEntry, AnswerAssignmentHistoryPagePosition, AnswerEntry,
+            AnswerId, AnswerListFilter, AnswerMoveHistoryEntry, AnswerMoveHistoryPagePosition,
+            AnswerPagePosition, AnswerRelation, AnswerStatistics, AnswerStatisticsScope,
+            AnswerStatusChange, AnswerStatusHistoryEntry, AnswerStatusHistoryPagePosition,
+            AnswerTitleHistoryEntry, AnswerTitleHistoryPagePosition,
         },
         models::ActiveForm,
+        temporary_answer_access::{TemporaryAnswerAccess, TemporaryAnswerAccessToken},
+        temporary_answer_claim::{TemporaryAnswerClaimCode, TemporaryAnswerClaimTicket},
     },
     pagination::{Page, PageRequest},
     types::authorization_guard::{Allowed, Create, Read, Update},
@@ -37,24 +41,49 @@ pub trait AnswerEntryRepository: Send + Sync + 'static {
         &self,
         form: &Allowed<ActiveForm, Read>,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error>;
     async fn list_all(
         &self,
         forms: &[Allowed<ActiveForm, Read>],
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error>;
     async fn post(
         &self,
         form: &Allowed<ActiveForm, Read>,
         answer_entry: &Allowed<AnswerEntry, Create>,
     ) -> Result<(), Error>;
+    /// [`Self::post`] と同じく一時回答を保存し、同じトランザクションでアクセスリンクと引き継ぎコードも保存する。
+    ///
+    /// トークンとコードの平文は保存しない。
+    async fn post_temporary(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        answer_entry: &Allowed<AnswerEntry, Create>,
+        access: Allowed<TemporaryAnswerAccess, Create>,
+        access_token: &TemporaryAnswerAccessToken,
+        claim_ticket: Allowed<TemporaryAnswerClaimTicket, Create>,
+        claim_code: &TemporaryAnswerClaimCode,
+    ) -> Result<(), Error>;
+    /// 回答のメタ情報・担当者・対応期限を保存し、状態・タイトル・担当者の変更履歴を記録する。
+    ///
+    /// 対応期限が変わった場合は、期限超過の通知記録も取り消す。
     async fn update(
         &self,
         form: &Allowed<ActiveForm, Update>,
         answer_entry: &Allowed<AnswerEntry, Update>,
     ) -> Result<Option<AnswerStatusChange>, Error>;
+    /// [`Self::update`] と同じく回答を保存し、同じトランザクションで回答間の関連も保存する。
+    ///
+    /// `relation` は `answer_entry` と `related` を結ぶものでなければならない。
+    async fn update_with_relation(
+        &self,
+        form: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+        relation: AnswerRelation,
+        related: &Allowed<AnswerEntry, Update>,
+    ) -> Result<Option<AnswerStatusChange>, Error>;
     async fn history(
         &self,
         answer: &Allowed<AnswerEntry, Read>,
@@ -65,8 +94,43 @@ pub trait AnswerEntryRepository: Send + Sync + 'static {
         answer: &Allowed<AnswerEntry, Read>,
         request: PageRequest<AnswerTitleHistoryPagePosition>,
     ) -> Result<Page<Allowed<AnswerTitleHistoryEntry, Read>, AnswerTitleHistoryPagePosition>, Error>;
-    /// 回答 (`answers`) の件数を返す。
+    async fn assignment_history(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+        request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<
+        Page<Allowed<AnswerAssignmentHistoryEntry, Read>, AnswerAssignmentHistoryPagePosition>,
+        Error,
+    >;
+    /// 回答を `from` のフォームから `to` のフォームへ移動し、移動の履歴を記録する。
+    ///
+    /// 回答内容の質問の付け替えと、回答関連の端点の更新も同じトランザクションで行う。
+    async fn move_to_form(
+        &self,
+        from: &Allowed<ActiveForm, Update>,
+        to: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+    ) -> Result<(), Error>;
+    async fn move_history(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+        request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerMoveHistoryEntry, Read>, AnswerMoveHistoryPagePosition>, Error>;
+    /// 期限を過ぎた回答について、期限超過の通知を送ったことを記録する。
+    ///
+    /// 同じ期限に対して既に記録済みの場合や、回答が期限を過ぎていない場合は `false` を返す。
+    async fn mark_overdue_notified(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+    ) -> Result<bool, Error>;
+    /// フォームの承認済みの回答を `scope` の範囲で集計する。
+    async fn statistics(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        scope: AnswerStatisticsScope,
+    ) -> Result<AnswerStatistics, Error>;
+    /// 承認済みの回答 (`answers`) の件数を返す。確認待ちや却下された回答は検索に載らないため数えない。
     async fn size(&self) -> Result<u32, Error>;
-    /// 回答本文 (`real_answers`) の件数を返す。
+    /// 承認済みの回答の回答本文 (`real_answers`) の件数を返す。
     async fn content_size(&self) -> Result<u32, Error>;
 }
@@ -29,7 +29,8 @@ pub trait AnswerRelationRepository: Send + Sync + 'static {
         source: &Allowed<ArchivedAnswerEntry, Read>,
     ) -> Result<Vec<Allowed<ReadableAnswerRelation, Read>>, Error>;
 
-    /// 関係を追加します。同じ正規化済み関係が存在する場合は成功として扱います。
+    /// 関係を追加します。同じ二つの回答の間に関係が存在する場合は、種類と向きを
+    /// `relation` のものに置き換えます。
     async fn add(
         &self,
         relation: AnswerRelation,
@@ -37,7 +38,7 @@ pub trait AnswerRelationRepository: Send + Sync + 'static {
         target: &Allowed<AnswerEntry, Update>,
     ) -> Result<(), Error>;
 
-    /// 関係を解除します。関係が存在しない場合は成功として扱います。
+    /// 二つの回答の間の関係を種類によらず解除します。関係が存在しない場合は成功として扱います。
     async fn remove(
         &self,
         relation: AnswerRelation,
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::{
+        content_filter::{
+            ContentFilterEnforcement, ContentFilterFlag, ContentFilterFlagId, ContentFilterRule,
+            ContentFilterRuleId,
+        },
+        models::FormId,
+    },
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read},
+};
+
+#[automock]
+#[async_trait]
+pub trait ContentFilterRepository: Send + Sync + 'static {
+    async fn list_rules(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterRule, Read>>, Error>;
+    async fn get_rule(
+        &self,
+        id: ContentFilterRuleId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterRule, Read>>, Error>;
+    async fn create_rule(&self, rule: Allowed<ContentFilterRule, Create>) -> Result<(), Error>;
+    async fn delete_rule(&self, rule: Allowed<ContentFilterRule, Delete>) -> Result<(), Error>;
+    async fn enforce(
+        &self,
+        enforcement: Allowed<ContentFilterEnforcement, Create>,
+    ) -> Result<(), Error>;
+    async fn list_flags(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterFlag, Read>>, Error>;
+    async fn get_flag(
+        &self,
+        id: ContentFilterFlagId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterFlag, Read>>, Error>;
+    async fn delete_flag(&self, flag: Allowed<ContentFilterFlag, Delete>) -> Result<(), Error>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::mention::Mention,
+    types::authorization_guard::{Allowed, Create},
+};
+
+#[automock]
+#[async_trait]
+pub trait MentionRepository: Send + Sync + 'static {
+    async fn create(&self, mentions: Vec<Allowed<Mention, Create>>) -> Result<(), Error>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::form::message::MessageAttachment;
+
+/// メッセージ添付ファイルの中身を保存するストレージ。
+#[automock]
+#[async_trait]
+pub trait MessageAttachmentStorage: Send + Sync + 'static {
+    async fn put(&self, attachment: &MessageAttachment, content: Vec<u8>) -> Result<(), Error>;
+    async fn get(&self, attachment: &MessageAttachment) -> Result<Vec<u8>, Error>;
+    /// 保存した中身を削除します。中身が存在しない場合も成功として扱います。
+    async fn delete(&self, attachment: &MessageAttachment) -> Result<(), Error>;
+}
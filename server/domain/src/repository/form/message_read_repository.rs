@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::{
+        message_read::{MessageReadReceipt, UnreadMessageCountTarget},
+        message_thread::MessageThread,
+    },
+    types::authorization_guard::{Allowed, Create, Read},
+};
+
+#[automock]
+#[async_trait]
+pub trait MessageReadRepository: Send + Sync + 'static {
+    /// 既読位置を保存する。保存済みの既読位置より古いメッセージは既読位置を戻さない。
+    async fn mark_as_read(&self, receipt: Allowed<MessageReadReceipt, Create>)
+    -> Result<(), Error>;
+    async fn receipts(
+        &self,
+        message_thread: &Allowed<MessageThread, Read>,
+    ) -> Result<Vec<Allowed<MessageReadReceipt, Read>>, Error>;
+    /// 各対象について、閲覧者以外が送信した、閲覧者の既読位置より後のメッセージの数を返す。
+    ///
+    /// 未読のメッセージがない対象も 0 として含める。回答の一覧でも一度の問い合わせで数えられるよう、
+    /// 対象をまとめて受け取る。
+    async fn unread_message_counts(
+        &self,
+        targets: &[UnreadMessageCountTarget],
+    ) -> Result<HashMap<UnreadMessageCountTarget, u32>, Error>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::message_template::{MessageTemplate, MessageTemplateId},
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read, Update},
+};
+
+#[automock]
+#[async_trait]
+pub trait MessageTemplateRepository: Send + Sync + 'static {
+    async fn create(&self, template: Allowed<MessageTemplate, Create>) -> Result<(), Error>;
+    /// テンプレートを名前順に返す。
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<MessageTemplate, Read>>, Error>;
+    async fn get(
+        &self,
+        id: MessageTemplateId,
+    ) -> Result<Option<AuthorizationGuard<MessageTemplate, Read>>, Error>;
+    async fn update(&self, template: Allowed<MessageTemplate, Update>) -> Result<(), Error>;
+    async fn delete(&self, template: Allowed<MessageTemplate, Delete>) -> Result<(), Error>;
+}
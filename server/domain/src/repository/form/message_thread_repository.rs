@@ -9,7 +9,8 @@ use crate::{
         message::{
             DeletedMessage, Message, MessageHistoryEntry, MessageHistoryPagePosition, MessagePost,
         },
-        message_thread::MessageThread,
+        message_thread::{MessageThread, MessageThreadReopening},
+        models::ActiveForm,
     },
     pagination::{Page, PageRequest},
     types::authorization_guard::{Allowed, Create, Read, Update},
@@ -18,8 +19,10 @@ use crate::{
 #[automock]
 #[async_trait]
 pub trait MessageThreadRepository: Send + Sync + 'static {
+    /// フォームのロック設定と再開状況を反映した Thread を組み立てます。
     async fn get_for_answer(
         &self,
+        form: &Allowed<ActiveForm, Read>,
         answer: &Allowed<AnswerEntry, Read>,
     ) -> Result<Allowed<MessageThread, Read>, Error>;
     async fn append(&self, post: Allowed<MessagePost, Create>) -> Result<(), Error>;
@@ -29,6 +32,8 @@ pub trait MessageThreadRepository: Send + Sync + 'static {
         updated_at: DateTime<Utc>,
     ) -> Result<(), Error>;
     async fn delete_message(&self, message: Allowed<DeletedMessage, Create>) -> Result<(), Error>;
+    async fn reopen(&self, reopening: Allowed<MessageThreadReopening, Create>)
+    -> Result<(), Error>;
     async fn history(
         &self,
         message_thread: &Allowed<MessageThread, Read>,
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::{
+        answer::AnswerId,
+        temporary_answer_abuse::{TemporaryAnswerAbuseMetadata, TemporaryAnswerSubmissionRecord},
+    },
+    types::authorization_guard::{Allowed, Create},
+};
+
+#[automock]
+#[async_trait]
+pub trait TemporaryAnswerAbuseRepository: Send + Sync + 'static {
+    /// 送信元はハッシュにしてから保存する。
+    async fn record(
+        &self,
+        record: Allowed<TemporaryAnswerSubmissionRecord, Create>,
+    ) -> Result<(), Error>;
+    /// 送信元を記録する前に受け付けた回答では `None` を返す。
+    async fn find_by_answer_id(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<Option<TemporaryAnswerAbuseMetadata>, Error>;
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::temporary_answer_access::{
+        TemporaryAnswerAccess, TemporaryAnswerAccessRevocation, TemporaryAnswerAccessToken,
+    },
+    types::authorization_guard::{Allowed, Update},
+};
+
+#[automock]
+#[async_trait]
+pub trait TemporaryAnswerAccessRepository: Send + Sync + 'static {
+    /// トークンに対応するアクセスを返す。取り消されたアクセスも返す。
+    async fn find_by_token(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+    ) -> Result<Option<TemporaryAnswerAccess>, Error>;
+    /// 回答に発行した、取り消されていないアクセスをすべて取り消す。
+    async fn revoke(
+        &self,
+        revocation: Allowed<TemporaryAnswerAccessRevocation, Update>,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    form::temporary_answer_claim::{
+        TemporaryAnswerClaim, TemporaryAnswerClaimCode, TemporaryAnswerClaimTicket,
+    },
+    types::authorization_guard::{Allowed, Create},
+};
+
+#[automock]
+#[async_trait]
+pub trait TemporaryAnswerClaimRepository: Send + Sync + 'static {
+    /// コードに対応する引き継ぎの状態を返す。使用済みのコードも返す。
+    async fn find_by_code(
+        &self,
+        code: &TemporaryAnswerClaimCode,
+    ) -> Result<Option<TemporaryAnswerClaimTicket>, Error>;
+    /// 回答者をアカウントに切り替え、コードを使用済みにする。
+    ///
+    /// 別のリクエストが先にコードを使っていた場合は何もせず `false` を返す。
+    async fn claim(&self, claim: Allowed<TemporaryAnswerClaim, Create>) -> Result<bool, Error>;
+}
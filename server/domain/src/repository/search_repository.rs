@@ -3,10 +3,14 @@ use errors::Error;
 use mockall::automock;
 
 use crate::{
-    form::{answer::AnswerStatus, models::FormId},
+    form::{
+        answer::{AnswerId, AnswerStatus},
+        models::FormId,
+    },
     search::models::{
-        AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit, FormLabelSearchHit, FormSearchHit,
-        NumberOfRecordsPerAggregate, SearchableFieldsWithOperation, UserSearchHit,
+        AnnouncementSearchHit, AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit,
+        FormLabelSearchHit, FormSearchHit, NumberOfRecordsPerAggregate,
+        SearchableFieldsWithOperation, SimilarAnswerLimit, UserSearchHit,
     },
 };
 
@@ -31,7 +35,18 @@ pub trait SearchRepository: Send + Sync + 'static {
         form_id: Option<FormId>,
         status: Option<AnswerStatus>,
     ) -> Result<Vec<AnswerSearchHit>, Error>;
+    /// `form_id` のフォームから `query` に類似する回答を検索する。`exclude` の回答はヒットに含めない。
+    ///
+    /// タイトルと回答内容のそれぞれから最大 `limit` 件ずつ取得するため、返り値は `limit` 件を超えうる。
+    async fn search_similar_answers(
+        &self,
+        query: &str,
+        form_id: FormId,
+        exclude: AnswerId,
+        limit: SimilarAnswerLimit,
+    ) -> Result<Vec<AnswerSearchHit>, Error>;
     async fn search_comments(&self, query: &str) -> Result<Vec<CommentSearchHit>, Error>;
+    async fn search_announcements(&self, query: &str) -> Result<Vec<AnnouncementSearchHit>, Error>;
     async fn sync_search_engine(&self, data: &[SearchableFieldsWithOperation])
     -> Result<(), Error>;
     async fn fetch_search_engine_stats(&self) -> Result<NumberOfRecordsPerAggregate, Error>;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    submission_ip_blocklist::{SubmissionIpBlock, SubmissionIpBlockId},
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read},
+};
+
+#[automock]
+#[async_trait]
+pub trait SubmissionIpBlocklistRepository: Send + Sync + 'static {
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<SubmissionIpBlock, Read>>, Error>;
+    async fn get(
+        &self,
+        id: SubmissionIpBlockId,
+    ) -> Result<Option<AuthorizationGuard<SubmissionIpBlock, Read>>, Error>;
+    async fn create(&self, block: Allowed<SubmissionIpBlock, Create>) -> Result<(), Error>;
+    async fn delete(&self, block: Allowed<SubmissionIpBlock, Delete>) -> Result<(), Error>;
+}
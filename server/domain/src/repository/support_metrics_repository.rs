@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use errors::Error;
+use mockall::automock;
+
+use crate::{
+    support_metrics::{SupportMetrics, SupportMetricsPeriod},
+    types::authorization_guard::{AuthorizationGuard, Read},
+};
+
+#[automock]
+#[async_trait]
+pub trait SupportMetricsRepository: Send + Sync + 'static {
+    async fn fetch(
+        &self,
+        period: SupportMetricsPeriod,
+    ) -> Result<AuthorizationGuard<SupportMetrics, Read>, Error>;
+}
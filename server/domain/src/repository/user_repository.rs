@@ -22,6 +22,11 @@ pub trait UserRepository: Send + Sync + 'static {
         &self,
         uuids: Vec<Uuid>,
     ) -> Result<Vec<AuthorizationGuard<AccountUser, Read>>, Error>;
+    /// ユーザー名 (大文字と小文字は区別しない) に一致するユーザーを取得します。
+    async fn find_by_names(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Vec<AuthorizationGuard<AccountUser, Read>>, Error>;
     async fn upsert_user(&self, user: Allowed<AccountUser, Create>) -> Result<(), Error>;
     async fn patch_user_role(&self, user: Allowed<AccountUser, Update>) -> Result<(), Error>;
     async fn create_user_group(&self, group: Allowed<UserGroup, Create>) -> Result<(), Error>;
@@ -1,4 +1,5 @@
 use crate::account::models::UserId;
+use crate::announcement::AnnouncementId;
 use crate::form::answer::FormAnswerContentId;
 use crate::form::{
     answer::{AnswerId, AnswerLabelId, AnswerStatus, AnswerTitle},
@@ -28,6 +29,7 @@ pub enum SearchableFields {
     LabelForFormAnswers(LabelForFormAnswers),
     LabelForForms(LabelForForms),
     Users(Users),
+    Announcements(Announcements),
 }
 
 pub type SearchableFieldsWithOperation = (SearchableFields, Operation);
@@ -71,6 +73,29 @@ pub struct AnswerSearchHit {
     pub answer_id: AnswerId,
 }
 
+/// 類似回答の提案で返す最大件数です。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SimilarAnswerLimit(u32);
+
+impl SimilarAnswerLimit {
+    pub const DEFAULT: u32 = 5;
+    pub const MAX: u32 = 20;
+
+    pub fn try_new(value: u32) -> Option<Self> {
+        (1..=Self::MAX).contains(&value).then_some(Self(value))
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for SimilarAnswerLimit {
+    fn default() -> Self {
+        Self(Self::DEFAULT)
+    }
+}
+
 #[derive(Debug)]
 pub struct CommentSearchHit {
     pub comment_id: CommentId,
@@ -115,6 +140,18 @@ pub struct Users {
     pub name: String,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Announcements {
+    pub id: AnnouncementId,
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub struct AnnouncementSearchHit {
+    pub announcement_id: AnnouncementId,
+}
+
 #[derive(Getters, Default, Debug)]
 pub struct NumberOfRecordsPerAggregate {
     pub form_meta_data: NumberOfRecords,
@@ -124,6 +161,7 @@ pub struct NumberOfRecordsPerAggregate {
     pub label_for_form_answers: NumberOfRecords,
     pub label_for_forms: NumberOfRecords,
     pub users: NumberOfRecords,
+    pub announcements: NumberOfRecords,
 }
 
 impl NumberOfRecordsPerAggregate {
@@ -136,6 +174,7 @@ impl NumberOfRecordsPerAggregate {
             label_for_form_answers,
             label_for_forms,
             users,
+            announcements,
         } = self;
 
         let Self {
@@ -146,6 +185,7 @@ impl NumberOfRecordsPerAggregate {
             label_for_form_answers: other_label_for_form_answers,
             label_for_forms: other_label_for_forms,
             users: other_users,
+            announcements: other_announcements,
         } = other;
 
         let form_meta_data_sync_rate = SyncRate::new(NonNegativeF32::try_new(
@@ -170,6 +210,9 @@ impl NumberOfRecordsPerAggregate {
         let users_sync_rate = SyncRate::new(NonNegativeF32::try_new(
             users.0 as f32 / other_users.0 as f32,
         )?);
+        let announcements_sync_rate = SyncRate::new(NonNegativeF32::try_new(
+            announcements.0 as f32 / other_announcements.0 as f32,
+        )?);
 
         Ok(SyncRate::average(&[
             form_meta_data_sync_rate,
@@ -179,6 +222,7 @@ impl NumberOfRecordsPerAggregate {
             label_for_form_answers_sync_rate,
             label_for_forms_sync_rate,
             users_sync_rate,
+            announcements_sync_rate,
         ]))
     }
 }
@@ -0,0 +1,210 @@
+use std::{
+    fmt::{Display, Formatter},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use domain_derive::UnsafeFromRawParts;
+use errors::domain::DomainError;
+use types::non_empty_string::NonEmptyString;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::is_administrator,
+    types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
+};
+
+/// 送信を拒否する IP アドレスの範囲 (CIDR 表記)。
+///
+/// `/` を省略した単一のアドレスは、全ビットを使う範囲として扱う。
+/// ネットワーク部より後ろのビットが立っている範囲は、意図と違う範囲を塞がないよう受け付けない。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockedIpRange {
+    network: IpAddr,
+    prefix_length: u8,
+}
+
+impl BlockedIpRange {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        ip.is_ipv4() == self.network.is_ipv4() && masked(ip, self.prefix_length) == self.network
+    }
+}
+
+fn max_prefix_length(ip: IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn masked(ip: IpAddr, prefix_length: u8) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix_length))
+                .unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(ip) & mask))
+        }
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix_length))
+                .unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(ip) & mask))
+        }
+    }
+}
+
+impl FromStr for BlockedIpRange {
+    type Err = DomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (network, prefix_length) = match value.trim().split_once('/') {
+            Some((network, prefix_length)) => {
+                let network = network
+                    .parse::<IpAddr>()
+                    .map_err(|_| DomainError::InvalidIpRange)?;
+                let prefix_length = prefix_length
+                    .parse::<u8>()
+                    .map_err(|_| DomainError::InvalidIpRange)?;
+                (network, prefix_length)
+            }
+            None => {
+                let network = value
+                    .trim()
+                    .parse::<IpAddr>()
+                    .map_err(|_| DomainError::InvalidIpRange)?
+                    .to_canonical();
+                (network, max_prefix_length(network))
+            }
+        };
+
+        if prefix_length > max_prefix_length(network) || masked(network, prefix_length) != network {
+            return Err(DomainError::InvalidIpRange);
+        }
+
+        Ok(Self {
+            network,
+            prefix_length,
+        })
+    }
+}
+
+impl TryFrom<String> for BlockedIpRange {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for BlockedIpRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.prefix_length == max_prefix_length(self.network) {
+            write!(f, "{}", self.network)
+        } else {
+            write!(f, "{}/{}", self.network, self.prefix_length)
+        }
+    }
+}
+
+pub type SubmissionIpBlockId = types::Id<SubmissionIpBlock>;
+
+/// 管理者が登録した、一時回答などの未ログインでの送信を拒否する IP アドレスの範囲。
+#[derive(UnsafeFromRawParts, Getters, Clone, Debug, PartialEq)]
+pub struct SubmissionIpBlock {
+    id: SubmissionIpBlockId,
+    range: BlockedIpRange,
+    reason: Option<NonEmptyString>,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl SubmissionIpBlock {
+    pub fn new(
+        range: BlockedIpRange,
+        reason: Option<NonEmptyString>,
+        created_by: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: SubmissionIpBlockId::new(),
+            range,
+            reason,
+            created_by,
+            created_at,
+        }
+    }
+
+    pub fn blocks(&self, ip: IpAddr) -> bool {
+        self.range.contains(ip)
+    }
+}
+
+impl AuthorizationRole for SubmissionIpBlock {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for SubmissionIpBlock {
+    fn can_create(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+
+    /// 送信を受け付ける前の確認のため、システムも読める。
+    fn can_read(&self, actor: &Actor) -> bool {
+        matches!(actor, Actor::System) || is_administrator(actor)
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, actor: &Actor) -> bool {
+        is_administrator(actor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(value: &str) -> BlockedIpRange {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn range_contains_addresses_in_its_network() {
+        assert!(range("203.0.113.0/24").contains("203.0.113.200".parse().unwrap()));
+        assert!(!range("203.0.113.0/24").contains("203.0.114.1".parse().unwrap()));
+        assert!(range("203.0.113.5").contains("::ffff:203.0.113.5".parse().unwrap()));
+        assert!(range("2001:db8::/32").contains("2001:db8:1::1".parse().unwrap()));
+        assert!(!range("2001:db8::/32").contains("203.0.113.5".parse().unwrap()));
+        assert!(range("0.0.0.0/0").contains("198.51.100.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn range_rejects_host_bits_and_invalid_prefixes() {
+        for invalid in [
+            "203.0.113.5/24",
+            "203.0.113.0/33",
+            "2001:db8::/129",
+            "example.com",
+            "203.0.113.0/",
+        ] {
+            assert_eq!(
+                invalid.parse::<BlockedIpRange>(),
+                Err(DomainError::InvalidIpRange)
+            );
+        }
+    }
+
+    #[test]
+    fn single_address_is_displayed_without_prefix() {
+        assert_eq!(range("203.0.113.5").to_string(), "203.0.113.5");
+        assert_eq!(range("203.0.113.0/24").to_string(), "203.0.113.0/24");
+        assert_eq!(range("2001:db8::/32").to_string(), "2001:db8::/32");
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_getters::Getters;
+use errors::domain::DomainError;
+
+use crate::{
+    account::models::UserId,
+    auth::Actor,
+    form::{is_administrator, models::FormId},
+    types::authorization_guard::{AuthorizationGuardDefinitions, AuthorizationRole, SelfGuarded},
+};
+
+/// 運営対応の集計対象とする期間です。`from` を含み `to` を含みません。
+///
+/// 集計は期間内の回答をすべて走査するため、長さは [`Self::MAX_LENGTH`] までに制限します。
+#[derive(Getters, Clone, Copy, Debug, PartialEq)]
+pub struct SupportMetricsPeriod {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+impl SupportMetricsPeriod {
+    pub const MAX_LENGTH: TimeDelta = TimeDelta::days(366);
+
+    pub fn try_new(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Self, DomainError> {
+        if from >= to {
+            return Err(DomainError::InvalidEntity {
+                message: "support metrics period must end after it starts".to_string(),
+            });
+        }
+        if to - from > Self::MAX_LENGTH {
+            return Err(DomainError::InvalidEntity {
+                message: "support metrics period must not exceed 366 days".to_string(),
+            });
+        }
+
+        Ok(Self { from, to })
+    }
+}
+
+/// フォームごとの未完了 (`COMPLETED` 以外) の回答件数です。
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpenAnswerCount {
+    pub form_id: FormId,
+    pub form_title: String,
+    pub count: u32,
+}
+
+/// 期間内に運営メンバーが行った対応の件数です。
+///
+/// `handled_answers` は完了への変更・メッセージ・コメントのいずれかを行った回答の件数です。
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffActivity {
+    pub user_id: UserId,
+    pub name: String,
+    pub handled_answers: u32,
+    pub completed_answers: u32,
+    pub messages: u32,
+    pub comments: u32,
+}
+
+/// 運営対応の状況をまとめた集計結果です。
+///
+/// 所要時間の中央値は、期間内に送信された回答のうち対象の対応が行われたものだけから求め、
+/// 該当する回答がなければ `None` になります。
+#[derive(Getters, Clone, Debug, PartialEq)]
+pub struct SupportMetrics {
+    period: SupportMetricsPeriod,
+    median_first_response: Option<TimeDelta>,
+    median_time_to_completion: Option<TimeDelta>,
+    open_answers: Vec<OpenAnswerCount>,
+    staff_activities: Vec<StaffActivity>,
+}
+
+impl SupportMetrics {
+    pub fn new(
+        period: SupportMetricsPeriod,
+        first_response_times: Vec<TimeDelta>,
+        completion_times: Vec<TimeDelta>,
+        open_answers: Vec<OpenAnswerCount>,
+        staff_activities: Vec<StaffActivity>,
+    ) -> Self {
+        Self {
+            period,
+            median_first_response: median(first_response_times),
+            median_time_to_completion: median(completion_times),
+            open_answers,
+            staff_activities,
+        }
+    }
+}
+
+/// 要素数が偶数のときは中央の 2 つの平均を中央値とします。
+fn median(mut durations: Vec<TimeDelta>) -> Option<TimeDelta> {
+    durations.sort_unstable();
+
+    let middle = durations.len() / 2;
+    match durations.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(durations[middle]),
+        _ => Some((durations[middle - 1] + durations[middle]) / 2),
+    }
+}
+
+/// 集計を始める前に、`actor` が集計結果を読めるかを確かめます。
+///
+/// 集計の負荷を閲覧権限のない利用者に発生させないため、取得より先に呼び出します。
+pub fn ensure_support_metrics_readable(actor: &Actor) -> Result<(), DomainError> {
+    if is_administrator(actor) {
+        Ok(())
+    } else {
+        Err(DomainError::Forbidden)
+    }
+}
+
+impl AuthorizationRole for SupportMetrics {
+    type Role = SelfGuarded;
+}
+
+impl AuthorizationGuardDefinitions for SupportMetrics {
+    fn can_create(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_read(&self, actor: &Actor) -> bool {
+        ensure_support_metrics_readable(actor).is_ok()
+    }
+
+    fn can_update(&self, _actor: &Actor) -> bool {
+        false
+    }
+
+    fn can_delete(&self, _actor: &Actor) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_must_end_after_it_starts() {
+        let now = Utc::now();
+
+        assert!(SupportMetricsPeriod::try_new(now, now + TimeDelta::days(1)).is_ok());
+        assert!(matches!(
+            SupportMetricsPeriod::try_new(now, now),
+            Err(DomainError::InvalidEntity { .. })
+        ));
+    }
+
+    #[test]
+    fn median_averages_the_two_middle_values_for_even_counts() {
+        let minutes = |values: &[i64]| values.iter().copied().map(TimeDelta::minutes).collect();
+
+        assert_eq!(median(Vec::new()), None);
+        assert_eq!(median(minutes(&[30, 10, 20])), Some(TimeDelta::minutes(20)));
+        assert_eq!(
+            median(minutes(&[40, 10, 30, 20])),
+            Some(TimeDelta::seconds(25 * 60))
+        );
+    }
+}
@@ -2,13 +2,14 @@ use std::{future::IntoFuture, net::SocketAddr, sync::Arc};
 
 use axum::{
     Json, Router,
+    extract::DefaultBodyLimit,
     http::{
         HeaderName, Method, StatusCode,
         header::{AUTHORIZATION, CONTENT_TYPE, LOCATION},
     },
     middleware,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use axum_tracing_opentelemetry::middleware::{OtelAxumLayer, OtelInResponseLayer};
 use common::config::{ENV, HTTP};
@@ -20,12 +21,18 @@ use opentelemetry::trace::TracerProvider as _;
 use presentation::api::global_discord_webhook::start_global_discord_webhook_worker;
 use presentation::api::notificator_impl::DiscordNotificator;
 use presentation::auth::{auth, optional_auth};
+use presentation::handlers::form::answer_draw_handler::create_answer_draw_handler;
+use presentation::handlers::form::answer_handler::{
+    start_watch_overdue_answers, update_answer_assignee_handler,
+};
+use presentation::handlers::form::comment_handler::post_form_comment;
 use presentation::handlers::form::message_handler::{
-    RealInfrastructureRepositoryWithNotificator, post_message_handler,
+    POST_MESSAGE_BODY_LIMIT, RealInfrastructureRepositoryWithNotificator, post_message_handler,
 };
 use presentation::handlers::search_handler::{
     initialize_search_engine, start_sync, start_watch_out_of_sync,
 };
+use presentation::ip_blocklist::{IpBlocklistState, middleware as ip_blocklist_middleware};
 use presentation::rate_limit::{RateLimitState, middleware as rate_limit_middleware};
 use presentation::turnstile::{TurnstileState, middleware as turnstile_middleware};
 use resource::rate_limit::ValkeyRateLimitStore;
@@ -49,6 +56,7 @@ use utoipa_swagger_ui::SwaggerUi;
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let turnstile_config = TurnstileConfig::from_environment()?;
+    resource::storage::validate_attachment_storage_config()?;
     let tracer_provider = telemetry::init_tracer_provider();
 
     // SQL 文の出力 (bind 値を含みうる) はログへ出さない
@@ -113,6 +121,8 @@ async fn main() -> anyhow::Result<()> {
             anyhow::anyhow!("invalid Valkey rate-limit configuration: {error:?}")
         })?);
     let proxy_secret = std::env::var("SEICHI_PROXY_SECRET").ok();
+    let ip_blocklist_state =
+        IpBlocklistState::new(shared_repository.to_owned(), proxy_secret.to_owned());
     let rate_limit_state = RateLimitState::new(rate_limit_store, proxy_secret);
     let turnstile_state = match turnstile_config {
         TurnstileConfig::Disabled => TurnstileState::disabled(),
@@ -173,11 +183,20 @@ async fn main() -> anyhow::Result<()> {
             auth,
         ));
 
-    // post_message_handler uses a different State type, so register it separately
-    let message_post_router = Router::new()
+    // 通知を送るハンドラーは State の型が異なるため、別のルーターに登録する
+    let notifying_router = Router::new()
         .route(
             "/forms/{form_id}/answers/{answer_id}/messages",
-            post(post_message_handler),
+            post(post_message_handler).layer(DefaultBodyLimit::max(POST_MESSAGE_BODY_LIMIT)),
+        )
+        .route(
+            "/forms/{form_id}/answers/{answer_id}/assignee",
+            put(update_answer_assignee_handler),
+        )
+        .route("/forms/{form_id}/draws", post(create_answer_draw_handler))
+        .route(
+            "/forms/{form_id}/answers/{answer_id}/comments",
+            post(post_form_comment),
         )
         .route_layer(middleware::from_fn_with_state(
             rate_limit_state.clone(),
@@ -196,7 +215,8 @@ async fn main() -> anyhow::Result<()> {
         .with_state(shared_repository.to_owned())
         .split_for_parts();
     let public_api = public_api
-        // route_layer は後から追加した layer が外側になるため、rate limit -> Turnstile -> handler の順にする。
+        // route_layer は後から追加した layer が外側になるため、
+        // IP ブロックリスト -> rate limit -> Turnstile -> handler の順にする。
         .route_layer(middleware::from_fn_with_state(
             turnstile_state,
             turnstile_middleware,
@@ -204,6 +224,10 @@ async fn main() -> anyhow::Result<()> {
         .route_layer(middleware::from_fn_with_state(
             rate_limit_state,
             rate_limit_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            ip_blocklist_state,
+            ip_blocklist_middleware,
         ));
 
     let app = Router::new()
@@ -215,7 +239,7 @@ async fn main() -> anyhow::Result<()> {
                 .merge(optional_auth_api)
                 .merge(authenticated_api)
                 .merge(authenticated_session_api)
-                .merge(message_post_router),
+                .merge(notifying_router),
         )
         .fallback(not_found_handler)
         // handler 内 panic で 500 を返し、コネクションを維持する
@@ -240,6 +264,7 @@ async fn main() -> anyhow::Result<()> {
                     HeaderName::from_static("x-seichi-proxy-secret"),
                     HeaderName::from_static("x-seichi-client-ip"),
                     HeaderName::from_static("x-seichi-turnstile-token"),
+                    HeaderName::from_static("x-seichi-temporary-answer-token"),
                 ])
                 .expose_headers([
                     LOCATION,
@@ -262,7 +287,7 @@ async fn main() -> anyhow::Result<()> {
 
     initialize_search_engine(shared_repository.to_owned()).await?;
 
-    let (_discord, _axum, _syncer, _messaging, _auto_of_sync_watcher) = join!(
+    let (_discord, _axum, _syncer, _messaging, _auto_of_sync_watcher, _overdue_answer_watcher) = join!(
         discord_connection.pool.start(),
         axum::serve(
             listener,
@@ -280,7 +305,8 @@ async fn main() -> anyhow::Result<()> {
             shutdown_notifier.clone(),
         ),
         messaging_conn.consumer(),
-        start_watch_out_of_sync(shared_repository.to_owned(), shutdown_notifier.clone())
+        start_watch_out_of_sync(shared_repository.to_owned(), shutdown_notifier.clone()),
+        start_watch_overdue_answers(shared_repository.to_owned(), shutdown_notifier.clone())
     );
 
     if let Some(agent) = pyroscope_agent {
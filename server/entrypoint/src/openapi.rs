@@ -1,5 +1,6 @@
 use presentation::handlers::{
-    global_discord_webhook_handler, notification_handler, search_handler, user_handler,
+    announcement_handler, global_discord_webhook_handler, notification_handler, search_handler,
+    submission_ip_blocklist_handler, support_metrics_handler, user_handler,
 };
 use resource::repository::RealInfrastructureRepository;
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
@@ -12,9 +13,12 @@ use utoipa_axum::routes;
     info(
         title = "Seichi Portal API",
         version = "1.0.0",
-        description = "## レートリミット\n\nAPI には、クライアント単位またはアカウント単位のレートリミットがあります。制限を超えた場合は `429 Too Many Requests` を返し、`Retry-After`、`RateLimit-Limit`、`RateLimit-Remaining`、`RateLimit-Reset` ヘッダーで再試行できる時刻を示します。\n\n- 未認証の GET: クライアント IP ごとに 1 分あたり 60 回\n- 一時回答の POST: クライアント IP ごとに 1 時間あたり 30 回、フォームごとに 1 時間あたり 10 回、クライアント IP ごとに 10 分あたり 5 回\n- セッション作成の POST: クライアント IP ごとに 1 時間あたり 10 回\n- 認証済みの GET: アカウントごとに 1 分あたり 600 回\n- 認証済みの書き込み: アカウントごとに 1 分あたり 120 回\n\n認証済みのリクエストはアカウント ID、未認証のリクエストはクライアント IP を基準に制限します。フロントエンドのプロキシがクライアント IP を転送する場合は、`X-Seichi-Proxy-Secret` が一致したときだけ `X-Seichi-Client-IP` を信頼します。"
+        description = "## レートリミット\n\nAPI には、クライアント単位またはアカウント単位のレートリミットがあります。制限を超えた場合は `429 Too Many Requests` を返し、`Retry-After`、`RateLimit-Limit`、`RateLimit-Remaining`、`RateLimit-Reset` ヘッダーで再試行できる時刻を示します。\n\n- 未認証の GET: クライアント IP ごとに 1 分あたり 60 回\n- 一時回答の POST: クライアント IP ごとに 1 時間あたり 30 回、フォームごとに 1 時間あたり 10 回、クライアント IP ごとに 10 分あたり 5 回\n- セッション作成の POST: クライアント IP ごとに 1 時間あたり 10 回\n- 回答のアクセスリンクからの GET: クライアント IP ごとに 1 分あたり 30 回\n- 回答のアクセスリンクからのメッセージの POST: クライアント IP ごとに 1 時間あたり 20 回、10 分あたり 5 回\n- 認証済みの GET: アカウントごとに 1 分あたり 600 回\n- 認証済みの書き込み: アカウントごとに 1 分あたり 120 回\n\n認証済みのリクエストはアカウント ID、未認証のリクエストはクライアント IP を基準に制限します。フロントエンドのプロキシがクライアント IP を転送する場合は、`X-Seichi-Proxy-Secret` が一致したときだけ `X-Seichi-Client-IP` を信頼します。"
     ),
     components(schemas(
+        presentation::schemas::announcement::AnnouncementCreateSchema,
+        presentation::schemas::announcement::AnnouncementResponse,
+        presentation::schemas::announcement::AnnouncementUpdateSchema,
         presentation::schemas::error_response::ErrorResponse,
         presentation::schemas::error_response::ErrorRestriction,
         presentation::schemas::user::UserInfoResponse,
@@ -32,18 +36,30 @@ use utoipa_axum::routes;
         presentation::schemas::form::form_response_schemas::AnswerAuthor,
         presentation::schemas::form::form_response_schemas::AnswerLabelResponseSchema,
         presentation::schemas::form::form_response_schemas::AnswerListPageResponse,
+        presentation::schemas::form::form_response_schemas::AnswerStatisticsResponse,
         presentation::schemas::form::form_response_schemas::AnswerStatusHistoryPageResponse,
         presentation::schemas::form::form_response_schemas::AnswerTitleHistoryPageResponse,
+        presentation::schemas::form::form_response_schemas::AnswerQuizScoreSchema,
         presentation::schemas::form::form_response_schemas::AnswerSettingsSchema,
         presentation::schemas::form::form_response_schemas::AnswerVisibility,
         presentation::schemas::form::form_response_schemas::ArchivedFormListPageResponse,
         presentation::schemas::form::form_response_schemas::ArchivedFormSchema,
+        presentation::schemas::form::form_response_schemas::BulkAnswerOperationItemResponse,
+        presentation::schemas::form::form_response_schemas::BulkAnswerOperationResponse,
+        presentation::schemas::form::form_response_schemas::ChoiceAnswerCountResponse,
+        presentation::schemas::form::form_response_schemas::DailyAnswerCountResponse,
         presentation::schemas::form::form_response_schemas::FormAnswer,
         presentation::schemas::form::form_response_schemas::FormLabelResponseSchema,
         presentation::schemas::form::form_response_schemas::FormListPageResponse,
         presentation::schemas::form::form_response_schemas::FormMetaSchema,
         presentation::schemas::form::form_response_schemas::FormSchema,
         presentation::schemas::form::form_response_schemas::FormSettingsResponseSchema,
+        presentation::schemas::form::form_response_schemas::PostedAnswerResponse,
+        presentation::schemas::form::form_response_schemas::PostedTemporaryAnswerResponse,
+        presentation::schemas::form::form_response_schemas::TemporaryAnswerAccessResponse,
+        presentation::schemas::form::form_response_schemas::TemporaryAnswerClaimResponse,
+        presentation::schemas::form::form_response_schemas::LabelAnswerCountResponse,
+        presentation::schemas::form::form_response_schemas::StatusAnswerCountResponse,
         presentation::schemas::form::form_response_schemas::TemporaryAnswerAuthor,
         presentation::schemas::form::form_response_schemas::MessageContentSchema,
         presentation::schemas::form::form_response_schemas::ChoiceResponseSchema,
@@ -51,6 +67,8 @@ use utoipa_axum::routes;
         presentation::schemas::form::form_response_schemas::QuestionResponseSchema,
         presentation::schemas::form::form_response_schemas::SelectQuestionResponseSchema,
         presentation::schemas::form::form_response_schemas::TextQuestionResponseSchema,
+        presentation::schemas::form::form_response_schemas::AnswerRelationDirection,
+        presentation::schemas::form::form_response_schemas::AnswerRelationKind,
         presentation::schemas::form::form_response_schemas::RelatedAnswerResponse,
         presentation::schemas::form::form_request_schemas::ChoiceSchema,
         presentation::schemas::form::form_request_schemas::QuestionDefinitionSchema,
@@ -60,6 +78,8 @@ use utoipa_axum::routes;
         presentation::schemas::form::form_request_schemas::TemporaryAnswerCreateSchema,
         presentation::schemas::form::form_request_schemas::RelatedAnswerRequest,
         presentation::schemas::form::form_request_schemas::TemporaryUserCreateSchema,
+        presentation::schemas::form::form_request_schemas::TemporaryAnswerMessageSchema,
+        presentation::schemas::form::form_request_schemas::TemporaryAnswerClaimSchema,
         presentation::schemas::form::form_response_schemas::AnswerAcceptancePeriodSchema,
         presentation::schemas::form::form_response_schemas::Role,
         presentation::schemas::form::form_response_schemas::SenderSchema,
@@ -69,6 +89,9 @@ use utoipa_axum::routes;
         presentation::schemas::search_schemas::CrossSearchResult,
         presentation::schemas::search_schemas::UserSearchResult,
         presentation::schemas::search_schemas::AnswerSearchResult,
+        presentation::schemas::support_metrics::SupportMetricsResponse,
+        presentation::schemas::support_metrics::OpenAnswerCountResponse,
+        presentation::schemas::support_metrics::StaffActivityResponse,
     )),
     modifiers(&SecurityAddon),
     tags(
@@ -82,7 +105,9 @@ use utoipa_axum::routes;
         (name = "User Groups"),
         (name = "Search"),
         (name = "Notifications"),
+        (name = "Announcements"),
         (name = "Settings"),
+        (name = "Support"),
         (name = "Session"),
         (name = "Health"),
     )
@@ -90,7 +115,12 @@ use utoipa_axum::routes;
 struct ApiMetadata;
 
 #[derive(OpenApi)]
-#[openapi(paths(presentation::handlers::form::message_handler::post_message_handler,))]
+#[openapi(paths(
+    presentation::handlers::form::message_handler::post_message_handler,
+    presentation::handlers::form::answer_handler::update_answer_assignee_handler,
+    presentation::handlers::form::answer_draw_handler::create_answer_draw_handler,
+    presentation::handlers::form::comment_handler::post_form_comment,
+))]
 struct ManuallyRegisteredApiDoc;
 
 struct SecurityAddon;
@@ -107,10 +137,20 @@ impl Modify for SecurityAddon {
 }
 
 pub fn public_api_router() -> OpenApiRouter<RealInfrastructureRepository> {
-    use presentation::handlers::form::answer_handler;
+    use presentation::handlers::form::{
+        answer_handler, form_handler, temporary_answer_access_handler,
+    };
 
     OpenApiRouter::new()
+        .routes(routes!(form_handler::public_form_feed_handler))
         .routes(routes!(answer_handler::post_temporary_answer_handler))
+        .routes(routes!(
+            temporary_answer_access_handler::get_temporary_answer_access_handler
+        ))
+        .routes(routes!(
+            temporary_answer_access_handler::get_temporary_answer_messages_handler,
+            temporary_answer_access_handler::post_temporary_answer_message_handler
+        ))
         .routes(routes!(user_handler::start_session))
 }
 
@@ -126,12 +166,15 @@ pub fn optional_auth_api_router() -> OpenApiRouter<RealInfrastructureRepository>
     OpenApiRouter::new()
         .routes(routes!(form_handler::form_list_handler))
         .routes(routes!(form_handler::get_form_handler))
+        .routes(routes!(announcement_handler::list_announcements_handler))
+        .routes(routes!(announcement_handler::get_announcement_handler))
 }
 
 pub fn authenticated_api_router() -> OpenApiRouter<RealInfrastructureRepository> {
     use presentation::handlers::form::{
-        answer_handler, answer_label_handler, answer_relation_handler, comment_handler,
-        form_handler, form_label_handler, message_handler,
+        answer_draw_handler, answer_handler, answer_label_handler, answer_relation_handler,
+        comment_handler, content_filter_handler, form_handler, form_label_handler, message_handler,
+        message_template_handler, temporary_answer_access_handler,
     };
 
     OpenApiRouter::new()
@@ -139,21 +182,51 @@ pub fn authenticated_api_router() -> OpenApiRouter<RealInfrastructureRepository>
             global_discord_webhook_handler::get_global_discord_webhook,
             global_discord_webhook_handler::update_global_discord_webhook
         ))
+        .routes(routes!(
+            submission_ip_blocklist_handler::list_submission_ip_blocks,
+            submission_ip_blocklist_handler::create_submission_ip_block
+        ))
+        .routes(routes!(
+            submission_ip_blocklist_handler::delete_submission_ip_block
+        ))
+        .routes(routes!(announcement_handler::create_announcement_handler))
+        .routes(routes!(
+            announcement_handler::update_announcement_handler,
+            announcement_handler::delete_announcement_handler
+        ))
         .routes(routes!(form_handler::create_form_handler))
         .routes(routes!(form_handler::update_form_handler))
         .routes(routes!(form_handler::archive_form_handler))
         .routes(routes!(form_handler::archived_form_list_handler))
         .routes(routes!(form_handler::get_archived_form_handler))
         .routes(routes!(form_handler::restore_archived_form_handler))
+        .routes(routes!(
+            content_filter_handler::list_content_filter_rules_handler,
+            content_filter_handler::create_content_filter_rule_handler
+        ))
+        .routes(routes!(
+            content_filter_handler::delete_content_filter_rule_handler
+        ))
+        .routes(routes!(
+            content_filter_handler::list_content_filter_flags_handler
+        ))
+        .routes(routes!(
+            content_filter_handler::dismiss_content_filter_flag_handler
+        ))
         .routes(routes!(
             answer_handler::get_answer_by_form_id_handler,
             answer_handler::post_answer_handler
         ))
+        .routes(routes!(answer_handler::export_answers_handler))
+        .routes(routes!(answer_handler::get_answer_statistics_handler))
         .routes(routes!(
             answer_relation_handler::get_related_answers_handler,
             answer_relation_handler::add_related_answer_handler,
             answer_relation_handler::remove_related_answer_handler
         ))
+        .routes(routes!(
+            answer_relation_handler::close_answer_as_duplicate_handler
+        ))
         .routes(routes!(answer_handler::get_all_answers))
         .routes(routes!(
             answer_label_handler::get_labels_for_answers,
@@ -175,13 +248,34 @@ pub fn authenticated_api_router() -> OpenApiRouter<RealInfrastructureRepository>
             answer_handler::get_answer_handler,
             answer_handler::update_answer_handler
         ))
+        .routes(routes!(
+            answer_handler::get_temporary_answer_abuse_metadata_handler
+        ))
         .routes(routes!(answer_handler::get_answer_status_history_handler))
         .routes(routes!(answer_handler::get_answer_title_history_handler))
-        .routes(routes!(answer_label_handler::replace_answer_labels))
         .routes(routes!(
-            comment_handler::get_form_comment,
-            comment_handler::post_form_comment
+            answer_handler::get_answer_assignment_history_handler
+        ))
+        .routes(routes!(answer_handler::update_answer_due_date_handler))
+        .routes(routes!(answer_handler::move_answer_handler))
+        .routes(routes!(answer_handler::get_answer_move_history_handler))
+        .routes(routes!(
+            temporary_answer_access_handler::revoke_temporary_answer_access_handler
         ))
+        .routes(routes!(
+            temporary_answer_access_handler::claim_temporary_answer_handler
+        ))
+        .routes(routes!(answer_draw_handler::get_answer_draws_handler))
+        .routes(routes!(answer_draw_handler::get_answer_draw_handler))
+        .routes(routes!(answer_handler::bulk_update_answer_status_handler))
+        .routes(routes!(
+            answer_handler::bulk_update_answer_publication_handler
+        ))
+        .routes(routes!(answer_handler::bulk_update_answer_labels_handler))
+        .routes(routes!(answer_handler::bulk_approve_answers_handler))
+        .routes(routes!(answer_handler::bulk_reject_answers_handler))
+        .routes(routes!(answer_label_handler::replace_answer_labels))
+        .routes(routes!(comment_handler::get_form_comment))
         .routes(routes!(comment_handler::get_comment_history))
         .routes(routes!(
             comment_handler::update_form_comment,
@@ -218,12 +312,25 @@ pub fn authenticated_api_router() -> OpenApiRouter<RealInfrastructureRepository>
         .routes(routes!(search_handler::cross_search))
         .routes(routes!(search_handler::search_users))
         .routes(routes!(search_handler::search_answers))
+        .routes(routes!(search_handler::similar_answers))
+        .routes(routes!(support_metrics_handler::get_support_metrics))
         .routes(routes!(message_handler::get_messages_handler))
         .routes(routes!(message_handler::get_message_history))
+        .routes(routes!(message_handler::get_message_attachment_handler))
+        .routes(routes!(message_handler::mark_messages_as_read_handler))
+        .routes(routes!(message_handler::reopen_message_thread_handler))
         .routes(routes!(
             message_handler::update_message_handler,
             message_handler::delete_message_handler
         ))
+        .routes(routes!(
+            message_template_handler::get_message_templates_handler,
+            message_template_handler::create_message_template_handler
+        ))
+        .routes(routes!(
+            message_template_handler::update_message_template_handler,
+            message_template_handler::delete_message_template_handler
+        ))
         .routes(routes!(notification_handler::get_notification_settings))
         .routes(routes!(
             notification_handler::get_my_notification_settings,
@@ -18,14 +18,32 @@ pub enum DomainError {
     },
     #[error("Empty message body.")]
     EmptyMessageBody,
-    #[error("Messages cannot be posted to temporary answers.")]
+    #[error("Messages cannot be posted to temporary answers without an active access link.")]
     MessagePostingNotSupportedForTemporaryAnswer,
     #[error("Messages cannot be posted to answers imported from Redmine.")]
     MessagePostingNotSupportedForImportedAnswer,
+    #[error("The message thread is locked.")]
+    MessageThreadLocked,
     #[error("Invalid answer acceptance period.")]
     InvalidAnswerAcceptancePeriod,
+    #[error("Invalid answer response deadline.")]
+    InvalidAnswerResponseDeadline,
     #[error("Invalid Discord webhook url.")]
     InvalidDiscordWebhookUrl,
+    #[error("Only administrators can be assigned to answers.")]
+    InvalidAnswerAssignee,
+    #[error("The answer is not pending moderation.")]
+    AnswerNotPendingModeration,
+    #[error("Invalid IP address range.")]
+    InvalidIpRange,
+    #[error("The IP address range is already blocked.")]
+    BlockedIpRangeAlreadyExists,
+    #[error("Invalid content filter rule.")]
+    InvalidContentFilterRule,
+    #[error("The content was rejected by the content filter.")]
+    ContentRejectedByFilter,
+    #[error("Invalid announcement publish period.")]
+    InvalidAnnouncementPublishPeriod,
     #[error("Invalid entity: {message}")]
     InvalidEntity { message: String },
 }
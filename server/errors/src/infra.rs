@@ -49,6 +49,8 @@ pub enum InfraError {
     },
     #[error("Send Error: {}", .cause)]
     Send { cause: String },
+    #[error("Storage Error: {}", .cause)]
+    Storage { cause: String },
 }
 
 impl PartialEq for InfraError {
@@ -99,6 +101,9 @@ impl PartialEq for InfraError {
             Self::Send { cause: left } => {
                 matches!(other, Self::Send { cause: right } if left == right)
             }
+            Self::Storage { cause: left } => {
+                matches!(other, Self::Storage { cause: right } if left == right)
+            }
         }
     }
 }
@@ -24,4 +24,24 @@ pub enum UseCaseError {
     UserGroupNotFound,
     #[error("Discord not linked.")]
     DiscordNotLinked,
+    #[error("Draw not found.")]
+    DrawNotFound,
+    #[error("Message template not found.")]
+    MessageTemplateNotFound,
+    #[error("Message attachment not found.")]
+    MessageAttachmentNotFound,
+    #[error("Temporary answer access not found.")]
+    TemporaryAnswerAccessNotFound,
+    #[error("Temporary answer claim code not found.")]
+    TemporaryAnswerClaimCodeNotFound,
+    #[error("Temporary answer abuse metadata not found.")]
+    TemporaryAnswerAbuseMetadataNotFound,
+    #[error("Blocked IP range not found.")]
+    BlockedIpRangeNotFound,
+    #[error("Content filter rule not found.")]
+    ContentFilterRuleNotFound,
+    #[error("Content filter flag not found.")]
+    ContentFilterFlagNotFound,
+    #[error("Announcement not found.")]
+    AnnouncementNotFound,
 }
@@ -1,3 +1,4 @@
+pub mod announcement;
 pub mod components;
 pub mod config;
 pub mod connection;
@@ -8,4 +9,6 @@ mod meilisearch_schemas;
 pub mod minecraft_ban;
 pub mod notification;
 pub mod search;
+pub mod submission_ip_blocklist;
+pub mod support_metrics;
 pub mod user;
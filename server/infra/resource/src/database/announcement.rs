@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use domain::announcement::{Announcement, AnnouncementId};
+use errors::infra::InfraError;
+use itertools::Itertools;
+
+use crate::{
+    database::{
+        connection::{ConnectionPool, DatabaseTransaction},
+        count::count_as_u32,
+    },
+    records::{AnnouncementRecord, AnnouncementTargetGroupRecord},
+};
+
+async fn insert_target_groups(
+    txn: &mut DatabaseTransaction,
+    announcement: &Announcement,
+) -> Result<(), InfraError> {
+    let announcement_id = announcement.id().to_string();
+
+    for group_id in announcement.target_groups().as_slice() {
+        sqlx::query!(
+            r"INSERT INTO announcement_target_groups (announcement_id, group_id) VALUES (?, ?)",
+            announcement_id,
+            group_id.to_string(),
+        )
+        .execute(&mut **txn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+impl ConnectionPool {
+    /// 固定表示のお知らせを先に、それぞれ公開開始日時の新しい順で返す。
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn fetch_announcements(
+        &self,
+    ) -> Result<Vec<(AnnouncementRecord, Vec<String>)>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let announcements = sqlx::query_as!(
+                    AnnouncementRecord,
+                    r"SELECT id, title, body,
+                        publish_from AS `publish_from: chrono::DateTime<chrono::Utc>`,
+                        publish_until AS `publish_until: chrono::DateTime<chrono::Utc>`,
+                        pinned AS `pinned: bool`,
+                        created_by,
+                        created_at AS `created_at: chrono::DateTime<chrono::Utc>`,
+                        updated_at AS `updated_at: chrono::DateTime<chrono::Utc>`
+                    FROM announcements
+                    ORDER BY pinned DESC, publish_from DESC, id"
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                let mut target_groups: HashMap<String, Vec<String>> = sqlx::query_as!(
+                    AnnouncementTargetGroupRecord,
+                    r"SELECT announcement_id, group_id
+                    FROM announcement_target_groups
+                    ORDER BY id"
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|record| (record.announcement_id, record.group_id))
+                .into_group_map();
+
+                Ok::<_, InfraError>(
+                    announcements
+                        .into_iter()
+                        .map(|record| {
+                            let group_ids = target_groups.remove(&record.id).unwrap_or_default();
+                            (record, group_ids)
+                        })
+                        .collect(),
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn fetch_announcement(
+        &self,
+        id: AnnouncementId,
+    ) -> Result<Option<(AnnouncementRecord, Vec<String>)>, InfraError> {
+        let id = id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let Some(announcement) = sqlx::query_as!(
+                    AnnouncementRecord,
+                    r"SELECT id, title, body,
+                        publish_from AS `publish_from: chrono::DateTime<chrono::Utc>`,
+                        publish_until AS `publish_until: chrono::DateTime<chrono::Utc>`,
+                        pinned AS `pinned: bool`,
+                        created_by,
+                        created_at AS `created_at: chrono::DateTime<chrono::Utc>`,
+                        updated_at AS `updated_at: chrono::DateTime<chrono::Utc>`
+                    FROM announcements
+                    WHERE id = ?",
+                    id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?
+                else {
+                    return Ok::<_, InfraError>(None);
+                };
+
+                let group_ids = sqlx::query_scalar!(
+                    r"SELECT group_id FROM announcement_target_groups
+                    WHERE announcement_id = ?
+                    ORDER BY id",
+                    id,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                Ok(Some((announcement, group_ids)))
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %announcement.id()))]
+    pub(crate) async fn insert_announcement(
+        &self,
+        announcement: &Announcement,
+    ) -> Result<(), InfraError> {
+        let announcement = announcement.clone();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO announcements
+                        (id, title, body, publish_from, publish_until, pinned, created_by, created_at, updated_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    announcement.id().to_string(),
+                    announcement.title().as_str(),
+                    announcement.body().as_str(),
+                    announcement.publish_period().publish_from(),
+                    announcement.publish_period().publish_until(),
+                    announcement.pinned(),
+                    announcement.created_by().to_string(),
+                    announcement.created_at(),
+                    announcement.updated_at(),
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                insert_target_groups(txn, &announcement).await
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %announcement.id()))]
+    pub(crate) async fn update_announcement(
+        &self,
+        announcement: &Announcement,
+    ) -> Result<(), InfraError> {
+        let announcement = announcement.clone();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                let id = announcement.id().to_string();
+
+                sqlx::query!(
+                    r"UPDATE announcements
+                    SET title = ?, body = ?, publish_from = ?, publish_until = ?, pinned = ?, updated_at = ?
+                    WHERE id = ?",
+                    announcement.title().as_str(),
+                    announcement.body().as_str(),
+                    announcement.publish_period().publish_from(),
+                    announcement.publish_period().publish_until(),
+                    announcement.pinned(),
+                    announcement.updated_at(),
+                    id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                sqlx::query!(
+                    r"DELETE FROM announcement_target_groups WHERE announcement_id = ?",
+                    id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                insert_target_groups(txn, &announcement).await
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn delete_announcement(&self, id: AnnouncementId) -> Result<(), InfraError> {
+        let id = id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(r"DELETE FROM announcements WHERE id = ?", id)
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn announcement_size(&self) -> Result<u32, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let size =
+                    sqlx::query_scalar!("SELECT COUNT(*) AS `count!: i64` FROM announcements")
+                        .fetch_one(&mut **txn)
+                        .await?;
+
+                count_as_u32(size, "announcements")
+            })
+        })
+        .await
+    }
+}
@@ -1,17 +1,19 @@
 use crate::{
     external::discord_api::DiscordAPI,
     records::{
-        ActiveFormRecord, AnswerLabelRecord, AnswerStatusHistoryRecord, AnswerTitleHistoryRecord,
-        ArchivedFormRecord, CommentHistoryRecord, CommentRecord, DiscordUserRecord,
-        FormAnswerRecord, FormLabelRecord, MessageHistoryRecord, MessageRecord,
-        NotificationSettingsRecord,
+        ActiveFormRecord, AnswerAssignmentHistoryRecord, AnswerLabelRecord,
+        AnswerMoveHistoryRecord, AnswerStatisticsRecord, AnswerStatusHistoryRecord,
+        AnswerTitleHistoryRecord, ArchivedFormRecord, CommentHistoryRecord, CommentRecord,
+        DiscordUserRecord, FormAnswerRecord, FormLabelRecord, MessageAttachmentRecord,
+        MessageHistoryRecord, MessageRecord, NotificationSettingsRecord,
     },
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::search::models::{
-    AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit, FormLabelSearchHit, FormSearchHit,
-    NumberOfRecordsPerAggregate, UserSearchHit,
+    AnnouncementSearchHit, AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit,
+    FormLabelSearchHit, FormSearchHit, NumberOfRecordsPerAggregate, SimilarAnswerLimit,
+    UserSearchHit,
 };
 use domain::{
     account::models::{
@@ -21,16 +23,20 @@ use domain::{
     form::{
         FormSubmissionRestriction,
         answer::{
-            AnswerEntry, AnswerId, AnswerLabel, AnswerLabelId, AnswerPagePosition,
-            AnswerPublication, AnswerReference, AnswerRelation, AnswerStatus, AnswerStatusChange,
+            AnswerAssignmentHistoryPagePosition, AnswerEntry, AnswerId, AnswerLabel, AnswerLabelId,
+            AnswerListFilter, AnswerMoveHistoryPagePosition, AnswerPagePosition, AnswerPublication,
+            AnswerReference, AnswerRelation, AnswerRelationKind, AnswerStatus, AnswerStatusChange,
             AnswerStatusHistoryPagePosition, AnswerTitleHistoryPagePosition,
         },
         comment::{Comment, CommentHistoryPagePosition, CommentId, DeletedComment},
         message::{DeletedMessage, Message, MessageHistoryPagePosition, MessageId},
+        message_thread::MessageThreadReopening,
         models::{
             ActiveForm, ArchivedForm, ArchivedFormPagePosition, FormId, FormLabel, FormLabelId,
             FormLabelName, FormPagePosition,
         },
+        temporary_answer_access::TemporaryAnswerAccess,
+        temporary_answer_claim::TemporaryAnswerClaimTicket,
     },
     minecraft_ban::MinecraftBan,
     notification::models::NotificationPreference,
@@ -102,12 +108,12 @@ pub trait FormDatabase: Send + Sync {
         &self,
         form_id: FormId,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerEntry, AnswerPagePosition>, InfraError>;
     async fn list_all_answer_entries(
         &self,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerEntry, AnswerPagePosition>, InfraError>;
 }
 
@@ -115,6 +121,8 @@ pub trait FormDatabase: Send + Sync {
 pub struct AnswerRelationRecord {
     pub first: AnswerReference,
     pub second: AnswerReference,
+    pub kind: AnswerRelationKind,
+    pub reversed: bool,
 }
 
 #[automock]
@@ -133,10 +141,29 @@ pub trait FormAnswerRelationDatabase: Send + Sync {
     ) -> Result<Option<AnswerRelationRecord>, InfraError>;
 }
 
+/// 一時回答と同じトランザクションで保存する、アクセスリンクと引き継ぎコード。
+///
+/// トークンとコードは平文ではなくハッシュで持つ。
+#[derive(Debug, Clone)]
+pub struct TemporaryAnswerCredentials {
+    pub access: TemporaryAnswerAccess,
+    pub access_token_hash: String,
+    pub claim_ticket: TemporaryAnswerClaimTicket,
+    pub claim_code_hash: String,
+}
+
 #[automock]
 #[async_trait]
 pub trait FormAnswerDatabase: Send + Sync {
     async fn post_answer(&self, answer: &AnswerEntry, form_id: FormId) -> Result<(), InfraError>;
+    /// 回答を保存します。`credentials` を指定した場合は、一時回答のアクセスリンクと引き継ぎコードも
+    /// 同じトランザクションで保存します。
+    async fn post_answer_with_temporary_credentials(
+        &self,
+        answer: &AnswerEntry,
+        form_id: FormId,
+        credentials: Option<TemporaryAnswerCredentials>,
+    ) -> Result<(), InfraError>;
     async fn get_answers(
         &self,
         answer_id: AnswerId,
@@ -151,6 +178,14 @@ pub trait FormAnswerDatabase: Send + Sync {
         form_id: FormId,
         updated_by: &AccountUser,
     ) -> Result<Option<AnswerStatusChange>, InfraError>;
+    /// 回答を更新します。`relation` を指定した場合は、回答間の関係も同じトランザクションで保存します。
+    async fn update_answer_entry_with_relation(
+        &self,
+        answer_entry: &AnswerEntry,
+        form_id: FormId,
+        updated_by: &AccountUser,
+        relation: Option<AnswerRelation>,
+    ) -> Result<Option<AnswerStatusChange>, InfraError>;
     async fn fetch_status_history(
         &self,
         answer_id: AnswerId,
@@ -161,6 +196,33 @@ pub trait FormAnswerDatabase: Send + Sync {
         answer_id: AnswerId,
         request: PageRequest<AnswerTitleHistoryPagePosition>,
     ) -> Result<Page<AnswerTitleHistoryRecord, AnswerTitleHistoryPagePosition>, InfraError>;
+    async fn fetch_assignment_history(
+        &self,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<Page<AnswerAssignmentHistoryRecord, AnswerAssignmentHistoryPagePosition>, InfraError>;
+    /// 移動後の回答を保存し、回答内容の質問・回答関連の端点の更新と移動履歴の記録を行う。
+    ///
+    /// 回答が `from_form_id` に所属していない場合は何も変更せずにエラーを返す。
+    async fn move_answer_entry(
+        &self,
+        answer_entry: &AnswerEntry,
+        from_form_id: FormId,
+        moved_by: &AccountUser,
+    ) -> Result<(), InfraError>;
+    async fn fetch_move_history(
+        &self,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<AnswerMoveHistoryRecord, AnswerMoveHistoryPagePosition>, InfraError>;
+    /// 未通知の期限超過を通知済みとして記録し、記録できたかを返す。
+    async fn mark_overdue_notified(&self, answer_id: AnswerId) -> Result<bool, InfraError>;
+    /// フォームの回答を集計する。`public_only` の場合は公開されている回答だけを数える。
+    async fn aggregate_answers(
+        &self,
+        form_id: FormId,
+        public_only: bool,
+    ) -> Result<AnswerStatisticsRecord, InfraError>;
     /// 回答 (`answers`) の件数を返す。
     async fn size(&self) -> Result<u32, InfraError>;
     /// 回答本文 (`real_answers`) の件数を返す。
@@ -196,11 +258,12 @@ pub trait FormAnswerLabelDatabase: Send + Sync {
 
 #[async_trait]
 pub trait FormMessageDatabase: Send + Sync {
+    /// `operated_by` は、一時回答者が送ったメッセージでは `None` になる。
     async fn post_message(
         &self,
         message: &Message,
         answer_id: AnswerId,
-        operated_by: &UserSnapshot,
+        operated_by: Option<&UserSnapshot>,
     ) -> Result<(), InfraError>;
     async fn update_message_with_history(
         &self,
@@ -220,6 +283,10 @@ pub trait FormMessageDatabase: Send + Sync {
         &self,
         message_id: &MessageId,
     ) -> Result<Option<MessageRecord>, InfraError>;
+    async fn fetch_message_attachments_by_answer_id(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<Vec<MessageAttachmentRecord>, InfraError>;
     async fn delete_message_with_history(&self, message: &DeletedMessage)
     -> Result<(), InfraError>;
     async fn fetch_history(
@@ -228,6 +295,16 @@ pub trait FormMessageDatabase: Send + Sync {
         request: PageRequest<MessageHistoryPagePosition>,
         includes_deleted_history: bool,
     ) -> Result<Page<MessageHistoryRecord, MessageHistoryPagePosition>, InfraError>;
+    async fn is_message_thread_reopened(&self, answer_id: AnswerId) -> Result<bool, InfraError>;
+    /// 回答に、取り消されていないアクセスリンクが発行されているか
+    async fn has_active_temporary_answer_access(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<bool, InfraError>;
+    async fn insert_message_thread_reopening(
+        &self,
+        reopening: &MessageThreadReopening,
+    ) -> Result<(), InfraError>;
 }
 
 #[automock]
@@ -260,7 +337,7 @@ pub trait FormCommentDatabase: Send + Sync {
         &self,
         answer_id: AnswerId,
         request: PageRequest<CommentHistoryPagePosition>,
-        includes_deleted_history: bool,
+        includes_staff_only_history: bool,
     ) -> Result<Page<CommentHistoryRecord, CommentHistoryPagePosition>, InfraError>;
     async fn size(&self) -> Result<u32, InfraError>;
 }
@@ -293,6 +370,7 @@ pub trait FormLabelDatabase: Send + Sync {
 pub trait UserDatabase: Send + Sync {
     async fn find_by(&self, uuid: Uuid) -> Result<Option<AccountUser>, InfraError>;
     async fn find_by_ids(&self, uuids: Vec<Uuid>) -> Result<Vec<AccountUser>, InfraError>;
+    async fn find_by_names(&self, names: Vec<String>) -> Result<Vec<AccountUser>, InfraError>;
     async fn upsert_user(&self, user: &AccountUser) -> Result<(), InfraError>;
     async fn patch_user_role(&self, uuid: Uuid, role: Role) -> Result<(), InfraError>;
     async fn create_user_group(&self, group: &UserGroup) -> Result<(), InfraError>;
@@ -379,7 +457,18 @@ pub trait SearchDatabase: Send + Sync {
         form_id: Option<FormId>,
         status: Option<AnswerStatus>,
     ) -> Result<Vec<AnswerSearchHit>, InfraError>;
+    async fn search_similar_answers(
+        &self,
+        query: &str,
+        form_id: FormId,
+        exclude: AnswerId,
+        limit: SimilarAnswerLimit,
+    ) -> Result<Vec<AnswerSearchHit>, InfraError>;
     async fn search_comments(&self, query: &str) -> Result<Vec<CommentSearchHit>, InfraError>;
+    async fn search_announcements(
+        &self,
+        query: &str,
+    ) -> Result<Vec<AnnouncementSearchHit>, InfraError>;
     async fn sync_search_engine(
         &self,
         data: &[SearchableFieldsWithOperation],
@@ -34,3 +34,15 @@ pub static MEILISEARCH: LazyLock<MeiliSearch> = LazyLock::new(|| {
         .from_env::<MeiliSearch>()
         .unwrap()
 });
+
+/// 一時回答の送信元をハッシュにするときの salt。秘密の値なので Debug を実装しない。
+#[derive(Deserialize)]
+pub struct AbuseMetadata {
+    pub hash_salt: Option<String>,
+}
+
+pub static ABUSE_METADATA: LazyLock<AbuseMetadata> = LazyLock::new(|| {
+    envy::prefixed("ABUSE_METADATA_")
+        .from_env::<AbuseMetadata>()
+        .unwrap()
+});
@@ -1,7 +1,15 @@
+pub mod answer_draw;
 pub mod answer_label;
 pub mod answer_relations;
 pub mod answers;
 pub mod comment;
+pub mod content_filter;
 pub mod form;
 pub mod form_label;
+pub mod mention;
 pub mod message;
+pub mod message_read;
+pub mod message_template;
+pub mod temporary_answer_abuse;
+pub mod temporary_answer_access;
+pub mod temporary_answer_claim;
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use domain::form::{
+    draw::{AnswerDraw, AnswerDrawId},
+    models::FormId,
+};
+use errors::infra::InfraError;
+
+use crate::{
+    database::connection::ConnectionPool,
+    records::{AnswerDrawEntryRecord, AnswerDrawRecord},
+};
+
+impl ConnectionPool {
+    /// 抽選の記録と、候補・当選者の一覧を保存する。
+    #[tracing::instrument(skip_all, fields(draw_id = %draw.id()))]
+    pub(crate) async fn insert_answer_draw(&self, draw: &AnswerDraw) -> Result<(), InfraError> {
+        let draw_id = draw.id().to_string();
+        let form_id = draw.form_id().to_string();
+        let seed = draw.seed().value();
+        let winner_count = draw.winner_count().value();
+        let status_filter = draw.criteria().status().map(|status| status.to_string());
+        let label_filter_id = draw
+            .criteria()
+            .label_id()
+            .map(|label_id| label_id.to_string());
+        let exclude_restricted_submitters = *draw.criteria().exclude_restricted_submitters();
+        let drawn_by = draw.drawn_by().to_owned();
+        let drawn_at = *draw.drawn_at();
+        let entries = draw
+            .candidate_ids()
+            .iter()
+            .map(|candidate_id| {
+                let winner_rank = draw
+                    .winner_ids()
+                    .iter()
+                    .position(|winner_id| winner_id == candidate_id)
+                    .map(|index| index as u32 + 1);
+                (candidate_id.to_string(), winner_rank)
+            })
+            .collect::<Vec<_>>();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO answer_draws
+                    (id, form_id, seed, winner_count, status_filter, label_filter_id,
+                        exclude_restricted_submitters, drawn_by_id, drawn_by_name, drawn_by_role,
+                        drawn_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    draw_id,
+                    form_id,
+                    seed,
+                    winner_count,
+                    status_filter,
+                    label_filter_id,
+                    exclude_restricted_submitters,
+                    drawn_by.id().to_string(),
+                    drawn_by.name(),
+                    drawn_by.role().to_string(),
+                    drawn_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                for (answer_id, winner_rank) in entries {
+                    sqlx::query!(
+                        r"INSERT INTO answer_draw_entries (draw_id, answer_id, winner_rank)
+                        VALUES (?, ?, ?)",
+                        draw_id,
+                        answer_id,
+                        winner_rank,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    /// フォームで行われた抽選を、候補・当選者の一覧とともに新しいものから順に返す。
+    #[tracing::instrument(skip_all, fields(form_id = %form_id))]
+    pub(crate) async fn fetch_answer_draws(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<(AnswerDrawRecord, Vec<AnswerDrawEntryRecord>)>, InfraError> {
+        let form_id = form_id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let draws = sqlx::query_as!(
+                    AnswerDrawRecord,
+                    r"SELECT id, form_id, seed, winner_count, status_filter, label_filter_id,
+                        exclude_restricted_submitters AS `exclude_restricted_submitters: bool`,
+                        drawn_by_id, drawn_by_name, drawn_by_role,
+                        drawn_at AS `drawn_at!: chrono::DateTime<chrono::Utc>`
+                    FROM answer_draws
+                    WHERE form_id = ?
+                    ORDER BY drawn_at DESC, id DESC",
+                    form_id,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                let mut entries = HashMap::<String, Vec<AnswerDrawEntryRecord>>::new();
+                for entry in sqlx::query_as!(
+                    AnswerDrawEntryRecord,
+                    r"SELECT answer_draw_entries.draw_id, answer_draw_entries.answer_id,
+                        answer_draw_entries.winner_rank
+                    FROM answer_draw_entries
+                    INNER JOIN answer_draws ON answer_draws.id = answer_draw_entries.draw_id
+                    WHERE answer_draws.form_id = ?",
+                    form_id,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                {
+                    entries
+                        .entry(entry.draw_id.clone())
+                        .or_default()
+                        .push(entry);
+                }
+
+                Ok::<_, InfraError>(
+                    draws
+                        .into_iter()
+                        .map(|draw| {
+                            let draw_entries = entries.remove(&draw.id).unwrap_or_default();
+                            (draw, draw_entries)
+                        })
+                        .collect(),
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(form_id = %form_id, draw_id = %draw_id))]
+    pub(crate) async fn fetch_answer_draw(
+        &self,
+        form_id: FormId,
+        draw_id: AnswerDrawId,
+    ) -> Result<Option<(AnswerDrawRecord, Vec<AnswerDrawEntryRecord>)>, InfraError> {
+        let form_id = form_id.to_string();
+        let draw_id = draw_id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let Some(draw) = sqlx::query_as!(
+                    AnswerDrawRecord,
+                    r"SELECT id, form_id, seed, winner_count, status_filter, label_filter_id,
+                        exclude_restricted_submitters AS `exclude_restricted_submitters: bool`,
+                        drawn_by_id, drawn_by_name, drawn_by_role,
+                        drawn_at AS `drawn_at!: chrono::DateTime<chrono::Utc>`
+                    FROM answer_draws
+                    WHERE id = ? AND form_id = ?",
+                    draw_id,
+                    form_id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?
+                else {
+                    return Ok::<_, InfraError>(None);
+                };
+
+                let entries = sqlx::query_as!(
+                    AnswerDrawEntryRecord,
+                    r"SELECT draw_id, answer_id, winner_rank
+                    FROM answer_draw_entries
+                    WHERE draw_id = ?",
+                    draw_id,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                Ok(Some((draw, entries)))
+            })
+        })
+        .await
+    }
+}
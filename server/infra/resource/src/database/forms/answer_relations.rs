@@ -1,11 +1,11 @@
 use async_trait::async_trait;
-use domain::form::answer::{AnswerId, AnswerReference, AnswerRelation};
+use domain::form::answer::{AnswerId, AnswerReference, AnswerRelation, AnswerRelationKind};
 use errors::infra::InfraError;
 use uuid::Uuid;
 
 use crate::database::{
     components::{AnswerRelationRecord, FormAnswerRelationDatabase},
-    connection::ConnectionPool,
+    connection::{ConnectionPool, DatabaseTransaction},
 };
 
 fn reference_columns(reference: AnswerReference) -> (String, String) {
@@ -27,30 +27,138 @@ fn relation_columns(relation: AnswerRelation) -> (String, String, String, String
     )
 }
 
-fn relation_from_row(
+struct AnswerRelationRow {
     first_form_id: String,
     first_answer_id: String,
     second_form_id: String,
     second_answer_id: String,
-) -> Result<AnswerRelationRecord, InfraError> {
+    kind: String,
+    reversed: bool,
+}
+
+fn relation_from_row(row: AnswerRelationRow) -> Result<AnswerRelationRecord, InfraError> {
     let first = AnswerReference::new(
-        Uuid::parse_str(&first_form_id)?.into(),
-        Uuid::parse_str(&first_answer_id)?.into(),
+        Uuid::parse_str(&row.first_form_id)?.into(),
+        Uuid::parse_str(&row.first_answer_id)?.into(),
     );
     let second = AnswerReference::new(
-        Uuid::parse_str(&second_form_id)?.into(),
-        Uuid::parse_str(&second_answer_id)?.into(),
+        Uuid::parse_str(&row.second_form_id)?.into(),
+        Uuid::parse_str(&row.second_answer_id)?.into(),
     );
-    let relation = AnswerRelation::new(first, second).map_err(|error| InfraError::Unexpected {
-        cause: error.to_string(),
-    })?;
+    let kind = row.kind.parse::<AnswerRelationKind>()?;
+    let relation =
+        AnswerRelation::from_normalized(first, second, kind, row.reversed).map_err(|error| {
+            InfraError::Unexpected {
+                cause: error.to_string(),
+            }
+        })?;
 
     Ok(AnswerRelationRecord {
         first: relation.first(),
         second: relation.second(),
+        kind: relation.kind(),
+        reversed: relation.is_reversed(),
     })
 }
 
+/// `relation` を `txn` 内で保存する。同じ二つの回答の間に関連があれば、種類と向きを置き換える。
+pub(crate) async fn upsert_relation(
+    txn: &mut DatabaseTransaction,
+    relation: AnswerRelation,
+) -> Result<(), InfraError> {
+    let (first_form_id, first_answer_id, second_form_id, second_answer_id) =
+        relation_columns(relation);
+    let kind = relation.kind().to_string();
+    let reversed = relation.is_reversed();
+
+    sqlx::query!(
+        r"INSERT INTO answer_relations
+            (first_form_id, first_answer_id, second_form_id, second_answer_id,
+                kind, reversed)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE kind = VALUES(kind), reversed = VALUES(reversed)",
+        first_form_id,
+        first_answer_id,
+        second_form_id,
+        second_answer_id,
+        kind,
+        reversed,
+    )
+    .execute(&mut **txn)
+    .await?;
+    Ok(())
+}
+
+/// `from` を端点に持つ関連を、端点を `to` に置き換えて保存し直す。
+///
+/// 端点の正規化順が変わりうるため、行を削除してから挿入し直す。
+pub(crate) async fn replace_relation_endpoint(
+    txn: &mut DatabaseTransaction,
+    from: AnswerReference,
+    to: AnswerReference,
+) -> Result<(), InfraError> {
+    let (form_id, answer_id) = reference_columns(from);
+    let rows = sqlx::query_as!(
+        AnswerRelationRow,
+        r"SELECT first_form_id, first_answer_id, second_form_id, second_answer_id,
+            kind, reversed AS `reversed: bool`
+        FROM answer_relations
+        WHERE (first_form_id = ? AND first_answer_id = ?)
+           OR (second_form_id = ? AND second_answer_id = ?)
+        FOR UPDATE",
+        &form_id,
+        &answer_id,
+        &form_id,
+        &answer_id,
+    )
+    .fetch_all(&mut **txn)
+    .await?;
+
+    sqlx::query!(
+        r"DELETE FROM answer_relations
+        WHERE (first_form_id = ? AND first_answer_id = ?)
+           OR (second_form_id = ? AND second_answer_id = ?)",
+        &form_id,
+        &answer_id,
+        &form_id,
+        &answer_id,
+    )
+    .execute(&mut **txn)
+    .await?;
+
+    for row in rows {
+        let record = relation_from_row(row)?;
+        let relation = AnswerRelation::from_normalized(
+            record.first,
+            record.second,
+            record.kind,
+            record.reversed,
+        )
+        .and_then(|relation| relation.replace_endpoint(from, to))
+        .map_err(|error| InfraError::Unexpected {
+            cause: error.to_string(),
+        })?;
+        let (first_form_id, first_answer_id, second_form_id, second_answer_id) =
+            relation_columns(relation);
+
+        sqlx::query!(
+            r"INSERT INTO answer_relations
+                (first_form_id, first_answer_id, second_form_id, second_answer_id, kind, reversed)
+            VALUES (?, ?, ?, ?, ?, ?)",
+            first_form_id,
+            first_answer_id,
+            second_form_id,
+            second_answer_id,
+            relation.kind().to_string(),
+            relation.is_reversed(),
+        )
+        .execute(&mut **txn)
+        .await?;
+    }
+
+    Ok(())
+}
+
 #[async_trait]
 impl FormAnswerRelationDatabase for ConnectionPool {
     #[tracing::instrument(skip_all)]
@@ -61,8 +169,10 @@ impl FormAnswerRelationDatabase for ConnectionPool {
         let (form_id, answer_id) = reference_columns(source);
         self.read_only_transaction(|txn| {
             Box::pin(async move {
-                let rows = sqlx::query!(
-                    r"SELECT first_form_id, first_answer_id, second_form_id, second_answer_id
+                let rows = sqlx::query_as!(
+                    AnswerRelationRow,
+                    r"SELECT first_form_id, first_answer_id, second_form_id, second_answer_id,
+                        kind, reversed AS `reversed: bool`
                     FROM answer_relations
                     WHERE (first_form_id = ? AND first_answer_id = ?)
                        OR (second_form_id = ? AND second_answer_id = ?)
@@ -75,16 +185,7 @@ impl FormAnswerRelationDatabase for ConnectionPool {
                 .fetch_all(&mut **txn)
                 .await?;
 
-                rows.into_iter()
-                    .map(|row| {
-                        relation_from_row(
-                            row.first_form_id,
-                            row.first_answer_id,
-                            row.second_form_id,
-                            row.second_answer_id,
-                        )
-                    })
-                    .collect()
+                rows.into_iter().map(relation_from_row).collect()
             })
         })
         .await
@@ -92,27 +193,8 @@ impl FormAnswerRelationDatabase for ConnectionPool {
 
     #[tracing::instrument(skip_all)]
     async fn add(&self, relation: AnswerRelation) -> Result<(), InfraError> {
-        let (first_form_id, first_answer_id, second_form_id, second_answer_id) =
-            relation_columns(relation);
-
-        self.read_write_transaction(|txn| {
-            Box::pin(async move {
-                sqlx::query!(
-                    r"INSERT INTO answer_relations
-                        (first_form_id, first_answer_id, second_form_id, second_answer_id)
-                    VALUES (?, ?, ?, ?)
-                    ON DUPLICATE KEY UPDATE first_form_id = first_form_id",
-                    first_form_id,
-                    first_answer_id,
-                    second_form_id,
-                    second_answer_id,
-                )
-                .execute(&mut **txn)
-                .await?;
-                Ok::<_, InfraError>(())
-            })
-        })
-        .await
+        self.read_write_transaction(|txn| Box::pin(upsert_relation(txn, relation)))
+            .await
     }
 
     #[tracing::instrument(skip_all)]
@@ -149,8 +231,10 @@ impl FormAnswerRelationDatabase for ConnectionPool {
         let answer_id = answer_id.to_string();
         self.read_only_transaction(|txn| {
             Box::pin(async move {
-                let row = sqlx::query!(
-                    r"SELECT first_form_id, first_answer_id, second_form_id, second_answer_id
+                let row = sqlx::query_as!(
+                    AnswerRelationRow,
+                    r"SELECT first_form_id, first_answer_id, second_form_id, second_answer_id,
+                        kind, reversed AS `reversed: bool`
                     FROM answer_relations
                     WHERE (first_form_id = ? AND first_answer_id = ? AND second_answer_id = ?)
                        OR (second_form_id = ? AND second_answer_id = ? AND first_answer_id = ?)
@@ -166,15 +250,7 @@ impl FormAnswerRelationDatabase for ConnectionPool {
                 .fetch_optional(&mut **txn)
                 .await?;
 
-                row.map(|row| {
-                    relation_from_row(
-                        row.first_form_id,
-                        row.first_answer_id,
-                        row.second_form_id,
-                        row.second_answer_id,
-                    )
-                })
-                .transpose()
+                row.map(relation_from_row).transpose()
             })
         })
         .await
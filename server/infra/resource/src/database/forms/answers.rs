@@ -5,8 +5,9 @@ use domain::{
     account::models::{AccountUser, Role},
     form::{
         answer::{
-            AnswerAuthor, AnswerEntry, AnswerId, AnswerStatus, AnswerStatusChange,
-            AnswerStatusHistoryPagePosition, AnswerTitleHistoryPagePosition,
+            AnswerAssignmentHistoryPagePosition, AnswerAuthor, AnswerEntry, AnswerId,
+            AnswerMoveHistoryPagePosition, AnswerReference, AnswerRelation, AnswerStatus,
+            AnswerStatusChange, AnswerStatusHistoryPagePosition, AnswerTitleHistoryPagePosition,
             RedmineImportedAnswerReference, RedmineUserSnapshot, TemporaryAnswerAuthor,
         },
         models::FormId,
@@ -21,13 +22,20 @@ use uuid::Uuid;
 
 use crate::{
     database::{
-        components::FormAnswerDatabase,
+        components::{FormAnswerDatabase, TemporaryAnswerCredentials},
         connection::{ConnectionPool, DatabaseTransaction},
         count::count_as_u32,
+        forms::{
+            answer_relations::{replace_relation_endpoint, upsert_relation},
+            temporary_answer_access::insert_temporary_answer_access,
+            temporary_answer_claim::insert_temporary_answer_claim_code,
+        },
     },
     records::{
-        AnswerAuthorRecord, AnswerStatusHistoryRecord, AnswerTitleHistoryRecord,
-        FormAnswerContentRecord, FormAnswerRecord, MessageRecord,
+        AnswerAssignmentHistoryRecord, AnswerAuthorRecord, AnswerContentCountRecord,
+        AnswerMoveHistoryRecord, AnswerStatisticsRecord, AnswerStatusHistoryRecord,
+        AnswerTitleHistoryRecord, DailyAnswerCountRecord, FormAnswerContentRecord,
+        FormAnswerRecord, LabelAnswerCountRecord, MessageRecord, StatusAnswerCountRecord,
     },
 };
 
@@ -223,12 +231,10 @@ where
     }
 
     let sql = format!(
-        r"SELECT messages.id AS id, related_answer_id AS related_answer,
-            sender AS sender_id, users.name AS sender_name,
-            users.role AS sender_role, body,
+        r"SELECT id, related_answer_id AS related_answer,
+            sender AS sender_id, temporary_sender AS temporary_sender_id, body,
             timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
         FROM messages
-        INNER JOIN users ON users.id = messages.sender
         WHERE related_answer_id IN ({})",
         std::iter::repeat_n("?", answer_ids.len()).join(", ")
     );
@@ -247,8 +253,7 @@ where
                 MessageRecord {
                     id: row.try_get("id")?,
                     sender_id: row.try_get("sender_id")?,
-                    sender_name: row.try_get("sender_name")?,
-                    sender_role: row.try_get("sender_role")?,
+                    temporary_sender_id: row.try_get("temporary_sender_id")?,
                     body: row.try_get("body")?,
                     timestamp: row.try_get("timestamp!: chrono::DateTime<chrono::Utc>")?,
                 },
@@ -294,6 +299,16 @@ pub(crate) fn attach_entry_children(
 impl FormAnswerDatabase for ConnectionPool {
     #[tracing::instrument(skip_all, fields(form_id = %form_id))]
     async fn post_answer(&self, answer: &AnswerEntry, form_id: FormId) -> Result<(), InfraError> {
+        self.post_answer_with_temporary_credentials(answer, form_id, None)
+            .await
+    }
+
+    async fn post_answer_with_temporary_credentials(
+        &self,
+        answer: &AnswerEntry,
+        form_id: FormId,
+        credentials: Option<TemporaryAnswerCredentials>,
+    ) -> Result<(), InfraError> {
         let answer_id = answer.id().to_owned().into_inner().to_string();
         let form_id = form_id.into_inner().to_string();
         let (author_type, user_id, temporary_user_id, redmine_user_id, redmine_author_name) =
@@ -304,6 +319,11 @@ impl FormAnswerDatabase for ConnectionPool {
             .map(|title| title.into_inner());
         let publication = answer.publication().to_string();
         let timestamp = answer.timestamp().to_owned();
+        let due_at = answer.due_at().to_owned();
+        let moderation_status = answer.moderation_status().to_string();
+        let quiz_score = answer
+            .quiz_score()
+            .map(|quiz_score| (*quiz_score.score(), *quiz_score.max_score()));
         let contents = answer
             .contents()
             .as_slice()
@@ -335,8 +355,8 @@ impl FormAnswerDatabase for ConnectionPool {
 
                 sqlx::query!(
                     r"INSERT INTO answers (id, form_id, author_type, user, temporary_user_id,
-                        redmine_user_id, redmine_author_name, title, publication, timestamp)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        redmine_user_id, redmine_author_name, title, publication, moderation_status, timestamp)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                     answer_id,
                     form_id,
                     author_type,
@@ -346,6 +366,7 @@ impl FormAnswerDatabase for ConnectionPool {
                     redmine_author_name,
                     title,
                     publication,
+                    moderation_status,
                     timestamp,
                 )
                 .execute(&mut **txn)
@@ -361,6 +382,27 @@ impl FormAnswerDatabase for ConnectionPool {
                     .await?;
                 }
 
+                if let Some(due_at) = due_at {
+                    sqlx::query!(
+                        "INSERT INTO answer_due_dates (answer_id, due_at) VALUES (?, ?)",
+                        answer_id,
+                        due_at,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                if let Some((score, max_score)) = quiz_score {
+                    sqlx::query!(
+                        "INSERT INTO answer_quiz_scores (answer_id, score, max_score) VALUES (?, ?, ?)",
+                        answer_id,
+                        score,
+                        max_score,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
                 if !contents.is_empty() {
                     let sql = format!(
                         "INSERT INTO real_answers (id, answer_id, question_id, answer) VALUES {}",
@@ -376,6 +418,21 @@ impl FormAnswerDatabase for ConnectionPool {
                         .await?;
                 }
 
+                if let Some(credentials) = credentials {
+                    insert_temporary_answer_access(
+                        txn,
+                        &credentials.access,
+                        credentials.access_token_hash,
+                    )
+                    .await?;
+                    insert_temporary_answer_claim_code(
+                        txn,
+                        &credentials.claim_ticket,
+                        credentials.claim_code_hash,
+                    )
+                    .await?;
+                }
+
                 Ok::<_, InfraError>(())
             })
         }).await
@@ -389,17 +446,23 @@ impl FormAnswerDatabase for ConnectionPool {
         self.read_only_transaction(|txn| {
             Box::pin(async move {
                 let answer_query_result_opt = sqlx::query!(
-                    r"SELECT form_id, answers.id AS answer_id, title, publication, status, author_type, user,
+                    r"SELECT form_id, answers.id AS answer_id, title, publication, status, moderation_status, author_type, user,
                         users.name AS user_name, users.role AS user_role,
                         temporary_user_id, temporary_users.name AS temporary_user_name,
                         temporary_users.contact_text AS temporary_user_contact_text,
                         answers.redmine_user_id, answers.redmine_author_name,
                         redmine_reference.redmine_issue_id,
+                        answer_assignees.assignee_id, answer_due_dates.due_at,
+                        answer_quiz_scores.score AS quiz_score,
+                        answer_quiz_scores.max_score AS max_quiz_score,
                         timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>` FROM answers
                         LEFT JOIN users ON answers.user = users.id
                         LEFT JOIN temporary_users ON answers.temporary_user_id = temporary_users.id
                         LEFT JOIN redmine_imported_answer_references redmine_reference
                             ON redmine_reference.answer_id = answers.id
+                        LEFT JOIN answer_assignees ON answer_assignees.answer_id = answers.id
+                        LEFT JOIN answer_due_dates ON answer_due_dates.answer_id = answers.id
+                        LEFT JOIN answer_quiz_scores ON answer_quiz_scores.answer_id = answers.id
                         WHERE answers.id = ?",
                     answer_id.into_inner().to_string(),
                 )
@@ -444,6 +507,7 @@ impl FormAnswerDatabase for ConnectionPool {
                             title: rs.title,
                             publication: rs.publication,
                             status: rs.status,
+                            moderation_status: rs.moderation_status,
                             contents,
                             messages: Vec::new(),
                             redmine_reference: rs
@@ -454,6 +518,10 @@ impl FormAnswerDatabase for ConnectionPool {
                                         issue_id.into(),
                                     )
                                 }),
+                            assignee_id: rs.assignee_id,
+                            due_at: rs.due_at,
+                            quiz_score: rs.quiz_score,
+                            max_quiz_score: rs.max_quiz_score,
                         })
                     })
                     .transpose()
@@ -479,17 +547,23 @@ impl FormAnswerDatabase for ConnectionPool {
         self.read_only_transaction(|txn| {
             Box::pin(async move {
                 let sql = format!(
-                    "SELECT form_id, answers.id AS answer_id, title, publication, status, author_type, user,
+                    "SELECT form_id, answers.id AS answer_id, title, publication, status, moderation_status, author_type, user,
                         users.name AS user_name, users.role AS user_role,
                         temporary_user_id, temporary_users.name AS temporary_user_name,
                         temporary_users.contact_text AS temporary_user_contact_text,
                         answers.redmine_user_id, answers.redmine_author_name,
                         redmine_reference.redmine_issue_id,
+                        answer_assignees.assignee_id, answer_due_dates.due_at,
+                        answer_quiz_scores.score AS quiz_score,
+                        answer_quiz_scores.max_score AS max_quiz_score,
                         timestamp FROM answers
                         LEFT JOIN users ON answers.user = users.id
                         LEFT JOIN temporary_users ON answers.temporary_user_id = temporary_users.id
                         LEFT JOIN redmine_imported_answer_references redmine_reference
                             ON redmine_reference.answer_id = answers.id
+                        LEFT JOIN answer_assignees ON answer_assignees.answer_id = answers.id
+                        LEFT JOIN answer_due_dates ON answer_due_dates.answer_id = answers.id
+                        LEFT JOIN answer_quiz_scores ON answer_quiz_scores.answer_id = answers.id
                         WHERE answers.id IN ({})
                         ORDER BY answers.timestamp",
                     std::iter::repeat_n("?", ids.len()).join(", ")
@@ -513,6 +587,7 @@ impl FormAnswerDatabase for ConnectionPool {
                             title: rs.try_get("title")?,
                             publication: rs.try_get("publication")?,
                             status: rs.try_get("status")?,
+                            moderation_status: rs.try_get("moderation_status")?,
                             contents: Vec::new(),
                             messages: Vec::new(),
                             redmine_reference: rs
@@ -523,6 +598,10 @@ impl FormAnswerDatabase for ConnectionPool {
                                         issue_id.into(),
                                     )
                                 }),
+                            assignee_id: rs.try_get("assignee_id")?,
+                            due_at: rs.try_get("due_at")?,
+                            quiz_score: rs.try_get("quiz_score")?,
+                            max_quiz_score: rs.try_get("max_quiz_score")?,
                         })
                     })
                     .collect::<Result<Vec<_>, _>>()?;
@@ -545,6 +624,17 @@ impl FormAnswerDatabase for ConnectionPool {
         answer_entry: &AnswerEntry,
         form_id: FormId,
         updated_by: &AccountUser,
+    ) -> Result<Option<AnswerStatusChange>, InfraError> {
+        self.update_answer_entry_with_relation(answer_entry, form_id, updated_by, None)
+            .await
+    }
+
+    async fn update_answer_entry_with_relation(
+        &self,
+        answer_entry: &AnswerEntry,
+        form_id: FormId,
+        updated_by: &AccountUser,
+        relation: Option<AnswerRelation>,
     ) -> Result<Option<AnswerStatusChange>, InfraError> {
         let answer_id = answer_entry.id().to_owned().into_inner().to_string();
         let form_id = form_id.into_inner().to_string();
@@ -555,10 +645,16 @@ impl FormAnswerDatabase for ConnectionPool {
         let publication = answer_entry.publication().to_string();
         let status = *answer_entry.status();
         let persisted_status = status.to_string();
+        let moderation_status = answer_entry.moderation_status().to_string();
+        let assignee_id = answer_entry.assignee().map(|assignee| assignee.to_string());
+        let due_at = answer_entry.due_at().to_owned();
         let updated_by = updated_by.clone();
 
         self.read_write_transaction(|txn| {
             Box::pin(async move {
+                if let Some(relation) = relation {
+                    upsert_relation(txn, relation).await?;
+                }
                 if let Some(temporary_user) = temporary_user {
                     sqlx::query!(
                         r"INSERT INTO temporary_users (id, name, contact_text)
@@ -624,13 +720,93 @@ impl FormAnswerDatabase for ConnectionPool {
                     .await?;
                 }
 
+                let current_assignee_id = sqlx::query_scalar!(
+                    "SELECT assignee_id FROM answer_assignees WHERE answer_id = ? FOR UPDATE",
+                    answer_id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?;
+
+                if current_assignee_id != assignee_id {
+                    sqlx::query!(
+                        r"INSERT INTO form_answer_assignment_history
+                        (id, answer_id, from_assignee_id, to_assignee_id, changed_by_id, changed_by_name, changed_by_role)
+                        VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        Uuid::now_v7().to_string(),
+                        answer_id,
+                        current_assignee_id,
+                        assignee_id.clone(),
+                        updated_by.id().to_string(),
+                        updated_by.name(),
+                        updated_by.role().to_string(),
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+
+                    match &assignee_id {
+                        Some(assignee_id) => {
+                            sqlx::query!(
+                                r"INSERT INTO answer_assignees (answer_id, assignee_id)
+                                VALUES (?, ?)
+                                ON DUPLICATE KEY UPDATE assignee_id = VALUES(assignee_id), assigned_at = CURRENT_TIMESTAMP",
+                                answer_id,
+                                assignee_id,
+                            )
+                            .execute(&mut **txn)
+                            .await?;
+                        }
+                        None => {
+                            sqlx::query!(
+                                "DELETE FROM answer_assignees WHERE answer_id = ?",
+                                answer_id,
+                            )
+                            .execute(&mut **txn)
+                            .await?;
+                        }
+                    }
+                }
+
+                let current_due_at = sqlx::query_scalar!(
+                    r"SELECT due_at AS `due_at: chrono::DateTime<chrono::Utc>`
+                    FROM answer_due_dates WHERE answer_id = ? FOR UPDATE",
+                    answer_id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?;
+
+                // 期限を変更したら、新しい期限を過ぎたときに改めて通知する
+                if current_due_at != due_at {
+                    match due_at {
+                        Some(due_at) => {
+                            sqlx::query!(
+                                r"INSERT INTO answer_due_dates (answer_id, due_at)
+                                VALUES (?, ?)
+                                ON DUPLICATE KEY UPDATE due_at = VALUES(due_at), overdue_notified_at = NULL",
+                                answer_id,
+                                due_at,
+                            )
+                            .execute(&mut **txn)
+                            .await?;
+                        }
+                        None => {
+                            sqlx::query!(
+                                "DELETE FROM answer_due_dates WHERE answer_id = ?",
+                                answer_id,
+                            )
+                            .execute(&mut **txn)
+                            .await?;
+                        }
+                    }
+                }
+
                 sqlx::query!(
                     r"UPDATE answers
-                    SET title = ?, publication = ?, status = ?
+                    SET title = ?, publication = ?, status = ?, moderation_status = ?
                     WHERE id = ? AND form_id = ?",
                     title,
                     publication,
                     persisted_status,
+                    moderation_status,
                     answer_id,
                     form_id,
                 )
@@ -761,13 +937,347 @@ impl FormAnswerDatabase for ConnectionPool {
         }))
     }
 
+    async fn fetch_assignment_history(
+        &self,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<Page<AnswerAssignmentHistoryRecord, AnswerAssignmentHistoryPagePosition>, InfraError>
+    {
+        let answer_id = answer_id.to_string();
+        let after = request
+            .after_position()
+            .map(|position| position.id().to_string());
+        let limit = request.limit();
+        let overfetch = limit.overfetch_value();
+        let rows = match after {
+            Some(after) => {
+                sqlx::query_as!(
+                    AnswerAssignmentHistoryRecord,
+                    r"SELECT id, answer_id, from_assignee_id, to_assignee_id, changed_by_id,
+                        changed_by_name, changed_by_role,
+                        changed_at AS `changed_at!: chrono::DateTime<chrono::Utc>`
+                    FROM form_answer_assignment_history
+                    WHERE answer_id = ? AND id < ?
+                    ORDER BY id DESC LIMIT ?",
+                    answer_id,
+                    after,
+                    overfetch,
+                )
+                .fetch_all(&self.rdb_pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as!(
+                    AnswerAssignmentHistoryRecord,
+                    r"SELECT id, answer_id, from_assignee_id, to_assignee_id, changed_by_id,
+                        changed_by_name, changed_by_role,
+                        changed_at AS `changed_at!: chrono::DateTime<chrono::Utc>`
+                    FROM form_answer_assignment_history
+                    WHERE answer_id = ?
+                    ORDER BY id DESC LIMIT ?",
+                    answer_id,
+                    overfetch,
+                )
+                .fetch_all(&self.rdb_pool)
+                .await?
+            }
+        };
+
+        Ok(Page::from_overfetched_items(rows, limit, |row| {
+            AnswerAssignmentHistoryPagePosition::new(
+                Uuid::parse_str(&row.id)
+                    .expect("history IDs stored by this service are valid UUIDs")
+                    .into(),
+            )
+        }))
+    }
+
+    #[tracing::instrument(skip_all, fields(from_form_id = %from_form_id))]
+    async fn move_answer_entry(
+        &self,
+        answer_entry: &AnswerEntry,
+        from_form_id: FormId,
+        moved_by: &AccountUser,
+    ) -> Result<(), InfraError> {
+        let answer_id = answer_entry.id().to_string();
+        let from_reference = AnswerReference::new(from_form_id, *answer_entry.id());
+        let to_reference = AnswerReference::new(*answer_entry.form_id(), *answer_entry.id());
+        let from_form_id = from_form_id.to_string();
+        let to_form_id = answer_entry.form_id().to_string();
+        let contents = answer_entry
+            .contents()
+            .iter()
+            .map(|content| (content.id.to_string(), content.question_id.to_string()))
+            .collect_vec();
+        let moved_by = moved_by.clone();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query_scalar!(
+                    "SELECT id FROM answers WHERE id = ? AND form_id = ? FOR UPDATE",
+                    answer_id,
+                    from_form_id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?
+                .ok_or_else(|| InfraError::Unexpected {
+                    cause: "answer to move was not found in the source form".to_string(),
+                })?;
+
+                sqlx::query!(
+                    "UPDATE answers SET form_id = ? WHERE id = ?",
+                    to_form_id,
+                    answer_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                for (content_id, question_id) in contents {
+                    sqlx::query!(
+                        "UPDATE real_answers SET question_id = ? WHERE id = ? AND answer_id = ?",
+                        question_id,
+                        content_id,
+                        answer_id,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                replace_relation_endpoint(txn, from_reference, to_reference).await?;
+
+                sqlx::query!(
+                    r"INSERT INTO form_answer_move_history
+                    (id, answer_id, from_form_id, to_form_id, changed_by_id, changed_by_name, changed_by_role)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    Uuid::now_v7().to_string(),
+                    answer_id,
+                    from_form_id,
+                    to_form_id,
+                    moved_by.id().to_string(),
+                    moved_by.name(),
+                    moved_by.role().to_string(),
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    async fn fetch_move_history(
+        &self,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<AnswerMoveHistoryRecord, AnswerMoveHistoryPagePosition>, InfraError> {
+        let answer_id = answer_id.to_string();
+        let after = request
+            .after_position()
+            .map(|position| position.id().to_string());
+        let limit = request.limit();
+        let overfetch = limit.overfetch_value();
+        let rows = match after {
+            Some(after) => {
+                sqlx::query_as!(
+                    AnswerMoveHistoryRecord,
+                    r"SELECT id, answer_id, from_form_id, to_form_id, changed_by_id,
+                        changed_by_name, changed_by_role,
+                        changed_at AS `changed_at!: chrono::DateTime<chrono::Utc>`
+                    FROM form_answer_move_history
+                    WHERE answer_id = ? AND id < ?
+                    ORDER BY id DESC LIMIT ?",
+                    answer_id,
+                    after,
+                    overfetch,
+                )
+                .fetch_all(&self.rdb_pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as!(
+                    AnswerMoveHistoryRecord,
+                    r"SELECT id, answer_id, from_form_id, to_form_id, changed_by_id,
+                        changed_by_name, changed_by_role,
+                        changed_at AS `changed_at!: chrono::DateTime<chrono::Utc>`
+                    FROM form_answer_move_history
+                    WHERE answer_id = ?
+                    ORDER BY id DESC LIMIT ?",
+                    answer_id,
+                    overfetch,
+                )
+                .fetch_all(&self.rdb_pool)
+                .await?
+            }
+        };
+
+        Ok(Page::from_overfetched_items(rows, limit, |row| {
+            AnswerMoveHistoryPagePosition::new(
+                Uuid::parse_str(&row.id)
+                    .expect("history IDs stored by this service are valid UUIDs")
+                    .into(),
+            )
+        }))
+    }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    async fn mark_overdue_notified(&self, answer_id: AnswerId) -> Result<bool, InfraError> {
+        let answer_id = answer_id.into_inner().to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                let result = sqlx::query!(
+                    r"UPDATE answer_due_dates SET overdue_notified_at = CURRENT_TIMESTAMP
+                    WHERE answer_id = ? AND overdue_notified_at IS NULL
+                        AND due_at <= CURRENT_TIMESTAMP",
+                    answer_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(result.rows_affected() == 1)
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn aggregate_answers(
+        &self,
+        form_id: FormId,
+        public_only: bool,
+    ) -> Result<AnswerStatisticsRecord, InfraError> {
+        let form_id = form_id.into_inner().to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let total = sqlx::query_scalar!(
+                    r"SELECT COUNT(*) AS `count!: i64` FROM answers
+                    WHERE form_id = ? AND (? = FALSE OR publication = 'PUBLIC')
+                        AND moderation_status = 'APPROVED'",
+                    form_id,
+                    public_only,
+                )
+                .fetch_one(&mut **txn)
+                .await?;
+
+                // 選択肢との突き合わせは質問定義を持つドメイン側で行うため、
+                // ここでは選択式の質問への回答本文ごとの件数までを集計する
+                let contents = sqlx::query!(
+                    r"SELECT real_answers.question_id, real_answers.answer,
+                        COUNT(*) AS `count!: i64`
+                    FROM real_answers
+                    INNER JOIN answers ON answers.id = real_answers.answer_id
+                    INNER JOIN form_questions
+                        ON form_questions.question_id = real_answers.question_id
+                    WHERE answers.form_id = ? AND (? = FALSE OR answers.publication = 'PUBLIC')
+                        AND answers.moderation_status = 'APPROVED'
+                        AND UPPER(form_questions.question_type) <> 'TEXT'
+                    GROUP BY real_answers.question_id, real_answers.answer",
+                    form_id,
+                    public_only,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(AnswerContentCountRecord {
+                        question_id: row.question_id,
+                        answer: row.answer,
+                        count: count_as_u32(row.count, "real_answers")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                let daily = sqlx::query!(
+                    r"SELECT DATE(timestamp) AS `date!: chrono::NaiveDate`,
+                        COUNT(*) AS `count!: i64`
+                    FROM answers
+                    WHERE form_id = ? AND (? = FALSE OR publication = 'PUBLIC')
+                        AND moderation_status = 'APPROVED'
+                    GROUP BY DATE(timestamp)
+                    ORDER BY DATE(timestamp)",
+                    form_id,
+                    public_only,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(DailyAnswerCountRecord {
+                        date: row.date,
+                        count: count_as_u32(row.count, "answers")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                let statuses = sqlx::query!(
+                    r"SELECT status, COUNT(*) AS `count!: i64`
+                    FROM answers
+                    WHERE form_id = ? AND (? = FALSE OR publication = 'PUBLIC')
+                        AND moderation_status = 'APPROVED'
+                    GROUP BY status",
+                    form_id,
+                    public_only,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(StatusAnswerCountRecord {
+                        status: row.status,
+                        count: count_as_u32(row.count, "answers")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                let labels = sqlx::query!(
+                    r"SELECT label_for_form_answers.id, label_for_form_answers.name,
+                        COUNT(*) AS `count!: i64`
+                    FROM label_settings_for_form_answers
+                    INNER JOIN label_for_form_answers
+                        ON label_for_form_answers.id = label_settings_for_form_answers.label_id
+                    INNER JOIN answers ON answers.id = label_settings_for_form_answers.answer_id
+                    WHERE answers.form_id = ? AND (? = FALSE OR answers.publication = 'PUBLIC')
+                        AND answers.moderation_status = 'APPROVED'
+                    GROUP BY label_for_form_answers.id, label_for_form_answers.name
+                    ORDER BY label_for_form_answers.name",
+                    form_id,
+                    public_only,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(LabelAnswerCountRecord {
+                        label_id: row.id,
+                        name: row.name,
+                        count: count_as_u32(row.count, "label_settings_for_form_answers")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                Ok::<_, InfraError>(AnswerStatisticsRecord {
+                    total: count_as_u32(total, "answers")?,
+                    contents,
+                    daily,
+                    statuses,
+                    labels,
+                })
+            })
+        })
+        .await
+    }
+
     #[tracing::instrument(skip_all)]
     async fn size(&self) -> Result<u32, InfraError> {
         self.read_only_transaction(|txn| {
             Box::pin(async move {
-                let size = sqlx::query_scalar!("SELECT COUNT(*) AS `count!: i64` FROM answers")
-                    .fetch_one(&mut **txn)
-                    .await?;
+                let size = sqlx::query_scalar!(
+                    "SELECT COUNT(*) AS `count!: i64` FROM answers WHERE moderation_status = 'APPROVED'"
+                )
+                .fetch_one(&mut **txn)
+                .await?;
 
                 count_as_u32(size, "answers")
             })
@@ -779,10 +1289,13 @@ impl FormAnswerDatabase for ConnectionPool {
     async fn content_size(&self) -> Result<u32, InfraError> {
         self.read_only_transaction(|txn| {
             Box::pin(async move {
-                let size =
-                    sqlx::query_scalar!("SELECT COUNT(*) AS `count!: i64` FROM real_answers")
-                        .fetch_one(&mut **txn)
-                        .await?;
+                let size = sqlx::query_scalar!(
+                    r"SELECT COUNT(*) AS `count!: i64` FROM real_answers
+                    INNER JOIN answers ON answers.id = real_answers.answer_id
+                    WHERE answers.moderation_status = 'APPROVED'"
+                )
+                .fetch_one(&mut **txn)
+                .await?;
 
                 count_as_u32(size, "real_answers")
             })
@@ -20,7 +20,9 @@ use domain::{
     auth::Actor,
     form::{
         answer::{AnswerAuthor, AnswerId, AnswerPublication, AnswerSettings, AnswerVisibility},
-        comment::{Comment, CommentHistoryPagePosition, CommentId, DeletedComment},
+        comment::{
+            Comment, CommentHistoryPagePosition, CommentId, CommentVisibility, DeletedComment,
+        },
         comment_thread::CommentThread,
         models::{ActiveForm, FormId, active_form_allows_read},
         settings::{AllowedUserGroups, Visibility},
@@ -68,7 +70,7 @@ impl FormCommentDatabase for ConnectionPool {
             Box::pin(async move {
                 let comment = sqlx::query_as!(
                     PortalCommentRecord,
-                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, is_internal AS `is_internal: bool`, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM form_answer_comments
                     INNER JOIN users ON form_answer_comments.commented_by = users.id
                     WHERE form_answer_comments.id = ?",
@@ -109,7 +111,7 @@ impl FormCommentDatabase for ConnectionPool {
             Box::pin(async move {
                 let portal_comments = sqlx::query_as!(
                     PortalCommentRecord,
-                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, is_internal AS `is_internal: bool`, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM form_answer_comments
                     INNER JOIN users ON form_answer_comments.commented_by = users.id
                     WHERE answer_id = ?
@@ -153,7 +155,7 @@ impl FormCommentDatabase for ConnectionPool {
             Box::pin(async move {
                 let portal_comments = sqlx::query_as!(
                     PortalCommentRecord,
-                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT form_answer_comments.id AS comment_id, answer_id, commented_by AS commented_by_id, name AS commented_by_name, role AS commented_by_role, content, is_internal AS `is_internal: bool`, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM form_answer_comments
                     INNER JOIN users ON form_answer_comments.commented_by = users.id
                     ORDER BY form_answer_comments.timestamp ASC, form_answer_comments.id ASC"
@@ -276,7 +278,7 @@ impl FormCommentDatabase for ConnectionPool {
         &self,
         answer_id: AnswerId,
         request: PageRequest<CommentHistoryPagePosition>,
-        includes_deleted_history: bool,
+        includes_staff_only_history: bool,
     ) -> Result<Page<CommentHistoryRecord, CommentHistoryPagePosition>, InfraError> {
         let answer_id = answer_id.to_string();
         let after = request
@@ -284,14 +286,14 @@ impl FormCommentDatabase for ConnectionPool {
             .map(|position| position.id().to_string());
         let limit = request.limit();
         let overfetch = limit.overfetch_value();
-        let rows = match (includes_deleted_history, after) {
+        let rows = match (includes_staff_only_history, after) {
             (true, Some(after)) => {
                 self.read_only_transaction(|txn| Box::pin(async move {
                     sqlx::query_as!(
                         CommentHistoryRecord,
                         r"SELECT id, answer_id, comment_id, original_author_id, original_author_name,
                             original_author_role, original_timestamp AS `original_timestamp!: chrono::DateTime<chrono::Utc>`,
-                            action, content, operated_by_id, operated_by_name,
+                            action, content, is_internal AS `is_internal: bool`, operated_by_id, operated_by_name,
                             operated_by_role, operated_at AS `operated_at!: chrono::DateTime<chrono::Utc>`
                         FROM form_answer_comment_history
                         WHERE answer_id = ? AND id < ?
@@ -312,7 +314,7 @@ impl FormCommentDatabase for ConnectionPool {
                         CommentHistoryRecord,
                         r"SELECT id, answer_id, comment_id, original_author_id, original_author_name,
                             original_author_role, original_timestamp AS `original_timestamp!: chrono::DateTime<chrono::Utc>`,
-                            action, content, operated_by_id, operated_by_name,
+                            action, content, is_internal AS `is_internal: bool`, operated_by_id, operated_by_name,
                             operated_by_role, operated_at AS `operated_at!: chrono::DateTime<chrono::Utc>`
                         FROM form_answer_comment_history
                         WHERE answer_id = ?
@@ -332,10 +334,10 @@ impl FormCommentDatabase for ConnectionPool {
                         CommentHistoryRecord,
                         r"SELECT id, answer_id, comment_id, original_author_id, original_author_name,
                             original_author_role, original_timestamp AS `original_timestamp!: chrono::DateTime<chrono::Utc>`,
-                            action, content, operated_by_id, operated_by_name,
+                            action, content, is_internal AS `is_internal: bool`, operated_by_id, operated_by_name,
                             operated_by_role, operated_at AS `operated_at!: chrono::DateTime<chrono::Utc>`
                         FROM form_answer_comment_history
-                        WHERE answer_id = ? AND action != 'DELETE' AND id < ?
+                        WHERE answer_id = ? AND action != 'DELETE' AND is_internal = FALSE AND id < ?
                         ORDER BY id DESC LIMIT ?",
                         answer_id,
                         after,
@@ -353,10 +355,10 @@ impl FormCommentDatabase for ConnectionPool {
                         CommentHistoryRecord,
                         r"SELECT id, answer_id, comment_id, original_author_id, original_author_name,
                             original_author_role, original_timestamp AS `original_timestamp!: chrono::DateTime<chrono::Utc>`,
-                            action, content, operated_by_id, operated_by_name,
+                            action, content, is_internal AS `is_internal: bool`, operated_by_id, operated_by_name,
                             operated_by_role, operated_at AS `operated_at!: chrono::DateTime<chrono::Utc>`
                         FROM form_answer_comment_history
-                        WHERE answer_id = ? AND action != 'DELETE'
+                        WHERE answer_id = ? AND action != 'DELETE' AND is_internal = FALSE
                         ORDER BY id DESC LIMIT ?",
                         answer_id,
                         overfetch,
@@ -493,7 +495,7 @@ async fn lock_comment_thread(
                 PortalCommentRecord,
                 r"SELECT c.id AS comment_id, c.answer_id, c.commented_by AS commented_by_id,
                 u.name AS commented_by_name, u.role AS commented_by_role, c.content,
-                c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                c.is_internal AS `is_internal: bool`, c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
             FROM form_answer_comments c
             INNER JOIN users u ON u.id = c.commented_by
             WHERE c.id = ? AND c.answer_id = ? FOR UPDATE",
@@ -555,23 +557,24 @@ async fn insert_created_comment(
         .ok_or(DomainError::Forbidden)?
         .to_string();
     let content = comment.content().to_string();
+    let is_internal = comment.visibility() == &CommentVisibility::Internal;
     let timestamp = *comment.timestamp();
     sqlx::query!(
-        "INSERT INTO form_answer_comments (id, answer_id, commented_by, content, timestamp) VALUES (?, ?, ?, ?, ?)",
-        comment_id, answer_id, commented_by, content, timestamp,
+        "INSERT INTO form_answer_comments (id, answer_id, commented_by, content, is_internal, timestamp) VALUES (?, ?, ?, ?, ?, ?)",
+        comment_id, answer_id, commented_by, content, is_internal, timestamp,
     ).execute(&mut **transaction).await.map_err(InfraError::from)?;
     // The candidate author is the current actor; its role/name snapshot is intentionally captured now.
     sqlx::query!(
         r"INSERT INTO form_answer_comment_history
         (id, answer_id, comment_id, original_author_id, original_author_name, original_author_role,
-         original_timestamp, action, content, operated_by_id, operated_by_name, operated_by_role, operated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, 'CREATE', ?, ?, ?, ?, ?)",
+         original_timestamp, action, content, is_internal, operated_by_id, operated_by_name, operated_by_role, operated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, 'CREATE', ?, ?, ?, ?, ?, ?)",
         Uuid::now_v7().to_string(), comment.answer_id().to_string(), comment.comment_id().to_string(),
         comment
             .commented_by()
             .ok_or(DomainError::Forbidden)?
             .to_string(), actor.name(), actor.role().to_string(), timestamp,
-        comment.content().to_string(), actor.id().to_string(), actor.name(), actor.role().to_string(), timestamp,
+        comment.content().to_string(), is_internal, actor.id().to_string(), actor.name(), actor.role().to_string(), timestamp,
     ).execute(&mut **transaction).await.map_err(InfraError::from)?;
     Ok(())
 }
@@ -588,7 +591,8 @@ async fn update_comment_with_history(
     let content = comment.content().to_string();
     let current = sqlx::query!(
         r"SELECT c.commented_by AS original_author_id, u.name AS original_author_name,
-        u.role AS original_author_role, c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+        u.role AS original_author_role, c.is_internal AS `is_internal: bool`,
+        c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
         FROM form_answer_comments c INNER JOIN users u ON u.id = c.commented_by WHERE c.id = ?",
         comment_id,
     )
@@ -598,11 +602,11 @@ async fn update_comment_with_history(
     sqlx::query!(
         r"INSERT INTO form_answer_comment_history
         (id, answer_id, comment_id, original_author_id, original_author_name, original_author_role,
-         original_timestamp, action, content, operated_by_id, operated_by_name, operated_by_role, operated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, 'UPDATE', ?, ?, ?, ?, ?)",
+         original_timestamp, action, content, is_internal, operated_by_id, operated_by_name, operated_by_role, operated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, 'UPDATE', ?, ?, ?, ?, ?, ?)",
         Uuid::now_v7().to_string(), answer_id, comment_id, current.original_author_id,
         current.original_author_name, current.original_author_role, current.timestamp, content,
-        actor.id().to_string(), actor.name(), actor.role().to_string(), operated_at,
+        current.is_internal, actor.id().to_string(), actor.name(), actor.role().to_string(), operated_at,
     ).execute(&mut **transaction).await.map_err(InfraError::from)?;
     sqlx::query!(
         "UPDATE form_answer_comments SET content = ? WHERE id = ?",
@@ -624,18 +628,22 @@ async fn delete_comment_with_history(
     let actor = deleted.deleted_by();
     let current = sqlx::query!(
         r"SELECT c.answer_id, c.commented_by AS original_author_id, u.name AS original_author_name,
-        u.role AS original_author_role, c.content, c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+        u.role AS original_author_role, c.content, c.is_internal AS `is_internal: bool`,
+        c.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
         FROM form_answer_comments c INNER JOIN users u ON u.id = c.commented_by WHERE c.id = ?",
         comment_id,
-    ).fetch_one(&mut **transaction).await.map_err(InfraError::from)?;
+    )
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(InfraError::from)?;
     sqlx::query!(
         r"INSERT INTO form_answer_comment_history
         (id, answer_id, comment_id, original_author_id, original_author_name, original_author_role,
-         original_timestamp, action, content, operated_by_id, operated_by_name, operated_by_role, operated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, 'DELETE', ?, ?, ?, ?, ?)",
+         original_timestamp, action, content, is_internal, operated_by_id, operated_by_name, operated_by_role, operated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, 'DELETE', ?, ?, ?, ?, ?, ?)",
         Uuid::now_v7().to_string(), current.answer_id, comment_id, current.original_author_id,
         current.original_author_name, current.original_author_role, current.timestamp, current.content,
-        actor.id().to_string(), actor.name(), actor.role().to_string(), deleted.deleted_at(),
+        current.is_internal, actor.id().to_string(), actor.name(), actor.role().to_string(), deleted.deleted_at(),
     ).execute(&mut **transaction).await.map_err(InfraError::from)?;
     sqlx::query!("DELETE FROM form_answer_comments WHERE id = ?", comment_id)
         .execute(&mut **transaction)
@@ -663,6 +671,7 @@ mod tests {
                 answer_id: "answer".to_string(),
                 comment_id: "b".to_string(),
                 content: "portal b".to_string(),
+                is_internal: false,
                 timestamp,
                 commented_by_name: "Portal user".to_string(),
                 commented_by_id: "user".to_string(),
@@ -681,6 +690,7 @@ mod tests {
                 answer_id: "answer".to_string(),
                 comment_id: "a".to_string(),
                 content: "portal a".to_string(),
+                is_internal: false,
                 timestamp,
                 commented_by_name: "Portal user".to_string(),
                 commented_by_id: "user".to_string(),
@@ -0,0 +1,305 @@
+use domain::form::{
+    content_filter::{
+        ContentFilterAction, ContentFilterCondition, ContentFilterEnforcement, ContentFilterFlagId,
+        ContentFilterRule, ContentFilterRuleId, ContentFilterTarget,
+    },
+    models::FormId,
+};
+use errors::infra::InfraError;
+
+use crate::{
+    database::connection::ConnectionPool,
+    records::{ContentFilterFlagRecord, ContentFilterRuleRecord},
+};
+
+/// 条件を `condition_type`・`pattern`・`threshold` の列の値に変換する。
+fn condition_columns(
+    condition: &ContentFilterCondition,
+) -> (&'static str, Option<String>, Option<u32>) {
+    match condition {
+        ContentFilterCondition::BlockedWord(word) => {
+            ("BLOCKED_WORD", Some(word.as_str().to_owned()), None)
+        }
+        ContentFilterCondition::Pattern(pattern) => {
+            ("PATTERN", Some(pattern.as_str().to_owned()), None)
+        }
+        ContentFilterCondition::MaxLinks(max_links) => ("MAX_LINKS", None, Some(*max_links)),
+        ContentFilterCondition::RepeatedCharacters(run_length) => {
+            ("REPEATED_CHARACTERS", None, Some(*run_length))
+        }
+    }
+}
+
+/// 処置を `action`・`label_id` の列の値に変換する。
+fn action_columns(action: &ContentFilterAction) -> (&'static str, Option<String>) {
+    match action {
+        ContentFilterAction::Reject => ("REJECT", None),
+        ContentFilterAction::FlagForReview => ("FLAG_FOR_REVIEW", None),
+        ContentFilterAction::AddLabel(label_id) => ("ADD_LABEL", Some(label_id.to_string())),
+    }
+}
+
+/// 対象を `target_type`・`target_id` の列の値に変換する。
+fn target_columns(target: &ContentFilterTarget) -> (&'static str, Option<String>) {
+    match target {
+        ContentFilterTarget::Answer => ("ANSWER", None),
+        ContentFilterTarget::Comment(comment_id) => ("COMMENT", Some(comment_id.to_string())),
+        ContentFilterTarget::Message(message_id) => ("MESSAGE", Some(message_id.to_string())),
+    }
+}
+
+impl ConnectionPool {
+    #[tracing::instrument(skip_all, fields(form_id = %form_id))]
+    pub(crate) async fn fetch_content_filter_rules(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<ContentFilterRuleRecord>, InfraError> {
+        let form_id = form_id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        ContentFilterRuleRecord,
+                        r"SELECT id, form_id, condition_type, pattern, threshold, action, label_id, created_by,
+                            created_at AS `created_at: chrono::DateTime<chrono::Utc>`
+                        FROM content_filter_rules
+                        WHERE form_id = ?
+                        ORDER BY created_at, id",
+                        form_id,
+                    )
+                    .fetch_all(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn fetch_content_filter_rule(
+        &self,
+        id: ContentFilterRuleId,
+    ) -> Result<Option<ContentFilterRuleRecord>, InfraError> {
+        let id = id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        ContentFilterRuleRecord,
+                        r"SELECT id, form_id, condition_type, pattern, threshold, action, label_id, created_by,
+                            created_at AS `created_at: chrono::DateTime<chrono::Utc>`
+                        FROM content_filter_rules
+                        WHERE id = ?",
+                        id,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %rule.id()))]
+    pub(crate) async fn insert_content_filter_rule(
+        &self,
+        rule: &ContentFilterRule,
+    ) -> Result<(), InfraError> {
+        let id = rule.id().to_string();
+        let form_id = rule.form_id().to_string();
+        let (condition_type, pattern, threshold) = condition_columns(rule.condition());
+        let (action, label_id) = action_columns(rule.action());
+        let created_by = rule.created_by().to_string();
+        let created_at = *rule.created_at();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO content_filter_rules (id, form_id, condition_type, pattern, threshold, action, label_id, created_by, created_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    id,
+                    form_id,
+                    condition_type,
+                    pattern,
+                    threshold,
+                    action,
+                    label_id,
+                    created_by,
+                    created_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn delete_content_filter_rule(
+        &self,
+        id: ContentFilterRuleId,
+    ) -> Result<(), InfraError> {
+        let id = id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(r"DELETE FROM content_filter_rules WHERE id = ?", id)
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    /// 確認待ちの記録と、回答へのラベルをまとめて保存する。
+    ///
+    /// 回答に付いているラベルはそのまま残し、まだ付いていないラベルだけを追加する。
+    #[tracing::instrument(skip_all, fields(answer_id = %enforcement.answer_id()))]
+    pub(crate) async fn record_content_filter_enforcement(
+        &self,
+        enforcement: &ContentFilterEnforcement,
+    ) -> Result<(), InfraError> {
+        let answer_id = enforcement.answer_id().to_string();
+        let flags = enforcement
+            .flags()
+            .iter()
+            .map(|flag| {
+                let (target_type, target_id) = target_columns(flag.target());
+                (
+                    flag.id().to_string(),
+                    flag.form_id().to_string(),
+                    flag.answer_id().to_string(),
+                    target_type,
+                    target_id,
+                    flag.rule_id().map(|rule_id| rule_id.to_string()),
+                    *flag.flagged_at(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let label_ids = enforcement
+            .label_ids()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                for (id, form_id, flag_answer_id, target_type, target_id, rule_id, flagged_at) in
+                    flags
+                {
+                    sqlx::query!(
+                        r"INSERT INTO content_filter_flags (id, form_id, answer_id, target_type, target_id, rule_id, flagged_at)
+                        VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        id,
+                        form_id,
+                        flag_answer_id,
+                        target_type,
+                        target_id,
+                        rule_id,
+                        flagged_at,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                for label_id in label_ids {
+                    sqlx::query!(
+                        r"INSERT INTO label_settings_for_form_answers (answer_id, label_id)
+                        SELECT ?, ? FROM DUAL
+                        WHERE NOT EXISTS (
+                            SELECT 1 FROM label_settings_for_form_answers
+                            WHERE answer_id = ? AND label_id = ?
+                        )",
+                        answer_id,
+                        label_id,
+                        answer_id,
+                        label_id,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(form_id = %form_id))]
+    pub(crate) async fn fetch_content_filter_flags(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<ContentFilterFlagRecord>, InfraError> {
+        let form_id = form_id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        ContentFilterFlagRecord,
+                        r"SELECT id, form_id, answer_id, target_type, target_id, rule_id,
+                            flagged_at AS `flagged_at: chrono::DateTime<chrono::Utc>`
+                        FROM content_filter_flags
+                        WHERE form_id = ?
+                        ORDER BY flagged_at DESC, id",
+                        form_id,
+                    )
+                    .fetch_all(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn fetch_content_filter_flag(
+        &self,
+        id: ContentFilterFlagId,
+    ) -> Result<Option<ContentFilterFlagRecord>, InfraError> {
+        let id = id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        ContentFilterFlagRecord,
+                        r"SELECT id, form_id, answer_id, target_type, target_id, rule_id,
+                            flagged_at AS `flagged_at: chrono::DateTime<chrono::Utc>`
+                        FROM content_filter_flags
+                        WHERE id = ?",
+                        id,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn delete_content_filter_flag(
+        &self,
+        id: ContentFilterFlagId,
+    ) -> Result<(), InfraError> {
+        let id = id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(r"DELETE FROM content_filter_flags WHERE id = ?", id)
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+}
@@ -2,9 +2,15 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::account::models::UserGroupId;
 use domain::form::{
-    answer::{AnswerEntry, AnswerId, AnswerPagePosition, AnswerPublication, AnswerStatus},
-    models::{ArchivedFormPagePosition, FormLabelId, FormPagePosition, FormSettings},
-    question::{Choice, Question, QuestionId, QuestionType},
+    answer::{
+        AnswerAssigneeFilter, AnswerEntry, AnswerId, AnswerListFilter, AnswerListOrder,
+        AnswerPagePosition, AnswerPublication,
+    },
+    models::{
+        ArchivedFormPagePosition, FormLabelId, FormPagePosition, FormSettings,
+        ResponseDeadlineHours,
+    },
+    question::{Choice, Question, QuestionId, QuestionType, QuizPoints},
 };
 use domain::{
     account::models::{AccountUser, Role},
@@ -50,6 +56,11 @@ struct FormRow {
     acceptance_period_start_at: Option<DateTime<Utc>>,
     acceptance_period_end_at: Option<DateTime<Utc>>,
     default_answer_title: Option<String>,
+    response_deadline_hours: Option<u32>,
+    quiz_enabled: bool,
+    show_quiz_score_to_author: bool,
+    lock_message_thread_on_completion: bool,
+    hold_temporary_answers_for_review: bool,
 }
 
 struct ArchivedFormRow {
@@ -74,6 +85,11 @@ struct ArchivedFormQueryRow {
     acceptance_period_start_at: Option<DateTime<Utc>>,
     acceptance_period_end_at: Option<DateTime<Utc>>,
     default_answer_title: Option<String>,
+    response_deadline_hours: Option<u32>,
+    quiz_enabled: bool,
+    show_quiz_score_to_author: bool,
+    lock_message_thread_on_completion: bool,
+    hold_temporary_answers_for_review: bool,
     archived_at: DateTime<Utc>,
     archived_by_name: String,
     archived_by_id: String,
@@ -107,6 +123,11 @@ impl TryFrom<ArchivedFormQueryRow> for ArchivedFormRow {
                 acceptance_period_start_at: row.acceptance_period_start_at,
                 acceptance_period_end_at: row.acceptance_period_end_at,
                 default_answer_title: row.default_answer_title,
+                response_deadline_hours: row.response_deadline_hours,
+                quiz_enabled: row.quiz_enabled,
+                show_quiz_score_to_author: row.show_quiz_score_to_author,
+                lock_message_thread_on_completion: row.lock_message_thread_on_completion,
+                hold_temporary_answers_for_review: row.hold_temporary_answers_for_review,
             },
             archived_at: row.archived_at,
             archived_by_name: row.archived_by_name,
@@ -130,7 +151,7 @@ async fn get_questions_txn_with_tables(
         ORDER BY position ASC, question_id ASC"
     );
     let choices_sql = format!(
-        "SELECT c.id, c.question_id, c.position, c.label
+        "SELECT c.id, c.question_id, c.position, c.label, c.quiz_points
         FROM {choices_table} c
         INNER JOIN {questions_table} q ON c.question_id = q.question_id
         WHERE q.form_id = ?
@@ -155,6 +176,7 @@ async fn get_questions_txn_with_tables(
                     id: Some(choice_rs.try_get("id")?),
                     position: choice_rs.try_get::<u16, _>("position")?,
                     label: choice_rs.try_get("label")?,
+                    quiz_points: choice_rs.try_get("quiz_points")?,
                 },
             ))
         })
@@ -262,6 +284,11 @@ async fn build_active_form_record(
         acceptance_period_start_at: row.acceptance_period_start_at,
         acceptance_period_end_at: row.acceptance_period_end_at,
         default_answer_title: row.default_answer_title,
+        response_deadline_hours: row.response_deadline_hours,
+        quiz_enabled: row.quiz_enabled,
+        show_quiz_score_to_author: row.show_quiz_score_to_author,
+        lock_message_thread_on_completion: row.lock_message_thread_on_completion,
+        hold_temporary_answers_for_review: row.hold_temporary_answers_for_review,
         allowed_group_ids: restrictions.allowed_group_ids,
         answer_group_ids: restrictions.answer_group_ids,
         questions: get_questions_txn_with_tables(txn, form_id, questions_table, choices_table)
@@ -444,10 +471,26 @@ async fn fetch_answer_entries_page(
     txn: &mut DatabaseTransaction,
     form_id: Option<FormId>,
     request: PageRequest<AnswerPagePosition>,
-    status: Option<AnswerStatus>,
+    filter: AnswerListFilter,
 ) -> Result<Page<AnswerEntry, AnswerPagePosition>, InfraError> {
     let form_id = form_id.map(|form_id| form_id.into_inner().to_string());
-    let status = status.map(|status| status.to_string());
+    let status = filter.status().map(|status| status.to_string());
+    let moderation_status = filter
+        .moderation_status()
+        .map(|moderation_status| moderation_status.to_string());
+    let assigned_to = match filter.assignee() {
+        Some(AnswerAssigneeFilter::AssignedTo(user_id)) => Some(user_id.to_string()),
+        _ => None,
+    };
+    let unassigned_only = matches!(filter.assignee(), Some(AnswerAssigneeFilter::Unassigned));
+    let overdue_only = filter.overdue_only();
+    let unnotified_overdue_only = filter.unnotified_overdue_only();
+    let min_score = filter.min_score();
+    let max_score = filter.max_score();
+    let unread_reader = filter
+        .unread_author_messages_for()
+        .map(|user_id| user_id.to_string());
+    let order = answer_list_order_key(filter.order());
     let (after_timestamp, after_answer_id) = request
         .after_position()
         .map(|position| {
@@ -457,38 +500,103 @@ async fn fetch_answer_entries_page(
             )
         })
         .unzip();
+    // 点数のない回答は、点数順で最も低い点数 (-1) として並べる
+    let after_score = request
+        .after_position()
+        .and_then(|position| position.last_score())
+        .map_or(-1, i64::from);
 
     let answers = sqlx::query!(
         r"SELECT answers.form_id, answers.id AS answer_id, answers.title, answers.publication,
-            answers.status,
+            answers.status, answers.moderation_status,
             answers.author_type, answers.user, users.name AS user_name, users.role AS user_role,
             answers.temporary_user_id, temporary_users.name AS temporary_user_name,
             temporary_users.contact_text AS temporary_user_contact_text,
             answers.redmine_user_id, answers.redmine_author_name,
             redmine_reference.redmine_issue_id,
+            answer_assignees.assignee_id, answer_due_dates.due_at,
+            answer_quiz_scores.score AS quiz_score,
+            answer_quiz_scores.max_score AS max_quiz_score,
             answers.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
         FROM answers
         LEFT JOIN users ON answers.user = users.id
         LEFT JOIN temporary_users ON answers.temporary_user_id = temporary_users.id
         LEFT JOIN redmine_imported_answer_references redmine_reference
             ON redmine_reference.answer_id = answers.id
+        LEFT JOIN answer_assignees ON answer_assignees.answer_id = answers.id
+        LEFT JOIN answer_due_dates ON answer_due_dates.answer_id = answers.id
+        LEFT JOIN answer_quiz_scores ON answer_quiz_scores.answer_id = answers.id
         WHERE (? IS NULL OR answers.form_id = ?)
             AND (? IS NULL OR answers.status = ?)
+            AND (? IS NULL OR answers.moderation_status = ?)
+            AND (? IS NULL OR answer_assignees.assignee_id = ?)
+            AND (? = FALSE OR answer_assignees.assignee_id IS NULL)
+            AND (
+                ? = FALSE
+                OR (answer_due_dates.due_at <= CURRENT_TIMESTAMP AND answers.status <> 'COMPLETED')
+            )
+            AND (? = FALSE OR answer_due_dates.overdue_notified_at IS NULL)
+            AND (? IS NULL OR answer_quiz_scores.score >= ?)
+            AND (? IS NULL OR answer_quiz_scores.score <= ?)
             AND (
                 ? IS NULL
-                OR answers.timestamp < ?
-                OR (answers.timestamp = ? AND answers.id < ?)
+                OR EXISTS (
+                    SELECT 1 FROM messages
+                    LEFT JOIN message_read_receipts receipt
+                        ON receipt.answer_id = messages.related_answer_id AND receipt.user_id = ?
+                    WHERE messages.related_answer_id = answers.id
+                        AND (messages.sender = answers.user
+                            OR messages.temporary_sender = answers.temporary_user_id)
+                        AND (receipt.last_read_message_id IS NULL
+                            OR messages.id > receipt.last_read_message_id)
+                )
             )
-        ORDER BY answers.timestamp DESC, answers.id DESC
+            AND (
+                ? IS NULL
+                OR (? = 'SCORE_DESC' AND COALESCE(CAST(answer_quiz_scores.score AS SIGNED), -1) < ?)
+                OR (? = 'SCORE_ASC' AND COALESCE(CAST(answer_quiz_scores.score AS SIGNED), -1) > ?)
+                OR (
+                    (? = 'NEWEST' OR COALESCE(CAST(answer_quiz_scores.score AS SIGNED), -1) = ?)
+                    AND (
+                        answers.timestamp < ?
+                        OR (answers.timestamp = ? AND answers.id < ?)
+                    )
+                )
+            )
+        ORDER BY
+            CASE WHEN ? = 'SCORE_DESC' THEN COALESCE(CAST(answer_quiz_scores.score AS SIGNED), -1) END DESC,
+            CASE WHEN ? = 'SCORE_ASC' THEN COALESCE(CAST(answer_quiz_scores.score AS SIGNED), -1) END ASC,
+            answers.timestamp DESC, answers.id DESC
         LIMIT ?",
         form_id.as_deref(),
         form_id.as_deref(),
         status.as_deref(),
         status.as_deref(),
+        moderation_status.as_deref(),
+        moderation_status.as_deref(),
+        assigned_to.as_deref(),
+        assigned_to.as_deref(),
+        unassigned_only,
+        overdue_only,
+        unnotified_overdue_only,
+        min_score,
+        min_score,
+        max_score,
+        max_score,
+        unread_reader.as_deref(),
+        unread_reader.as_deref(),
         after_timestamp,
+        order,
+        after_score,
+        order,
+        after_score,
+        order,
+        after_score,
         after_timestamp,
         after_timestamp,
         after_answer_id,
+        order,
+        order,
         i64::from(request.limit().overfetch_value()),
     )
     .fetch_all(&mut **txn)
@@ -517,6 +625,7 @@ async fn fetch_answer_entries_page(
                 title: row.title,
                 publication: row.publication,
                 status: row.status,
+                moderation_status: row.moderation_status,
                 contents: Vec::new(),
                 messages: Vec::new(),
                 redmine_reference: row.redmine_issue_id.map(|issue_id| {
@@ -525,6 +634,10 @@ async fn fetch_answer_entries_page(
                         issue_id.into(),
                     )
                 }),
+                assignee_id: row.assignee_id,
+                due_at: row.due_at,
+                quiz_score: row.quiz_score,
+                max_quiz_score: row.max_quiz_score,
             })
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -547,10 +660,18 @@ async fn fetch_answer_entries_page(
     Ok(Page::from_overfetched_items(
         entries,
         request.limit(),
-        |entry| AnswerPagePosition::new(*entry.timestamp(), *entry.id()),
+        AnswerPagePosition::of,
     ))
 }
 
+fn answer_list_order_key(order: AnswerListOrder) -> &'static str {
+    match order {
+        AnswerListOrder::Newest => "NEWEST",
+        AnswerListOrder::ScoreDescending => "SCORE_DESC",
+        AnswerListOrder::ScoreAscending => "SCORE_ASC",
+    }
+}
+
 async fn fetch_form_row(
     txn: &mut DatabaseTransaction,
     form_id: FormId,
@@ -561,7 +682,10 @@ async fn fetch_form_row(
             f.answer_visibility, f.hide_author AS `hide_author: _`,
             f.allow_temporary_answers AS `allow_temporary_answers: _`,
             f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
-            f.acceptance_period_end_at AS `acceptance_period_end_at: _`, f.default_answer_title,
+            f.acceptance_period_end_at AS `acceptance_period_end_at: _`, f.default_answer_title, f.response_deadline_hours,
+            f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+            f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+            f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
             f.created_at AS `created_at: _`, f.updated_at AS `updated_at: _`,
             w.url AS `discord_webhook_url?`
         FROM form_meta_data f
@@ -585,7 +709,10 @@ async fn fetch_archived_form_row(
             f.answer_visibility, f.hide_author AS `hide_author: _`,
             f.allow_temporary_answers AS `allow_temporary_answers: _`,
             f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
-            f.acceptance_period_end_at AS `acceptance_period_end_at: _`, f.default_answer_title,
+            f.acceptance_period_end_at AS `acceptance_period_end_at: _`, f.default_answer_title, f.response_deadline_hours,
+            f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+            f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+            f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
             f.created_at AS `created_at: _`, f.updated_at AS `updated_at: _`,
             w.url AS `discord_webhook_url?`,
             f.archived_at AS `archived_at: _`, u.name AS archived_by_name,
@@ -624,14 +751,27 @@ async fn insert_form_root(
         .map(NonEmptyString::into_inner);
     let acceptance_period_start_at = answer_settings.acceptance_period().start_at().to_owned();
     let acceptance_period_end_at = answer_settings.acceptance_period().end_at().to_owned();
+    let response_deadline_hours = answer_settings
+        .response_deadline()
+        .into_inner()
+        .map(ResponseDeadlineHours::hours);
+    let quiz_enabled = answer_settings.quiz().is_enabled();
+    let show_quiz_score_to_author = answer_settings.quiz().is_score_visible_to_author();
+    let lock_message_thread_on_completion = answer_settings
+        .message_thread_lock_policy()
+        .locks_on_completion();
+    let hold_temporary_answers_for_review = answer_settings
+        .temporary_answer_moderation_policy()
+        .holds_for_review();
     let discord_webhook_url = discord_webhook_url_for_persistence(form.settings());
 
     sqlx::query!(
         r#"INSERT INTO form_meta_data
         (id, title, description, visibility, answer_visibility, hide_author, allow_temporary_answers,
          acceptance_period_start_at, acceptance_period_end_at, default_answer_title,
-         created_by, updated_by)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+         response_deadline_hours, quiz_enabled, show_quiz_score_to_author,
+         lock_message_thread_on_completion, hold_temporary_answers_for_review, created_by, updated_by)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         form_id,
         title,
         description,
@@ -642,6 +782,11 @@ async fn insert_form_root(
         acceptance_period_start_at,
         acceptance_period_end_at,
         default_answer_title,
+        response_deadline_hours,
+        quiz_enabled,
+        show_quiz_score_to_author,
+        lock_message_thread_on_completion,
+        hold_temporary_answers_for_review,
         user_id,
         user_id,
     )
@@ -690,6 +835,18 @@ async fn update_form_root(
         .map(NonEmptyString::into_inner);
     let acceptance_period_start_at = answer_settings.acceptance_period().start_at().to_owned();
     let acceptance_period_end_at = answer_settings.acceptance_period().end_at().to_owned();
+    let response_deadline_hours = answer_settings
+        .response_deadline()
+        .into_inner()
+        .map(ResponseDeadlineHours::hours);
+    let quiz_enabled = answer_settings.quiz().is_enabled();
+    let show_quiz_score_to_author = answer_settings.quiz().is_score_visible_to_author();
+    let lock_message_thread_on_completion = answer_settings
+        .message_thread_lock_policy()
+        .locks_on_completion();
+    let hold_temporary_answers_for_review = answer_settings
+        .temporary_answer_moderation_policy()
+        .holds_for_review();
 
     let discord_webhook_url = discord_webhook_url_for_persistence(form.settings());
 
@@ -704,6 +861,11 @@ async fn update_form_root(
             acceptance_period_start_at = ?,
             acceptance_period_end_at = ?,
             default_answer_title = ?,
+            response_deadline_hours = ?,
+            quiz_enabled = ?,
+            show_quiz_score_to_author = ?,
+            lock_message_thread_on_completion = ?,
+            hold_temporary_answers_for_review = ?,
             updated_by = ?
             WHERE id = ?"#,
         title,
@@ -715,6 +877,11 @@ async fn update_form_root(
         acceptance_period_start_at,
         acceptance_period_end_at,
         default_answer_title,
+        response_deadline_hours,
+        quiz_enabled,
+        show_quiz_score_to_author,
+        lock_message_thread_on_completion,
+        hold_temporary_answers_for_review,
         updated_by_id,
         form_id,
     )
@@ -744,8 +911,8 @@ async fn copy_active_form_to_archive(
     execute_typed_query!(
         txn,
         r"INSERT INTO archived_form_meta_data
-        (id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, created_at, created_by, updated_at, updated_by, archived_at, archived_by)
-        SELECT id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, created_at, created_by, updated_at, updated_by, ?, ?
+        (id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, response_deadline_hours, quiz_enabled, show_quiz_score_to_author, lock_message_thread_on_completion, hold_temporary_answers_for_review, created_at, created_by, updated_at, updated_by, archived_at, archived_by)
+        SELECT id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, response_deadline_hours, quiz_enabled, show_quiz_score_to_author, lock_message_thread_on_completion, hold_temporary_answers_for_review, created_at, created_by, updated_at, updated_by, ?, ?
         FROM form_meta_data
         WHERE id = ?",
         archived_at,
@@ -764,8 +931,8 @@ async fn copy_active_form_to_archive(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO archived_form_choices (id, question_id, position, label)
-        SELECT c.id, c.question_id, c.position, c.label
+        r"INSERT INTO archived_form_choices (id, question_id, position, label, quiz_points)
+        SELECT c.id, c.question_id, c.position, c.label, c.quiz_points
         FROM form_choices c
         INNER JOIN form_questions q ON c.question_id = q.question_id
         WHERE q.form_id = ?",
@@ -785,9 +952,9 @@ async fn copy_active_form_to_archive(
         txn,
         r"INSERT INTO archived_answers
         (id, form_id, author_type, user, temporary_user_id, redmine_user_id, redmine_author_name,
-         title, publication, status, timestamp)
+         title, publication, status, moderation_status, timestamp)
         SELECT id, form_id, author_type, user, temporary_user_id, redmine_user_id,
-            redmine_author_name, title, publication, status, timestamp FROM answers WHERE form_id = ?",
+            redmine_author_name, title, publication, status, moderation_status, timestamp FROM answers WHERE form_id = ?",
         &form_id,
     );
 
@@ -803,8 +970,8 @@ async fn copy_active_form_to_archive(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO archived_form_answer_comments (id, answer_id, commented_by, content, timestamp)
-        SELECT c.id, c.answer_id, c.commented_by, c.content, c.timestamp
+        r"INSERT INTO archived_form_answer_comments (id, answer_id, commented_by, content, is_internal, timestamp)
+        SELECT c.id, c.answer_id, c.commented_by, c.content, c.is_internal, c.timestamp
         FROM form_answer_comments c
         INNER JOIN answers a ON c.answer_id = a.id
         WHERE a.form_id = ?",
@@ -813,8 +980,8 @@ async fn copy_active_form_to_archive(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO archived_messages (id, related_answer_id, sender, body, timestamp)
-        SELECT m.id, m.related_answer_id, m.sender, m.body, m.timestamp
+        r"INSERT INTO archived_messages (id, related_answer_id, sender, temporary_sender, body, timestamp)
+        SELECT m.id, m.related_answer_id, m.sender, m.temporary_sender, m.body, m.timestamp
         FROM messages m
         INNER JOIN answers a ON m.related_answer_id = a.id
         WHERE a.form_id = ?",
@@ -852,8 +1019,8 @@ async fn restore_archived_form_to_active(
     execute_typed_query!(
         txn,
         r"INSERT INTO form_meta_data
-        (id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, created_at, created_by, updated_at, updated_by)
-        SELECT id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, created_at, created_by, updated_at, updated_by
+        (id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, response_deadline_hours, quiz_enabled, show_quiz_score_to_author, lock_message_thread_on_completion, hold_temporary_answers_for_review, created_at, created_by, updated_at, updated_by)
+        SELECT id, title, description, visibility, allow_temporary_answers, answer_visibility, hide_author, acceptance_period_start_at, acceptance_period_end_at, default_answer_title, response_deadline_hours, quiz_enabled, show_quiz_score_to_author, lock_message_thread_on_completion, hold_temporary_answers_for_review, created_at, created_by, updated_at, updated_by
         FROM archived_form_meta_data
         WHERE id = ?",
         &form_id,
@@ -870,8 +1037,8 @@ async fn restore_archived_form_to_active(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO form_choices (question_id, position, label)
-        SELECT question_id, position, label
+        r"INSERT INTO form_choices (question_id, position, label, quiz_points)
+        SELECT question_id, position, label, quiz_points
         FROM archived_form_choices
         WHERE question_id IN (
             SELECT question_id FROM archived_form_questions WHERE form_id = ?
@@ -892,9 +1059,9 @@ async fn restore_archived_form_to_active(
         txn,
         r"INSERT INTO answers
         (id, form_id, author_type, user, temporary_user_id, redmine_user_id, redmine_author_name,
-         title, publication, status, timestamp)
+         title, publication, status, moderation_status, timestamp)
         SELECT id, form_id, author_type, user, temporary_user_id, redmine_user_id,
-            redmine_author_name, title, publication, status, timestamp FROM archived_answers WHERE form_id = ?",
+            redmine_author_name, title, publication, status, moderation_status, timestamp FROM archived_answers WHERE form_id = ?",
         &form_id,
     );
 
@@ -909,8 +1076,8 @@ async fn restore_archived_form_to_active(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO form_answer_comments (id, answer_id, commented_by, content, timestamp)
-        SELECT id, answer_id, commented_by, content, timestamp
+        r"INSERT INTO form_answer_comments (id, answer_id, commented_by, content, is_internal, timestamp)
+        SELECT id, answer_id, commented_by, content, is_internal, timestamp
         FROM archived_form_answer_comments
         WHERE answer_id IN (SELECT id FROM archived_answers WHERE form_id = ?)",
         &form_id,
@@ -918,8 +1085,8 @@ async fn restore_archived_form_to_active(
 
     execute_typed_query!(
         txn,
-        r"INSERT INTO messages (id, related_answer_id, sender, body, timestamp)
-        SELECT id, related_answer_id, sender, body, timestamp
+        r"INSERT INTO messages (id, related_answer_id, sender, temporary_sender, body, timestamp)
+        SELECT id, related_answer_id, sender, temporary_sender, body, timestamp
         FROM archived_messages
         WHERE related_answer_id IN (SELECT id FROM archived_answers WHERE form_id = ?)",
         &form_id,
@@ -1042,7 +1209,11 @@ impl FormDatabase for ConnectionPool {
                     f.allow_temporary_answers AS `allow_temporary_answers: _`,
                     f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                     f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                    f.default_answer_title, f.created_at AS `created_at: _`,
+                    f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                     f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`
                     FROM form_meta_data f
                     LEFT JOIN form_discord_webhooks w ON f.id = w.form_id
@@ -1062,7 +1233,11 @@ impl FormDatabase for ConnectionPool {
                     f.allow_temporary_answers AS `allow_temporary_answers: _`,
                     f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                     f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                    f.default_answer_title, f.created_at AS `created_at: _`,
+                    f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                     f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`
                     FROM form_meta_data f
                     LEFT JOIN form_discord_webhooks w ON f.id = w.form_id
@@ -1135,7 +1310,11 @@ impl FormDatabase for ConnectionPool {
                     f.allow_temporary_answers AS `allow_temporary_answers: _`,
                     f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                     f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                    f.default_answer_title, f.created_at AS `created_at: _`,
+                    f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                     f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`
                     FROM form_meta_data f
                     LEFT JOIN form_discord_webhooks w ON f.id = w.form_id
@@ -1213,7 +1392,11 @@ impl FormDatabase for ConnectionPool {
                         f.allow_temporary_answers AS `allow_temporary_answers: _`,
                         f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                         f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                        f.default_answer_title, f.created_at AS `created_at: _`,
+                        f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                         f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`,
                         f.archived_at AS `archived_at: _`, u.name AS archived_by_name,
                         u.id AS archived_by_id, u.role AS archived_by_role
@@ -1241,7 +1424,11 @@ impl FormDatabase for ConnectionPool {
                         f.allow_temporary_answers AS `allow_temporary_answers: _`,
                         f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                         f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                        f.default_answer_title, f.created_at AS `created_at: _`,
+                        f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                         f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`,
                         f.archived_at AS `archived_at: _`, u.name AS archived_by_name,
                         u.id AS archived_by_id, u.role AS archived_by_role
@@ -1266,7 +1453,11 @@ impl FormDatabase for ConnectionPool {
                         f.allow_temporary_answers AS `allow_temporary_answers: _`,
                         f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                         f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                        f.default_answer_title, f.created_at AS `created_at: _`,
+                        f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                         f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`,
                         f.archived_at AS `archived_at: _`, u.name AS archived_by_name,
                         u.id AS archived_by_id, u.role AS archived_by_role
@@ -1291,7 +1482,11 @@ impl FormDatabase for ConnectionPool {
                         f.allow_temporary_answers AS `allow_temporary_answers: _`,
                         f.acceptance_period_start_at AS `acceptance_period_start_at: _`,
                         f.acceptance_period_end_at AS `acceptance_period_end_at: _`,
-                        f.default_answer_title, f.created_at AS `created_at: _`,
+                        f.default_answer_title, f.response_deadline_hours,
+                    f.quiz_enabled AS `quiz_enabled: _`, f.show_quiz_score_to_author AS `show_quiz_score_to_author: _`,
+                    f.lock_message_thread_on_completion AS `lock_message_thread_on_completion: _`,
+                    f.hold_temporary_answers_for_review AS `hold_temporary_answers_for_review: _`,
+                    f.created_at AS `created_at: _`,
                         f.updated_at AS `updated_at: _`, w.url AS `discord_webhook_url?`,
                         f.archived_at AS `archived_at: _`, u.name AS archived_by_name,
                         u.id AS archived_by_id, u.role AS archived_by_role
@@ -1499,11 +1694,11 @@ impl FormDatabase for ConnectionPool {
         &self,
         form_id: FormId,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerEntry, AnswerPagePosition>, InfraError> {
         self.read_only_transaction(|txn| {
             Box::pin(
-                async move { fetch_answer_entries_page(txn, Some(form_id), request, status).await },
+                async move { fetch_answer_entries_page(txn, Some(form_id), request, filter).await },
             )
         })
         .await
@@ -1513,10 +1708,10 @@ impl FormDatabase for ConnectionPool {
     async fn list_all_answer_entries(
         &self,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerEntry, AnswerPagePosition>, InfraError> {
         self.read_only_transaction(|txn| {
-            Box::pin(async move { fetch_answer_entries_page(txn, None, request, status).await })
+            Box::pin(async move { fetch_answer_entries_page(txn, None, request, filter).await })
         })
         .await
     }
@@ -1868,6 +2063,7 @@ async fn sync_choices(
                 question_id,
                 choice.position,
                 choice.label.to_owned().into_inner(),
+                choice.quiz_points.map(QuizPoints::value),
             )
         })
         .collect_vec();
@@ -1880,6 +2076,7 @@ async fn sync_choices(
                 question_id,
                 choice.position,
                 choice.label.to_owned().into_inner(),
+                choice.quiz_points.map(QuizPoints::value),
             )
         })
         .collect_vec();
@@ -1935,30 +2132,32 @@ async fn delete_choices(txn: &mut MySqlConnection, choice_ids: Vec<i32>) -> Resu
 
 async fn upsert_existing_choices(
     txn: &mut MySqlConnection,
-    choices: Vec<(i32, QuestionId, u16, String)>,
+    choices: Vec<(i32, QuestionId, u16, String, Option<u32>)>,
 ) -> Result<(), InfraError> {
     if choices.is_empty() {
         return Ok(());
     }
 
     let sql = format!(
-        r"INSERT INTO form_choices (id, question_id, position, label) VALUES {}
+        r"INSERT INTO form_choices (id, question_id, position, label, quiz_points) VALUES {}
         ON DUPLICATE KEY UPDATE
         question_id = VALUES(question_id),
         position = VALUES(position),
-        label = VALUES(label)",
-        std::iter::repeat_n("(?, ?, ?, ?)", choices.len()).join(", ")
+        label = VALUES(label),
+        quiz_points = VALUES(quiz_points)",
+        std::iter::repeat_n("(?, ?, ?, ?, ?)", choices.len()).join(", ")
     );
     choices
         .iter()
         .fold(
             query(AssertSqlSafe(&*sql)),
-            |query, (choice_id, question_id, position, label)| {
+            |query, (choice_id, question_id, position, label, quiz_points)| {
                 query
                     .bind(choice_id)
                     .bind(question_id.into_inner().to_string())
                     .bind(position)
                     .bind(label)
+                    .bind(quiz_points)
             },
         )
         .execute(&mut *txn)
@@ -1969,25 +2168,26 @@ async fn upsert_existing_choices(
 
 async fn insert_new_choices(
     txn: &mut MySqlConnection,
-    choices: Vec<(QuestionId, u16, String)>,
+    choices: Vec<(QuestionId, u16, String, Option<u32>)>,
 ) -> Result<(), InfraError> {
     if choices.is_empty() {
         return Ok(());
     }
 
     let sql = format!(
-        "INSERT INTO form_choices (question_id, position, label) VALUES {}",
-        std::iter::repeat_n("(?, ?, ?)", choices.len()).join(", ")
+        "INSERT INTO form_choices (question_id, position, label, quiz_points) VALUES {}",
+        std::iter::repeat_n("(?, ?, ?, ?)", choices.len()).join(", ")
     );
     choices
         .iter()
         .fold(
             query(AssertSqlSafe(&*sql)),
-            |query, (question_id, position, label)| {
+            |query, (question_id, position, label, quiz_points)| {
                 query
                     .bind(question_id.into_inner().to_string())
                     .bind(position)
                     .bind(label)
+                    .bind(quiz_points)
             },
         )
         .execute(&mut *txn)
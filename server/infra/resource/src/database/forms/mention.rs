@@ -0,0 +1,64 @@
+use domain::form::mention::{Mention, MentionSource};
+use errors::infra::InfraError;
+
+use crate::database::connection::ConnectionPool;
+
+impl ConnectionPool {
+    /// コメントやメッセージでのメンションをまとめて保存する。
+    #[tracing::instrument(skip_all, fields(mention_count = mentions.len()))]
+    pub(crate) async fn insert_mentions(&self, mentions: &[Mention]) -> Result<(), InfraError> {
+        let rows = mentions
+            .iter()
+            .map(|mention| {
+                let (comment_id, message_id) = match mention.source() {
+                    MentionSource::Comment(comment_id) => (Some(comment_id.to_string()), None),
+                    MentionSource::Message(message_id) => (None, Some(message_id.to_string())),
+                };
+
+                (
+                    mention.id().to_string(),
+                    mention.answer_id().to_string(),
+                    comment_id,
+                    message_id,
+                    mention.mentioned_user_id().to_string(),
+                    mention.mentioned_by().to_string(),
+                    *mention.mentioned_at(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                for (
+                    id,
+                    answer_id,
+                    comment_id,
+                    message_id,
+                    mentioned_user_id,
+                    mentioned_by,
+                    mentioned_at,
+                ) in rows
+                {
+                    sqlx::query!(
+                        r"INSERT INTO answer_mentions
+                        (id, answer_id, comment_id, message_id, mentioned_user_id, mentioned_by,
+                            mentioned_at)
+                        VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        id,
+                        answer_id,
+                        comment_id,
+                        message_id,
+                        mentioned_user_id,
+                        mentioned_by,
+                        mentioned_at,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+}
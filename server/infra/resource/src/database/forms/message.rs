@@ -2,7 +2,8 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::form::{
     answer::{AnswerEntry, AnswerId},
-    message::{DeletedMessage, Message, MessageHistoryPagePosition, MessageId},
+    message::{DeletedMessage, Message, MessageHistoryPagePosition, MessageId, MessageSender},
+    message_thread::{MessageThreadReopening, is_reopened_since_completion},
 };
 use domain::{
     account::models::{AccountUser, UserSnapshot},
@@ -13,7 +14,7 @@ use uuid::Uuid;
 
 use crate::{
     database::{components::FormMessageDatabase, connection::ConnectionPool},
-    records::{MessageHistoryRecord, MessageRecord},
+    records::{MessageAttachmentRecord, MessageHistoryRecord, MessageRecord},
 };
 
 #[async_trait]
@@ -23,51 +24,78 @@ impl FormMessageDatabase for ConnectionPool {
         &self,
         message: &Message,
         answer_id: AnswerId,
-        operated_by: &UserSnapshot,
+        operated_by: Option<&UserSnapshot>,
     ) -> Result<(), InfraError> {
         let id = message.id().to_string().to_owned();
         let related_answer_id = answer_id.into_inner().to_string();
-        let sender = message.sender_id().to_string();
+        let (sender, temporary_sender) = match message.sender() {
+            MessageSender::User(user_id) => (Some(user_id.to_string()), None),
+            MessageSender::TemporaryAnswerAuthor(author_id) => (None, Some(author_id.to_string())),
+        };
         let body = message.body().as_str().to_owned();
         let timestamp = message.timestamp().to_owned();
-        let operator_id = operated_by.id().to_string();
-        let operator_name = operated_by.name().to_owned();
-        let operator_role = operated_by.role().to_string();
+        let operator = operated_by.map(|operated_by| {
+            (
+                operated_by.id().to_string(),
+                operated_by.name().to_owned(),
+                operated_by.role().to_string(),
+            )
+        });
+        let attachments = message.attachments().to_owned();
 
         self.read_write_transaction(|txn| {
             Box::pin(async move {
                 sqlx::query!(
-                    r"INSERT INTO messages (id, related_answer_id, sender, body, timestamp) VALUES (?, ?, ?, ?, ?)",
+                    r"INSERT INTO messages (id, related_answer_id, sender, temporary_sender, body, timestamp) VALUES (?, ?, ?, ?, ?, ?)",
                     id,
                     related_answer_id,
                     sender,
+                    temporary_sender,
                     body,
                     timestamp,
                 )
                 .execute(&mut **txn)
                 .await?;
 
-                sqlx::query!(
-                    r"INSERT INTO message_history
-                    (id, related_answer_id, message_id, original_author_id, original_author_name,
-                     original_author_role, original_timestamp, action, body,
-                     operated_by_id, operated_by_name, operated_by_role, operated_at)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, 'CREATE', ?, ?, ?, ?, ?)",
-                    Uuid::now_v7().to_string(),
-                    related_answer_id,
-                    id,
-                    sender,
-                    operator_name,
-                    operator_role,
-                    timestamp,
-                    body,
-                    operator_id,
-                    operator_name,
-                    operator_role,
-                    timestamp,
-                )
-                .execute(&mut **txn)
-                .await?;
+                // 一時回答者のメッセージは編集も削除もできないため、履歴を残さない
+                if let Some((operator_id, operator_name, operator_role)) = operator {
+                    sqlx::query!(
+                        r"INSERT INTO message_history
+                        (id, related_answer_id, message_id, original_author_id, original_author_name,
+                         original_author_role, original_timestamp, action, body,
+                         operated_by_id, operated_by_name, operated_by_role, operated_at)
+                        VALUES (?, ?, ?, ?, ?, ?, ?, 'CREATE', ?, ?, ?, ?, ?)",
+                        Uuid::now_v7().to_string(),
+                        related_answer_id,
+                        id,
+                        operator_id,
+                        operator_name,
+                        operator_role,
+                        timestamp,
+                        body,
+                        operator_id,
+                        operator_name,
+                        operator_role,
+                        timestamp,
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
+
+                for attachment in attachments {
+                    sqlx::query!(
+                        r"INSERT INTO message_attachments (id, message_id, related_answer_id, file_name, content_type, size)
+                        VALUES (?, ?, ?, ?, ?, ?)",
+                        attachment.id().to_string(),
+                        id,
+                        related_answer_id,
+                        attachment.file_name(),
+                        attachment.content_type(),
+                        attachment.size(),
+                    )
+                    .execute(&mut **txn)
+                    .await?;
+                }
 
                 Ok::<_, InfraError>(())
             })
@@ -89,7 +117,7 @@ impl FormMessageDatabase for ConnectionPool {
         self.read_write_transaction(|txn| {
             Box::pin(async move {
                 let current = sqlx::query!(
-                    r"SELECT m.related_answer_id, m.sender AS original_author_id,
+                    r"SELECT m.related_answer_id, m.sender AS `original_author_id!`,
                         u.name AS original_author_name, u.role AS original_author_role,
                         m.body, m.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM messages m INNER JOIN users u ON u.id = m.sender
@@ -159,9 +187,8 @@ impl FormMessageDatabase for ConnectionPool {
             Box::pin(async move {
                 let rows = sqlx::query_as!(
                     MessageRecord,
-                    r"SELECT messages.id AS id, sender AS sender_id, users.name AS sender_name, users.role AS sender_role, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT id, sender AS sender_id, temporary_sender AS temporary_sender_id, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM messages
-                    INNER JOIN users ON users.id = messages.sender
                     WHERE related_answer_id = ?",
                     answer_id.to_string(),
                 )
@@ -185,9 +212,8 @@ impl FormMessageDatabase for ConnectionPool {
             Box::pin(async move {
                 let rows = sqlx::query_as!(
                     MessageRecord,
-                    r"SELECT messages.id AS id, sender AS sender_id, users.name AS sender_name, users.role AS sender_role, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT id, sender AS sender_id, temporary_sender AS temporary_sender_id, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM messages
-                    INNER JOIN users ON users.id = messages.sender
                     WHERE related_answer_id = ?",
                     answer_id,
                 )
@@ -211,10 +237,9 @@ impl FormMessageDatabase for ConnectionPool {
             Box::pin(async move {
                 let row = sqlx::query_as!(
                     MessageRecord,
-                    r"SELECT messages.id AS id, sender AS sender_id, users.name AS sender_name, users.role AS sender_role, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
+                    r"SELECT id, sender AS sender_id, temporary_sender AS temporary_sender_id, body, timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM messages
-                    INNER JOIN users ON users.id = messages.sender
-                    WHERE messages.id = ?",
+                    WHERE id = ?",
                     message_id,
                 )
                 .fetch_optional(&mut **txn)
@@ -226,6 +251,32 @@ impl FormMessageDatabase for ConnectionPool {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    async fn fetch_message_attachments_by_answer_id(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<Vec<MessageAttachmentRecord>, InfraError> {
+        let answer_id = answer_id.into_inner().to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let rows = sqlx::query_as!(
+                    MessageAttachmentRecord,
+                    r"SELECT id, message_id, file_name, content_type, size
+                    FROM message_attachments
+                    WHERE related_answer_id = ?
+                    ORDER BY created_at, id",
+                    answer_id,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(rows)
+            })
+        })
+        .await
+    }
+
     #[tracing::instrument(skip_all)]
     async fn delete_message_with_history(
         &self,
@@ -234,7 +285,7 @@ impl FormMessageDatabase for ConnectionPool {
         let message = deleted.message();
         let message_id = message.id().to_string();
         let expected_answer_id = deleted.answer_id().to_string();
-        let expected_author_id = message.sender_id().to_string();
+        let expected_author_id = message.sender_id().map(ToString::to_string);
         let expected_body = message.body().as_str().to_owned();
         let expected_timestamp = *message.timestamp();
         let operator_id = deleted.deleted_by().id().to_string();
@@ -244,7 +295,7 @@ impl FormMessageDatabase for ConnectionPool {
         self.read_write_transaction(|txn| {
             Box::pin(async move {
                 let current = sqlx::query!(
-                    r"SELECT m.related_answer_id, m.sender AS original_author_id,
+                    r"SELECT m.related_answer_id, m.sender AS `original_author_id!`,
                         u.name AS original_author_name, u.role AS original_author_role,
                         m.body, m.timestamp AS `timestamp!: chrono::DateTime<chrono::Utc>`
                     FROM messages m INNER JOIN users u ON u.id = m.sender
@@ -258,7 +309,7 @@ impl FormMessageDatabase for ConnectionPool {
                 })?;
 
                 if current.related_answer_id != expected_answer_id
-                    || current.original_author_id != expected_author_id
+                    || expected_author_id.as_ref() != Some(&current.original_author_id)
                     || current.body != expected_body
                     || current.timestamp != expected_timestamp
                 {
@@ -411,4 +462,86 @@ impl FormMessageDatabase for ConnectionPool {
             )
         }))
     }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    async fn is_message_thread_reopened(&self, answer_id: AnswerId) -> Result<bool, InfraError> {
+        let answer_id = answer_id.into_inner().to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let reopened_at = sqlx::query_scalar!(
+                    r"SELECT reopened_at AS `reopened_at: chrono::DateTime<chrono::Utc>` FROM message_thread_reopenings
+                    WHERE answer_id = ?",
+                    answer_id,
+                )
+                .fetch_optional(&mut **txn)
+                .await?;
+                let last_completed_at = sqlx::query_scalar!(
+                    r"SELECT MAX(changed_at) AS `last_completed_at: chrono::DateTime<chrono::Utc>`
+                    FROM form_answer_status_history
+                    WHERE answer_id = ? AND to_status = 'COMPLETED'",
+                    answer_id,
+                )
+                .fetch_one(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(is_reopened_since_completion(
+                    reopened_at,
+                    last_completed_at,
+                ))
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    async fn has_active_temporary_answer_access(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<bool, InfraError> {
+        let answer_id = answer_id.into_inner().to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let count = sqlx::query_scalar!(
+                    r"SELECT COUNT(*) AS `count!: i64` FROM temporary_answer_access_tokens
+                    WHERE answer_id = ? AND revoked_at IS NULL",
+                    answer_id,
+                )
+                .fetch_one(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(count > 0)
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn insert_message_thread_reopening(
+        &self,
+        reopening: &MessageThreadReopening,
+    ) -> Result<(), InfraError> {
+        let answer_id = reopening.answer_id().into_inner().to_string();
+        let reopened_by = reopening.reopened_by().to_string();
+        let reopened_at = reopening.reopened_at().to_owned();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO message_thread_reopenings (answer_id, reopened_by, reopened_at)
+                    VALUES (?, ?, ?)
+                    ON DUPLICATE KEY UPDATE reopened_by = VALUES(reopened_by), reopened_at = VALUES(reopened_at)",
+                    answer_id,
+                    reopened_by,
+                    reopened_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
 }
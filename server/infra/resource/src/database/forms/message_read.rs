@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use domain::form::message_read::{MessageReadReceipt, UnreadMessageCountTarget};
+use errors::infra::InfraError;
+use itertools::Itertools;
+use sqlx::{AssertSqlSafe, Row, query};
+
+use crate::{database::connection::ConnectionPool, records::MessageReadReceiptRecord};
+
+impl ConnectionPool {
+    /// 既読位置を保存する。メッセージ ID は UUID v7 のため、文字列の大小で投稿順を比較できる。
+    #[tracing::instrument(skip_all, fields(answer_id = %receipt.answer_id()))]
+    pub(crate) async fn upsert_message_read_receipt(
+        &self,
+        receipt: &MessageReadReceipt,
+    ) -> Result<(), InfraError> {
+        let answer_id = receipt.answer_id().to_string();
+        let user_id = receipt.reader_id().to_string();
+        let last_read_message_id = receipt.last_read_message_id().to_string();
+        let read_at = *receipt.read_at();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO message_read_receipts
+                        (answer_id, user_id, last_read_message_id, read_at)
+                    VALUES (?, ?, ?, ?)
+                    ON DUPLICATE KEY UPDATE
+                        last_read_message_id = GREATEST(last_read_message_id, VALUES(last_read_message_id)),
+                        read_at = VALUES(read_at)",
+                    answer_id,
+                    user_id,
+                    last_read_message_id,
+                    read_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    pub(crate) async fn fetch_message_read_receipts(
+        &self,
+        answer_id: String,
+    ) -> Result<Vec<MessageReadReceiptRecord>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        MessageReadReceiptRecord,
+                        r"SELECT answer_id, user_id, last_read_message_id,
+                            read_at AS `read_at: chrono::DateTime<chrono::Utc>`
+                        FROM message_read_receipts
+                        WHERE answer_id = ?
+                        ORDER BY user_id",
+                        answer_id,
+                    )
+                    .fetch_all(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    /// 対象ごとの未読メッセージ数を返す。閲覧者ごとに、回答でまとめた一度の問い合わせで数える。
+    #[tracing::instrument(skip_all, fields(targets = targets.len()))]
+    pub(crate) async fn count_unread_messages(
+        &self,
+        targets: &[UnreadMessageCountTarget],
+    ) -> Result<HashMap<UnreadMessageCountTarget, u32>, InfraError> {
+        let targets = targets.to_vec();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let mut counts = targets
+                    .iter()
+                    .map(|target| (*target, 0))
+                    .collect::<HashMap<_, _>>();
+
+                let targets_by_reader = targets.iter().into_group_map_by(|target| *target.reader_id());
+                for (reader_id, reader_targets) in targets_by_reader {
+                    let reader_id = reader_id.to_string();
+                    let sql = format!(
+                        r"SELECT messages.related_answer_id AS answer_id, COUNT(*) AS count
+                        FROM messages
+                        LEFT JOIN message_read_receipts receipt
+                            ON receipt.answer_id = messages.related_answer_id AND receipt.user_id = ?
+                        WHERE messages.related_answer_id IN ({})
+                            AND (messages.sender IS NULL OR messages.sender <> ?)
+                            AND (receipt.last_read_message_id IS NULL
+                                OR messages.id > receipt.last_read_message_id)
+                        GROUP BY messages.related_answer_id",
+                        std::iter::repeat_n("?", reader_targets.len()).join(", ")
+                    );
+                    let query = reader_targets
+                        .iter()
+                        .fold(query(AssertSqlSafe(&*sql)).bind(&reader_id), |query, target| {
+                            query.bind(target.answer_id().to_string())
+                        })
+                        .bind(&reader_id);
+
+                    for row in query.fetch_all(&mut **txn).await? {
+                        let answer_id = row.try_get::<String, _>("answer_id")?;
+                        let count = row.try_get::<i64, _>("count")?;
+                        if let Some(target) = reader_targets
+                            .iter()
+                            .find(|target| target.answer_id().to_string() == answer_id)
+                        {
+                            counts.insert(**target, u32::try_from(count).unwrap_or(u32::MAX));
+                        }
+                    }
+                }
+
+                Ok::<_, InfraError>(counts)
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,119 @@
+use domain::form::message_template::{MessageTemplate, MessageTemplateId};
+use errors::infra::InfraError;
+
+use crate::{database::connection::ConnectionPool, records::MessageTemplateRecord};
+
+impl ConnectionPool {
+    #[tracing::instrument(skip_all, fields(template_id = %template.id()))]
+    pub(crate) async fn insert_message_template(
+        &self,
+        template: &MessageTemplate,
+    ) -> Result<(), InfraError> {
+        let id = template.id().to_string();
+        let name = template.name().to_owned().into_inner().into_inner();
+        let body = template.body().to_owned().into_inner().into_inner();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    "INSERT INTO message_templates (id, name, body) VALUES (?, ?, ?)",
+                    id,
+                    name,
+                    body,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn fetch_message_templates(
+        &self,
+    ) -> Result<Vec<MessageTemplateRecord>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        MessageTemplateRecord,
+                        "SELECT id, name, body FROM message_templates ORDER BY name, id",
+                    )
+                    .fetch_all(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(template_id = %id))]
+    pub(crate) async fn fetch_message_template(
+        &self,
+        id: MessageTemplateId,
+    ) -> Result<Option<MessageTemplateRecord>, InfraError> {
+        let id = id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        MessageTemplateRecord,
+                        "SELECT id, name, body FROM message_templates WHERE id = ?",
+                        id,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(template_id = %template.id()))]
+    pub(crate) async fn update_message_template(
+        &self,
+        template: &MessageTemplate,
+    ) -> Result<(), InfraError> {
+        let id = template.id().to_string();
+        let name = template.name().to_owned().into_inner().into_inner();
+        let body = template.body().to_owned().into_inner().into_inner();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    "UPDATE message_templates SET name = ?, body = ? WHERE id = ?",
+                    name,
+                    body,
+                    id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(template_id = %id))]
+    pub(crate) async fn delete_message_template(
+        &self,
+        id: MessageTemplateId,
+    ) -> Result<(), InfraError> {
+        let id = id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!("DELETE FROM message_templates WHERE id = ?", id)
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+}
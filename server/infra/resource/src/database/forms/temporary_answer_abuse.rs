@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use domain::form::answer::AnswerId;
+use errors::infra::InfraError;
+
+use crate::{database::connection::ConnectionPool, records::TemporaryAnswerAbuseMetadataRecord};
+
+impl ConnectionPool {
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    pub(crate) async fn insert_temporary_answer_submission_source(
+        &self,
+        answer_id: AnswerId,
+        client_ip_hash: Option<String>,
+        user_agent_fingerprint: Option<String>,
+        recorded_at: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        let answer_id = answer_id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO temporary_answer_submission_sources (answer_id, client_ip_hash, user_agent_fingerprint, recorded_at)
+                    VALUES (?, ?, ?, ?)",
+                    answer_id,
+                    client_ip_hash,
+                    user_agent_fingerprint,
+                    recorded_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %answer_id))]
+    pub(crate) async fn fetch_temporary_answer_submission_source(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<Option<TemporaryAnswerAbuseMetadataRecord>, InfraError> {
+        let answer_id = answer_id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        TemporaryAnswerAbuseMetadataRecord,
+                        r"SELECT answer_id, client_ip_hash, user_agent_fingerprint,
+                            recorded_at AS `recorded_at: chrono::DateTime<chrono::Utc>`
+                        FROM temporary_answer_submission_sources
+                        WHERE answer_id = ?",
+                        answer_id,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+}
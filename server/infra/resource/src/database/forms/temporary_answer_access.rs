@@ -0,0 +1,89 @@
+use domain::form::temporary_answer_access::{
+    TemporaryAnswerAccess, TemporaryAnswerAccessRevocation,
+};
+use errors::infra::InfraError;
+
+use crate::{
+    database::connection::{ConnectionPool, DatabaseTransaction},
+    records::TemporaryAnswerAccessRecord,
+};
+
+/// 一時回答の保存と同じトランザクションで、アクセスリンクのトークンを保存する。
+pub(crate) async fn insert_temporary_answer_access(
+    txn: &mut DatabaseTransaction,
+    access: &TemporaryAnswerAccess,
+    token_hash: String,
+) -> Result<(), InfraError> {
+    sqlx::query!(
+        r"INSERT INTO temporary_answer_access_tokens (token_hash, answer_id, issued_at)
+        VALUES (?, ?, ?)",
+        token_hash,
+        access.answer_id().to_string(),
+        *access.issued_at(),
+    )
+    .execute(&mut **txn)
+    .await?;
+
+    Ok(())
+}
+
+impl ConnectionPool {
+    /// アーカイブされた回答のアクセスは返さない。
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn fetch_temporary_answer_access(
+        &self,
+        token_hash: String,
+    ) -> Result<Option<TemporaryAnswerAccessRecord>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        TemporaryAnswerAccessRecord,
+                        r"SELECT tokens.answer_id, answers.form_id,
+                            temporary_users.id AS temporary_user_id,
+                            temporary_users.name AS temporary_user_name,
+                            temporary_users.contact_text AS temporary_user_contact_text,
+                            tokens.issued_at AS `issued_at: chrono::DateTime<chrono::Utc>`,
+                            tokens.revoked_at AS `revoked_at: chrono::DateTime<chrono::Utc>`
+                        FROM temporary_answer_access_tokens tokens
+                        INNER JOIN answers ON answers.id = tokens.answer_id
+                        INNER JOIN temporary_users ON temporary_users.id = answers.temporary_user_id
+                        WHERE tokens.token_hash = ?",
+                        token_hash,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(answer_id = %revocation.answer_id()))]
+    pub(crate) async fn revoke_temporary_answer_accesses(
+        &self,
+        revocation: &TemporaryAnswerAccessRevocation,
+    ) -> Result<(), InfraError> {
+        let answer_id = revocation.answer_id().to_string();
+        let revoked_by = revocation.revoked_by().to_string();
+        let revoked_at = *revocation.revoked_at();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"UPDATE temporary_answer_access_tokens
+                    SET revoked_at = ?, revoked_by = ?
+                    WHERE answer_id = ? AND revoked_at IS NULL",
+                    revoked_at,
+                    revoked_by,
+                    answer_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,139 @@
+use domain::form::temporary_answer_claim::{TemporaryAnswerClaim, TemporaryAnswerClaimTicket};
+use errors::infra::InfraError;
+
+use crate::{
+    database::connection::{ConnectionPool, DatabaseTransaction},
+    records::TemporaryAnswerClaimTicketRecord,
+};
+
+/// 一時回答の保存と同じトランザクションで、引き継ぎコードを保存する。
+pub(crate) async fn insert_temporary_answer_claim_code(
+    txn: &mut DatabaseTransaction,
+    ticket: &TemporaryAnswerClaimTicket,
+    code_hash: String,
+) -> Result<(), InfraError> {
+    sqlx::query!(
+        r"INSERT INTO temporary_answer_claim_codes (code_hash, answer_id, temporary_user_id, issued_at)
+        VALUES (?, ?, ?, ?)",
+        code_hash,
+        ticket.answer_id().to_string(),
+        ticket.author().id().to_string(),
+        *ticket.issued_at(),
+    )
+    .execute(&mut **txn)
+    .await?;
+
+    Ok(())
+}
+
+impl ConnectionPool {
+    /// 引き継ぎコードのハッシュから、コードと回答者の情報を取得する。
+    ///
+    /// フォームがアーカイブされているかはここでは判定しない。
+    /// ユースケースが `active_form_repository` からフォームを取得する際に確認する。
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn fetch_temporary_answer_claim_code(
+        &self,
+        code_hash: String,
+    ) -> Result<Option<TemporaryAnswerClaimTicketRecord>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        TemporaryAnswerClaimTicketRecord,
+                        r"SELECT codes.answer_id, answers.form_id,
+                            temporary_users.id AS temporary_user_id,
+                            temporary_users.name AS temporary_user_name,
+                            temporary_users.contact_text AS temporary_user_contact_text,
+                            codes.issued_at AS `issued_at: chrono::DateTime<chrono::Utc>`,
+                            codes.claimed_at AS `claimed_at: chrono::DateTime<chrono::Utc>`
+                        FROM temporary_answer_claim_codes codes
+                        INNER JOIN answers ON answers.id = codes.answer_id
+                        INNER JOIN temporary_users ON temporary_users.id = codes.temporary_user_id
+                        WHERE codes.code_hash = ?",
+                        code_hash,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    /// 回答者とその回答で送ったメッセージの送信者をアカウントに切り替え、アクセスリンクを取り消す。
+    ///
+    /// `temporary_users` の行は監査のために残す。
+    #[tracing::instrument(skip_all, fields(answer_id = %claim.answer_id()))]
+    pub(crate) async fn claim_temporary_answer(
+        &self,
+        claim: &TemporaryAnswerClaim,
+    ) -> Result<bool, InfraError> {
+        let answer_id = claim.answer_id().to_string();
+        let temporary_user_id = claim.temporary_author().id().to_string();
+        let claimed_by = claim.claimed_by().to_string();
+        let claimed_at = *claim.claimed_at();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                let claimed = sqlx::query!(
+                    r"UPDATE temporary_answer_claim_codes
+                    SET claimed_at = ?, claimed_by = ?
+                    WHERE answer_id = ? AND temporary_user_id = ? AND claimed_at IS NULL",
+                    claimed_at,
+                    claimed_by,
+                    answer_id,
+                    temporary_user_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                if claimed.rows_affected() != 1 {
+                    return Ok::<_, InfraError>(false);
+                }
+
+                let answer = sqlx::query!(
+                    r"UPDATE answers
+                    SET author_type = 'AUTHENTICATED_USER', user = ?, temporary_user_id = NULL
+                    WHERE id = ? AND author_type = 'TEMPORARY_USER' AND temporary_user_id = ?",
+                    claimed_by,
+                    answer_id,
+                    temporary_user_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                if answer.rows_affected() != 1 {
+                    return Err(InfraError::Unexpected {
+                        cause: "claimed answer is no longer a temporary answer".to_string(),
+                    });
+                }
+
+                sqlx::query!(
+                    r"UPDATE messages
+                    SET sender = ?, temporary_sender = NULL
+                    WHERE related_answer_id = ? AND temporary_sender = ?",
+                    claimed_by,
+                    answer_id,
+                    temporary_user_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                sqlx::query!(
+                    r"UPDATE temporary_answer_access_tokens
+                    SET revoked_at = ?, revoked_by = ?
+                    WHERE answer_id = ? AND revoked_at IS NULL",
+                    claimed_at,
+                    claimed_by,
+                    answer_id,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok(true)
+            })
+        })
+        .await
+    }
+}
@@ -23,6 +23,8 @@ pub struct MeilisearchIndexSchema {
     label_for_forms: NumberOfDocuments,
     #[serde(default)]
     users: NumberOfDocuments,
+    #[serde(default)]
+    announcements: NumberOfDocuments,
 }
 
 impl From<MeilisearchIndexSchema> for NumberOfRecordsPerAggregate {
@@ -37,6 +39,7 @@ impl From<MeilisearchIndexSchema> for NumberOfRecordsPerAggregate {
             ),
             label_for_forms: NumberOfRecords(value.label_for_forms.number_of_documents),
             users: NumberOfRecords(value.users.number_of_documents),
+            announcements: NumberOfRecords(value.announcements.number_of_documents),
         }
     }
 }
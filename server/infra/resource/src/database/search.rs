@@ -15,18 +15,22 @@ use domain::{
         question::QuestionId,
     },
     search::models::{
-        AnswerLabelSearchHit, AnswerSearchHit, AnswerTitleSearchDocument, CommentSearchHit,
-        FormAnswerComments, FormLabelSearchHit, FormMetaData, FormSearchHit, LabelForFormAnswers,
-        LabelForForms, NumberOfRecordsPerAggregate, Operation, SearchableFields,
-        SearchableFieldsWithOperation, UserSearchHit, Users,
+        AnnouncementSearchHit, Announcements, AnswerLabelSearchHit, AnswerSearchHit,
+        AnswerTitleSearchDocument, CommentSearchHit, FormAnswerComments, FormLabelSearchHit,
+        FormMetaData, FormSearchHit, LabelForFormAnswers, LabelForForms,
+        NumberOfRecordsPerAggregate, Operation, SearchableFields, SearchableFieldsWithOperation,
+        SimilarAnswerLimit, UserSearchHit, Users,
     },
 };
 use errors::infra::InfraError;
 use futures::{future::try_join_all, try_join};
 use itertools::Itertools;
-use meilisearch_sdk::{errors::Error as MeilisearchError, search::Selectors};
+use meilisearch_sdk::{
+    errors::Error as MeilisearchError,
+    search::{MatchingStrategies, Selectors},
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 #[derive(Serialize, Deserialize)]
@@ -57,6 +61,11 @@ fn answer_filter(form_id: Option<FormId>, status: Option<AnswerStatus>) -> Optio
     .reduce(|left, right| format!("{left} AND {right}"))
 }
 
+/// `answer_id_attribute` はインデックスごとに回答 ID を保持する属性名 (`answers` では `id`)。
+fn similar_answer_filter(form_id: FormId, answer_id_attribute: &str, exclude: AnswerId) -> String {
+    format!("form_id = \"{form_id}\" AND {answer_id_attribute} != \"{exclude}\"")
+}
+
 fn answer_content_documents(
     data: &[SearchableFieldsWithOperation],
     metadata_by_answer_id: &HashMap<AnswerId, (FormId, AnswerStatus)>,
@@ -137,16 +146,20 @@ fn answer_documents_from_entry(entry: &AnswerEntry) -> Vec<SearchableFieldsWithO
         .collect()
 }
 
-fn answer_documents_from_record(
-    record: FormAnswerRecord,
-) -> Result<Vec<SearchableFieldsWithOperation>, InfraError> {
-    let entry: AnswerEntry =
-        record
-            .try_into()
-            .map_err(|error: errors::Error| InfraError::Unexpected {
-                cause: error.to_string(),
-            })?;
-    Ok(answer_documents_from_entry(&entry))
+/// 承認されていない回答は検索に載せず、既に載っているドキュメントを取り除く。
+fn withdrawn_answer_documents(entry: &AnswerEntry) -> Vec<SearchableFieldsWithOperation> {
+    answer_documents_from_entry(entry)
+        .into_iter()
+        .map(|(fields, _)| (fields, Operation::Delete))
+        .collect()
+}
+
+fn answer_entry_from_record(record: FormAnswerRecord) -> Result<AnswerEntry, InfraError> {
+    record
+        .try_into()
+        .map_err(|error: errors::Error| InfraError::Unexpected {
+            cause: error.to_string(),
+        })
 }
 
 async fn answer_documents_need_reprojection(
@@ -315,6 +328,52 @@ impl SearchDatabase for ConnectionPool {
         ))
     }
 
+    /// 回答全文をクエリにすると一致しない語が多くなるため、出現頻度の高い語から
+    /// 無視していく `frequency` 戦略で検索する。
+    #[tracing::instrument(skip_all, fields(otel.kind = "client", db.system = "meilisearch"))]
+    async fn search_similar_answers(
+        &self,
+        query: &str,
+        form_id: FormId,
+        exclude: AnswerId,
+        limit: SimilarAnswerLimit,
+    ) -> Result<Vec<AnswerSearchHit>, InfraError> {
+        let limit = limit.value() as usize;
+        let title_filter = similar_answer_filter(form_id, "id", exclude);
+        let content_filter = similar_answer_filter(form_id, "answer_id", exclude);
+        let title_search = async {
+            self.meilisearch_client
+                .index("answers")
+                .search()
+                .with_query(query)
+                .with_matching_strategy(MatchingStrategies::FREQUENCY)
+                .with_filter(&title_filter)
+                .with_limit(limit)
+                .execute::<AnswerTitleSearchDocument>()
+                .await
+        };
+        let content_search = async {
+            self.meilisearch_client
+                .index("real_answers")
+                .search()
+                .with_query(query)
+                .with_matching_strategy(MatchingStrategies::FREQUENCY)
+                .with_filter(&content_filter)
+                .with_limit(limit)
+                .execute::<AnswerContentSearchDocument>()
+                .await
+        };
+        let (title_results, content_results) = try_join!(title_search, content_search)?;
+
+        Ok(merge_answer_hits(
+            title_results.hits.into_iter().map(|hit| hit.result.id),
+            content_results
+                .hits
+                .into_iter()
+                .map(|hit| hit.result.answer_id),
+        ))
+    }
+
     #[tracing::instrument(skip_all, fields(otel.kind = "client", db.system = "meilisearch", db.collection.name = "form_answer_comments"))]
     async fn search_comments(&self, query: &str) -> Result<Vec<CommentSearchHit>, InfraError> {
         Ok(self
@@ -334,6 +393,27 @@ impl SearchDatabase for ConnectionPool {
             .collect_vec())
     }
 
+    #[tracing::instrument(skip_all, fields(otel.kind = "client", db.system = "meilisearch", db.collection.name = "announcements"))]
+    async fn search_announcements(
+        &self,
+        query: &str,
+    ) -> Result<Vec<AnnouncementSearchHit>, InfraError> {
+        Ok(self
+            .meilisearch_client
+            .index("announcements")
+            .search()
+            .with_query(query)
+            .with_attributes_to_highlight(Selectors::All)
+            .execute::<Announcements>()
+            .await?
+            .hits
+            .into_iter()
+            .map(|hit| AnnouncementSearchHit {
+                announcement_id: hit.result.id,
+            })
+            .collect_vec())
+    }
+
     #[tracing::instrument(skip_all, fields(otel.kind = "client", db.system = "meilisearch"))]
     async fn sync_search_engine(
         &self,
@@ -354,13 +434,24 @@ impl SearchDatabase for ConnectionPool {
             .collect_vec();
         let answer_records = self.get_answers_by_answer_ids(answer_ids_to_fetch).await?;
         let metadata_by_answer_id = answer_metadata_from_records(&answer_records)?;
-        let reprojected_documents = answer_records
+        let (answer_entries, held_answer_entries): (Vec<_>, Vec<_>) = answer_records
             .into_iter()
-            .map(answer_documents_from_record)
-            .try_fold(Vec::new(), |mut documents, result| {
-                documents.extend(result?);
-                Ok::<_, InfraError>(documents)
-            })?;
+            .map(answer_entry_from_record)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .partition(|entry| entry.moderation_status().is_approved());
+        let held_answer_ids = held_answer_entries
+            .iter()
+            .map(|entry| *entry.id())
+            .collect::<HashSet<_>>();
+        let reprojected_documents = answer_entries
+            .iter()
+            .flat_map(answer_documents_from_entry)
+            .collect_vec();
+        let withdrawn_documents = held_answer_entries
+            .iter()
+            .flat_map(withdrawn_answer_documents)
+            .collect_vec();
         let content_documents = answer_content_documents(data, &metadata_by_answer_id)?;
 
         let reproject_futures = reprojected_documents.iter().map(|(fields, _)| async {
@@ -411,8 +502,15 @@ impl SearchDatabase for ConnectionPool {
                         SearchableFields::AnswerTitle(_),
                         Operation::Create | Operation::Update
                     )
+                ) && !matches!(
+                    (searchable_fields, operation),
+                    (
+                        SearchableFields::RealAnswers(content),
+                        Operation::Create | Operation::Update
+                    ) if held_answer_ids.contains(&content.answer_id)
                 )
             })
+            .chain(&withdrawn_documents)
             .map(async |(searchable_fields, operation)| match operation {
                 Operation::Create | Operation::Update => match searchable_fields {
                     SearchableFields::FormMetaData(data) => {
@@ -454,6 +552,12 @@ impl SearchDatabase for ConnectionPool {
                             .add_or_replace(&[users], Some("id"))
                             .await
                     }
+                    SearchableFields::Announcements(announcement) => {
+                        self.meilisearch_client
+                            .index("announcements")
+                            .add_or_replace(&[announcement], Some("id"))
+                            .await
+                    }
                 },
                 Operation::Delete => match searchable_fields {
                     SearchableFields::FormMetaData(data) => {
@@ -498,6 +602,12 @@ impl SearchDatabase for ConnectionPool {
                             .delete_document(users.id.to_string())
                             .await
                     }
+                    SearchableFields::Announcements(announcement) => {
+                        self.meilisearch_client
+                            .index("announcements")
+                            .delete_document(announcement.id.to_string())
+                            .await
+                    }
                 },
             })
             .collect::<Vec<_>>();
@@ -535,6 +645,7 @@ impl SearchDatabase for ConnectionPool {
             ("label_for_form_answers", "id"),
             ("label_for_forms", "id"),
             ("users", "id"),
+            ("announcements", "id"),
         ];
 
         let futures = index_with_uid
@@ -552,16 +663,18 @@ impl SearchDatabase for ConnectionPool {
 
         try_join_all(futures).await?;
 
-        let settings_futures = ["answers", "real_answers"].into_iter().map(async |index| {
-            self.meilisearch_client
-                .index(index)
-                .set_filterable_attributes(["form_id", "status"])
-                .await?
-                .wait_for_completion(&self.meilisearch_client, None, None)
-                .await?;
+        let settings_futures = [("answers", "id"), ("real_answers", "answer_id")]
+            .into_iter()
+            .map(async |(index, answer_id_attribute)| {
+                self.meilisearch_client
+                    .index(index)
+                    .set_filterable_attributes(["form_id", "status", answer_id_attribute])
+                    .await?
+                    .wait_for_completion(&self.meilisearch_client, None, None)
+                    .await?;
 
-            Ok::<_, MeilisearchError>(())
-        });
+                Ok::<_, MeilisearchError>(())
+            });
         try_join_all(settings_futures).await?;
 
         answer_documents_need_reprojection(self).await
@@ -572,11 +685,14 @@ impl SearchDatabase for ConnectionPool {
 mod tests {
     use super::{
         add_meilisearch_stats_auth, answer_content_documents, answer_documents_from_entry,
-        answer_filter, merge_answer_hits,
+        answer_filter, merge_answer_hits, similar_answer_filter, withdrawn_answer_documents,
     };
     use domain::{
         form::{
-            answer::{AnswerAuthor, AnswerEntry, AnswerId, AnswerStatus, AnswerTitle},
+            answer::{
+                AnswerAuthor, AnswerEntry, AnswerId, AnswerModerationStatus, AnswerStatus,
+                AnswerTitle,
+            },
             models::FormId,
         },
         search::models::{Operation, RealAnswers, SearchableFields},
@@ -601,6 +717,17 @@ mod tests {
         assert_eq!(answer_filter(None, None), None);
     }
 
+    #[test]
+    fn similar_answer_filter_excludes_the_source_answer_within_its_form() {
+        let form_id = FormId::from(Uuid::from_u128(1));
+        let source = answer_id(2);
+
+        assert_eq!(
+            similar_answer_filter(form_id, "answer_id", source),
+            format!("form_id = \"{form_id}\" AND answer_id != \"{source}\"")
+        );
+    }
+
     #[test]
     fn meilisearch_stats_uses_bearer_authorization() {
         let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
@@ -707,4 +834,41 @@ mod tests {
                 if document.status == AnswerStatus::COMPLETED
         ));
     }
+
+    #[test]
+    fn held_answers_are_withdrawn_from_search() {
+        let answer_id = answer_id(1);
+        let content_id = Uuid::from_u128(2).into();
+        let entry = unsafe {
+            AnswerEntry::from_raw_parts_with_status_and_redmine_reference(
+                answer_id,
+                Uuid::from_u128(3).into(),
+                AnswerAuthor::AuthenticatedUser(Uuid::from_u128(4).into()),
+                chrono::Utc::now(),
+                AnswerTitle::new(None),
+                domain::form::answer::AnswerPublication::PUBLIC,
+                AnswerStatus::UNADDRESSED,
+                vec![domain::form::answer::FormAnswerContent {
+                    id: content_id,
+                    question_id: Uuid::from_u128(5).into(),
+                    answer: "spam".to_string(),
+                }],
+                None,
+            )
+            .with_raw_moderation_status(AnswerModerationStatus::PENDING)
+        };
+
+        let documents = withdrawn_answer_documents(&entry);
+
+        assert_eq!(documents.len(), 2);
+        assert!(
+            documents
+                .iter()
+                .all(|(_, operation)| matches!(operation, Operation::Delete))
+        );
+        assert!(matches!(
+            &documents[1].0,
+            SearchableFields::RealAnswers(document) if document.id == content_id
+        ));
+    }
 }
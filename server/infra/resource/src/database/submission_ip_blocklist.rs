@@ -0,0 +1,107 @@
+use domain::submission_ip_blocklist::{SubmissionIpBlock, SubmissionIpBlockId};
+use errors::infra::InfraError;
+
+use crate::{database::connection::ConnectionPool, records::SubmissionIpBlockRecord};
+
+impl ConnectionPool {
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn fetch_submission_ip_blocklist(
+        &self,
+    ) -> Result<Vec<SubmissionIpBlockRecord>, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        SubmissionIpBlockRecord,
+                        r"SELECT id, ip_range, reason, created_by,
+                            created_at AS `created_at: chrono::DateTime<chrono::Utc>`
+                        FROM submission_ip_blocklist
+                        ORDER BY created_at DESC, id"
+                    )
+                    .fetch_all(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn fetch_submission_ip_block(
+        &self,
+        id: SubmissionIpBlockId,
+    ) -> Result<Option<SubmissionIpBlockRecord>, InfraError> {
+        let id = id.to_string();
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                Ok::<_, InfraError>(
+                    sqlx::query_as!(
+                        SubmissionIpBlockRecord,
+                        r"SELECT id, ip_range, reason, created_by,
+                            created_at AS `created_at: chrono::DateTime<chrono::Utc>`
+                        FROM submission_ip_blocklist
+                        WHERE id = ?",
+                        id,
+                    )
+                    .fetch_optional(&mut **txn)
+                    .await?,
+                )
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %block.id()))]
+    pub(crate) async fn insert_submission_ip_block(
+        &self,
+        block: &SubmissionIpBlock,
+    ) -> Result<(), InfraError> {
+        let id = block.id().to_string();
+        let ip_range = block.range().to_string();
+        let reason = block
+            .reason()
+            .as_ref()
+            .map(|reason| reason.as_str().to_owned());
+        let created_by = block.created_by().to_string();
+        let created_at = *block.created_at();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(
+                    r"INSERT INTO submission_ip_blocklist (id, ip_range, reason, created_by, created_at)
+                    VALUES (?, ?, ?, ?, ?)",
+                    id,
+                    ip_range,
+                    reason,
+                    created_by,
+                    created_at,
+                )
+                .execute(&mut **txn)
+                .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(id = %id))]
+    pub(crate) async fn delete_submission_ip_block(
+        &self,
+        id: SubmissionIpBlockId,
+    ) -> Result<(), InfraError> {
+        let id = id.to_string();
+
+        self.read_write_transaction(|txn| {
+            Box::pin(async move {
+                sqlx::query!(r"DELETE FROM submission_ip_blocklist WHERE id = ?", id)
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, InfraError>(())
+            })
+        })
+        .await
+    }
+}
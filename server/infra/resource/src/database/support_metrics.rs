@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use errors::infra::InfraError;
+
+use crate::{
+    database::{connection::ConnectionPool, count::count_as_u32},
+    records::{OpenAnswerCountRecord, StaffActivityRecord, SupportMetricsRecord},
+};
+
+impl ConnectionPool {
+    /// 期間内に送信された回答への対応時間と、期間内の運営メンバーごとの対応件数を集計する。
+    ///
+    /// 運営メンバーは現在 `ADMINISTRATOR` のロールを持つユーザーとし、回答者本人の
+    /// メッセージは初回対応として扱わない。未完了の回答件数は期間によらない現在の件数。
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn aggregate_support_metrics(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<SupportMetricsRecord, InfraError> {
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                let first_response_seconds = sqlx::query_scalar!(
+                    r"SELECT TIMESTAMPDIFF(SECOND, answers.timestamp, MIN(messages.timestamp))
+                        AS `seconds!: i64`
+                    FROM answers
+                    INNER JOIN messages ON messages.related_answer_id = answers.id
+                    INNER JOIN users ON users.id = messages.sender
+                    WHERE answers.timestamp >= ? AND answers.timestamp < ?
+                        AND users.role = 'ADMINISTRATOR'
+                        AND (answers.user IS NULL OR messages.sender <> answers.user)
+                    GROUP BY answers.id, answers.timestamp",
+                    from,
+                    to,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                let completion_seconds = sqlx::query_scalar!(
+                    r"SELECT TIMESTAMPDIFF(SECOND, answers.timestamp,
+                            MIN(form_answer_status_history.changed_at)) AS `seconds!: i64`
+                    FROM answers
+                    INNER JOIN form_answer_status_history
+                        ON form_answer_status_history.answer_id = answers.id
+                    WHERE answers.timestamp >= ? AND answers.timestamp < ?
+                        AND form_answer_status_history.to_status = 'COMPLETED'
+                    GROUP BY answers.id, answers.timestamp",
+                    from,
+                    to,
+                )
+                .fetch_all(&mut **txn)
+                .await?;
+
+                let open_answers = sqlx::query!(
+                    r"SELECT form_meta_data.id AS form_id, form_meta_data.title,
+                        COUNT(*) AS `count!: i64`
+                    FROM answers
+                    INNER JOIN form_meta_data ON form_meta_data.id = answers.form_id
+                    WHERE answers.status <> 'COMPLETED'
+                    GROUP BY form_meta_data.id, form_meta_data.title
+                    ORDER BY COUNT(*) DESC, form_meta_data.id",
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(OpenAnswerCountRecord {
+                        form_id: row.form_id,
+                        form_title: row.title,
+                        count: count_as_u32(row.count, "answers")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                let staff_activities = sqlx::query!(
+                    r"SELECT users.id, users.name,
+                        COUNT(DISTINCT activities.answer_id) AS `handled_answers!: i64`,
+                        COUNT(DISTINCT CASE WHEN activities.kind = 'COMPLETED'
+                            THEN activities.answer_id END) AS `completed_answers!: i64`,
+                        CAST(SUM(activities.kind = 'MESSAGE') AS SIGNED) AS `messages!: i64`,
+                        CAST(SUM(activities.kind = 'COMMENT') AS SIGNED) AS `comments!: i64`
+                    FROM (
+                        SELECT answer_id, changed_by_id AS user_id, 'COMPLETED' AS kind
+                        FROM form_answer_status_history
+                        WHERE to_status = 'COMPLETED' AND changed_at >= ? AND changed_at < ?
+                        UNION ALL
+                        SELECT related_answer_id, sender, 'MESSAGE'
+                        FROM messages
+                        WHERE timestamp >= ? AND timestamp < ?
+                        UNION ALL
+                        SELECT answer_id, commented_by, 'COMMENT'
+                        FROM form_answer_comments
+                        WHERE timestamp >= ? AND timestamp < ?
+                    ) AS activities
+                    INNER JOIN users ON users.id = activities.user_id
+                    WHERE users.role = 'ADMINISTRATOR'
+                    GROUP BY users.id, users.name
+                    ORDER BY COUNT(DISTINCT activities.answer_id) DESC, users.name",
+                    from,
+                    to,
+                    from,
+                    to,
+                    from,
+                    to,
+                )
+                .fetch_all(&mut **txn)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    Ok::<_, InfraError>(StaffActivityRecord {
+                        user_id: row.id,
+                        name: row.name,
+                        handled_answers: count_as_u32(row.handled_answers, "answers")?,
+                        completed_answers: count_as_u32(
+                            row.completed_answers,
+                            "form_answer_status_history",
+                        )?,
+                        messages: count_as_u32(row.messages, "messages")?,
+                        comments: count_as_u32(row.comments, "form_answer_comments")?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+                Ok::<_, InfraError>(SupportMetricsRecord {
+                    first_response_seconds,
+                    completion_seconds,
+                    open_answers,
+                    staff_activities,
+                })
+            })
+        })
+        .await
+    }
+}
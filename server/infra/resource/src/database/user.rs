@@ -176,6 +176,57 @@ impl UserDatabase for ConnectionPool {
         .await
     }
 
+    async fn find_by_names(&self, names: Vec<String>) -> Result<Vec<AccountUser>, InfraError> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.read_only_transaction(|txn| {
+            Box::pin(async move {
+                // users.name の照合順序は大文字と小文字を区別しないため、そのまま比較する
+                let sql = format!(
+                    "SELECT id, name, role FROM users WHERE name IN ({})",
+                    std::iter::repeat_n("?", names.len()).join(", ")
+                );
+
+                let rows = names
+                    .iter()
+                    .fold(query(AssertSqlSafe(&*sql)), |query, name| query.bind(name))
+                    .fetch_all(&mut **txn)
+                    .await?;
+
+                let users = rows
+                    .into_iter()
+                    .map(|row| {
+                        Ok::<_, InfraError>((
+                            row.try_get::<String, _>("id")?,
+                            row.try_get::<String, _>("name")?,
+                            row.try_get::<String, _>("role")?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let user_ids = users.iter().map(|(id, _, _)| id.to_owned()).collect_vec();
+                let mut groups_by_user = fetch_groups_by_user_ids(txn, &user_ids).await?;
+
+                users
+                    .into_iter()
+                    .map(|(id, name, role)| {
+                        let user_id = Uuid::parse_str(&id)?;
+                        let groups = groups_by_user.remove(&id).unwrap_or_default();
+                        Ok::<_, InfraError>(AccountUser::with_groups(
+                            name,
+                            user_id.into(),
+                            Role::from_str(&role)?,
+                            groups,
+                        ))
+                    })
+                    .collect()
+            })
+        })
+        .await
+    }
+
     async fn upsert_user(&self, user: &AccountUser) -> Result<(), InfraError> {
         let user_id = user.id().to_string();
         let user_name = user.name().to_owned();
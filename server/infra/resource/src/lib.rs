@@ -6,4 +6,5 @@ pub mod outgoing;
 pub mod rate_limit;
 pub mod records;
 pub mod repository;
+pub mod storage;
 pub mod turnstile;
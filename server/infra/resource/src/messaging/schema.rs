@@ -80,6 +80,10 @@ impl Payload {
                 let users: Users = serde_json::from_value(value)?;
                 Ok(Some(ActualDataFields::Users(users)))
             }
+            "announcements" => {
+                let announcements: Announcements = serde_json::from_value(value)?;
+                Ok(Some(ActualDataFields::Announcements(announcements)))
+            }
             _ => Ok(None),
         }
     }
@@ -313,6 +317,35 @@ impl TryFrom<Users> for domain::search::models::Users {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Announcements {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+}
+
+impl From<domain::search::models::Announcements> for Announcements {
+    fn from(announcements: domain::search::models::Announcements) -> Self {
+        Self {
+            id: announcements.id.to_string(),
+            title: announcements.title,
+            body: announcements.body,
+        }
+    }
+}
+
+impl TryFrom<Announcements> for domain::search::models::Announcements {
+    type Error = InfraError;
+
+    fn try_from(announcements: Announcements) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::from_str(&announcements.id)?.into(),
+            title: announcements.title,
+            body: announcements.body,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub enum ActualDataFields {
     FormMetaData(FormMetaData),
@@ -322,6 +355,7 @@ pub enum ActualDataFields {
     LabelForFormAnswers(LabelForFormAnswers),
     LabelForForms(LabelForForms),
     Users(Users),
+    Announcements(Announcements),
 }
 
 impl From<SearchableFields> for ActualDataFields {
@@ -338,6 +372,7 @@ impl From<SearchableFields> for ActualDataFields {
             }
             SearchableFields::LabelForForms(data) => ActualDataFields::LabelForForms(data.into()),
             SearchableFields::Users(data) => ActualDataFields::Users(data.into()),
+            SearchableFields::Announcements(data) => ActualDataFields::Announcements(data.into()),
         }
     }
 }
@@ -366,6 +401,9 @@ impl TryFrom<ActualDataFields> for SearchableFields {
                 Ok(SearchableFields::LabelForForms(data.try_into()?))
             }
             ActualDataFields::Users(data) => Ok(SearchableFields::Users(data.try_into()?)),
+            ActualDataFields::Announcements(data) => {
+                Ok(SearchableFields::Announcements(data.try_into()?))
+            }
         }
     }
 }
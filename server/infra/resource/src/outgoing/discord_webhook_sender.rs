@@ -250,6 +250,7 @@ impl From<DiscordWebhookField> for DiscordEmbedField {
 }
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
 
@@ -1,27 +1,41 @@
 use std::str::FromStr;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use domain::{
     account::models::{
         AccountUser, DiscordUser, DiscordUserId, DiscordUserName, Role, UserGroupId,
     },
+    announcement::{Announcement, AnnouncementPublishPeriod},
     form::answer::TemporaryAnswerAuthor,
     form::{
         answer::{
-            AnswerAuthor, AnswerEntry, AnswerLabel, AnswerPublication, AnswerStatus, AnswerTitle,
-            FormAnswerContent, RedmineImportedAnswerReference, RedmineUserSnapshot,
+            AnswerAuthor, AnswerEntry, AnswerLabel, AnswerModerationStatus, AnswerPublication,
+            AnswerQuizScore, AnswerStatus, AnswerTitle, FormAnswerContent,
+            RedmineImportedAnswerReference, RedmineUserSnapshot,
         },
-        comment::{Comment, CommentContent},
-        message::{Message, MessageBody},
+        comment::{Comment, CommentContent, CommentVisibility},
+        content_filter::{
+            ContentFilterAction, ContentFilterCondition, ContentFilterFlag, ContentFilterPattern,
+            ContentFilterRule, ContentFilterTarget,
+        },
+        message::{Message, MessageAttachment, MessageBody, MessageSender},
+        message_read::MessageReadReceipt,
+        message_template::{MessageTemplate, MessageTemplateBody, MessageTemplateName},
         models::{
             ActiveForm, AllowedUserGroups, AnswerAcceptancePeriod, AnswerAuthorPublicationPolicy,
-            AnswerSettings, ArchivedForm, DefaultAnswerTitle, DiscordWebhookUrl, FormDescription,
-            FormId, FormLabel, FormLabelAssignment, FormLabelId, FormLabelName, FormMeta,
-            FormSettings, FormTitle, QuestionSet,
+            AnswerQuizSettings, AnswerResponseDeadline, AnswerSettings, ArchivedForm,
+            DefaultAnswerTitle, DiscordWebhookUrl, FormDescription, FormId, FormLabel,
+            FormLabelAssignment, FormLabelId, FormLabelName, FormMeta, FormSettings, FormTitle,
+            MessageThreadLockPolicy, QuestionSet, ResponseDeadlineHours,
+            TemporaryAnswerModerationPolicy,
         },
-        question::{Choice, Question, QuestionType},
+        question::{Choice, Question, QuestionType, QuizPoints},
+        temporary_answer_abuse::TemporaryAnswerAbuseMetadata,
+        temporary_answer_access::TemporaryAnswerAccess,
+        temporary_answer_claim::TemporaryAnswerClaimTicket,
     },
     notification::models::NotificationPreference,
+    submission_ip_blocklist::{BlockedIpRange, SubmissionIpBlock},
 };
 use errors::{Error, domain::DomainError, infra::InfraError};
 use types::non_empty_string::NonEmptyString;
@@ -33,6 +47,7 @@ pub struct ChoiceRecord {
     pub id: Option<i32>,
     pub position: u16,
     pub label: String,
+    pub quiz_points: Option<u32>,
 }
 
 impl TryFrom<ChoiceRecord> for Choice {
@@ -43,11 +58,17 @@ impl TryFrom<ChoiceRecord> for Choice {
             id,
             position,
             label,
+            quiz_points,
         }: ChoiceRecord,
     ) -> Result<Self, Self::Error> {
         unsafe {
-            Choice::from_raw_parts(id.map(Into::into), position, label.try_into()?)
-                .map_err(Into::into)
+            Choice::from_raw_parts(
+                id.map(Into::into),
+                position,
+                label.try_into()?,
+                quiz_points.map(QuizPoints::try_from).transpose()?,
+            )
+            .map_err(Into::into)
         }
     }
 }
@@ -122,6 +143,11 @@ pub struct ActiveFormRecord {
     pub acceptance_period_start_at: Option<DateTime<Utc>>,
     pub acceptance_period_end_at: Option<DateTime<Utc>>,
     pub default_answer_title: Option<String>,
+    pub response_deadline_hours: Option<u32>,
+    pub quiz_enabled: bool,
+    pub show_quiz_score_to_author: bool,
+    pub lock_message_thread_on_completion: bool,
+    pub hold_temporary_answers_for_review: bool,
     pub allowed_group_ids: Vec<UserGroupId>,
     pub answer_group_ids: Vec<UserGroupId>,
     pub questions: Vec<QuestionRecord>,
@@ -146,6 +172,11 @@ impl TryFrom<ActiveFormRecord> for ActiveForm {
             acceptance_period_start_at,
             acceptance_period_end_at,
             default_answer_title,
+            response_deadline_hours,
+            quiz_enabled,
+            show_quiz_score_to_author,
+            lock_message_thread_on_completion,
+            hold_temporary_answers_for_review,
             allowed_group_ids,
             answer_group_ids,
             questions,
@@ -169,8 +200,25 @@ impl TryFrom<ActiveFormRecord> for ActiveForm {
             allow_temporary_answers,
             AllowedUserGroups::new(answer_group_ids),
         )?
-        .change_author_publication_policy(
-            AnswerAuthorPublicationPolicy::from_hide_author(hide_author),
+        .change_author_publication_policy(AnswerAuthorPublicationPolicy::from_hide_author(
+            hide_author,
+        ))
+        .change_response_deadline(AnswerResponseDeadline::new(
+            response_deadline_hours
+                .map(ResponseDeadlineHours::try_from)
+                .transpose()?,
+        ))
+        .change_quiz(AnswerQuizSettings::new(
+            quiz_enabled,
+            show_quiz_score_to_author,
+        ))
+        .change_message_thread_lock_policy(MessageThreadLockPolicy::from_lock_on_completion(
+            lock_message_thread_on_completion,
+        ))
+        .change_temporary_answer_moderation_policy(
+            TemporaryAnswerModerationPolicy::from_hold_for_review(
+                hold_temporary_answers_for_review,
+            ),
         );
 
         Ok(unsafe {
@@ -261,6 +309,7 @@ pub struct PortalCommentRecord {
     pub answer_id: String,
     pub comment_id: String,
     pub content: String,
+    pub is_internal: bool,
     pub timestamp: DateTime<Utc>,
     pub commented_by_name: String,
     pub commented_by_id: String,
@@ -294,12 +343,21 @@ pub struct CommentHistoryRecord {
     pub original_timestamp: DateTime<Utc>,
     pub action: String,
     pub content: String,
+    pub is_internal: bool,
     pub operated_by_id: String,
     pub operated_by_name: String,
     pub operated_by_role: String,
     pub operated_at: DateTime<Utc>,
 }
 
+pub(crate) fn comment_visibility(is_internal: bool) -> CommentVisibility {
+    if is_internal {
+        CommentVisibility::Internal
+    } else {
+        CommentVisibility::Public
+    }
+}
+
 impl TryFrom<PortalCommentRecord> for Comment {
     type Error = Error;
 
@@ -308,6 +366,7 @@ impl TryFrom<PortalCommentRecord> for Comment {
             answer_id,
             comment_id,
             content,
+            is_internal,
             timestamp,
             commented_by_name: _,
             commented_by_id,
@@ -327,6 +386,7 @@ impl TryFrom<PortalCommentRecord> for Comment {
                 Uuid::from_str(&commented_by_id)
                     .map_err(Into::<InfraError>::into)?
                     .into(),
+                comment_visibility(is_internal),
             )
         })
     }
@@ -380,9 +440,14 @@ pub struct FormAnswerRecord {
     pub title: Option<String>,
     pub publication: String,
     pub status: String,
+    pub moderation_status: String,
     pub contents: Vec<FormAnswerContentRecord>,
     pub messages: Vec<MessageRecord>,
     pub redmine_reference: Option<RedmineImportedAnswerReference>,
+    pub assignee_id: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub quiz_score: Option<u32>,
+    pub max_quiz_score: Option<u32>,
 }
 
 pub enum AnswerAuthorRecord {
@@ -403,9 +468,14 @@ impl TryFrom<FormAnswerRecord> for AnswerEntry {
             title,
             publication,
             status,
+            moderation_status,
             contents,
             messages: _,
             redmine_reference,
+            assignee_id,
+            due_at,
+            quiz_score,
+            max_quiz_score,
         }: FormAnswerRecord,
     ) -> Result<Self, Self::Error> {
         let author = match author {
@@ -436,6 +506,13 @@ impl TryFrom<FormAnswerRecord> for AnswerEntry {
             }
             .into());
         }
+        let assignee = assignee_id
+            .map(|assignee_id| Uuid::from_str(&assignee_id).map(Into::into))
+            .transpose()
+            .map_err(Into::<InfraError>::into)?;
+        let quiz_score = quiz_score
+            .zip(max_quiz_score)
+            .map(|(score, max_score)| unsafe { AnswerQuizScore::from_raw_parts(score, max_score) });
         unsafe {
             Ok(
                 AnswerEntry::from_raw_parts_with_status_and_redmine_reference(
@@ -451,7 +528,11 @@ impl TryFrom<FormAnswerRecord> for AnswerEntry {
                         .map(TryInto::try_into)
                         .collect::<Result<_, _>>()?,
                     redmine_reference,
-                ),
+                )
+                .with_raw_assignee(assignee)
+                .with_raw_due_at(due_at)
+                .with_raw_quiz_score(quiz_score)
+                .with_raw_moderation_status(AnswerModerationStatus::try_from(moderation_status)?),
             )
         }
     }
@@ -497,16 +578,108 @@ impl TryFrom<FormLabelRecord> for FormLabel {
     }
 }
 
+pub struct MessageTemplateRecord {
+    pub id: String,
+    pub name: String,
+    pub body: String,
+}
+
+impl TryFrom<MessageTemplateRecord> for MessageTemplate {
+    type Error = Error;
+
+    fn try_from(
+        MessageTemplateRecord { id, name, body }: MessageTemplateRecord,
+    ) -> Result<Self, Self::Error> {
+        Ok(unsafe {
+            MessageTemplate::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                MessageTemplateName::new(name.try_into()?),
+                MessageTemplateBody::new(body.try_into()?),
+            )
+        })
+    }
+}
+
+pub struct MessageReadReceiptRecord {
+    pub answer_id: String,
+    pub user_id: String,
+    pub last_read_message_id: String,
+    pub read_at: DateTime<Utc>,
+}
+
+impl TryFrom<MessageReadReceiptRecord> for MessageReadReceipt {
+    type Error = Error;
+
+    fn try_from(
+        MessageReadReceiptRecord {
+            answer_id,
+            user_id,
+            last_read_message_id,
+            read_at,
+        }: MessageReadReceiptRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(MessageReadReceipt::from_raw_parts(
+                Uuid::from_str(&answer_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&user_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&last_read_message_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                read_at,
+            ))
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct MessageRecord {
     pub id: String,
-    pub sender_name: String,
-    pub sender_id: String,
-    pub sender_role: String,
+    pub sender_id: Option<String>,
+    pub temporary_sender_id: Option<String>,
     pub body: String,
     pub timestamp: DateTime<Utc>,
 }
 
+#[derive(Clone)]
+pub struct MessageAttachmentRecord {
+    pub id: String,
+    pub message_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u32,
+}
+
+impl TryFrom<MessageAttachmentRecord> for MessageAttachment {
+    type Error = Error;
+
+    fn try_from(
+        MessageAttachmentRecord {
+            id,
+            message_id: _,
+            file_name,
+            content_type,
+            size,
+        }: MessageAttachmentRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(MessageAttachment::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                file_name,
+                content_type,
+                size.into(),
+            ))
+        }
+    }
+}
+
 pub struct MessageHistoryRecord {
     pub id: String,
     pub answer_id: String,
@@ -523,6 +696,58 @@ pub struct MessageHistoryRecord {
     pub operated_at: DateTime<Utc>,
 }
 
+pub struct AnswerStatisticsRecord {
+    pub total: u32,
+    pub contents: Vec<AnswerContentCountRecord>,
+    pub daily: Vec<DailyAnswerCountRecord>,
+    pub statuses: Vec<StatusAnswerCountRecord>,
+    pub labels: Vec<LabelAnswerCountRecord>,
+}
+
+pub struct AnswerContentCountRecord {
+    pub question_id: String,
+    pub answer: String,
+    pub count: u32,
+}
+
+pub struct DailyAnswerCountRecord {
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+pub struct StatusAnswerCountRecord {
+    pub status: String,
+    pub count: u32,
+}
+
+pub struct LabelAnswerCountRecord {
+    pub label_id: String,
+    pub name: String,
+    pub count: u32,
+}
+
+pub struct SupportMetricsRecord {
+    pub first_response_seconds: Vec<i64>,
+    pub completion_seconds: Vec<i64>,
+    pub open_answers: Vec<OpenAnswerCountRecord>,
+    pub staff_activities: Vec<StaffActivityRecord>,
+}
+
+pub struct OpenAnswerCountRecord {
+    pub form_id: String,
+    pub form_title: String,
+    pub count: u32,
+}
+
+pub struct StaffActivityRecord {
+    pub user_id: String,
+    pub name: String,
+    pub handled_answers: u32,
+    pub completed_answers: u32,
+    pub messages: u32,
+    pub comments: u32,
+}
+
 pub struct AnswerStatusHistoryRecord {
     pub id: String,
     pub answer_id: String,
@@ -534,6 +759,28 @@ pub struct AnswerStatusHistoryRecord {
     pub changed_at: DateTime<Utc>,
 }
 
+pub struct AnswerAssignmentHistoryRecord {
+    pub id: String,
+    pub answer_id: String,
+    pub from_assignee_id: Option<String>,
+    pub to_assignee_id: Option<String>,
+    pub changed_by_id: String,
+    pub changed_by_name: String,
+    pub changed_by_role: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+pub struct AnswerMoveHistoryRecord {
+    pub id: String,
+    pub answer_id: String,
+    pub from_form_id: String,
+    pub to_form_id: String,
+    pub changed_by_id: String,
+    pub changed_by_name: String,
+    pub changed_by_role: String,
+    pub changed_at: DateTime<Utc>,
+}
+
 pub struct AnswerTitleHistoryRecord {
     pub id: String,
     pub answer_id: String,
@@ -545,34 +792,442 @@ pub struct AnswerTitleHistoryRecord {
     pub changed_at: DateTime<Utc>,
 }
 
-impl TryFrom<MessageRecord> for Message {
+pub struct AnswerDrawRecord {
+    pub id: String,
+    pub form_id: String,
+    pub seed: u64,
+    pub winner_count: u32,
+    pub status_filter: Option<String>,
+    pub label_filter_id: Option<String>,
+    pub exclude_restricted_submitters: bool,
+    pub drawn_by_id: String,
+    pub drawn_by_name: String,
+    pub drawn_by_role: String,
+    pub drawn_at: DateTime<Utc>,
+}
+
+pub struct AnswerDrawEntryRecord {
+    pub draw_id: String,
+    pub answer_id: String,
+    pub winner_rank: Option<u32>,
+}
+
+/// メッセージとその添付ファイルのレコードの組
+pub struct MessageWithAttachmentsRecord {
+    pub message: MessageRecord,
+    pub attachments: Vec<MessageAttachmentRecord>,
+}
+
+impl TryFrom<MessageWithAttachmentsRecord> for Message {
     type Error = Error;
 
     fn try_from(
-        MessageRecord {
-            id,
-            sender_name: _,
-            sender_id,
-            sender_role: _,
-            body,
-            timestamp,
-        }: MessageRecord,
+        MessageWithAttachmentsRecord {
+            message:
+                MessageRecord {
+                    id,
+                    sender_id,
+                    temporary_sender_id,
+                    body,
+                    timestamp,
+                },
+            attachments,
+        }: MessageWithAttachmentsRecord,
     ) -> Result<Self, Self::Error> {
+        let attachments = attachments
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
         unsafe {
             Ok(Message::from_raw_parts(
                 Uuid::from_str(&id)
                     .map_err(Into::<InfraError>::into)?
                     .into(),
-                Uuid::from_str(&sender_id)
-                    .map_err(Into::<InfraError>::into)?
-                    .into(),
+                message_sender(sender_id, temporary_sender_id)?,
                 MessageBody::new(body.try_into()?),
                 timestamp,
+                attachments,
             ))
         }
     }
 }
 
+pub struct TemporaryAnswerAccessRecord {
+    pub answer_id: String,
+    pub form_id: String,
+    pub temporary_user_id: String,
+    pub temporary_user_name: String,
+    pub temporary_user_contact_text: String,
+    pub issued_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<TemporaryAnswerAccessRecord> for TemporaryAnswerAccess {
+    type Error = Error;
+
+    fn try_from(
+        TemporaryAnswerAccessRecord {
+            answer_id,
+            form_id,
+            temporary_user_id,
+            temporary_user_name,
+            temporary_user_contact_text,
+            issued_at,
+            revoked_at,
+        }: TemporaryAnswerAccessRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(TemporaryAnswerAccess::from_raw_parts(
+                Uuid::from_str(&answer_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&form_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                TemporaryAnswerAuthor::from_raw_parts(
+                    Uuid::from_str(&temporary_user_id)
+                        .map_err(Into::<InfraError>::into)?
+                        .into(),
+                    temporary_user_name,
+                    temporary_user_contact_text,
+                ),
+                issued_at,
+                revoked_at,
+            ))
+        }
+    }
+}
+
+pub struct TemporaryAnswerClaimTicketRecord {
+    pub answer_id: String,
+    pub form_id: String,
+    pub temporary_user_id: String,
+    pub temporary_user_name: String,
+    pub temporary_user_contact_text: String,
+    pub issued_at: DateTime<Utc>,
+    pub claimed_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<TemporaryAnswerClaimTicketRecord> for TemporaryAnswerClaimTicket {
+    type Error = Error;
+
+    fn try_from(
+        TemporaryAnswerClaimTicketRecord {
+            answer_id,
+            form_id,
+            temporary_user_id,
+            temporary_user_name,
+            temporary_user_contact_text,
+            issued_at,
+            claimed_at,
+        }: TemporaryAnswerClaimTicketRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(TemporaryAnswerClaimTicket::from_raw_parts(
+                Uuid::from_str(&answer_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&form_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                TemporaryAnswerAuthor::from_raw_parts(
+                    Uuid::from_str(&temporary_user_id)
+                        .map_err(Into::<InfraError>::into)?
+                        .into(),
+                    temporary_user_name,
+                    temporary_user_contact_text,
+                ),
+                issued_at,
+                claimed_at,
+            ))
+        }
+    }
+}
+
+pub struct TemporaryAnswerAbuseMetadataRecord {
+    pub answer_id: String,
+    pub client_ip_hash: Option<String>,
+    pub user_agent_fingerprint: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl TryFrom<TemporaryAnswerAbuseMetadataRecord> for TemporaryAnswerAbuseMetadata {
+    type Error = Error;
+
+    fn try_from(
+        TemporaryAnswerAbuseMetadataRecord {
+            answer_id,
+            client_ip_hash,
+            user_agent_fingerprint,
+            recorded_at,
+        }: TemporaryAnswerAbuseMetadataRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(TemporaryAnswerAbuseMetadata::from_raw_parts(
+                Uuid::from_str(&answer_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                client_ip_hash,
+                user_agent_fingerprint,
+                recorded_at,
+            ))
+        }
+    }
+}
+
+pub struct SubmissionIpBlockRecord {
+    pub id: String,
+    pub ip_range: String,
+    pub reason: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<SubmissionIpBlockRecord> for SubmissionIpBlock {
+    type Error = Error;
+
+    fn try_from(
+        SubmissionIpBlockRecord {
+            id,
+            ip_range,
+            reason,
+            created_by,
+            created_at,
+        }: SubmissionIpBlockRecord,
+    ) -> Result<Self, Self::Error> {
+        unsafe {
+            Ok(SubmissionIpBlock::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                BlockedIpRange::try_from(ip_range)?,
+                reason.map(NonEmptyString::try_new).transpose()?,
+                Uuid::from_str(&created_by)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                created_at,
+            ))
+        }
+    }
+}
+
+pub struct ContentFilterRuleRecord {
+    pub id: String,
+    pub form_id: String,
+    pub condition_type: String,
+    pub pattern: Option<String>,
+    pub threshold: Option<u32>,
+    pub action: String,
+    pub label_id: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+fn unexpected_content_filter_rule() -> InfraError {
+    InfraError::Unexpected {
+        cause: "a content filter rule has an unexpected condition or action".to_string(),
+    }
+}
+
+impl TryFrom<ContentFilterRuleRecord> for ContentFilterRule {
+    type Error = Error;
+
+    fn try_from(
+        ContentFilterRuleRecord {
+            id,
+            form_id,
+            condition_type,
+            pattern,
+            threshold,
+            action,
+            label_id,
+            created_by,
+            created_at,
+        }: ContentFilterRuleRecord,
+    ) -> Result<Self, Self::Error> {
+        let condition = match (condition_type.as_str(), pattern, threshold) {
+            ("BLOCKED_WORD", Some(word), None) => {
+                ContentFilterCondition::BlockedWord(NonEmptyString::try_new(word)?)
+            }
+            ("PATTERN", Some(pattern), None) => {
+                ContentFilterCondition::Pattern(ContentFilterPattern::try_from(pattern)?)
+            }
+            ("MAX_LINKS", None, Some(threshold)) => ContentFilterCondition::MaxLinks(threshold),
+            ("REPEATED_CHARACTERS", None, Some(threshold)) => {
+                ContentFilterCondition::RepeatedCharacters(threshold)
+            }
+            _ => return Err(unexpected_content_filter_rule().into()),
+        };
+        let action = match (action.as_str(), label_id) {
+            ("REJECT", None) => ContentFilterAction::Reject,
+            ("FLAG_FOR_REVIEW", None) => ContentFilterAction::FlagForReview,
+            ("ADD_LABEL", Some(label_id)) => ContentFilterAction::AddLabel(
+                Uuid::from_str(&label_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+            ),
+            _ => return Err(unexpected_content_filter_rule().into()),
+        };
+
+        unsafe {
+            Ok(ContentFilterRule::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&form_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                condition,
+                action,
+                Uuid::from_str(&created_by)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                created_at,
+            ))
+        }
+    }
+}
+
+pub struct ContentFilterFlagRecord {
+    pub id: String,
+    pub form_id: String,
+    pub answer_id: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub rule_id: Option<String>,
+    pub flagged_at: DateTime<Utc>,
+}
+
+impl TryFrom<ContentFilterFlagRecord> for ContentFilterFlag {
+    type Error = Error;
+
+    fn try_from(
+        ContentFilterFlagRecord {
+            id,
+            form_id,
+            answer_id,
+            target_type,
+            target_id,
+            rule_id,
+            flagged_at,
+        }: ContentFilterFlagRecord,
+    ) -> Result<Self, Self::Error> {
+        let target_id = target_id
+            .map(|target_id| Uuid::from_str(&target_id))
+            .transpose()
+            .map_err(Into::<InfraError>::into)?;
+        let target = match (target_type.as_str(), target_id) {
+            ("ANSWER", None) => ContentFilterTarget::Answer,
+            ("COMMENT", Some(target_id)) => ContentFilterTarget::Comment(target_id.into()),
+            ("MESSAGE", Some(target_id)) => ContentFilterTarget::Message(target_id.into()),
+            _ => {
+                return Err(InfraError::Unexpected {
+                    cause: "a content filter flag has an unexpected target".to_string(),
+                }
+                .into());
+            }
+        };
+
+        unsafe {
+            Ok(ContentFilterFlag::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&form_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                Uuid::from_str(&answer_id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                target,
+                rule_id
+                    .map(|rule_id| Uuid::from_str(&rule_id))
+                    .transpose()
+                    .map_err(Into::<InfraError>::into)?
+                    .map(Into::into),
+                flagged_at,
+            ))
+        }
+    }
+}
+
+pub struct AnnouncementRecord {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub publish_from: DateTime<Utc>,
+    pub publish_until: Option<DateTime<Utc>>,
+    pub pinned: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct AnnouncementTargetGroupRecord {
+    pub announcement_id: String,
+    pub group_id: String,
+}
+
+impl AnnouncementRecord {
+    /// `announcement_target_groups` から読み出した掲載先のグループと合わせて復元する。
+    pub fn try_into_announcement(
+        self,
+        target_group_ids: Vec<String>,
+    ) -> Result<Announcement, Error> {
+        let AnnouncementRecord {
+            id,
+            title,
+            body,
+            publish_from,
+            publish_until,
+            pinned,
+            created_by,
+            created_at,
+            updated_at,
+        } = self;
+        let target_group_ids = target_group_ids
+            .iter()
+            .map(|group_id| Uuid::from_str(group_id).map(UserGroupId::from))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Into::<InfraError>::into)?;
+
+        unsafe {
+            Ok(Announcement::from_raw_parts(
+                Uuid::from_str(&id)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                NonEmptyString::try_new(title)?,
+                NonEmptyString::try_new(body)?,
+                AnnouncementPublishPeriod::try_new(publish_from, publish_until)?,
+                pinned,
+                AllowedUserGroups::new(target_group_ids),
+                Uuid::from_str(&created_by)
+                    .map_err(Into::<InfraError>::into)?
+                    .into(),
+                created_at,
+                updated_at,
+            ))
+        }
+    }
+}
+
+/// `messages` テーブルの `sender` と `temporary_sender` は、どちらか一方だけが設定される。
+fn message_sender(
+    sender_id: Option<String>,
+    temporary_sender_id: Option<String>,
+) -> Result<MessageSender, InfraError> {
+    match (sender_id, temporary_sender_id) {
+        (Some(sender_id), None) => Ok(MessageSender::User(Uuid::from_str(&sender_id)?.into())),
+        (None, Some(temporary_sender_id)) => Ok(MessageSender::TemporaryAnswerAuthor(
+            Uuid::from_str(&temporary_sender_id)?.into(),
+        )),
+        _ => Err(InfraError::Unexpected {
+            cause: "a message must have exactly one sender".to_string(),
+        }),
+    }
+}
+
 pub struct NotificationSettingsRecord {
     pub recipient: UserRecord,
     pub is_send_message_notification: bool,
@@ -627,6 +1282,7 @@ mod tests {
                 id: Some(1),
                 position: 0,
                 label: "A".to_string(),
+                quiz_points: None,
             }],
             is_required: true,
         }
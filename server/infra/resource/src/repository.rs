@@ -1,9 +1,20 @@
+pub mod announcement_repository_impl;
+pub mod answer_draw_repository_impl;
+pub mod content_filter_repository_impl;
 pub mod form_repository_impls;
 pub mod form_submission_restriction_repository_impl;
 pub mod global_discord_webhook_repository_impl;
+pub mod mention_repository_impl;
+pub mod message_read_repository_impl;
+pub mod message_template_repository_impl;
 pub mod minecraft_ban_repository_impl;
 pub mod notification_repository_impl;
 pub mod search_repository_impl;
+pub mod submission_ip_blocklist_repository_impl;
+pub mod support_metrics_repository_impl;
+pub mod temporary_answer_abuse_repository_impl;
+pub mod temporary_answer_access_repository_impl;
+pub mod temporary_answer_claim_repository_impl;
 pub mod user_repository_impl;
 
 use std::sync::Arc;
@@ -15,6 +26,7 @@ use domain::repository::health_check_repository::HealthCheckRepository;
 use crate::{
     database::{components::DatabaseComponents, connection::ConnectionPool},
     health_check::HealthCheckRepositoryImpl,
+    storage::{ConfiguredAttachmentStorage, MESSAGE_ATTACHMENT_STORAGE},
 };
 
 pub type RealInfrastructureRepository = SharedRepository<ConnectionPool, HealthCheckRepositoryImpl>;
@@ -62,6 +74,54 @@ impl<H: HealthCheckRepository + Send + Sync + 'static> SharedRepository<Connecti
     pub fn global_discord_webhook_repository(&self) -> &Repository<ConnectionPool> {
         &self.db
     }
+
+    pub fn answer_draw_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn support_metrics_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn message_template_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn mention_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn message_read_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn temporary_answer_access_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn temporary_answer_claim_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn temporary_answer_abuse_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn submission_ip_blocklist_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn announcement_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn content_filter_repository(&self) -> &Repository<ConnectionPool> {
+        &self.db
+    }
+
+    pub fn message_attachment_storage(&self) -> &'static ConfiguredAttachmentStorage {
+        &MESSAGE_ATTACHMENT_STORAGE
+    }
 }
 
 impl<Client: DatabaseComponents + 'static, H: HealthCheckRepository + Send + Sync + 'static>
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use domain::{
+    announcement::{Announcement, AnnouncementId},
+    repository::announcement_repository::AnnouncementRepository,
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read, Update},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl AnnouncementRepository for Repository<ConnectionPool> {
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<Announcement, Read>>, Error> {
+        self.client
+            .fetch_announcements()
+            .await?
+            .into_iter()
+            .map(|(record, target_group_ids)| {
+                record
+                    .try_into_announcement(target_group_ids)
+                    .map(AuthorizationGuard::from)
+            })
+            .collect()
+    }
+
+    async fn get(
+        &self,
+        id: AnnouncementId,
+    ) -> Result<Option<AuthorizationGuard<Announcement, Read>>, Error> {
+        self.client
+            .fetch_announcement(id)
+            .await?
+            .map(|(record, target_group_ids)| {
+                record
+                    .try_into_announcement(target_group_ids)
+                    .map(AuthorizationGuard::from)
+            })
+            .transpose()
+    }
+
+    async fn create(&self, announcement: Allowed<Announcement, Create>) -> Result<(), Error> {
+        self.client
+            .insert_announcement(announcement.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn update(&self, announcement: Allowed<Announcement, Update>) -> Result<(), Error> {
+        self.client
+            .update_announcement(announcement.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn delete(&self, announcement: Allowed<Announcement, Delete>) -> Result<(), Error> {
+        self.client
+            .delete_announcement(*announcement.id())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn size(&self) -> Result<u32, Error> {
+        self.client.announcement_size().await.map_err(Into::into)
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use domain::{
+    account::models::UserSnapshot,
+    form::{
+        answer::{AnswerId, AnswerStatus},
+        draw::{
+            AnswerDraw, AnswerDrawCriteria, AnswerDrawId, AnswerDrawSeed, AnswerDrawWinnerCount,
+        },
+        models::ActiveForm,
+    },
+    repository::form::answer_draw_repository::AnswerDrawRepository,
+    types::authorization_guard::{Allowed, Create, Read},
+};
+use errors::{Error, infra::InfraError};
+use uuid::Uuid;
+
+use crate::{
+    database::connection::ConnectionPool,
+    records::{AnswerDrawEntryRecord, AnswerDrawRecord},
+    repository::Repository,
+};
+
+fn into_answer_draw(
+    record: AnswerDrawRecord,
+    entries: Vec<AnswerDrawEntryRecord>,
+) -> Result<AnswerDraw, Error> {
+    let mut candidates = entries
+        .into_iter()
+        .map(|entry| {
+            Ok::<_, Error>((
+                AnswerId::from(Uuid::parse_str(&entry.answer_id).map_err(InfraError::from)?),
+                entry.winner_rank,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    candidates.sort_by_key(|(answer_id, _)| *answer_id);
+
+    let mut winners = candidates
+        .iter()
+        .filter_map(|(answer_id, rank)| rank.map(|rank| (rank, *answer_id)))
+        .collect::<Vec<_>>();
+    winners.sort_by_key(|(rank, _)| *rank);
+
+    let criteria = AnswerDrawCriteria::new(
+        record
+            .status_filter
+            .map(AnswerStatus::try_from)
+            .transpose()
+            .map_err(Error::from)?,
+        record
+            .label_filter_id
+            .map(|label_id| Uuid::parse_str(&label_id))
+            .transpose()
+            .map_err(InfraError::from)?
+            .map(Into::into),
+        record.exclude_restricted_submitters,
+    );
+
+    Ok(unsafe {
+        AnswerDraw::from_raw_parts(
+            Uuid::parse_str(&record.id)
+                .map_err(InfraError::from)?
+                .into(),
+            Uuid::parse_str(&record.form_id)
+                .map_err(InfraError::from)?
+                .into(),
+            AnswerDrawSeed::new(record.seed),
+            AnswerDrawWinnerCount::try_new(record.winner_count)?,
+            criteria,
+            candidates
+                .into_iter()
+                .map(|(answer_id, _)| answer_id)
+                .collect(),
+            winners
+                .into_iter()
+                .map(|(_, answer_id)| answer_id)
+                .collect(),
+            UserSnapshot::new(
+                Uuid::parse_str(&record.drawn_by_id)
+                    .map_err(InfraError::from)?
+                    .into(),
+                record.drawn_by_name,
+                record.drawn_by_role.parse().map_err(InfraError::from)?,
+            ),
+            record.drawn_at,
+        )
+    })
+}
+
+#[async_trait]
+impl AnswerDrawRepository for Repository<ConnectionPool> {
+    async fn save(&self, draw: &Allowed<AnswerDraw, Create>) -> Result<(), Error> {
+        self.client
+            .insert_answer_draw(draw.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn list_by_form(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+    ) -> Result<Vec<Allowed<AnswerDraw, Read>>, Error> {
+        self.client
+            .fetch_answer_draws(*form.id())
+            .await?
+            .into_iter()
+            .map(|(record, entries)| {
+                form.read_draw(into_answer_draw(record, entries)?)
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
+
+    async fn get(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        draw_id: AnswerDrawId,
+    ) -> Result<Option<Allowed<AnswerDraw, Read>>, Error> {
+        self.client
+            .fetch_answer_draw(*form.id(), draw_id)
+            .await?
+            .map(|(record, entries)| {
+                form.read_draw(into_answer_draw(record, entries)?)
+                    .map_err(Into::into)
+            })
+            .transpose()
+    }
+}
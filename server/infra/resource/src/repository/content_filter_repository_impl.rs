@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use domain::{
+    form::{
+        content_filter::{
+            ContentFilterEnforcement, ContentFilterFlag, ContentFilterFlagId, ContentFilterRule,
+            ContentFilterRuleId,
+        },
+        models::FormId,
+    },
+    repository::form::content_filter_repository::ContentFilterRepository,
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl ContentFilterRepository for Repository<ConnectionPool> {
+    async fn list_rules(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterRule, Read>>, Error> {
+        self.client
+            .fetch_content_filter_rules(form_id)
+            .await?
+            .into_iter()
+            .map(|record| ContentFilterRule::try_from(record).map(AuthorizationGuard::from))
+            .collect()
+    }
+
+    async fn get_rule(
+        &self,
+        id: ContentFilterRuleId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterRule, Read>>, Error> {
+        self.client
+            .fetch_content_filter_rule(id)
+            .await?
+            .map(|record| ContentFilterRule::try_from(record).map(AuthorizationGuard::from))
+            .transpose()
+    }
+
+    async fn create_rule(&self, rule: Allowed<ContentFilterRule, Create>) -> Result<(), Error> {
+        self.client
+            .insert_content_filter_rule(rule.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn delete_rule(&self, rule: Allowed<ContentFilterRule, Delete>) -> Result<(), Error> {
+        self.client
+            .delete_content_filter_rule(*rule.id())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn enforce(
+        &self,
+        enforcement: Allowed<ContentFilterEnforcement, Create>,
+    ) -> Result<(), Error> {
+        self.client
+            .record_content_filter_enforcement(enforcement.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn list_flags(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterFlag, Read>>, Error> {
+        self.client
+            .fetch_content_filter_flags(form_id)
+            .await?
+            .into_iter()
+            .map(|record| ContentFilterFlag::try_from(record).map(AuthorizationGuard::from))
+            .collect()
+    }
+
+    async fn get_flag(
+        &self,
+        id: ContentFilterFlagId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterFlag, Read>>, Error> {
+        self.client
+            .fetch_content_filter_flag(id)
+            .await?
+            .map(|record| ContentFilterFlag::try_from(record).map(AuthorizationGuard::from))
+            .transpose()
+    }
+
+    async fn delete_flag(&self, flag: Allowed<ContentFilterFlag, Delete>) -> Result<(), Error> {
+        self.client
+            .delete_content_filter_flag(*flag.id())
+            .await
+            .map_err(Into::into)
+    }
+}
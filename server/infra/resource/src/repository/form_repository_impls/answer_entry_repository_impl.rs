@@ -2,28 +2,54 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use domain::{
-    account::models::UserSnapshot,
+    account::models::{AccountUser, UserId, UserSnapshot},
     auth::Actor,
     form::{
         answer::{
-            AnswerEntry, AnswerId, AnswerPagePosition, AnswerStatus, AnswerStatusChange,
-            AnswerStatusHistoryEntry, AnswerStatusHistoryPagePosition, AnswerTitle,
-            AnswerTitleHistoryEntry, AnswerTitleHistoryPagePosition,
+            AnswerAssignmentHistoryEntry, AnswerAssignmentHistoryPagePosition, AnswerContentCount,
+            AnswerEntry, AnswerId, AnswerListFilter, AnswerMoveHistoryEntry,
+            AnswerMoveHistoryPagePosition, AnswerPagePosition, AnswerRelation, AnswerStatistics,
+            AnswerStatisticsScope, AnswerStatus, AnswerStatusChange, AnswerStatusHistoryEntry,
+            AnswerStatusHistoryPagePosition, AnswerTitle, AnswerTitleHistoryEntry,
+            AnswerTitleHistoryPagePosition, DailyAnswerCount, LabelAnswerCount, StatusAnswerCount,
         },
         models::ActiveForm,
+        temporary_answer_access::{TemporaryAnswerAccess, TemporaryAnswerAccessToken},
+        temporary_answer_claim::{TemporaryAnswerClaimCode, TemporaryAnswerClaimTicket},
     },
     pagination::{Page, PageRequest},
     repository::form::answer_entry_repository::AnswerEntryRepository,
     types::authorization_guard::{Allowed, Create, Read, Update},
 };
 use errors::{Error, infra::InfraError};
+use types::non_empty_string::NonEmptyString;
 use uuid::Uuid;
 
 use crate::{
-    database::components::{DatabaseComponents, FormAnswerDatabase, FormDatabase},
-    repository::Repository,
+    database::components::{
+        DatabaseComponents, FormAnswerDatabase, FormDatabase, TemporaryAnswerCredentials,
+    },
+    records::AnswerStatisticsRecord,
+    repository::{
+        Repository,
+        form_repository_impls::answer_relation_repository_impl::ensure_relation_matches_answers,
+        temporary_answer_access_repository_impl::token_hash,
+        temporary_answer_claim_repository_impl::code_hash,
+    },
 };
 
+fn updated_by(answer_entry: &Allowed<AnswerEntry, Update>) -> Result<&AccountUser, Error> {
+    match answer_entry.actor() {
+        Actor::AccountUser(user) => Ok(user),
+        Actor::TemporaryAnswerAuthor(_) | Actor::Anonymous | Actor::System => {
+            Err(InfraError::Unexpected {
+                cause: "answer update actor is not an account user".to_string(),
+            }
+            .into())
+        }
+    }
+}
+
 #[async_trait]
 impl<Client> AnswerEntryRepository for Repository<Client>
 where
@@ -85,7 +111,7 @@ where
         &self,
         form: &Allowed<ActiveForm, Read>,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error> {
         let mut scan_cursor = request.after_position().copied();
         let mut authorized_entries = Vec::new();
@@ -97,7 +123,7 @@ where
                 .list_answer_entries(
                     *form.id(),
                     PageRequest::new(scan_cursor, request.limit()),
-                    status,
+                    filter,
                 )
                 .await?;
             let (entries, next_raw) = page.into_parts();
@@ -111,7 +137,7 @@ where
                 return Ok(Page::from_overfetched_items(
                     authorized_entries,
                     request.limit(),
-                    |entry| AnswerPagePosition::new(*entry.timestamp(), *entry.id()),
+                    |entry| AnswerPagePosition::of(entry),
                 ));
             }
 
@@ -119,7 +145,7 @@ where
                 Some(_) if authorized_entries.len() == request.limit().value() as usize => {
                     let next = authorized_entries
                         .last()
-                        .map(|entry| AnswerPagePosition::new(*entry.timestamp(), *entry.id()));
+                        .map(|entry| AnswerPagePosition::of(entry));
                     return Ok(Page::new(authorized_entries, next));
                 }
                 Some(next_raw) => scan_cursor = Some(next_raw),
@@ -133,7 +159,7 @@ where
         &self,
         forms: &[Allowed<ActiveForm, Read>],
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error> {
         if forms.is_empty() {
             return Ok(Page::new(Vec::new(), None));
@@ -150,7 +176,7 @@ where
             let page = self
                 .client
                 .form()
-                .list_all_answer_entries(PageRequest::new(scan_cursor, request.limit()), status)
+                .list_all_answer_entries(PageRequest::new(scan_cursor, request.limit()), filter)
                 .await?;
             let (entries, next_raw) = page.into_parts();
             authorized_entries.extend(entries.into_iter().filter_map(|entry| {
@@ -163,7 +189,7 @@ where
                 return Ok(Page::from_overfetched_items(
                     authorized_entries,
                     request.limit(),
-                    |entry| AnswerPagePosition::new(*entry.timestamp(), *entry.id()),
+                    |entry| AnswerPagePosition::of(entry),
                 ));
             }
 
@@ -171,7 +197,7 @@ where
                 Some(_) if authorized_entries.len() == request.limit().value() as usize => {
                     let next = authorized_entries
                         .last()
-                        .map(|entry| AnswerPagePosition::new(*entry.timestamp(), *entry.id()));
+                        .map(|entry| AnswerPagePosition::of(entry));
                     return Ok(Page::new(authorized_entries, next));
                 }
                 Some(next_raw) => scan_cursor = Some(next_raw),
@@ -193,6 +219,33 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn post_temporary(
+        &self,
+        _form: &Allowed<ActiveForm, Read>,
+        answer_entry: &Allowed<AnswerEntry, Create>,
+        access: Allowed<TemporaryAnswerAccess, Create>,
+        access_token: &TemporaryAnswerAccessToken,
+        claim_ticket: Allowed<TemporaryAnswerClaimTicket, Create>,
+        claim_code: &TemporaryAnswerClaimCode,
+    ) -> Result<(), Error> {
+        let credentials = TemporaryAnswerCredentials {
+            access: access.into_inner(),
+            access_token_hash: token_hash(access_token),
+            claim_ticket: claim_ticket.into_inner(),
+            claim_code_hash: code_hash(claim_code),
+        };
+        self.client
+            .form_answer()
+            .post_answer_with_temporary_credentials(
+                answer_entry.value(),
+                *answer_entry.value().form_id(),
+                Some(credentials),
+            )
+            .await?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn update(
         &self,
@@ -205,15 +258,28 @@ where
             .update_answer_entry(
                 answer_entry.value(),
                 *answer_entry.value().form_id(),
-                match answer_entry.actor() {
-                    Actor::AccountUser(user) => user,
-                    Actor::TemporaryAnswerAuthor(_) | Actor::Anonymous | Actor::System => {
-                        return Err(InfraError::Unexpected {
-                            cause: "answer update actor is not an account user".to_string(),
-                        }
-                        .into());
-                    }
-                },
+                updated_by(answer_entry)?,
+            )
+            .await?;
+        Ok(status_change)
+    }
+
+    async fn update_with_relation(
+        &self,
+        _form: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+        relation: AnswerRelation,
+        related: &Allowed<AnswerEntry, Update>,
+    ) -> Result<Option<AnswerStatusChange>, Error> {
+        ensure_relation_matches_answers(relation, answer_entry, related)?;
+        let status_change = self
+            .client
+            .form_answer()
+            .update_answer_entry_with_relation(
+                answer_entry.value(),
+                *answer_entry.value().form_id(),
+                updated_by(answer_entry)?,
+                Some(relation),
             )
             .await?;
         Ok(status_change)
@@ -318,6 +384,212 @@ where
         Ok(Page::new(items, next))
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn assignment_history(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+        request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<
+        Page<Allowed<AnswerAssignmentHistoryEntry, Read>, AnswerAssignmentHistoryPagePosition>,
+        Error,
+    > {
+        let parse_assignee = |assignee_id: Option<String>| {
+            assignee_id
+                .map(|id| Uuid::parse_str(&id).map(UserId::from))
+                .transpose()
+                .map_err(InfraError::from)
+        };
+
+        let page = self
+            .client
+            .form_answer()
+            .fetch_assignment_history(*answer.id(), request)
+            .await?;
+        let (records, next) = page.into_parts();
+        let items = records
+            .into_iter()
+            .map(|record| {
+                let entry = unsafe {
+                    AnswerAssignmentHistoryEntry::from_raw_parts(
+                        Uuid::parse_str(&record.id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        Uuid::parse_str(&record.answer_id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        parse_assignee(record.from_assignee_id)?,
+                        parse_assignee(record.to_assignee_id)?,
+                        UserSnapshot::new(
+                            Uuid::parse_str(&record.changed_by_id)
+                                .map_err(InfraError::from)?
+                                .into(),
+                            record.changed_by_name,
+                            record.changed_by_role.parse().map_err(InfraError::from)?,
+                        ),
+                        record.changed_at,
+                    )
+                };
+                answer
+                    .authorize_assignment_history_entry(entry)
+                    .map_err(Error::from)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Page::new(items, next))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn move_to_form(
+        &self,
+        from: &Allowed<ActiveForm, Update>,
+        _to: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+    ) -> Result<(), Error> {
+        let moved_by = match answer_entry.actor() {
+            Actor::AccountUser(user) => user,
+            Actor::TemporaryAnswerAuthor(_) | Actor::Anonymous | Actor::System => {
+                return Err(InfraError::Unexpected {
+                    cause: "answer move actor is not an account user".to_string(),
+                }
+                .into());
+            }
+        };
+
+        self.client
+            .form_answer()
+            .move_answer_entry(answer_entry.value(), *from.value().id(), moved_by)
+            .await
+            .map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn move_history(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+        request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerMoveHistoryEntry, Read>, AnswerMoveHistoryPagePosition>, Error>
+    {
+        let page = self
+            .client
+            .form_answer()
+            .fetch_move_history(*answer.id(), request)
+            .await?;
+        let (records, next) = page.into_parts();
+        let items = records
+            .into_iter()
+            .map(|record| {
+                let entry = unsafe {
+                    AnswerMoveHistoryEntry::from_raw_parts(
+                        Uuid::parse_str(&record.id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        Uuid::parse_str(&record.answer_id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        Uuid::parse_str(&record.from_form_id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        Uuid::parse_str(&record.to_form_id)
+                            .map_err(InfraError::from)?
+                            .into(),
+                        UserSnapshot::new(
+                            Uuid::parse_str(&record.changed_by_id)
+                                .map_err(InfraError::from)?
+                                .into(),
+                            record.changed_by_name,
+                            record.changed_by_role.parse().map_err(InfraError::from)?,
+                        ),
+                        record.changed_at,
+                    )
+                };
+                answer
+                    .authorize_move_history_entry(entry)
+                    .map_err(Error::from)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Page::new(items, next))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn mark_overdue_notified(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+    ) -> Result<bool, Error> {
+        self.client
+            .form_answer()
+            .mark_overdue_notified(*answer.id())
+            .await
+            .map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn statistics(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        scope: AnswerStatisticsScope,
+    ) -> Result<AnswerStatistics, Error> {
+        let AnswerStatisticsRecord {
+            total,
+            contents,
+            daily,
+            statuses,
+            labels,
+        } = self
+            .client
+            .form_answer()
+            .aggregate_answers(*form.id(), scope == AnswerStatisticsScope::PublicOnly)
+            .await?;
+
+        let contents = contents
+            .into_iter()
+            .map(|record| {
+                Ok::<_, Error>(AnswerContentCount {
+                    question_id: Uuid::parse_str(&record.question_id)
+                        .map_err(InfraError::from)?
+                        .into(),
+                    answer: record.answer,
+                    count: record.count,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let daily = daily
+            .into_iter()
+            .map(|record| DailyAnswerCount {
+                date: record.date,
+                count: record.count,
+            })
+            .collect();
+        let statuses = statuses
+            .into_iter()
+            .map(|record| {
+                Ok::<_, Error>(StatusAnswerCount {
+                    status: record.status.parse().map_err(InfraError::from)?,
+                    count: record.count,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let labels = labels
+            .into_iter()
+            .map(|record| {
+                Ok::<_, Error>(LabelAnswerCount {
+                    label_id: Uuid::parse_str(&record.label_id)
+                        .map_err(InfraError::from)?
+                        .into(),
+                    name: NonEmptyString::try_new(record.name)?,
+                    count: record.count,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AnswerStatistics::new(
+            form.questions().as_slice(),
+            total,
+            contents,
+            daily,
+            statuses,
+            labels,
+        ))
+    }
+
     #[tracing::instrument(skip_all)]
     async fn size(&self) -> Result<u32, Error> {
         self.client.form_answer().size().await.map_err(Into::into)
@@ -17,7 +17,7 @@ use crate::{
     repository::Repository,
 };
 
-fn ensure_relation_matches_answers(
+pub(super) fn ensure_relation_matches_answers(
     relation: AnswerRelation,
     source: &Allowed<AnswerEntry, Update>,
     target: &Allowed<AnswerEntry, Update>,
@@ -147,7 +147,13 @@ where
         .await?;
     let mut relations = Vec::with_capacity(records.len());
     for record in records {
-        let relation = AnswerRelation::new(record.first, record.second).map_err(|error| {
+        let relation = AnswerRelation::from_normalized(
+            record.first,
+            record.second,
+            record.kind,
+            record.reversed,
+        )
+        .map_err(|error| {
             Error::from(DomainError::InvalidEntity {
                 message: error.to_string(),
             })
@@ -243,7 +249,13 @@ where
         else {
             return Ok(None);
         };
-        let relation = AnswerRelation::new(record.first, record.second).map_err(|error| {
+        let relation = AnswerRelation::from_normalized(
+            record.first,
+            record.second,
+            record.kind,
+            record.reversed,
+        )
+        .map_err(|error| {
             Error::from(DomainError::InvalidEntity {
                 message: error.to_string(),
             })
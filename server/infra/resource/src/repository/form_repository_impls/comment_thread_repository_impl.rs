@@ -20,6 +20,7 @@ use uuid::Uuid;
 
 use crate::{
     database::components::{DatabaseComponents, FormCommentDatabase},
+    records::comment_visibility,
     repository::Repository,
 };
 
@@ -103,7 +104,7 @@ where
             .get_history(
                 *comment_thread.answer_id(),
                 request,
-                comment_thread.can_read_deleted_comment_history(),
+                comment_thread.can_read_staff_only_comment_history(),
             )
             .await?;
         let (records, next) = page.into_parts();
@@ -142,6 +143,7 @@ where
                                 .map_err(InfraError::from)?,
                         ),
                         record.operated_at,
+                        comment_visibility(record.is_internal),
                     )
                 };
                 comment_thread
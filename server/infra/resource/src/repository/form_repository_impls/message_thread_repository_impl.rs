@@ -8,18 +8,20 @@ use domain::{
             DeletedMessage, Message, MessageBody, MessageHistoryAction, MessageHistoryEntry,
             MessageHistoryPagePosition, MessagePost,
         },
-        message_thread::MessageThread,
+        message_thread::{MessageThread, MessageThreadReopening},
+        models::ActiveForm,
     },
     pagination::{Page, PageRequest},
     repository::form::message_thread_repository::MessageThreadRepository,
     types::authorization_guard::{Allowed, Create, Read, Update},
 };
 use errors::{Error, infra::InfraError};
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 use uuid::Uuid;
 
 use crate::{
     database::components::{DatabaseComponents, FormMessageDatabase},
+    records::{MessageAttachmentRecord, MessageWithAttachmentsRecord},
     repository::Repository,
 };
 
@@ -31,29 +33,52 @@ where
     #[tracing::instrument(skip_all)]
     async fn get_for_answer(
         &self,
+        form: &Allowed<ActiveForm, Read>,
         answer: &Allowed<AnswerEntry, Read>,
     ) -> Result<Allowed<MessageThread, Read>, Error> {
-        let messages = self
-            .client
-            .form_message()
+        let form_message = self.client.form_message();
+        let mut attachments = attachment_records_by_message_id(
+            form_message
+                .fetch_message_attachments_by_answer_id(*answer.id())
+                .await?,
+        );
+        let messages = form_message
             .fetch_messages_by_answer_id(*answer.id())
             .await?
             .into_iter()
-            .map(TryInto::try_into)
+            .map(|message| {
+                let attachments = attachments.remove(&message.id).unwrap_or_default();
+                MessageWithAttachmentsRecord {
+                    message,
+                    attachments,
+                }
+                .try_into()
+            })
             .collect::<Result<Vec<_>, _>>()?;
+        let reopened = form_message
+            .is_message_thread_reopened(*answer.id())
+            .await?;
+        let temporary_access_active = answer.author().temporary_user().is_some()
+            && form_message
+                .has_active_temporary_answer_access(*answer.id())
+                .await?;
 
-        answer.message_thread(messages).map_err(Error::from)
+        form.message_thread(answer, messages, reopened, temporary_access_active)
+            .map_err(Error::from)
     }
 
     #[tracing::instrument(skip_all)]
     async fn append(&self, post: Allowed<MessagePost, Create>) -> Result<(), Error> {
-        let operated_by = account_user_snapshot(post.actor())?;
+        let operated_by = match post.actor() {
+            domain::auth::Actor::TemporaryAnswerAuthor(_) => None,
+            actor => Some(account_user_snapshot(actor)?),
+        };
         let post = post.into_inner();
         let answer_id = *post.answer_id();
         let message = post.into_message();
         self.client
             .form_message()
-            .post_message(&message, answer_id, &operated_by)
+            .post_message(&message, answer_id, operated_by.as_ref())
             .await?;
         Ok(())
     }
@@ -79,6 +104,17 @@ where
         Ok(())
     }
 
+    async fn reopen(
+        &self,
+        reopening: Allowed<MessageThreadReopening, Create>,
+    ) -> Result<(), Error> {
+        self.client
+            .form_message()
+            .insert_message_thread_reopening(reopening.value())
+            .await?;
+        Ok(())
+    }
+
     async fn history(
         &self,
         message_thread: &Allowed<MessageThread, Read>,
@@ -94,10 +130,26 @@ where
             )
             .await?;
         let (records, next) = page.into_parts();
+        let attachments = if records.is_empty() {
+            HashMap::new()
+        } else {
+            attachment_records_by_message_id(
+                self.client
+                    .form_message()
+                    .fetch_message_attachments_by_answer_id(*message_thread.answer_id())
+                    .await?,
+            )
+        };
         let items = records
             .into_iter()
             .map(|record| {
                 let action = message_history_action(record.action.as_str())?;
+                let history_attachments = attachments
+                    .get(&record.message_id)
+                    .into_iter()
+                    .flatten()
+                    .map(|attachment| attachment.clone().try_into())
+                    .collect::<Result<Vec<_>, Error>>()?;
                 let history_entry = unsafe {
                     MessageHistoryEntry::from_raw_parts(
                         Uuid::parse_str(&record.id)
@@ -129,6 +181,7 @@ where
                                 .map_err(InfraError::from)?,
                         ),
                         record.operated_at,
+                        history_attachments,
                     )
                 };
                 message_thread
@@ -140,6 +193,20 @@ where
     }
 }
 
+fn attachment_records_by_message_id(
+    records: Vec<MessageAttachmentRecord>,
+) -> HashMap<String, Vec<MessageAttachmentRecord>> {
+    records
+        .into_iter()
+        .fold(HashMap::new(), |mut grouped, record| {
+            grouped
+                .entry(record.message_id.clone())
+                .or_insert_with(Vec::new)
+                .push(record);
+            grouped
+        })
+}
+
 fn message_history_action(action: &str) -> Result<MessageHistoryAction, InfraError> {
     match action {
         "CREATE" => Ok(MessageHistoryAction::Create),
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use domain::{
+    form::mention::Mention,
+    repository::form::mention_repository::MentionRepository,
+    types::authorization_guard::{Allowed, Create},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl MentionRepository for Repository<ConnectionPool> {
+    async fn create(&self, mentions: Vec<Allowed<Mention, Create>>) -> Result<(), Error> {
+        let mentions = mentions
+            .into_iter()
+            .map(Allowed::into_inner)
+            .collect::<Vec<_>>();
+
+        self.client
+            .insert_mentions(&mentions)
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use domain::{
+    form::{
+        message_read::{MessageReadReceipt, UnreadMessageCountTarget},
+        message_thread::MessageThread,
+    },
+    repository::form::message_read_repository::MessageReadRepository,
+    types::authorization_guard::{Allowed, Create, Read},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl MessageReadRepository for Repository<ConnectionPool> {
+    async fn mark_as_read(
+        &self,
+        receipt: Allowed<MessageReadReceipt, Create>,
+    ) -> Result<(), Error> {
+        self.client
+            .upsert_message_read_receipt(receipt.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn receipts(
+        &self,
+        message_thread: &Allowed<MessageThread, Read>,
+    ) -> Result<Vec<Allowed<MessageReadReceipt, Read>>, Error> {
+        self.client
+            .fetch_message_read_receipts(message_thread.answer_id().to_string())
+            .await?
+            .into_iter()
+            .map(|record| {
+                message_thread
+                    .authorize_read_receipt(MessageReadReceipt::try_from(record)?)
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
+
+    async fn unread_message_counts(
+        &self,
+        targets: &[UnreadMessageCountTarget],
+    ) -> Result<HashMap<UnreadMessageCountTarget, u32>, Error> {
+        self.client
+            .count_unread_messages(targets)
+            .await
+            .map_err(Into::into)
+    }
+}
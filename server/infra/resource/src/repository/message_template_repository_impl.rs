@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use domain::{
+    form::message_template::{MessageTemplate, MessageTemplateId},
+    repository::form::message_template_repository::MessageTemplateRepository,
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read, Update},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl MessageTemplateRepository for Repository<ConnectionPool> {
+    async fn create(&self, template: Allowed<MessageTemplate, Create>) -> Result<(), Error> {
+        self.client
+            .insert_message_template(template.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<MessageTemplate, Read>>, Error> {
+        self.client
+            .fetch_message_templates()
+            .await?
+            .into_iter()
+            .map(|record| MessageTemplate::try_from(record).map(Into::into))
+            .collect()
+    }
+
+    async fn get(
+        &self,
+        id: MessageTemplateId,
+    ) -> Result<Option<AuthorizationGuard<MessageTemplate, Read>>, Error> {
+        self.client
+            .fetch_message_template(id)
+            .await?
+            .map(|record| MessageTemplate::try_from(record).map(Into::into))
+            .transpose()
+    }
+
+    async fn update(&self, template: Allowed<MessageTemplate, Update>) -> Result<(), Error> {
+        self.client
+            .update_message_template(template.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn delete(&self, template: Allowed<MessageTemplate, Delete>) -> Result<(), Error> {
+        self.client
+            .delete_message_template(*template.id())
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -4,11 +4,15 @@ use crate::{
 };
 use async_trait::async_trait;
 use domain::{
-    form::{answer::AnswerStatus, models::FormId},
+    form::{
+        answer::{AnswerId, AnswerStatus},
+        models::FormId,
+    },
     repository::search_repository::SearchRepository,
     search::models::{
-        AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit, FormLabelSearchHit, FormSearchHit,
-        NumberOfRecordsPerAggregate, SearchableFieldsWithOperation, UserSearchHit,
+        AnnouncementSearchHit, AnswerLabelSearchHit, AnswerSearchHit, CommentSearchHit,
+        FormLabelSearchHit, FormSearchHit, NumberOfRecordsPerAggregate,
+        SearchableFieldsWithOperation, SimilarAnswerLimit, UserSearchHit,
     },
 };
 use errors::Error;
@@ -63,6 +67,20 @@ impl<Client: DatabaseComponents + 'static> SearchRepository for Repository<Clien
             .map_err(Into::into)
     }
 
+    async fn search_similar_answers(
+        &self,
+        query: &str,
+        form_id: FormId,
+        exclude: AnswerId,
+        limit: SimilarAnswerLimit,
+    ) -> Result<Vec<AnswerSearchHit>, Error> {
+        self.client
+            .search()
+            .search_similar_answers(query, form_id, exclude, limit)
+            .await
+            .map_err(Into::into)
+    }
+
     async fn search_comments(&self, query: &str) -> Result<Vec<CommentSearchHit>, Error> {
         self.client
             .search()
@@ -71,6 +89,14 @@ impl<Client: DatabaseComponents + 'static> SearchRepository for Repository<Clien
             .map_err(Into::into)
     }
 
+    async fn search_announcements(&self, query: &str) -> Result<Vec<AnnouncementSearchHit>, Error> {
+        self.client
+            .search()
+            .search_announcements(query)
+            .await
+            .map_err(Into::into)
+    }
+
     async fn sync_search_engine(
         &self,
         data: &[SearchableFieldsWithOperation],
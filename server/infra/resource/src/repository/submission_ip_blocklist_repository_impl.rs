@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use domain::{
+    repository::submission_ip_blocklist_repository::SubmissionIpBlocklistRepository,
+    submission_ip_blocklist::{SubmissionIpBlock, SubmissionIpBlockId},
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read},
+};
+use errors::Error;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl SubmissionIpBlocklistRepository for Repository<ConnectionPool> {
+    async fn list(&self) -> Result<Vec<AuthorizationGuard<SubmissionIpBlock, Read>>, Error> {
+        self.client
+            .fetch_submission_ip_blocklist()
+            .await?
+            .into_iter()
+            .map(|record| SubmissionIpBlock::try_from(record).map(AuthorizationGuard::from))
+            .collect()
+    }
+
+    async fn get(
+        &self,
+        id: SubmissionIpBlockId,
+    ) -> Result<Option<AuthorizationGuard<SubmissionIpBlock, Read>>, Error> {
+        self.client
+            .fetch_submission_ip_block(id)
+            .await?
+            .map(|record| SubmissionIpBlock::try_from(record).map(AuthorizationGuard::from))
+            .transpose()
+    }
+
+    async fn create(&self, block: Allowed<SubmissionIpBlock, Create>) -> Result<(), Error> {
+        self.client
+            .insert_submission_ip_block(block.value())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn delete(&self, block: Allowed<SubmissionIpBlock, Delete>) -> Result<(), Error> {
+        self.client
+            .delete_submission_ip_block(*block.id())
+            .await
+            .map_err(Into::into)
+    }
+}
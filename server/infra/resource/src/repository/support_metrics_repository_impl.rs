@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use domain::{
+    repository::support_metrics_repository::SupportMetricsRepository,
+    support_metrics::{OpenAnswerCount, StaffActivity, SupportMetrics, SupportMetricsPeriod},
+    types::authorization_guard::{AuthorizationGuard, Read},
+};
+use errors::{Error, infra::InfraError};
+use uuid::Uuid;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+#[async_trait]
+impl SupportMetricsRepository for Repository<ConnectionPool> {
+    async fn fetch(
+        &self,
+        period: SupportMetricsPeriod,
+    ) -> Result<AuthorizationGuard<SupportMetrics, Read>, Error> {
+        let record = self
+            .client
+            .aggregate_support_metrics(*period.from(), *period.to())
+            .await?;
+
+        let open_answers = record
+            .open_answers
+            .into_iter()
+            .map(|record| {
+                Ok::<_, Error>(OpenAnswerCount {
+                    form_id: Uuid::parse_str(&record.form_id)
+                        .map_err(InfraError::from)?
+                        .into(),
+                    form_title: record.form_title,
+                    count: record.count,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let staff_activities = record
+            .staff_activities
+            .into_iter()
+            .map(|record| {
+                Ok::<_, Error>(StaffActivity {
+                    user_id: Uuid::parse_str(&record.user_id)
+                        .map_err(InfraError::from)?
+                        .into(),
+                    name: record.name,
+                    handled_answers: record.handled_answers,
+                    completed_answers: record.completed_answers,
+                    messages: record.messages,
+                    comments: record.comments,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SupportMetrics::new(
+            period,
+            record
+                .first_response_seconds
+                .into_iter()
+                .map(TimeDelta::seconds)
+                .collect(),
+            record
+                .completion_seconds
+                .into_iter()
+                .map(TimeDelta::seconds)
+                .collect(),
+            open_answers,
+            staff_activities,
+        )
+        .into())
+    }
+}
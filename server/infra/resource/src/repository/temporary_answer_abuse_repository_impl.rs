@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use domain::{
+    form::{
+        answer::AnswerId,
+        temporary_answer_abuse::{TemporaryAnswerAbuseMetadata, TemporaryAnswerSubmissionRecord},
+    },
+    repository::form::temporary_answer_abuse_repository::TemporaryAnswerAbuseRepository,
+    types::authorization_guard::{Allowed, Create},
+};
+use errors::Error;
+use sha256::digest;
+
+use crate::{
+    database::{config::ABUSE_METADATA, connection::ConnectionPool},
+    repository::Repository,
+};
+
+/// 送信元の平文は保存せず、salt 付きのハッシュだけを保存する。
+///
+/// 同じ送信元の回答を見つけられるよう、同じ salt と値からは常に同じハッシュになる。
+fn salted_hash(salt: &str, kind: &str, value: &str) -> String {
+    digest(format!("{salt}\0{kind}\0{value}"))
+}
+
+#[async_trait]
+impl TemporaryAnswerAbuseRepository for Repository<ConnectionPool> {
+    async fn record(
+        &self,
+        record: Allowed<TemporaryAnswerSubmissionRecord, Create>,
+    ) -> Result<(), Error> {
+        // IPv4 アドレスは総当たりで戻せてしまうため、salt がなければ何も記録しない
+        let Some(salt) = ABUSE_METADATA
+            .hash_salt
+            .as_deref()
+            .filter(|salt| !salt.is_empty())
+        else {
+            tracing::warn!(
+                "ABUSE_METADATA_HASH_SALT is not set; temporary answer source was not recorded"
+            );
+            return Ok(());
+        };
+        let source = record.source();
+
+        self.client
+            .insert_temporary_answer_submission_source(
+                *record.answer_id(),
+                source
+                    .client_ip()
+                    .map(|ip| salted_hash(salt, "ip", &ip.to_string())),
+                source
+                    .user_agent()
+                    .map(|user_agent| salted_hash(salt, "user-agent", user_agent)),
+                *record.recorded_at(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn find_by_answer_id(
+        &self,
+        answer_id: AnswerId,
+    ) -> Result<Option<TemporaryAnswerAbuseMetadata>, Error> {
+        self.client
+            .fetch_temporary_answer_submission_source(answer_id)
+            .await?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_depends_on_the_salt_and_the_kind_of_value() {
+        let hash = salted_hash("salt", "ip", "203.0.113.5");
+
+        assert_eq!(hash, salted_hash("salt", "ip", "203.0.113.5"));
+        assert_ne!(hash, salted_hash("another salt", "ip", "203.0.113.5"));
+        assert_ne!(hash, salted_hash("salt", "user-agent", "203.0.113.5"));
+        assert!(!hash.contains("203.0.113.5"));
+    }
+}
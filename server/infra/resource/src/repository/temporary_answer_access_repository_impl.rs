@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use domain::{
+    form::temporary_answer_access::{
+        TemporaryAnswerAccess, TemporaryAnswerAccessRevocation, TemporaryAnswerAccessToken,
+    },
+    repository::form::temporary_answer_access_repository::TemporaryAnswerAccessRepository,
+    types::authorization_guard::{Allowed, Update},
+};
+use errors::Error;
+use sha256::digest;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+/// トークンの平文は保存せず、ハッシュだけで照合する。
+pub(crate) fn token_hash(token: &TemporaryAnswerAccessToken) -> String {
+    digest(token.expose_secret())
+}
+
+#[async_trait]
+impl TemporaryAnswerAccessRepository for Repository<ConnectionPool> {
+    async fn find_by_token(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+    ) -> Result<Option<TemporaryAnswerAccess>, Error> {
+        self.client
+            .fetch_temporary_answer_access(token_hash(token))
+            .await?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+
+    async fn revoke(
+        &self,
+        revocation: Allowed<TemporaryAnswerAccessRevocation, Update>,
+    ) -> Result<(), Error> {
+        self.client
+            .revoke_temporary_answer_accesses(revocation.value())
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use domain::{
+    form::temporary_answer_claim::{
+        TemporaryAnswerClaim, TemporaryAnswerClaimCode, TemporaryAnswerClaimTicket,
+    },
+    repository::form::temporary_answer_claim_repository::TemporaryAnswerClaimRepository,
+    types::authorization_guard::{Allowed, Create},
+};
+use errors::Error;
+use sha256::digest;
+
+use crate::{database::connection::ConnectionPool, repository::Repository};
+
+/// コードの平文は保存せず、ハッシュだけで照合する。
+pub(crate) fn code_hash(code: &TemporaryAnswerClaimCode) -> String {
+    digest(code.expose_secret())
+}
+
+#[async_trait]
+impl TemporaryAnswerClaimRepository for Repository<ConnectionPool> {
+    async fn find_by_code(
+        &self,
+        code: &TemporaryAnswerClaimCode,
+    ) -> Result<Option<TemporaryAnswerClaimTicket>, Error> {
+        self.client
+            .fetch_temporary_answer_claim_code(code_hash(code))
+            .await?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+
+    async fn claim(&self, claim: Allowed<TemporaryAnswerClaim, Create>) -> Result<bool, Error> {
+        self.client
+            .claim_temporary_answer(claim.value())
+            .await
+            .map_err(Into::into)
+    }
+}
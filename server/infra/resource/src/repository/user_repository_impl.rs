@@ -43,6 +43,20 @@ impl<Client: DatabaseComponents + 'static> UserRepository for Repository<Client>
             .collect_vec())
     }
 
+    async fn find_by_names(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Vec<AuthorizationGuard<AccountUser, Read>>, Error> {
+        Ok(self
+            .client
+            .user()
+            .find_by_names(names)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect_vec())
+    }
+
     async fn upsert_user(&self, user: Allowed<AccountUser, Create>) -> Result<(), Error> {
         self.client
             .user()
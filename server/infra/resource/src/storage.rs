@@ -0,0 +1,105 @@
+mod config;
+pub mod local;
+pub mod s3;
+
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
+use domain::{
+    form::message::MessageAttachment,
+    repository::form::message_attachment_storage::MessageAttachmentStorage,
+};
+use errors::{Error, infra::InfraError};
+
+use crate::storage::{
+    config::{ATTACHMENT_STORAGE, AttachmentStorage, AttachmentStorageBackend},
+    local::LocalDiskStorage,
+    s3::S3CompatibleStorage,
+};
+
+/// 環境変数 `ATTACHMENT_STORAGE_BACKEND` で選択された添付ファイルのストレージ。
+pub enum ConfiguredAttachmentStorage {
+    Local(LocalDiskStorage),
+    S3(S3CompatibleStorage),
+}
+
+impl ConfiguredAttachmentStorage {
+    fn try_from_config(config: &AttachmentStorage) -> Result<Self, InfraError> {
+        match config.backend {
+            AttachmentStorageBackend::Local => {
+                Ok(Self::Local(LocalDiskStorage::new(&config.local_directory)))
+            }
+            AttachmentStorageBackend::S3 => {
+                let required = |value: &Option<String>, name: &str| {
+                    value.clone().ok_or_else(|| InfraError::Storage {
+                        cause: format!("ATTACHMENT_STORAGE_{name} must be set"),
+                    })
+                };
+
+                Ok(Self::S3(S3CompatibleStorage::new(
+                    required(&config.s3_endpoint, "S3_ENDPOINT")?,
+                    required(&config.s3_bucket, "S3_BUCKET")?,
+                    config.s3_region.to_owned(),
+                    required(&config.s3_access_key_id, "S3_ACCESS_KEY_ID")?,
+                    required(&config.s3_secret_access_key, "S3_SECRET_ACCESS_KEY")?,
+                )))
+            }
+        }
+    }
+}
+
+/// 添付ファイルのストレージ設定を検証し、[`MESSAGE_ATTACHMENT_STORAGE`] を初期化します。
+///
+/// 設定の不備が最初の添付ファイルの保存時に初めて分からないよう、起動時に呼び出します。
+pub fn validate_attachment_storage_config() -> Result<(), InfraError> {
+    ConfiguredAttachmentStorage::try_from_config(&ATTACHMENT_STORAGE)?;
+    LazyLock::force(&MESSAGE_ATTACHMENT_STORAGE);
+
+    Ok(())
+}
+
+pub static MESSAGE_ATTACHMENT_STORAGE: LazyLock<ConfiguredAttachmentStorage> =
+    LazyLock::new(|| {
+        ConfiguredAttachmentStorage::try_from_config(&ATTACHMENT_STORAGE)
+            .expect("attachment storage config is validated at startup")
+    });
+
+fn object_key(attachment: &MessageAttachment) -> String {
+    format!("message-attachments/{}", attachment.id())
+}
+
+#[async_trait]
+impl MessageAttachmentStorage for ConfiguredAttachmentStorage {
+    #[tracing::instrument(skip_all)]
+    async fn put(&self, attachment: &MessageAttachment, content: Vec<u8>) -> Result<(), Error> {
+        let key = object_key(attachment);
+        match self {
+            Self::Local(storage) => storage.put_object(&key, content).await?,
+            Self::S3(storage) => storage.put_object(&key, content).await?,
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn get(&self, attachment: &MessageAttachment) -> Result<Vec<u8>, Error> {
+        let key = object_key(attachment);
+        let content = match self {
+            Self::Local(storage) => storage.get_object(&key).await?,
+            Self::S3(storage) => storage.get_object(&key).await?,
+        };
+
+        Ok(content)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn delete(&self, attachment: &MessageAttachment) -> Result<(), Error> {
+        let key = object_key(attachment);
+        match self {
+            Self::Local(storage) => storage.delete_object(&key).await?,
+            Self::S3(storage) => storage.delete_object(&key).await?,
+        }
+
+        Ok(())
+    }
+}
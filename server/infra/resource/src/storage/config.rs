@@ -0,0 +1,39 @@
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AttachmentStorageBackend {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AttachmentStorage {
+    #[serde(default)]
+    pub backend: AttachmentStorageBackend,
+    #[serde(default = "default_local_directory")]
+    pub local_directory: String,
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    #[serde(default = "default_s3_region")]
+    pub s3_region: String,
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+}
+
+fn default_local_directory() -> String {
+    "attachments".to_string()
+}
+
+fn default_s3_region() -> String {
+    "us-east-1".to_string()
+}
+
+pub static ATTACHMENT_STORAGE: LazyLock<AttachmentStorage> = LazyLock::new(|| {
+    envy::prefixed("ATTACHMENT_STORAGE_")
+        .from_env::<AttachmentStorage>()
+        .unwrap()
+});
@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use errors::infra::InfraError;
+
+/// ローカルディスク上のディレクトリにオブジェクトを保存するストレージ。
+pub struct LocalDiskStorage {
+    root: PathBuf,
+}
+
+impl LocalDiskStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub(super) async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), InfraError> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|err| storage_error(key, err))?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|err| storage_error(key, err))
+    }
+
+    pub(super) async fn get_object(&self, key: &str) -> Result<Vec<u8>, InfraError> {
+        tokio::fs::read(self.root.join(key))
+            .await
+            .map_err(|err| storage_error(key, err))
+    }
+
+    pub(super) async fn delete_object(&self, key: &str) -> Result<(), InfraError> {
+        match tokio::fs::remove_file(self.root.join(key)).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(storage_error(key, err)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn storage_error(key: &str, err: std::io::Error) -> InfraError {
+    InfraError::Storage {
+        cause: format!("local storage object {key}: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stored_object_can_be_read_back() {
+        let root = std::env::temp_dir().join(format!("seichi-portal-{}", uuid::Uuid::now_v7()));
+        let storage = LocalDiskStorage::new(&root);
+
+        storage
+            .put_object("message-attachments/object", b"evidence".to_vec())
+            .await
+            .unwrap();
+        let content = storage
+            .get_object("message-attachments/object")
+            .await
+            .unwrap();
+        let missing = storage.get_object("message-attachments/missing").await;
+
+        assert_eq!(content, b"evidence");
+        assert!(matches!(missing, Err(InfraError::Storage { .. })));
+
+        storage
+            .delete_object("message-attachments/object")
+            .await
+            .unwrap();
+        storage
+            .delete_object("message-attachments/object")
+            .await
+            .unwrap();
+        assert!(
+            storage
+                .get_object("message-attachments/object")
+                .await
+                .is_err()
+        );
+
+        tokio::fs::remove_dir_all(root).await.unwrap();
+    }
+}
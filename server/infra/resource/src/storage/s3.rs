@@ -0,0 +1,278 @@
+use chrono::{DateTime, Utc};
+use errors::infra::InfraError;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::outgoing::http::HTTP_CLIENT;
+
+const SIGNED_HEADERS: &str = "host;x-amz-content-sha256;x-amz-date";
+
+/// S3 互換 API (path-style) でオブジェクトを保存するストレージ。
+///
+/// リクエストは AWS Signature Version 4 で署名する。
+/// エンドポイントにパスが含まれる場合は、バケット名の前に付けてリクエストする。
+pub struct S3CompatibleStorage {
+    /// スキームとホスト (`http://localhost:9000` など)
+    origin: String,
+    /// エンドポイントのパス。末尾の `/` は含まない。
+    base_path: String,
+    bucket: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+}
+
+impl S3CompatibleStorage {
+    pub fn new(
+        endpoint: String,
+        bucket: String,
+        region: String,
+        access_key_id: String,
+        secret_access_key: String,
+    ) -> Self {
+        let endpoint = endpoint.trim_end_matches('/');
+        let host_start = endpoint
+            .find("://")
+            .map_or(0, |scheme_end| scheme_end + "://".len());
+        let path_start = endpoint[host_start..]
+            .find('/')
+            .map_or(endpoint.len(), |host_end| host_start + host_end);
+        let (origin, base_path) = endpoint.split_at(path_start);
+
+        Self {
+            origin: origin.to_owned(),
+            base_path: base_path.to_owned(),
+            bucket,
+            region,
+            access_key_id,
+            secret_access_key,
+        }
+    }
+
+    pub(super) async fn put_object(&self, key: &str, content: Vec<u8>) -> Result<(), InfraError> {
+        let path = self.object_path(key);
+        let payload_hash = sha256::digest(content.as_slice());
+        let now = Utc::now();
+        let response = HTTP_CLIENT
+            .put(format!("{}{path}", self.origin))
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("x-amz-date", amz_date(now))
+            .header(
+                "authorization",
+                self.authorization("PUT", &path, &payload_hash, now),
+            )
+            .body(content)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(InfraError::Storage {
+                cause: format!("S3 PUT {key} returned {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn get_object(&self, key: &str) -> Result<Vec<u8>, InfraError> {
+        let path = self.object_path(key);
+        let payload_hash = sha256::digest("");
+        let now = Utc::now();
+        let response = HTTP_CLIENT
+            .get(format!("{}{path}", self.origin))
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("x-amz-date", amz_date(now))
+            .header(
+                "authorization",
+                self.authorization("GET", &path, &payload_hash, now),
+            )
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(InfraError::Storage {
+                cause: format!("S3 GET {key} returned {}", response.status()),
+            });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub(super) async fn delete_object(&self, key: &str) -> Result<(), InfraError> {
+        let path = self.object_path(key);
+        let payload_hash = sha256::digest("");
+        let now = Utc::now();
+        let response = HTTP_CLIENT
+            .delete(format!("{}{path}", self.origin))
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("x-amz-date", amz_date(now))
+            .header(
+                "authorization",
+                self.authorization("DELETE", &path, &payload_hash, now),
+            )
+            .send()
+            .await?;
+
+        // S3 は存在しないオブジェクトの削除にも 204 を返すが、互換実装の 404 も成功として扱う
+        if !response.status().is_success() && response.status() != reqwest::StatusCode::NOT_FOUND {
+            return Err(InfraError::Storage {
+                cause: format!("S3 DELETE {key} returned {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn object_path(&self, key: &str) -> String {
+        format!("{}/{}/{key}", self.base_path, self.bucket)
+    }
+
+    fn host(&self) -> &str {
+        self.origin
+            .split_once("://")
+            .map_or(self.origin.as_str(), |(_, host)| host)
+    }
+
+    fn authorization(
+        &self,
+        method: &str,
+        path: &str,
+        payload_hash: &str,
+        now: DateTime<Utc>,
+    ) -> String {
+        let amz_date = amz_date(now);
+        let date = now.format("%Y%m%d").to_string();
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let canonical_request = format!(
+            "{method}\n{path}\n\nhost:{}\nx-amz-content-sha256:{payload_hash}\nx-amz-date:{amz_date}\n\n{SIGNED_HEADERS}\n{payload_hash}",
+            self.host()
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            sha256::digest(canonical_request)
+        );
+        let signing_key = signing_key(&self.secret_access_key, &date, &self.region, "s3");
+        let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={SIGNED_HEADERS}, Signature={signature}",
+            self.access_key_id
+        )
+    }
+}
+
+fn amz_date(now: DateTime<Utc>) -> String {
+    now.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> [u8; 32] {
+    let date_key = hmac_sha256(
+        format!("AWS4{secret_access_key}").as_bytes(),
+        date.as_bytes(),
+    );
+    let region_key = hmac_sha256(&date_key, region.as_bytes());
+    let service_key = hmac_sha256(&region_key, service.as_bytes());
+
+    hmac_sha256(&service_key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+
+    mac.finalize().into_bytes().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn hmac_sha256_matches_rfc4231_test_case() {
+        assert_eq!(
+            hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn signing_key_matches_aws_documented_example() {
+        assert_eq!(
+            hex(&signing_key(
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                "20120215",
+                "us-east-1",
+                "iam"
+            )),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn authorization_header_scopes_credential_to_date_and_region() {
+        let storage = S3CompatibleStorage::new(
+            "http://localhost:9000/".to_string(),
+            "attachments".to_string(),
+            "ap-northeast-1".to_string(),
+            "access-key".to_string(),
+            "secret-key".to_string(),
+        );
+        let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+
+        let authorization = storage.authorization(
+            "GET",
+            &storage.object_path("message-attachments/object"),
+            &sha256::digest(""),
+            now,
+        );
+
+        assert_eq!(storage.host(), "localhost:9000");
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=access-key/20261018/ap-northeast-1/s3/aws4_request, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature="
+        ));
+    }
+
+    #[test]
+    fn endpoint_path_is_kept_in_the_signed_object_path() {
+        let storage = |endpoint: &str| {
+            S3CompatibleStorage::new(
+                endpoint.to_string(),
+                "attachments".to_string(),
+                "ap-northeast-1".to_string(),
+                "access-key".to_string(),
+                "secret-key".to_string(),
+            )
+        };
+        let behind_proxy = storage("https://storage.example.com/s3/");
+        let at_root = storage("https://storage.example.com");
+        let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        let path = behind_proxy.object_path("message-attachments/object");
+
+        assert_eq!(path, "/s3/attachments/message-attachments/object");
+        assert_eq!(behind_proxy.origin, "https://storage.example.com");
+        assert_eq!(behind_proxy.host(), "storage.example.com");
+        assert_eq!(
+            behind_proxy.authorization("GET", &path, &sha256::digest(""), now),
+            at_root.authorization(
+                "GET",
+                "/s3/attachments/message-attachments/object",
+                &sha256::digest(""),
+                now
+            )
+        );
+        assert_ne!(
+            behind_proxy.authorization("GET", &path, &sha256::digest(""), now),
+            at_root.authorization(
+                "GET",
+                &at_root.object_path("message-attachments/object"),
+                &sha256::digest(""),
+                now
+            )
+        );
+    }
+}
@@ -0,0 +1,21 @@
+-- 回答は archive 時に answers と archived_answers の間を移動するため、
+-- 担当者と割り当て履歴は answers への外部キーを持たず、回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS answer_assignees(
+    answer_id CHAR(36) NOT NULL PRIMARY KEY,
+    assignee_id CHAR(36) NOT NULL,
+    assigned_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_answer_assignees_assignee_id(assignee_id),
+    FOREIGN KEY fk_answer_assignees_assignee_id(assignee_id) REFERENCES users(id)
+);
+
+CREATE TABLE IF NOT EXISTS form_answer_assignment_history(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    answer_id CHAR(36) NOT NULL,
+    from_assignee_id CHAR(36),
+    to_assignee_id CHAR(36),
+    changed_by_id CHAR(36) NOT NULL,
+    changed_by_name TEXT NOT NULL,
+    changed_by_role VARCHAR(32) NOT NULL,
+    changed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_form_answer_assignment_history_answer_id_id(answer_id, id)
+);
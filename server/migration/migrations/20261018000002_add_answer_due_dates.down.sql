@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS answer_due_dates;
+
+ALTER TABLE archived_form_meta_data DROP COLUMN response_deadline_hours;
+ALTER TABLE form_meta_data DROP COLUMN response_deadline_hours;
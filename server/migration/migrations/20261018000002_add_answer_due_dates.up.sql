@@ -0,0 +1,10 @@
+ALTER TABLE form_meta_data ADD COLUMN response_deadline_hours INT UNSIGNED AFTER default_answer_title;
+ALTER TABLE archived_form_meta_data ADD COLUMN response_deadline_hours INT UNSIGNED AFTER default_answer_title;
+
+-- 担当者と同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS answer_due_dates(
+    answer_id CHAR(36) NOT NULL PRIMARY KEY,
+    due_at TIMESTAMP NOT NULL,
+    overdue_notified_at TIMESTAMP NULL,
+    INDEX idx_answer_due_dates_due_at(due_at)
+);
@@ -0,0 +1,3 @@
+ALTER TABLE answer_relations
+    DROP COLUMN reversed,
+    DROP COLUMN kind;
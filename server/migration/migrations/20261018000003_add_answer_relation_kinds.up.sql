@@ -0,0 +1,5 @@
+-- 端点の正規化と主キーは変えず、同じ二つの回答の間には一つの関連だけを保存する。
+-- 向きを持つ種類では、関連元が second 側であるときに reversed を立てる。
+ALTER TABLE answer_relations
+    ADD COLUMN kind ENUM('RELATED', 'DUPLICATE_OF', 'FOLLOW_UP_OF', 'BLOCKS') NOT NULL DEFAULT 'RELATED',
+    ADD COLUMN reversed BOOL NOT NULL DEFAULT FALSE;
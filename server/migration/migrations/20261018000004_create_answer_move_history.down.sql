@@ -0,0 +1 @@
+DROP TABLE IF EXISTS form_answer_move_history;
@@ -0,0 +1,13 @@
+-- 回答は archive やフォーム間の移動で所属が変わるため、移動履歴は answers への外部キーを持たず、
+-- 回答 ID と移動元・移動先のフォーム ID だけを保存する。
+CREATE TABLE IF NOT EXISTS form_answer_move_history(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    answer_id CHAR(36) NOT NULL,
+    from_form_id CHAR(36) NOT NULL,
+    to_form_id CHAR(36) NOT NULL,
+    changed_by_id CHAR(36) NOT NULL,
+    changed_by_name TEXT NOT NULL,
+    changed_by_role VARCHAR(32) NOT NULL,
+    changed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_form_answer_move_history_answer_id_id(answer_id, id)
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS answer_draw_entries;
+DROP TABLE IF EXISTS answer_draws;
@@ -0,0 +1,25 @@
+-- フォームは archive で別テーブルへ移るため、抽選の記録は form_meta_data への外部キーを持たない。
+-- 候補と当選者も、回答の移動や削除に関わらず抽選を再現できるよう answers への外部キーを持たない。
+CREATE TABLE IF NOT EXISTS answer_draws(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    form_id CHAR(36) NOT NULL,
+    seed BIGINT UNSIGNED NOT NULL,
+    winner_count INT UNSIGNED NOT NULL,
+    status_filter VARCHAR(32),
+    label_filter_id CHAR(36),
+    exclude_restricted_submitters BOOL NOT NULL,
+    drawn_by_id CHAR(36) NOT NULL,
+    drawn_by_name TEXT NOT NULL,
+    drawn_by_role VARCHAR(32) NOT NULL,
+    drawn_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_answer_draws_form_id_drawn_at(form_id, drawn_at)
+);
+
+-- winner_rank は当選者の順位 (1 始まり)。落選した候補は NULL。
+CREATE TABLE IF NOT EXISTS answer_draw_entries(
+    draw_id CHAR(36) NOT NULL,
+    answer_id CHAR(36) NOT NULL,
+    winner_rank INT UNSIGNED,
+    PRIMARY KEY(draw_id, answer_id),
+    FOREIGN KEY fk_answer_draw_entries_draw_id(draw_id) REFERENCES answer_draws(id) ON DELETE CASCADE
+);
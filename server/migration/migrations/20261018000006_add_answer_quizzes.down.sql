@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS answer_quiz_scores;
+
+ALTER TABLE archived_form_choices DROP COLUMN quiz_points;
+ALTER TABLE form_choices DROP COLUMN quiz_points;
+
+ALTER TABLE archived_form_meta_data DROP COLUMN show_quiz_score_to_author;
+ALTER TABLE archived_form_meta_data DROP COLUMN quiz_enabled;
+ALTER TABLE form_meta_data DROP COLUMN show_quiz_score_to_author;
+ALTER TABLE form_meta_data DROP COLUMN quiz_enabled;
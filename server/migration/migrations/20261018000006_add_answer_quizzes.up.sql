@@ -0,0 +1,16 @@
+ALTER TABLE form_meta_data ADD COLUMN quiz_enabled BOOL NOT NULL DEFAULT FALSE AFTER response_deadline_hours;
+ALTER TABLE form_meta_data ADD COLUMN show_quiz_score_to_author BOOL NOT NULL DEFAULT FALSE AFTER quiz_enabled;
+ALTER TABLE archived_form_meta_data ADD COLUMN quiz_enabled BOOL NOT NULL DEFAULT FALSE AFTER response_deadline_hours;
+ALTER TABLE archived_form_meta_data ADD COLUMN show_quiz_score_to_author BOOL NOT NULL DEFAULT FALSE AFTER quiz_enabled;
+
+-- 正解の選択肢の点数。NULL は不正解の選択肢。
+ALTER TABLE form_choices ADD COLUMN quiz_points INT UNSIGNED AFTER label;
+ALTER TABLE archived_form_choices ADD COLUMN quiz_points INT UNSIGNED AFTER label;
+
+-- 対応期限と同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS answer_quiz_scores(
+    answer_id CHAR(36) NOT NULL PRIMARY KEY,
+    score INT UNSIGNED NOT NULL,
+    max_score INT UNSIGNED NOT NULL,
+    INDEX idx_answer_quiz_scores_score(score)
+);
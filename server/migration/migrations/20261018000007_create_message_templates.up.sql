@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS message_templates(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    body TEXT NOT NULL
+);
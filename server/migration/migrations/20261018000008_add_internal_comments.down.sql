@@ -0,0 +1,3 @@
+ALTER TABLE form_answer_comment_history DROP COLUMN is_internal;
+ALTER TABLE archived_form_answer_comments DROP COLUMN is_internal;
+ALTER TABLE form_answer_comments DROP COLUMN is_internal;
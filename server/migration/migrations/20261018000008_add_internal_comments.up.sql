@@ -0,0 +1,4 @@
+-- 運営のみが閲覧できる内部コメントかどうか。既存のコメントは回答者にも公開されたものとして扱う。
+ALTER TABLE form_answer_comments ADD COLUMN is_internal BOOL NOT NULL DEFAULT FALSE AFTER content;
+ALTER TABLE archived_form_answer_comments ADD COLUMN is_internal BOOL NOT NULL DEFAULT FALSE AFTER content;
+ALTER TABLE form_answer_comment_history ADD COLUMN is_internal BOOL NOT NULL DEFAULT FALSE AFTER content;
@@ -0,0 +1,11 @@
+-- archive で回答やコメントが移動しても失われないよう、投稿の ID だけを保存する。
+CREATE TABLE IF NOT EXISTS answer_mentions(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    answer_id CHAR(36) NOT NULL,
+    comment_id CHAR(36),
+    message_id CHAR(36),
+    mentioned_user_id CHAR(36) NOT NULL,
+    mentioned_by CHAR(36) NOT NULL,
+    mentioned_at TIMESTAMP NOT NULL,
+    INDEX idx_answer_mentions_mentioned_user_id(mentioned_user_id)
+);
@@ -0,0 +1,11 @@
+-- メッセージの削除や archive 後も履歴から参照できるよう、外部キーは張らない。
+CREATE TABLE IF NOT EXISTS message_attachments(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    message_id CHAR(36) NOT NULL,
+    related_answer_id CHAR(36) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(127) NOT NULL,
+    size INT UNSIGNED NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_message_attachments_related_answer_id(related_answer_id)
+);
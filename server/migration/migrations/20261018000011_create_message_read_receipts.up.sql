@@ -0,0 +1,10 @@
+-- メッセージ ID は UUID v7 のため、文字列として比較すると投稿順になる。
+CREATE TABLE IF NOT EXISTS message_read_receipts(
+    answer_id CHAR(36) NOT NULL,
+    user_id CHAR(36) NOT NULL,
+    last_read_message_id CHAR(36) NOT NULL,
+    read_at TIMESTAMP NOT NULL,
+    PRIMARY KEY(answer_id, user_id),
+    FOREIGN KEY fk_message_read_receipts_answer_id(answer_id) REFERENCES answers(id) ON DELETE CASCADE,
+    FOREIGN KEY fk_message_read_receipts_user_id(user_id) REFERENCES users(id)
+);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS message_thread_reopenings;
+
+ALTER TABLE archived_form_meta_data DROP COLUMN lock_message_thread_on_completion;
+ALTER TABLE form_meta_data DROP COLUMN lock_message_thread_on_completion;
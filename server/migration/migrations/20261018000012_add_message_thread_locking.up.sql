@@ -0,0 +1,10 @@
+ALTER TABLE form_meta_data ADD COLUMN lock_message_thread_on_completion BOOL NOT NULL DEFAULT FALSE AFTER show_quiz_score_to_author;
+ALTER TABLE archived_form_meta_data ADD COLUMN lock_message_thread_on_completion BOOL NOT NULL DEFAULT FALSE AFTER show_quiz_score_to_author;
+
+-- 対応期限と同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS message_thread_reopenings(
+    answer_id CHAR(36) NOT NULL PRIMARY KEY,
+    reopened_by CHAR(36) NOT NULL,
+    reopened_at TIMESTAMP NOT NULL,
+    FOREIGN KEY fk_message_thread_reopenings_reopened_by(reopened_by) REFERENCES users(id)
+);
@@ -0,0 +1,13 @@
+DROP TABLE IF EXISTS temporary_answer_access_tokens;
+
+DELETE FROM archived_messages WHERE temporary_sender IS NOT NULL;
+ALTER TABLE archived_messages DROP CHECK chk_archived_messages_sender;
+ALTER TABLE archived_messages DROP FOREIGN KEY fk_archived_messages_temporary_sender;
+ALTER TABLE archived_messages DROP COLUMN temporary_sender;
+ALTER TABLE archived_messages MODIFY sender CHAR(36) NOT NULL;
+
+DELETE FROM messages WHERE temporary_sender IS NOT NULL;
+ALTER TABLE messages DROP CHECK chk_message_sender;
+ALTER TABLE messages DROP FOREIGN KEY fk_message_temporary_sender;
+ALTER TABLE messages DROP COLUMN temporary_sender;
+ALTER TABLE messages MODIFY sender CHAR(36) NOT NULL;
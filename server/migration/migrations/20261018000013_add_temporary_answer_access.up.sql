@@ -0,0 +1,28 @@
+-- 一時回答者がアクセスリンクから送ったメッセージは、users ではなく temporary_users を送信者にする。
+ALTER TABLE messages MODIFY sender CHAR(36) NULL;
+ALTER TABLE messages ADD COLUMN temporary_sender CHAR(36) NULL AFTER sender;
+ALTER TABLE messages ADD CONSTRAINT fk_message_temporary_sender FOREIGN KEY (temporary_sender) REFERENCES temporary_users(id);
+ALTER TABLE messages ADD CONSTRAINT chk_message_sender CHECK (
+    (sender IS NOT NULL AND temporary_sender IS NULL)
+    OR (sender IS NULL AND temporary_sender IS NOT NULL)
+);
+
+ALTER TABLE archived_messages MODIFY sender CHAR(36) NULL;
+ALTER TABLE archived_messages ADD COLUMN temporary_sender CHAR(36) NULL AFTER sender;
+ALTER TABLE archived_messages ADD CONSTRAINT fk_archived_messages_temporary_sender FOREIGN KEY (temporary_sender) REFERENCES temporary_users(id);
+ALTER TABLE archived_messages ADD CONSTRAINT chk_archived_messages_sender CHECK (
+    (sender IS NOT NULL AND temporary_sender IS NULL)
+    OR (sender IS NULL AND temporary_sender IS NOT NULL)
+);
+
+-- 対応期限と同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+-- トークンの平文は保存せず、SHA-256 のハッシュだけを保存する。
+CREATE TABLE IF NOT EXISTS temporary_answer_access_tokens(
+    token_hash CHAR(64) NOT NULL PRIMARY KEY,
+    answer_id CHAR(36) NOT NULL,
+    issued_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP NULL,
+    revoked_by CHAR(36) NULL,
+    INDEX idx_temporary_answer_access_tokens_answer_id(answer_id),
+    FOREIGN KEY fk_temporary_answer_access_tokens_revoked_by(revoked_by) REFERENCES users(id)
+);
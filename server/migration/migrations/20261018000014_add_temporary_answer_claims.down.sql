@@ -0,0 +1 @@
+DROP TABLE IF EXISTS temporary_answer_claim_codes;
@@ -0,0 +1,13 @@
+-- 一時回答をアカウントに引き継ぐための一度だけ使えるコード。
+-- コードの平文は保存せず、SHA-256 のハッシュだけを保存する。
+-- 引き継いだ後も、監査のために元の一時回答者 (temporary_user_id) を残す。
+CREATE TABLE IF NOT EXISTS temporary_answer_claim_codes(
+    code_hash CHAR(64) NOT NULL PRIMARY KEY,
+    answer_id CHAR(36) NOT NULL UNIQUE,
+    temporary_user_id CHAR(36) NOT NULL,
+    issued_at TIMESTAMP NOT NULL,
+    claimed_at TIMESTAMP NULL,
+    claimed_by CHAR(36) NULL,
+    FOREIGN KEY fk_temporary_answer_claim_codes_temporary_user_id(temporary_user_id) REFERENCES temporary_users(id),
+    FOREIGN KEY fk_temporary_answer_claim_codes_claimed_by(claimed_by) REFERENCES users(id)
+);
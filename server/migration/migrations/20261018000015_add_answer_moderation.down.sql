@@ -0,0 +1,5 @@
+ALTER TABLE archived_answers DROP COLUMN moderation_status;
+ALTER TABLE answers DROP COLUMN moderation_status;
+
+ALTER TABLE archived_form_meta_data DROP COLUMN hold_temporary_answers_for_review;
+ALTER TABLE form_meta_data DROP COLUMN hold_temporary_answers_for_review;
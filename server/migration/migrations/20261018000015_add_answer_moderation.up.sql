@@ -0,0 +1,6 @@
+ALTER TABLE form_meta_data ADD COLUMN hold_temporary_answers_for_review BOOL NOT NULL DEFAULT FALSE AFTER lock_message_thread_on_completion;
+ALTER TABLE archived_form_meta_data ADD COLUMN hold_temporary_answers_for_review BOOL NOT NULL DEFAULT FALSE AFTER lock_message_thread_on_completion;
+
+-- 既存の回答は承認済みとして扱う。
+ALTER TABLE answers ADD COLUMN moderation_status ENUM('APPROVED', 'PENDING', 'REJECTED') NOT NULL DEFAULT 'APPROVED' AFTER status;
+ALTER TABLE archived_answers ADD COLUMN moderation_status ENUM('APPROVED', 'PENDING', 'REJECTED') NOT NULL DEFAULT 'APPROVED' AFTER status;
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS submission_ip_blocklist;
+DROP TABLE IF EXISTS temporary_answer_submission_sources;
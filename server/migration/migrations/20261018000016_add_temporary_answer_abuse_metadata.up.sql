@@ -0,0 +1,19 @@
+-- 一時回答の送信元。IP アドレスと User-Agent の平文は保存せず、salt 付きの SHA-256 だけを保存する。
+-- アクセスリンクと同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS temporary_answer_submission_sources(
+    answer_id CHAR(36) NOT NULL PRIMARY KEY,
+    client_ip_hash CHAR(64) NULL,
+    user_agent_fingerprint CHAR(64) NULL,
+    recorded_at TIMESTAMP NOT NULL,
+    INDEX idx_temporary_answer_submission_sources_client_ip_hash(client_ip_hash)
+);
+
+-- 未ログインでの送信を拒否する IP アドレスの範囲。ip_range は正規化した CIDR 表記で保存する。
+CREATE TABLE IF NOT EXISTS submission_ip_blocklist(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    ip_range VARCHAR(43) NOT NULL UNIQUE,
+    reason TEXT NULL,
+    created_by CHAR(36) NOT NULL,
+    created_at DATETIME(6) NOT NULL,
+    FOREIGN KEY fk_submission_ip_blocklist_created_by(created_by) REFERENCES users(id)
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS content_filter_flags;
+DROP TABLE IF EXISTS content_filter_rules;
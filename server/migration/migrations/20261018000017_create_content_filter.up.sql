@@ -0,0 +1,36 @@
+-- フォームごとに管理者が登録する、投稿の内容を確かめる規則。
+-- archive でフォームが移動しても失われないよう、フォーム ID だけを保存する。
+-- condition_type に応じて pattern (語句・正規表現) か threshold (リンク数・連続する文字数) のどちらかを使う。
+CREATE TABLE IF NOT EXISTS content_filter_rules(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    form_id CHAR(36) NOT NULL,
+    condition_type ENUM('BLOCKED_WORD', 'PATTERN', 'MAX_LINKS', 'REPEATED_CHARACTERS') NOT NULL,
+    pattern TEXT NULL,
+    threshold INT UNSIGNED NULL,
+    action ENUM('REJECT', 'FLAG_FOR_REVIEW', 'ADD_LABEL') NOT NULL,
+    label_id CHAR(36) NULL,
+    created_by CHAR(36) NOT NULL,
+    created_at DATETIME(6) NOT NULL,
+    INDEX idx_content_filter_rules_form_id(form_id),
+    FOREIGN KEY fk_content_filter_rules_label_id(label_id) REFERENCES label_for_form_answers(id) ON DELETE CASCADE,
+    FOREIGN KEY fk_content_filter_rules_created_by(created_by) REFERENCES users(id),
+    CONSTRAINT chk_content_filter_rules_condition CHECK (
+        (condition_type IN ('BLOCKED_WORD', 'PATTERN') AND pattern IS NOT NULL AND threshold IS NULL)
+        OR (condition_type IN ('MAX_LINKS', 'REPEATED_CHARACTERS') AND pattern IS NULL AND threshold IS NOT NULL)
+    ),
+    CONSTRAINT chk_content_filter_rules_action CHECK ((action = 'ADD_LABEL') = (label_id IS NOT NULL))
+);
+
+-- 確認を求める規則に当てはまった投稿。管理者が確認したら削除する。
+-- 規則と同様に、archive で回答が移動しても失われないよう回答 ID だけを保存する。
+CREATE TABLE IF NOT EXISTS content_filter_flags(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    form_id CHAR(36) NOT NULL,
+    answer_id CHAR(36) NOT NULL,
+    target_type ENUM('ANSWER', 'COMMENT', 'MESSAGE') NOT NULL,
+    target_id CHAR(36) NULL,
+    rule_id CHAR(36) NULL,
+    flagged_at DATETIME(6) NOT NULL,
+    INDEX idx_content_filter_flags_form_id(form_id),
+    FOREIGN KEY fk_content_filter_flags_rule_id(rule_id) REFERENCES content_filter_rules(id) ON DELETE SET NULL
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS announcement_target_groups;
+DROP TABLE IF EXISTS announcements;
@@ -0,0 +1,26 @@
+-- 管理者が掲載するお知らせ。本文はコメントやメッセージと同じ制限された Markdown で保存する。
+-- publish_until が NULL の場合は、削除するまで公開し続ける。
+CREATE TABLE IF NOT EXISTS announcements(
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    publish_from DATETIME(6) NOT NULL,
+    publish_until DATETIME(6) NULL,
+    pinned BOOLEAN NOT NULL DEFAULT FALSE,
+    created_by CHAR(36) NOT NULL,
+    created_at DATETIME(6) NOT NULL,
+    updated_at DATETIME(6) NOT NULL,
+    FOREIGN KEY fk_announcements_created_by(created_by) REFERENCES users(id),
+    CONSTRAINT chk_announcements_publish_period CHECK (publish_until IS NULL OR publish_from < publish_until)
+);
+
+-- お知らせを掲載するユーザーグループ。行がないお知らせは全ユーザーに掲載する。
+-- 対象のグループが消えると全ユーザーに掲載されてしまうため、掲載先のグループは削除させない。
+CREATE TABLE IF NOT EXISTS announcement_target_groups(
+    id INT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    announcement_id CHAR(36) NOT NULL,
+    group_id CHAR(36) NOT NULL,
+    UNIQUE KEY uk_announcement_target_groups(announcement_id, group_id),
+    FOREIGN KEY fk_announcement_target_groups_announcement_id(announcement_id) REFERENCES announcements(id) ON DELETE CASCADE,
+    FOREIGN KEY fk_announcement_target_groups_group_id(group_id) REFERENCES user_groups(id) ON DELETE RESTRICT
+);
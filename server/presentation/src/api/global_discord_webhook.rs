@@ -2,7 +2,8 @@ use std::sync::LazyLock;
 
 use common::config::FRONTEND;
 use domain::{
-    auth::Actor, repository::global_discord_webhook_repository::GlobalDiscordWebhookRepository,
+    auth::Actor, markdown::render_discord,
+    repository::global_discord_webhook_repository::GlobalDiscordWebhookRepository,
 };
 use resource::{
     outgoing::discord_webhook_sender::{
@@ -221,6 +222,24 @@ pub(crate) fn message_from_event(
                 fields,
             )
         }
+        ApplicationEvent::AnswerOverdue {
+            form_id,
+            answer_title,
+            answer_id,
+            due_at,
+            status,
+        } => {
+            let link_url = format!("{frontend}/forms/{form_id}/answers/{answer_id}");
+            let fields = vec![
+                DiscordWebhookField::new("対応期限".to_string(), due_at.to_rfc3339(), true),
+                DiscordWebhookField::new("現在のステータス".to_string(), status.to_string(), true),
+            ];
+            (
+                answer_title.unwrap_or_else(|| "（タイトルなし）".to_string()),
+                link_url,
+                fields,
+            )
+        }
         ApplicationEvent::CommentCreated {
             actor,
             form_id,
@@ -249,7 +268,11 @@ pub(crate) fn message_from_event(
                 format!("{frontend}/forms/{form_id}/answers/{answer_id}?commentId={comment_id}");
             let fields = [
                 actor_fields(actor),
-                vec![DiscordWebhookField::new("内容".to_string(), content, false)],
+                vec![DiscordWebhookField::new(
+                    "内容".to_string(),
+                    render_discord(&content),
+                    false,
+                )],
             ]
             .concat();
             (
@@ -286,7 +309,11 @@ pub(crate) fn message_from_event(
                 format!("{frontend}/forms/{form_id}/answers/{answer_id}?messageId={message_id}");
             let fields = [
                 actor_fields(actor),
-                vec![DiscordWebhookField::new("内容".to_string(), body, false)],
+                vec![DiscordWebhookField::new(
+                    "内容".to_string(),
+                    render_discord(&body),
+                    false,
+                )],
             ]
             .concat();
             (
@@ -313,6 +340,7 @@ fn operation_name(event: &ApplicationEvent) -> &'static str {
         ApplicationEvent::FormRestored { .. } => "form_restored",
         ApplicationEvent::AnswerSubmitted { .. } => "answer_submitted",
         ApplicationEvent::AnswerStatusChanged { .. } => "answer_status_changed",
+        ApplicationEvent::AnswerOverdue { .. } => "answer_overdue",
         ApplicationEvent::CommentCreated { .. } => "comment_created",
         ApplicationEvent::CommentUpdated { .. } => "comment_updated",
         ApplicationEvent::CommentDeleted { .. } => "comment_deleted",
@@ -331,6 +359,7 @@ fn operation_display_name(event: &ApplicationEvent) -> &'static str {
         ApplicationEvent::FormRestored { .. } => "が復元されました",
         ApplicationEvent::AnswerSubmitted { .. } => "に回答が投稿されました",
         ApplicationEvent::AnswerStatusChanged { .. } => "の対応ステータスが変更されました",
+        ApplicationEvent::AnswerOverdue { .. } => "の対応期限を過ぎました",
         ApplicationEvent::CommentCreated { .. } => "にコメントが投稿されました",
         ApplicationEvent::CommentUpdated { .. } => "のコメントが更新されました",
         ApplicationEvent::CommentDeleted { .. } => "のコメントが削除されました",
@@ -343,6 +372,7 @@ fn operation_display_name(event: &ApplicationEvent) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{DateTime, Utc};
     use domain::form::answer::{AnswerStatus, AnswerStatusChange};
 
     #[test]
@@ -414,7 +444,7 @@ mod tests {
                 answer_title: None,
                 answer_id: "answer-id".to_string(),
                 message_id: "message-id".to_string(),
-                body: "body".to_string(),
+                body: "**body** <b>@everyone</b>".to_string(),
             },
             "https://discord.com/api/webhooks/123/token".to_string(),
             "https://portal.example.com/",
@@ -428,6 +458,12 @@ mod tests {
             message.title,
             "「（タイトルなし）」にメッセージが投稿されました"
         );
+        assert!(
+            message
+                .fields
+                .iter()
+                .any(|field| field.name == "内容" && field.value == "**body** \\@everyone")
+        );
     }
 
     #[test]
@@ -521,4 +557,35 @@ mod tests {
             "「（タイトルなし）」の対応ステータスが変更されました"
         );
     }
+
+    #[test]
+    fn answer_overdue_event_reports_the_due_date_and_current_status() {
+        let due_at = "2026-10-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let message = message_from_event(
+            ApplicationEvent::AnswerOverdue {
+                form_id: "form-id".to_string(),
+                answer_title: Some("Answer".to_string()),
+                answer_id: "answer-id".to_string(),
+                due_at,
+                status: AnswerStatus::IN_PROGRESS,
+            },
+            "https://discord.com/api/webhooks/123/token".to_string(),
+            "https://portal.example.com/",
+        );
+
+        assert_eq!(message.title, "「Answer」の対応期限を過ぎました");
+        assert_eq!(
+            message.link_url,
+            "https://portal.example.com/forms/form-id/answers/answer-id"
+        );
+        assert!(
+            message.fields.iter().any(|field| {
+                field.name == "対応期限" && field.value == due_at.to_rfc3339()
+            })
+        );
+        assert!(message.fields.iter().any(|field| {
+            field.name == "現在のステータス" && field.value == "IN_PROGRESS"
+        }));
+        assert!(message.fields.iter().all(|field| field.name != "実行者"));
+    }
 }
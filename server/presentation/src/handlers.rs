@@ -1,7 +1,10 @@
+pub mod announcement_handler;
 pub mod error_handler;
 pub mod form;
 pub mod global_discord_webhook_handler;
 pub mod health_check_handler;
 pub mod notification_handler;
 pub mod search_handler;
+pub mod submission_ip_blocklist_handler;
+pub mod support_metrics_handler;
 pub mod user_handler;
@@ -0,0 +1,224 @@
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, Query, State,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    announcement::{AnnouncementId, AnnouncementPublishPeriod},
+    auth::Actor,
+    form::models::AllowedUserGroups,
+    repository::Repositories,
+};
+use errors::ErrorExtra;
+use resource::repository::RealInfrastructureRepository;
+use usecase::announcement::AnnouncementUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    schemas::{
+        announcement::{
+            AnnouncementCreateSchema, AnnouncementListQuery, AnnouncementResponse,
+            AnnouncementUpdateSchema,
+        },
+        error_responses::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/announcements",
+    summary = "お知らせの一覧",
+    description = "閲覧者に掲載するお知らせを、固定表示のものから順に返します。ログインしていない場合は、全ユーザー向けのお知らせだけを返します。",
+    params(AnnouncementListQuery),
+    responses(
+        (status = 200, body = [AnnouncementResponse]),
+        BadRequest,
+        InternalServerError,
+    ),
+    security((), ("bearer" = [])),
+    tag = "Announcements"
+)]
+pub async fn list_announcements_handler(
+    Extension(actor): Extension<Actor>,
+    State(repository): State<RealInfrastructureRepository>,
+    query: Result<Query<AnnouncementListQuery>, QueryRejection>,
+) -> Result<Json<Vec<AnnouncementResponse>>, Response> {
+    let Query(query) = query.map_err_to_error().map_err(handle_error)?;
+    let usecase = AnnouncementUseCase {
+        announcement_repository: repository.announcement_repository(),
+        user_repository: repository.user_repository(),
+    };
+    let announcements = usecase
+        .list(&actor, query.include_unpublished)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(announcements.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/announcements/{announcement_id}",
+    summary = "お知らせの取得",
+    params(("announcement_id" = String, Path, description = "Announcement ID")),
+    responses(
+        (status = 200, body = AnnouncementResponse),
+        BadRequest,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security((), ("bearer" = [])),
+    tag = "Announcements"
+)]
+pub async fn get_announcement_handler(
+    Extension(actor): Extension<Actor>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<AnnouncementId>, PathRejection>,
+) -> Result<Json<AnnouncementResponse>, Response> {
+    let Path(announcement_id) = path.map_err_to_error().map_err(handle_error)?;
+    let usecase = AnnouncementUseCase {
+        announcement_repository: repository.announcement_repository(),
+        user_repository: repository.user_repository(),
+    };
+    let announcement = usecase
+        .get(&actor, announcement_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(announcement.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/announcements",
+    summary = "お知らせの作成",
+    request_body = AnnouncementCreateSchema,
+    responses(
+        (status = 201, body = AnnouncementResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Announcements"
+)]
+pub async fn create_announcement_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<AnnouncementCreateSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Json(request) = json.map_err_to_error().map_err(handle_error)?;
+    let publish_period =
+        AnnouncementPublishPeriod::try_new(request.publish_from, request.publish_until)
+            .map_err(errors::Error::from)
+            .map_err(handle_error)?;
+    let usecase = AnnouncementUseCase {
+        announcement_repository: repository.announcement_repository(),
+        user_repository: repository.user_repository(),
+    };
+    let announcement = usecase
+        .create(
+            &user,
+            request.title,
+            request.body,
+            publish_period,
+            request.pinned,
+            AllowedUserGroups::new(request.target_group_ids),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AnnouncementResponse::from(announcement)),
+    ))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/announcements/{announcement_id}",
+    summary = "お知らせの更新",
+    params(("announcement_id" = String, Path, description = "Announcement ID")),
+    request_body = AnnouncementUpdateSchema,
+    responses(
+        (status = 200, body = AnnouncementResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Announcements"
+)]
+pub async fn update_announcement_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<AnnouncementId>, PathRejection>,
+    json: Result<Json<AnnouncementUpdateSchema>, JsonRejection>,
+) -> Result<Json<AnnouncementResponse>, Response> {
+    let Path(announcement_id) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(request) = json.map_err_to_error().map_err(handle_error)?;
+    let publish_until = request.publish_until_update();
+    let usecase = AnnouncementUseCase {
+        announcement_repository: repository.announcement_repository(),
+        user_repository: repository.user_repository(),
+    };
+    let announcement = usecase
+        .update(
+            &user,
+            announcement_id,
+            request.title,
+            request.body,
+            request.publish_from,
+            publish_until,
+            request.pinned,
+            request.target_group_ids.map(AllowedUserGroups::new),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(announcement.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/announcements/{announcement_id}",
+    summary = "お知らせの削除",
+    params(("announcement_id" = String, Path, description = "Announcement ID")),
+    responses(
+        (status = 204),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Announcements"
+)]
+pub async fn delete_announcement_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<AnnouncementId>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path(announcement_id) = path.map_err_to_error().map_err(handle_error)?;
+    let usecase = AnnouncementUseCase {
+        announcement_repository: repository.announcement_repository(),
+        user_repository: repository.user_repository(),
+    };
+    usecase
+        .delete(&user, announcement_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -12,7 +12,12 @@ use errors::{
 
 use crate::schemas::error_response::{ErrorResponse, ErrorRestriction};
 
-fn problem_response(status: StatusCode, title: &str, detail: &str, error_code: &str) -> Response {
+fn problem_response(
+    status: StatusCode,
+    title: &str,
+    detail: &str,
+    error_code: &str,
+) -> ErrorResponse {
     problem_response_with_restriction(status, title, detail, error_code, None)
 }
 
@@ -22,23 +27,18 @@ fn problem_response_with_restriction(
     detail: &str,
     error_code: &str,
     restriction: Option<ErrorRestriction>,
-) -> Response {
-    (
-        status,
-        [(header::CONTENT_TYPE, "application/problem+json")],
-        Json(ErrorResponse {
-            problem_type: "about:blank".to_string(),
-            title: title.to_string(),
-            status: status.as_u16(),
-            detail: detail.to_string(),
-            error_code: error_code.to_string(),
-            restriction,
-        }),
-    )
-        .into_response()
+) -> ErrorResponse {
+    ErrorResponse {
+        problem_type: "about:blank".to_string(),
+        title: title.to_string(),
+        status: status.as_u16(),
+        detail: detail.to_string(),
+        error_code: error_code.to_string(),
+        restriction,
+    }
 }
 
-fn handle_domain_error(err: DomainError) -> impl IntoResponse {
+fn handle_domain_error(err: DomainError) -> ErrorResponse {
     match err {
         DomainError::Forbidden => problem_response(
             StatusCode::FORBIDDEN,
@@ -70,7 +70,7 @@ fn handle_domain_error(err: DomainError) -> impl IntoResponse {
         DomainError::MessagePostingNotSupportedForTemporaryAnswer => problem_response(
             StatusCode::UNPROCESSABLE_ENTITY,
             "Unprocessable Entity",
-            "Messages cannot be posted to temporary answers.",
+            "Messages cannot be posted to temporary answers without an active access link.",
             "MESSAGE_POSTING_NOT_SUPPORTED_FOR_TEMPORARY_ANSWER",
         ),
         DomainError::MessagePostingNotSupportedForImportedAnswer => problem_response(
@@ -79,6 +79,12 @@ fn handle_domain_error(err: DomainError) -> impl IntoResponse {
             "Messages cannot be posted to answers imported from Redmine.",
             "MESSAGE_POSTING_NOT_SUPPORTED_FOR_IMPORTED_ANSWER",
         ),
+        DomainError::MessageThreadLocked => problem_response(
+            StatusCode::CONFLICT,
+            "Conflict",
+            "The message thread is locked.",
+            "MESSAGE_THREAD_LOCKED",
+        ),
         DomainError::Conversion { source } => {
             tracing::error!("Conversion Error: {}", source);
             problem_response(
@@ -94,12 +100,60 @@ fn handle_domain_error(err: DomainError) -> impl IntoResponse {
             "Invalid answer acceptance period.",
             "INVALID_ANSWER_ACCEPTANCE_PERIOD",
         ),
+        DomainError::InvalidAnswerResponseDeadline => problem_response(
+            StatusCode::BAD_REQUEST,
+            "Bad Request",
+            "Invalid answer response deadline.",
+            "INVALID_ANSWER_RESPONSE_DEADLINE",
+        ),
         DomainError::InvalidDiscordWebhookUrl => problem_response(
             StatusCode::BAD_REQUEST,
             "Bad Request",
             "Invalid Discord webhook url. (Seichi-Portal only supports Discord webhook)",
             "INVALID_DISCORD_WEBHOOK_URL",
         ),
+        DomainError::InvalidAnswerAssignee => problem_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Unprocessable Entity",
+            "Only administrators can be assigned to answers.",
+            "INVALID_ANSWER_ASSIGNEE",
+        ),
+        DomainError::AnswerNotPendingModeration => problem_response(
+            StatusCode::CONFLICT,
+            "Conflict",
+            "The answer is not pending moderation.",
+            "ANSWER_NOT_PENDING_MODERATION",
+        ),
+        DomainError::InvalidIpRange => problem_response(
+            StatusCode::BAD_REQUEST,
+            "Bad Request",
+            "Invalid IP address range.",
+            "INVALID_IP_RANGE",
+        ),
+        DomainError::BlockedIpRangeAlreadyExists => problem_response(
+            StatusCode::CONFLICT,
+            "Conflict",
+            "The IP address range is already blocked.",
+            "BLOCKED_IP_RANGE_ALREADY_EXISTS",
+        ),
+        DomainError::InvalidContentFilterRule => problem_response(
+            StatusCode::BAD_REQUEST,
+            "Bad Request",
+            "Invalid content filter rule.",
+            "INVALID_CONTENT_FILTER_RULE",
+        ),
+        DomainError::ContentRejectedByFilter => problem_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Unprocessable Entity",
+            "The content was rejected by the content filter.",
+            "CONTENT_REJECTED_BY_FILTER",
+        ),
+        DomainError::InvalidAnnouncementPublishPeriod => problem_response(
+            StatusCode::BAD_REQUEST,
+            "Bad Request",
+            "Invalid announcement publish period.",
+            "INVALID_ANNOUNCEMENT_PUBLISH_PERIOD",
+        ),
         DomainError::InvalidEntity { message } => problem_response(
             StatusCode::UNPROCESSABLE_ENTITY,
             "Unprocessable Entity",
@@ -109,7 +163,7 @@ fn handle_domain_error(err: DomainError) -> impl IntoResponse {
     }
 }
 
-fn handle_usecase_error(err: UseCaseError) -> impl IntoResponse {
+fn handle_usecase_error(err: UseCaseError) -> ErrorResponse {
     match err {
         UseCaseError::AnswerNotFound => problem_response(
             StatusCode::NOT_FOUND,
@@ -177,10 +231,70 @@ fn handle_usecase_error(err: UseCaseError) -> impl IntoResponse {
             "Discord is not linked.",
             "DISCORD_NOT_LINKED",
         ),
+        UseCaseError::DrawNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Draw not found.",
+            "DRAW_NOT_FOUND",
+        ),
+        UseCaseError::MessageTemplateNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Message template not found.",
+            "MESSAGE_TEMPLATE_NOT_FOUND",
+        ),
+        UseCaseError::MessageAttachmentNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Message attachment not found.",
+            "MESSAGE_ATTACHMENT_NOT_FOUND",
+        ),
+        UseCaseError::TemporaryAnswerAccessNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Temporary answer access not found.",
+            "TEMPORARY_ANSWER_ACCESS_NOT_FOUND",
+        ),
+        UseCaseError::TemporaryAnswerClaimCodeNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Temporary answer claim code not found.",
+            "TEMPORARY_ANSWER_CLAIM_CODE_NOT_FOUND",
+        ),
+        UseCaseError::TemporaryAnswerAbuseMetadataNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Temporary answer abuse metadata not found.",
+            "TEMPORARY_ANSWER_ABUSE_METADATA_NOT_FOUND",
+        ),
+        UseCaseError::BlockedIpRangeNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Blocked IP range not found.",
+            "BLOCKED_IP_RANGE_NOT_FOUND",
+        ),
+        UseCaseError::ContentFilterRuleNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Content filter rule not found.",
+            "CONTENT_FILTER_RULE_NOT_FOUND",
+        ),
+        UseCaseError::ContentFilterFlagNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Content filter flag not found.",
+            "CONTENT_FILTER_FLAG_NOT_FOUND",
+        ),
+        UseCaseError::AnnouncementNotFound => problem_response(
+            StatusCode::NOT_FOUND,
+            "Not Found",
+            "Announcement not found.",
+            "ANNOUNCEMENT_NOT_FOUND",
+        ),
     }
 }
 
-fn handle_infra_error(err: InfraError) -> impl IntoResponse {
+fn handle_infra_error(err: InfraError) -> ErrorResponse {
     match err {
         InfraError::Database { source } => {
             tracing::error!("Database Error: {}", source);
@@ -317,10 +431,19 @@ fn handle_infra_error(err: InfraError) -> impl IntoResponse {
                 "INTERNAL_SERVER_ERROR",
             )
         }
+        InfraError::Storage { cause } => {
+            tracing::error!("Storage Error: {}", cause);
+            problem_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Internal Server Error",
+                "Storage Error",
+                "INTERNAL_SERVER_ERROR",
+            )
+        }
     }
 }
 
-fn handle_validation_error(err: ValidationError) -> impl IntoResponse {
+fn handle_validation_error(err: ValidationError) -> ErrorResponse {
     match err {
         ValidationError::EmptyValue => problem_response(
             StatusCode::BAD_REQUEST,
@@ -343,7 +466,7 @@ fn handle_validation_error(err: ValidationError) -> impl IntoResponse {
     }
 }
 
-fn handle_presentation_error(err: PresentationError) -> impl IntoResponse {
+fn handle_presentation_error(err: PresentationError) -> ErrorResponse {
     match err {
         PresentationError::JsonRejection { cause } => problem_response(
             StatusCode::UNPROCESSABLE_ENTITY,
@@ -372,16 +495,27 @@ fn handle_presentation_error(err: PresentationError) -> impl IntoResponse {
     }
 }
 
-pub fn handle_error(err: Error) -> Response {
+/// レスポンスにせず、problem details の値だけが必要な場合 (一括操作の各結果など) に使う。
+pub fn problem_details(err: Error) -> ErrorResponse {
     match err {
-        Error::Domain { source } => handle_domain_error(source).into_response(),
-        Error::UseCase { source } => handle_usecase_error(source).into_response(),
-        Error::Infra { source } => handle_infra_error(source).into_response(),
-        Error::Validation { source } => handle_validation_error(source).into_response(),
-        Error::Presentation { source } => handle_presentation_error(source).into_response(),
+        Error::Domain { source } => handle_domain_error(source),
+        Error::UseCase { source } => handle_usecase_error(source),
+        Error::Infra { source } => handle_infra_error(source),
+        Error::Validation { source } => handle_validation_error(source),
+        Error::Presentation { source } => handle_presentation_error(source),
     }
 }
 
+pub fn handle_error(err: Error) -> Response {
+    let problem = problem_details(err);
+    (
+        StatusCode::from_u16(problem.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        [(header::CONTENT_TYPE, "application/problem+json")],
+        Json(problem),
+    )
+        .into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use axum::{body::to_bytes, http::header::CONTENT_TYPE};
@@ -410,7 +544,7 @@ mod tests {
         );
         assert_eq!(
             problem["detail"],
-            "Messages cannot be posted to temporary answers."
+            "Messages cannot be posted to temporary answers without an active access link."
         );
     }
 }
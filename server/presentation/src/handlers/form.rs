@@ -1,7 +1,11 @@
+pub mod answer_draw_handler;
 pub mod answer_handler;
 pub mod answer_label_handler;
 pub mod answer_relation_handler;
 pub mod comment_handler;
+pub mod content_filter_handler;
 pub mod form_handler;
 pub mod form_label_handler;
 pub mod message_handler;
+pub mod message_template_handler;
+pub mod temporary_answer_access_handler;
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    form::{
+        draw::{AnswerDrawCriteria, AnswerDrawId, AnswerDrawSeed, AnswerDrawWinnerCount},
+        models::FormId,
+    },
+    notification::notificator::Notificator,
+    repository::Repositories,
+};
+use errors::{Error, ErrorExtra};
+use resource::{
+    database::connection::ConnectionPool,
+    repository::{RealInfrastructureRepository, Repository},
+};
+use usecase::forms::answer_draw::AnswerDrawUseCase;
+
+use crate::{
+    handlers::{
+        error_handler::handle_error,
+        form::message_handler::RealInfrastructureRepositoryWithNotificator,
+    },
+    schemas::{
+        error_responses::{
+            BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity,
+        },
+        form::{
+            form_request_schemas::AnswerDrawCreateSchema, form_response_schemas::AnswerDrawResponse,
+        },
+    },
+};
+
+type ResourceRepository = Repository<ConnectionPool>;
+type ResourceAnswerDrawUseCase<'a> = AnswerDrawUseCase<
+    'a,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+>;
+
+fn build_answer_draw_use_case(
+    repository: &RealInfrastructureRepository,
+) -> ResourceAnswerDrawUseCase<'_> {
+    AnswerDrawUseCase {
+        active_form_repository: repository.active_form_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        answer_label_repository: repository.answer_label_repository(),
+        form_submission_restriction_repository: repository.form_submission_restriction_repository(),
+        user_repository: repository.user_repository(),
+        answer_draw_repository: repository.answer_draw_repository(),
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+pub enum CreateAnswerDrawResponse {
+    #[response(
+        status = 201,
+        description = "The request has succeeded and a new resource has been created as a result."
+    )]
+    Created(AnswerDrawResponse),
+}
+
+impl IntoResponse for CreateAnswerDrawResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Created(body) => (StatusCode::CREATED, Json(body)).into_response(),
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/draws",
+    summary = "回答の中から抽選を行う",
+    description = "条件に一致する回答の中から当選者を選び、抽選の条件・シード・候補・当選者を記録します。当選者は記録されたシードと候補から誰でも再現できます。管理者のみ利用できます。",
+    params(("form_id" = String, Path, description = "Form ID")),
+    request_body = AnswerDrawCreateSchema,
+    responses(
+        CreateAnswerDrawResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn create_answer_draw_handler<N: Notificator>(
+    Extension(user): Extension<AccountUser>,
+    State(state): State<Arc<RealInfrastructureRepositoryWithNotificator<N>>>,
+    path: Result<Path<FormId>, PathRejection>,
+    json: Result<Json<AnswerDrawCreateSchema>, JsonRejection>,
+) -> Result<CreateAnswerDrawResponse, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+    let winner_count = AnswerDrawWinnerCount::try_new(schema.winner_count)
+        .map_err(Error::from)
+        .map_err(handle_error)?;
+    let seed = schema
+        .seed
+        .as_deref()
+        .map(str::parse::<AnswerDrawSeed>)
+        .transpose()
+        .map_err(Error::from)
+        .map_err(handle_error)?;
+
+    let draw = build_answer_draw_use_case(&state.repository)
+        .draw(
+            &user,
+            form_id,
+            AnswerDrawCriteria::new(
+                schema.status,
+                schema.label_id,
+                schema.exclude_restricted_submitters,
+            ),
+            winner_count,
+            seed,
+            schema.notify_winners,
+            &state.notificator,
+            state.repository.notification_repository(),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(CreateAnswerDrawResponse::Created(draw.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/draws",
+    summary = "フォームで行われた抽選の一覧",
+    description = "フォームで行われた抽選を新しいものから順に返します。各抽選には、記録されたシードと候補から抽選をやり直した結果が当選者と一致するかを `verified` として含めます。",
+    params(("form_id" = String, Path, description = "Form ID")),
+    responses(
+        (status = 200, body = [AnswerDrawResponse]),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_answer_draws_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+) -> Result<Json<Vec<AnswerDrawResponse>>, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+
+    let draws = build_answer_draw_use_case(&repository)
+        .list_draws(&user, form_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(draws.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/draws/{draw_id}",
+    summary = "抽選の結果を取得",
+    description = "抽選の条件・シード・候補・当選者を返します。`verified` は、記録されたシードと候補から抽選をやり直した結果が当選者と一致するかを表します。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("draw_id" = String, Path, description = "Draw ID"),
+    ),
+    responses(
+        (status = 200, body = AnswerDrawResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_answer_draw_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerDrawId)>, PathRejection>,
+) -> Result<Json<AnswerDrawResponse>, Response> {
+    let Path((form_id, draw_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    let draw = build_answer_draw_use_case(&repository)
+        .get_draw(&user, form_id, draw_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(draw.into()))
+}
@@ -2,25 +2,30 @@ use async_trait::async_trait;
 use axum::extract::rejection::{JsonRejection, PathRejection};
 use axum::response::Response;
 use axum::{
-    Extension, Json,
+    BoxError, Extension, Json,
+    body::Body,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode, header},
     response::IntoResponse,
 };
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
 use domain::form::answer::{
-    AnswerPagePosition, AnswerStatusHistoryPagePosition, AnswerTitleHistoryPagePosition,
-    FormAnswerContent, FormAnswerContentId,
+    AnswerAssignmentHistoryPagePosition, AnswerListFilter, AnswerModerationDecision,
+    AnswerMoveHistoryPagePosition, AnswerPagePosition, AnswerStatusHistoryPagePosition,
+    AnswerTitleHistoryPagePosition, FormAnswerContent, FormAnswerContentId,
 };
 use domain::{
     account::models::AccountUser,
     form::answer::TemporaryAnswerAuthor,
+    form::temporary_answer_abuse::TemporaryAnswerSubmissionSource,
     form::{answer::AnswerId, models::FormId},
+    notification::notificator::Notificator,
     pagination::{PageLimit, PageRequest},
     repository::Repositories,
 };
 use errors::{Error, ErrorExtra, presentation::PresentationError};
+use futures::{StreamExt, TryStreamExt, stream};
 use itertools::Itertools;
 use resource::{
     outgoing::discord_webhook_sender::{
@@ -30,24 +35,41 @@ use resource::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::Notify;
 use tracing::warn;
-use usecase::forms::{
-    answer::AnswerUseCase,
-    discord_answer_webhook::{DiscordAnswerWebhookNotification, DiscordAnswerWebhookNotifier},
+use usecase::{
+    forms::{
+        answer::{AnswerUseCase, BulkAnswerOperation},
+        discord_answer_webhook::{DiscordAnswerWebhookNotification, DiscordAnswerWebhookNotifier},
+    },
+    models::BulkAnswerOperationResult,
 };
 
 use crate::api::global_discord_webhook::APPLICATION_EVENT_PUBLISHER;
 use crate::schemas::error_responses::*;
 use crate::{
-    handlers::error_handler::handle_error,
+    handlers::{
+        error_handler::{handle_error, problem_details},
+        form::message_handler::RealInfrastructureRepositoryWithNotificator,
+    },
+    ip_blocklist::ClientIp,
     schemas::form::{
+        answer_export::AnswerExportEncoder,
         form_request_schemas::{
-            AnswerCreateSchema, AnswerListQuery, AnswerUpdateSchema, HistoryListQuery,
+            AnswerAssigneeUpdateSchema, AnswerCreateSchema, AnswerDueDateUpdateSchema,
+            AnswerExportQuery, AnswerListQuery, AnswerMoveSchema, AnswerUpdateSchema,
+            BulkAnswerLabelAction, BulkAnswerLabelUpdateSchema, BulkAnswerModerationSchema,
+            BulkAnswerPublicationUpdateSchema, BulkAnswerStatusUpdateSchema, HistoryListQuery,
             TemporaryAnswerCreateSchema,
         },
         form_response_schemas::{
-            AnswerListPageResponse, AnswerStatusHistoryPageResponse,
-            AnswerTitleHistoryPageResponse, FormAnswer,
+            AnswerAssignmentHistoryPageResponse, AnswerListPageResponse,
+            AnswerMoveHistoryPageResponse, AnswerStatisticsResponse,
+            AnswerStatusHistoryPageResponse, AnswerTitleHistoryPageResponse,
+            BulkAnswerOperationItemResponse, BulkAnswerOperationResponse, FormAnswer,
+            PostedAnswerResponse, PostedTemporaryAnswerResponse,
+            TemporaryAnswerAbuseMetadataResponse,
         },
     },
 };
@@ -60,9 +82,11 @@ type ResourceAnswerUseCase<'a> = AnswerUseCase<
     ResourceRepository,
     ResourceRepository,
     ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
 >;
 
-fn build_answer_use_case<'a>(
+pub(super) fn build_answer_use_case<'a>(
     repository: &'a RealInfrastructureRepository,
     discord_answer_webhook_notifier: Option<&'a dyn DiscordAnswerWebhookNotifier>,
 ) -> ResourceAnswerUseCase<'a> {
@@ -72,6 +96,9 @@ fn build_answer_use_case<'a>(
         user_repository: repository.user_repository(),
         form_submission_restriction_repository: repository.form_submission_restriction_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
+        content_filter_repository: repository.content_filter_repository(),
+        temporary_answer_abuse_repository: repository.temporary_answer_abuse_repository(),
+        message_read_repository: Some(repository.message_read_repository()),
         discord_answer_webhook_notifier,
         application_event_publisher: Some(&APPLICATION_EVENT_PUBLISHER),
     }
@@ -161,6 +188,55 @@ impl IntoResponse for GetAnswersByFormResponse {
     }
 }
 
+#[derive(utoipa::IntoResponses)]
+pub enum GetAnswerStatisticsResponse {
+    #[response(status = 200, description = "The request has succeeded.")]
+    Ok(AnswerStatisticsResponse),
+}
+
+impl IntoResponse for GetAnswerStatisticsResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Ok(body) => (StatusCode::OK, Json(body)).into_response(),
+        }
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+pub enum BulkUpdateAnswersResponse {
+    #[response(
+        status = 200,
+        description = "The request has succeeded. Each item reports whether it was applied."
+    )]
+    Ok(BulkAnswerOperationResponse),
+}
+
+impl IntoResponse for BulkUpdateAnswersResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Ok(body) => (StatusCode::OK, Json(body)).into_response(),
+        }
+    }
+}
+
+impl From<Vec<BulkAnswerOperationResult>> for BulkUpdateAnswersResponse {
+    fn from(results: Vec<BulkAnswerOperationResult>) -> Self {
+        Self::Ok(BulkAnswerOperationResponse {
+            results: results
+                .into_iter()
+                .map(|BulkAnswerOperationResult { answer, result }| {
+                    BulkAnswerOperationItemResponse {
+                        form_id: answer.form_id().into_inner(),
+                        answer_id: answer.answer_id().into_inner(),
+                        succeeded: result.is_ok(),
+                        error: result.err().map(problem_details),
+                    }
+                })
+                .collect_vec(),
+        })
+    }
+}
+
 #[derive(utoipa::IntoResponses)]
 pub enum UpdateAnswerResponse {
     #[response(status = 200, description = "The request has succeeded.")]
@@ -179,6 +255,8 @@ impl IntoResponse for UpdateAnswerResponse {
 struct AnswerListCursor {
     after_timestamp: DateTime<Utc>,
     after_answer_id: uuid::Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after_score: Option<u32>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -191,6 +269,16 @@ struct AnswerTitleHistoryCursor {
     after_history_id: uuid::Uuid,
 }
 
+#[derive(Deserialize, Serialize)]
+struct AnswerAssignmentHistoryCursor {
+    after_history_id: uuid::Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+struct AnswerMoveHistoryCursor {
+    after_history_id: uuid::Uuid,
+}
+
 fn status_history_page_request(
     query: HistoryListQuery,
 ) -> Result<PageRequest<AnswerStatusHistoryPagePosition>, Error> {
@@ -261,6 +349,92 @@ fn encode_title_history_cursor(position: AnswerTitleHistoryPagePosition) -> Resu
     Ok(URL_SAFE_NO_PAD.encode(bytes))
 }
 
+fn assignment_history_page_request(
+    query: HistoryListQuery,
+) -> Result<PageRequest<AnswerAssignmentHistoryPagePosition>, Error> {
+    let limit = match query.limit {
+        Some(limit) => PageLimit::try_new(limit)
+            .map_err(|error| bad_query(format!("Invalid limit: {}.", error.value())))?,
+        None => PageLimit::default_limit(),
+    };
+    let after = query
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            let decoded = URL_SAFE_NO_PAD
+                .decode(cursor)
+                .map_err(|_| bad_query("Invalid cursor."))?;
+            let cursor = serde_json::from_slice::<AnswerAssignmentHistoryCursor>(&decoded)
+                .map_err(|_| bad_query("Invalid cursor."))?;
+            Ok::<_, Error>(AnswerAssignmentHistoryPagePosition::new(
+                cursor.after_history_id.into(),
+            ))
+        })
+        .transpose()?;
+
+    Ok(PageRequest::new(after, limit))
+}
+
+fn encode_assignment_history_cursor(
+    position: AnswerAssignmentHistoryPagePosition,
+) -> Result<String, Error> {
+    let bytes = serde_json::to_vec(&AnswerAssignmentHistoryCursor {
+        after_history_id: position.id().into_inner(),
+    })
+    .map_err(|_| bad_query("Invalid cursor."))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+fn move_history_page_request(
+    query: HistoryListQuery,
+) -> Result<PageRequest<AnswerMoveHistoryPagePosition>, Error> {
+    let limit = match query.limit {
+        Some(limit) => PageLimit::try_new(limit)
+            .map_err(|error| bad_query(format!("Invalid limit: {}.", error.value())))?,
+        None => PageLimit::default_limit(),
+    };
+    let after = query
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            let decoded = URL_SAFE_NO_PAD
+                .decode(cursor)
+                .map_err(|_| bad_query("Invalid cursor."))?;
+            let cursor = serde_json::from_slice::<AnswerMoveHistoryCursor>(&decoded)
+                .map_err(|_| bad_query("Invalid cursor."))?;
+            Ok::<_, Error>(AnswerMoveHistoryPagePosition::new(
+                cursor.after_history_id.into(),
+            ))
+        })
+        .transpose()?;
+
+    Ok(PageRequest::new(after, limit))
+}
+
+fn encode_move_history_cursor(position: AnswerMoveHistoryPagePosition) -> Result<String, Error> {
+    let bytes = serde_json::to_vec(&AnswerMoveHistoryCursor {
+        after_history_id: position.id().into_inner(),
+    })
+    .map_err(|_| bad_query("Invalid cursor."))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+fn answer_list_filter(query: &AnswerListQuery, user: &AccountUser) -> AnswerListFilter {
+    AnswerListFilter::default()
+        .with_status(query.status)
+        .with_assignee(query.assignee.map(|assignee| assignee.into_filter(user)))
+        .with_overdue_only(query.overdue.unwrap_or_default())
+        .with_score_range(query.min_score, query.max_score)
+        .with_unread_author_messages_for(
+            query
+                .has_unread_author_messages
+                .unwrap_or_default()
+                .then_some(*user.id()),
+        )
+        .with_moderation_status(query.moderation_status)
+        .with_order(query.sort.map(Into::into).unwrap_or_default())
+}
+
 fn bad_query(message: impl Into<String>) -> Error {
     Error::from(PresentationError::QueryRejection {
         cause: message.into(),
@@ -274,16 +448,17 @@ fn decode_answer_list_cursor(cursor: &str) -> Result<AnswerPagePosition, Error>
     let cursor = serde_json::from_slice::<AnswerListCursor>(&decoded)
         .map_err(|_| bad_query("Invalid cursor."))?;
 
-    Ok(AnswerPagePosition::new(
-        cursor.after_timestamp,
-        cursor.after_answer_id.into(),
-    ))
+    Ok(
+        AnswerPagePosition::new(cursor.after_timestamp, cursor.after_answer_id.into())
+            .with_last_score(cursor.after_score),
+    )
 }
 
 fn encode_answer_list_cursor(position: AnswerPagePosition) -> Result<String, Error> {
     let cursor = AnswerListCursor {
         after_timestamp: position.last_timestamp(),
         after_answer_id: position.last_answer_id().into_inner(),
+        after_score: position.last_score(),
     };
     let bytes = serde_json::to_vec(&cursor).map_err(|_| bad_query("Invalid cursor."))?;
 
@@ -331,11 +506,11 @@ pub async fn get_all_answers(
 ) -> Result<GetAllAnswersResponse, Response> {
     let form_answer_use_case = build_answer_use_case(&repository, None);
     let Query(query) = query.map_err_to_error().map_err(handle_error)?;
-    let status = query.status;
+    let filter = answer_list_filter(&query, &user);
     let request = answer_list_page_request(query).map_err(handle_error)?;
 
     let page = form_answer_use_case
-        .get_all_answers(&user, request, status)
+        .get_all_answers(&user, request, filter)
         .await
         .map_err(handle_error)?;
     let (answers, next) = page.into_parts();
@@ -345,16 +520,7 @@ pub async fn get_all_answers(
         .map_err(handle_error)?;
 
     Ok(GetAllAnswersResponse::Ok(AnswerListPageResponse {
-        items: answers
-            .into_iter()
-            .map(|answer_details| {
-                FormAnswer::new(
-                    answer_details.answer,
-                    answer_details.form_id,
-                    answer_details.labels,
-                )
-            })
-            .collect_vec(),
+        items: answers.into_iter().map(FormAnswer::from).collect_vec(),
         next_cursor,
     }))
 }
@@ -393,11 +559,45 @@ pub async fn get_answer_handler(
         .await
         .map_err(handle_error)?;
 
-    Ok(GetAnswerResponse::Ok(FormAnswer::new(
-        answer_details.answer,
-        answer_details.form_id,
-        answer_details.labels,
-    )))
+    Ok(GetAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/{answer_id}/abuse-metadata",
+    summary = "未ログイン回答の送信元情報を取得",
+    description = "管理者のみ取得できます。IP アドレスと User-Agent は salt 付きのハッシュで返します。",
+    params(("form_id" = String, Path), ("answer_id" = String, Path)),
+    responses(
+        (status = 200, description = "The request has succeeded.", body = TemporaryAnswerAbuseMetadataResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_temporary_answer_abuse_metadata_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let form_answer_use_case = build_answer_use_case(&repository, None);
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    let metadata = form_answer_use_case
+        .get_temporary_answer_abuse_metadata(&user, form_id, answer_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(TemporaryAnswerAbuseMetadataResponse::from(metadata)),
+    )
+        .into_response())
 }
 
 #[utoipa::path(
@@ -472,6 +672,78 @@ pub async fn get_answer_title_history_handler(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/{answer_id}/assignee/history",
+    summary = "回答の担当者変更履歴を取得",
+    params(("form_id" = String, Path), ("answer_id" = String, Path), HistoryListQuery),
+    responses((status = 200, body = AnswerAssignmentHistoryPageResponse), BadRequest, Unauthorized, Forbidden, NotFound, InternalServerError),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_answer_assignment_history_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    query: Result<Query<HistoryListQuery>, axum::extract::rejection::QueryRejection>,
+) -> Result<Json<AnswerAssignmentHistoryPageResponse>, Response> {
+    let use_case = build_answer_use_case(&repository, None);
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Query(query) = query.map_err_to_error().map_err(handle_error)?;
+    let request = assignment_history_page_request(query).map_err(handle_error)?;
+    let page = use_case
+        .get_assignment_history(&user, form_id, answer_id, request)
+        .await
+        .map_err(handle_error)?;
+    let (items, next) = page.into_parts();
+    Ok(Json(AnswerAssignmentHistoryPageResponse {
+        items: items
+            .into_iter()
+            .map(|entry| entry.into_inner().into())
+            .collect(),
+        next_cursor: next
+            .map(encode_assignment_history_cursor)
+            .transpose()
+            .map_err(handle_error)?,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/{answer_id}/move/history",
+    summary = "回答のフォーム間の移動履歴を取得",
+    params(("form_id" = String, Path), ("answer_id" = String, Path), HistoryListQuery),
+    responses((status = 200, body = AnswerMoveHistoryPageResponse), BadRequest, Unauthorized, Forbidden, NotFound, InternalServerError),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_answer_move_history_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    query: Result<Query<HistoryListQuery>, axum::extract::rejection::QueryRejection>,
+) -> Result<Json<AnswerMoveHistoryPageResponse>, Response> {
+    let use_case = build_answer_use_case(&repository, None);
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Query(query) = query.map_err_to_error().map_err(handle_error)?;
+    let request = move_history_page_request(query).map_err(handle_error)?;
+    let page = use_case
+        .get_move_history(&user, form_id, answer_id, request)
+        .await
+        .map_err(handle_error)?;
+    let (items, next) = page.into_parts();
+    Ok(Json(AnswerMoveHistoryPageResponse {
+        items: items
+            .into_iter()
+            .map(|entry| entry.into_inner().into())
+            .collect(),
+        next_cursor: next
+            .map(encode_move_history_cursor)
+            .transpose()
+            .map_err(handle_error)?,
+    }))
+}
+
 #[utoipa::path(
     get,
     path = "/forms/{form_id}/answers",
@@ -502,11 +774,11 @@ pub async fn get_answer_by_form_id_handler(
 
     let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
     let Query(query) = query.map_err_to_error().map_err(handle_error)?;
-    let status = query.status;
+    let filter = answer_list_filter(&query, &user);
     let request = answer_list_page_request(query).map_err(handle_error)?;
 
     let page = form_answer_use_case
-        .get_answers_by_form_id(form_id, &user, request, status)
+        .get_answers_by_form_id(form_id, &user, request, filter)
         .await
         .map_err(handle_error)?;
     let (answers, next) = page.into_parts();
@@ -516,20 +788,132 @@ pub async fn get_answer_by_form_id_handler(
         .map_err(handle_error)?;
 
     Ok(GetAnswersByFormResponse::Ok(AnswerListPageResponse {
-        items: answers
-            .into_iter()
-            .map(|answer_details| {
-                FormAnswer::new(
-                    answer_details.answer,
-                    answer_details.form_id,
-                    answer_details.labels,
-                )
-            })
-            .collect_vec(),
+        items: answers.into_iter().map(FormAnswer::from).collect_vec(),
         next_cursor,
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/statistics",
+    summary = "回答の集計",
+    description = "選択肢ごと・日ごと・状態ごと・ラベルごとの回答数を返します。管理者以外には、回答一覧が公開されているフォームの公開済みの回答だけを集計します。",
+    params(("form_id" = String, Path, description = "Form ID")),
+    responses(
+        GetAnswerStatisticsResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn get_answer_statistics_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+) -> Result<GetAnswerStatisticsResponse, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+
+    let statistics = build_answer_use_case(&repository, None)
+        .get_answer_statistics(form_id, &user)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(GetAnswerStatisticsResponse::Ok(statistics.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/export",
+    summary = "回答のエクスポート",
+    description = "フォームの回答を CSV または NDJSON でストリーミングします。回答者の表示は閲覧者に対する開示設定に従います。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        AnswerExportQuery,
+    ),
+    responses(
+        (status = 200, description = "The request has succeeded.", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn export_answers_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+    query: Result<Query<AnswerExportQuery>, axum::extract::rejection::QueryRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+    let Query(AnswerExportQuery { format }) = query.map_err_to_error().map_err(handle_error)?;
+
+    // ストリームを返し始めた後はステータスコードを変えられないため、閲覧権限はここで確認する
+    let questions = build_answer_use_case(&repository, None)
+        .get_answer_export_questions(form_id, &user)
+        .await
+        .map_err(handle_error)?;
+    let encoder = Arc::new(AnswerExportEncoder::new(format, questions));
+    let heading = encoder.header();
+
+    let rows = stream::try_unfold(
+        Some(None),
+        move |after: Option<Option<AnswerPagePosition>>| {
+            let repository = repository.clone();
+            let user = user.clone();
+            let encoder = Arc::clone(&encoder);
+
+            async move {
+                let Some(after) = after else {
+                    return Ok(None);
+                };
+
+                let page = build_answer_use_case(&repository, None)
+                    .get_answers_by_form_id(
+                        form_id,
+                        &user,
+                        PageRequest::new(after, PageLimit::default_limit()),
+                        AnswerListFilter::default(),
+                    )
+                    .await?;
+                let (answers, next) = page.into_parts();
+                let chunk = answers
+                    .into_iter()
+                    .map(|answer| encoder.encode(answer))
+                    .collect::<Result<String, _>>()?;
+
+                Ok::<_, BoxError>(Some((chunk, next.map(Some))))
+            }
+        },
+    )
+    .inspect_err(|err| warn!("Answer export was aborted: {err}"));
+    let body = Body::from_stream(stream::once(async { Ok(heading) }).chain(rows));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"answers-{}.{}\"",
+                    form_id.into_inner(),
+                    format.file_extension()
+                ),
+            ),
+        ],
+        body,
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/forms/{form_id}/answers",
@@ -539,7 +923,7 @@ pub async fn get_answer_by_form_id_handler(
     ),
     request_body = AnswerCreateSchema,
     responses(
-        (status = 200, description = "The request has succeeded."),
+        (status = 200, description = "The request has succeeded.", body = PostedAnswerResponse),
         BadRequest,
         Unauthorized,
         Forbidden,
@@ -574,12 +958,18 @@ pub async fn post_answer_handler(
         })
         .collect_vec();
 
-    form_answer_use_case
+    let quiz_score = form_answer_use_case
         .post_answers(user, form_id, answer_contents)
         .await
         .map_err(handle_error)?;
 
-    Ok(StatusCode::OK.into_response())
+    Ok((
+        StatusCode::OK,
+        Json(PostedAnswerResponse {
+            quiz_score: quiz_score.map(Into::into),
+        }),
+    )
+        .into_response())
 }
 
 #[utoipa::path(
@@ -592,7 +982,7 @@ pub async fn post_answer_handler(
     ),
     request_body = TemporaryAnswerCreateSchema,
     responses(
-        (status = 200, description = "The request has succeeded."),
+        (status = 200, description = "The request has succeeded.", body = PostedTemporaryAnswerResponse),
         BadRequest,
         Forbidden,
         NotFound,
@@ -604,6 +994,8 @@ pub async fn post_answer_handler(
 )]
 pub async fn post_temporary_answer_handler(
     State(repository): State<RealInfrastructureRepository>,
+    client_ip: Option<Extension<ClientIp>>,
+    headers: HeaderMap,
     path: Result<Path<FormId>, PathRejection>,
     json: Result<Json<TemporaryAnswerCreateSchema>, JsonRejection>,
 ) -> Result<impl IntoResponse, Response> {
@@ -628,13 +1020,28 @@ pub async fn post_temporary_answer_handler(
             answer: schema.answer,
         })
         .collect_vec();
+    let source = TemporaryAnswerSubmissionSource::new(
+        client_ip.map(|Extension(ClientIp(ip))| ip),
+        headers
+            .get(header::USER_AGENT)
+            .and_then(|user_agent| user_agent.to_str().ok())
+            .map(str::to_owned),
+    );
 
-    form_answer_use_case
-        .post_temporary_answers(temporary_user, form_id, answer_contents)
+    let posted = form_answer_use_case
+        .post_temporary_answers(temporary_user, form_id, answer_contents, source)
         .await
         .map_err(handle_error)?;
 
-    Ok(StatusCode::OK.into_response())
+    Ok((
+        StatusCode::OK,
+        Json(PostedTemporaryAnswerResponse {
+            quiz_score: posted.quiz_score.map(Into::into),
+            access_token: posted.access_token.expose_secret().to_owned(),
+            claim_code: posted.claim_code.expose_secret().to_owned(),
+        }),
+    )
+        .into_response())
 }
 
 #[utoipa::path(
@@ -681,20 +1088,362 @@ pub async fn update_answer_handler(
         .await
         .map_err(handle_error)?;
 
-    Ok(UpdateAnswerResponse::Ok(FormAnswer::new(
-        answer_details.answer,
-        answer_details.form_id,
-        answer_details.labels,
-    )))
+    Ok(UpdateAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/forms/{form_id}/answers/{answer_id}/assignee",
+    summary = "回答の担当者を変更",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    request_body = AnswerAssigneeUpdateSchema,
+    responses(
+        UpdateAnswerResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn update_answer_assignee_handler<N: Notificator>(
+    Extension(user): Extension<AccountUser>,
+    State(state): State<Arc<RealInfrastructureRepositoryWithNotificator<N>>>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    json: Result<Json<AnswerAssigneeUpdateSchema>, JsonRejection>,
+) -> Result<UpdateAnswerResponse, Response> {
+    let form_answer_use_case = build_answer_use_case(&state.repository, None);
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let answer_details = form_answer_use_case
+        .assign_answer(
+            &user,
+            form_id,
+            answer_id,
+            schema.assignee_id.map(Into::into),
+            &state.notificator,
+            state.repository.notification_repository(),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(UpdateAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/forms/{form_id}/answers/{answer_id}/due-date",
+    summary = "回答の対応期限を変更",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    request_body = AnswerDueDateUpdateSchema,
+    responses(
+        UpdateAnswerResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn update_answer_due_date_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    json: Result<Json<AnswerDueDateUpdateSchema>, JsonRejection>,
+) -> Result<UpdateAnswerResponse, Response> {
+    let form_answer_use_case = build_answer_use_case(&repository, None);
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let answer_details = form_answer_use_case
+        .change_answer_due_date(&user, form_id, answer_id, schema.due_at)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(UpdateAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/answers/{answer_id}/move",
+    summary = "回答を別のフォームへ移動",
+    description = "回答を `destination_form_id` のフォームへ移動します。`question_mapping` で指定しなかった質問は、同じ `template_key` を持つ移動先の質問に対応付けます。回答済みの質問がすべて対応付けられない場合は移動しません。コメント・メッセージ・ラベル・関連・各種履歴は回答に付いたまま移動し、移動の記録は移動履歴に残ります。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    request_body = AnswerMoveSchema,
+    responses(
+        UpdateAnswerResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn move_answer_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    json: Result<Json<AnswerMoveSchema>, JsonRejection>,
+) -> Result<UpdateAnswerResponse, Response> {
+    let form_answer_use_case = build_answer_use_case(&repository, None);
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+    let question_mapping = schema
+        .question_mapping
+        .into_iter()
+        .map(|mapping| (mapping.source_question_id, mapping.destination_question_id))
+        .collect();
+
+    let answer_details = form_answer_use_case
+        .move_answer(
+            &user,
+            form_id,
+            answer_id,
+            schema.destination_form_id,
+            question_mapping,
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(UpdateAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/answers/bulk/status",
+    summary = "回答の対応ステータスを一括変更",
+    request_body = BulkAnswerStatusUpdateSchema,
+    responses(
+        BulkUpdateAnswersResponse,
+        BadRequest,
+        Unauthorized,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn bulk_update_answer_status_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<BulkAnswerStatusUpdateSchema>, JsonRejection>,
+) -> Result<BulkUpdateAnswersResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let results = build_answer_use_case(&repository, None)
+        .bulk_update_answers(
+            &user,
+            schema.answers.into_inner(),
+            BulkAnswerOperation::ChangeStatus(schema.status),
+        )
+        .await;
+
+    Ok(results.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/answers/bulk/publication",
+    summary = "回答の公開設定を一括変更",
+    request_body = BulkAnswerPublicationUpdateSchema,
+    responses(
+        BulkUpdateAnswersResponse,
+        BadRequest,
+        Unauthorized,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn bulk_update_answer_publication_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<BulkAnswerPublicationUpdateSchema>, JsonRejection>,
+) -> Result<BulkUpdateAnswersResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let results = build_answer_use_case(&repository, None)
+        .bulk_update_answers(
+            &user,
+            schema.answers.into_inner(),
+            BulkAnswerOperation::ChangePublication(schema.publication),
+        )
+        .await;
+
+    Ok(results.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/answers/bulk/labels",
+    summary = "回答のラベルを一括で付け外し",
+    request_body = BulkAnswerLabelUpdateSchema,
+    responses(
+        BulkUpdateAnswersResponse,
+        BadRequest,
+        Unauthorized,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn bulk_update_answer_labels_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<BulkAnswerLabelUpdateSchema>, JsonRejection>,
+) -> Result<BulkUpdateAnswersResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+    let operation = match schema.action {
+        BulkAnswerLabelAction::Add => BulkAnswerOperation::AddLabel(schema.label_id),
+        BulkAnswerLabelAction::Remove => BulkAnswerOperation::RemoveLabel(schema.label_id),
+    };
+
+    let results = build_answer_use_case(&repository, None)
+        .bulk_update_answers(&user, schema.answers.into_inner(), operation)
+        .await;
+
+    Ok(results.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/answers/bulk/approve",
+    summary = "確認待ちの回答を一括で承認",
+    description = "承認した一時回答は、この時点で検索に載り、回答の受付イベントと Discord の Webhook が送られます。",
+    request_body = BulkAnswerModerationSchema,
+    responses(
+        BulkUpdateAnswersResponse,
+        BadRequest,
+        Unauthorized,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn bulk_approve_answers_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<BulkAnswerModerationSchema>, JsonRejection>,
+) -> Result<BulkUpdateAnswersResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let results = build_answer_use_case(&repository, None)
+        .bulk_update_answers(
+            &user,
+            schema.answers.into_inner(),
+            BulkAnswerOperation::Moderate(AnswerModerationDecision::Approve),
+        )
+        .await;
+
+    Ok(results.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/answers/bulk/reject",
+    summary = "確認待ちの回答を一括で却下",
+    request_body = BulkAnswerModerationSchema,
+    responses(
+        BulkUpdateAnswersResponse,
+        BadRequest,
+        Unauthorized,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn bulk_reject_answers_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<BulkAnswerModerationSchema>, JsonRejection>,
+) -> Result<BulkUpdateAnswersResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    let results = build_answer_use_case(&repository, None)
+        .bulk_update_answers(
+            &user,
+            schema.answers.into_inner(),
+            BulkAnswerOperation::Moderate(AnswerModerationDecision::Reject),
+        )
+        .await;
+
+    Ok(results.into())
+}
+
+pub async fn start_watch_overdue_answers(
+    repository: RealInfrastructureRepository,
+    shutdown_notifier: Arc<Notify>,
+) -> Result<(), Error> {
+    build_answer_use_case(&repository, None)
+        .start_watch_overdue_answers(shutdown_notifier)
+        .await
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::TimeZone;
+    use domain::form::answer::AnswerReference;
     use uuid::Uuid;
 
     use super::*;
 
+    #[test]
+    fn bulk_results_report_failures_with_the_single_answer_problem_details() {
+        let succeeded = AnswerReference::new(FormId::new(), AnswerId::new());
+        let missing = AnswerReference::new(FormId::new(), AnswerId::new());
+
+        let BulkUpdateAnswersResponse::Ok(response) = BulkUpdateAnswersResponse::from(vec![
+            BulkAnswerOperationResult {
+                answer: succeeded,
+                result: Ok(()),
+            },
+            BulkAnswerOperationResult {
+                answer: missing,
+                result: Err(errors::usecase::UseCaseError::AnswerNotFound.into()),
+            },
+        ]);
+        let serialized = serde_json::to_value(response).unwrap();
+
+        assert_eq!(
+            serialized["results"][0]["answer_id"],
+            succeeded.answer_id().to_string()
+        );
+        assert_eq!(serialized["results"][0]["succeeded"], true);
+        assert!(serialized["results"][0]["error"].is_null());
+        assert_eq!(serialized["results"][1]["succeeded"], false);
+        assert_eq!(serialized["results"][1]["error"]["status"], 404);
+        assert_eq!(
+            serialized["results"][1]["error"]["errorCode"],
+            "ANSWER_NOT_FOUND"
+        );
+    }
+
     #[test]
     fn answer_list_cursor_round_trips_timestamp_and_answer_id() {
         let timestamp = Utc.with_ymd_and_hms(2026, 8, 3, 12, 0, 0).unwrap();
@@ -1,5 +1,9 @@
-use axum::extract::rejection::{JsonRejection, PathRejection};
-use axum::{Extension, Json, extract::Path, extract::State, response::IntoResponse};
+use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
 use domain::{
     account::models::AccountUser,
     form::answer::{AnswerId, AnswerReference},
@@ -13,12 +17,18 @@ use usecase::forms::answer_relation::AnswerRelationUseCase;
 use utoipa::IntoResponses;
 
 use crate::{
-    handlers::error_handler::handle_error,
+    handlers::{
+        error_handler::handle_error,
+        form::answer_handler::{UpdateAnswerResponse, build_answer_use_case},
+    },
     schemas::error_responses::{
         BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity,
     },
     schemas::form::{
-        form_request_schemas::RelatedAnswerRequest, form_response_schemas::RelatedAnswerResponse,
+        form_request_schemas::{
+            AnswerReferenceSchema, RelatedAnswerListQuery, RelatedAnswerRequest,
+        },
+        form_response_schemas::{FormAnswer, RelatedAnswerResponse},
     },
 };
 
@@ -63,6 +73,7 @@ impl IntoResponse for GetRelatedAnswersResponse {
     params(
         ("form_id" = String, Path, description = "Form ID"),
         ("answer_id" = String, Path, description = "Answer ID"),
+        RelatedAnswerListQuery,
     ),
     responses(
         GetRelatedAnswersResponse,
@@ -80,11 +91,13 @@ pub async fn get_related_answers_handler(
     Extension(user): Extension<AccountUser>,
     State(repository): State<RealInfrastructureRepository>,
     path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    query: Result<Query<RelatedAnswerListQuery>, QueryRejection>,
 ) -> Result<GetRelatedAnswersResponse, axum::response::Response> {
     let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Query(query) = query.map_err_to_error().map_err(handle_error)?;
     let use_case = build_answer_relation_use_case(&repository);
     let related_answers = use_case
-        .list_related_answers(&user, form_id, answer_id)
+        .list_related_answers(&user, form_id, answer_id, query.kind)
         .await
         .map_err(handle_error)?
         .into_iter()
@@ -129,6 +142,7 @@ pub async fn add_related_answer_handler(
             &user,
             AnswerReference::new(form_id, answer_id),
             AnswerReference::new(request.form_id, request.answer_id),
+            request.kind,
         )
         .await
         .map_err(handle_error)?;
@@ -176,3 +190,46 @@ pub async fn remove_related_answer_handler(
 
     Ok(axum::http::StatusCode::OK.into_response())
 }
+
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/answers/{answer_id}/close-as-duplicate",
+    summary = "回答を重複として完了にする",
+    description = "パスで指定した回答から本文で指定した回答へ `DUPLICATE_OF` の関連を付け、回答の状態を `COMPLETED` にします。二つの回答の間に既に関連がある場合は、その種類を `DUPLICATE_OF` に置き換えます。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    request_body = AnswerReferenceSchema,
+    responses(
+        UpdateAnswerResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn close_answer_as_duplicate_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    json_body: Result<Json<AnswerReferenceSchema>, JsonRejection>,
+) -> Result<UpdateAnswerResponse, axum::response::Response> {
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(original) = json_body.map_err_to_error().map_err(handle_error)?;
+
+    let answer_details = build_answer_use_case(&repository, None)
+        .close_answer_as_duplicate(
+            &user,
+            AnswerReference::new(form_id, answer_id),
+            AnswerReference::new(original.form_id, original.answer_id),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(UpdateAnswerResponse::Ok(FormAnswer::from(answer_details)))
+}
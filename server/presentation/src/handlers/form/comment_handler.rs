@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use axum::extract::rejection::{JsonRejection, PathRejection};
 use axum::response::Response;
 use axum::{
@@ -10,6 +12,7 @@ use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use domain::form::answer::AnswerId;
 use domain::form::comment::CommentHistoryPagePosition;
 use domain::form::models::FormId;
+use domain::notification::notificator::Notificator;
 use domain::pagination::{PageLimit, PageRequest};
 use domain::{
     account::models::AccountUser,
@@ -21,6 +24,7 @@ use resource::repository::RealInfrastructureRepository;
 use usecase::forms::comment::CommentUseCase;
 
 use crate::api::global_discord_webhook::APPLICATION_EVENT_PUBLISHER;
+use crate::handlers::form::message_handler::RealInfrastructureRepositoryWithNotificator;
 use crate::schemas::error_responses::*;
 use crate::schemas::form::form_request_schemas::{CommentUpdateSchema, HistoryListQuery};
 use crate::schemas::form::form_response_schemas::{AnswerComment, CommentHistoryPageResponse};
@@ -206,12 +210,13 @@ pub async fn get_form_comment(
     security(("bearer" = [])),
     tag = "Comments"
 )]
-pub async fn post_form_comment(
+pub async fn post_form_comment<N: Notificator>(
     Extension(user): Extension<AccountUser>,
-    State(repository): State<RealInfrastructureRepository>,
+    State(state): State<Arc<RealInfrastructureRepositoryWithNotificator<N>>>,
     path: Result<Path<(FormId, AnswerId)>, PathRejection>,
     json: Result<Json<CommentPostSchema>, JsonRejection>,
 ) -> Result<impl IntoResponse, Response> {
+    let repository = &state.repository;
     let form_comment_use_case = CommentUseCase {
         active_form_repository: repository.active_form_repository(),
         user_repository: repository.user_repository(),
@@ -229,7 +234,12 @@ pub async fn post_form_comment(
             form_id,
             answer_id,
             CommentContent::new(comment_schema.content),
+            comment_schema.visibility.unwrap_or_default(),
             repository.form_submission_restriction_repository(),
+            repository.mention_repository(),
+            repository.notification_repository(),
+            &state.notificator,
+            repository.content_filter_repository(),
         )
         .await
         .map_err(handle_error)?;
@@ -283,6 +293,7 @@ pub async fn update_form_comment(
             answer_id,
             comment_id,
             comment_schema.content.map(CommentContent::new),
+            repository.content_filter_repository(),
         )
         .await
         .map_err(handle_error)?;
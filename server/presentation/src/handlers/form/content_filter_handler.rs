@@ -0,0 +1,221 @@
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    form::{
+        content_filter::{ContentFilterCondition, ContentFilterFlagId, ContentFilterRuleId},
+        models::FormId,
+    },
+    repository::Repositories,
+};
+use errors::ErrorExtra;
+use resource::{
+    database::connection::ConnectionPool,
+    repository::{RealInfrastructureRepository, Repository},
+};
+use usecase::forms::content_filter::ContentFilterUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    schemas::{
+        error_responses::{
+            BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity,
+        },
+        form::{
+            form_request_schemas::ContentFilterRuleCreateSchema,
+            form_response_schemas::{ContentFilterFlagResponse, ContentFilterRuleResponse},
+        },
+    },
+};
+
+type ResourceRepository = Repository<ConnectionPool>;
+type ResourceContentFilterUseCase<'a> =
+    ContentFilterUseCase<'a, ResourceRepository, ResourceRepository, ResourceRepository>;
+
+fn build_use_case(repository: &RealInfrastructureRepository) -> ResourceContentFilterUseCase<'_> {
+    ContentFilterUseCase {
+        active_form_repository: repository.active_form_repository(),
+        answer_label_repository: repository.answer_label_repository(),
+        content_filter_repository: repository.content_filter_repository(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/content-filter-rules",
+    summary = "フォームの投稿フィルターの規則の一覧",
+    params(("form_id" = String, Path, description = "Form ID")),
+    responses(
+        (status = 200, body = [ContentFilterRuleResponse]),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Forms"
+)]
+pub async fn list_content_filter_rules_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+) -> Result<Json<Vec<ContentFilterRuleResponse>>, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+
+    let rules = build_use_case(&repository)
+        .list_rules(&user, form_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(rules.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/content-filter-rules",
+    summary = "フォームの投稿フィルターに規則を追加する",
+    description = "回答・コメント・メッセージの投稿時に規則を確かめます。追加した規則は次の投稿から適用されます。",
+    params(("form_id" = String, Path, description = "Form ID")),
+    request_body = ContentFilterRuleCreateSchema,
+    responses(
+        (status = 201, body = ContentFilterRuleResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Forms"
+)]
+pub async fn create_content_filter_rule_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+    json: Result<Json<ContentFilterRuleCreateSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(rule) = json.map_err_to_error().map_err(handle_error)?;
+    let condition = ContentFilterCondition::try_from(rule.condition)
+        .map_err(errors::Error::from)
+        .map_err(handle_error)?;
+
+    let created = build_use_case(&repository)
+        .add_rule(&user, form_id, condition, rule.action.into())
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ContentFilterRuleResponse::from(created)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/forms/{form_id}/content-filter-rules/{rule_id}",
+    summary = "フォームの投稿フィルターから規則を削除する",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("rule_id" = String, Path, description = "Content filter rule ID"),
+    ),
+    responses(
+        (status = 204),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Forms"
+)]
+pub async fn delete_content_filter_rule_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, ContentFilterRuleId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path((form_id, rule_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    build_use_case(&repository)
+        .remove_rule(&user, form_id, rule_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/content-filter-flags",
+    summary = "投稿フィルターで確認待ちになった投稿の一覧",
+    params(("form_id" = String, Path, description = "Form ID")),
+    responses(
+        (status = 200, body = [ContentFilterFlagResponse]),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Forms"
+)]
+pub async fn list_content_filter_flags_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<FormId>, PathRejection>,
+) -> Result<Json<Vec<ContentFilterFlagResponse>>, Response> {
+    let Path(form_id) = path.map_err_to_error().map_err(handle_error)?;
+
+    let flags = build_use_case(&repository)
+        .list_flags(&user, form_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(flags.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/forms/{form_id}/content-filter-flags/{flag_id}",
+    summary = "確認を終えた投稿を確認待ちの一覧から外す",
+    description = "記録を削除するだけで、投稿そのものや回答の公開前の確認の状態は変わりません。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("flag_id" = String, Path, description = "Content filter flag ID"),
+    ),
+    responses(
+        (status = 204),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Forms"
+)]
+pub async fn dismiss_content_filter_flag_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, ContentFilterFlagId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path((form_id, flag_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    build_use_case(&repository)
+        .dismiss_flag(&user, form_id, flag_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -7,8 +7,14 @@ use axum::{
     http::{HeaderValue, StatusCode, header},
     response::IntoResponse,
 };
+use axum_extra::{
+    TypedHeader,
+    headers::{ETag, HeaderMapExt, IfNoneMatch},
+    typed_header::TypedHeaderRejection,
+};
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
+use common::config::FRONTEND;
 use domain::{
     account::models::AccountUser,
     auth::Actor,
@@ -16,6 +22,7 @@ use domain::{
         models::{
             AllowedUserGroups, AnswerAuthorPublicationPolicy, ArchivedForm,
             ArchivedFormPagePosition, FormDescription, FormId, FormLabel, FormPagePosition,
+            MessageThreadLockPolicy, TemporaryAnswerModerationPolicy,
         },
         question::{Choice, Question, QuestionSet, QuestionType},
     },
@@ -42,12 +49,14 @@ use crate::schemas::{
     form::{
         form_request_schemas::{
             ArchivedFormListQuery, ChoiceSchema, FormCreateSchema, FormListQuery, FormUpdateSchema,
-            QuestionSchema, into_default_answer_title, into_discord_webhook_url,
+            QuestionSchema, into_answer_response_deadline, into_default_answer_title,
+            into_discord_webhook_url,
         },
         form_response_schemas::{
             ArchivedFormListPageResponse, ArchivedFormSchema, FormListPageResponse, FormMetaSchema,
             FormSchema, FormSettingsResponseSchema, QuestionResponseSchema,
         },
+        public_form_feed::{ATOM_CONTENT_TYPE, render_atom},
     },
 };
 
@@ -342,6 +351,15 @@ pub async fn create_form_handler(
             answer_settings
                 .hide_author
                 .map(AnswerAuthorPublicationPolicy::from_hide_author),
+            into_answer_response_deadline(answer_settings.response_deadline_hours),
+            answer_settings.quiz_enabled,
+            answer_settings.show_quiz_score_to_author,
+            answer_settings
+                .lock_message_thread_on_completion
+                .map(MessageThreadLockPolicy::from_lock_on_completion),
+            answer_settings
+                .hold_temporary_answers_for_review
+                .map(TemporaryAnswerModerationPolicy::from_hold_for_review),
             &user,
         )
         .await
@@ -437,6 +455,55 @@ pub async fn get_form_handler(
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/forms/feed.atom",
+    summary = "回答受付中の公開フォームの Atom フィード",
+    description = "ログインせずに閲覧でき、回答を受け付けているフォームを Atom 形式で返します。回答の受付が始まるとエントリーが更新され、終わるとフィードから外れます。\n\n`If-None-Match` を指定した条件付きリクエストに対応しており、フィードが変わっていなければ `304 Not Modified` を返します。`ETag` はフィードの内容から求めるため、フォームの公開範囲の変更や削除でも変わります。",
+    params(
+        ("If-None-Match" = Option<String>, Header, description = "前回のレスポンスの `ETag`"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Atom フィード",
+            content_type = "application/atom+xml",
+            body = String,
+            headers(("ETag" = String)),
+        ),
+        (status = 304, description = "前回のレスポンスからフィードが変わっていない"),
+        InternalServerError,
+    ),
+    tag = "Forms"
+)]
+pub async fn public_form_feed_handler(
+    State(repository): State<RealInfrastructureRepository>,
+    if_none_match: Result<TypedHeader<IfNoneMatch>, TypedHeaderRejection>,
+) -> Result<Response, Response> {
+    let feed = build_form_use_case(&repository)
+        .public_form_feed(Utc::now())
+        .await
+        .map_err(handle_error)?;
+    let atom = render_atom(&feed, &FRONTEND.url);
+
+    // フォームが非公開になったり削除されたりしても更新日時は進まないため、
+    // Last-Modified は返さず、描画したフィードそのものから ETag を求める
+    let etag = format!("\"{}\"", sha256::digest(atom.as_str()))
+        .parse::<ETag>()
+        .expect("a quoted hex digest is a valid entity tag");
+
+    let not_modified = if_none_match
+        .is_ok_and(|TypedHeader(if_none_match)| !if_none_match.precondition_passes(&etag));
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        ([(header::CONTENT_TYPE, ATOM_CONTENT_TYPE)], atom).into_response()
+    };
+    response.headers_mut().typed_insert(etag);
+
+    Ok(response)
+}
+
 #[utoipa::path(
     post,
     path = "/forms/{form_id}/archive",
@@ -540,6 +607,15 @@ pub async fn update_form_handler(
             answer_settings
                 .hide_author
                 .map(AnswerAuthorPublicationPolicy::from_hide_author),
+            into_answer_response_deadline(answer_settings.response_deadline_hours),
+            answer_settings.quiz_enabled,
+            answer_settings.show_quiz_score_to_author,
+            answer_settings
+                .lock_message_thread_on_completion
+                .map(MessageThreadLockPolicy::from_lock_on_completion),
+            answer_settings
+                .hold_temporary_answers_for_review
+                .map(TemporaryAnswerModerationPolicy::from_hold_for_review),
             questions,
             labels,
         )
@@ -1,15 +1,21 @@
 use axum::{
     Extension, Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{StatusCode, header},
     response::IntoResponse,
 };
-use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
 use domain::{
     account::models::AccountUser,
     form::{
         answer::AnswerId,
-        message::{MessageBody, MessageHistoryPagePosition, MessageId},
+        message::{
+            MAX_MESSAGE_ATTACHMENT_SIZE, MAX_MESSAGE_ATTACHMENTS, MessageAttachmentId, MessageBody,
+            MessageHistoryPagePosition, MessageId,
+        },
     },
     pagination::{PageLimit, PageRequest},
     repository::Repositories,
@@ -18,15 +24,18 @@ use itertools::Itertools;
 use resource::repository::RealInfrastructureRepository;
 use serde_json::json;
 use std::sync::Arc;
-use usecase::forms::message::MessageUseCase;
+use usecase::{forms::message::MessageUseCase, models::MessageAttachmentUpload};
 
 use crate::api::global_discord_webhook::APPLICATION_EVENT_PUBLISHER;
 use crate::schemas::error_responses::*;
 use crate::{
     handlers::error_handler::handle_error,
     schemas::form::{
-        form_request_schemas::{HistoryListQuery, MessageUpdateSchema, PostedMessageSchema},
-        form_response_schemas::{MessageContentSchema, MessageHistoryPageResponse, SenderSchema},
+        form_request_schemas::{
+            HistoryListQuery, MessageReadSchema, MessageUpdateSchema,
+            PostedMessageAttachmentSchema, PostedMessageSchema,
+        },
+        form_response_schemas::{MessageContentSchema, MessageHistoryPageResponse},
     },
 };
 
@@ -93,6 +102,58 @@ fn encode_history_cursor(position: MessageHistoryPagePosition) -> Result<String,
     })?;
     Ok(URL_SAFE_NO_PAD.encode(bytes))
 }
+/// メッセージ投稿のリクエストボディの上限。添付ファイルは Base64 で膨らむため、その分を見込む。
+pub const POST_MESSAGE_BODY_LIMIT: usize =
+    MAX_MESSAGE_ATTACHMENTS * (MAX_MESSAGE_ATTACHMENT_SIZE as usize).div_ceil(3) * 4 + 1024 * 1024;
+
+fn decode_attachments(
+    attachments: Vec<PostedMessageAttachmentSchema>,
+) -> Result<Vec<MessageAttachmentUpload>, Error> {
+    attachments
+        .into_iter()
+        .map(|attachment| {
+            let content = STANDARD.decode(attachment.content).map_err(|_| {
+                Error::from(PresentationError::JsonRejection {
+                    cause: format!("Attachment {} is not valid base64.", attachment.file_name),
+                })
+            })?;
+
+            Ok(MessageAttachmentUpload {
+                file_name: attachment.file_name,
+                content_type: attachment.content_type,
+                content,
+            })
+        })
+        .collect()
+}
+
+/// RFC 6266 の `filename*` パラメータを付けた Content-Disposition を組み立てる。
+fn attachment_content_disposition(file_name: &str) -> String {
+    let encoded = file_name
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect::<String>();
+
+    format!("attachment; filename*=UTF-8''{encoded}")
+}
+
 use axum::extract::rejection::{JsonRejection, PathRejection};
 use axum::response::Response;
 use domain::form::models::FormId;
@@ -170,6 +231,7 @@ pub async fn get_message_history(
         Unauthorized,
         Forbidden,
         NotFound,
+        Conflict,
         UnprocessableEntity,
         InternalServerError,
     ),
@@ -194,17 +256,48 @@ pub async fn post_message_handler<N: Notificator>(
     let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
     let Json(message) = json.map_err_to_error().map_err(handle_error)?;
 
-    form_message_use_case
-        .post_message(
-            &user,
-            form_id,
-            MessageBody::new(message.body),
-            answer_id,
-            &state.notificator,
-            state.repository.form_submission_restriction_repository(),
-        )
-        .await
-        .map_err(handle_error)?;
+    match (message.body, message.template_id) {
+        (Some(body), None) => {
+            let attachments = decode_attachments(message.attachments).map_err(handle_error)?;
+            form_message_use_case
+                .post_message(
+                    &user,
+                    form_id,
+                    MessageBody::new(body),
+                    attachments,
+                    answer_id,
+                    &state.notificator,
+                    state.repository.form_submission_restriction_repository(),
+                    state.repository.mention_repository(),
+                    state.repository.message_attachment_storage(),
+                    state.repository.content_filter_repository(),
+                )
+                .await
+        }
+        (None, Some(_)) if !message.attachments.is_empty() => {
+            Err(Error::from(PresentationError::JsonRejection {
+                cause: "Attachments cannot be posted with template_id.".to_string(),
+            }))
+        }
+        (None, Some(template_id)) => {
+            form_message_use_case
+                .post_message_from_template(
+                    &user,
+                    form_id,
+                    template_id,
+                    answer_id,
+                    &state.notificator,
+                    state.repository.form_submission_restriction_repository(),
+                    state.repository.message_template_repository(),
+                    state.repository.mention_repository(),
+                )
+                .await
+        }
+        _ => Err(Error::from(PresentationError::JsonRejection {
+            cause: "Exactly one of body or template_id must be specified.".to_string(),
+        })),
+    }
+    .map_err(handle_error)?;
 
     Ok(StatusCode::OK.into_response())
 }
@@ -256,6 +349,7 @@ pub async fn update_message_handler(
             answer_id,
             &message_id,
             body_schema.body.map(MessageBody::new),
+            repository.content_filter_repository(),
         )
         .await
         .map_err(handle_error)?;
@@ -300,27 +394,117 @@ pub async fn get_messages_handler(
     let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
 
     let messages = form_message_use_case
-        .get_messages(&user, form_id, answer_id)
+        .get_messages(
+            &user,
+            form_id,
+            answer_id,
+            repository.message_read_repository(),
+        )
         .await
         .map_err(handle_error)?;
 
     Ok(GetMessagesResponse::Ok(
-        messages
-            .into_iter()
-            .map(|message_with_sender| MessageContentSchema {
-                id: message_with_sender.message.id().into_inner(),
-                body: message_with_sender.message.body().as_str().to_owned(),
-                sender: SenderSchema {
-                    uuid: message_with_sender.sender.id().to_string(),
-                    name: message_with_sender.sender.name().to_owned(),
-                    role: message_with_sender.sender.role().to_string(),
-                },
-                timestamp: message_with_sender.message.timestamp().to_owned(),
-            })
-            .collect_vec(),
+        messages.into_iter().map(Into::into).collect_vec(),
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/answers/{answer_id}/messages/read",
+    summary = "メッセージを既読にする",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    request_body = MessageReadSchema,
+    responses(
+        (status = 204, description = "There is no content to send for this request, but the headers may be useful."),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn mark_messages_as_read_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    json: Result<Json<MessageReadSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let form_message_use_case = MessageUseCase {
+        notification_repository: repository.notification_repository(),
+        active_form_repository: repository.active_form_repository(),
+        user_repository: repository.user_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        message_thread_repository: repository.message_thread_repository(),
+        application_event_publisher: Some(&APPLICATION_EVENT_PUBLISHER),
+    };
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(read_schema) = json.map_err_to_error().map_err(handle_error)?;
+
+    form_message_use_case
+        .mark_messages_as_read(
+            &user,
+            form_id,
+            answer_id,
+            read_schema.message_id,
+            repository.message_read_repository(),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/forms/{form_id}/answers/{answer_id}/messages/reopen",
+    summary = "ロックされたメッセージスレッドを再開する",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    responses(
+        (status = 204, description = "There is no content to send for this request, but the headers may be useful."),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn reopen_message_thread_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let form_message_use_case = MessageUseCase {
+        notification_repository: repository.notification_repository(),
+        active_form_repository: repository.active_form_repository(),
+        user_repository: repository.user_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        message_thread_repository: repository.message_thread_repository(),
+        application_event_publisher: Some(&APPLICATION_EVENT_PUBLISHER),
+    };
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    form_message_use_case
+        .reopen_message_thread(&user, form_id, answer_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
 #[utoipa::path(
     delete,
     path = "/forms/{form_id}/answers/{answer_id}/messages/{message_id}",
@@ -364,3 +548,82 @@ pub async fn delete_message_handler(
 
     Ok(StatusCode::NO_CONTENT.into_response())
 }
+
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/{answer_id}/messages/attachments/{attachment_id}",
+    summary = "メッセージの添付ファイルを取得",
+    description = "回答のメッセージスレッドを閲覧できるユーザーのみ取得できます。",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+        ("attachment_id" = String, Path, description = "Attachment ID"),
+    ),
+    responses(
+        (status = 200, description = "The request has succeeded.", content_type = "application/octet-stream", body = Vec<u8>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn get_message_attachment_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId, MessageAttachmentId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let form_message_use_case = MessageUseCase {
+        notification_repository: repository.notification_repository(),
+        active_form_repository: repository.active_form_repository(),
+        user_repository: repository.user_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        message_thread_repository: repository.message_thread_repository(),
+        application_event_publisher: Some(&APPLICATION_EVENT_PUBLISHER),
+    };
+
+    let Path((form_id, answer_id, attachment_id)) =
+        path.map_err_to_error().map_err(handle_error)?;
+
+    let (attachment, content) = form_message_use_case
+        .get_message_attachment(
+            &user,
+            form_id,
+            answer_id,
+            attachment_id,
+            repository.message_attachment_storage(),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.content_type().to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                attachment_content_disposition(attachment.file_name()),
+            ),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_owned()),
+        ],
+        content,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attachment_content_disposition_percent_encodes_non_token_characters() {
+        assert_eq!(
+            attachment_content_disposition("証拠 1.png"),
+            "attachment; filename*=UTF-8''%E8%A8%BC%E6%8B%A0%201.png"
+        );
+        assert_eq!(
+            attachment_content_disposition("a\"b;c.txt"),
+            "attachment; filename*=UTF-8''a%22b%3Bc.txt"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    form::message_template::{MessageTemplateBody, MessageTemplateId, MessageTemplateName},
+};
+use errors::ErrorExtra;
+use resource::repository::RealInfrastructureRepository;
+use usecase::forms::message_template::MessageTemplateUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    schemas::{
+        error_responses::{
+            BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity,
+        },
+        form::{
+            form_request_schemas::{MessageTemplateCreateSchema, MessageTemplateUpdateSchema},
+            form_response_schemas::MessageTemplateResponse,
+        },
+    },
+};
+
+#[derive(utoipa::IntoResponses)]
+pub enum CreateMessageTemplateResponse {
+    #[response(
+        status = 201,
+        description = "The request has succeeded and a new resource has been created as a result."
+    )]
+    Created(MessageTemplateResponse),
+}
+
+impl IntoResponse for CreateMessageTemplateResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Created(body) => (
+                StatusCode::CREATED,
+                [(
+                    header::LOCATION,
+                    HeaderValue::from_str(body.id.as_str()).unwrap(),
+                )],
+                Json(body),
+            )
+                .into_response(),
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/message-templates",
+    summary = "メッセージテンプレートを作成する",
+    request_body = MessageTemplateCreateSchema,
+    responses(
+        CreateMessageTemplateResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn create_message_template_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<MessageTemplateCreateSchema>, JsonRejection>,
+) -> Result<CreateMessageTemplateResponse, Response> {
+    let use_case = MessageTemplateUseCase {
+        message_template_repository: repository.message_template_repository(),
+    };
+    let Json(template) = json.map_err_to_error().map_err(handle_error)?;
+
+    let created = use_case
+        .create_template(
+            &user,
+            MessageTemplateName::new(template.name),
+            MessageTemplateBody::new(template.body),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(CreateMessageTemplateResponse::Created(created.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/message-templates",
+    summary = "メッセージテンプレートの一覧を取得する",
+    responses(
+        (status = 200, body = [MessageTemplateResponse]),
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn get_message_templates_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+) -> Result<Json<Vec<MessageTemplateResponse>>, Response> {
+    let use_case = MessageTemplateUseCase {
+        message_template_repository: repository.message_template_repository(),
+    };
+
+    let templates = use_case.get_templates(&user).await.map_err(handle_error)?;
+
+    Ok(Json(templates.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/message-templates/{template_id}",
+    summary = "メッセージテンプレートを更新する",
+    params(("template_id" = String, Path, description = "Message template ID")),
+    request_body = MessageTemplateUpdateSchema,
+    responses(
+        (status = 200, body = MessageTemplateResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn update_message_template_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<MessageTemplateId>, PathRejection>,
+    json: Result<Json<MessageTemplateUpdateSchema>, JsonRejection>,
+) -> Result<Json<MessageTemplateResponse>, Response> {
+    let use_case = MessageTemplateUseCase {
+        message_template_repository: repository.message_template_repository(),
+    };
+    let Path(template_id) = path.map_err_to_error().map_err(handle_error)?;
+    let Json(template) = json.map_err_to_error().map_err(handle_error)?;
+
+    let updated = use_case
+        .update_template(
+            &user,
+            template_id,
+            template.name.map(MessageTemplateName::new),
+            template.body.map(MessageTemplateBody::new),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(updated.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/message-templates/{template_id}",
+    summary = "メッセージテンプレートを削除する",
+    params(("template_id" = String, Path, description = "Message template ID")),
+    responses(
+        (status = 204, description = "There is no content to send for this request, but the headers may be useful."),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Messages"
+)]
+pub async fn delete_message_template_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<MessageTemplateId>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let use_case = MessageTemplateUseCase {
+        message_template_repository: repository.message_template_repository(),
+    };
+    let Path(template_id) = path.map_err_to_error().map_err(handle_error)?;
+
+    use_case
+        .delete_template(&user, template_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,245 @@
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    form::{
+        answer::AnswerId, message::MessageBody, models::FormId,
+        temporary_answer_access::TemporaryAnswerAccessToken,
+        temporary_answer_claim::TemporaryAnswerClaimCode,
+    },
+    repository::Repositories,
+};
+use errors::{Error, ErrorExtra, usecase::UseCaseError};
+use itertools::Itertools;
+use resource::repository::{RealInfrastructureRepository, Repository};
+use usecase::forms::temporary_answer_access::TemporaryAnswerAccessUseCase;
+
+use crate::api::global_discord_webhook::APPLICATION_EVENT_PUBLISHER;
+use crate::schemas::error_responses::*;
+use crate::{
+    handlers::{error_handler::handle_error, form::message_handler::GetMessagesResponse},
+    schemas::form::{
+        form_request_schemas::{TemporaryAnswerClaimSchema, TemporaryAnswerMessageSchema},
+        form_response_schemas::{TemporaryAnswerAccessResponse, TemporaryAnswerClaimResponse},
+    },
+};
+
+/// 回答のアクセスリンク用のトークンを受け取るヘッダー。
+///
+/// アクセスログに残らないよう、トークンは URL に含めない。
+pub const TEMPORARY_ANSWER_TOKEN_HEADER: &str = "x-seichi-temporary-answer-token";
+
+fn access_token(headers: &HeaderMap) -> Result<TemporaryAnswerAccessToken, Error> {
+    headers
+        .get(TEMPORARY_ANSWER_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| TemporaryAnswerAccessToken::try_from(value.to_owned()).ok())
+        .ok_or(Error::from(UseCaseError::TemporaryAnswerAccessNotFound))
+}
+
+type ResourceRepository = Repository<resource::database::connection::ConnectionPool>;
+type ResourceTemporaryAnswerAccessUseCase<'a> = TemporaryAnswerAccessUseCase<
+    'a,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+    ResourceRepository,
+>;
+
+fn build_use_case(
+    repository: &RealInfrastructureRepository,
+) -> ResourceTemporaryAnswerAccessUseCase<'_> {
+    TemporaryAnswerAccessUseCase {
+        temporary_answer_access_repository: repository.temporary_answer_access_repository(),
+        active_form_repository: repository.active_form_repository(),
+        user_repository: repository.user_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        message_thread_repository: repository.message_thread_repository(),
+        application_event_publisher: Some(&APPLICATION_EVENT_PUBLISHER),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/temporary-answer-access",
+    summary = "アクセスリンクから未ログイン回答の状況を取得する",
+    params(
+        ("X-Seichi-Temporary-Answer-Token" = String, Header, description = "回答時に発行されたアクセスリンク用のトークン"),
+    ),
+    responses(
+        (status = 200, description = "The request has succeeded.", body = TemporaryAnswerAccessResponse),
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    tag = "Answers"
+)]
+pub async fn get_temporary_answer_access_handler(
+    State(repository): State<RealInfrastructureRepository>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, Response> {
+    let token = access_token(&headers).map_err(handle_error)?;
+    let (form, answer) = build_use_case(&repository)
+        .get_answer(&token)
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(TemporaryAnswerAccessResponse::new(&form, &answer)),
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/temporary-answer-access/messages",
+    summary = "アクセスリンクから未ログイン回答のメッセージを取得する",
+    params(
+        ("X-Seichi-Temporary-Answer-Token" = String, Header, description = "回答時に発行されたアクセスリンク用のトークン"),
+    ),
+    responses(
+        GetMessagesResponse,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    tag = "Messages"
+)]
+pub async fn get_temporary_answer_messages_handler(
+    State(repository): State<RealInfrastructureRepository>,
+    headers: HeaderMap,
+) -> Result<GetMessagesResponse, Response> {
+    let token = access_token(&headers).map_err(handle_error)?;
+    let messages = build_use_case(&repository)
+        .get_messages(&token, repository.message_read_repository())
+        .await
+        .map_err(handle_error)?;
+
+    Ok(GetMessagesResponse::Ok(
+        messages.into_iter().map(Into::into).collect_vec(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/temporary-answer-access/messages",
+    summary = "アクセスリンクから未ログイン回答にメッセージを送る",
+    params(
+        ("X-Seichi-Temporary-Answer-Token" = String, Header, description = "回答時に発行されたアクセスリンク用のトークン"),
+        ("X-Seichi-Turnstile-Token" = String, Header, description = "Cloudflare Turnstile token"),
+    ),
+    request_body = TemporaryAnswerMessageSchema,
+    responses(
+        (status = 204, description = "There is no content to send for this request, but the headers may be useful."),
+        BadRequest,
+        Forbidden,
+        NotFound,
+        Conflict,
+        UnprocessableEntity,
+        InternalServerError,
+        ServiceUnavailable,
+    ),
+    tag = "Messages"
+)]
+pub async fn post_temporary_answer_message_handler(
+    State(repository): State<RealInfrastructureRepository>,
+    headers: HeaderMap,
+    json: Result<Json<TemporaryAnswerMessageSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let token = access_token(&headers).map_err(handle_error)?;
+    let Json(message) = json.map_err_to_error().map_err(handle_error)?;
+
+    build_use_case(&repository)
+        .post_message(
+            &token,
+            MessageBody::new(message.body),
+            repository.content_filter_repository(),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/forms/{form_id}/answers/{answer_id}/temporary-access",
+    summary = "未ログイン回答のアクセスリンクを取り消す",
+    params(
+        ("form_id" = String, Path, description = "Form ID"),
+        ("answer_id" = String, Path, description = "Answer ID"),
+    ),
+    responses(
+        (status = 204, description = "There is no content to send for this request, but the headers may be useful."),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn revoke_temporary_answer_access_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+
+    build_use_case(&repository)
+        .revoke(&user, form_id, answer_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/temporary-answer-claims",
+    summary = "ログインせずに送った回答を、引き継ぎコードで自分のアカウントに引き継ぐ",
+    request_body = TemporaryAnswerClaimSchema,
+    responses(
+        (status = 200, description = "The request has succeeded.", body = TemporaryAnswerClaimResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Answers"
+)]
+pub async fn claim_temporary_answer_handler(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<TemporaryAnswerClaimSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Json(schema) = json.map_err_to_error().map_err(handle_error)?;
+    let code = TemporaryAnswerClaimCode::try_from(schema.code)
+        .map_err(|_| handle_error(UseCaseError::TemporaryAnswerClaimCodeNotFound.into()))?;
+
+    let (form_id, answer_id) = build_use_case(&repository)
+        .claim(&user, &code, repository.temporary_answer_claim_repository())
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(TemporaryAnswerClaimResponse {
+            form_id: form_id.into_inner(),
+            answer_id: answer_id.into_inner(),
+        }),
+    )
+        .into_response())
+}
@@ -1,16 +1,18 @@
 use std::sync::Arc;
 
-use axum::extract::rejection::QueryRejection;
+use axum::extract::rejection::{PathRejection, QueryRejection};
 use axum::response::Response;
 use axum::{
     Extension, Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
 use domain::{
-    account::models::AccountUser, repository::Repositories,
-    search::models::SearchableFieldsWithOperation,
+    account::models::AccountUser,
+    form::{answer::AnswerId, models::FormId},
+    repository::Repositories,
+    search::models::{SearchableFieldsWithOperation, SimilarAnswerLimit},
 };
 use errors::{Error, ErrorExtra, presentation::PresentationError};
 use resource::repository::RealInfrastructureRepository;
@@ -22,7 +24,8 @@ use crate::schemas::error_responses::*;
 use crate::{
     handlers::error_handler::handle_error,
     schemas::search_schemas::{
-        AnswerSearchQuery, AnswerSearchResult, CrossSearchResult, SearchQuery, UserSearchResult,
+        AnswerSearchQuery, AnswerSearchResult, CrossSearchResult, SearchQuery, SimilarAnswerQuery,
+        UserSearchResult,
     },
 };
 
@@ -110,6 +113,7 @@ pub async fn cross_search(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     let query = required_query(query).map_err(handle_error)?;
@@ -153,6 +157,7 @@ pub async fn search_users(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     let query = required_query(query).map_err(handle_error)?;
@@ -199,6 +204,7 @@ pub async fn search_answers(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     let Query(search_query) = query.map_err_to_error().map_err(handle_error)?;
@@ -219,6 +225,63 @@ pub async fn search_answers(
     Ok(AnswerSearchResponse::Ok(answers.into()))
 }
 
+#[utoipa::path(
+    get,
+    path = "/forms/{form_id}/answers/{answer_id}/similar-answers",
+    summary = "回答に類似する回答の候補を取得する",
+    description = "同じフォームに投稿された回答から、タイトルと回答内容が似ているものを類似度順に返します。重複報告の確認に使います。",
+    params(
+        ("form_id" = String, Path, format = "uuid", description = "Form ID"),
+        ("answer_id" = String, Path, format = "uuid", description = "Answer ID"),
+        SimilarAnswerQuery,
+    ),
+    responses(
+        AnswerSearchResponse,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Search"
+)]
+pub async fn similar_answers(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<(FormId, AnswerId)>, PathRejection>,
+    query: Result<Query<SimilarAnswerQuery>, QueryRejection>,
+) -> Result<AnswerSearchResponse, Response> {
+    let search_use_case = SearchUseCase {
+        search_repository: repository.search_repository(),
+        active_form_repository: repository.active_form_repository(),
+        form_answer_label_repository: repository.answer_label_repository(),
+        form_label_repository: repository.form_label_repository(),
+        user_repository: repository.user_repository(),
+        answer_entry_repository: repository.answer_entry_repository(),
+        comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
+    };
+
+    let Path((form_id, answer_id)) = path.map_err_to_error().map_err(handle_error)?;
+    let Query(similar_query) = query.map_err_to_error().map_err(handle_error)?;
+    let limit = match similar_query.limit {
+        Some(limit) => SimilarAnswerLimit::try_new(limit).ok_or_else(|| {
+            handle_error(Error::from(PresentationError::QueryRejection {
+                cause: format!("Invalid limit: {limit}."),
+            }))
+        })?,
+        None => SimilarAnswerLimit::default(),
+    };
+
+    let answers = search_use_case
+        .similar_answers(&user, form_id, answer_id, limit)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(AnswerSearchResponse::Ok(answers.into()))
+}
+
 pub async fn start_sync(
     repository: RealInfrastructureRepository,
     receiver: Receiver<SearchableFieldsWithOperation>,
@@ -232,6 +295,7 @@ pub async fn start_sync(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     search_use_case
@@ -251,6 +315,7 @@ pub async fn start_watch_out_of_sync(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     search_use_case
@@ -269,6 +334,7 @@ pub async fn initialize_search_engine(
         user_repository: repository.user_repository(),
         answer_entry_repository: repository.answer_entry_repository(),
         comment_thread_repository: repository.comment_thread_repository(),
+        announcement_repository: repository.announcement_repository(),
     };
 
     search_use_case.initialize_search_engine().await
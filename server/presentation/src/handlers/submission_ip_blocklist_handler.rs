@@ -0,0 +1,135 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State, rejection::JsonRejection, rejection::PathRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use domain::{
+    account::models::AccountUser,
+    auth::Actor,
+    submission_ip_blocklist::{BlockedIpRange, SubmissionIpBlockId},
+};
+use errors::ErrorExtra;
+use resource::repository::RealInfrastructureRepository;
+use types::non_empty_string::NonEmptyString;
+use usecase::submission_ip_blocklist::SubmissionIpBlocklistUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    schemas::{
+        error_responses::{
+            BadRequest, Conflict, Forbidden, InternalServerError, NotFound, Unauthorized,
+        },
+        submission_ip_blocklist::{SubmissionIpBlockCreateSchema, SubmissionIpBlockResponse},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/settings/submission-ip-blocklist",
+    summary = "未ログインでの送信を拒否する IP アドレスの一覧",
+    responses(
+        (status = 200, body = [SubmissionIpBlockResponse]),
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Settings"
+)]
+pub async fn list_submission_ip_blocks(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+) -> Result<Json<Vec<SubmissionIpBlockResponse>>, Response> {
+    let usecase = SubmissionIpBlocklistUseCase {
+        repository: repository.submission_ip_blocklist_repository(),
+    };
+    let blocks = usecase
+        .list(&Actor::from(user))
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(blocks.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/settings/submission-ip-blocklist",
+    summary = "未ログインでの送信を拒否する IP アドレスの追加",
+    description = "一時回答の作成と、回答のアクセスリンクからのメッセージの送信を、指定した範囲から受け付けなくなります。",
+    request_body = SubmissionIpBlockCreateSchema,
+    responses(
+        (status = 201, body = SubmissionIpBlockResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        Conflict,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Settings"
+)]
+pub async fn create_submission_ip_block(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    json: Result<Json<SubmissionIpBlockCreateSchema>, JsonRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Json(request) = json.map_err_to_error().map_err(handle_error)?;
+    let range = request
+        .ip_range
+        .parse::<BlockedIpRange>()
+        .map_err(errors::Error::from)
+        .map_err(handle_error)?;
+    let reason = request
+        .reason
+        .filter(|reason| !reason.trim().is_empty())
+        .map(NonEmptyString::try_new)
+        .transpose()
+        .map_err(errors::Error::from)
+        .map_err(handle_error)?;
+    let usecase = SubmissionIpBlocklistUseCase {
+        repository: repository.submission_ip_blocklist_repository(),
+    };
+    let block = usecase
+        .add(&user, range, reason)
+        .await
+        .map_err(handle_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SubmissionIpBlockResponse::from(block)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/settings/submission-ip-blocklist/{block_id}",
+    summary = "未ログインでの送信を拒否する IP アドレスの削除",
+    params(("block_id" = String, Path, description = "Blocked IP range ID")),
+    responses(
+        (status = 204),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Settings"
+)]
+pub async fn delete_submission_ip_block(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    path: Result<Path<SubmissionIpBlockId>, PathRejection>,
+) -> Result<impl IntoResponse, Response> {
+    let Path(block_id) = path.map_err_to_error().map_err(handle_error)?;
+    let usecase = SubmissionIpBlocklistUseCase {
+        repository: repository.submission_ip_blocklist_repository(),
+    };
+    usecase
+        .remove(&Actor::from(user), block_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,53 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State, rejection::QueryRejection},
+    response::Response,
+};
+use domain::{account::models::AccountUser, auth::Actor};
+use errors::ErrorExtra;
+use resource::repository::RealInfrastructureRepository;
+use usecase::support_metrics::SupportMetricsUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    schemas::{
+        error_responses::{
+            BadRequest, Forbidden, InternalServerError, Unauthorized, UnprocessableEntity,
+        },
+        support_metrics::{SupportMetricsQuery, SupportMetricsResponse},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/support/metrics",
+    summary = "運営対応状況の集計",
+    description = "期間内に送信された回答について、初回対応と完了までの所要時間の中央値を返します。あわせて、フォームごとの未完了の回答件数と、期間内の運営メンバーごとの対応件数を返します。期間は 366 日までです。管理者のみ利用できます。",
+    params(SupportMetricsQuery),
+    responses(
+        (status = 200, body = SupportMetricsResponse),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(("bearer" = [])),
+    tag = "Support"
+)]
+pub async fn get_support_metrics(
+    Extension(user): Extension<AccountUser>,
+    State(repository): State<RealInfrastructureRepository>,
+    query: Result<Query<SupportMetricsQuery>, QueryRejection>,
+) -> Result<Json<SupportMetricsResponse>, Response> {
+    let Query(query) = query.map_err_to_error().map_err(handle_error)?;
+    let usecase = SupportMetricsUseCase {
+        repository: repository.support_metrics_repository(),
+    };
+    let metrics = usecase
+        .get(&Actor::from(user), query.from, query.to)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(Json(metrics.into()))
+}
@@ -0,0 +1,162 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{Method, Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use resource::repository::RealInfrastructureRepository;
+use usecase::submission_ip_blocklist::SubmissionIpBlocklistUseCase;
+
+use crate::{
+    handlers::error_handler::handle_error,
+    rate_limit::{ClientIpResolution, resolve_client_ip},
+    schemas::error_response::ErrorResponse,
+};
+
+const API_PREFIX: &str = "/api/v1";
+
+#[derive(Clone)]
+pub struct IpBlocklistState {
+    pub repository: RealInfrastructureRepository,
+    pub proxy_secret: Option<String>,
+}
+
+impl IpBlocklistState {
+    pub fn new(repository: RealInfrastructureRepository, proxy_secret: Option<String>) -> Self {
+        Self {
+            repository,
+            proxy_secret: proxy_secret.filter(|secret| !secret.is_empty()),
+        }
+    }
+}
+
+/// このミドルウェアが解決したクライアントの IP アドレス。
+///
+/// 一時回答の送信元を記録するため、ハンドラーへリクエストの extension として渡す。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+fn request_path_without_api_prefix(path: &str) -> Option<&str> {
+    if path == API_PREFIX {
+        Some("/")
+    } else if let Some(stripped) = path.strip_prefix(API_PREFIX) {
+        stripped.starts_with('/').then_some(stripped)
+    } else if path.starts_with('/') {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// ブロックリストで拒否する、ログインせずに内容を送るリクエストか。
+fn is_anonymous_submission(method: &Method, path: &str) -> bool {
+    if *method != Method::POST {
+        return false;
+    }
+
+    let Some(path) = request_path_without_api_prefix(path) else {
+        return false;
+    };
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    matches!(
+        segments.as_slice(),
+        ["forms", _, "temporary-answers"] | ["temporary-answer-access", "messages"]
+    )
+}
+
+fn blocked_response() -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        [(header::CONTENT_TYPE, "application/problem+json")],
+        axum::Json(ErrorResponse {
+            problem_type: "about:blank".to_owned(),
+            title: "Forbidden".to_owned(),
+            status: StatusCode::FORBIDDEN.as_u16(),
+            detail: "Submissions from this network are not accepted.".to_owned(),
+            error_code: "SUBMISSION_IP_BLOCKED".to_owned(),
+            restriction: None,
+        }),
+    )
+        .into_response()
+}
+
+/// 管理者が登録した IP アドレスの範囲からの、ログインせずに行う送信を拒否する。
+///
+/// 拒否したリクエストで Turnstile の検証やレートリミットの枠を使わないよう、
+/// それらのミドルウェアより外側に置く。
+pub async fn middleware(
+    State(state): State<IpBlocklistState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let tcp_peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|connect_info| connect_info.0.ip());
+    let client_ip =
+        match resolve_client_ip(request.headers(), tcp_peer, state.proxy_secret.as_deref()) {
+            ClientIpResolution::Canonical(ip) | ClientIpResolution::TcpPeer(ip) => Some(ip),
+            ClientIpResolution::Unavailable => None,
+        };
+
+    if let Some(ip) = client_ip {
+        request.extensions_mut().insert(ClientIp(ip));
+    }
+
+    let Some(ip) =
+        client_ip.filter(|_| is_anonymous_submission(request.method(), request.uri().path()))
+    else {
+        return next.run(request).await;
+    };
+
+    let usecase = SubmissionIpBlocklistUseCase {
+        repository: state.repository.submission_ip_blocklist_repository(),
+    };
+    match usecase.is_blocked(ip).await {
+        Ok(false) => next.run(request).await,
+        Ok(true) => {
+            tracing::warn!("submission from a blocked IP address was rejected");
+            blocked_response()
+        }
+        Err(error) => handle_error(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_anonymous_submissions_are_checked() {
+        let form_id = "0190f0f6-8f3c-7a7e-9c3a-1f2e3d4c5b6a";
+
+        assert!(is_anonymous_submission(
+            &Method::POST,
+            &format!("/forms/{form_id}/temporary-answers")
+        ));
+        assert!(is_anonymous_submission(
+            &Method::POST,
+            &format!("/api/v1/forms/{form_id}/temporary-answers")
+        ));
+        assert!(is_anonymous_submission(
+            &Method::POST,
+            "/temporary-answer-access/messages"
+        ));
+        assert!(!is_anonymous_submission(
+            &Method::GET,
+            "/temporary-answer-access/messages"
+        ));
+        assert!(!is_anonymous_submission(&Method::POST, "/session"));
+        assert!(!is_anonymous_submission(
+            &Method::POST,
+            &format!("/api/v10/forms/{form_id}/temporary-answers")
+        ));
+    }
+}
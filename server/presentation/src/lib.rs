@@ -1,6 +1,7 @@
 pub mod api;
 pub mod auth;
 pub mod handlers;
+pub mod ip_blocklist;
 pub mod rate_limit;
 pub mod schemas;
 pub mod turnstile;
@@ -23,6 +23,9 @@ const TEMPORARY_IP_HOURLY_LIMIT: u64 = 30;
 const TEMPORARY_FORM_HOURLY_LIMIT: u64 = 10;
 const TEMPORARY_IP_BURST_LIMIT: u64 = 5;
 const SESSION_CREATE_IP_HOURLY_LIMIT: u64 = 10;
+const TEMPORARY_ACCESS_GET_LIMIT: u64 = 30;
+const TEMPORARY_MESSAGE_IP_HOURLY_LIMIT: u64 = 20;
+const TEMPORARY_MESSAGE_IP_BURST_LIMIT: u64 = 5;
 const MINUTE: u64 = 60;
 const HOUR: u64 = 60 * MINUTE;
 
@@ -93,6 +96,8 @@ pub enum RateLimitPlan {
     AuthenticatedWrite { user_id: String },
     TemporaryAnswer { ip: IpAddr, form_id: Uuid },
     SessionCreate { ip: IpAddr },
+    TemporaryAnswerAccessGet { ip: IpAddr },
+    TemporaryAnswerMessage { ip: IpAddr },
     Skip,
 }
 
@@ -104,6 +109,8 @@ impl RateLimitPlan {
             Self::AuthenticatedWrite { .. } => "authenticated_write",
             Self::TemporaryAnswer { .. } => "temporary_answer",
             Self::SessionCreate { .. } => "session_create",
+            Self::TemporaryAnswerAccessGet { .. } => "temporary_answer_access_get",
+            Self::TemporaryAnswerMessage { .. } => "temporary_answer_message",
             Self::Skip => "skip",
         }
     }
@@ -153,6 +160,23 @@ impl RateLimitPlan {
                 SESSION_CREATE_IP_HOURLY_LIMIT,
                 HOUR,
             )],
+            Self::TemporaryAnswerAccessGet { ip } => vec![RateLimitQuota::new(
+                format!("rl:v1:temporary-access:get:{ip}"),
+                TEMPORARY_ACCESS_GET_LIMIT,
+                MINUTE,
+            )],
+            Self::TemporaryAnswerMessage { ip } => vec![
+                RateLimitQuota::new(
+                    format!("rl:v1:temporary-access:message:{ip}"),
+                    TEMPORARY_MESSAGE_IP_HOURLY_LIMIT,
+                    HOUR,
+                ),
+                RateLimitQuota::new(
+                    format!("rl:v1:temporary-access:message-burst:{ip}"),
+                    TEMPORARY_MESSAGE_IP_BURST_LIMIT,
+                    10 * MINUTE,
+                ),
+            ],
             Self::Skip => Vec::new(),
         }
     }
@@ -199,7 +223,7 @@ pub fn policy_for(
         .collect::<Vec<_>>();
 
     if *method == Method::GET
-        && segments.first() == Some(&"forms")
+        && matches!(segments.first(), Some(&"forms" | &"announcements"))
         && (segments.len() == 1 || segments.len() == 2)
     {
         return Ok(match identity {
@@ -228,6 +252,22 @@ pub fn policy_for(
         });
     }
 
+    if segments.first() == Some(&"temporary-answer-access") {
+        let Some(RateLimitIdentity::Ip(ip)) = identity else {
+            return Ok(RateLimitPlan::Skip);
+        };
+        return Ok(match (method, segments.as_slice()) {
+            (&Method::GET, ["temporary-answer-access"])
+            | (&Method::GET, ["temporary-answer-access", "messages"]) => {
+                RateLimitPlan::TemporaryAnswerAccessGet { ip: *ip }
+            }
+            (&Method::POST, ["temporary-answer-access", "messages"]) => {
+                RateLimitPlan::TemporaryAnswerMessage { ip: *ip }
+            }
+            _ => RateLimitPlan::Skip,
+        });
+    }
+
     if segments == ["session"] {
         return match *method {
             Method::POST => Ok(match identity {
@@ -450,6 +490,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn temporary_answer_access_has_its_own_read_and_message_quotas() {
+        let identity = RateLimitIdentity::Ip(ip("192.0.2.1"));
+        let read = policy_for(
+            &Method::GET,
+            "/api/v1/temporary-answer-access/messages",
+            Some(&identity),
+        )
+        .unwrap();
+        let post = policy_for(
+            &Method::POST,
+            "/temporary-answer-access/messages",
+            Some(&identity),
+        )
+        .unwrap();
+
+        assert_eq!(read.label(), "temporary_answer_access_get");
+        assert_eq!(post.label(), "temporary_answer_message");
+        assert_eq!(post.quotas().len(), 2);
+        assert!(
+            read.quotas()
+                .iter()
+                .chain(post.quotas().iter())
+                .all(|quota| quota.key.starts_with("rl:v1:temporary-access:"))
+        );
+        assert_eq!(
+            policy_for(&Method::DELETE, "/temporary-answer-access", Some(&identity)).unwrap(),
+            RateLimitPlan::Skip
+        );
+    }
+
     #[test]
     fn nested_router_stripped_form_path_still_gets_anonymous_quota() {
         let plan = policy_for(
@@ -461,6 +532,28 @@ mod tests {
         assert_eq!(plan.label(), "anonymous_get");
     }
 
+    #[test]
+    fn announcements_get_anonymous_quota() {
+        let identity = RateLimitIdentity::Ip(ip("192.0.2.1"));
+
+        assert_eq!(
+            policy_for(&Method::GET, "/api/v1/announcements", Some(&identity))
+                .unwrap()
+                .label(),
+            "anonymous_get"
+        );
+        assert_eq!(
+            policy_for(
+                &Method::GET,
+                "/api/v1/announcements/018f4f37-2f5e-7b9a-8b39-9a2f2695d7ad",
+                Some(&identity)
+            )
+            .unwrap()
+            .label(),
+            "anonymous_get"
+        );
+    }
+
     #[test]
     fn nested_router_stripped_temporary_path_still_gets_temporary_quota() {
         let plan = policy_for(
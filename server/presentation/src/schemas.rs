@@ -1,3 +1,4 @@
+pub mod announcement;
 pub mod error_response;
 pub mod error_responses;
 pub mod field_update;
@@ -6,4 +7,6 @@ pub mod global_discord_webhook;
 pub mod notification;
 pub mod search_schemas;
 pub mod session;
+pub mod submission_ip_blocklist;
+pub mod support_metrics;
 pub mod user;
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use domain::{account::models::UserGroupId, announcement::Announcement, markdown::render_html};
+use serde::{Deserialize, Serialize};
+use types::non_empty_string::NonEmptyString;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::schemas::field_update::FieldUpdate;
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AnnouncementListQuery {
+    /// 公開期間外のお知らせも含める。管理者以外が指定しても結果は変わらない。
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct AnnouncementCreateSchema {
+    #[schema(value_type = String, min_length = 1)]
+    pub title: NonEmptyString,
+    /// 本文。コメントと同じ制限された Markdown で書く。
+    #[schema(value_type = String, min_length = 1)]
+    pub body: NonEmptyString,
+    pub publish_from: DateTime<Utc>,
+    /// 公開を終える日時。省略すると削除するまで公開する。
+    #[serde(default)]
+    pub publish_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub pinned: bool,
+    /// 掲載対象のユーザーグループ。空の場合は全ユーザー (未ログインを含む) に掲載する。
+    #[serde(default)]
+    #[schema(value_type = Vec<String>)]
+    pub target_group_ids: Vec<UserGroupId>,
+}
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct AnnouncementUpdateSchema {
+    #[serde(default)]
+    #[schema(value_type = Option<String>, min_length = 1)]
+    pub title: Option<NonEmptyString>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, min_length = 1)]
+    pub body: Option<NonEmptyString>,
+    #[serde(default)]
+    pub publish_from: Option<DateTime<Utc>>,
+    /// 公開を終える日時。キーを省略すると変更なし、`null` を指定すると終了日時を解除する。
+    #[serde(default)]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub publish_until: FieldUpdate<DateTime<Utc>>,
+    #[serde(default)]
+    pub pinned: Option<bool>,
+    #[serde(default)]
+    #[schema(value_type = Option<Vec<String>>)]
+    pub target_group_ids: Option<Vec<UserGroupId>>,
+}
+
+impl AnnouncementUpdateSchema {
+    /// `publish_until` を、ユースケースが受け取る「変更なし / 解除 / 設定」の形に変換する。
+    pub fn publish_until_update(&self) -> Option<Option<DateTime<Utc>>> {
+        match self.publish_until {
+            FieldUpdate::Unchanged => None,
+            FieldUpdate::Clear => Some(None),
+            FieldUpdate::Set(publish_until) => Some(Some(publish_until)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AnnouncementResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub body_html: String,
+    pub publish_from: DateTime<Utc>,
+    pub publish_until: Option<DateTime<Utc>>,
+    pub pinned: bool,
+    #[schema(value_type = Vec<String>)]
+    pub target_group_ids: Vec<UserGroupId>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Announcement> for AnnouncementResponse {
+    fn from(announcement: Announcement) -> Self {
+        Self {
+            id: announcement.id().into_inner(),
+            title: announcement.title().as_str().to_owned(),
+            body: announcement.body().as_str().to_owned(),
+            body_html: render_html(announcement.body().as_str()),
+            publish_from: announcement.publish_period().publish_from(),
+            publish_until: announcement.publish_period().publish_until(),
+            pinned: *announcement.pinned(),
+            target_group_ids: announcement.target_groups().as_slice().to_vec(),
+            created_by: announcement.created_by().into_inner(),
+            created_at: *announcement.created_at(),
+            updated_at: *announcement.updated_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn omitted_publish_until_is_left_unchanged() {
+        let omitted: AnnouncementUpdateSchema = serde_json::from_str(r#"{"pinned":true}"#).unwrap();
+        let cleared: AnnouncementUpdateSchema =
+            serde_json::from_str(r#"{"publish_until":null}"#).unwrap();
+
+        assert_eq!(omitted.publish_until_update(), None);
+        assert_eq!(cleared.publish_until_update(), Some(None));
+    }
+}
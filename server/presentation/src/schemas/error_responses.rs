@@ -40,6 +40,16 @@ pub enum NotFound {
     NotFound(ErrorResponse),
 }
 
+#[derive(utoipa::IntoResponses)]
+pub enum Conflict {
+    #[response(
+        status = 409,
+        description = "The request conflicts with the current state of the resource.",
+        content_type = "application/problem+json"
+    )]
+    Conflict(ErrorResponse),
+}
+
 #[derive(utoipa::IntoResponses)]
 pub enum UnprocessableEntity {
     #[response(
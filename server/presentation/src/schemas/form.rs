@@ -1,2 +1,4 @@
+pub mod answer_export;
 pub mod form_request_schemas;
 pub mod form_response_schemas;
+pub mod public_form_feed;
@@ -0,0 +1,170 @@
+use domain::form::question::Question;
+use itertools::Itertools;
+use serde::Deserialize;
+use usecase::models::{AnswerDetails, PublishedAnswerAuthor};
+
+use crate::schemas::form::form_response_schemas::FormAnswer;
+
+#[derive(Deserialize, Debug, Clone, Copy, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerExportFormat {
+    Csv,
+    Ndjson,
+}
+
+impl AnswerExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+        }
+    }
+}
+
+/// 回答を 1 件ずつエクスポート形式の行へ変換する。
+///
+/// CSV の列は回答のメタ情報に続けて、フォームの質問を並び順どおりに 1 列ずつ並べる。
+pub struct AnswerExportEncoder {
+    format: AnswerExportFormat,
+    questions: Vec<Question>,
+}
+
+impl AnswerExportEncoder {
+    pub fn new(format: AnswerExportFormat, questions: Vec<Question>) -> Self {
+        let questions = questions
+            .into_iter()
+            .sorted_by_key(Question::position)
+            .collect();
+
+        Self { format, questions }
+    }
+
+    /// エクスポートの先頭に書き出す内容。NDJSON には見出しがないため空になる。
+    ///
+    /// CSV は表計算ソフトが UTF-8 と判別できるよう BOM を付ける。
+    pub fn header(&self) -> String {
+        match self.format {
+            AnswerExportFormat::Csv => {
+                let columns = [
+                    "回答ID",
+                    "タイトル",
+                    "回答者",
+                    "回答日時",
+                    "ステータス",
+                    "ラベル",
+                ]
+                .into_iter()
+                .map(str::to_owned)
+                .chain(
+                    self.questions
+                        .iter()
+                        .map(|question| question.title().to_string()),
+                );
+
+                format!("\u{FEFF}{}", csv_record(columns))
+            }
+            AnswerExportFormat::Ndjson => String::new(),
+        }
+    }
+
+    pub fn encode(&self, answer: AnswerDetails) -> Result<String, serde_json::Error> {
+        match self.format {
+            AnswerExportFormat::Csv => Ok(self.csv_row(answer)),
+            AnswerExportFormat::Ndjson => {
+                let line = serde_json::to_string(&FormAnswer::new(
+                    answer.answer,
+                    answer.form_id,
+                    answer.labels,
+                ))?;
+
+                Ok(format!("{line}\n"))
+            }
+        }
+    }
+
+    fn csv_row(&self, AnswerDetails { answer, labels, .. }: AnswerDetails) -> String {
+        let author = match answer.author {
+            PublishedAnswerAuthor::AuthenticatedUser(user) => user.name().to_owned(),
+            PublishedAnswerAuthor::Temporary(author) => author.name().to_owned(),
+            PublishedAnswerAuthor::ImportedFromRedmine(author) => author.display_name().to_owned(),
+            PublishedAnswerAuthor::Anonymous => String::new(),
+        };
+        let labels = labels.iter().map(|label| label.name().as_str()).join(", ");
+        // 複数選択の質問には同じ質問への回答が複数あるため、セル内で改行して並べる
+        let contents = self.questions.iter().map(|question| {
+            answer
+                .contents
+                .iter()
+                .filter(|content| content.question_id == question.id())
+                .map(|content| content.answer.as_str())
+                .join("\n")
+        });
+
+        csv_record(
+            [
+                answer.id.to_string(),
+                answer
+                    .title
+                    .into_inner()
+                    .map(|title| title.to_string())
+                    .unwrap_or_default(),
+                author,
+                answer.timestamp.to_rfc3339(),
+                answer.status.to_string(),
+                labels,
+            ]
+            .into_iter()
+            .chain(contents),
+        )
+    }
+}
+
+fn csv_record(fields: impl IntoIterator<Item = String>) -> String {
+    let record = fields.into_iter().map(|field| csv_field(&field)).join(",");
+
+    format!("{record}\r\n")
+}
+
+/// RFC 4180 に従ってフィールドを引用する。
+///
+/// 回答本文は利用者の入力なので、表計算ソフトで数式として評価されないよう
+/// 数式の開始文字で始まる値には `'` を前置する。
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_owned()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed_and_formulas_are_neutralized() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(
+            csv_record(["a".to_string(), "b,c".to_string()]),
+            "a,\"b,c\"\r\n"
+        );
+    }
+}
@@ -1,17 +1,27 @@
-use domain::account::models::UserGroupId;
-use domain::form::question::{ChoiceId, QuestionId, QuestionType, TemplateKey};
+use chrono::{DateTime, Utc};
+use domain::account::models::{AccountUser, UserGroupId};
+use domain::form::question::{ChoiceId, QuestionId, QuestionType, QuizPoints, TemplateKey};
 use domain::form::{
-    answer::{AnswerId, AnswerLabelId, AnswerPublication, AnswerStatus, AnswerTitle},
+    answer::{
+        AnswerAssigneeFilter, AnswerId, AnswerLabelId, AnswerListOrder, AnswerModerationStatus,
+        AnswerPublication, AnswerReference, AnswerRelationKind, AnswerStatus, AnswerTitle,
+    },
+    comment::CommentVisibility,
+    content_filter::{ContentFilterAction, ContentFilterCondition, ContentFilterPattern},
+    message::MessageId,
+    message_template::MessageTemplateId,
     models::{
-        AnswerAcceptancePeriod, AnswerVisibility, DefaultAnswerTitle, DiscordWebhookUrl, FormId,
-        FormLabelId, FormTitle, Visibility,
+        AnswerAcceptancePeriod, AnswerResponseDeadline, AnswerVisibility, DefaultAnswerTitle,
+        DiscordWebhookUrl, FormId, FormLabelId, FormTitle, ResponseDeadlineHours, Visibility,
     },
 };
+use errors::domain::DomainError;
 use serde::{Deserialize, Deserializer};
 use types::non_empty_string::NonEmptyString;
 use types::non_empty_vec::NonEmptyVec;
 
 use crate::schemas::field_update::FieldUpdate;
+use crate::schemas::form::answer_export::AnswerExportFormat;
 
 /// usecase 境界の「`None` = 変更なし / `Some` = 設定」規約へ写す。
 /// 解除は「値のない `DiscordWebhookUrl` を設定する」ことで表す。
@@ -37,6 +47,18 @@ pub fn into_default_answer_title(field: FieldUpdate<NonEmptyString>) -> Option<D
     }
 }
 
+/// usecase 境界の「`None` = 変更なし / `Some` = 設定」規約へ写す。
+/// 解除は「期限のない `AnswerResponseDeadline` を設定する」ことで表す。
+pub fn into_answer_response_deadline(
+    field: FieldUpdate<ResponseDeadlineHours>,
+) -> Option<AnswerResponseDeadline> {
+    match field {
+        FieldUpdate::Unchanged => None,
+        FieldUpdate::Clear => Some(AnswerResponseDeadline::new(None)),
+        FieldUpdate::Set(hours) => Some(AnswerResponseDeadline::new(Some(hours))),
+    }
+}
+
 #[derive(Deserialize, Debug, utoipa::IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct FormListQuery {
@@ -69,6 +91,68 @@ pub struct AnswerListQuery {
     /// Limit results to the specified answer status
     #[param(value_type = Option<String>)]
     pub status: Option<AnswerStatus>,
+    /// Limit results to answers assigned to the requesting user or to unassigned answers
+    /// (administrators only)
+    #[param(inline)]
+    pub assignee: Option<AnswerAssigneeQuery>,
+    /// Limit results to unfinished answers past their due date (administrators only)
+    pub overdue: Option<bool>,
+    /// Limit results to answers whose quiz score is at least this value (administrators only)
+    pub min_score: Option<u32>,
+    /// Limit results to answers whose quiz score is at most this value (administrators only)
+    pub max_score: Option<u32>,
+    /// Limit results to answers with messages from their author that the requesting user has not
+    /// read yet (administrators only)
+    pub has_unread_author_messages: Option<bool>,
+    /// Limit results to the specified moderation status (`APPROVED`, `PENDING` or `REJECTED`).
+    /// Pending and rejected answers are visible to administrators and their authors only
+    #[param(value_type = Option<String>)]
+    pub moderation_status: Option<AnswerModerationStatus>,
+    /// Order of the answers. Ordering by quiz score is available to administrators only
+    #[param(inline)]
+    pub sort: Option<AnswerListSortQuery>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerListSortQuery {
+    Newest,
+    ScoreDesc,
+    ScoreAsc,
+}
+
+impl From<AnswerListSortQuery> for AnswerListOrder {
+    fn from(sort: AnswerListSortQuery) -> Self {
+        match sort {
+            AnswerListSortQuery::Newest => Self::Newest,
+            AnswerListSortQuery::ScoreDesc => Self::ScoreDescending,
+            AnswerListSortQuery::ScoreAsc => Self::ScoreAscending,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AnswerExportQuery {
+    /// Output format of the export
+    #[param(inline)]
+    pub format: AnswerExportFormat,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerAssigneeQuery {
+    Me,
+    Unassigned,
+}
+
+impl AnswerAssigneeQuery {
+    pub fn into_filter(self, user: &AccountUser) -> AnswerAssigneeFilter {
+        match self {
+            Self::Me => AnswerAssigneeFilter::AssignedTo(*user.id()),
+            Self::Unassigned => AnswerAssigneeFilter::Unassigned,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, utoipa::IntoParams)]
@@ -109,6 +193,22 @@ pub struct AnswerSettingsSchema {
     #[serde(default)]
     #[schema(value_type = Option<Vec<String>>)]
     pub answer_group_ids: Option<Vec<UserGroupId>>,
+    /// 新しい回答の対応期限 (回答からの時間数)。キーを省略すると変更なし、`null` を指定すると期限を設けない。
+    #[serde(default)]
+    #[schema(value_type = Option<u32>, minimum = 1, maximum = 8760)]
+    pub response_deadline_hours: FieldUpdate<ResponseDeadlineHours>,
+    /// 選択肢の正解と点数で回答を採点するか。
+    #[serde(default)]
+    pub quiz_enabled: Option<bool>,
+    /// 採点結果を回答者本人に見せるか。
+    #[serde(default)]
+    pub show_quiz_score_to_author: Option<bool>,
+    /// 回答が完了したときにメッセージスレッドをロックするか。
+    #[serde(default)]
+    pub lock_message_thread_on_completion: Option<bool>,
+    /// ログインせずに送られた回答を、モデレーターが承認するまで保留するか。
+    #[serde(default)]
+    pub hold_temporary_answers_for_review: Option<bool>,
 }
 
 #[derive(utoipa::ToSchema)]
@@ -184,6 +284,36 @@ mod tests {
             .map(|title| title.into_inner().map(NonEmptyString::into_inner))
     }
 
+    fn response_deadline_update(json: &str) -> Option<Option<u32>> {
+        let settings = serde_json::from_str::<AnswerSettingsSchema>(json).unwrap();
+
+        into_answer_response_deadline(settings.response_deadline_hours)
+            .map(|deadline| deadline.into_inner().map(u32::from))
+    }
+
+    #[test]
+    fn bulk_answer_targets_must_contain_between_one_and_the_maximum_number_of_answers() {
+        let targets = |count: usize| {
+            let answers = (0..count)
+                .map(|_| {
+                    serde_json::json!({
+                        "form_id": uuid::Uuid::new_v4(),
+                        "answer_id": uuid::Uuid::new_v4(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            serde_json::from_value::<BulkAnswerTargets>(serde_json::Value::Array(answers))
+        };
+
+        assert!(targets(0).is_err());
+        assert_eq!(targets(1).unwrap().into_inner().len(), 1);
+        assert_eq!(
+            targets(BulkAnswerTargets::MAX).unwrap().into_inner().len(),
+            BulkAnswerTargets::MAX
+        );
+        assert!(targets(BulkAnswerTargets::MAX + 1).is_err());
+    }
+
     #[test]
     fn omitted_discord_webhook_url_changes_nothing_while_null_clears_it() {
         assert_eq!(discord_webhook_url_update(r#"{}"#), None);
@@ -242,6 +372,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn omitted_response_deadline_changes_nothing_while_null_removes_it() {
+        assert_eq!(response_deadline_update(r#"{}"#), None);
+        assert_eq!(
+            response_deadline_update(r#"{"response_deadline_hours":null}"#),
+            Some(None)
+        );
+        assert_eq!(
+            response_deadline_update(r#"{"response_deadline_hours":72}"#),
+            Some(Some(72))
+        );
+        for hours in ["0", "8761"] {
+            let json = format!(r#"{{"response_deadline_hours":{hours}}}"#);
+
+            assert!(
+                serde_json::from_str::<AnswerSettingsSchema>(&json).is_err(),
+                "{hours} should be rejected"
+            );
+        }
+    }
+
     /// 型が生成するスキーマだけを見ている。`AnswerSettingsSchema` は
     /// レスポンス側と名前が衝突していて `docs/openapi.json` にはリクエスト側が
     /// 出ないため、`default_answer_title` については実文書を検証できていない。
@@ -274,6 +425,11 @@ mod tests {
                 .unwrap(),
             "default_answer_title",
         );
+        assert_optional_and_nullable(
+            serde_json::to_value(<AnswerSettingsSchema as utoipa::PartialSchema>::schema())
+                .unwrap(),
+            "response_deadline_hours",
+        );
     }
 
     #[test]
@@ -348,12 +504,162 @@ pub struct AnswerUpdateSchema {
     pub status: Option<AnswerStatus>,
 }
 
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerAssigneeUpdateSchema {
+    /// 担当者にする管理者の ID。`null` を指定すると担当者を解除する。
+    pub assignee_id: Option<uuid::Uuid>,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerDueDateUpdateSchema {
+    /// 回答の対応期限。`null` を指定すると期限を外す。
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerQuestionMappingSchema {
+    #[schema(value_type = String, format = "uuid")]
+    pub source_question_id: QuestionId,
+    #[schema(value_type = String, format = "uuid")]
+    pub destination_question_id: QuestionId,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerMoveSchema {
+    #[schema(value_type = String, format = "uuid")]
+    pub destination_form_id: FormId,
+    /// 移動元の質問と移動先の質問の対応。指定しなかった質問は、同じ `template_key` を持つ
+    /// 移動先の質問に対応付ける。
+    #[serde(default)]
+    pub question_mapping: Vec<AnswerQuestionMappingSchema>,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerDrawCreateSchema {
+    /// 当選者の人数。1 以上 100 以下で、候補より多い場合は候補の全員が当選する。
+    pub winner_count: u32,
+    /// 抽選に使うシード。64 bit 符号なし整数を 10 進数の文字列で指定する。
+    /// 省略した場合は生成したシードを使い、抽選結果に記録する。
+    #[serde(default)]
+    #[schema(example = "12345678901234567890")]
+    pub seed: Option<String>,
+    /// 指定した場合、この対応ステータスの回答だけを候補にする。
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub status: Option<AnswerStatus>,
+    /// 指定した場合、このラベルが付いた回答だけを候補にする。
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub label_id: Option<AnswerLabelId>,
+    /// `true` の場合、回答の送信を制限されている回答者の回答を候補から除く。
+    #[serde(default)]
+    pub exclude_restricted_submitters: bool,
+    /// `true` の場合、ログインして回答した当選者に通知を送る。
+    #[serde(default)]
+    pub notify_winners: bool,
+}
+
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
 pub struct RelatedAnswerRequest {
     #[schema(value_type = String, format = "uuid")]
     pub form_id: FormId,
     #[schema(value_type = String, format = "uuid")]
     pub answer_id: AnswerId,
+    /// 関連の種類。`RELATED` 以外は、パスで指定した回答から本文で指定した回答への向きを持つ。
+    /// 省略時は `RELATED`。
+    #[serde(default)]
+    #[schema(value_type = Option<String>, example = "DUPLICATE_OF")]
+    pub kind: AnswerRelationKind,
+}
+
+#[derive(Deserialize, Debug, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RelatedAnswerListQuery {
+    /// Limit results to the specified relation kind
+    #[param(value_type = Option<String>)]
+    pub kind: Option<AnswerRelationKind>,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct AnswerReferenceSchema {
+    #[schema(value_type = String, format = "uuid")]
+    pub form_id: FormId,
+    #[schema(value_type = String, format = "uuid")]
+    pub answer_id: AnswerId,
+}
+
+/// 一括操作の対象とする回答。1 件以上 [`BulkAnswerTargets::MAX`] 件以下でなければならない。
+#[derive(Debug)]
+pub struct BulkAnswerTargets(Vec<AnswerReference>);
+
+impl BulkAnswerTargets {
+    pub const MAX: usize = 100;
+
+    pub fn into_inner(self) -> Vec<AnswerReference> {
+        self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for BulkAnswerTargets {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let answers = Vec::<AnswerReferenceSchema>::deserialize(deserializer)?;
+        if !(1..=Self::MAX).contains(&answers.len()) {
+            return Err(serde::de::Error::invalid_length(
+                answers.len(),
+                &"between 1 and 100 answers",
+            ));
+        }
+
+        Ok(Self(
+            answers
+                .into_iter()
+                .map(|answer| AnswerReference::new(answer.form_id, answer.answer_id))
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerStatusUpdateSchema {
+    #[schema(value_type = Vec<AnswerReferenceSchema>, min_items = 1, max_items = 100)]
+    pub answers: BulkAnswerTargets,
+    #[schema(value_type = String)]
+    pub status: AnswerStatus,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerPublicationUpdateSchema {
+    #[schema(value_type = Vec<AnswerReferenceSchema>, min_items = 1, max_items = 100)]
+    pub answers: BulkAnswerTargets,
+    #[schema(value_type = String)]
+    pub publication: AnswerPublication,
+}
+
+/// 確認待ちの回答を一括で承認または却下するときのリクエスト。
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerModerationSchema {
+    #[schema(value_type = Vec<AnswerReferenceSchema>, min_items = 1, max_items = 100)]
+    pub answers: BulkAnswerTargets,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAnswerLabelAction {
+    Add,
+    Remove,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerLabelUpdateSchema {
+    #[schema(value_type = Vec<AnswerReferenceSchema>, min_items = 1, max_items = 100)]
+    pub answers: BulkAnswerTargets,
+    #[schema(value_type = String, format = "uuid")]
+    pub label_id: AnswerLabelId,
+    /// 対象の回答にラベルを付けるか (`add`)、外すか (`remove`)。
+    pub action: BulkAnswerLabelAction,
 }
 
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
@@ -363,11 +669,15 @@ pub struct ChoiceSchema {
     pub position: u16,
     #[schema(value_type = String)]
     pub label: NonEmptyString,
+    /// 正解の選択肢の点数。省略または `null` の場合は不正解の選択肢になる。
+    #[serde(default)]
+    #[schema(value_type = Option<u32>, minimum = 1, maximum = 1000)]
+    pub quiz_points: Option<QuizPoints>,
 }
 
 impl From<ChoiceSchema> for domain::form::question::Choice {
     fn from(choice: ChoiceSchema) -> Self {
-        Self::new(choice.id, choice.position, choice.label)
+        Self::new(choice.id, choice.position, choice.label).with_quiz_points(choice.quiz_points)
     }
 }
 
@@ -460,6 +770,10 @@ impl QuestionSchema {
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
 pub struct CommentPostSchema {
     pub content: NonEmptyString,
+    /// `INTERNAL` を指定すると運営のみが閲覧できるコメントになる。省略時は `PUBLIC`。
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub visibility: Option<CommentVisibility>,
 }
 
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
@@ -493,14 +807,137 @@ pub struct ReplaceAnswerLabelSchema {
     pub labels: Vec<AnswerLabelId>,
 }
 
+/// `body` と `template_id` のどちらか一方を指定する。
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
 pub struct PostedMessageSchema {
+    #[serde(default)]
+    #[schema(value_type = Option<String>, min_length = 1)]
+    pub body: Option<NonEmptyString>,
+    /// 本文の代わりに使うメッセージテンプレートの ID。プレースホルダーは回答の内容で置き換えられる。
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub template_id: Option<MessageTemplateId>,
+    /// `body` と一緒に送る添付ファイル。テンプレートからの投稿には添付できない。
+    #[serde(default)]
+    pub attachments: Vec<PostedMessageAttachmentSchema>,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct PostedMessageAttachmentSchema {
+    pub file_name: String,
+    pub content_type: String,
+    /// ファイルの内容を Base64 でエンコードした文字列
+    pub content: String,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct MessageTemplateCreateSchema {
+    #[schema(value_type = String, min_length = 1)]
+    pub name: NonEmptyString,
+    /// `$username`・`$form_name`・`$answer_title` と、質問のテンプレートキーを `$key` の形で埋め込める。
     #[schema(value_type = String, min_length = 1)]
     pub body: NonEmptyString,
 }
 
+/// 一時回答をアカウントに引き継ぐときに送る、回答時に発行されたコード。
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct TemporaryAnswerClaimSchema {
+    pub code: String,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct MessageTemplateUpdateSchema {
+    #[schema(value_type = Option<String>, min_length = 1)]
+    pub name: Option<NonEmptyString>,
+    #[schema(value_type = Option<String>, min_length = 1)]
+    pub body: Option<NonEmptyString>,
+}
+
 #[derive(Deserialize, Debug, utoipa::ToSchema)]
 pub struct MessageUpdateSchema {
     #[schema(value_type = Option<String>, min_length = 1)]
     pub body: Option<NonEmptyString>,
 }
+
+/// 回答のアクセスリンクから送るメッセージ。添付ファイルやテンプレートは使えない。
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct TemporaryAnswerMessageSchema {
+    #[schema(value_type = String, min_length = 1)]
+    pub body: NonEmptyString,
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct MessageReadSchema {
+    /// どのメッセージまで既読にするか。省略すると最新のメッセージまでを既読にする。
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub message_id: Option<MessageId>,
+}
+
+/// 投稿を確かめる条件。
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentFilterConditionSchema {
+    /// 語句を含む投稿に当てはまる。大文字と小文字は区別しない。
+    BlockedWord {
+        #[schema(value_type = String, min_length = 1)]
+        word: NonEmptyString,
+    },
+    /// 正規表現に一致する投稿に当てはまる。
+    Pattern { pattern: String },
+    /// `max_links` より多くのリンクを含む投稿に当てはまる。
+    MaxLinks { max_links: u32 },
+    /// 空白以外の同じ文字が `run_length` 回以上続く投稿に当てはまる。
+    RepeatedCharacters {
+        #[schema(minimum = 2)]
+        run_length: u32,
+    },
+}
+
+impl TryFrom<ContentFilterConditionSchema> for ContentFilterCondition {
+    type Error = DomainError;
+
+    fn try_from(condition: ContentFilterConditionSchema) -> Result<Self, Self::Error> {
+        Ok(match condition {
+            ContentFilterConditionSchema::BlockedWord { word } => Self::BlockedWord(word),
+            ContentFilterConditionSchema::Pattern { pattern } => {
+                Self::Pattern(ContentFilterPattern::try_from(pattern)?)
+            }
+            ContentFilterConditionSchema::MaxLinks { max_links } => Self::MaxLinks(max_links),
+            ContentFilterConditionSchema::RepeatedCharacters { run_length } => {
+                Self::RepeatedCharacters(run_length)
+            }
+        })
+    }
+}
+
+/// 条件に当てはまった投稿への処置。
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentFilterActionSchema {
+    /// 投稿を受け付けない。
+    Reject,
+    /// 投稿を受け付け、管理者の確認待ちとして記録する。回答は公開前の確認待ちになる。
+    FlagForReview,
+    /// 回答にラベルを付ける。コメントとメッセージでは、その回答にラベルを付ける。
+    AddLabel {
+        #[schema(value_type = String, format = "uuid")]
+        label_id: AnswerLabelId,
+    },
+}
+
+impl From<ContentFilterActionSchema> for ContentFilterAction {
+    fn from(action: ContentFilterActionSchema) -> Self {
+        match action {
+            ContentFilterActionSchema::Reject => Self::Reject,
+            ContentFilterActionSchema::FlagForReview => Self::FlagForReview,
+            ContentFilterActionSchema::AddLabel { label_id } => Self::AddLabel(label_id),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, utoipa::ToSchema)]
+pub struct ContentFilterRuleCreateSchema {
+    pub condition: ContentFilterConditionSchema,
+    pub action: ContentFilterActionSchema,
+}
@@ -1,28 +1,46 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use domain::account::models::AccountUser;
 use domain::account::models::{UserGroupId, UserSnapshot};
 use domain::form::{
     answer::{
-        AnswerLabel, AnswerPublication as DomainAnswerPublication, AnswerReference,
+        AnswerAssignmentHistoryEntry, AnswerEntry, AnswerLabel,
+        AnswerModerationStatus as DomainAnswerModerationStatus, AnswerMoveHistoryEntry,
+        AnswerPublication as DomainAnswerPublication, AnswerQuizScore,
+        AnswerRelationDirection as DomainAnswerRelationDirection,
+        AnswerRelationKind as DomainAnswerRelationKind, AnswerStatistics,
         AnswerStatus as DomainAnswerStatus, AnswerStatusHistoryEntry, AnswerTitleHistoryEntry,
-        FormAnswerContent, RedmineUserSnapshot,
+        FormAnswerContent, RedmineUserSnapshot, RelatedAnswer,
     },
-    comment::{CommentHistoryAction, CommentHistoryEntry, CommentId},
-    message::{MessageHistoryAction, MessageHistoryEntry},
+    comment::{
+        CommentHistoryAction, CommentHistoryEntry, CommentId,
+        CommentVisibility as DomainCommentVisibility,
+    },
+    content_filter::{
+        ContentFilterAction, ContentFilterCondition, ContentFilterFlag, ContentFilterRule,
+        ContentFilterTarget,
+    },
+    draw::AnswerDraw,
+    message::{MessageAttachment, MessageHistoryAction, MessageHistoryEntry},
+    message_template::MessageTemplate,
     models::{
         ActiveForm, AnswerSettings, DefaultAnswerTitle, FormDescription, FormId, FormLabel,
         FormMeta, FormSettings, FormTitle, Visibility,
     },
     question::{Choice, Question, QuestionType},
+    temporary_answer_abuse::TemporaryAnswerAbuseMetadata,
 };
+use domain::markdown::render_html;
 use itertools::Itertools;
 use serde::Serialize;
 use types::non_empty_string::NonEmptyString;
 use usecase::models::{
-    CommentAuthor, CommentWithAuthor, PublishedAnswerAuthor, PublishedAnswerEntry,
+    AnswerDetails, CommentAuthor, CommentWithAuthor, MessageSenderProfile, MessageWithSender,
+    PublishedAnswerAuthor, PublishedAnswerEntry,
 };
 use uuid::Uuid;
 
+use crate::schemas::error_response::ErrorResponse;
+
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct AnswerAcceptancePeriodSchema {
     pub start_at: Option<DateTime<Utc>>,
@@ -74,6 +92,27 @@ impl From<DomainAnswerStatus> for AnswerStatus {
     }
 }
 
+/// 回答の公開前の確認の状態。`PENDING` と `REJECTED` の回答は管理者と回答者本人にしか見えない。
+#[derive(Serialize, Debug, utoipa::ToSchema, Copy, Clone)]
+pub enum AnswerModerationStatus {
+    #[serde(rename = "APPROVED")]
+    Approved,
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+}
+
+impl From<DomainAnswerModerationStatus> for AnswerModerationStatus {
+    fn from(value: DomainAnswerModerationStatus) -> Self {
+        match value {
+            DomainAnswerModerationStatus::APPROVED => Self::Approved,
+            DomainAnswerModerationStatus::PENDING => Self::Pending,
+            DomainAnswerModerationStatus::REJECTED => Self::Rejected,
+        }
+    }
+}
+
 impl From<domain::form::models::AnswerVisibility> for AnswerVisibility {
     fn from(val: domain::form::models::AnswerVisibility) -> Self {
         match val {
@@ -92,6 +131,11 @@ pub struct AnswerSettingsSchema {
     pub acceptance_period: AnswerAcceptancePeriodSchema,
     #[schema(value_type = Vec<String>)]
     pub answer_group_ids: Vec<UserGroupId>,
+    pub response_deadline_hours: Option<u32>,
+    pub quiz_enabled: bool,
+    pub show_quiz_score_to_author: bool,
+    pub lock_message_thread_on_completion: bool,
+    pub hold_temporary_answers_for_review: bool,
 }
 
 impl AnswerSettingsSchema {
@@ -105,6 +149,18 @@ impl AnswerSettingsSchema {
                 end_at: answer_settings.acceptance_period().end_at().to_owned(),
             },
             answer_group_ids: answer_settings.answer_group_ids().to_vec(),
+            response_deadline_hours: answer_settings
+                .response_deadline()
+                .into_inner()
+                .map(u32::from),
+            quiz_enabled: answer_settings.quiz().is_enabled(),
+            show_quiz_score_to_author: answer_settings.quiz().is_score_visible_to_author(),
+            lock_message_thread_on_completion: answer_settings
+                .message_thread_lock_policy()
+                .locks_on_completion(),
+            hold_temporary_answers_for_review: answer_settings
+                .temporary_answer_moderation_policy()
+                .holds_for_review(),
         }
     }
 }
@@ -299,6 +355,8 @@ pub struct ChoiceResponseSchema {
     pub id: Option<i32>,
     pub position: u16,
     pub label: String,
+    /// 正解の選択肢の点数。管理者以外には常に `null` になる。
+    pub quiz_points: Option<u32>,
 }
 
 impl From<Choice> for ChoiceResponseSchema {
@@ -307,6 +365,7 @@ impl From<Choice> for ChoiceResponseSchema {
             id: val.id.map(|id| id.into_inner()),
             position: val.position,
             label: val.label.into_inner(),
+            quiz_points: val.quiz_points.map(u32::from),
         }
     }
 }
@@ -326,6 +385,24 @@ impl From<FormLabel> for FormLabelResponseSchema {
     }
 }
 
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct MessageTemplateResponse {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: String,
+    pub name: String,
+    pub body: String,
+}
+
+impl From<MessageTemplate> for MessageTemplateResponse {
+    fn from(value: MessageTemplate) -> Self {
+        Self {
+            id: value.id().to_string(),
+            name: value.name().to_owned().into_inner().into_inner(),
+            body: value.body().as_str().to_owned(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct AnswerLabelResponseSchema {
     pub id: String,
@@ -468,13 +545,33 @@ pub enum AnswerCommentSource {
     ImportedFromRedmine,
 }
 
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub enum CommentVisibility {
+    #[serde(rename = "PUBLIC")]
+    Public,
+    #[serde(rename = "INTERNAL")]
+    Internal,
+}
+
+impl From<DomainCommentVisibility> for CommentVisibility {
+    fn from(value: DomainCommentVisibility) -> Self {
+        match value {
+            DomainCommentVisibility::Public => Self::Public,
+            DomainCommentVisibility::Internal => Self::Internal,
+        }
+    }
+}
+
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct AnswerComment {
     #[schema(value_type = String, format = "uuid")]
     id: CommentId,
     content: String,
+    /// `content` を Markdown として描画し、サニタイズした HTML
+    content_html: String,
     timestamp: DateTime<Utc>,
     source: AnswerCommentSource,
+    visibility: CommentVisibility,
     #[serde(skip_serializing_if = "Option::is_none")]
     commented_by: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -519,6 +616,9 @@ pub struct CommentHistoryResponseEntry {
     original_timestamp: DateTime<Utc>,
     action: HistoryAction,
     content: String,
+    /// `content` を Markdown として描画し、サニタイズした HTML
+    content_html: String,
+    visibility: CommentVisibility,
     operated_by: HistoryUser,
     operated_at: DateTime<Utc>,
 }
@@ -538,6 +638,8 @@ impl From<CommentHistoryEntry> for CommentHistoryResponseEntry {
             original_timestamp: *value.original_timestamp(),
             action,
             content: value.content().to_string(),
+            content_html: render_html(&value.content().to_string()),
+            visibility: (*value.visibility()).into(),
             operated_by: value.operated_by().into(),
             operated_at: *value.operated_at(),
         }
@@ -560,8 +662,11 @@ pub struct MessageHistoryResponseEntry {
     original_timestamp: DateTime<Utc>,
     action: HistoryAction,
     body: String,
+    /// `body` を Markdown として描画し、サニタイズした HTML
+    body_html: String,
     operated_by: HistoryUser,
     operated_at: DateTime<Utc>,
+    attachments: Vec<MessageAttachmentSchema>,
 }
 
 impl From<MessageHistoryEntry> for MessageHistoryResponseEntry {
@@ -579,8 +684,10 @@ impl From<MessageHistoryEntry> for MessageHistoryResponseEntry {
             original_timestamp: *value.original_timestamp(),
             action,
             body: value.body().as_str().to_owned(),
+            body_html: render_html(value.body().as_str()),
             operated_by: value.operated_by().into(),
             operated_at: *value.operated_at(),
+            attachments: value.attachments().iter().map(Into::into).collect(),
         }
     }
 }
@@ -621,6 +728,117 @@ pub struct AnswerStatusHistoryPageResponse {
     pub next_cursor: Option<String>,
 }
 
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerAssignmentHistoryResponseEntry {
+    #[schema(value_type = String, format = "uuid")]
+    id: String,
+    #[serde(rename = "from")]
+    from_assignee_id: Option<Uuid>,
+    #[serde(rename = "to")]
+    to_assignee_id: Option<Uuid>,
+    changed_by: HistoryUser,
+    changed_at: DateTime<Utc>,
+}
+
+impl From<AnswerAssignmentHistoryEntry> for AnswerAssignmentHistoryResponseEntry {
+    fn from(value: AnswerAssignmentHistoryEntry) -> Self {
+        Self {
+            id: value.id().to_string(),
+            from_assignee_id: (*value.from_assignee()).map(|user_id| user_id.into_inner()),
+            to_assignee_id: (*value.to_assignee()).map(|user_id| user_id.into_inner()),
+            changed_by: value.changed_by().into(),
+            changed_at: *value.changed_at(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerAssignmentHistoryPageResponse {
+    pub items: Vec<AnswerAssignmentHistoryResponseEntry>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerMoveHistoryResponseEntry {
+    #[schema(value_type = String, format = "uuid")]
+    id: String,
+    #[serde(rename = "from")]
+    from_form_id: Uuid,
+    #[serde(rename = "to")]
+    to_form_id: Uuid,
+    changed_by: HistoryUser,
+    changed_at: DateTime<Utc>,
+}
+
+impl From<AnswerMoveHistoryEntry> for AnswerMoveHistoryResponseEntry {
+    fn from(value: AnswerMoveHistoryEntry) -> Self {
+        Self {
+            id: value.id().to_string(),
+            from_form_id: value.from_form_id().into_inner(),
+            to_form_id: value.to_form_id().into_inner(),
+            changed_by: value.changed_by().into(),
+            changed_at: *value.changed_at(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerMoveHistoryPageResponse {
+    pub items: Vec<AnswerMoveHistoryResponseEntry>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerDrawResponse {
+    #[schema(value_type = String, format = "uuid")]
+    id: String,
+    form_id: Uuid,
+    /// 抽選に使ったシード。64 bit 符号なし整数の 10 進数表記。
+    seed: String,
+    winner_count: u32,
+    status: Option<AnswerStatus>,
+    label_id: Option<Uuid>,
+    exclude_restricted_submitters: bool,
+    /// 抽選の候補になった回答の ID (昇順)。
+    candidate_ids: Vec<Uuid>,
+    /// 当選した回答の ID (当選順)。
+    winner_ids: Vec<Uuid>,
+    drawn_by: HistoryUser,
+    drawn_at: DateTime<Utc>,
+    /// 記録されたシードと候補から抽選をやり直した結果が、記録された当選者と一致するか。
+    verified: bool,
+}
+
+impl From<AnswerDraw> for AnswerDrawResponse {
+    fn from(value: AnswerDraw) -> Self {
+        Self {
+            id: value.id().to_string(),
+            form_id: value.form_id().into_inner(),
+            seed: value.seed().to_string(),
+            winner_count: value.winner_count().value(),
+            status: value.criteria().status().map(Into::into),
+            label_id: value
+                .criteria()
+                .label_id()
+                .map(|label_id| label_id.into_inner()),
+            exclude_restricted_submitters: *value.criteria().exclude_restricted_submitters(),
+            candidate_ids: value
+                .candidate_ids()
+                .iter()
+                .map(|answer_id| answer_id.into_inner())
+                .collect(),
+            winner_ids: value
+                .winner_ids()
+                .iter()
+                .map(|answer_id| answer_id.into_inner())
+                .collect(),
+            drawn_by: value.drawn_by().into(),
+            drawn_at: *value.drawn_at(),
+            verified: value.verify(),
+        }
+    }
+}
+
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct AnswerTitleHistoryResponseEntry {
     #[schema(value_type = String, format = "uuid")]
@@ -676,8 +894,10 @@ impl From<CommentWithAuthor> for AnswerComment {
         AnswerComment {
             id: val.comment.comment_id().to_owned(),
             content: val.comment.content().to_string(),
+            content_html: render_html(&val.comment.content().to_string()),
             timestamp: val.comment.timestamp().to_owned(),
             source,
+            visibility: (*val.comment.visibility()).into(),
             commented_by,
             redmine_journal_id,
             redmine_author_snapshot,
@@ -709,22 +929,126 @@ pub struct FormAnswer {
     title: Option<String>,
     publication: AnswerPublication,
     status: AnswerStatus,
+    moderation_status: AnswerModerationStatus,
     answers: Vec<AnswerContent>,
     labels: Vec<AnswerLabels>,
     redmine_issue_id: Option<i64>,
+    assignee_id: Option<Uuid>,
+    due_at: Option<DateTime<Utc>>,
+    quiz_score: Option<AnswerQuizScoreSchema>,
+    /// 閲覧者から見た未読メッセージ数。閲覧者がメッセージを読めない回答では `null` になる。
+    unread_message_count: Option<u32>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerQuizScoreSchema {
+    pub score: u32,
+    pub max_score: u32,
+}
+
+impl From<AnswerQuizScore> for AnswerQuizScoreSchema {
+    fn from(quiz_score: AnswerQuizScore) -> Self {
+        Self {
+            score: *quiz_score.score(),
+            max_score: *quiz_score.max_score(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct PostedAnswerResponse {
+    /// 採点結果。クイズでないフォームや、回答者に点数を見せないフォームでは `null` になる。
+    pub quiz_score: Option<AnswerQuizScoreSchema>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct PostedTemporaryAnswerResponse {
+    /// 採点結果。クイズでないフォームや、回答者に点数を見せないフォームでは `null` になる。
+    pub quiz_score: Option<AnswerQuizScoreSchema>,
+    /// 回答のアクセスリンク用のトークン。再発行できないため、回答者に控えてもらう必要がある。
+    pub access_token: String,
+    /// ログイン後に回答を自分のアカウントへ引き継ぐためのコード。一度だけ使える。
+    pub claim_code: String,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct TemporaryAnswerClaimResponse {
+    pub form_id: Uuid,
+    pub answer_id: Uuid,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerOperationResponse {
+    pub results: Vec<BulkAnswerOperationItemResponse>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct BulkAnswerOperationItemResponse {
+    pub form_id: Uuid,
+    pub answer_id: Uuid,
+    pub succeeded: bool,
+    /// 失敗した理由。その回答だけを操作した場合のエラーレスポンスと同じ内容になる。
+    pub error: Option<ErrorResponse>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub enum AnswerRelationKind {
+    #[serde(rename = "RELATED")]
+    Related,
+    #[serde(rename = "DUPLICATE_OF")]
+    DuplicateOf,
+    #[serde(rename = "FOLLOW_UP_OF")]
+    FollowUpOf,
+    #[serde(rename = "BLOCKS")]
+    Blocks,
+}
+
+impl From<DomainAnswerRelationKind> for AnswerRelationKind {
+    fn from(value: DomainAnswerRelationKind) -> Self {
+        match value {
+            DomainAnswerRelationKind::Related => Self::Related,
+            DomainAnswerRelationKind::DuplicateOf => Self::DuplicateOf,
+            DomainAnswerRelationKind::FollowUpOf => Self::FollowUpOf,
+            DomainAnswerRelationKind::Blocks => Self::Blocks,
+        }
+    }
+}
+
+/// 向きを持つ関連で、パスで指定した回答が関連元 (`OUTGOING`) と関連先 (`INCOMING`) の
+/// どちらであるか。
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub enum AnswerRelationDirection {
+    #[serde(rename = "OUTGOING")]
+    Outgoing,
+    #[serde(rename = "INCOMING")]
+    Incoming,
+}
+
+impl From<DomainAnswerRelationDirection> for AnswerRelationDirection {
+    fn from(value: DomainAnswerRelationDirection) -> Self {
+        match value {
+            DomainAnswerRelationDirection::Outgoing => Self::Outgoing,
+            DomainAnswerRelationDirection::Incoming => Self::Incoming,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct RelatedAnswerResponse {
     pub form_id: Uuid,
     pub answer_id: Uuid,
+    pub kind: AnswerRelationKind,
+    /// `RELATED` では `null`
+    pub direction: Option<AnswerRelationDirection>,
 }
 
-impl From<AnswerReference> for RelatedAnswerResponse {
-    fn from(reference: AnswerReference) -> Self {
+impl From<RelatedAnswer> for RelatedAnswerResponse {
+    fn from(related: RelatedAnswer) -> Self {
         Self {
-            form_id: reference.form_id().into_inner(),
-            answer_id: reference.answer_id().into_inner(),
+            form_id: related.reference.form_id().into_inner(),
+            answer_id: related.reference.answer_id().into_inner(),
+            kind: related.kind.into(),
+            direction: related.direction.map(Into::into),
         }
     }
 }
@@ -735,6 +1059,88 @@ pub struct AnswerListPageResponse {
     pub next_cursor: Option<String>,
 }
 
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct AnswerStatisticsResponse {
+    pub total: u32,
+    pub choices: Vec<ChoiceAnswerCountResponse>,
+    pub daily: Vec<DailyAnswerCountResponse>,
+    pub statuses: Vec<StatusAnswerCountResponse>,
+    pub labels: Vec<LabelAnswerCountResponse>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct ChoiceAnswerCountResponse {
+    #[schema(value_type = String, format = "uuid")]
+    pub question_id: String,
+    pub choice_id: Option<i32>,
+    pub label: String,
+    pub count: u32,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct DailyAnswerCountResponse {
+    /// Date in UTC
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct StatusAnswerCountResponse {
+    pub status: AnswerStatus,
+    pub count: u32,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct LabelAnswerCountResponse {
+    #[schema(value_type = String, format = "uuid")]
+    pub label_id: String,
+    pub name: String,
+    pub count: u32,
+}
+
+impl From<AnswerStatistics> for AnswerStatisticsResponse {
+    fn from(statistics: AnswerStatistics) -> Self {
+        Self {
+            total: *statistics.total(),
+            choices: statistics
+                .choices()
+                .iter()
+                .map(|choice| ChoiceAnswerCountResponse {
+                    question_id: choice.question_id.into_inner().to_string(),
+                    choice_id: choice.choice_id.map(|choice_id| choice_id.into_inner()),
+                    label: choice.label.to_string(),
+                    count: choice.count,
+                })
+                .collect_vec(),
+            daily: statistics
+                .daily()
+                .iter()
+                .map(|daily| DailyAnswerCountResponse {
+                    date: daily.date,
+                    count: daily.count,
+                })
+                .collect_vec(),
+            statuses: statistics
+                .statuses()
+                .iter()
+                .map(|status| StatusAnswerCountResponse {
+                    status: status.status.into(),
+                    count: status.count,
+                })
+                .collect_vec(),
+            labels: statistics
+                .labels()
+                .iter()
+                .map(|label| LabelAnswerCountResponse {
+                    label_id: label.label_id.into_inner().to_string(),
+                    name: label.name.to_string(),
+                    count: label.count,
+                })
+                .collect_vec(),
+        }
+    }
+}
+
 impl FormAnswer {
     pub fn new(answer: PublishedAnswerEntry, form_id: FormId, labels: Vec<AnswerLabel>) -> Self {
         FormAnswer {
@@ -745,6 +1151,7 @@ impl FormAnswer {
             title: answer.title.into_inner().map(|title| title.to_string()),
             publication: answer.publication.into(),
             status: answer.status.into(),
+            moderation_status: answer.moderation_status.into(),
             answers: answer
                 .contents
                 .iter()
@@ -754,6 +1161,23 @@ impl FormAnswer {
             redmine_issue_id: answer
                 .redmine_reference
                 .map(|reference| reference.issue_id().into_inner()),
+            assignee_id: answer.assignee.map(|user_id| user_id.into_inner()),
+            due_at: answer.due_at,
+            quiz_score: answer.quiz_score.map(Into::into),
+            unread_message_count: None,
+        }
+    }
+}
+
+impl From<AnswerDetails> for FormAnswer {
+    fn from(answer_details: AnswerDetails) -> Self {
+        FormAnswer {
+            unread_message_count: answer_details.unread_message_count,
+            ..FormAnswer::new(
+                answer_details.answer,
+                answer_details.form_id,
+                answer_details.labels,
+            )
         }
     }
 }
@@ -762,17 +1186,137 @@ impl FormAnswer {
 pub struct MessageContentSchema {
     pub id: Uuid,
     pub body: String,
+    /// `body` を Markdown として描画し、サニタイズした HTML
+    pub body_html: String,
     pub sender: SenderSchema,
     pub timestamp: DateTime<Utc>,
+    pub attachments: Vec<MessageAttachmentSchema>,
+    /// 送信者以外で、このメッセージを既読にしたユーザー
+    pub read_by: Vec<SenderSchema>,
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct MessageAttachmentSchema {
+    pub id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u64,
+}
+
+impl From<&MessageAttachment> for MessageAttachmentSchema {
+    fn from(attachment: &MessageAttachment) -> Self {
+        Self {
+            id: attachment.id().into_inner(),
+            file_name: attachment.file_name().to_owned(),
+            content_type: attachment.content_type().to_owned(),
+            size: *attachment.size(),
+        }
+    }
 }
 
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct SenderSchema {
     pub uuid: String,
     pub name: String,
+    /// 送信者のロール。ログインせずに回答した人は `TEMPORARY_ANSWER_AUTHOR` になる。
     pub role: String,
 }
 
+impl From<&AccountUser> for SenderSchema {
+    fn from(user: &AccountUser) -> Self {
+        Self {
+            uuid: user.id().to_string(),
+            name: user.name().to_owned(),
+            role: user.role().to_string(),
+        }
+    }
+}
+
+impl From<&MessageSenderProfile> for SenderSchema {
+    fn from(sender: &MessageSenderProfile) -> Self {
+        match sender {
+            MessageSenderProfile::User(user) => user.into(),
+            MessageSenderProfile::TemporaryAnswerAuthor(author) => Self {
+                uuid: author.id().to_string(),
+                name: author.name().to_owned(),
+                role: "TEMPORARY_ANSWER_AUTHOR".to_owned(),
+            },
+        }
+    }
+}
+
+impl From<MessageWithSender> for MessageContentSchema {
+    fn from(message_with_sender: MessageWithSender) -> Self {
+        let message = message_with_sender.message;
+
+        Self {
+            id: message.id().into_inner(),
+            body: message.body().as_str().to_owned(),
+            body_html: render_html(message.body().as_str()),
+            sender: (&message_with_sender.sender).into(),
+            timestamp: message.timestamp().to_owned(),
+            attachments: message.attachments().iter().map(Into::into).collect(),
+            read_by: message_with_sender.read_by.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// 一時回答の送信元をハッシュにした値。管理者だけが取得できる。
+///
+/// 同じ値を持つ回答は、同じ送信元から送られた可能性が高い。
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct TemporaryAnswerAbuseMetadataResponse {
+    /// salt 付きでハッシュにしたクライアントの IP アドレス。
+    pub client_ip_hash: Option<String>,
+    /// salt 付きでハッシュにした User-Agent。
+    pub user_agent_fingerprint: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<TemporaryAnswerAbuseMetadata> for TemporaryAnswerAbuseMetadataResponse {
+    fn from(metadata: TemporaryAnswerAbuseMetadata) -> Self {
+        Self {
+            client_ip_hash: metadata.client_ip_hash().to_owned(),
+            user_agent_fingerprint: metadata.user_agent_fingerprint().to_owned(),
+            recorded_at: *metadata.recorded_at(),
+        }
+    }
+}
+
+/// 回答のアクセスリンクから見た、ログインせずに投稿した回答の状況。
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct TemporaryAnswerAccessResponse {
+    pub form_id: Uuid,
+    pub form_title: String,
+    pub answer_id: Uuid,
+    pub title: Option<String>,
+    pub status: AnswerStatus,
+    pub timestamp: DateTime<Utc>,
+    pub answers: Vec<AnswerContent>,
+}
+
+impl TemporaryAnswerAccessResponse {
+    pub fn new(form: &ActiveForm, answer: &AnswerEntry) -> Self {
+        Self {
+            form_id: form.id().into_inner(),
+            form_title: form.title().as_str().to_owned(),
+            answer_id: answer.id().into_inner(),
+            title: answer
+                .title()
+                .clone()
+                .into_inner()
+                .map(|title| title.into_inner()),
+            status: (*answer.status()).into(),
+            timestamp: *answer.timestamp(),
+            answers: answer
+                .contents()
+                .iter()
+                .map(AnswerContent::from_ref)
+                .collect_vec(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -874,8 +1418,12 @@ mod tests {
             title: AnswerTitle::new(None),
             publication: DomainAnswerPublication::PUBLIC,
             status: DomainAnswerStatus::UNADDRESSED,
+            moderation_status: DomainAnswerModerationStatus::APPROVED,
             contents: vec![],
             redmine_reference: None,
+            assignee: None,
+            due_at: None,
+            quiz_score: None,
         };
 
         let serialized = serde_json::to_value(FormAnswer::new(
@@ -903,15 +1451,19 @@ mod tests {
             title: AnswerTitle::new(None),
             publication: DomainAnswerPublication::PUBLIC,
             status: DomainAnswerStatus::UNADDRESSED,
+            moderation_status: DomainAnswerModerationStatus::APPROVED,
             contents: vec![],
             redmine_reference: Some(RedmineImportedAnswerReference::new(answer_id, 1234.into())),
+            assignee: None,
+            due_at: None,
+            quiz_score: None,
         };
         let comment = Comment::imported_from_redmine(
             answer_id,
             CommentId::new(),
             5678,
             RedmineUserSnapshot::new(None, "Redmine commenter".to_string()),
-            CommentContent::new("imported comment".to_string().try_into().unwrap()),
+            CommentContent::new("imported **comment**".to_string().try_into().unwrap()),
             Utc::now(),
         );
 
@@ -937,6 +1489,12 @@ mod tests {
         );
         assert_eq!(answer_json["redmine_issue_id"], 1234);
         assert_eq!(comment_json["source"], "IMPORTED_FROM_REDMINE");
+        assert_eq!(comment_json["visibility"], "PUBLIC");
+        assert_eq!(comment_json["content"], "imported **comment**");
+        assert_eq!(
+            comment_json["content_html"],
+            "<p>imported <strong>comment</strong></p>"
+        );
         assert_eq!(comment_json["redmine_journal_id"], 5678);
         assert_eq!(
             comment_json["redmine_author_snapshot"]["display_name"],
@@ -945,3 +1503,131 @@ mod tests {
         assert!(comment_json.get("commented_by").is_none());
     }
 }
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentFilterConditionResponse {
+    BlockedWord { word: String },
+    Pattern { pattern: String },
+    MaxLinks { max_links: u32 },
+    RepeatedCharacters { run_length: u32 },
+}
+
+impl From<&ContentFilterCondition> for ContentFilterConditionResponse {
+    fn from(condition: &ContentFilterCondition) -> Self {
+        match condition {
+            ContentFilterCondition::BlockedWord(word) => Self::BlockedWord {
+                word: word.as_str().to_owned(),
+            },
+            ContentFilterCondition::Pattern(pattern) => Self::Pattern {
+                pattern: pattern.as_str().to_owned(),
+            },
+            ContentFilterCondition::MaxLinks(max_links) => Self::MaxLinks {
+                max_links: *max_links,
+            },
+            ContentFilterCondition::RepeatedCharacters(run_length) => Self::RepeatedCharacters {
+                run_length: *run_length,
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentFilterActionResponse {
+    Reject,
+    FlagForReview,
+    AddLabel {
+        #[schema(value_type = String, format = "uuid")]
+        label_id: String,
+    },
+}
+
+impl From<ContentFilterAction> for ContentFilterActionResponse {
+    fn from(action: ContentFilterAction) -> Self {
+        match action {
+            ContentFilterAction::Reject => Self::Reject,
+            ContentFilterAction::FlagForReview => Self::FlagForReview,
+            ContentFilterAction::AddLabel(label_id) => Self::AddLabel {
+                label_id: label_id.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct ContentFilterRuleResponse {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: String,
+    pub condition: ContentFilterConditionResponse,
+    pub action: ContentFilterActionResponse,
+    #[schema(value_type = String, format = "uuid")]
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ContentFilterRule> for ContentFilterRuleResponse {
+    fn from(rule: ContentFilterRule) -> Self {
+        Self {
+            id: rule.id().to_string(),
+            condition: rule.condition().into(),
+            action: (*rule.action()).into(),
+            created_by: rule.created_by().to_string(),
+            created_at: *rule.created_at(),
+        }
+    }
+}
+
+/// 条件に当てはまった投稿。コメントとメッセージは、投稿先の回答と合わせて示す。
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentFilterTargetResponse {
+    Answer,
+    Comment {
+        #[schema(value_type = String, format = "uuid")]
+        comment_id: String,
+    },
+    Message {
+        #[schema(value_type = String, format = "uuid")]
+        message_id: String,
+    },
+}
+
+impl From<&ContentFilterTarget> for ContentFilterTargetResponse {
+    fn from(target: &ContentFilterTarget) -> Self {
+        match target {
+            ContentFilterTarget::Answer => Self::Answer,
+            ContentFilterTarget::Comment(comment_id) => Self::Comment {
+                comment_id: comment_id.to_string(),
+            },
+            ContentFilterTarget::Message(message_id) => Self::Message {
+                message_id: message_id.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug, utoipa::ToSchema)]
+pub struct ContentFilterFlagResponse {
+    #[schema(value_type = String, format = "uuid")]
+    pub id: String,
+    #[schema(value_type = String, format = "uuid")]
+    pub answer_id: String,
+    pub target: ContentFilterTargetResponse,
+    /// 当てはまった規則の ID。規則が削除された場合は `null`。
+    #[schema(value_type = Option<String>, format = "uuid")]
+    pub rule_id: Option<String>,
+    pub flagged_at: DateTime<Utc>,
+}
+
+impl From<ContentFilterFlag> for ContentFilterFlagResponse {
+    fn from(flag: ContentFilterFlag) -> Self {
+        Self {
+            id: flag.id().to_string(),
+            answer_id: flag.answer_id().to_string(),
+            target: flag.target().into(),
+            rule_id: flag.rule_id().map(|rule_id| rule_id.to_string()),
+            flagged_at: *flag.flagged_at(),
+        }
+    }
+}
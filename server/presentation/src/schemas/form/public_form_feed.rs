@@ -0,0 +1,187 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use domain::form::{
+    models::AnswerAcceptancePeriod,
+    public_feed::{PublicFormFeed, PublicFormFeedEntry},
+};
+
+pub const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+
+/// エントリーの概要に載せる、フォームの説明の最大文字数。
+const DESCRIPTION_EXCERPT_CHARS: usize = 200;
+
+/// 回答受付中の公開フォームを Atom (RFC 4287) で表す。
+///
+/// フォームの URL は `frontend_url` を基準に組み立てる。
+pub fn render_atom(feed: &PublicFormFeed, frontend_url: &str) -> String {
+    let frontend_url = frontend_url.trim_end_matches('/');
+    let forms_url = escape_xml(&format!("{frontend_url}/forms"));
+    let updated_at = feed.updated_at().unwrap_or(DateTime::UNIX_EPOCH);
+
+    let mut atom = String::new();
+    atom.push_str(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    atom.push('\n');
+    atom.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+    atom.push('\n');
+    atom.push_str(&format!("  <id>{forms_url}</id>\n"));
+    atom.push_str("  <title>Seichi Portal - 回答受付中のフォーム</title>\n");
+    atom.push_str(&format!(
+        "  <link rel=\"alternate\" type=\"text/html\" href=\"{forms_url}\"/>\n"
+    ));
+    atom.push_str(&format!("  <updated>{}</updated>\n", timestamp(updated_at)));
+    atom.push_str("  <author><name>Seichi Portal</name></author>\n");
+    for entry in feed.entries() {
+        atom.push_str(&render_entry(entry, frontend_url));
+    }
+    atom.push_str("</feed>\n");
+
+    atom
+}
+
+fn render_entry(entry: &PublicFormFeedEntry, frontend_url: &str) -> String {
+    let form = entry.form();
+    let form_id = form.id().into_inner();
+    let form_url = escape_xml(&format!("{frontend_url}/forms/{form_id}"));
+    let period = acceptance_period_text(form.answer_settings().acceptance_period());
+
+    let mut atom = String::new();
+    atom.push_str("  <entry>\n");
+    atom.push_str(&format!("    <id>urn:uuid:{form_id}</id>\n"));
+    atom.push_str(&format!(
+        "    <title type=\"text\">{}</title>\n",
+        escape_xml(form.title().as_str())
+    ));
+    atom.push_str(&format!(
+        "    <link rel=\"alternate\" type=\"text/html\" href=\"{form_url}\"/>\n"
+    ));
+    atom.push_str(&format!(
+        "    <published>{}</published>\n",
+        timestamp(form.metadata().created_at)
+    ));
+    atom.push_str(&format!(
+        "    <updated>{}</updated>\n",
+        timestamp(*entry.updated_at())
+    ));
+    atom.push_str(&format!(
+        "    <summary type=\"text\">{}</summary>\n",
+        escape_xml(&excerpt(form.description().as_str()))
+    ));
+    atom.push_str(&format!(
+        "    <content type=\"text\">{}</content>\n",
+        escape_xml(&period)
+    ));
+    atom.push_str("  </entry>\n");
+
+    atom
+}
+
+fn timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn excerpt(description: &str) -> String {
+    let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    match description.char_indices().nth(DESCRIPTION_EXCERPT_CHARS) {
+        Some((end, _)) => format!("{}…", &description[..end]),
+        None => description,
+    }
+}
+
+fn acceptance_period_text(period: &AnswerAcceptancePeriod) -> String {
+    let start_at = period
+        .start_at()
+        .map_or_else(|| "指定なし".to_owned(), timestamp);
+    let end_at = period
+        .end_at()
+        .map_or_else(|| "指定なし".to_owned(), timestamp);
+
+    format!("回答受付期間: {start_at} 〜 {end_at}")
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 で使えない制御文字は取り除く。
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use domain::form::models::{
+        ActiveForm, AnswerSettings, FormDescription, FormId, FormLabelAssignment, FormMeta,
+        FormSettings, FormTitle, Question, QuestionSet,
+    };
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn form(title: &str, description: &str, period: AnswerAcceptancePeriod) -> ActiveForm {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap();
+
+        unsafe {
+            ActiveForm::from_raw_parts(
+                FormId::from(Uuid::from_u128(1)),
+                FormTitle::new(title.to_string().try_into().unwrap()),
+                FormDescription::new(description.to_string()),
+                FormMeta::new(),
+                FormSettings::new(),
+                AnswerSettings::default().change_acceptance_period(period),
+                QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+                FormLabelAssignment::empty(),
+            )
+        }
+    }
+
+    #[test]
+    fn renders_escaped_entries_with_form_urls() {
+        let end_at = Utc.with_ymd_and_hms(2099, 1, 1, 0, 0, 0).unwrap();
+        let feed = PublicFormFeed::new(
+            vec![form(
+                "建築 & 整地 <募集>",
+                "説明",
+                AnswerAcceptancePeriod::try_new(None, Some(end_at)).unwrap(),
+            )],
+            Utc::now(),
+        );
+
+        let atom = render_atom(&feed, "https://portal.example.com/");
+
+        assert!(atom.contains("<title type=\"text\">建築 &amp; 整地 &lt;募集&gt;</title>"));
+        assert!(atom.contains(
+            "href=\"https://portal.example.com/forms/00000000-0000-0000-0000-000000000001\""
+        ));
+        assert!(atom.contains("<id>urn:uuid:00000000-0000-0000-0000-000000000001</id>"));
+        assert!(atom.contains("回答受付期間: 指定なし 〜 2099-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn long_descriptions_are_shortened() {
+        let description = "あ".repeat(DESCRIPTION_EXCERPT_CHARS + 10);
+
+        assert_eq!(
+            excerpt(&description),
+            format!("{}…", "あ".repeat(DESCRIPTION_EXCERPT_CHARS))
+        );
+        assert_eq!(excerpt("短い\n説明"), "短い 説明");
+    }
+}
@@ -7,18 +7,13 @@ use types::non_empty_string::NonEmptyString;
 use usecase::models::{AnswerDetails, CrossSearchComment, CrossSearchOutput};
 
 use crate::schemas::{
+    announcement::AnnouncementResponse,
     form::form_response_schemas::{
         AnswerComment, AnswerLabelResponseSchema, FormAnswer, FormLabelResponseSchema, FormSchema,
     },
     user::UserSchema,
 };
 
-impl From<AnswerDetails> for FormAnswer {
-    fn from(details: AnswerDetails) -> Self {
-        Self::new(details.answer, details.form_id, details.labels)
-    }
-}
-
 #[derive(Deserialize, Debug, PartialEq, utoipa::ToSchema)]
 pub struct SearchQuery {
     #[serde(default)]
@@ -37,6 +32,14 @@ pub struct AnswerSearchQuery {
     pub status: Option<AnswerStatus>,
 }
 
+#[derive(Deserialize, Debug, PartialEq, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SimilarAnswerQuery {
+    /// Maximum number of suggestions (1-20, default 5)
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
 #[derive(Serialize, Debug, utoipa::ToSchema)]
 pub struct SearchCommentSchema {
     #[schema(value_type = String, format = "uuid")]
@@ -65,6 +68,7 @@ pub struct CrossSearchResult {
     pub label_for_forms: Vec<FormLabelResponseSchema>,
     pub label_for_answers: Vec<AnswerLabelResponseSchema>,
     pub comments: Vec<SearchCommentSchema>,
+    pub announcements: Vec<AnnouncementResponse>,
 }
 
 impl CrossSearchResult {
@@ -84,6 +88,7 @@ impl CrossSearchResult {
                 .map(Into::into)
                 .collect(),
             comments: output.comments.into_iter().map(Into::into).collect(),
+            announcements: output.announcements.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -117,7 +122,7 @@ mod tests {
                 AnswerAuthor, AnswerEntry, AnswerId, AnswerLabel, AnswerTitle, FormAnswerContent,
                 FormAnswerContentId,
             },
-            comment::{Comment, CommentContent, CommentId},
+            comment::{Comment, CommentContent, CommentId, CommentVisibility},
             models::{
                 ActiveForm, DiscordWebhookUrl, FormDescription, FormLabel, FormLabelName,
                 FormSettings, FormTitle,
@@ -244,6 +249,7 @@ mod tests {
                 CommentContent::new("comment content".to_string().try_into().unwrap()),
                 Utc::now(),
                 *answer_author.id(),
+                CommentVisibility::Public,
             )
         };
         let searched_user = AccountUser::with_groups(
@@ -268,10 +274,13 @@ mod tests {
                 answer: PublishedAnswerEntry::new(
                     answer,
                     PublishedAnswerAuthor::AuthenticatedUser(answer_author.clone()),
+                    None,
+                    None,
                 ),
                 labels: vec![AnswerLabel::new(
                     "answer label".to_string().try_into().unwrap(),
                 )],
+                unread_message_count: None,
             }],
             label_for_forms: vec![FormLabel::new(FormLabelName::new(
                 "matching form label".to_string().try_into().unwrap(),
@@ -286,6 +295,7 @@ mod tests {
                     commented_by: CommentAuthor::Portal(answer_author),
                 },
             }],
+            announcements: vec![],
         });
 
         let serialized = serde_json::to_value(result).unwrap();
@@ -354,6 +364,7 @@ mod tests {
             label_for_forms: vec![],
             label_for_answers: vec![],
             comments: vec![],
+            announcements: vec![],
         });
 
         let serialized = serde_json::to_value(result).unwrap();
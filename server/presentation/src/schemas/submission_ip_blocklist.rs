@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use domain::submission_ip_blocklist::SubmissionIpBlock;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SubmissionIpBlockCreateSchema {
+    /// 拒否する IP アドレス、または CIDR 表記の範囲 (例: `203.0.113.0/24`)。
+    pub ip_range: String,
+    /// 登録した理由。管理者だけが見られる。
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SubmissionIpBlockResponse {
+    pub id: Uuid,
+    pub ip_range: String,
+    pub reason: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<SubmissionIpBlock> for SubmissionIpBlockResponse {
+    fn from(block: SubmissionIpBlock) -> Self {
+        Self {
+            id: block.id().into_inner(),
+            ip_range: block.range().to_string(),
+            reason: block
+                .reason()
+                .as_ref()
+                .map(|reason| reason.as_str().to_owned()),
+            created_by: block.created_by().into_inner(),
+            created_at: *block.created_at(),
+        }
+    }
+}
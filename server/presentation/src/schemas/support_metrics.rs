@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use domain::support_metrics::{OpenAnswerCount, StaffActivity, SupportMetrics};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SupportMetricsQuery {
+    /// 集計期間の開始日時 (この日時を含む)
+    pub from: DateTime<Utc>,
+    /// 集計期間の終了日時 (この日時を含まない)
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SupportMetricsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// 回答の送信から運営メンバーの最初のメッセージまでの秒数の中央値
+    pub median_first_response_seconds: Option<i64>,
+    /// 回答の送信から最初に `COMPLETED` になるまでの秒数の中央値
+    pub median_time_to_completion_seconds: Option<i64>,
+    /// 現在 `COMPLETED` になっていない回答のフォームごとの件数
+    pub open_answers: Vec<OpenAnswerCountResponse>,
+    pub staff_activities: Vec<StaffActivityResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OpenAnswerCountResponse {
+    pub form_id: String,
+    pub form_title: String,
+    pub count: u32,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StaffActivityResponse {
+    pub user_id: String,
+    pub name: String,
+    /// 完了への変更・メッセージ・コメントのいずれかを行った回答の件数
+    pub handled_answers: u32,
+    pub completed_answers: u32,
+    pub messages: u32,
+    pub comments: u32,
+}
+
+impl From<SupportMetrics> for SupportMetricsResponse {
+    fn from(metrics: SupportMetrics) -> Self {
+        Self {
+            from: *metrics.period().from(),
+            to: *metrics.period().to(),
+            median_first_response_seconds: metrics
+                .median_first_response()
+                .map(|duration| duration.num_seconds()),
+            median_time_to_completion_seconds: metrics
+                .median_time_to_completion()
+                .map(|duration| duration.num_seconds()),
+            open_answers: metrics
+                .open_answers()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            staff_activities: metrics
+                .staff_activities()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<OpenAnswerCount> for OpenAnswerCountResponse {
+    fn from(count: OpenAnswerCount) -> Self {
+        Self {
+            form_id: count.form_id.to_string(),
+            form_title: count.form_title,
+            count: count.count,
+        }
+    }
+}
+
+impl From<StaffActivity> for StaffActivityResponse {
+    fn from(activity: StaffActivity) -> Self {
+        Self {
+            user_id: activity.user_id.to_string(),
+            name: activity.name,
+            handled_answers: activity.handled_answers,
+            completed_answers: activity.completed_answers,
+            messages: activity.messages,
+            comments: activity.comments,
+        }
+    }
+}
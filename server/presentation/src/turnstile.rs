@@ -19,6 +19,7 @@ pub const TURNSTILE_TOKEN_HEADER: &str = "x-seichi-turnstile-token";
 enum TurnstileAction {
     SessionCreate,
     TemporaryAnswer,
+    TemporaryAnswerMessage,
 }
 
 impl TurnstileAction {
@@ -26,6 +27,7 @@ impl TurnstileAction {
         match self {
             Self::SessionCreate => "session-create",
             Self::TemporaryAnswer => "temporary-answer",
+            Self::TemporaryAnswerMessage => "temporary-answer-message",
         }
     }
 }
@@ -86,6 +88,7 @@ fn expected_action(method: &Method, path: &str) -> Option<TurnstileAction> {
     match segments.as_slice() {
         ["session"] => Some(TurnstileAction::SessionCreate),
         ["forms", _, "temporary-answers"] => Some(TurnstileAction::TemporaryAnswer),
+        ["temporary-answer-access", "messages"] => Some(TurnstileAction::TemporaryAnswerMessage),
         _ => None,
     }
 }
@@ -279,7 +282,7 @@ mod tests {
     }
 
     #[test]
-    fn only_the_two_public_post_routes_have_an_action() {
+    fn only_the_public_post_routes_have_an_action() {
         assert_eq!(
             expected_action(&Method::POST, "/session"),
             Some(TurnstileAction::SessionCreate)
@@ -291,6 +294,14 @@ mod tests {
             ),
             Some(TurnstileAction::TemporaryAnswer)
         );
+        assert_eq!(
+            expected_action(&Method::POST, "/temporary-answer-access/messages"),
+            Some(TurnstileAction::TemporaryAnswerMessage)
+        );
+        assert_eq!(
+            expected_action(&Method::GET, "/temporary-answer-access/messages"),
+            None
+        );
         assert_eq!(expected_action(&Method::GET, "/forms"), None);
         assert_eq!(expected_action(&Method::POST, "/users"), None);
         assert_eq!(expected_action(&Method::POST, "/api/v10/session"), None);
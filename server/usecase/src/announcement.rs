@@ -0,0 +1,335 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    account::models::AccountUser,
+    announcement::{Announcement, AnnouncementId, AnnouncementPublishPeriod},
+    auth::Actor,
+    form::models::AllowedUserGroups,
+    repository::{
+        announcement_repository::AnnouncementRepository, user_repository::UserRepository,
+    },
+    types::authorization_guard::{AuthorizationGuard, Create},
+};
+use errors::{
+    Error,
+    usecase::UseCaseError::{AnnouncementNotFound, UserGroupNotFound},
+};
+use types::non_empty_string::NonEmptyString;
+
+pub struct AnnouncementUseCase<
+    'a,
+    AnnouncementRepo: AnnouncementRepository,
+    UserRepo: UserRepository,
+> {
+    pub announcement_repository: &'a AnnouncementRepo,
+    pub user_repository: &'a UserRepo,
+}
+
+impl<R1: AnnouncementRepository, R2: UserRepository> AnnouncementUseCase<'_, R1, R2> {
+    async fn validate_target_groups(
+        &self,
+        actor: &Actor,
+        groups: &AllowedUserGroups,
+    ) -> Result<(), Error> {
+        for group_id in groups.as_slice() {
+            self.user_repository
+                .find_user_group(*group_id)
+                .await?
+                .ok_or(Error::from(UserGroupNotFound))?
+                .try_read(actor.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// `actor` に掲載するお知らせを、固定表示のものから順に返す。
+    ///
+    /// `include_unpublished` を指定すると、読み取れるお知らせを公開期間に関わらず返す。
+    /// 公開期間外のお知らせを読めるのは管理者だけなので、それ以外の利用者には影響しない。
+    pub async fn list(
+        &self,
+        actor: &Actor,
+        include_unpublished: bool,
+    ) -> Result<Vec<Announcement>, Error> {
+        let now = Utc::now();
+
+        Ok(self
+            .announcement_repository
+            .list()
+            .await?
+            .into_iter()
+            .filter_map(|announcement| announcement.try_read(actor.clone()).ok())
+            .map(|announcement| announcement.into_inner())
+            .filter(|announcement| include_unpublished || announcement.is_published_at(now))
+            .collect())
+    }
+
+    pub async fn get(&self, actor: &Actor, id: AnnouncementId) -> Result<Announcement, Error> {
+        self.announcement_repository
+            .get(id)
+            .await?
+            .ok_or(AnnouncementNotFound)?
+            .try_read(actor.clone())
+            .map(|announcement| announcement.into_inner())
+            .map_err(Into::into)
+    }
+
+    pub async fn create(
+        &self,
+        actor: &AccountUser,
+        title: NonEmptyString,
+        body: NonEmptyString,
+        publish_period: AnnouncementPublishPeriod,
+        pinned: bool,
+        target_groups: AllowedUserGroups,
+    ) -> Result<Announcement, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let announcement = AuthorizationGuard::<_, Create>::from(Announcement::new(
+            title,
+            body,
+            publish_period,
+            pinned,
+            target_groups,
+            *actor.id(),
+        ))
+        .try_create(actor_ref.clone())?;
+        self.validate_target_groups(&actor_ref, announcement.target_groups())
+            .await?;
+
+        let created = announcement.value().to_owned();
+        self.announcement_repository.create(announcement).await?;
+
+        Ok(created)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        actor: &AccountUser,
+        id: AnnouncementId,
+        title: Option<NonEmptyString>,
+        body: Option<NonEmptyString>,
+        publish_from: Option<DateTime<Utc>>,
+        publish_until: Option<Option<DateTime<Utc>>>,
+        pinned: Option<bool>,
+        target_groups: Option<AllowedUserGroups>,
+    ) -> Result<Announcement, Error> {
+        let actor = Actor::from(actor.clone());
+        let announcement = self
+            .announcement_repository
+            .get(id)
+            .await?
+            .ok_or(AnnouncementNotFound)?
+            .into_update()
+            .try_update(actor.clone())?
+            .try_map(|announcement| {
+                announcement.try_edited(
+                    title,
+                    body,
+                    publish_from,
+                    publish_until,
+                    pinned,
+                    target_groups,
+                )
+            })?;
+        self.validate_target_groups(&actor, announcement.target_groups())
+            .await?;
+
+        let updated = announcement.value().to_owned();
+        self.announcement_repository.update(announcement).await?;
+
+        Ok(updated)
+    }
+
+    pub async fn delete(&self, actor: &AccountUser, id: AnnouncementId) -> Result<(), Error> {
+        let announcement = self
+            .announcement_repository
+            .get(id)
+            .await?
+            .ok_or(AnnouncementNotFound)?
+            .into_delete()
+            .try_delete(Actor::from(actor.clone()))?;
+
+        self.announcement_repository.delete(announcement).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use domain::{
+        account::models::{Role, UserGroup, UserGroupName, UserId},
+        repository::{
+            announcement_repository::MockAnnouncementRepository,
+            user_repository::MockUserRepository,
+        },
+        types::authorization_guard::Read,
+    };
+    use errors::domain::DomainError;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn text(value: &str) -> NonEmptyString {
+        NonEmptyString::try_new(value.to_string()).unwrap()
+    }
+
+    fn announcement(
+        title: &str,
+        publish_period: AnnouncementPublishPeriod,
+        target_groups: AllowedUserGroups,
+    ) -> Announcement {
+        Announcement::new(
+            text(title),
+            text("本文"),
+            publish_period,
+            false,
+            target_groups,
+            UserId::from(Uuid::new_v4()),
+        )
+    }
+
+    fn published_since_an_hour_ago() -> AnnouncementPublishPeriod {
+        AnnouncementPublishPeriod::try_new(Utc::now() - Duration::hours(1), None).unwrap()
+    }
+
+    fn repository_with(announcements: Vec<Announcement>) -> MockAnnouncementRepository {
+        let mut repository = MockAnnouncementRepository::new();
+        repository.expect_list().returning(move || {
+            Ok(announcements
+                .iter()
+                .cloned()
+                .map(AuthorizationGuard::<_, Read>::from)
+                .collect())
+        });
+        repository
+    }
+
+    fn titles(announcements: Vec<Announcement>) -> Vec<String> {
+        announcements
+            .into_iter()
+            .map(|announcement| announcement.title().as_str().to_owned())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn list_respects_publish_period_and_targeting() {
+        let group = UserGroup::new(UserGroupName::new(
+            "builders".to_string().try_into().unwrap(),
+        ));
+        let scheduled_period =
+            AnnouncementPublishPeriod::try_new(Utc::now() + Duration::days(1), None).unwrap();
+        let repository = repository_with(vec![
+            announcement(
+                "全体",
+                published_since_an_hour_ago(),
+                AllowedUserGroups::unrestricted(),
+            ),
+            announcement(
+                "建築勢向け",
+                published_since_an_hour_ago(),
+                AllowedUserGroups::new(vec![*group.id()]),
+            ),
+            announcement(
+                "予約済み",
+                scheduled_period,
+                AllowedUserGroups::unrestricted(),
+            ),
+        ]);
+        let user_repository = MockUserRepository::new();
+        let usecase = AnnouncementUseCase {
+            announcement_repository: &repository,
+            user_repository: &user_repository,
+        };
+        let member = Actor::from(AccountUser::with_groups(
+            "member".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+            vec![group],
+        ));
+        let administrator = Actor::from(AccountUser::new(
+            "admin".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        ));
+
+        assert_eq!(
+            titles(usecase.list(&Actor::Anonymous, true).await.unwrap()),
+            vec!["全体"]
+        );
+        assert_eq!(
+            titles(usecase.list(&member, false).await.unwrap()),
+            vec!["全体", "建築勢向け"]
+        );
+        assert_eq!(
+            titles(usecase.list(&administrator, false).await.unwrap()),
+            vec!["全体", "建築勢向け"]
+        );
+        assert_eq!(
+            titles(usecase.list(&administrator, true).await.unwrap()),
+            vec!["全体", "建築勢向け", "予約済み"]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_administrators_can_create_announcements() {
+        let mut repository = MockAnnouncementRepository::new();
+        repository.expect_create().never();
+        let user_repository = MockUserRepository::new();
+        let usecase = AnnouncementUseCase {
+            announcement_repository: &repository,
+            user_repository: &user_repository,
+        };
+        let standard_user = AccountUser::new(
+            "user".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+
+        assert_eq!(
+            usecase
+                .create(
+                    &standard_user,
+                    text("お知らせ"),
+                    text("本文"),
+                    published_since_an_hour_ago(),
+                    false,
+                    AllowedUserGroups::unrestricted(),
+                )
+                .await,
+            Err(DomainError::Forbidden.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn announcement_for_an_unknown_group_is_not_created() {
+        let mut repository = MockAnnouncementRepository::new();
+        repository.expect_create().never();
+        let mut user_repository = MockUserRepository::new();
+        user_repository
+            .expect_find_user_group()
+            .returning(|_| Ok(None));
+        let usecase = AnnouncementUseCase {
+            announcement_repository: &repository,
+            user_repository: &user_repository,
+        };
+        let administrator = AccountUser::new(
+            "admin".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+
+        assert_eq!(
+            usecase
+                .create(
+                    &administrator,
+                    text("お知らせ"),
+                    text("本文"),
+                    published_since_an_hour_ago(),
+                    false,
+                    AllowedUserGroups::new(vec![Uuid::new_v4().into()]),
+                )
+                .await,
+            Err(UserGroupNotFound.into())
+        );
+    }
+}
@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use domain::{
     account::models::AccountUser,
-    form::answer::{AnswerStatusChange, TemporaryAnswerAuthor},
+    form::answer::{AnswerStatus, AnswerStatusChange, TemporaryAnswerAuthor},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -87,6 +88,14 @@ pub enum ApplicationEvent {
         answer_id: String,
         status_change: AnswerStatusChange,
     },
+    /// 対応期限を過ぎても完了していない回答を、定期チェックが検出したことを表す。
+    AnswerOverdue {
+        form_id: String,
+        answer_title: Option<String>,
+        answer_id: String,
+        due_at: DateTime<Utc>,
+        status: AnswerStatus,
+    },
     CommentCreated {
         actor: ApplicationActor,
         form_id: String,
@@ -1,9 +1,14 @@
 pub mod answer;
+pub mod answer_draw;
 pub mod answer_label;
 pub mod answer_relation;
 pub mod comment;
+pub mod content_filter;
 pub mod discord_answer_webhook;
 pub mod form;
 pub mod form_label;
+pub(crate) mod mention;
 pub mod message;
+pub mod message_template;
 pub(crate) mod submission;
+pub mod temporary_answer_access;
@@ -1,52 +1,89 @@
-#[cfg(test)]
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use domain::{
-    account::models::AccountUser,
+    account::models::{AccountUser, UserId},
     auth::Actor,
     form::answer::TemporaryAnswerAuthor,
     form::{
         answer::{
-            AnswerAuthor, AnswerAuthorDisclosure, AnswerEntry, AnswerId, AnswerLabel,
-            AnswerPagePosition, AnswerPublication, AnswerStatus, AnswerStatusChange,
+            AnswerAssignmentHistoryEntry, AnswerAssignmentHistoryPagePosition, AnswerAuthor,
+            AnswerAuthorDisclosure, AnswerEntry, AnswerId, AnswerLabel, AnswerLabelId,
+            AnswerListFilter, AnswerModerationDecision, AnswerModerationStatus,
+            AnswerMoveHistoryEntry, AnswerMoveHistoryPagePosition, AnswerPagePosition,
+            AnswerPublication, AnswerQuizScore, AnswerReference, AnswerRelation,
+            AnswerRelationKind, AnswerStatistics, AnswerStatus, AnswerStatusChange,
             AnswerStatusHistoryEntry, AnswerStatusHistoryPagePosition, AnswerTitle,
             AnswerTitleHistoryEntry, AnswerTitleHistoryPagePosition, FormAnswerContent,
             PostedAnswerContents,
         },
-        models::{ActiveForm, FormId},
+        content_filter::{ContentFilterTarget, ContentFilterVerdict},
+        models::{ActiveForm, AnswerSettings, FormId},
+        question::{Question, QuestionId},
         service::DefaultAnswerTitleDomainService,
+        temporary_answer_abuse::{TemporaryAnswerAbuseMetadata, TemporaryAnswerSubmissionSource},
+    },
+    notification::{
+        models::{NotificationContent, NotificationType},
+        notificator::Notificator,
     },
-    pagination::{Page, PageRequest},
+    pagination::{Page, PageLimit, PageRequest},
     repository::user_repository::UserRepository,
     repository::{
         form::{
             active_form_repository::ActiveFormRepository,
             answer_entry_repository::AnswerEntryRepository,
             answer_label_repository::AnswerLabelRepository,
+            content_filter_repository::ContentFilterRepository,
+            message_read_repository::MessageReadRepository,
+            temporary_answer_abuse_repository::TemporaryAnswerAbuseRepository,
         },
         form_submission_restriction_repository::FormSubmissionRestrictionRepository,
+        notification_repository::NotificationRepository,
     },
-    types::authorization_guard::{Allowed, Create, Read},
+    types::authorization_guard::{Allowed, Read},
 };
 use errors::{
     Error,
     domain::DomainError,
-    usecase::UseCaseError::{AnswerNotFound, FormNotFound, UserNotFound},
+    usecase::UseCaseError::{
+        AnswerNotFound, FormNotFound, LabelNotFound, TemporaryAnswerAbuseMetadataNotFound,
+        UserNotFound,
+    },
 };
 use futures::{StreamExt, stream};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{sync::Notify, time};
 
 use crate::{
     application_event::{
         AnswerSubmissionActor, ApplicationActor, ApplicationEvent, ApplicationEventPublisher,
         EventDetail,
     },
-    forms::discord_answer_webhook::{
-        DiscordAnswerWebhookField, DiscordAnswerWebhookNotification, DiscordAnswerWebhookNotifier,
+    forms::{
+        content_filter::{enforce_verdict, screen_content},
+        discord_answer_webhook::{
+            DiscordAnswerWebhookField, DiscordAnswerWebhookNotification,
+            DiscordAnswerWebhookNotifier,
+        },
+    },
+    models::{
+        AnswerDetails, BulkAnswerOperationResult, PostedTemporaryAnswer, PublishedAnswerAuthor,
+        PublishedAnswerEntry,
     },
-    models::{AnswerDetails, PublishedAnswerAuthor, PublishedAnswerEntry},
+    notification::notification_preference_for,
     user_reference_resolver::resolve_user_references,
 };
 use common::config::FRONTEND;
 
+/// 回答の一括操作で、対象の各回答に適用する変更です。
+#[derive(Clone, Copy, Debug)]
+pub enum BulkAnswerOperation {
+    ChangeStatus(AnswerStatus),
+    ChangePublication(AnswerPublication),
+    AddLabel(AnswerLabelId),
+    RemoveLabel(AnswerLabelId),
+    Moderate(AnswerModerationDecision),
+}
+
 pub struct AnswerUseCase<
     'a,
     FormRepo: ActiveFormRepository,
@@ -54,12 +91,17 @@ pub struct AnswerUseCase<
     UserRepo: UserRepository,
     FormSubmissionRestrictionRepo: FormSubmissionRestrictionRepository,
     AnswerEntryRepo: AnswerEntryRepository,
+    ContentFilterRepo: ContentFilterRepository,
+    TemporaryAnswerAbuseRepo: TemporaryAnswerAbuseRepository,
 > {
     pub active_form_repository: &'a FormRepo,
     pub answer_label_repository: &'a AnswerLabelRepo,
     pub user_repository: &'a UserRepo,
     pub form_submission_restriction_repository: &'a FormSubmissionRestrictionRepo,
     pub answer_entry_repository: &'a AnswerEntryRepo,
+    pub content_filter_repository: &'a ContentFilterRepo,
+    pub temporary_answer_abuse_repository: &'a TemporaryAnswerAbuseRepo,
+    pub message_read_repository: Option<&'a dyn MessageReadRepository>,
     pub discord_answer_webhook_notifier: Option<&'a dyn DiscordAnswerWebhookNotifier>,
     pub application_event_publisher: Option<&'a dyn ApplicationEventPublisher>,
 }
@@ -70,7 +112,9 @@ impl<
     R3: UserRepository,
     R4: FormSubmissionRestrictionRepository,
     R5: AnswerEntryRepository,
-> AnswerUseCase<'_, R1, R2, R3, R4, R5>
+    R6: ContentFilterRepository,
+    R7: TemporaryAnswerAbuseRepository,
+> AnswerUseCase<'_, R1, R2, R3, R4, R5, R6, R7>
 {
     async fn read_form(
         &self,
@@ -85,15 +129,54 @@ impl<
             .map_err(Into::into)
     }
 
+    /// 閲覧者がメッセージスレッドを読める回答について、未読メッセージ数を一度にまとめて数えます。
+    ///
+    /// スレッドを読めない回答は結果に含みません。
+    async fn unread_message_counts<'b>(
+        &self,
+        answers: impl IntoIterator<Item = &'b Allowed<AnswerEntry, Read>>,
+    ) -> Result<HashMap<AnswerId, u32>, Error> {
+        let Some(repository) = self.message_read_repository else {
+            return Ok(HashMap::new());
+        };
+        let targets = answers
+            .into_iter()
+            .filter_map(Allowed::unread_message_count_target)
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(repository
+            .unread_message_counts(&targets)
+            .await?
+            .into_iter()
+            .map(|(target, count)| (*target.answer_id(), count))
+            .collect())
+    }
+
+    async fn unread_message_count(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+    ) -> Result<Option<u32>, Error> {
+        Ok(self
+            .unread_message_counts([answer])
+            .await?
+            .get(answer.id())
+            .copied())
+    }
+
     async fn build_answer_details(
         &self,
         actor: &AccountUser,
         form_id: FormId,
         form_answer: Allowed<AnswerEntry, Read>,
-        author_disclosure: AnswerAuthorDisclosure,
+        answer_settings: &AnswerSettings,
         labels: Vec<AnswerLabel>,
+        unread_message_count: Option<u32>,
     ) -> Result<AnswerDetails, Error> {
-        let author = match author_disclosure {
+        let viewer = Actor::from(actor.clone());
+        let author = match answer_settings.author_disclosure_for(&viewer) {
             AnswerAuthorDisclosure::Anonymous => PublishedAnswerAuthor::Anonymous,
             AnswerAuthorDisclosure::Disclosed => {
                 let user_ids = form_answer
@@ -122,17 +205,22 @@ impl<
             }
         };
 
+        let assignee = form_answer.assignee_visible_to(&viewer);
+        let due_at = form_answer.due_at_visible_to(&viewer);
+        let quiz_score = answer_settings.quiz_score_visible_to(&form_answer, &viewer);
         Ok(AnswerDetails {
             form_id,
-            answer: PublishedAnswerEntry::new(form_answer.into_inner(), author),
+            answer: PublishedAnswerEntry::new(form_answer.into_inner(), author, assignee, due_at)
+                .with_quiz_score(quiz_score),
             labels,
+            unread_message_count,
         })
     }
 
     async fn notify_discord_answer_webhook(
         &self,
         form: &Allowed<ActiveForm, Read>,
-        answer_entry: &Allowed<AnswerEntry, Create>,
+        answer_entry: &AnswerEntry,
         author_disclosure: AnswerAuthorDisclosure,
         respondent: &str,
     ) {
@@ -203,12 +291,15 @@ impl<
             .await;
     }
 
+    /// 回答を投稿し、回答者に見せてよいクイズの点数を返します。
+    ///
+    /// フィルターで確認待ちになった回答は、承認されたときに改めて知らせます。
     pub async fn post_answers(
         &self,
         user: AccountUser,
         form_id: FormId,
         answers: Vec<FormAnswerContent>,
-    ) -> Result<(), Error> {
+    ) -> Result<Option<AnswerQuizScore>, Error> {
         let actor = Actor::from(user.clone());
 
         let form_guard = self
@@ -218,6 +309,12 @@ impl<
             .ok_or(FormNotFound)?;
         let form = form_guard.try_read(actor.clone())?;
         let questions = form.value().questions().as_slice().to_vec();
+        let verdict = screen_content(
+            self.content_filter_repository,
+            form_id,
+            answers.iter().map(|answer| answer.answer.as_str()),
+        )
+        .await?;
         let posted_answers = PostedAnswerContents::try_new(&questions, answers)?;
         let submitter = super::submission::authorize_form_submission(
             user.clone(),
@@ -238,48 +335,68 @@ impl<
                 .default_title_author_name(user.name()),
         )?;
 
-        let answer_entry = form.try_accept_answer(submitter, title, posted_answers)?;
+        let answer_entry = form.try_accept_answer(submitter, title, posted_answers, &verdict)?;
 
         self.answer_entry_repository
             .post(&form, &answer_entry)
             .await?;
+        enforce_verdict(
+            self.content_filter_repository,
+            &verdict,
+            form_id,
+            *answer_entry.id(),
+            ContentFilterTarget::Answer,
+        )
+        .await?;
+
+        if answer_entry.moderation_status().is_approved() {
+            self.announce_answer(&form, &answer_entry, ApplicationActor::from(&user))
+                .await;
+        }
+
+        Ok(form
+            .answer_settings()
+            .quiz_score_visible_to(&answer_entry, &actor))
+    }
+
+    /// 回答の受付を、アプリケーションイベントと Discord の Webhook で知らせます。
+    async fn announce_answer(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        answer_entry: &AnswerEntry,
+        author: ApplicationActor,
+    ) {
+        let author_disclosure = form
+            .answer_settings()
+            .author_disclosure_for(&Actor::Anonymous);
+
+        let author_name = author.display_name.clone();
 
         if let Some(publisher) = self.application_event_publisher {
             publisher.publish(answer_submitted_event(
-                match form
-                    .answer_settings()
-                    .author_disclosure_for(&Actor::Anonymous)
-                {
-                    AnswerAuthorDisclosure::Disclosed => {
-                        AnswerSubmissionActor::Identified(ApplicationActor::from(&user))
-                    }
+                match author_disclosure {
+                    AnswerAuthorDisclosure::Disclosed => AnswerSubmissionActor::Identified(author),
                     AnswerAuthorDisclosure::Anonymous => AnswerSubmissionActor::AuthorHidden,
                 },
-                &form,
-                &answer_entry,
+                form,
+                answer_entry,
             ));
         }
 
-        self.notify_discord_answer_webhook(
-            &form,
-            &answer_entry,
-            form.answer_settings()
-                .author_disclosure_for(&Actor::Anonymous),
-            user.name(),
-        )
-        .await;
-
-        Ok(())
+        self.notify_discord_answer_webhook(form, answer_entry, author_disclosure, &author_name)
+            .await;
     }
 
+    /// 一時回答者として回答を投稿し、回答者に見せてよいクイズの点数と、回答のアクセスリンク用の
+    /// トークン、アカウントへの引き継ぎコードを返します。
     pub async fn post_temporary_answers(
         &self,
         temporary_user: TemporaryAnswerAuthor,
         form_id: FormId,
         answers: Vec<FormAnswerContent>,
-    ) -> Result<(), Error> {
+        source: TemporaryAnswerSubmissionSource,
+    ) -> Result<PostedTemporaryAnswer, Error> {
         let actor = Actor::from(temporary_user.clone());
-        let application_actor = ApplicationActor::from(&temporary_user);
 
         let form_guard = self
             .active_form_repository
@@ -288,6 +405,12 @@ impl<
             .ok_or(FormNotFound)?;
         let form = form_guard.try_read(actor.clone())?;
         let questions = form.value().questions().as_slice().to_vec();
+        let verdict = screen_content(
+            self.content_filter_repository,
+            form_id,
+            answers.iter().map(|answer| answer.answer.as_str()),
+        )
+        .await?;
         let posted_answers = PostedAnswerContents::try_new(&questions, answers)?;
 
         let title = DefaultAnswerTitleDomainService::to_answer_title_from_questions(
@@ -303,40 +426,73 @@ impl<
                 .default_title_author_name(temporary_user.name()),
         )?;
 
-        let respondent = temporary_user.name().to_owned();
-        let answer_entry =
-            form.try_accept_temporary_answer(temporary_user, title, posted_answers)?;
+        let answer_entry = form.try_accept_temporary_answer(
+            temporary_user.clone(),
+            title,
+            posted_answers,
+            &verdict,
+        )?;
 
+        // 回答者に返すトークンとコードは、回答と同時に保存できたものだけを返す
+        let (access, access_token) = answer_entry.issue_temporary_access(Utc::now())?;
+        let (claim_ticket, claim_code) = answer_entry.issue_temporary_claim_code(Utc::now())?;
+        let submission = answer_entry.record_temporary_submission_source(source, Utc::now())?;
         self.answer_entry_repository
-            .post(&form, &answer_entry)
+            .post_temporary(
+                &form,
+                &answer_entry,
+                access,
+                &access_token,
+                claim_ticket,
+                &claim_code,
+            )
             .await?;
 
-        if let Some(publisher) = self.application_event_publisher {
-            publisher.publish(answer_submitted_event(
-                match form
-                    .answer_settings()
-                    .author_disclosure_for(&Actor::Anonymous)
-                {
-                    AnswerAuthorDisclosure::Disclosed => {
-                        AnswerSubmissionActor::Identified(application_actor)
-                    }
-                    AnswerAuthorDisclosure::Anonymous => AnswerSubmissionActor::AuthorHidden,
-                },
+        // 回答は保存済みのため、ここからの記録に失敗しても回答の受け付けは取り消さない
+        if let Err(error) = enforce_verdict(
+            self.content_filter_repository,
+            &verdict,
+            form_id,
+            *answer_entry.id(),
+            ContentFilterTarget::Answer,
+        )
+        .await
+        {
+            tracing::warn!(
+                answer_id = %answer_entry.id(),
+                error = %error,
+                "failed to record the content filter verdict for a temporary answer"
+            );
+        }
+        if let Err(error) = self
+            .temporary_answer_abuse_repository
+            .record(submission)
+            .await
+        {
+            tracing::warn!(
+                answer_id = %answer_entry.id(),
+                error = %error,
+                "failed to record the submission source of a temporary answer"
+            );
+        }
+
+        // 確認待ちの回答は、承認されたときに改めて知らせる
+        if answer_entry.moderation_status().is_approved() {
+            self.announce_answer(
                 &form,
                 &answer_entry,
-            ));
+                ApplicationActor::from(&temporary_user),
+            )
+            .await;
         }
 
-        self.notify_discord_answer_webhook(
-            &form,
-            &answer_entry,
-            form.answer_settings()
-                .author_disclosure_for(&Actor::Anonymous),
-            &respondent,
-        )
-        .await;
-
-        Ok(())
+        Ok(PostedTemporaryAnswer {
+            quiz_score: form
+                .answer_settings()
+                .quiz_score_visible_to(&answer_entry, &actor),
+            access_token,
+            claim_code,
+        })
     }
 
     pub async fn get_answers(
@@ -366,9 +522,41 @@ impl<
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let author_disclosure = form.answer_settings().author_disclosure_for(&actor);
-        self.build_answer_details(user, form_id, form_answer, author_disclosure, labels)
-            .await
+        let unread_message_count = self.unread_message_count(&form_answer).await?;
+        self.build_answer_details(
+            user,
+            form_id,
+            form_answer,
+            form.answer_settings(),
+            labels,
+            unread_message_count,
+        )
+        .await
+    }
+
+    /// 一時回答の送信元のハッシュを、管理者に限って取得する。
+    pub async fn get_temporary_answer_abuse_metadata(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+    ) -> Result<TemporaryAnswerAbuseMetadata, Error> {
+        let form = self.read_form(form_id, &Actor::from(actor.clone())).await?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+        let metadata = self
+            .temporary_answer_abuse_repository
+            .find_by_answer_id(answer_id)
+            .await?
+            .ok_or(TemporaryAnswerAbuseMetadataNotFound)?;
+
+        answer
+            .read_temporary_abuse_metadata(metadata)
+            .map(Allowed::into_inner)
+            .map_err(Into::into)
     }
 
     pub async fn get_answers_by_form_id(
@@ -376,17 +564,20 @@ impl<
         form_id: FormId,
         actor: &AccountUser,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerDetails, AnswerPagePosition>, Error> {
         let actor_ref = Actor::from(actor.clone());
+        if !filter.can_be_used_by(&actor_ref) {
+            return Err(DomainError::Forbidden.into());
+        }
         let form = self.read_form(form_id, &actor_ref).await?;
 
         let page = self
             .answer_entry_repository
-            .list_by_form(&form, request, status)
+            .list_by_form(&form, request, filter)
             .await?;
         let (visible_answers, next) = page.into_parts();
-        let author_disclosure = form.answer_settings().author_disclosure_for(&actor_ref);
+        let unread_message_counts = self.unread_message_counts(&visible_answers).await?;
 
         let answers = stream::iter(visible_answers)
             .then(|form_answer| async {
@@ -403,8 +594,16 @@ impl<
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
-                self.build_answer_details(actor, form_id, form_answer, author_disclosure, labels)
-                    .await
+                let unread_message_count = unread_message_counts.get(&answer_id).copied();
+                self.build_answer_details(
+                    actor,
+                    form_id,
+                    form_answer,
+                    form.answer_settings(),
+                    labels,
+                    unread_message_count,
+                )
+                .await
             })
             .collect::<Vec<Result<AnswerDetails, Error>>>()
             .await
@@ -414,6 +613,38 @@ impl<
         Ok(Page::new(answers, next))
     }
 
+    /// フォームの回答の集計を返します。
+    ///
+    /// 管理者以外には、回答一覧が公開されているフォームの公開済みの回答だけを集計します。
+    pub async fn get_answer_statistics(
+        &self,
+        form_id: FormId,
+        actor: &AccountUser,
+    ) -> Result<AnswerStatistics, Error> {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+        let scope = form
+            .answer_settings()
+            .statistics_scope_for(&actor)
+            .ok_or(DomainError::Forbidden)?;
+
+        self.answer_entry_repository.statistics(&form, scope).await
+    }
+
+    /// 回答のエクスポートで列に使う、フォームの質問を返します。
+    ///
+    /// ストリーミングを始める前に呼ぶことで、フォームを閲覧できない場合は
+    /// レスポンスを返し始める前に失敗させます。
+    pub async fn get_answer_export_questions(
+        &self,
+        form_id: FormId,
+        actor: &AccountUser,
+    ) -> Result<Vec<Question>, Error> {
+        let form = self.read_form(form_id, &Actor::from(actor.clone())).await?;
+
+        Ok(form.questions().as_slice().to_vec())
+    }
+
     async fn readable_forms(&self, actor: &Actor) -> Result<Vec<Allowed<ActiveForm, Read>>, Error> {
         Ok(self
             .active_form_repository
@@ -428,40 +659,42 @@ impl<
         &self,
         user: &AccountUser,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<AnswerDetails, AnswerPagePosition>, Error> {
         let actor_ref = Actor::from(user.clone());
+        if !filter.can_be_used_by(&actor_ref) {
+            return Err(DomainError::Forbidden.into());
+        }
         let readable_forms = self.readable_forms(&actor_ref).await?;
 
         let page = self
             .answer_entry_repository
-            .list_all(&readable_forms, request, status)
+            .list_all(&readable_forms, request, filter)
             .await?;
         let (visible_answers, next) = page.into_parts();
-        let publication_by_form_id = readable_forms
+        let answer_settings_by_form_id = readable_forms
             .iter()
-            .map(|form| {
-                (
-                    *form.id(),
-                    form.answer_settings().author_disclosure_for(&actor_ref),
-                )
-            })
+            .map(|form| (*form.id(), form.answer_settings()))
             .collect::<std::collections::HashMap<_, _>>();
-        let visible_answers: Vec<(FormId, AnswerAuthorDisclosure, Allowed<AnswerEntry, Read>)> =
+        let visible_answers: Vec<(FormId, &AnswerSettings, Allowed<AnswerEntry, Read>)> =
             visible_answers
                 .into_iter()
                 .filter_map(|entry| {
                     let form_id = *entry.value().form_id();
-                    publication_by_form_id
+                    answer_settings_by_form_id
                         .get(&form_id)
                         .copied()
-                        .map(|disclosure| (form_id, disclosure, entry))
+                        .map(|answer_settings| (form_id, answer_settings, entry))
                 })
                 .collect();
+        let unread_message_counts = self
+            .unread_message_counts(visible_answers.iter().map(|(_, _, entry)| entry))
+            .await?;
 
         let answers = stream::iter(visible_answers)
-            .then(|(form_id, author_disclosure, form_answer)| {
+            .then(|(form_id, answer_settings, form_answer)| {
                 let user = user.clone();
+                let unread_message_count = unread_message_counts.get(form_answer.id()).copied();
                 async move {
                     let actor_ref = Actor::from(user.clone());
 
@@ -482,8 +715,9 @@ impl<
                         &user,
                         form_id,
                         form_answer,
-                        author_disclosure,
+                        answer_settings,
                         labels,
+                        unread_message_count,
                     )
                     .await
                 }
@@ -537,6 +771,15 @@ impl<
                     .get(&form, answer_id)
                     .await?
                     .ok_or(AnswerNotFound)?;
+                // タイトルは回答の一覧などで表示されるため、回答の内容と同じく確かめる
+                let verdict = match title.clone().and_then(|title| title.into_inner()) {
+                    Some(title) => {
+                        screen_content(self.content_filter_repository, form_id, [title.as_str()])
+                            .await?
+                    }
+                    None => ContentFilterVerdict::default(),
+                };
+                verdict.ensure_accepted()?;
                 let updated_entry = form_update.change_entry_meta(
                     entry.into_inner(),
                     title,
@@ -548,6 +791,14 @@ impl<
                     .answer_entry_repository
                     .update(&form_update, &updated_entry)
                     .await?;
+                enforce_verdict(
+                    self.content_filter_repository,
+                    &verdict,
+                    form_id,
+                    answer_id,
+                    ContentFilterTarget::Answer,
+                )
+                .await?;
 
                 (
                     self.answer_entry_repository
@@ -582,420 +833,2084 @@ impl<
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let author_disclosure = form.answer_settings().author_disclosure_for(&actor_ref);
-        self.build_answer_details(actor, form_id, form_answer, author_disclosure, labels)
-            .await
+        let unread_message_count = self.unread_message_count(&form_answer).await?;
+        self.build_answer_details(
+            actor,
+            form_id,
+            form_answer,
+            form.answer_settings(),
+            labels,
+            unread_message_count,
+        )
+        .await
     }
 
-    pub async fn get_status_history(
+    /// `duplicate` から `original` へ `DUPLICATE_OF` の関連を付け、`duplicate` を完了にします。
+    ///
+    /// 関連と状態は一つのトランザクションで保存するため、どちらか一方だけが反映されることはありません。
+    /// 二つの回答の間に既に関連がある場合は、その種類を `DUPLICATE_OF` に置き換えます。
+    pub async fn close_answer_as_duplicate(
         &self,
         actor: &AccountUser,
-        form_id: FormId,
-        answer_id: AnswerId,
-        request: PageRequest<AnswerStatusHistoryPagePosition>,
-    ) -> Result<Page<Allowed<AnswerStatusHistoryEntry, Read>, AnswerStatusHistoryPagePosition>, Error>
-    {
-        let actor = Actor::from(actor.clone());
-        let form = self.read_form(form_id, &actor).await?;
-        let answer = self
+        duplicate: AnswerReference,
+        original: AnswerReference,
+    ) -> Result<AnswerDetails, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(duplicate.form_id(), &actor_ref).await?;
+        let form_update = self
+            .active_form_repository
+            .get(duplicate.form_id())
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let entry = self
             .answer_entry_repository
-            .get(&form, answer_id)
+            .get(&form, duplicate.answer_id())
             .await?
             .ok_or(AnswerNotFound)?;
-        self.answer_entry_repository.history(&answer, request).await
+        let updated_entry = form_update.change_entry_meta(
+            entry.into_inner(),
+            None,
+            None,
+            Some(AnswerStatus::COMPLETED),
+        )?;
+
+        let original_form = self.read_form(original.form_id(), &actor_ref).await?;
+        let original_form_update = self
+            .active_form_repository
+            .get(original.form_id())
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let original_entry = self
+            .answer_entry_repository
+            .get(&original_form, original.answer_id())
+            .await?
+            .ok_or(AnswerNotFound)?;
+        let original_entry =
+            original_form_update.authorize_entry_update(original_entry.into_inner())?;
+        let relation =
+            AnswerRelation::with_kind(duplicate, original, AnswerRelationKind::DuplicateOf)?;
+
+        let status_change = self
+            .answer_entry_repository
+            .update_with_relation(&form_update, &updated_entry, relation, &original_entry)
+            .await?;
+        let form_answer = self
+            .answer_entry_repository
+            .get(&form, duplicate.answer_id())
+            .await?
+            .ok_or(AnswerNotFound)?;
+
+        if let (Some(publisher), Some(status_change)) =
+            (self.application_event_publisher, status_change)
+        {
+            publisher.publish(answer_status_changed_event(
+                actor,
+                duplicate.form_id(),
+                &form_answer,
+                status_change,
+            ));
+        }
+
+        let labels = self
+            .answer_label_repository
+            .get_labels_for_answers_by_answer_id(duplicate.answer_id())
+            .await?
+            .into_iter()
+            .map(|label| {
+                label
+                    .try_read(actor_ref.clone())
+                    .map(|label| label.into_inner())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unread_message_count = self.unread_message_count(&form_answer).await?;
+        self.build_answer_details(
+            actor,
+            duplicate.form_id(),
+            form_answer,
+            form.answer_settings(),
+            labels,
+            unread_message_count,
+        )
+        .await
     }
 
-    pub async fn get_title_history(
+    /// 確認待ちの回答を承認または却下します。
+    ///
+    /// 承認した回答は、投稿時に保留していたイベントと Discord の Webhook をここで送ります。
+    pub async fn moderate_answer(
         &self,
         actor: &AccountUser,
         form_id: FormId,
         answer_id: AnswerId,
-        request: PageRequest<AnswerTitleHistoryPagePosition>,
-    ) -> Result<Page<Allowed<AnswerTitleHistoryEntry, Read>, AnswerTitleHistoryPagePosition>, Error>
-    {
-        let actor = Actor::from(actor.clone());
-        let form = self.read_form(form_id, &actor).await?;
-        let answer = self
+        decision: AnswerModerationDecision,
+    ) -> Result<(), Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor_ref).await?;
+
+        let form_update = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref)?;
+        let entry = self
             .answer_entry_repository
             .get(&form, answer_id)
             .await?
-            .ok_or(AnswerNotFound)?;
+            .ok_or(AnswerNotFound)?
+            .into_inner();
+        let moderated_entry = form_update.moderate_entry(entry, decision)?;
         self.answer_entry_repository
-            .title_history(&answer, request)
-            .await
+            .update(&form_update, &moderated_entry)
+            .await?;
+
+        if matches!(decision, AnswerModerationDecision::Approve) {
+            let author = match moderated_entry.author() {
+                AnswerAuthor::AuthenticatedUser(user_id) => {
+                    resolve_user_references(self.user_repository, actor, vec![*user_id])
+                        .await?
+                        .get(user_id)
+                        .map(ApplicationActor::from)
+                }
+                AnswerAuthor::Temporary(temporary_user) => {
+                    Some(ApplicationActor::from(temporary_user))
+                }
+                AnswerAuthor::ImportedFromRedmine(_) => None,
+            };
+            if let Some(author) = author {
+                self.announce_answer(&form, &moderated_entry, author).await;
+            }
+        }
+
+        Ok(())
     }
-}
 
-fn answer_submitted_event(
-    actor: AnswerSubmissionActor,
-    form: &Allowed<ActiveForm, Read>,
-    answer: &Allowed<AnswerEntry, Create>,
-) -> ApplicationEvent {
-    let questions = form.questions().as_slice();
-    let title = answer
-        .title()
-        .to_owned()
-        .into_inner()
-        .map(|title| EventDetail::new("回答タイトル", title.into_inner()));
-    let contents = answer.contents().iter().map(|content| {
-        let question_title = questions
-            .iter()
-            .find(|question| question.id() == content.question_id)
-            .map(|question| question.title().as_str().to_owned())
-            .unwrap_or_else(|| "不明な質問".to_string());
-        EventDetail::new(question_title, content.answer.to_owned())
-    });
+    /// `answers` の各回答に `operation` を適用します。
+    ///
+    /// 認可とイベント発行は単一の回答を操作する場合と同じく 1 件ずつ行い、
+    /// 一部の回答で失敗しても残りの回答への適用は続けます。
+    pub async fn bulk_update_answers(
+        &self,
+        actor: &AccountUser,
+        answers: Vec<AnswerReference>,
+        operation: BulkAnswerOperation,
+    ) -> Vec<BulkAnswerOperationResult> {
+        stream::iter(answers)
+            .then(|answer| async move {
+                let result = match operation {
+                    BulkAnswerOperation::ChangeStatus(status) => self
+                        .update_answer_meta(
+                            answer.form_id(),
+                            answer.answer_id(),
+                            actor,
+                            None,
+                            None,
+                            Some(status),
+                        )
+                        .await
+                        .map(|_| ()),
+                    BulkAnswerOperation::ChangePublication(publication) => self
+                        .update_answer_meta(
+                            answer.form_id(),
+                            answer.answer_id(),
+                            actor,
+                            None,
+                            Some(publication),
+                            None,
+                        )
+                        .await
+                        .map(|_| ()),
+                    BulkAnswerOperation::AddLabel(label_id) => {
+                        self.change_answer_label(actor, answer, label_id, true)
+                            .await
+                    }
+                    BulkAnswerOperation::RemoveLabel(label_id) => {
+                        self.change_answer_label(actor, answer, label_id, false)
+                            .await
+                    }
+                    BulkAnswerOperation::Moderate(decision) => {
+                        self.moderate_answer(actor, answer.form_id(), answer.answer_id(), decision)
+                            .await
+                    }
+                };
 
-    ApplicationEvent::AnswerSubmitted {
-        actor,
-        form_id: form.id().to_string(),
-        form_title: form.title().as_str().to_owned(),
-        answer_id: answer.id().to_string(),
-        details: title.into_iter().chain(contents).collect(),
+                BulkAnswerOperationResult { answer, result }
+            })
+            .collect()
+            .await
     }
-}
 
-fn answer_status_changed_event(
-    actor: &AccountUser,
-    form_id: FormId,
-    answer: &Allowed<AnswerEntry, Read>,
-    status_change: AnswerStatusChange,
-) -> ApplicationEvent {
-    ApplicationEvent::AnswerStatusChanged {
-        actor: ApplicationActor::from(actor),
-        form_id: form_id.to_string(),
-        answer_title: answer
-            .title()
-            .to_owned()
-            .into_inner()
-            .map(|title| title.into_inner()),
-        answer_id: answer.id().to_string(),
-        status_change,
+    /// 回答に付いているラベルを保ったまま、`label_id` のラベルだけを付け外しします。
+    async fn change_answer_label(
+        &self,
+        actor: &AccountUser,
+        answer: AnswerReference,
+        label_id: AnswerLabelId,
+        attach: bool,
+    ) -> Result<(), Error> {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(answer.form_id(), &actor).await?;
+        self.answer_entry_repository
+            .get(&form, answer.answer_id())
+            .await?
+            .ok_or(AnswerNotFound)?;
+
+        let mut label_ids = self
+            .answer_label_repository
+            .get_labels_for_answers_by_answer_id(answer.answer_id())
+            .await?
+            .into_iter()
+            .map(|label| label.try_read(actor.clone()).map(|label| *label.id()))
+            .collect::<Result<Vec<_>, _>>()?;
+        label_ids.retain(|id| *id != label_id);
+        if attach {
+            label_ids.push(label_id);
+        }
+
+        let labels = self
+            .answer_label_repository
+            .get_labels_for_answers_by_label_ids(label_ids)
+            .await?
+            .into_iter()
+            .map(|label| label.into_update().try_update(actor.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if attach && !labels.iter().any(|label| *label.id() == label_id) {
+            return Err(LabelNotFound.into());
+        }
+
+        self.answer_label_repository
+            .replace_answer_labels(answer.answer_id(), labels)
+            .await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use async_trait::async_trait;
-    use domain::{
-        account::models::Role,
-        form::{
-            FormSubmissionRestriction, FormSubmissionRestrictionReason,
-            answer::{AnswerLabelId, FormAnswerContentId},
-            models::{
-                AllowedUserGroups, AnswerAuthorPublicationPolicy, AnswerSettings,
-                DefaultAnswerTitle, DiscordWebhookUrl, FormDescription, FormTitle, QuestionSet,
-            },
-            question::Question,
-        },
-        pagination::PageLimit,
-        repository::form::answer_label_repository::AnswerLabelRepository,
-        types::authorization_guard::{AuthorizationGuard, Create, Delete, Update},
-    };
-    use errors::domain::DomainError;
-    use std::sync::Mutex;
-    use types::non_empty_string::NonEmptyString;
-    use types::non_empty_vec::NonEmptyVec;
-    use uuid::Uuid;
+    pub async fn assign_answer<N: Notificator, NotificationRepo: NotificationRepository>(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        assignee_id: Option<UserId>,
+        notificator: &N,
+        notification_repository: &NotificationRepo,
+    ) -> Result<AnswerDetails, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor_ref).await?;
+        let assignee = match assignee_id {
+            Some(assignee_id) => Some(
+                self.user_repository
+                    .find_by(assignee_id.into_inner())
+                    .await?
+                    .ok_or(UserNotFound)?
+                    .try_read(actor_ref.clone())?
+                    .into_inner(),
+            ),
+            None => None,
+        };
+
+        let form_update = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let entry = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?
+            .into_inner();
+        let previous_assignee = *entry.assignee();
+        let updated_entry = form_update.assign_entry(entry, assignee.as_ref())?;
+        self.answer_entry_repository
+            .update(&form_update, &updated_entry)
+            .await?;
 
-    use crate::test_utils::repositories::FormUseCaseTestRepositories;
+        let form_answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
 
-    #[derive(Default)]
-    struct RecordingPublisher(Mutex<Vec<ApplicationEvent>>);
+        if let Some(assignee) = assignee
+            .filter(|assignee| Some(*assignee.id()) != previous_assignee)
+            .filter(|assignee| assignee.id() != actor.id())
+        {
+            let notification_preference = notification_preference_for(
+                notification_repository,
+                self.user_repository,
+                &actor_ref,
+                *assignee.id(),
+            )
+            .await?;
 
-    impl ApplicationEventPublisher for RecordingPublisher {
-        fn publish(&self, event: ApplicationEvent) {
-            self.0.lock().unwrap().push(event);
+            notificator
+                .notify(
+                    *assignee.id(),
+                    NotificationType::AnswerAssigned,
+                    &notification_preference,
+                    &answer_assigned_notification_content(
+                        &FRONTEND.url,
+                        form_id,
+                        answer_id,
+                        form_answer.title(),
+                    ),
+                )
+                .await?;
         }
-    }
 
-    impl RecordingPublisher {
-        fn events(&self) -> Vec<ApplicationEvent> {
-            self.0.lock().unwrap().clone()
-        }
+        let labels = self
+            .answer_label_repository
+            .get_labels_for_answers_by_answer_id(answer_id)
+            .await?
+            .into_iter()
+            .map(|label| {
+                label
+                    .try_read(actor_ref.clone())
+                    .map(|label| label.into_inner())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unread_message_count = self.unread_message_count(&form_answer).await?;
+        self.build_answer_details(
+            actor,
+            form_id,
+            form_answer,
+            form.answer_settings(),
+            labels,
+            unread_message_count,
+        )
+        .await
     }
 
-    #[derive(Default)]
-    struct RecordingDiscordAnswerWebhookNotifier(Mutex<Vec<DiscordAnswerWebhookNotification>>);
+    /// 回答の対応期限を手動で変更します。`None` を指定すると期限を外します。
+    pub async fn change_answer_due_date(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        due_at: Option<DateTime<Utc>>,
+    ) -> Result<AnswerDetails, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor_ref).await?;
 
-    #[async_trait]
-    impl DiscordAnswerWebhookNotifier for RecordingDiscordAnswerWebhookNotifier {
-        async fn notify_answer_posted(&self, notification: DiscordAnswerWebhookNotification) {
-            self.0.lock().unwrap().push(notification);
-        }
+        let form_update = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let entry = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?
+            .into_inner();
+        let updated_entry = form_update.change_entry_due_at(entry, due_at)?;
+        self.answer_entry_repository
+            .update(&form_update, &updated_entry)
+            .await?;
+
+        let form_answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+
+        let labels = self
+            .answer_label_repository
+            .get_labels_for_answers_by_answer_id(answer_id)
+            .await?
+            .into_iter()
+            .map(|label| {
+                label
+                    .try_read(actor_ref.clone())
+                    .map(|label| label.into_inner())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unread_message_count = self.unread_message_count(&form_answer).await?;
+        self.build_answer_details(
+            actor,
+            form_id,
+            form_answer,
+            form.answer_settings(),
+            labels,
+            unread_message_count,
+        )
+        .await
     }
 
-    impl RecordingDiscordAnswerWebhookNotifier {
-        fn notifications(&self) -> Vec<DiscordAnswerWebhookNotification> {
-            self.0.lock().unwrap().clone()
-        }
+    /// 回答を `destination_form_id` のフォームへ移動します。
+    ///
+    /// `question_mapping` で指定されなかった質問は、同じ `TemplateKey` を持つ移動先の質問に
+    /// 対応付けます。コメント・メッセージ・ラベル・関連・各種履歴は回答に付いたまま移動します。
+    pub async fn move_answer(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        destination_form_id: FormId,
+        question_mapping: HashMap<QuestionId, QuestionId>,
+    ) -> Result<AnswerDetails, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor_ref).await?;
+
+        let source_update = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let destination_update = self
+            .active_form_repository
+            .get(destination_form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+        let entry = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?
+            .into_inner();
+        let entry = source_update.authorize_entry_update(entry)?;
+        let moved_entry =
+            destination_update.move_entry_into(&source_update, entry, question_mapping)?;
+        self.answer_entry_repository
+            .move_to_form(&source_update, &destination_update, &moved_entry)
+            .await?;
+
+        self.get_answers(destination_form_id, answer_id, actor)
+            .await
     }
 
-    #[derive(Default)]
-    struct EmptyAnswerLabelRepository;
+    pub async fn start_watch_overdue_answers(
+        &self,
+        shutdown_notifier: Arc<Notify>,
+    ) -> Result<(), Error> {
+        let mut interval = time::interval(Duration::from_secs(60));
 
-    #[async_trait]
-    impl AnswerLabelRepository for EmptyAnswerLabelRepository {
-        async fn create_label_for_answers(
-            &self,
-            _label: Allowed<AnswerLabel, Create>,
-        ) -> Result<(), Error> {
-            Ok(())
+        loop {
+            tokio::select! {
+                _ = shutdown_notifier.notified() => {
+                    break
+                },
+                _ = interval.tick() => {
+                    if let Err(error) = self.notify_overdue_answers().await {
+                        tracing::error!(error = %error, "failed to check overdue answers");
+                    }
+                }
+            }
         }
 
-        async fn get_labels_for_answers(
-            &self,
-        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
-            Ok(vec![])
-        }
+        Ok(())
+    }
 
-        async fn get_label_for_answers(
-            &self,
-            _label_id: AnswerLabelId,
-        ) -> Result<Option<AuthorizationGuard<AnswerLabel, Read>>, Error> {
-            Ok(None)
-        }
+    /// 対応期限を過ぎた未完了の回答を探し、まだ通知していないものについて
+    /// [`ApplicationEvent::AnswerOverdue`] を発行する。
+    ///
+    /// 定期実行タスクのため、実行ごとに新しいルートスパンを作る。
+    #[tracing::instrument(name = "answer.watch_overdue", parent = None, skip_all)]
+    async fn notify_overdue_answers(&self) -> Result<(), Error> {
+        let forms = self
+            .active_form_repository
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|guard| guard.try_read(Actor::System).map_err(Into::into))
+            .collect::<Result<Vec<_>, Error>>()?;
+        // 確認待ちの回答は回答者以外に公開されていないため、期限超過も知らせない
+        let filter = AnswerListFilter::default()
+            .with_overdue_only(true)
+            .with_unnotified_overdue_only(true)
+            .with_moderation_status(Some(AnswerModerationStatus::APPROVED));
+        let mut request = PageRequest::first(PageLimit::default_limit());
+
+        loop {
+            let (answers, next) = self
+                .answer_entry_repository
+                .list_all(&forms, request, filter)
+                .await?
+                .into_parts();
+
+            for answer in answers {
+                if !self
+                    .answer_entry_repository
+                    .mark_overdue_notified(&answer)
+                    .await?
+                {
+                    continue;
+                }
 
-        async fn get_labels_for_answers_by_label_ids(
-            &self,
-            _label_ids: Vec<AnswerLabelId>,
-        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
-            Ok(vec![])
-        }
+                if let (Some(publisher), Some(due_at)) =
+                    (self.application_event_publisher, *answer.due_at())
+                {
+                    publisher.publish(ApplicationEvent::AnswerOverdue {
+                        form_id: answer.form_id().to_string(),
+                        answer_title: answer
+                            .title()
+                            .to_owned()
+                            .into_inner()
+                            .map(|title| title.into_inner()),
+                        answer_id: answer.id().to_string(),
+                        due_at,
+                        status: *answer.status(),
+                    });
+                }
+            }
 
-        async fn get_labels_for_answers_by_answer_id(
-            &self,
-            _answer_id: AnswerId,
-        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
-            Ok(vec![])
+            let Some(next) = next else {
+                break;
+            };
+            request = PageRequest::after(next, PageLimit::default_limit());
         }
 
-        async fn delete_label_for_answers(
-            &self,
-            _label: Allowed<AnswerLabel, Delete>,
-        ) -> Result<(), Error> {
-            Ok(())
-        }
+        Ok(())
+    }
 
-        async fn edit_label_for_answers(
-            &self,
-            _label: Allowed<AnswerLabel, Update>,
-        ) -> Result<(), Error> {
-            Ok(())
-        }
+    pub async fn get_assignment_history(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<
+        Page<Allowed<AnswerAssignmentHistoryEntry, Read>, AnswerAssignmentHistoryPagePosition>,
+        Error,
+    > {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+        self.answer_entry_repository
+            .assignment_history(&answer, request)
+            .await
+    }
+
+    pub async fn get_status_history(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerStatusHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerStatusHistoryEntry, Read>, AnswerStatusHistoryPagePosition>, Error>
+    {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+        self.answer_entry_repository.history(&answer, request).await
+    }
+
+    pub async fn get_title_history(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerTitleHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerTitleHistoryEntry, Read>, AnswerTitleHistoryPagePosition>, Error>
+    {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+        self.answer_entry_repository
+            .title_history(&answer, request)
+            .await
+    }
+
+    pub async fn get_move_history(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerMoveHistoryEntry, Read>, AnswerMoveHistoryPagePosition>, Error>
+    {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+        self.answer_entry_repository
+            .move_history(&answer, request)
+            .await
+    }
+}
+
+fn answer_assigned_notification_content(
+    frontend_url: &str,
+    form_id: FormId,
+    answer_id: AnswerId,
+    answer_title: &AnswerTitle,
+) -> NotificationContent {
+    let title = answer_title
+        .clone()
+        .into_inner()
+        .map(|title| title.into_inner())
+        .unwrap_or_else(|| "（タイトルなし）".to_string());
+
+    NotificationContent::new(vec![
+        format!("回答『{title}』の担当者に割り当てられました。"),
+        "以下のリンクから回答を確認できます。".to_string(),
+        format!("{frontend_url}/forms/{form_id}/answers/{answer_id}"),
+    ])
+}
+
+fn answer_submitted_event(
+    actor: AnswerSubmissionActor,
+    form: &Allowed<ActiveForm, Read>,
+    answer: &AnswerEntry,
+) -> ApplicationEvent {
+    let questions = form.questions().as_slice();
+    let title = answer
+        .title()
+        .to_owned()
+        .into_inner()
+        .map(|title| EventDetail::new("回答タイトル", title.into_inner()));
+    let contents = answer.contents().iter().map(|content| {
+        let question_title = questions
+            .iter()
+            .find(|question| question.id() == content.question_id)
+            .map(|question| question.title().as_str().to_owned())
+            .unwrap_or_else(|| "不明な質問".to_string());
+        EventDetail::new(question_title, content.answer.to_owned())
+    });
+
+    ApplicationEvent::AnswerSubmitted {
+        actor,
+        form_id: form.id().to_string(),
+        form_title: form.title().as_str().to_owned(),
+        answer_id: answer.id().to_string(),
+        details: title.into_iter().chain(contents).collect(),
+    }
+}
+
+fn answer_status_changed_event(
+    actor: &AccountUser,
+    form_id: FormId,
+    answer: &Allowed<AnswerEntry, Read>,
+    status_change: AnswerStatusChange,
+) -> ApplicationEvent {
+    ApplicationEvent::AnswerStatusChanged {
+        actor: ApplicationActor::from(actor),
+        form_id: form_id.to_string(),
+        answer_title: answer
+            .title()
+            .to_owned()
+            .into_inner()
+            .map(|title| title.into_inner()),
+        answer_id: answer.id().to_string(),
+        status_change,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use domain::{
+        account::models::Role,
+        form::{
+            FormSubmissionRestriction, FormSubmissionRestrictionReason,
+            answer::{AnswerLabelId, AnswerListOrder, FormAnswerContentId},
+            content_filter::{ContentFilterAction, ContentFilterCondition, ContentFilterRule},
+            models::{
+                AllowedUserGroups, AnswerAuthorPublicationPolicy, AnswerQuizSettings,
+                AnswerSettings, DefaultAnswerTitle, DiscordWebhookUrl, FormDescription, FormTitle,
+                QuestionSet, TemporaryAnswerModerationPolicy,
+            },
+            question::{Choice, Question, QuizPoints},
+        },
+        pagination::PageLimit,
+        repository::form::{
+            answer_label_repository::AnswerLabelRepository,
+            message_read_repository::MockMessageReadRepository,
+        },
+        types::authorization_guard::{AuthorizationGuard, Create, Delete, Update},
+    };
+    use errors::domain::DomainError;
+    use std::sync::Mutex;
+    use types::non_empty_string::NonEmptyString;
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use crate::test_utils::repositories::{
+        FormUseCaseTestRepositories, InMemoryContentFilterRepository,
+    };
+
+    #[derive(Default)]
+    struct RecordingPublisher(Mutex<Vec<ApplicationEvent>>);
+
+    impl ApplicationEventPublisher for RecordingPublisher {
+        fn publish(&self, event: ApplicationEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    impl RecordingPublisher {
+        fn events(&self) -> Vec<ApplicationEvent> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingDiscordAnswerWebhookNotifier(Mutex<Vec<DiscordAnswerWebhookNotification>>);
+
+    #[async_trait]
+    impl DiscordAnswerWebhookNotifier for RecordingDiscordAnswerWebhookNotifier {
+        async fn notify_answer_posted(&self, notification: DiscordAnswerWebhookNotification) {
+            self.0.lock().unwrap().push(notification);
+        }
+    }
+
+    impl RecordingDiscordAnswerWebhookNotifier {
+        fn notifications(&self) -> Vec<DiscordAnswerWebhookNotification> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[derive(Default)]
+    struct EmptyAnswerLabelRepository;
+
+    #[async_trait]
+    impl AnswerLabelRepository for EmptyAnswerLabelRepository {
+        async fn create_label_for_answers(
+            &self,
+            _label: Allowed<AnswerLabel, Create>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn get_labels_for_answers(
+            &self,
+        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
+            Ok(vec![])
+        }
+
+        async fn get_label_for_answers(
+            &self,
+            _label_id: AnswerLabelId,
+        ) -> Result<Option<AuthorizationGuard<AnswerLabel, Read>>, Error> {
+            Ok(None)
+        }
+
+        async fn get_labels_for_answers_by_label_ids(
+            &self,
+            _label_ids: Vec<AnswerLabelId>,
+        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
+            Ok(vec![])
+        }
+
+        async fn get_labels_for_answers_by_answer_id(
+            &self,
+            _answer_id: AnswerId,
+        ) -> Result<Vec<AuthorizationGuard<AnswerLabel, Read>>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_label_for_answers(
+            &self,
+            _label: Allowed<AnswerLabel, Delete>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn edit_label_for_answers(
+            &self,
+            _label: Allowed<AnswerLabel, Update>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn replace_answer_labels(
+            &self,
+            _answer_id: AnswerId,
+            _labels: Vec<Allowed<AnswerLabel, Update>>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn size(&self) -> Result<u32, Error> {
+            Ok(0)
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingNotificator(Mutex<Vec<(UserId, NotificationType)>>);
+
+    #[async_trait::async_trait]
+    impl Notificator for RecordingNotificator {
+        async fn notify(
+            &self,
+            recipient: UserId,
+            notification_type: NotificationType,
+            _notification_preference: &domain::notification::models::NotificationPreference,
+            _content: &NotificationContent,
+        ) -> Result<(), Error> {
+            self.0.lock().unwrap().push((recipient, notification_type));
+            Ok(())
+        }
+    }
+
+    fn active_user(name: &str, role: Role) -> AccountUser {
+        AccountUser::new(name.to_string(), Uuid::new_v4().into(), role)
+    }
+
+    fn sample_form() -> ActiveForm {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            true,
+        )
+        .unwrap();
+
+        ActiveForm::new(
+            FormTitle::new("Form".to_string().try_into().unwrap()),
+            FormDescription::new("description".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+    }
+
+    fn form_with_default_answer_title(allow_temporary_answers: bool) -> ActiveForm {
+        sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .change_default_answer_title(DefaultAnswerTitle::new(Some(
+                    "$form_name".to_string().try_into().unwrap(),
+                )))
+                .try_change_audience(allow_temporary_answers, AllowedUserGroups::unrestricted())
+                .unwrap(),
+        )
+    }
+
+    fn answer_to(form: &ActiveForm) -> FormAnswerContent {
+        FormAnswerContent {
+            id: FormAnswerContentId::new(),
+            question_id: (*form.questions().as_slice()[0].id()).into(),
+            answer: "answer".to_string(),
+        }
+    }
+
+    async fn only_posted_answer_title(
+        repositories: &FormUseCaseTestRepositories,
+        form_id: FormId,
+    ) -> String {
+        let administrator = active_user("admin", Role::Administrator);
+        let form = repositories
+            .active_form_repository
+            .get(form_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .try_read(Actor::from(administrator))
+            .unwrap();
+        let answers = repositories
+            .answer_entry_repository
+            .list_by_form(
+                &form,
+                PageRequest::first(PageLimit::default_limit()),
+                AnswerListFilter::default(),
+            )
+            .await
+            .unwrap();
+
+        answers.items()[0]
+            .title()
+            .to_owned()
+            .into_inner()
+            .unwrap()
+            .into_inner()
+    }
+
+    #[tokio::test]
+    async fn post_answers_uses_current_form_title_for_generated_title() {
+        let form = form_with_default_answer_title(false);
+        let form_id = *form.id();
+        let answer = answer_to(&form);
+        let user = active_user("user", Role::StandardUser);
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        let empty_answer_label_repository = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &empty_answer_label_repository,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: Some(&publisher),
+        };
+
+        usecase
+            .post_answers(user, form_id, vec![answer])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            only_posted_answer_title(&repositories, form_id).await,
+            "Form"
+        );
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerSubmitted { actor, details, .. }]
+                if matches!(actor, AnswerSubmissionActor::Identified(ApplicationActor {
+                    display_name,
+                    account_id: Some(_),
+                }) if display_name == "user")
+                    && details.iter().any(|detail| detail.value == "answer")
+        ));
+    }
+
+    #[tokio::test]
+    async fn post_temporary_answers_uses_current_form_title_for_generated_title() {
+        // This test is the only usecase test that enables the form-specific notifier, which
+        // constructs a frontend link from the process configuration.
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let form = form_with_default_answer_title(true);
+        let settings = form.settings().clone().change_discord_webhook_url(
+            DiscordWebhookUrl::try_new(Some(
+                NonEmptyString::try_new("https://discord.com/api/webhooks/123/token".to_string())
+                    .unwrap(),
+            ))
+            .unwrap(),
+        );
+        let form = form.change_settings(settings);
+        let form_id = *form.id();
+        let answer = answer_to(&form);
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        let empty_answer_label_repository = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let notifier = RecordingDiscordAnswerWebhookNotifier::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &empty_answer_label_repository,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: Some(&notifier),
+            application_event_publisher: Some(&publisher),
+        };
+
+        usecase
+            .post_temporary_answers(
+                TemporaryAnswerAuthor::new("temporary user".to_string(), "contact".to_string()),
+                form_id,
+                vec![answer],
+                TemporaryAnswerSubmissionSource::new(
+                    Some("203.0.113.5".parse().unwrap()),
+                    Some("Mozilla/5.0".to_string()),
+                ),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            repositories
+                .answer_entry_repository
+                .saved_temporary_credentials()
+                .as_slice(),
+            [(access, ticket)]
+                if access.author().name() == "temporary user"
+                    && ticket.author().name() == "temporary user"
+                    && !ticket.is_claimed()
+        ));
+        assert!(matches!(
+            repositories
+                .temporary_answer_abuse_repository
+                .records()
+                .as_slice(),
+            [record]
+                if record.source().client_ip() == Some("203.0.113.5".parse().unwrap())
+                    && record.source().user_agent() == Some("Mozilla/5.0")
+        ));
+        assert_eq!(
+            only_posted_answer_title(&repositories, form_id).await,
+            "Form"
+        );
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerSubmitted { actor, details, .. }]
+                if matches!(actor, AnswerSubmissionActor::Identified(ApplicationActor {
+                    display_name,
+                    account_id: None,
+                }) if display_name == "temporary user")
+                    && details.iter().all(|detail| !detail.value.contains("contact"))
+        ));
+        assert!(matches!(
+            notifier.notifications().as_slice(),
+            [notification]
+                if notification.fields.iter().all(|field| !field.value.contains("contact"))
+                    && notification.fields.iter().any(|field|
+                        field.name == "回答者" && field.value == "temporary user")
+        ));
+    }
+
+    #[tokio::test]
+    async fn held_temporary_answer_is_announced_only_after_approval() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .try_change_audience(true, AllowedUserGroups::unrestricted())
+                .unwrap()
+                .change_temporary_answer_moderation_policy(
+                    TemporaryAnswerModerationPolicy::HoldForReview,
+                ),
+        );
+        let settings = form.settings().clone().change_discord_webhook_url(
+            DiscordWebhookUrl::try_new(Some(
+                NonEmptyString::try_new("https://discord.com/api/webhooks/123/token".to_string())
+                    .unwrap(),
+            ))
+            .unwrap(),
+        );
+        let form = form.change_settings(settings);
+        let form_id = *form.id();
+        let answer = answer_to(&form);
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        let labels = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let notifier = RecordingDiscordAnswerWebhookNotifier::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: Some(&notifier),
+            application_event_publisher: Some(&publisher),
+        };
+        usecase
+            .post_temporary_answers(
+                TemporaryAnswerAuthor::new("temporary user".to_string(), "contact".to_string()),
+                form_id,
+                vec![answer],
+                TemporaryAnswerSubmissionSource::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(publisher.events().is_empty());
+        assert!(notifier.notifications().is_empty());
+        assert_eq!(
+            repositories.answer_entry_repository.size().await.unwrap(),
+            0
+        );
+
+        let administrator = active_user("administrator", Role::Administrator);
+        let readable_form = repositories
+            .active_form_repository
+            .get(form_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .try_read(Actor::from(administrator.clone()))
+            .unwrap();
+        let answer_id = *repositories
+            .answer_entry_repository
+            .list_by_form(
+                &readable_form,
+                PageRequest::first(PageLimit::default_limit()),
+                AnswerListFilter::default()
+                    .with_moderation_status(Some(AnswerModerationStatus::PENDING)),
+            )
+            .await
+            .unwrap()
+            .items()[0]
+            .id();
+        let results = usecase
+            .bulk_update_answers(
+                &administrator,
+                vec![AnswerReference::new(form_id, answer_id)],
+                BulkAnswerOperation::Moderate(AnswerModerationDecision::Approve),
+            )
+            .await;
+        let approved_twice = usecase
+            .moderate_answer(
+                &administrator,
+                form_id,
+                answer_id,
+                AnswerModerationDecision::Reject,
+            )
+            .await;
+
+        assert!(results[0].result.is_ok());
+        assert!(matches!(
+            approved_twice,
+            Err(Error::Domain {
+                source: DomainError::AnswerNotPendingModeration
+            })
+        ));
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerSubmitted { answer_id: published, .. }]
+                if published == &answer_id.to_string()
+        ));
+        assert_eq!(notifier.notifications().len(), 1);
+        assert_eq!(
+            repositories.answer_entry_repository.size().await.unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn hidden_author_is_removed_from_title_and_both_discord_notifications() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let form = sample_form()
+            .change_answer_settings(
+                AnswerSettings::default()
+                    .change_default_answer_title(DefaultAnswerTitle::new(Some(
+                        "$username".to_string().try_into().unwrap(),
+                    )))
+                    .change_author_publication_policy(AnswerAuthorPublicationPolicy::Hide),
+            )
+            .change_settings(
+                domain::form::models::FormSettings::new().change_discord_webhook_url(
+                    DiscordWebhookUrl::try_new(Some(
+                        "https://discord.com/api/webhooks/123/token"
+                            .to_string()
+                            .try_into()
+                            .unwrap(),
+                    ))
+                    .unwrap(),
+                ),
+            );
+        let form_id = *form.id();
+        let answer = answer_to(&form);
+        let user = active_user("secret user", Role::StandardUser);
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        let labels = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let notifier = RecordingDiscordAnswerWebhookNotifier::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: Some(&notifier),
+            application_event_publisher: Some(&publisher),
+        };
+
+        usecase
+            .post_answers(user, form_id, vec![answer])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            only_posted_answer_title(&repositories, form_id).await,
+            "匿名"
+        );
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerSubmitted {
+                actor: AnswerSubmissionActor::AuthorHidden,
+                ..
+            }]
+        ));
+        assert!(matches!(
+            notifier.notifications().as_slice(),
+            [notification]
+                if notification.fields.iter().any(|field|
+                    field.name == "回答者" && field.value == "回答者は非公開です")
+                    && notification.fields.iter().all(|field|
+                        !field.value.contains("secret user"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn hidden_answer_author_is_anonymous_to_its_author_and_identified_to_administrator() {
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .change_author_publication_policy(AnswerAuthorPublicationPolicy::Hide),
+        );
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let answer = AnswerEntry::new(
+            form_id,
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                .unwrap(),
+        );
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+
+        let answer_for_author = usecase
+            .get_answers(form_id, answer_id, &author)
+            .await
+            .unwrap();
+        let answer_for_administrator = usecase
+            .get_answers(form_id, answer_id, &administrator)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            answer_for_author.answer.author,
+            PublishedAnswerAuthor::Anonymous
+        ));
+        assert!(matches!(
+            answer_for_administrator.answer.author,
+            PublishedAnswerAuthor::AuthenticatedUser(user)
+                if user.id() == author.id() && user.name() == "answer author"
+        ));
+    }
+
+    #[tokio::test]
+    async fn administrator_can_make_an_answer_private_and_third_parties_cannot_read_it() {
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .change_visibility(domain::form::models::AnswerVisibility::PUBLIC),
+        );
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let third_party = active_user("third party", Role::StandardUser);
+        let answer = AnswerEntry::new(
+            form_id,
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                .unwrap(),
+        );
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author);
+        let labels = EmptyAnswerLabelRepository;
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+
+        let updated = usecase
+            .update_answer_meta(
+                form_id,
+                answer_id,
+                &administrator,
+                None,
+                Some(AnswerPublication::PRIVATE),
+                Some(AnswerStatus::COMPLETED),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.answer.publication, AnswerPublication::PRIVATE);
+        assert_eq!(updated.answer.status, AnswerStatus::COMPLETED);
+        assert!(matches!(
+            usecase.get_answers(form_id, answer_id, &third_party).await,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn closing_as_duplicate_saves_the_relation_and_completes_the_answer() {
+        let form = sample_form();
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let new_answer = || {
+            AnswerEntry::new(
+                form_id,
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                    .unwrap(),
+            )
+        };
+        let answers = vec![new_answer(), new_answer()];
+        let duplicate = AnswerReference::new(form_id, *answers[0].id());
+        let original = AnswerReference::new(form_id, *answers[1].id());
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form.clone()]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(answers);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: Some(&publisher),
+        };
+
+        assert!(matches!(
+            usecase
+                .close_answer_as_duplicate(&author, duplicate, original)
+                .await,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
+        ));
+        assert!(
+            repositories
+                .answer_entry_repository
+                .saved_relations()
+                .is_empty()
+        );
+
+        let closed = usecase
+            .close_answer_as_duplicate(&administrator, duplicate, original)
+            .await
+            .unwrap();
+
+        assert_eq!(closed.answer.status, AnswerStatus::COMPLETED);
+        assert_eq!(
+            repositories.answer_entry_repository.saved_relations(),
+            vec![
+                AnswerRelation::with_kind(duplicate, original, AnswerRelationKind::DuplicateOf)
+                    .unwrap()
+            ]
+        );
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerStatusChanged { status_change, .. }]
+                if status_change.to() == AnswerStatus::COMPLETED
+        ));
+    }
+
+    #[tokio::test]
+    async fn unread_message_counts_are_only_shown_to_message_thread_participants() {
+        let form = sample_form().change_answer_settings(
+            AnswerSettings::default()
+                .change_visibility(domain::form::models::AnswerVisibility::PUBLIC),
+        );
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let third_party = active_user("third party", Role::StandardUser);
+        let answer = AnswerEntry::new(
+            form_id,
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                .unwrap(),
+        );
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let author_id = *author.id();
+        let mut message_reads = MockMessageReadRepository::new();
+        message_reads
+            .expect_unread_message_counts()
+            .returning(move |targets| {
+                Ok(targets
+                    .iter()
+                    .map(|target| {
+                        let count = if *target.reader_id() == author_id {
+                            2
+                        } else {
+                            0
+                        };
+                        (*target, count)
+                    })
+                    .collect())
+            });
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: Some(&message_reads),
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+
+        let mut unread_counts = Vec::new();
+        for viewer in [&author, &administrator, &third_party] {
+            unread_counts.push(
+                usecase
+                    .get_answers(form_id, answer_id, viewer)
+                    .await
+                    .unwrap()
+                    .unread_message_count,
+            );
+        }
+
+        assert_eq!(unread_counts, vec![Some(2), Some(0), None]);
+        for (viewer, reader) in [
+            (&third_party, *third_party.id()),
+            (&administrator, *author.id()),
+        ] {
+            assert!(matches!(
+                usecase
+                    .get_answers_by_form_id(
+                        form_id,
+                        viewer,
+                        PageRequest::first(PageLimit::default_limit()),
+                        AnswerListFilter::default().with_unread_author_messages_for(Some(reader)),
+                    )
+                    .await,
+                Err(Error::Domain {
+                    source: DomainError::Forbidden
+                })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn unread_message_counts_for_a_page_are_fetched_at_once() {
+        let form = sample_form();
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let answers = (0..3)
+            .map(|_| {
+                AnswerEntry::new(
+                    form_id,
+                    AnswerAuthor::AuthenticatedUser(*author.id()),
+                    AnswerTitle::default(),
+                    PostedAnswerContents::try_new(
+                        form.questions().as_slice(),
+                        vec![answer_to(&form)],
+                    )
+                    .unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(answers);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let mut message_reads = MockMessageReadRepository::new();
+        message_reads
+            .expect_unread_message_counts()
+            .times(1)
+            .returning(|targets| Ok(targets.iter().map(|target| (*target, 1)).collect()));
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: Some(&message_reads),
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+
+        let page = usecase
+            .get_answers_by_form_id(
+                form_id,
+                &author,
+                PageRequest::first(PageLimit::default_limit()),
+                AnswerListFilter::default(),
+            )
+            .await
+            .unwrap();
+
+        let (answers, _) = page.into_parts();
+        assert_eq!(
+            answers
+                .iter()
+                .map(|answer| answer.unread_message_count)
+                .collect::<Vec<_>>(),
+            vec![Some(1); 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn moving_an_answer_remaps_its_contents_by_template_key_and_manual_mapping() {
+        let text_question = |template_key: &str, position| {
+            Question::new_text(
+                template_key.to_string().try_into().unwrap(),
+                position,
+                template_key.to_string().try_into().unwrap(),
+                None,
+                false,
+            )
+            .unwrap()
+        };
+        let source = ActiveForm::new(
+            FormTitle::new("Source".to_string().try_into().unwrap()),
+            FormDescription::new("source".to_string()),
+            QuestionSet::try_new(
+                NonEmptyVec::try_new(vec![text_question("body", 0), text_question("detail", 1)])
+                    .unwrap(),
+            )
+            .unwrap(),
+        );
+        let destination = ActiveForm::new(
+            FormTitle::new("Destination".to_string().try_into().unwrap()),
+            FormDescription::new("destination".to_string()),
+            QuestionSet::try_new(
+                NonEmptyVec::try_new(vec![
+                    text_question("body", 0),
+                    text_question("description", 1),
+                ])
+                .unwrap(),
+            )
+            .unwrap(),
+        );
+        let source_questions = source.questions().as_slice().to_vec();
+        let destination_questions = destination.questions().as_slice().to_vec();
+        let (source_id, destination_id) = (*source.id(), *destination.id());
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let contents = source_questions
+            .iter()
+            .map(|question| FormAnswerContent {
+                id: FormAnswerContentId::new(),
+                question_id: question.id(),
+                answer: question.template_key().to_string(),
+            })
+            .collect::<Vec<_>>();
+        let answer = AnswerEntry::new(
+            source_id,
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&source_questions, contents).unwrap(),
+        );
+        let answer_id = *answer.id();
+        let mut repositories =
+            FormUseCaseTestRepositories::with_active_forms(vec![source, destination]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
 
-        async fn replace_answer_labels(
-            &self,
-            _answer_id: AnswerId,
-            _labels: Vec<Allowed<AnswerLabel, Update>>,
-        ) -> Result<(), Error> {
-            Ok(())
-        }
+        assert!(matches!(
+            usecase
+                .move_answer(
+                    &administrator,
+                    source_id,
+                    answer_id,
+                    destination_id,
+                    HashMap::new(),
+                )
+                .await,
+            Err(Error::Domain {
+                source: DomainError::InvalidEntity { .. }
+            })
+        ));
+        assert!(matches!(
+            usecase
+                .move_answer(
+                    &author,
+                    source_id,
+                    answer_id,
+                    destination_id,
+                    HashMap::from([(source_questions[1].id(), destination_questions[1].id())]),
+                )
+                .await,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
+        ));
 
-        async fn size(&self) -> Result<u32, Error> {
-            Ok(0)
-        }
-    }
+        let moved = usecase
+            .move_answer(
+                &administrator,
+                source_id,
+                answer_id,
+                destination_id,
+                HashMap::from([(source_questions[1].id(), destination_questions[1].id())]),
+            )
+            .await
+            .unwrap();
 
-    fn active_user(name: &str, role: Role) -> AccountUser {
-        AccountUser::new(name.to_string(), Uuid::new_v4().into(), role)
+        assert_eq!(moved.form_id, destination_id);
+        assert_eq!(
+            moved
+                .answer
+                .contents
+                .iter()
+                .map(|content| (content.question_id, content.answer.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (destination_questions[0].id(), "body"),
+                (destination_questions[1].id(), "detail"),
+            ]
+        );
+        assert!(matches!(
+            usecase
+                .get_answers(source_id, answer_id, &administrator)
+                .await,
+            Err(Error::UseCase {
+                source: errors::usecase::UseCaseError::AnswerNotFound
+            })
+        ));
     }
 
-    fn sample_form() -> ActiveForm {
-        let question = Question::new_text(
-            "body".to_string().try_into().unwrap(),
-            0,
-            "Body".to_string().try_into().unwrap(),
-            None,
-            true,
-        )
-        .unwrap();
+    #[tokio::test]
+    async fn assigning_an_answer_notifies_only_a_newly_assigned_administrator() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let form = sample_form();
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let assignee = active_user("assignee", Role::Administrator);
+        let answer = AnswerEntry::new(
+            form_id,
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                .unwrap(),
+        );
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author.clone());
+        repositories
+            .user_repository
+            .save_user(administrator.clone());
+        repositories.user_repository.save_user(assignee.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let notificator = RecordingNotificator::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+        let assign = |assignee_id| {
+            usecase.assign_answer(
+                &administrator,
+                form_id,
+                answer_id,
+                assignee_id,
+                &notificator,
+                &repositories.notification_repository,
+            )
+        };
 
-        ActiveForm::new(
-            FormTitle::new("Form".to_string().try_into().unwrap()),
-            FormDescription::new("description".to_string()),
-            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
-        )
-    }
+        let assigned = assign(Some(*assignee.id())).await.unwrap();
+        assign(Some(*assignee.id())).await.unwrap();
+        assign(Some(*administrator.id())).await.unwrap();
+        let unassigned = assign(None).await.unwrap();
 
-    fn form_with_default_answer_title(allow_temporary_answers: bool) -> ActiveForm {
-        sample_form().change_answer_settings(
-            AnswerSettings::default()
-                .change_default_answer_title(DefaultAnswerTitle::new(Some(
-                    "$form_name".to_string().try_into().unwrap(),
-                )))
-                .try_change_audience(allow_temporary_answers, AllowedUserGroups::unrestricted())
-                .unwrap(),
-        )
+        assert_eq!(assigned.answer.assignee, Some(*assignee.id()));
+        assert_eq!(unassigned.answer.assignee, None);
+        assert_eq!(
+            notificator.0.lock().unwrap().as_slice(),
+            [(*assignee.id(), NotificationType::AnswerAssigned)]
+        );
+        assert_eq!(
+            usecase
+                .get_answers(form_id, answer_id, &author)
+                .await
+                .unwrap()
+                .answer
+                .assignee,
+            None
+        );
     }
 
-    fn answer_to(form: &ActiveForm) -> FormAnswerContent {
-        FormAnswerContent {
-            id: FormAnswerContentId::new(),
-            question_id: (*form.questions().as_slice()[0].id()).into(),
-            answer: "answer".to_string(),
-        }
-    }
+    #[tokio::test]
+    async fn overdue_answers_are_announced_once_per_due_date() {
+        let form = sample_form();
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let new_answer = || {
+            AnswerEntry::new(
+                form_id,
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                    .unwrap(),
+            )
+        };
+        let overdue_answer = new_answer();
+        let overdue_answer_id = *overdue_answer.id();
+        let upcoming_answer = new_answer();
+        let upcoming_answer_id = *upcoming_answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form.clone()]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![
+                overdue_answer,
+                upcoming_answer,
+            ]);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: Some(&publisher),
+        };
+        let past = Utc::now() - chrono::TimeDelta::hours(1);
+        let future = Utc::now() + chrono::TimeDelta::hours(1);
 
-    async fn only_posted_answer_title(
-        repositories: &FormUseCaseTestRepositories,
-        form_id: FormId,
-    ) -> String {
-        let administrator = active_user("admin", Role::Administrator);
-        let form = repositories
-            .active_form_repository
-            .get(form_id)
+        let changed = usecase
+            .change_answer_due_date(&administrator, form_id, overdue_answer_id, Some(past))
             .await
-            .unwrap()
-            .unwrap()
-            .try_read(Actor::from(administrator))
             .unwrap();
-        let answers = repositories
-            .answer_entry_repository
-            .list_by_form(&form, PageRequest::first(PageLimit::default_limit()), None)
+        usecase
+            .change_answer_due_date(&administrator, form_id, upcoming_answer_id, Some(future))
             .await
             .unwrap();
+        usecase.notify_overdue_answers().await.unwrap();
+        usecase.notify_overdue_answers().await.unwrap();
 
-        answers.items()[0]
-            .title()
-            .to_owned()
-            .into_inner()
-            .unwrap()
-            .into_inner()
+        assert_eq!(changed.answer.due_at, Some(past));
+        assert_eq!(
+            usecase
+                .get_answers(form_id, overdue_answer_id, &author)
+                .await
+                .unwrap()
+                .answer
+                .due_at,
+            None
+        );
+        assert!(matches!(
+            publisher.events().as_slice(),
+            [ApplicationEvent::AnswerOverdue { answer_id, due_at, .. }]
+                if answer_id == &overdue_answer_id.to_string() && due_at == &past
+        ));
+
+        let extended = past + chrono::TimeDelta::minutes(30);
+        usecase
+            .change_answer_due_date(&administrator, form_id, overdue_answer_id, Some(extended))
+            .await
+            .unwrap();
+        usecase.notify_overdue_answers().await.unwrap();
+
+        assert_eq!(publisher.events().len(), 2);
     }
 
     #[tokio::test]
-    async fn post_answers_uses_current_form_title_for_generated_title() {
-        let form = form_with_default_answer_title(false);
+    async fn pending_overdue_answers_are_not_announced() {
+        let form = sample_form();
         let form_id = *form.id();
-        let answer = answer_to(&form);
-        let user = active_user("user", Role::StandardUser);
-        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
-        let empty_answer_label_repository = EmptyAnswerLabelRepository;
+        let author = active_user("answer author", Role::StandardUser);
+        let pending_answer = unsafe {
+            AnswerEntry::new(
+                form_id,
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                    .unwrap(),
+            )
+            .with_raw_due_at(Some(Utc::now() - chrono::TimeDelta::hours(1)))
+            .with_raw_moderation_status(AnswerModerationStatus::PENDING)
+        };
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![
+                pending_answer,
+            ]);
+        let labels = EmptyAnswerLabelRepository;
         let publisher = RecordingPublisher::default();
         let usecase = AnswerUseCase {
             active_form_repository: &repositories.active_form_repository,
-            answer_label_repository: &empty_answer_label_repository,
+            answer_label_repository: &labels,
             user_repository: &repositories.user_repository,
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: Some(&publisher),
         };
 
-        usecase
-            .post_answers(user, form_id, vec![answer])
+        usecase.notify_overdue_answers().await.unwrap();
+
+        assert!(publisher.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn statistics_count_choices_and_hide_private_answers_from_standard_users() {
+        let new_poll = || {
+            let question = Question::new_single_choice(
+                "vote".to_string().try_into().unwrap(),
+                0,
+                "Vote".to_string().try_into().unwrap(),
+                None,
+                NonEmptyVec::try_new(vec![
+                    Choice::new(None, 0, "Yes".to_string().try_into().unwrap()),
+                    Choice::new(None, 1, "No".to_string().try_into().unwrap()),
+                ])
+                .unwrap(),
+                true,
+            )
+            .unwrap();
+
+            ActiveForm::new(
+                FormTitle::new("Poll".to_string().try_into().unwrap()),
+                FormDescription::new("description".to_string()),
+                QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+            )
+        };
+        let poll = new_poll();
+        let public_poll = new_poll().change_answer_settings(
+            AnswerSettings::default()
+                .change_visibility(domain::form::models::AnswerVisibility::PUBLIC),
+        );
+        let author = active_user("answer author", Role::StandardUser);
+        let viewer = active_user("viewer", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let vote = |form: &ActiveForm, choice: &str, publication: AnswerPublication| {
+            AnswerEntry::new(
+                *form.id(),
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(
+                    form.questions().as_slice(),
+                    vec![FormAnswerContent {
+                        id: FormAnswerContentId::new(),
+                        question_id: form.questions().as_slice()[0].id(),
+                        answer: choice.to_string(),
+                    }],
+                )
+                .unwrap(),
+            )
+            .change_publication(publication)
+        };
+        let mut repositories =
+            FormUseCaseTestRepositories::with_active_forms(vec![poll.clone(), public_poll.clone()]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![
+                vote(&public_poll, "Yes", AnswerPublication::PUBLIC),
+                vote(&public_poll, "Yes", AnswerPublication::PUBLIC),
+                vote(&public_poll, "No", AnswerPublication::PRIVATE),
+                // 確認待ちの回答は集計に含めない
+                unsafe {
+                    vote(&public_poll, "No", AnswerPublication::PUBLIC)
+                        .with_raw_moderation_status(AnswerModerationStatus::PENDING)
+                },
+            ]);
+        let labels = EmptyAnswerLabelRepository;
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+        let choice_counts = |statistics: &AnswerStatistics| {
+            statistics
+                .choices()
+                .iter()
+                .map(|choice| (choice.label.to_string(), choice.count))
+                .collect::<Vec<_>>()
+        };
+
+        let for_administrator = usecase
+            .get_answer_statistics(*public_poll.id(), &administrator)
+            .await
+            .unwrap();
+        let for_viewer = usecase
+            .get_answer_statistics(*public_poll.id(), &viewer)
             .await
             .unwrap();
 
+        assert_eq!(*for_administrator.total(), 3);
         assert_eq!(
-            only_posted_answer_title(&repositories, form_id).await,
-            "Form"
+            choice_counts(&for_administrator),
+            vec![("Yes".to_string(), 2), ("No".to_string(), 1)]
+        );
+        assert_eq!(*for_viewer.total(), 2);
+        assert_eq!(
+            choice_counts(&for_viewer),
+            vec![("Yes".to_string(), 2), ("No".to_string(), 0)]
         );
         assert!(matches!(
-            publisher.events().as_slice(),
-            [ApplicationEvent::AnswerSubmitted { actor, details, .. }]
-                if matches!(actor, AnswerSubmissionActor::Identified(ApplicationActor {
-                    display_name,
-                    account_id: Some(_),
-                }) if display_name == "user")
-                    && details.iter().any(|detail| detail.value == "answer")
+            usecase.get_answer_statistics(*poll.id(), &viewer).await,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
         ));
     }
 
+    fn blocked_word_rule(form_id: FormId, action: ContentFilterAction) -> ContentFilterRule {
+        ContentFilterRule::try_new(
+            form_id,
+            ContentFilterCondition::BlockedWord("spam".to_string().try_into().unwrap()),
+            action,
+            Uuid::new_v4().into(),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
     #[tokio::test]
-    async fn post_temporary_answers_uses_current_form_title_for_generated_title() {
-        // This test is the only usecase test that enables the form-specific notifier, which
-        // constructs a frontend link from the process configuration.
-        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
-        let form = form_with_default_answer_title(true);
-        let settings = form.settings().clone().change_discord_webhook_url(
-            DiscordWebhookUrl::try_new(Some(
-                NonEmptyString::try_new("https://discord.com/api/webhooks/123/token".to_string())
-                    .unwrap(),
-            ))
-            .unwrap(),
-        );
-        let form = form.change_settings(settings);
+    async fn answer_rejected_by_content_filter_is_not_stored() {
+        let form = sample_form();
         let form_id = *form.id();
-        let answer = answer_to(&form);
-        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
-        let empty_answer_label_repository = EmptyAnswerLabelRepository;
+        let answer = FormAnswerContent {
+            answer: "Buy SPAM now".to_string(),
+            ..answer_to(&form)
+        };
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.content_filter_repository =
+            InMemoryContentFilterRepository::with_rules(vec![blocked_word_rule(
+                form_id,
+                ContentFilterAction::Reject,
+            )]);
+        let labels = EmptyAnswerLabelRepository;
         let publisher = RecordingPublisher::default();
-        let notifier = RecordingDiscordAnswerWebhookNotifier::default();
         let usecase = AnswerUseCase {
             active_form_repository: &repositories.active_form_repository,
-            answer_label_repository: &empty_answer_label_repository,
+            answer_label_repository: &labels,
             user_repository: &repositories.user_repository,
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
-            discord_answer_webhook_notifier: Some(&notifier),
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
             application_event_publisher: Some(&publisher),
         };
 
-        usecase
-            .post_temporary_answers(
-                TemporaryAnswerAuthor::new("temporary user".to_string(), "contact".to_string()),
+        let result = usecase
+            .post_answers(
+                active_user("user", Role::StandardUser),
                 form_id,
                 vec![answer],
             )
-            .await
-            .unwrap();
+            .await;
 
-        assert_eq!(
-            only_posted_answer_title(&repositories, form_id).await,
-            "Form"
+        assert_eq!(result, Err(DomainError::ContentRejectedByFilter.into()));
+        assert!(publisher.events().is_empty());
+        assert!(
+            repositories
+                .content_filter_repository
+                .enforcements()
+                .is_empty()
         );
-        assert!(matches!(
-            publisher.events().as_slice(),
-            [ApplicationEvent::AnswerSubmitted { actor, details, .. }]
-                if matches!(actor, AnswerSubmissionActor::Identified(ApplicationActor {
-                    display_name,
-                    account_id: None,
-                }) if display_name == "temporary user")
-                    && details.iter().all(|detail| !detail.value.contains("contact"))
-        ));
-        assert!(matches!(
-            notifier.notifications().as_slice(),
-            [notification]
-                if notification.fields.iter().all(|field| !field.value.contains("contact"))
-                    && notification.fields.iter().any(|field|
-                        field.name == "回答者" && field.value == "temporary user")
-        ));
     }
 
     #[tokio::test]
-    async fn hidden_author_is_removed_from_title_and_both_discord_notifications() {
-        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
-        let form = sample_form()
-            .change_answer_settings(
-                AnswerSettings::default()
-                    .change_default_answer_title(DefaultAnswerTitle::new(Some(
-                        "$username".to_string().try_into().unwrap(),
-                    )))
-                    .change_author_publication_policy(AnswerAuthorPublicationPolicy::Hide),
-            )
-            .change_settings(
-                domain::form::models::FormSettings::new().change_discord_webhook_url(
-                    DiscordWebhookUrl::try_new(Some(
-                        "https://discord.com/api/webhooks/123/token"
-                            .to_string()
-                            .try_into()
-                            .unwrap(),
-                    ))
-                    .unwrap(),
-                ),
-            );
+    async fn flagged_answer_is_announced_only_after_approval() {
+        let form = sample_form();
         let form_id = *form.id();
-        let answer = answer_to(&form);
-        let user = active_user("secret user", Role::StandardUser);
-        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        let answer = FormAnswerContent {
+            answer: "Buy SPAM now".to_string(),
+            ..answer_to(&form)
+        };
+        let user = active_user("user", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.user_repository.save_user(user.clone());
+        repositories.content_filter_repository =
+            InMemoryContentFilterRepository::with_rules(vec![blocked_word_rule(
+                form_id,
+                ContentFilterAction::FlagForReview,
+            )]);
         let labels = EmptyAnswerLabelRepository;
         let publisher = RecordingPublisher::default();
-        let notifier = RecordingDiscordAnswerWebhookNotifier::default();
         let usecase = AnswerUseCase {
             active_form_repository: &repositories.active_form_repository,
             answer_label_repository: &labels,
@@ -1003,7 +2918,10 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
-            discord_answer_webhook_notifier: Some(&notifier),
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
             application_event_publisher: Some(&publisher),
         };
 
@@ -1012,33 +2930,36 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(
-            only_posted_answer_title(&repositories, form_id).await,
-            "匿名"
-        );
+        assert!(publisher.events().is_empty());
+        let enforcements = repositories.content_filter_repository.enforcements();
+        let [enforcement] = enforcements.as_slice() else {
+            panic!("expected a single enforcement, got {enforcements:?}");
+        };
+        assert_eq!(enforcement.flags().len(), 1);
+
+        usecase
+            .moderate_answer(
+                &administrator,
+                form_id,
+                *enforcement.answer_id(),
+                AnswerModerationDecision::Approve,
+            )
+            .await
+            .unwrap();
+
         assert!(matches!(
             publisher.events().as_slice(),
-            [ApplicationEvent::AnswerSubmitted {
-                actor: AnswerSubmissionActor::AuthorHidden,
-                ..
-            }]
-        ));
-        assert!(matches!(
-            notifier.notifications().as_slice(),
-            [notification]
-                if notification.fields.iter().any(|field|
-                    field.name == "回答者" && field.value == "回答者は非公開です")
-                    && notification.fields.iter().all(|field|
-                        !field.value.contains("secret user"))
+            [ApplicationEvent::AnswerSubmitted { actor, .. }]
+                if matches!(actor, AnswerSubmissionActor::Identified(ApplicationActor {
+                    display_name,
+                    account_id: Some(_),
+                }) if display_name == "user")
         ));
     }
 
     #[tokio::test]
-    async fn hidden_answer_author_is_anonymous_to_its_author_and_identified_to_administrator() {
-        let form = sample_form().change_answer_settings(
-            AnswerSettings::default()
-                .change_author_publication_policy(AnswerAuthorPublicationPolicy::Hide),
-        );
+    async fn edited_answer_titles_are_screened_by_the_content_filter() {
+        let form = sample_form();
         let form_id = *form.id();
         let author = active_user("answer author", Role::StandardUser);
         let administrator = active_user("administrator", Role::Administrator);
@@ -1053,7 +2974,18 @@ mod tests {
         let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
         repositories.answer_entry_repository =
             crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
-        repositories.user_repository.save_user(author.clone());
+        repositories.user_repository.save_user(author);
+        repositories.content_filter_repository = InMemoryContentFilterRepository::with_rules(vec![
+            blocked_word_rule(form_id, ContentFilterAction::Reject),
+            ContentFilterRule::try_new(
+                form_id,
+                ContentFilterCondition::BlockedWord("discount".to_string().try_into().unwrap()),
+                ContentFilterAction::FlagForReview,
+                Uuid::new_v4().into(),
+                Utc::now(),
+            )
+            .unwrap(),
+        ]);
         let labels = EmptyAnswerLabelRepository;
         let usecase = AnswerUseCase {
             active_form_repository: &repositories.active_form_repository,
@@ -1062,40 +2994,160 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: None,
         };
+        let update_title = |title: &str| {
+            usecase.update_answer_meta(
+                form_id,
+                answer_id,
+                &administrator,
+                Some(AnswerTitle::new(Some(
+                    NonEmptyString::try_new(title.to_string()).unwrap(),
+                ))),
+                None,
+                Some(AnswerStatus::COMPLETED),
+            )
+        };
 
-        let answer_for_author = usecase
-            .get_answers(form_id, answer_id, &author)
-            .await
-            .unwrap();
-        let answer_for_administrator = usecase
-            .get_answers(form_id, answer_id, &administrator)
+        let rejected = update_title("Buy SPAM now").await;
+        let unchanged = usecase
+            .update_answer_meta(form_id, answer_id, &administrator, None, None, None)
             .await
             .unwrap();
+        update_title("A discount").await.unwrap();
 
+        assert_eq!(
+            rejected.map(|_| ()),
+            Err(DomainError::ContentRejectedByFilter.into())
+        );
+        assert_eq!(unchanged.answer.title, AnswerTitle::default());
+        assert_eq!(unchanged.answer.status, AnswerStatus::UNADDRESSED);
         assert!(matches!(
-            answer_for_author.answer.author,
-            PublishedAnswerAuthor::Anonymous
+            repositories.content_filter_repository.enforcements().as_slice(),
+            [enforcement] if enforcement.answer_id() == &answer_id
+                && matches!(
+                    enforcement.flags().as_slice(),
+                    [flag] if flag.target() == &ContentFilterTarget::Answer
+                )
         ));
+    }
+
+    #[tokio::test]
+    async fn quiz_answers_are_scored_on_submission_and_only_administrators_can_sort_by_score() {
+        let question = Question::new_single_choice(
+            "mob".to_string().try_into().unwrap(),
+            0,
+            "Which mob explodes?".to_string().try_into().unwrap(),
+            None,
+            NonEmptyVec::try_new(vec![
+                Choice::new(None, 0, "Creeper".to_string().try_into().unwrap())
+                    .with_quiz_points(Some(QuizPoints::try_from(10).unwrap())),
+                Choice::new(None, 1, "Zombie".to_string().try_into().unwrap()),
+            ])
+            .unwrap(),
+            true,
+        )
+        .unwrap();
+        let quiz = ActiveForm::new(
+            FormTitle::new("Quiz".to_string().try_into().unwrap()),
+            FormDescription::new("description".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+        .change_answer_settings(
+            AnswerSettings::default().change_quiz(AnswerQuizSettings::new(true, true)),
+        );
+        let quiz_id = *quiz.id();
+        let answer = |choice: &str| {
+            vec![FormAnswerContent {
+                id: FormAnswerContentId::new(),
+                question_id: quiz.questions().as_slice()[0].id(),
+                answer: choice.to_string(),
+            }]
+        };
+        let respondent = active_user("respondent", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![quiz.clone()]);
+        repositories.user_repository.save_user(respondent.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: None,
+        };
+
+        let correct = usecase
+            .post_answers(respondent.clone(), quiz_id, answer("Creeper"))
+            .await
+            .unwrap()
+            .unwrap();
+        let incorrect = usecase
+            .post_answers(respondent.clone(), quiz_id, answer("Zombie"))
+            .await
+            .unwrap()
+            .unwrap();
+        let by_score = usecase
+            .get_answers_by_form_id(
+                quiz_id,
+                &administrator,
+                PageRequest::first(PageLimit::default_limit()),
+                AnswerListFilter::default().with_order(AnswerListOrder::ScoreDescending),
+            )
+            .await
+            .unwrap();
+        let passed = usecase
+            .get_answers_by_form_id(
+                quiz_id,
+                &administrator,
+                PageRequest::first(PageLimit::default_limit()),
+                AnswerListFilter::default().with_score_range(Some(5), None),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((*correct.score(), *correct.max_score()), (10, 10));
+        assert_eq!((*incorrect.score(), *incorrect.max_score()), (0, 10));
+        assert_eq!(
+            by_score
+                .items()
+                .iter()
+                .map(|details| details.answer.quiz_score.map(|score| *score.score()))
+                .collect::<Vec<_>>(),
+            vec![Some(10), Some(0)]
+        );
+        assert_eq!(passed.items().len(), 1);
         assert!(matches!(
-            answer_for_administrator.answer.author,
-            PublishedAnswerAuthor::AuthenticatedUser(user)
-                if user.id() == author.id() && user.name() == "answer author"
+            usecase
+                .get_answers_by_form_id(
+                    quiz_id,
+                    &respondent,
+                    PageRequest::first(PageLimit::default_limit()),
+                    AnswerListFilter::default().with_order(AnswerListOrder::ScoreAscending),
+                )
+                .await,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
         ));
     }
 
     #[tokio::test]
-    async fn administrator_can_make_an_answer_private_and_third_parties_cannot_read_it() {
-        let form = sample_form().change_answer_settings(
-            AnswerSettings::default()
-                .change_visibility(domain::form::models::AnswerVisibility::PUBLIC),
-        );
+    async fn standard_users_cannot_be_assigned_to_an_answer() {
+        let form = sample_form();
         let form_id = *form.id();
         let author = active_user("answer author", Role::StandardUser);
         let administrator = active_user("administrator", Role::Administrator);
-        let third_party = active_user("third party", Role::StandardUser);
         let answer = AnswerEntry::new(
             form_id,
             AnswerAuthor::AuthenticatedUser(*author.id()),
@@ -1107,8 +3159,9 @@ mod tests {
         let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
         repositories.answer_entry_repository =
             crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![answer]);
-        repositories.user_repository.save_user(author);
+        repositories.user_repository.save_user(author.clone());
         let labels = EmptyAnswerLabelRepository;
+        let notificator = RecordingNotificator::default();
         let usecase = AnswerUseCase {
             active_form_repository: &repositories.active_form_repository,
             answer_label_repository: &labels,
@@ -1116,30 +3169,31 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: None,
         };
 
-        let updated = usecase
-            .update_answer_meta(
+        let result = usecase
+            .assign_answer(
+                &administrator,
                 form_id,
                 answer_id,
-                &administrator,
-                None,
-                Some(AnswerPublication::PRIVATE),
-                Some(AnswerStatus::COMPLETED),
+                Some(*author.id()),
+                &notificator,
+                &repositories.notification_repository,
             )
-            .await
-            .unwrap();
+            .await;
 
-        assert_eq!(updated.answer.publication, AnswerPublication::PRIVATE);
-        assert_eq!(updated.answer.status, AnswerStatus::COMPLETED);
         assert!(matches!(
-            usecase.get_answers(form_id, answer_id, &third_party).await,
+            result,
             Err(Error::Domain {
-                source: DomainError::Forbidden
+                source: DomainError::InvalidAnswerAssignee
             })
         ));
+        assert!(notificator.0.lock().unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -1169,6 +3223,9 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: Some(&publisher),
         };
@@ -1204,6 +3261,103 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn bulk_status_change_reports_each_answer_and_publishes_its_usual_event() {
+        let form = sample_form();
+        let form_id = *form.id();
+        let author = active_user("answer author", Role::StandardUser);
+        let administrator = active_user("administrator", Role::Administrator);
+        let new_answer = || {
+            AnswerEntry::new(
+                form_id,
+                AnswerAuthor::AuthenticatedUser(*author.id()),
+                AnswerTitle::default(),
+                PostedAnswerContents::try_new(form.questions().as_slice(), vec![answer_to(&form)])
+                    .unwrap(),
+            )
+        };
+        let first = new_answer();
+        let second = new_answer();
+        let targets = vec![
+            AnswerReference::new(form_id, *first.id()),
+            AnswerReference::new(form_id, AnswerId::new()),
+            AnswerReference::new(form_id, *second.id()),
+        ];
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form.clone()]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![
+                first, second,
+            ]);
+        repositories.user_repository.save_user(author.clone());
+        let labels = EmptyAnswerLabelRepository;
+        let publisher = RecordingPublisher::default();
+        let usecase = AnswerUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_label_repository: &labels,
+            user_repository: &repositories.user_repository,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
+            discord_answer_webhook_notifier: None,
+            application_event_publisher: Some(&publisher),
+        };
+
+        let results = usecase
+            .bulk_update_answers(
+                &administrator,
+                targets.clone(),
+                BulkAnswerOperation::ChangeStatus(AnswerStatus::IN_PROGRESS),
+            )
+            .await;
+        let rejected = usecase
+            .bulk_update_answers(
+                &author,
+                targets[..1].to_vec(),
+                BulkAnswerOperation::ChangeStatus(AnswerStatus::COMPLETED),
+            )
+            .await;
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| result.answer)
+                .collect::<Vec<_>>(),
+            targets
+        );
+        assert!(results[0].result.is_ok());
+        assert!(matches!(
+            results[1].result,
+            Err(Error::UseCase {
+                source: errors::usecase::UseCaseError::AnswerNotFound
+            })
+        ));
+        assert!(results[2].result.is_ok());
+        assert!(matches!(
+            rejected[0].result,
+            Err(Error::Domain {
+                source: DomainError::Forbidden
+            })
+        ));
+        assert_eq!(
+            publisher
+                .events()
+                .iter()
+                .filter_map(|event| match event {
+                    ApplicationEvent::AnswerStatusChanged { answer_id, .. } =>
+                        Some(answer_id.to_owned()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                targets[0].answer_id().to_string(),
+                targets[2].answer_id().to_string()
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn updates_without_a_status_transition_do_not_publish_a_status_event() {
         let form = sample_form();
@@ -1231,6 +3385,9 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: Some(&publisher),
         };
@@ -1305,6 +3462,9 @@ mod tests {
             form_submission_restriction_repository: &repositories
                 .form_submission_restriction_repository,
             answer_entry_repository: &repositories.answer_entry_repository,
+            content_filter_repository: &repositories.content_filter_repository,
+            temporary_answer_abuse_repository: &repositories.temporary_answer_abuse_repository,
+            message_read_repository: None,
             discord_answer_webhook_notifier: None,
             application_event_publisher: None,
         };
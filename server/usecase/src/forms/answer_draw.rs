@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+
+use common::config::FRONTEND;
+use domain::{
+    account::models::{AccountUser, UserId},
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId, AnswerLabelId, AnswerListFilter, AnswerModerationStatus},
+        draw::{
+            AnswerDraw, AnswerDrawCriteria, AnswerDrawId, AnswerDrawSeed, AnswerDrawWinnerCount,
+        },
+        models::{ActiveForm, FormId, FormTitle},
+    },
+    notification::{
+        models::{NotificationContent, NotificationType},
+        notificator::Notificator,
+    },
+    pagination::{PageLimit, PageRequest},
+    repository::{
+        form::{
+            active_form_repository::ActiveFormRepository,
+            answer_draw_repository::AnswerDrawRepository,
+            answer_entry_repository::AnswerEntryRepository,
+            answer_label_repository::AnswerLabelRepository,
+        },
+        form_submission_restriction_repository::FormSubmissionRestrictionRepository,
+        notification_repository::NotificationRepository,
+        user_repository::UserRepository,
+    },
+    types::authorization_guard::{Allowed, Read},
+};
+use errors::{
+    Error,
+    usecase::UseCaseError::{DrawNotFound, FormNotFound},
+};
+
+use crate::notification::notification_preference_for;
+
+pub struct AnswerDrawUseCase<
+    'a,
+    FormRepo: ActiveFormRepository,
+    AnswerEntryRepo: AnswerEntryRepository,
+    AnswerLabelRepo: AnswerLabelRepository,
+    FormSubmissionRestrictionRepo: FormSubmissionRestrictionRepository,
+    UserRepo: UserRepository,
+    AnswerDrawRepo: AnswerDrawRepository,
+> {
+    pub active_form_repository: &'a FormRepo,
+    pub answer_entry_repository: &'a AnswerEntryRepo,
+    pub answer_label_repository: &'a AnswerLabelRepo,
+    pub form_submission_restriction_repository: &'a FormSubmissionRestrictionRepo,
+    pub user_repository: &'a UserRepo,
+    pub answer_draw_repository: &'a AnswerDrawRepo,
+}
+
+impl<
+    R1: ActiveFormRepository,
+    R2: AnswerEntryRepository,
+    R3: AnswerLabelRepository,
+    R4: FormSubmissionRestrictionRepository,
+    R5: UserRepository,
+    R6: AnswerDrawRepository,
+> AnswerDrawUseCase<'_, R1, R2, R3, R4, R5, R6>
+{
+    async fn read_form(
+        &self,
+        form_id: FormId,
+        actor: &Actor,
+    ) -> Result<Allowed<ActiveForm, Read>, Error> {
+        self.active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .try_read(actor.clone())
+            .map_err(Into::into)
+    }
+
+    /// `criteria` に一致する回答の中から `winner_count` 件の当選者を選び、抽選の記録を保存します。
+    ///
+    /// `seed` を指定しない場合は、予測できないシードを生成して記録します。
+    /// `notify_winners` が `true` の場合、ログインして回答した当選者に通知を送ります。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn draw<N: Notificator, NotificationRepo: NotificationRepository>(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        criteria: AnswerDrawCriteria,
+        winner_count: AnswerDrawWinnerCount,
+        seed: Option<AnswerDrawSeed>,
+        notify_winners: bool,
+        notificator: &N,
+        notification_repository: &NotificationRepo,
+    ) -> Result<AnswerDraw, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor_ref).await?;
+        let form_update = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor_ref.clone())?;
+
+        let candidates = self
+            .collect_candidates(&form, &criteria, &actor_ref)
+            .await?;
+        let draw = form_update.conduct_draw(
+            seed.unwrap_or_else(AnswerDrawSeed::random),
+            winner_count,
+            criteria,
+            candidates.keys().copied().collect(),
+            actor,
+        )?;
+        self.answer_draw_repository.save(&draw).await?;
+
+        if notify_winners {
+            let mut notified = HashSet::new();
+            for winner_id in draw.winner_ids() {
+                let Some(user_id) = candidates.get(winner_id).copied().flatten() else {
+                    continue;
+                };
+                if !notified.insert(user_id) {
+                    continue;
+                }
+
+                let notification_preference = notification_preference_for(
+                    notification_repository,
+                    self.user_repository,
+                    &actor_ref,
+                    user_id,
+                )
+                .await?;
+
+                notificator
+                    .notify(
+                        user_id,
+                        NotificationType::DrawWon,
+                        &notification_preference,
+                        &draw_won_notification_content(
+                            &FRONTEND.url,
+                            form.title(),
+                            form_id,
+                            *winner_id,
+                        ),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(draw.into_inner())
+    }
+
+    /// 抽選の候補になる回答と、その回答者のユーザー ID (ログインして回答した場合のみ) を返します。
+    async fn collect_candidates(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        criteria: &AnswerDrawCriteria,
+        actor: &Actor,
+    ) -> Result<HashMap<AnswerId, Option<UserId>>, Error> {
+        // 確認待ちや却下された回答は回答者以外に公開されていないため、抽選の対象にしない
+        let filter = AnswerListFilter::default()
+            .with_status(*criteria.status())
+            .with_moderation_status(Some(AnswerModerationStatus::APPROVED));
+        let mut request = PageRequest::first(PageLimit::default_limit());
+        let mut answers = Vec::new();
+
+        loop {
+            let (page, next) = self
+                .answer_entry_repository
+                .list_by_form(form, request, filter)
+                .await?
+                .into_parts();
+            answers.extend(page);
+
+            let Some(next) = next else {
+                break;
+            };
+            request = PageRequest::after(next, PageLimit::default_limit());
+        }
+
+        let mut restricted_submitters = HashMap::new();
+        let mut candidates = HashMap::new();
+        for answer in answers {
+            if let Some(label_id) = criteria.label_id()
+                && !self.has_label(&answer, *label_id, actor).await?
+            {
+                continue;
+            }
+
+            let submitter = answer.author().authenticated_user_id();
+            if *criteria.exclude_restricted_submitters()
+                && let Some(submitter) = submitter
+            {
+                let restricted = match restricted_submitters.get(&submitter) {
+                    Some(restricted) => *restricted,
+                    None => {
+                        let restricted = self
+                            .form_submission_restriction_repository
+                            .fetch_active_by_submitter_id(submitter.into_inner())
+                            .await?
+                            .is_some();
+                        restricted_submitters.insert(submitter, restricted);
+                        restricted
+                    }
+                };
+                if restricted {
+                    continue;
+                }
+            }
+
+            candidates.insert(*answer.id(), submitter);
+        }
+
+        Ok(candidates)
+    }
+
+    async fn has_label(
+        &self,
+        answer: &AnswerEntry,
+        label_id: AnswerLabelId,
+        actor: &Actor,
+    ) -> Result<bool, Error> {
+        for label in self
+            .answer_label_repository
+            .get_labels_for_answers_by_answer_id(*answer.id())
+            .await?
+        {
+            if *label.try_read(actor.clone())?.id() == label_id {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub async fn list_draws(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+    ) -> Result<Vec<AnswerDraw>, Error> {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+
+        Ok(self
+            .answer_draw_repository
+            .list_by_form(&form)
+            .await?
+            .into_iter()
+            .map(|draw| draw.into_inner())
+            .collect())
+    }
+
+    pub async fn get_draw(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        draw_id: AnswerDrawId,
+    ) -> Result<AnswerDraw, Error> {
+        let actor = Actor::from(actor.clone());
+        let form = self.read_form(form_id, &actor).await?;
+
+        self.answer_draw_repository
+            .get(&form, draw_id)
+            .await?
+            .map(|draw| draw.into_inner())
+            .ok_or(DrawNotFound.into())
+    }
+}
+
+fn draw_won_notification_content(
+    frontend_url: &str,
+    form_title: &FormTitle,
+    form_id: FormId,
+    answer_id: AnswerId,
+) -> NotificationContent {
+    NotificationContent::new(vec![
+        format!("フォーム『{}』の抽選に当選しました。", form_title.as_str()),
+        "以下のリンクから当選した回答を確認できます。".to_string(),
+        format!("{frontend_url}/forms/{form_id}/answers/{answer_id}"),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Utc;
+    use domain::{
+        account::models::Role,
+        form::{
+            FormSubmissionRestriction, FormSubmissionRestrictionReason,
+            answer::{AnswerAuthor, AnswerStatus, AnswerTitle, PostedAnswerContents},
+            models::{FormDescription, QuestionSet},
+            question::Question,
+        },
+        notification::models::NotificationPreference,
+        repository::form::{
+            answer_draw_repository::MockAnswerDrawRepository,
+            answer_label_repository::MockAnswerLabelRepository,
+        },
+    };
+    use errors::domain::DomainError;
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::test_utils::repositories::{
+        FormUseCaseTestRepositories, InMemoryAnswerEntryRepository,
+    };
+
+    #[derive(Default)]
+    struct RecordingNotificator(Mutex<Vec<(UserId, NotificationType)>>);
+
+    #[async_trait::async_trait]
+    impl Notificator for RecordingNotificator {
+        async fn notify(
+            &self,
+            recipient: UserId,
+            notification_type: NotificationType,
+            _notification_preference: &NotificationPreference,
+            _content: &NotificationContent,
+        ) -> Result<(), Error> {
+            self.0.lock().unwrap().push((recipient, notification_type));
+            Ok(())
+        }
+    }
+
+    fn active_user(name: &str, role: Role) -> AccountUser {
+        AccountUser::new(name.to_string(), Uuid::new_v4().into(), role)
+    }
+
+    fn sample_form() -> ActiveForm {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            false,
+        )
+        .unwrap();
+
+        ActiveForm::new(
+            FormTitle::new("Form".to_string().try_into().unwrap()),
+            FormDescription::new("description".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+    }
+
+    fn answer_by(form: &ActiveForm, author: &AccountUser, status: AnswerStatus) -> AnswerEntry {
+        AnswerEntry::new(
+            *form.id(),
+            AnswerAuthor::AuthenticatedUser(*author.id()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(form.questions().as_slice(), Vec::new()).unwrap(),
+        )
+        .change_status(status)
+    }
+
+    fn saving_draw_repository() -> MockAnswerDrawRepository {
+        let mut repository = MockAnswerDrawRepository::new();
+        repository.expect_save().returning(|_| Ok(()));
+        repository
+    }
+
+    #[tokio::test]
+    async fn draw_picks_winners_among_matching_answers_of_unrestricted_submitters() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let form = sample_form();
+        let form_id = *form.id();
+        let administrator = active_user("admin", Role::Administrator);
+        let winner = active_user("winner", Role::StandardUser);
+        let restricted = active_user("restricted", Role::StandardUser);
+        let unmatched = active_user("unmatched", Role::StandardUser);
+        let pending = active_user("pending", Role::StandardUser);
+        let winning_answer = answer_by(&form, &winner, AnswerStatus::COMPLETED);
+        let winning_answer_id = *winning_answer.id();
+        let answers = vec![
+            winning_answer,
+            answer_by(&form, &restricted, AnswerStatus::COMPLETED),
+            answer_by(&form, &unmatched, AnswerStatus::UNADDRESSED),
+            unsafe {
+                answer_by(&form, &pending, AnswerStatus::COMPLETED)
+                    .with_raw_moderation_status(AnswerModerationStatus::PENDING)
+            },
+        ];
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(answers);
+        repositories.user_repository.save_user(winner.clone());
+        repositories
+            .form_submission_restriction_repository
+            .save_form_submission_restriction(
+                FormSubmissionRestriction::new(
+                    *restricted.id(),
+                    FormSubmissionRestrictionReason::new("spam".to_string().try_into().unwrap()),
+                    *administrator.id(),
+                    Utc::now(),
+                    None,
+                )
+                .unwrap(),
+            );
+        let labels = MockAnswerLabelRepository::new();
+        let draws = saving_draw_repository();
+        let notificator = RecordingNotificator::default();
+        let usecase = AnswerDrawUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            answer_label_repository: &labels,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            user_repository: &repositories.user_repository,
+            answer_draw_repository: &draws,
+        };
+
+        let draw = usecase
+            .draw(
+                &administrator,
+                form_id,
+                AnswerDrawCriteria::new(Some(AnswerStatus::COMPLETED), None, true),
+                AnswerDrawWinnerCount::try_new(3).unwrap(),
+                Some(AnswerDrawSeed::new(1)),
+                true,
+                &notificator,
+                &repositories.notification_repository,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(draw.candidate_ids(), &vec![winning_answer_id]);
+        assert_eq!(draw.winner_ids(), &vec![winning_answer_id]);
+        assert!(draw.verify());
+        assert_eq!(
+            notificator.0.lock().unwrap().as_slice(),
+            [(*winner.id(), NotificationType::DrawWon)]
+        );
+    }
+
+    #[tokio::test]
+    async fn standard_user_cannot_conduct_a_draw() {
+        let form = sample_form();
+        let form_id = *form.id();
+        let user = active_user("user", Role::StandardUser);
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form.clone()]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer_by(
+            &form,
+            &user,
+            AnswerStatus::UNADDRESSED,
+        )]);
+        let labels = MockAnswerLabelRepository::new();
+        let draws = MockAnswerDrawRepository::new();
+        let usecase = AnswerDrawUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            answer_label_repository: &labels,
+            form_submission_restriction_repository: &repositories
+                .form_submission_restriction_repository,
+            user_repository: &repositories.user_repository,
+            answer_draw_repository: &draws,
+        };
+
+        let result = usecase
+            .draw(
+                &user,
+                form_id,
+                AnswerDrawCriteria::default(),
+                AnswerDrawWinnerCount::try_new(1).unwrap(),
+                None,
+                false,
+                &RecordingNotificator::default(),
+                &repositories.notification_repository,
+            )
+            .await;
+
+        assert_eq!(result, Err(Error::from(DomainError::Forbidden)));
+    }
+}
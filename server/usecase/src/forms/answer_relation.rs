@@ -2,7 +2,8 @@ use domain::{
     account::models::AccountUser,
     auth::Actor,
     form::answer::{
-        AnswerEntry, AnswerId, AnswerReference, AnswerRelation, ReadableAnswerRelation,
+        AnswerEntry, AnswerId, AnswerReference, AnswerRelation, AnswerRelationKind,
+        ReadableAnswerRelation, RelatedAnswer,
     },
     form::models::FormId,
     repository::form::{
@@ -111,34 +112,41 @@ impl<
     }
 
     /// 指定回答から直接つながる、actor が閲覧可能な関連先だけを返します。
+    ///
+    /// `kind` を指定した場合は、その種類の関連だけを返します。
     pub async fn list_related_answers(
         &self,
         actor: &AccountUser,
         form_id: FormId,
         answer_id: AnswerId,
-    ) -> Result<Vec<AnswerReference>, Error> {
+        kind: Option<AnswerRelationKind>,
+    ) -> Result<Vec<RelatedAnswer>, Error> {
         let actor = Actor::from(actor.clone());
         let source = AnswerReference::new(form_id, answer_id);
         let relations = self.load_source_for_read(&actor, source).await?;
         let mut visible = relations
             .iter()
-            .filter_map(|relation| relation.opposite_endpoint_for(source).ok())
+            .filter_map(|relation| relation.related_answer_for(source).ok())
+            .filter(|related| kind.is_none_or(|kind| related.kind == kind))
             .collect::<Vec<_>>();
         visible.sort_unstable();
         Ok(visible)
     }
 
-    /// 関連を追加します。source と target はともに active 回答でなければなりません。
+    /// `source` から `target` への `kind` の関連を追加します。source と target はともに
+    /// active 回答でなければなりません。二つの回答の間に既に関連がある場合は、種類と向きを
+    /// 置き換えます。
     pub async fn add_related_answer(
         &self,
         actor: &AccountUser,
         source: AnswerReference,
         target: AnswerReference,
+        kind: AnswerRelationKind,
     ) -> Result<(), Error> {
         let actor = Actor::from(actor.clone());
         let source_answer = self.update_active_answer(&actor, source).await?;
         let target_answer = self.update_active_answer(&actor, target).await?;
-        let relation = AnswerRelation::new(source, target)?;
+        let relation = AnswerRelation::with_kind(source, target, kind)?;
 
         self.answer_relation_repository
             .add(relation, &source_answer, &target_answer)
@@ -211,8 +219,8 @@ mod tests {
         auth::Actor,
         form::{
             answer::{
-                AnswerAuthor, AnswerPublication, AnswerSettings, AnswerTitle, AnswerVisibility,
-                ArchivedAnswerEntry, PostedAnswerContents,
+                AnswerAuthor, AnswerPublication, AnswerRelationDirection, AnswerSettings,
+                AnswerTitle, AnswerVisibility, ArchivedAnswerEntry, PostedAnswerContents,
             },
             models::{
                 ActiveForm, ArchivedForm, FormDescription, FormSettings, FormTitle, Visibility,
@@ -268,6 +276,14 @@ mod tests {
         AnswerReference::new(*answer.form_id(), *answer.id())
     }
 
+    fn plain_related(reference: AnswerReference) -> RelatedAnswer {
+        RelatedAnswer {
+            reference,
+            kind: AnswerRelationKind::Related,
+            direction: None,
+        }
+    }
+
     fn readable_answer(
         form: &ActiveForm,
         answer: &AnswerEntry,
@@ -357,11 +373,11 @@ mod tests {
 
         let related = repositories
             .answer_relation_use_case()
-            .list_related_answers(&standard_user, *source.form_id(), *source.id())
+            .list_related_answers(&standard_user, *source.form_id(), *source.id(), None)
             .await
             .unwrap();
 
-        assert_eq!(related, vec![reference(&visible_target)]);
+        assert_eq!(related, vec![plain_related(reference(&visible_target))]);
     }
 
     #[tokio::test]
@@ -382,11 +398,21 @@ mod tests {
         let usecase = repositories.answer_relation_use_case();
 
         usecase
-            .add_related_answer(&administrator, source_reference, target_reference)
+            .add_related_answer(
+                &administrator,
+                source_reference,
+                target_reference,
+                AnswerRelationKind::Related,
+            )
             .await
             .unwrap();
         usecase
-            .add_related_answer(&administrator, source_reference, target_reference)
+            .add_related_answer(
+                &administrator,
+                source_reference,
+                target_reference,
+                AnswerRelationKind::Related,
+            )
             .await
             .unwrap();
         assert_eq!(
@@ -395,15 +421,21 @@ mod tests {
                     &administrator,
                     source_reference.form_id(),
                     source_reference.answer_id(),
+                    None,
                 )
                 .await
                 .unwrap(),
-            vec![target_reference]
+            vec![plain_related(target_reference)]
         );
 
         assert_eq!(
             usecase
-                .add_related_answer(&standard_user, source_reference, target_reference)
+                .add_related_answer(
+                    &standard_user,
+                    source_reference,
+                    target_reference,
+                    AnswerRelationKind::Related
+                )
                 .await,
             Err(Error::from(DomainError::Forbidden))
         );
@@ -449,6 +481,7 @@ mod tests {
                     &administrator,
                     source_reference.form_id(),
                     source_reference.answer_id(),
+                    None,
                 )
                 .await
                 .unwrap()
@@ -500,7 +533,12 @@ mod tests {
         assert_eq!(
             repositories
                 .answer_relation_use_case()
-                .add_related_answer(&administrator, source_reference, third_reference)
+                .add_related_answer(
+                    &administrator,
+                    source_reference,
+                    third_reference,
+                    AnswerRelationKind::Related
+                )
                 .await,
             Err(Error::from(FormNotFound))
         );
@@ -522,10 +560,11 @@ mod tests {
                     &administrator,
                     source_reference.form_id(),
                     source_reference.answer_id(),
+                    None,
                 )
                 .await
                 .unwrap(),
-            vec![target_reference]
+            vec![plain_related(target_reference)]
         );
 
         let archived = repositories
@@ -556,7 +595,12 @@ mod tests {
 
         repositories
             .answer_relation_use_case()
-            .add_related_answer(&administrator, source_reference, third_reference)
+            .add_related_answer(
+                &administrator,
+                source_reference,
+                third_reference,
+                AnswerRelationKind::Related,
+            )
             .await
             .unwrap();
         let related = repositories
@@ -565,10 +609,94 @@ mod tests {
                 &administrator,
                 source_reference.form_id(),
                 source_reference.answer_id(),
+                None,
             )
             .await
             .unwrap();
-        let expected = HashSet::from([target_reference, third_reference]);
+        let expected = HashSet::from([
+            plain_related(target_reference),
+            plain_related(third_reference),
+        ]);
         assert_eq!(related.into_iter().collect::<HashSet<_>>(), expected);
     }
+
+    #[tokio::test]
+    async fn typed_relations_are_listed_with_their_direction_and_can_be_filtered() {
+        let administrator = user(30, Role::Administrator);
+        let form = public_form();
+        let original = answer(&form, &administrator);
+        let duplicate = answer(&form, &administrator);
+        let follow_up = answer(&form, &administrator);
+        let original_reference = reference(&original);
+        let duplicate_reference = reference(&duplicate);
+        let follow_up_reference = reference(&follow_up);
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            crate::test_utils::repositories::InMemoryAnswerEntryRepository::new(vec![
+                original, duplicate, follow_up,
+            ]);
+        let usecase = repositories.answer_relation_use_case();
+
+        usecase
+            .add_related_answer(
+                &administrator,
+                duplicate_reference,
+                original_reference,
+                AnswerRelationKind::Related,
+            )
+            .await
+            .unwrap();
+        // Re-adding the same pair replaces the kind instead of adding a second relation.
+        usecase
+            .add_related_answer(
+                &administrator,
+                duplicate_reference,
+                original_reference,
+                AnswerRelationKind::DuplicateOf,
+            )
+            .await
+            .unwrap();
+        usecase
+            .add_related_answer(
+                &administrator,
+                follow_up_reference,
+                original_reference,
+                AnswerRelationKind::FollowUpOf,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            usecase
+                .list_related_answers(
+                    &administrator,
+                    duplicate_reference.form_id(),
+                    duplicate_reference.answer_id(),
+                    None,
+                )
+                .await
+                .unwrap(),
+            vec![RelatedAnswer {
+                reference: original_reference,
+                kind: AnswerRelationKind::DuplicateOf,
+                direction: Some(AnswerRelationDirection::Outgoing),
+            }]
+        );
+        assert_eq!(
+            usecase
+                .list_related_answers(
+                    &administrator,
+                    original_reference.form_id(),
+                    original_reference.answer_id(),
+                    Some(AnswerRelationKind::DuplicateOf),
+                )
+                .await
+                .unwrap(),
+            vec![RelatedAnswer {
+                reference: duplicate_reference,
+                kind: AnswerRelationKind::DuplicateOf,
+                direction: Some(AnswerRelationDirection::Incoming),
+            }]
+        );
+    }
 }
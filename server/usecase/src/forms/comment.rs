@@ -6,18 +6,24 @@ use domain::{
         answer::{AnswerEntry, AnswerId},
         comment::{
             Comment, CommentContent, CommentHistoryEntry, CommentHistoryPagePosition, CommentId,
+            CommentVisibility,
         },
         comment_thread::CommentThread,
+        content_filter::ContentFilterTarget,
         models::{ActiveForm, FormId},
     },
+    notification::notificator::Notificator,
     pagination::{Page, PageRequest},
     repository::{
         form::{
             active_form_repository::ActiveFormRepository,
             answer_entry_repository::AnswerEntryRepository,
             comment_thread_repository::CommentThreadRepository,
+            content_filter_repository::ContentFilterRepository,
+            mention_repository::MentionRepository,
         },
         form_submission_restriction_repository::FormSubmissionRestrictionRepository,
+        notification_repository::NotificationRepository,
         user_repository::UserRepository,
     },
     types::authorization_guard::{Allowed, AuthorizationGuard, Read, Update},
@@ -30,6 +36,10 @@ use errors::{
 
 use crate::{
     application_event::{ApplicationActor, ApplicationEvent, ApplicationEventPublisher},
+    forms::{
+        content_filter::{enforce_verdict, screen_content},
+        mention::{resolve_mentioned_users, save_and_notify_mentions},
+    },
     models::{CommentAuthor, CommentWithAuthor},
     user_reference_resolver::resolve_user_references,
 };
@@ -152,39 +162,75 @@ impl<
                 answer_id,
             )
             .await?;
-        self.build_comments_with_authors(actor, thread.comments().to_vec())
+        self.build_comments_with_authors(actor, thread.readable_comments())
             .await
     }
 
-    pub async fn post_comment(
+    /// コメントを投稿し、本文でメンションされたユーザーのうちコメントを閲覧できるユーザーに通知する。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_comment<N: Notificator>(
         &self,
         actor: &AccountUser,
         form_id: FormId,
         answer_id: AnswerId,
         content: CommentContent,
+        visibility: CommentVisibility,
         restriction_repository: &impl FormSubmissionRestrictionRepository,
+        mention_repository: &impl MentionRepository,
+        notification_repository: &impl NotificationRepository,
+        notificator: &N,
+        content_filter_repository: &impl ContentFilterRepository,
     ) -> Result<(), Error> {
         super::submission::authorize_form_submission(actor.clone(), restriction_repository).await?;
+        let actor_user = Actor::from(actor.clone());
         let (form, answer) = self
-            .readable_form_and_answer(&Actor::from(actor.clone()), form_id, answer_id)
+            .readable_form_and_answer(&actor_user, form_id, answer_id)
             .await?;
-        let answer_title = answer
-            .title()
-            .clone()
-            .into_inner()
-            .map(|title| title.into_inner());
+        let title = answer.title().clone();
+        let answer_title = title.clone().into_inner().map(|title| title.into_inner());
         let thread = self
             .comment_thread_repository
-            .get_for_answer(&form, answer)
+            .get_for_answer(&form, answer.clone())
             .await?;
         let comment = AuthorizationGuard::<_, Update>::from(thread.into_inner())
-            .try_update(Actor::from(actor.clone()))?
-            .create_comment(content)?;
+            .try_update(actor_user.clone())?
+            .create_comment(content, visibility)?;
+        let filter_target = ContentFilterTarget::Comment(*comment.comment_id());
         let comment_id = comment.comment_id().to_string();
         let content = comment.content().to_owned().into_inner().into_inner();
+        let verdict =
+            screen_content(content_filter_repository, form_id, [content.as_str()]).await?;
+        verdict.ensure_accepted()?;
+        let mentioned_users =
+            resolve_mentioned_users(self.user_repository, &actor_user, &content).await?;
+        let mentions = form.comment_mentions(&answer, comment.value(), mentioned_users)?;
         self.comment_thread_repository
             .create(&form, comment)
             .await?;
+        enforce_verdict(
+            content_filter_repository,
+            &verdict,
+            form_id,
+            answer_id,
+            filter_target,
+        )
+        .await?;
+        // 確認を求められたコメントは、管理者が確認するまで本文を通知やイベントで広めない
+        if verdict.is_flagged() {
+            return Ok(());
+        }
+        save_and_notify_mentions(
+            mention_repository,
+            notification_repository,
+            self.user_repository,
+            notificator,
+            &actor_user,
+            form_id,
+            &title,
+            mentions,
+            &[],
+        )
+        .await?;
         if let Some(publisher) = self.application_event_publisher {
             publisher.publish(ApplicationEvent::CommentCreated {
                 actor: ApplicationActor::from(actor),
@@ -213,6 +259,7 @@ impl<
             .await
     }
 
+    /// コメントを編集する。編集後の本文も、投稿時と同じくコンテンツフィルターで確かめる。
     pub async fn update_comment(
         &self,
         actor: &AccountUser,
@@ -220,6 +267,7 @@ impl<
         answer_id: AnswerId,
         comment_id: CommentId,
         content: Option<CommentContent>,
+        content_filter_repository: &impl ContentFilterRepository,
     ) -> Result<(), Error> {
         if let Some(content) = content {
             let (form, answer) = self
@@ -246,17 +294,30 @@ impl<
                 return Ok(());
             }
             let content = updated.content().to_owned().into_inner().into_inner();
-            let comment_id = updated.comment_id().to_string();
+            let verdict =
+                screen_content(content_filter_repository, form_id, [content.as_str()]).await?;
+            verdict.ensure_accepted()?;
             self.comment_thread_repository
                 .update(&form, updated, Utc::now())
                 .await?;
+            enforce_verdict(
+                content_filter_repository,
+                &verdict,
+                form_id,
+                answer_id,
+                ContentFilterTarget::Comment(comment_id),
+            )
+            .await?;
+            if verdict.is_flagged() {
+                return Ok(());
+            }
             if let Some(publisher) = self.application_event_publisher {
                 publisher.publish(ApplicationEvent::CommentUpdated {
                     actor: ApplicationActor::from(actor),
                     form_id: form_id.to_string(),
                     answer_title,
                     answer_id: answer_id.to_string(),
-                    comment_id,
+                    comment_id: comment_id.to_string(),
                     content,
                 });
             }
@@ -320,21 +381,54 @@ mod tests {
                 AnswerAuthor, AnswerPublication, AnswerSettings, AnswerTitle, AnswerVisibility,
             },
             comment::DeletedComment,
+            content_filter::{ContentFilterAction, ContentFilterCondition, ContentFilterRule},
             models::{FormDescription, FormTitle, QuestionSet},
             question::Question,
         },
-        repository::form::comment_thread_repository::CommentThreadRepository,
+        notification::models::{NotificationContent, NotificationPreference, NotificationType},
+        repository::form::{
+            comment_thread_repository::CommentThreadRepository,
+            mention_repository::MockMentionRepository,
+        },
         types::authorization_guard::{Create, Update},
     };
+    use std::sync::Mutex;
     use types::non_empty_vec::NonEmptyVec;
     use uuid::Uuid;
 
     use crate::test_utils::repositories::{
-        FormUseCaseTestRepositories, InMemoryAnswerEntryRepository,
+        FormUseCaseTestRepositories, InMemoryAnswerEntryRepository, InMemoryContentFilterRepository,
     };
 
     struct ThreadRepository;
 
+    #[derive(Default)]
+    struct StoredThreadRepository(Mutex<Vec<Comment>>);
+
+    #[derive(Default)]
+    struct RecordingPublisher(Mutex<Vec<ApplicationEvent>>);
+
+    impl ApplicationEventPublisher for RecordingPublisher {
+        fn publish(&self, event: ApplicationEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    struct NoopNotificator;
+
+    #[async_trait]
+    impl Notificator for NoopNotificator {
+        async fn notify(
+            &self,
+            _recipient: UserId,
+            _notification_type: NotificationType,
+            _notification_preference: &NotificationPreference,
+            _content: &NotificationContent,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
     #[async_trait]
     impl CommentThreadRepository for ThreadRepository {
         async fn get_for_answer(
@@ -393,6 +487,71 @@ mod tests {
         }
     }
 
+    #[async_trait]
+    impl CommentThreadRepository for StoredThreadRepository {
+        async fn get_for_answer(
+            &self,
+            form: &Allowed<ActiveForm, Read>,
+            answer: AnswerEntry,
+        ) -> Result<Allowed<CommentThread, Read>, Error> {
+            form.comment_thread(answer).map_err(Into::into)
+        }
+
+        async fn get_with_comments_for_answer(
+            &self,
+            form: &Allowed<ActiveForm, Read>,
+            answer: AnswerEntry,
+        ) -> Result<Allowed<CommentThread, Read>, Error> {
+            form.comment_thread_with_comments(answer, self.0.lock().unwrap().clone())
+                .map_err(Into::into)
+        }
+
+        async fn create(
+            &self,
+            _form: &Allowed<ActiveForm, Read>,
+            comment: Allowed<Comment, Create>,
+        ) -> Result<(), Error> {
+            self.0.lock().unwrap().push(comment.into_inner());
+            Ok(())
+        }
+
+        async fn update(
+            &self,
+            _form: &Allowed<ActiveForm, Read>,
+            comment: Allowed<Comment, Update>,
+            _updated_at: chrono::DateTime<Utc>,
+        ) -> Result<(), Error> {
+            let comment = comment.into_inner();
+            for stored in self.0.lock().unwrap().iter_mut() {
+                if stored.comment_id() == comment.comment_id() {
+                    *stored = comment.clone();
+                }
+            }
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            _form: &Allowed<ActiveForm, Read>,
+            _comment: Allowed<DeletedComment, Create>,
+        ) -> Result<(), Error> {
+            unreachable!("comments are not deleted in these tests")
+        }
+
+        async fn history(
+            &self,
+            _comment_thread: &Allowed<CommentThread, Read>,
+            _request: PageRequest<CommentHistoryPagePosition>,
+        ) -> Result<Page<Allowed<CommentHistoryEntry, Read>, CommentHistoryPagePosition>, Error>
+        {
+            unreachable!("comment history is not read in these tests")
+        }
+
+        async fn size(&self) -> Result<u32, Error> {
+            Ok(self.0.lock().unwrap().len() as u32)
+        }
+    }
+
     fn private_form_and_answer(author: &AccountUser) -> (ActiveForm, AnswerEntry) {
         let question = Question::new_text(
             "body".to_string().try_into().unwrap(),
@@ -472,7 +631,12 @@ mod tests {
                     form_id,
                     answer_id,
                     CommentContent::new("comment".to_string().try_into().unwrap()),
+                    CommentVisibility::Public,
                     &repositories.form_submission_restriction_repository,
+                    &MockMentionRepository::new(),
+                    &repositories.notification_repository,
+                    &NoopNotificator,
+                    &repositories.content_filter_repository,
                 )
                 .await,
             forbidden
@@ -487,6 +651,7 @@ mod tests {
                     Some(CommentContent::new(
                         "updated".to_string().try_into().unwrap()
                     )),
+                    &repositories.content_filter_repository,
                 )
                 .await,
             forbidden
@@ -498,4 +663,161 @@ mod tests {
             forbidden
         );
     }
+
+    fn content_filter_rules(
+        form_id: FormId,
+        created_by: &AccountUser,
+    ) -> InMemoryContentFilterRepository {
+        let rule = |word: &str, action| {
+            ContentFilterRule::try_new(
+                form_id,
+                ContentFilterCondition::BlockedWord(word.to_string().try_into().unwrap()),
+                action,
+                *created_by.id(),
+                Utc::now(),
+            )
+            .unwrap()
+        };
+        InMemoryContentFilterRepository::with_rules(vec![
+            rule("spam", ContentFilterAction::Reject),
+            rule("discount", ContentFilterAction::FlagForReview),
+        ])
+    }
+
+    #[tokio::test]
+    async fn flagged_comments_are_neither_published_nor_mentioned() {
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let administrator = AccountUser::new(
+            "admin".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let colleague = AccountUser::new(
+            "colleague".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let (form, answer) = private_form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.content_filter_repository = content_filter_rules(form_id, &administrator);
+        for user in [&author, &administrator, &colleague] {
+            repositories.user_repository.save_user(user.clone());
+        }
+        let thread_repository = StoredThreadRepository::default();
+        let publisher = RecordingPublisher::default();
+        let use_case = CommentUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            comment_thread_repository: &thread_repository,
+            application_event_publisher: Some(&publisher),
+        };
+
+        // メンションを保存しようとするとモックが失敗する
+        use_case
+            .post_comment(
+                &administrator,
+                form_id,
+                answer_id,
+                CommentContent::new("@colleague a discount".to_string().try_into().unwrap()),
+                CommentVisibility::Public,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &repositories.notification_repository,
+                &NoopNotificator,
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(thread_repository.size().await, Ok(1));
+        assert!(publisher.0.lock().unwrap().is_empty());
+        assert_eq!(
+            repositories.content_filter_repository.enforcements().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn edited_comments_are_screened_by_the_content_filter() {
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let administrator = AccountUser::new(
+            "admin".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let (form, answer) = private_form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.content_filter_repository = content_filter_rules(form_id, &administrator);
+        let thread_repository = StoredThreadRepository::default();
+        let publisher = RecordingPublisher::default();
+        let use_case = CommentUseCase {
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            comment_thread_repository: &thread_repository,
+            application_event_publisher: Some(&publisher),
+        };
+        use_case
+            .post_comment(
+                &administrator,
+                form_id,
+                answer_id,
+                CommentContent::new("original".to_string().try_into().unwrap()),
+                CommentVisibility::Public,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &repositories.notification_repository,
+                &NoopNotificator,
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+        let comment_id = *thread_repository.0.lock().unwrap()[0].comment_id();
+        let update = |content: &str| {
+            use_case.update_comment(
+                &administrator,
+                form_id,
+                answer_id,
+                comment_id,
+                Some(CommentContent::new(content.to_string().try_into().unwrap())),
+                &repositories.content_filter_repository,
+            )
+        };
+
+        let rejected = update("cheap spam").await;
+        let content_after_rejection = thread_repository.0.lock().unwrap()[0].content().clone();
+        update("a discount for you").await.unwrap();
+
+        assert_eq!(rejected, Err(DomainError::ContentRejectedByFilter.into()));
+        assert_eq!(
+            content_after_rejection,
+            CommentContent::new("original".to_string().try_into().unwrap())
+        );
+        assert!(matches!(
+            repositories.content_filter_repository.enforcements().as_slice(),
+            [enforcement] if matches!(
+                enforcement.flags().as_slice(),
+                [flag] if flag.target() == &ContentFilterTarget::Comment(comment_id)
+            )
+        ));
+        assert!(matches!(
+            publisher.0.lock().unwrap().as_slice(),
+            [ApplicationEvent::CommentCreated { .. }]
+        ));
+    }
 }
@@ -0,0 +1,200 @@
+use chrono::Utc;
+use domain::{
+    account::models::AccountUser,
+    auth::Actor,
+    form::{
+        answer::AnswerId,
+        content_filter::{
+            ContentFilterAction, ContentFilterCondition, ContentFilterFlag, ContentFilterFlagId,
+            ContentFilterRule, ContentFilterRuleId, ContentFilterTarget, ContentFilterVerdict,
+        },
+        models::FormId,
+    },
+    repository::form::{
+        active_form_repository::ActiveFormRepository,
+        answer_label_repository::AnswerLabelRepository,
+        content_filter_repository::ContentFilterRepository,
+    },
+    types::authorization_guard::{Allowed, AuthorizationGuard, Create},
+};
+use errors::{
+    Error,
+    usecase::UseCaseError::{
+        ContentFilterFlagNotFound, ContentFilterRuleNotFound, FormNotFound, LabelNotFound,
+    },
+};
+
+/// 投稿の内容を、フォームに登録された規則で確かめる。
+///
+/// 規則は毎回読み込むため、管理者が規則を変更すると次の投稿から反映される。
+pub(crate) async fn screen_content<'a>(
+    content_filter_repository: &impl ContentFilterRepository,
+    form_id: FormId,
+    texts: impl IntoIterator<Item = &'a str>,
+) -> Result<ContentFilterVerdict, Error> {
+    let rules = content_filter_repository
+        .list_rules(form_id)
+        .await?
+        .into_iter()
+        .map(|rule| rule.try_read(Actor::System).map(Allowed::into_inner))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ContentFilterVerdict::evaluate(&rules, texts))
+}
+
+/// 受け付けた投稿に、当てはまった規則の処置を行う。
+pub(crate) async fn enforce_verdict(
+    content_filter_repository: &impl ContentFilterRepository,
+    verdict: &ContentFilterVerdict,
+    form_id: FormId,
+    answer_id: AnswerId,
+    target: ContentFilterTarget,
+) -> Result<(), Error> {
+    let Some(enforcement) = verdict.enforcement(form_id, answer_id, target, Utc::now()) else {
+        return Ok(());
+    };
+    let enforcement =
+        AuthorizationGuard::<_, Create>::from(enforcement).try_create(Actor::System)?;
+
+    content_filter_repository.enforce(enforcement).await
+}
+
+pub struct ContentFilterUseCase<
+    'a,
+    FormRepo: ActiveFormRepository,
+    AnswerLabelRepo: AnswerLabelRepository,
+    ContentFilterRepo: ContentFilterRepository,
+> {
+    pub active_form_repository: &'a FormRepo,
+    pub answer_label_repository: &'a AnswerLabelRepo,
+    pub content_filter_repository: &'a ContentFilterRepo,
+}
+
+impl<R1: ActiveFormRepository, R2: AnswerLabelRepository, R3: ContentFilterRepository>
+    ContentFilterUseCase<'_, R1, R2, R3>
+{
+    /// 規則と確認待ちの記録は、フォームを編集できるユーザーだけが扱える。
+    async fn ensure_form_manageable(&self, actor: &Actor, form_id: FormId) -> Result<(), Error> {
+        self.active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .into_update()
+            .try_update(actor.clone())?;
+
+        Ok(())
+    }
+
+    pub async fn list_rules(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+    ) -> Result<Vec<ContentFilterRule>, Error> {
+        let actor = Actor::from(actor.clone());
+        self.ensure_form_manageable(&actor, form_id).await?;
+
+        self.content_filter_repository
+            .list_rules(form_id)
+            .await?
+            .into_iter()
+            .map(|rule| rule.try_read(actor.clone()).map(Allowed::into_inner))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    pub async fn add_rule(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        condition: ContentFilterCondition,
+        action: ContentFilterAction,
+    ) -> Result<ContentFilterRule, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        self.ensure_form_manageable(&actor_ref, form_id).await?;
+
+        if let ContentFilterAction::AddLabel(label_id) = action {
+            self.answer_label_repository
+                .get_label_for_answers(label_id)
+                .await?
+                .ok_or(LabelNotFound)?;
+        }
+
+        let rule = AuthorizationGuard::<_, Create>::from(ContentFilterRule::try_new(
+            form_id,
+            condition,
+            action,
+            *actor.id(),
+            Utc::now(),
+        )?)
+        .try_create(actor_ref)?;
+
+        let created = rule.value().clone();
+        self.content_filter_repository.create_rule(rule).await?;
+
+        Ok(created)
+    }
+
+    pub async fn remove_rule(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        rule_id: ContentFilterRuleId,
+    ) -> Result<(), Error> {
+        let actor = Actor::from(actor.clone());
+        self.ensure_form_manageable(&actor, form_id).await?;
+
+        let rule = self
+            .content_filter_repository
+            .get_rule(rule_id)
+            .await?
+            .ok_or(ContentFilterRuleNotFound)?
+            .into_delete()
+            .try_delete(actor)?;
+        if rule.form_id() != &form_id {
+            return Err(ContentFilterRuleNotFound.into());
+        }
+
+        self.content_filter_repository.delete_rule(rule).await
+    }
+
+    pub async fn list_flags(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+    ) -> Result<Vec<ContentFilterFlag>, Error> {
+        let actor = Actor::from(actor.clone());
+        self.ensure_form_manageable(&actor, form_id).await?;
+
+        self.content_filter_repository
+            .list_flags(form_id)
+            .await?
+            .into_iter()
+            .map(|flag| flag.try_read(actor.clone()).map(Allowed::into_inner))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    /// 確認を終えた記録を削除する。
+    pub async fn dismiss_flag(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        flag_id: ContentFilterFlagId,
+    ) -> Result<(), Error> {
+        let actor = Actor::from(actor.clone());
+        self.ensure_form_manageable(&actor, form_id).await?;
+
+        let flag = self
+            .content_filter_repository
+            .get_flag(flag_id)
+            .await?
+            .ok_or(ContentFilterFlagNotFound)?
+            .into_delete()
+            .try_delete(actor)?;
+        if flag.form_id() != &form_id {
+            return Err(ContentFilterFlagNotFound.into());
+        }
+
+        self.content_filter_repository.delete_flag(flag).await
+    }
+}
@@ -1,14 +1,17 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use domain::{
     account::models::{AccountUser, UserGroupId},
     auth::Actor,
+    form::answer::AnswerListFilter,
     form::models::{
         ActiveForm, AllowedUserGroups, AnswerAcceptancePeriod, AnswerAuthorPublicationPolicy,
-        AnswerSettings, AnswerVisibility, ArchivedForm, ArchivedFormPagePosition,
-        DefaultAnswerTitle, DiscordWebhookUrl, FormDescription, FormId, FormLabel,
-        FormLabelAssignment, FormLabelId, FormPagePosition, FormSettings, FormTitle, Question,
-        QuestionSet, Visibility,
+        AnswerQuizSettings, AnswerResponseDeadline, AnswerSettings, AnswerVisibility, ArchivedForm,
+        ArchivedFormPagePosition, DefaultAnswerTitle, DiscordWebhookUrl, FormDescription, FormId,
+        FormLabel, FormLabelAssignment, FormLabelId, FormPagePosition, FormSettings, FormTitle,
+        MessageThreadLockPolicy, Question, QuestionSet, TemporaryAnswerModerationPolicy,
+        Visibility,
     },
+    form::public_feed::PublicFormFeed,
     pagination::{Page, PageLimit, PageRequest},
     repository::{
         form::{
@@ -96,6 +99,11 @@ impl<
         acceptance_period: Option<AnswerAcceptancePeriod>,
         default_answer_title: Option<DefaultAnswerTitle>,
         author_publication_policy: Option<AnswerAuthorPublicationPolicy>,
+        response_deadline: Option<AnswerResponseDeadline>,
+        quiz_enabled: Option<bool>,
+        quiz_score_visible_to_author: Option<bool>,
+        message_thread_lock_policy: Option<MessageThreadLockPolicy>,
+        temporary_answer_moderation_policy: Option<TemporaryAnswerModerationPolicy>,
         user: &AccountUser,
     ) -> Result<ActiveForm, Error> {
         let user_as_user = Actor::from(user.clone());
@@ -148,6 +156,22 @@ impl<
             Some(policy) => answer_settings.change_author_publication_policy(policy),
             None => answer_settings,
         };
+        let answer_settings = match response_deadline {
+            Some(deadline) => answer_settings.change_response_deadline(deadline),
+            None => answer_settings,
+        };
+        let answer_settings = answer_settings.change_quiz(AnswerQuizSettings::new(
+            quiz_enabled.unwrap_or_default(),
+            quiz_score_visible_to_author.unwrap_or_default(),
+        ));
+        let answer_settings = match message_thread_lock_policy {
+            Some(policy) => answer_settings.change_message_thread_lock_policy(policy),
+            None => answer_settings,
+        };
+        let answer_settings = match temporary_answer_moderation_policy {
+            Some(policy) => answer_settings.change_temporary_answer_moderation_policy(policy),
+            None => answer_settings,
+        };
 
         let form = ActiveForm::new(
             title,
@@ -196,7 +220,10 @@ impl<
         let (forms, next) = page.into_parts();
         let forms = forms
             .into_iter()
-            .flat_map(|form| form.try_read(actor.clone()).map(|form| form.into_inner()))
+            .flat_map(|form| {
+                form.try_read(actor.clone())
+                    .map(|form| form.into_visible_form())
+            })
             .collect::<Vec<_>>();
 
         let form_labels = futures::future::try_join_all(forms.iter().map(|form| {
@@ -236,7 +263,7 @@ impl<
             .await?
             .ok_or(Error::from(FormNotFound))?
             .try_read(actor.clone())?
-            .into_inner();
+            .into_visible_form();
         let labels = self
             .form_label_repository
             .fetch_labels_by_form_id(form_id)
@@ -252,6 +279,22 @@ impl<
         Ok(ActiveFormWithLabels { form, labels })
     }
 
+    /// 未ログインで閲覧でき、`now` の時点で回答を受け付けているフォームのフィードを取得する
+    pub async fn public_form_feed(&self, now: DateTime<Utc>) -> Result<PublicFormFeed, Error> {
+        let forms = self
+            .active_form_repository
+            .list_all()
+            .await?
+            .into_iter()
+            .flat_map(|form| {
+                form.try_read(Actor::Anonymous)
+                    .map(|form| form.into_visible_form())
+            })
+            .collect();
+
+        Ok(PublicFormFeed::new(forms, now))
+    }
+
     pub async fn archived_form_list(
         &self,
         actor: &AccountUser,
@@ -421,6 +464,11 @@ impl<
         answer_visibility: Option<AnswerVisibility>,
         answer_groups: Option<AllowedUserGroups>,
         author_publication_policy: Option<AnswerAuthorPublicationPolicy>,
+        response_deadline: Option<AnswerResponseDeadline>,
+        quiz_enabled: Option<bool>,
+        quiz_score_visible_to_author: Option<bool>,
+        message_thread_lock_policy: Option<MessageThreadLockPolicy>,
+        temporary_answer_moderation_policy: Option<TemporaryAnswerModerationPolicy>,
         questions: Option<Vec<UpsertQuestionInput>>,
         label_ids: Option<Vec<FormLabelId>>,
     ) -> Result<(ActiveForm, Vec<FormLabel>), Error> {
@@ -464,7 +512,7 @@ impl<
                 .list_by_form(
                     &current_form_read,
                     PageRequest::first(PageLimit::default_limit()),
-                    None,
+                    AnswerListFilter::default(),
                 )
                 .await?
                 .items()
@@ -538,6 +586,30 @@ impl<
                 None => updated_answer_settings,
                 Some(policy) => updated_answer_settings.change_author_publication_policy(policy),
             };
+            let updated_answer_settings = match response_deadline {
+                None => updated_answer_settings,
+                Some(deadline) => updated_answer_settings.change_response_deadline(deadline),
+            };
+            let quiz = *updated_answer_settings.quiz();
+            let quiz = match quiz_enabled {
+                None => quiz,
+                Some(enabled) => quiz.change_enabled(enabled),
+            };
+            let quiz = match quiz_score_visible_to_author {
+                None => quiz,
+                Some(visible) => quiz.change_score_visible_to_author(visible),
+            };
+            let updated_answer_settings = updated_answer_settings.change_quiz(quiz);
+            let updated_answer_settings = match message_thread_lock_policy {
+                None => updated_answer_settings,
+                Some(policy) => updated_answer_settings.change_message_thread_lock_policy(policy),
+            };
+            let updated_answer_settings = match temporary_answer_moderation_policy {
+                None => updated_answer_settings,
+                Some(policy) => {
+                    updated_answer_settings.change_temporary_answer_moderation_policy(policy)
+                }
+            };
 
             let updated_form = match title {
                 None => form,
@@ -664,6 +736,21 @@ fn form_creation_details(form: &ActiveForm) -> Vec<EventDetail> {
             "回答者の公開",
             format_author_publication_policy(*form.answer_settings().author_publication_policy()),
         ),
+        EventDetail::new(
+            "回答の対応期限",
+            format_response_deadline(*form.answer_settings().response_deadline()),
+        ),
+        EventDetail::new("クイズ", format_quiz(*form.answer_settings().quiz())),
+        EventDetail::new(
+            "メッセージスレッドのロック",
+            format_message_thread_lock_policy(*form.answer_settings().message_thread_lock_policy()),
+        ),
+        EventDetail::new(
+            "一時回答の確認",
+            format_temporary_answer_moderation_policy(
+                *form.answer_settings().temporary_answer_moderation_policy(),
+            ),
+        ),
         EventDetail::new(
             "匿名回答",
             format_allowed(form.answer_settings().allow_temporary_answers()),
@@ -751,6 +838,38 @@ fn form_update_details(before: &ActiveForm, after: &ActiveForm) -> Vec<EventDeta
                 ),
             )
         }),
+        (before.answer_settings().response_deadline()
+            != after.answer_settings().response_deadline())
+        .then(|| {
+            EventDetail::new(
+                "回答の対応期限",
+                format_response_deadline(*after.answer_settings().response_deadline()),
+            )
+        }),
+        (before.answer_settings().quiz() != after.answer_settings().quiz())
+            .then(|| EventDetail::new("クイズ", format_quiz(*after.answer_settings().quiz()))),
+        (before.answer_settings().message_thread_lock_policy()
+            != after.answer_settings().message_thread_lock_policy())
+        .then(|| {
+            EventDetail::new(
+                "メッセージスレッドのロック",
+                format_message_thread_lock_policy(
+                    *after.answer_settings().message_thread_lock_policy(),
+                ),
+            )
+        }),
+        (before
+            .answer_settings()
+            .temporary_answer_moderation_policy()
+            != after.answer_settings().temporary_answer_moderation_policy())
+        .then(|| {
+            EventDetail::new(
+                "一時回答の確認",
+                format_temporary_answer_moderation_policy(
+                    *after.answer_settings().temporary_answer_moderation_policy(),
+                ),
+            )
+        }),
     ]
     .into_iter()
     .flatten()
@@ -817,6 +936,37 @@ fn format_author_publication_policy(policy: AnswerAuthorPublicationPolicy) -> &'
     }
 }
 
+fn format_response_deadline(deadline: AnswerResponseDeadline) -> String {
+    deadline
+        .into_inner()
+        .map(|hours| format!("{} 時間", hours.hours()))
+        .unwrap_or_else(|| "未設定".to_string())
+}
+
+fn format_quiz(quiz: AnswerQuizSettings) -> &'static str {
+    match (quiz.is_enabled(), quiz.is_score_visible_to_author()) {
+        (false, _) => "無効",
+        (true, true) => "有効 (点数を回答者に表示)",
+        (true, false) => "有効 (点数は回答者に非表示)",
+    }
+}
+
+fn format_message_thread_lock_policy(policy: MessageThreadLockPolicy) -> &'static str {
+    match policy {
+        MessageThreadLockPolicy::KeepOpen => "ロックしない",
+        MessageThreadLockPolicy::LockOnCompletion => "回答の完了時にロック",
+    }
+}
+
+fn format_temporary_answer_moderation_policy(
+    policy: TemporaryAnswerModerationPolicy,
+) -> &'static str {
+    match policy {
+        TemporaryAnswerModerationPolicy::Publish => "確認せずに受け付ける",
+        TemporaryAnswerModerationPolicy::HoldForReview => "承認まで保留する",
+    }
+}
+
 fn question_details(questions: &[Question]) -> impl Iterator<Item = EventDetail> + '_ {
     questions.iter().map(|question| {
         let choices = question
@@ -1003,7 +1153,8 @@ mod tests {
         account::models::{AccountUser, Role},
         form::{
             models::{
-                ActiveForm, FormDescription, FormLabelAssignment, FormMeta, FormSettings, FormTitle,
+                ActiveForm, FormDescription, FormLabelAssignment, FormMeta, FormSettings,
+                FormTitle, ResponseDeadlineHours,
             },
             question::{QuestionId, QuestionSet, QuestionType},
         },
@@ -1107,6 +1258,11 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
                 &user,
             )
             .await
@@ -1152,6 +1308,11 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
             )
             .await
             .unwrap();
@@ -1196,6 +1357,13 @@ mod tests {
                 None, // answer_visibility
                 None, // answer_groups
                 Some(AnswerAuthorPublicationPolicy::Hide),
+                Some(AnswerResponseDeadline::new(Some(
+                    ResponseDeadlineHours::try_from(72).unwrap(),
+                ))),
+                Some(true), // quiz_enabled
+                None,       // quiz_score_visible_to_author
+                Some(MessageThreadLockPolicy::LockOnCompletion),
+                None, // temporary_answer_moderation_policy
                 None, // questions
                 None, // label_ids
             )
@@ -1209,6 +1377,13 @@ mod tests {
                     detail.name == "タイトル" && detail.value == "Updated form")
                     && changes.iter().any(|detail|
                         detail.name == "回答者の公開" && detail.value == "非公開")
+                    && changes.iter().any(|detail|
+                        detail.name == "回答の対応期限" && detail.value == "72 時間")
+                    && changes.iter().any(|detail|
+                        detail.name == "クイズ" && detail.value == "有効 (点数は回答者に非表示)")
+                    && changes.iter().any(|detail|
+                        detail.name == "メッセージスレッドのロック"
+                            && detail.value == "回答の完了時にロック")
         ));
     }
 
@@ -1242,6 +1417,11 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                None,
+                None,
+                None,
+                None,
             )
             .await
             .unwrap();
@@ -1283,4 +1463,34 @@ mod tests {
             ] if archived_id == &form_id.to_string() && restored_id == &form_id.to_string()
         ));
     }
+
+    #[tokio::test]
+    async fn public_form_feed_lists_only_forms_anonymous_users_can_read() {
+        let public_form = sample_form(FormId::new());
+        let private_form = sample_form(FormId::new())
+            .change_settings(FormSettings::new().change_visibility(Visibility::PRIVATE));
+        let group_form = sample_form(FormId::new()).change_settings(
+            FormSettings::new()
+                .change_allowed_user_groups(AllowedUserGroups::new(vec![Uuid::new_v4().into()])),
+        );
+        let repositories = FormUseCaseTestRepositories::with_active_forms(vec![
+            public_form.clone(),
+            private_form,
+            group_form,
+        ]);
+
+        let feed = repositories
+            .form_use_case()
+            .public_form_feed(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            feed.entries()
+                .iter()
+                .map(|entry| *entry.form().id())
+                .collect::<Vec<_>>(),
+            vec![*public_form.id()]
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use domain::{
+    account::models::{AccountUser, UserId},
+    auth::Actor,
+    form::{
+        answer::{AnswerId, AnswerTitle},
+        mention::{Mention, MentionSource, mentioned_usernames},
+        models::FormId,
+    },
+    notification::{
+        models::{NotificationContent, NotificationType},
+        notificator::Notificator,
+    },
+    repository::{
+        form::mention_repository::MentionRepository,
+        notification_repository::NotificationRepository, user_repository::UserRepository,
+    },
+    types::authorization_guard::{Allowed, Create},
+};
+use errors::Error;
+
+use crate::notification::notification_preference_for;
+
+fn mention_notification_content(
+    frontend_url: &str,
+    form_id: FormId,
+    answer_id: AnswerId,
+    answer_title: &AnswerTitle,
+    source: MentionSource,
+) -> NotificationContent {
+    let title = answer_title
+        .clone()
+        .into_inner()
+        .map(|title| title.into_inner())
+        .unwrap_or_else(|| "（タイトルなし）".to_string());
+    let (post_kind, query) = match source {
+        MentionSource::Comment(comment_id) => ("コメント", format!("commentId={comment_id}")),
+        MentionSource::Message(message_id) => ("メッセージ", format!("messageId={message_id}")),
+    };
+
+    NotificationContent::new(vec![
+        format!("回答『{title}』の{post_kind}であなたがメンションされました。"),
+        format!("以下のリンクから{post_kind}を確認できます。"),
+        format!("{frontend_url}/forms/{form_id}/answers/{answer_id}?{query}"),
+    ])
+}
+
+/// 本文中の `@username` を、存在するユーザーに解決します。
+pub(super) async fn resolve_mentioned_users(
+    user_repository: &impl UserRepository,
+    actor: &Actor,
+    text: &str,
+) -> Result<Vec<AccountUser>, Error> {
+    let usernames = mentioned_usernames(text);
+    if usernames.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    user_repository
+        .find_by_names(usernames)
+        .await?
+        .into_iter()
+        .map(|user| Ok(user.try_read(actor.clone())?.into_inner()))
+        .collect()
+}
+
+/// メンションを保存し、メンションされたユーザーに通知します。
+///
+/// `notified_user_ids` に含まれるユーザーは、同じ投稿について別の通知を受け取っているため通知しません。
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn save_and_notify_mentions<N: Notificator>(
+    mention_repository: &impl MentionRepository,
+    notification_repository: &impl NotificationRepository,
+    user_repository: &impl UserRepository,
+    notificator: &N,
+    actor: &Actor,
+    form_id: FormId,
+    answer_title: &AnswerTitle,
+    mentions: Vec<Allowed<Mention, Create>>,
+    notified_user_ids: &[UserId],
+) -> Result<(), Error> {
+    if mentions.is_empty() {
+        return Ok(());
+    }
+
+    let notifications = mentions
+        .iter()
+        .filter(|mention| !notified_user_ids.contains(mention.mentioned_user_id()))
+        .map(|mention| {
+            (
+                *mention.mentioned_user_id(),
+                mention_notification_content(
+                    &common::config::FRONTEND.url,
+                    form_id,
+                    *mention.answer_id(),
+                    answer_title,
+                    *mention.source(),
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+    mention_repository.create(mentions).await?;
+
+    for (recipient_id, content) in notifications {
+        let preference = notification_preference_for(
+            notification_repository,
+            user_repository,
+            actor,
+            recipient_id,
+        )
+        .await?;
+
+        notificator
+            .notify(
+                recipient_id,
+                NotificationType::Mentioned,
+                &preference,
+                &content,
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::form::comment::CommentId;
+
+    use super::*;
+
+    #[test]
+    fn mention_notification_content_links_to_the_mentioned_post() {
+        let form_id = FormId::new();
+        let answer_id = AnswerId::new();
+        let comment_id = CommentId::new();
+
+        let content = mention_notification_content(
+            "https://example.com",
+            form_id,
+            answer_id,
+            &AnswerTitle::new(None),
+            MentionSource::Comment(comment_id),
+        );
+
+        assert_eq!(
+            content.to_message(),
+            format!(
+                "回答『（タイトルなし）』のコメントであなたがメンションされました。\n\
+以下のリンクからコメントを確認できます。\n\
+https://example.com/forms/{form_id}/answers/{answer_id}?commentId={comment_id}"
+            )
+        );
+    }
+}
@@ -4,42 +4,109 @@ use domain::form::models::{ActiveForm, FormId};
 use domain::notification::models::{NotificationContent, NotificationType};
 use domain::notification::notificator::Notificator;
 use domain::{
-    account::models::AccountUser,
+    account::models::{AccountUser, UserId},
     auth::Actor,
     form::{
-        answer::{AnswerEntry, AnswerId, AnswerTitle},
+        answer::{AnswerAuthor, AnswerEntry, AnswerId, AnswerTitle},
+        content_filter::ContentFilterTarget,
+        mention::Mention,
         message::{
-            Message, MessageBody, MessageHistoryEntry, MessageHistoryPagePosition, MessageId,
+            Message, MessageAttachment, MessageAttachmentId, MessageBody, MessageHistoryEntry,
+            MessageHistoryPagePosition, MessageId, MessagePost, MessageSender,
         },
+        message_template::MessageTemplateId,
+        message_thread::MessageThread,
     },
-    notification::models::NotificationPreference,
     pagination::{Page, PageRequest},
     repository::{
         form::{
             active_form_repository::ActiveFormRepository,
             answer_entry_repository::AnswerEntryRepository,
+            content_filter_repository::ContentFilterRepository,
+            mention_repository::MentionRepository,
+            message_attachment_storage::MessageAttachmentStorage,
+            message_read_repository::MessageReadRepository,
+            message_template_repository::MessageTemplateRepository,
             message_thread_repository::MessageThreadRepository,
         },
         form_submission_restriction_repository::FormSubmissionRestrictionRepository,
         notification_repository::NotificationRepository,
         user_repository::UserRepository,
     },
-    types::{
-        authorization_guard::{Allowed, AuthorizationGuard},
-        authorization_guard::{Create, Read},
-    },
+    types::authorization_guard::{Allowed, Create, Read},
 };
 use errors::{
     Error,
-    usecase::UseCaseError::{AnswerNotFound, FormNotFound, MessageNotFound, UserNotFound},
+    domain::DomainError,
+    usecase::UseCaseError::{
+        AnswerNotFound, FormNotFound, MessageAttachmentNotFound, MessageNotFound,
+        MessageTemplateNotFound, UserNotFound,
+    },
 };
 
 use crate::{
     application_event::{ApplicationActor, ApplicationEvent, ApplicationEventPublisher},
-    models::MessageWithSender,
-    user_reference_resolver::resolve_user_references,
+    forms::{
+        content_filter::{enforce_verdict, screen_content},
+        mention::{resolve_mentioned_users, save_and_notify_mentions},
+    },
+    models::{MessageAttachmentUpload, MessageSenderProfile, MessageWithSender},
+    notification::notification_preference_for,
+    user_reference_resolver::{resolve_user_references, resolve_user_references_as},
 };
 
+/// スレッドのメッセージに、送信者と既読にしたユーザーを添える。
+pub(crate) async fn messages_with_senders(
+    user_repository: &impl UserRepository,
+    message_read_repository: &impl MessageReadRepository,
+    actor: &Actor,
+    answer: &AnswerEntry,
+    thread: &Allowed<MessageThread, Read>,
+) -> Result<Vec<MessageWithSender>, Error> {
+    let receipts = message_read_repository
+        .receipts(thread)
+        .await?
+        .into_iter()
+        .map(Allowed::into_inner)
+        .collect::<Vec<_>>();
+    let messages = thread.messages().to_vec();
+
+    let user_ids = messages
+        .iter()
+        .filter_map(|message| message.sender_id().copied())
+        .chain(receipts.iter().map(|receipt| *receipt.reader_id()))
+        .collect();
+    let users = resolve_user_references_as(user_repository, actor, user_ids).await?;
+    let find_user = |user_id: &UserId| users.get(user_id).cloned().ok_or(Error::from(UserNotFound));
+
+    messages
+        .into_iter()
+        .map(|message| {
+            let sender = match message.sender() {
+                MessageSender::User(user_id) => MessageSenderProfile::User(find_user(user_id)?),
+                MessageSender::TemporaryAnswerAuthor(_) => answer
+                    .author()
+                    .temporary_user()
+                    .cloned()
+                    .map(MessageSenderProfile::TemporaryAnswerAuthor)
+                    .ok_or(Error::from(UserNotFound))?,
+            };
+            let read_by = receipts
+                .iter()
+                .filter(|receipt| {
+                    Some(receipt.reader_id()) != message.sender_id() && receipt.has_read(&message)
+                })
+                .map(|receipt| find_user(receipt.reader_id()))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(MessageWithSender {
+                message,
+                sender,
+                read_by,
+            })
+        })
+        .collect()
+}
+
 fn message_notification_content(
     frontend_url: &str,
     form_id: FormId,
@@ -60,6 +127,36 @@ fn message_notification_content(
     ])
 }
 
+/// 保存済みの添付ファイルの中身を削除します。
+///
+/// 失敗の後始末として呼び出すため、削除できなくても元の失敗を優先し、記録だけを残します。
+async fn discard_attachments(
+    attachment_storage: &dyn MessageAttachmentStorage,
+    attachments: &[MessageAttachment],
+) {
+    for attachment in attachments {
+        if let Err(error) = attachment_storage.delete(attachment).await {
+            tracing::warn!(
+                attachment_id = %attachment.id(),
+                error = %error,
+                "failed to discard an orphaned message attachment"
+            );
+        }
+    }
+}
+
+/// 保存したメッセージについて、投稿後の通知に使う内容。
+struct PostedMessage {
+    form_id: FormId,
+    form_answer: Allowed<AnswerEntry, Read>,
+    message_id: String,
+    message_body: String,
+    notification_recipient_id: Option<UserId>,
+    mentions: Vec<Allowed<Mention, Create>>,
+    /// コンテンツフィルターで確認を求められたメッセージは、管理者が確認するまで本文を広めない。
+    held_for_review: bool,
+}
+
 pub struct MessageUseCase<
     'a,
     NotificationRepo: NotificationRepository,
@@ -106,40 +203,237 @@ impl<
         Ok((form, answer))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn post_message<N: Notificator>(
         &self,
         actor: &AccountUser,
         form_id: FormId,
         message_body: MessageBody,
+        attachments: Vec<MessageAttachmentUpload>,
         answer_id: AnswerId,
         notificator: &N,
         restriction_repository: &impl FormSubmissionRestrictionRepository,
+        mention_repository: &impl MentionRepository,
+        attachment_storage: &impl MessageAttachmentStorage,
+        content_filter_repository: &impl ContentFilterRepository,
     ) -> Result<(), Error> {
         super::submission::authorize_form_submission(actor.clone(), restriction_repository).await?;
         let actor_user = Actor::from(actor.clone());
-        let (_, form_answer) = self
+        let (form, form_answer) = self
             .read_form_and_answer_entry(&actor_user, form_id, answer_id)
             .await?;
-        let answer_title = form_answer
-            .title()
-            .clone()
-            .into_inner()
-            .map(|title| title.into_inner());
+        let verdict =
+            screen_content(content_filter_repository, form_id, [message_body.as_str()]).await?;
+        verdict.ensure_accepted()?;
 
-        let message = Message::new(*actor.id(), message_body);
-        let message_id = message.id().to_string();
-        let message_body = message.body().as_str().to_owned();
-        let message_sender_id = *message.sender_id();
+        // サイズはクライアントの申告ではなく実際に受け取った内容から求める
+        let attachments = attachments
+            .into_iter()
+            .map(|upload| {
+                let attachment = MessageAttachment::try_new(
+                    upload.file_name,
+                    upload.content_type,
+                    upload.content.len() as u64,
+                )?;
+                Ok((attachment, upload.content))
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+        let message = Message::new(*actor.id(), message_body).attach(
+            attachments
+                .iter()
+                .map(|(attachment, _)| attachment.clone())
+                .collect(),
+        )?;
+        let post = self
+            .authorize_message_post(&form, &form_answer, message)
+            .await?;
+
+        // 認可が済んでから保存し、メッセージより先に中身を置いておく
+        let mut stored_attachments = Vec::with_capacity(attachments.len());
+        for (attachment, content) in attachments {
+            if let Err(error) = attachment_storage.put(&attachment, content).await {
+                discard_attachments(attachment_storage, &stored_attachments).await;
+                return Err(error);
+            }
+            stored_attachments.push(attachment);
+        }
+
+        let filter_target = ContentFilterTarget::Message(*post.message().id());
+        let mut posted = self
+            .save_message_post(actor, &form, form_answer, post, Some(attachment_storage))
+            .await?;
+        posted.held_for_review = verdict.is_flagged();
+        self.notify_message_posted(actor, posted, notificator, mention_repository)
+            .await?;
+
+        enforce_verdict(
+            content_filter_repository,
+            &verdict,
+            form_id,
+            answer_id,
+            filter_target,
+        )
+        .await
+    }
+
+    /// メッセージテンプレートを回答の内容で展開し、その本文をメッセージとして投稿する。
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_message_from_template<N: Notificator>(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        template_id: MessageTemplateId,
+        answer_id: AnswerId,
+        notificator: &N,
+        restriction_repository: &impl FormSubmissionRestrictionRepository,
+        message_template_repository: &impl MessageTemplateRepository,
+        mention_repository: &impl MentionRepository,
+    ) -> Result<(), Error> {
+        super::submission::authorize_form_submission(actor.clone(), restriction_repository).await?;
+        let actor_user = Actor::from(actor.clone());
+        let (form, form_answer) = self
+            .read_form_and_answer_entry(&actor_user, form_id, answer_id)
+            .await?;
+        let template = message_template_repository
+            .get(template_id)
+            .await?
+            .ok_or(MessageTemplateNotFound)?
+            .try_read(actor_user)?;
+        let author_name = self.answer_author_name(actor, form_answer.author()).await?;
+        let message_body = template.render(&form, &form_answer, &author_name)?;
+        let post = self
+            .authorize_message_post(&form, &form_answer, Message::new(*actor.id(), message_body))
+            .await?;
+
+        self.append_message(
+            actor,
+            &form,
+            form_answer,
+            post,
+            notificator,
+            mention_repository,
+        )
+        .await
+    }
 
+    async fn answer_author_name(
+        &self,
+        actor: &AccountUser,
+        author: &AnswerAuthor,
+    ) -> Result<String, Error> {
+        match author {
+            AnswerAuthor::AuthenticatedUser(user_id) => {
+                resolve_user_references(self.user_repository, actor, vec![*user_id])
+                    .await?
+                    .remove(user_id)
+                    .map(|user| user.name().to_owned())
+                    .ok_or(Error::from(UserNotFound))
+            }
+            AnswerAuthor::Temporary(author) => Ok(author.name().to_owned()),
+            AnswerAuthor::ImportedFromRedmine(author) => Ok(author.display_name().to_owned()),
+        }
+    }
+
+    async fn authorize_message_post(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        form_answer: &Allowed<AnswerEntry, Read>,
+        message: Message,
+    ) -> Result<Allowed<MessagePost, Create>, Error> {
         let thread = self
             .message_thread_repository
-            .get_for_answer(&form_answer)
+            .get_for_answer(form, form_answer)
             .await?
             .try_into_update()?;
-        let post = thread.try_post_message(message)?;
-        let notification_recipient_id = *post.answer_author_id();
-        self.message_thread_repository.append(post).await?;
-        let notification_content = (message_sender_id != notification_recipient_id).then(|| {
+
+        Ok(thread.try_post_message(message)?)
+    }
+
+    /// メッセージを投稿し、回答者と本文でメンションされたユーザーに通知する。
+    async fn append_message<N: Notificator>(
+        &self,
+        actor: &AccountUser,
+        form: &Allowed<ActiveForm, Read>,
+        form_answer: Allowed<AnswerEntry, Read>,
+        post: Allowed<MessagePost, Create>,
+        notificator: &N,
+        mention_repository: &impl MentionRepository,
+    ) -> Result<(), Error> {
+        let posted = self
+            .save_message_post(actor, form, form_answer, post, None)
+            .await?;
+
+        self.notify_message_posted(actor, posted, notificator, mention_repository)
+            .await
+    }
+
+    /// メッセージを保存し、投稿後の通知に必要な内容を返す。
+    ///
+    /// `attachment_storage` を渡した場合、保存できなかったメッセージの添付ファイルの中身を削除する。
+    async fn save_message_post(
+        &self,
+        actor: &AccountUser,
+        form: &Allowed<ActiveForm, Read>,
+        form_answer: Allowed<AnswerEntry, Read>,
+        post: Allowed<MessagePost, Create>,
+        attachment_storage: Option<&dyn MessageAttachmentStorage>,
+    ) -> Result<PostedMessage, Error> {
+        let actor_user = Actor::from(actor.clone());
+        let message = post.message();
+        let message_id = message.id().to_string();
+        let message_body = message.body().as_str().to_owned();
+        let message_sender_id = message.sender_id().copied();
+        let mentioned_users =
+            resolve_mentioned_users(self.user_repository, &actor_user, &message_body).await?;
+        let mentions = form.message_mentions(form_answer.value(), message, mentioned_users)?;
+
+        // 一時回答の回答者はアカウントを持たないため、通知先にならない
+        let notification_recipient_id = (*post.answer_author_id())
+            .filter(|recipient_id| Some(*recipient_id) != message_sender_id);
+        let attachments = message.attachments().to_vec();
+        if let Err(error) = self.message_thread_repository.append(post).await {
+            // メッセージが残らなかった場合は、先に保存した添付ファイルの中身も残さない
+            if let Some(attachment_storage) = attachment_storage {
+                discard_attachments(attachment_storage, &attachments).await;
+            }
+            return Err(error);
+        }
+
+        Ok(PostedMessage {
+            form_id: *form.id(),
+            form_answer,
+            message_id,
+            message_body,
+            notification_recipient_id,
+            mentions,
+            held_for_review: false,
+        })
+    }
+
+    async fn notify_message_posted<N: Notificator>(
+        &self,
+        actor: &AccountUser,
+        posted: PostedMessage,
+        notificator: &N,
+        mention_repository: &impl MentionRepository,
+    ) -> Result<(), Error> {
+        let PostedMessage {
+            form_id,
+            form_answer,
+            message_id,
+            message_body,
+            notification_recipient_id,
+            mentions,
+            held_for_review,
+        } = posted;
+        let actor_user = Actor::from(actor.clone());
+        let answer_id = *form_answer.id();
+        let answer_title = form_answer
+            .title()
+            .clone()
+            .into_inner()
+            .map(|title| title.into_inner());
+        let notification_content = notification_recipient_id.map(|_| {
             message_notification_content(
                 &FRONTEND.url,
                 form_id,
@@ -148,7 +442,10 @@ impl<
                 &message_id,
             )
         });
-        if let Some(publisher) = self.application_event_publisher {
+        if let Some(publisher) = self
+            .application_event_publisher
+            .filter(|_| !held_for_review)
+        {
             publisher.publish(ApplicationEvent::MessageCreated {
                 actor: ApplicationActor::from(actor),
                 form_id: form_id.to_string(),
@@ -159,37 +456,18 @@ impl<
             });
         }
 
-        if let Some(notification_content) = notification_content {
-            let fetched_notification_preference = self
-                .notification_repository
-                .fetch_notification_settings(notification_recipient_id.into_inner())
-                .await?;
-
-            let notification_preference = match fetched_notification_preference {
-                Some(settings) => settings.try_read(Actor::System)?.into_inner(),
-                None => {
-                    let recipient = self
-                        .user_repository
-                        .find_by(notification_recipient_id.into_inner())
-                        .await?
-                        .ok_or(Error::from(UserNotFound))?
-                        .try_read(actor_user.clone())?
-                        .into_inner();
-
-                    let preference = NotificationPreference::new(*recipient.id());
-
-                    self.notification_repository
-                        .create_notification_settings(
-                            AuthorizationGuard::<_, Create>::from(preference.clone())
-                                .try_create(Actor::from(recipient.clone()))?,
-                        )
-                        .await?;
-
-                    AuthorizationGuard::<_, Read>::from(preference)
-                        .try_read(Actor::System)?
-                        .into_inner()
-                }
-            };
+        // 回答者は新着メッセージとして通知されるため、メンションでは重ねて通知しない
+        let notified_user_ids = notification_recipient_id.into_iter().collect::<Vec<_>>();
+        if let Some((notification_recipient_id, notification_content)) =
+            notification_recipient_id.zip(notification_content)
+        {
+            let notification_preference = notification_preference_for(
+                self.notification_repository,
+                self.user_repository,
+                &actor_user,
+                notification_recipient_id,
+            )
+            .await?;
 
             notificator
                 .notify(
@@ -200,8 +478,22 @@ impl<
                 )
                 .await?;
         }
+        if held_for_review {
+            return Ok(());
+        }
 
-        Ok(())
+        save_and_notify_mentions(
+            mention_repository,
+            self.notification_repository,
+            self.user_repository,
+            notificator,
+            &actor_user,
+            form_id,
+            form_answer.title(),
+            mentions,
+            &notified_user_ids,
+        )
+        .await
     }
 
     pub async fn get_messages(
@@ -209,34 +501,82 @@ impl<
         actor: &AccountUser,
         form_id: FormId,
         answer_id: AnswerId,
+        message_read_repository: &impl MessageReadRepository,
     ) -> Result<Vec<MessageWithSender>, Error> {
         let actor_user = Actor::from(actor.clone());
-        let (_, form_answer) = self
+        let (form, form_answer) = self
             .read_form_and_answer_entry(&actor_user, form_id, answer_id)
             .await?;
 
-        let messages = self
+        let thread = self
             .message_thread_repository
-            .get_for_answer(&form_answer)
-            .await?
-            .messages()
-            .to_vec();
+            .get_for_answer(&form, &form_answer)
+            .await?;
 
-        let sender_ids = messages.iter().map(|m| *m.sender_id()).collect();
-        let senders = resolve_user_references(self.user_repository, actor, sender_ids).await?;
+        messages_with_senders(
+            self.user_repository,
+            message_read_repository,
+            &actor_user,
+            form_answer.value(),
+            &thread,
+        )
+        .await
+    }
 
-        messages
-            .into_iter()
-            .map(|message| {
-                let sender = senders
-                    .get(message.sender_id())
-                    .cloned()
-                    .ok_or(Error::from(UserNotFound))?;
-                Ok(MessageWithSender { message, sender })
-            })
-            .collect()
+    /// `up_to` までのメッセージを既読にする。`up_to` を省略した場合は最新のメッセージまでを既読にする。
+    pub async fn mark_messages_as_read(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        up_to: Option<MessageId>,
+        message_read_repository: &impl MessageReadRepository,
+    ) -> Result<(), Error> {
+        let actor_user = Actor::from(actor.clone());
+        let (form, form_answer) = self
+            .read_form_and_answer_entry(&actor_user, form_id, answer_id)
+            .await?;
+        let thread = self
+            .message_thread_repository
+            .get_for_answer(&form, &form_answer)
+            .await?;
+        if let Some(message_id) = up_to {
+            thread
+                .find_message(message_id)
+                .ok_or(Error::from(MessageNotFound))?;
+        }
+        let receipt = thread.mark_as_read(up_to, Utc::now())?;
+
+        message_read_repository.mark_as_read(receipt).await
+    }
+
+    /// 閲覧できるメッセージスレッドに添付されたファイルのメタデータと中身を取得する。
+    pub async fn get_message_attachment(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        attachment_id: MessageAttachmentId,
+        attachment_storage: &impl MessageAttachmentStorage,
+    ) -> Result<(MessageAttachment, Vec<u8>), Error> {
+        let actor_user = Actor::from(actor.clone());
+        let (form, form_answer) = self
+            .read_form_and_answer_entry(&actor_user, form_id, answer_id)
+            .await?;
+        let thread = self
+            .message_thread_repository
+            .get_for_answer(&form, &form_answer)
+            .await?;
+        let attachment = thread
+            .attachment(attachment_id)
+            .cloned()
+            .ok_or(MessageAttachmentNotFound)?;
+        let content = attachment_storage.get(&attachment).await?;
+
+        Ok((attachment, content))
     }
 
+    /// メッセージの本文を編集する。編集後の本文も、投稿時と同じくコンテンツフィルターで確かめる。
     pub async fn update_message_body(
         &self,
         actor: &AccountUser,
@@ -244,9 +584,10 @@ impl<
         answer_id: AnswerId,
         message_id: &MessageId,
         body: Option<MessageBody>,
+        content_filter_repository: &impl ContentFilterRepository,
     ) -> Result<(), Error> {
         let actor_user = Actor::from(actor.clone());
-        let (_, form_answer) = self
+        let (form, form_answer) = self
             .read_form_and_answer_entry(&actor_user, form_id, answer_id)
             .await?;
         let answer_title = form_answer
@@ -258,7 +599,7 @@ impl<
         if let Some(body) = body {
             let thread = self
                 .message_thread_repository
-                .get_for_answer(&form_answer)
+                .get_for_answer(&form, &form_answer)
                 .await?
                 .try_into_update()?;
 
@@ -269,12 +610,26 @@ impl<
                 return Ok(());
             }
             let body_for_event = body.as_str().to_owned();
+            let verdict =
+                screen_content(content_filter_repository, form_id, [body.as_str()]).await?;
+            verdict.ensure_accepted()?;
 
             let updated = thread.authorize_message_update(*message_id, body)?;
             self.message_thread_repository
                 .update_message(updated, Utc::now())
                 .await?;
-            if let Some(publisher) = self.application_event_publisher {
+            enforce_verdict(
+                content_filter_repository,
+                &verdict,
+                form_id,
+                answer_id,
+                ContentFilterTarget::Message(*message_id),
+            )
+            .await?;
+            if let Some(publisher) = self
+                .application_event_publisher
+                .filter(|_| !verdict.is_flagged())
+            {
                 publisher.publish(ApplicationEvent::MessageUpdated {
                     actor: ApplicationActor::from(actor),
                     form_id: form_id.to_string(),
@@ -297,7 +652,7 @@ impl<
         message_id: &MessageId,
     ) -> Result<(), Error> {
         let actor_user = Actor::from(actor.clone());
-        let (_, form_answer) = self
+        let (form, form_answer) = self
             .read_form_and_answer_entry(&actor_user, form_id, answer_id)
             .await?;
         let answer_title = form_answer
@@ -308,7 +663,7 @@ impl<
 
         let thread = self
             .message_thread_repository
-            .get_for_answer(&form_answer)
+            .get_for_answer(&form, &form_answer)
             .await?
             .try_into_update()?;
 
@@ -337,6 +692,27 @@ impl<
         Ok(())
     }
 
+    /// ロックされたメッセージスレッドを管理者が再開し、再び投稿できるようにする。
+    pub async fn reopen_message_thread(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+    ) -> Result<(), Error> {
+        let actor_user = Actor::from(actor.clone());
+        let (form, form_answer) = self
+            .read_form_and_answer_entry(&actor_user, form_id, answer_id)
+            .await?;
+        let reopening = self
+            .message_thread_repository
+            .get_for_answer(&form, &form_answer)
+            .await?
+            .try_into_update()?
+            .reopen(Utc::now())?;
+
+        self.message_thread_repository.reopen(reopening).await
+    }
+
     pub async fn get_history(
         &self,
         actor: &AccountUser,
@@ -345,12 +721,12 @@ impl<
         request: PageRequest<MessageHistoryPagePosition>,
     ) -> Result<Page<Allowed<MessageHistoryEntry, Read>, MessageHistoryPagePosition>, Error> {
         let actor_user = Actor::from(actor.clone());
-        let (_, form_answer) = self
+        let (form, form_answer) = self
             .read_form_and_answer_entry(&actor_user, form_id, answer_id)
             .await?;
         let thread = self
             .message_thread_repository
-            .get_for_answer(&form_answer)
+            .get_for_answer(&form, &form_answer)
             .await?;
         self.message_thread_repository
             .history(&thread, request)
@@ -360,9 +736,12 @@ impl<
 
 #[cfg(test)]
 mod tests {
-    use std::sync::{
-        Mutex,
-        atomic::{AtomicBool, Ordering},
+    use std::{
+        collections::HashMap,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
     };
 
     use super::*;
@@ -371,14 +750,28 @@ mod tests {
         account::models::{AccountUser, Role, UserId},
         form::{
             FormSubmissionRestriction, FormSubmissionRestrictionReason,
-            answer::{AnswerAuthor, AnswerEntry, AnswerId, AnswerTitle, TemporaryAnswerAuthor},
+            answer::{
+                AnswerAuthor, AnswerEntry, AnswerId, AnswerStatus, AnswerTitle,
+                TemporaryAnswerAuthor,
+            },
+            content_filter::{ContentFilterAction, ContentFilterCondition, ContentFilterRule},
             message::{DeletedMessage, MessageHistoryEntry, MessageId, MessagePost},
-            message_thread::MessageThread,
-            models::{ActiveForm, FormDescription, FormTitle, QuestionSet},
+            message_read::{MessageReadReceipt, UnreadMessageCountTarget},
+            message_template::{MessageTemplate, MessageTemplateBody, MessageTemplateName},
+            message_thread::{MessageThread, MessageThreadReopening},
+            models::{
+                ActiveForm, FormDescription, FormTitle, MessageThreadLockPolicy, QuestionSet,
+            },
             question::Question,
         },
-        notification::models::{NotificationContent, NotificationType},
+        notification::models::{NotificationContent, NotificationPreference, NotificationType},
         pagination::{Page, PageLimit},
+        repository::form::{
+            mention_repository::MockMentionRepository,
+            message_attachment_storage::MockMessageAttachmentStorage,
+            message_template_repository::MockMessageTemplateRepository,
+            message_thread_repository::MockMessageThreadRepository,
+        },
         types::authorization_guard::{Create, Update},
     };
     use types::non_empty_vec::NonEmptyVec;
@@ -386,7 +779,10 @@ mod tests {
 
     use crate::{
         application_event::{ApplicationEvent, ApplicationEventPublisher},
-        test_utils::repositories::{FormUseCaseTestRepositories, InMemoryAnswerEntryRepository},
+        test_utils::repositories::{
+            FormUseCaseTestRepositories, InMemoryAnswerEntryRepository,
+            InMemoryContentFilterRepository,
+        },
     };
 
     #[derive(Default)]
@@ -399,16 +795,16 @@ mod tests {
     }
 
     #[derive(Default)]
-    struct InMemoryMessageThreadRepository(Mutex<Vec<(AnswerId, Message)>>);
+    struct InMemoryMessageThreadRepository(Arc<Mutex<Vec<(AnswerId, Message)>>>);
 
     impl InMemoryMessageThreadRepository {
         fn with_messages(answer_id: AnswerId, messages: Vec<Message>) -> Self {
-            Self(Mutex::new(
+            Self(Arc::new(Mutex::new(
                 messages
                     .into_iter()
                     .map(|message| (answer_id, message))
                     .collect(),
-            ))
+            )))
         }
 
         fn only_message_id(&self) -> MessageId {
@@ -434,10 +830,91 @@ mod tests {
         }
     }
 
+    /// 未読数を数えるため、`messages` とメッセージを共有する。
+    struct InMemoryMessageReadRepository {
+        messages: Arc<Mutex<Vec<(AnswerId, Message)>>>,
+        receipts: Mutex<Vec<MessageReadReceipt>>,
+    }
+
+    impl InMemoryMessageReadRepository {
+        fn new(messages: &InMemoryMessageThreadRepository) -> Self {
+            Self {
+                messages: Arc::clone(&messages.0),
+                receipts: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MessageReadRepository for InMemoryMessageReadRepository {
+        async fn mark_as_read(
+            &self,
+            receipt: Allowed<MessageReadReceipt, Create>,
+        ) -> Result<(), Error> {
+            let receipt = receipt.into_inner();
+            let mut receipts = self.receipts.lock().unwrap();
+            match receipts.iter_mut().find(|stored| {
+                stored.answer_id() == receipt.answer_id()
+                    && stored.reader_id() == receipt.reader_id()
+            }) {
+                Some(stored) if stored.last_read_message_id() >= receipt.last_read_message_id() => {
+                }
+                Some(stored) => *stored = receipt,
+                None => receipts.push(receipt),
+            }
+            Ok(())
+        }
+
+        async fn receipts(
+            &self,
+            message_thread: &Allowed<MessageThread, Read>,
+        ) -> Result<Vec<Allowed<MessageReadReceipt, Read>>, Error> {
+            self.receipts
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|receipt| receipt.answer_id() == message_thread.answer_id())
+                .map(|receipt| {
+                    message_thread
+                        .authorize_read_receipt(receipt.clone())
+                        .map_err(Into::into)
+                })
+                .collect()
+        }
+
+        async fn unread_message_counts(
+            &self,
+            targets: &[UnreadMessageCountTarget],
+        ) -> Result<HashMap<UnreadMessageCountTarget, u32>, Error> {
+            let messages = self.messages.lock().unwrap();
+            let receipts = self.receipts.lock().unwrap();
+
+            Ok(targets
+                .iter()
+                .map(|target| {
+                    let receipt = receipts.iter().find(|receipt| {
+                        receipt.answer_id() == target.answer_id()
+                            && receipt.reader_id() == target.reader_id()
+                    });
+                    let count = messages
+                        .iter()
+                        .filter(|(answer_id, message)| {
+                            answer_id == target.answer_id()
+                                && message.sender_id() != Some(target.reader_id())
+                                && receipt.is_none_or(|receipt| !receipt.has_read(message))
+                        })
+                        .count();
+                    (*target, count as u32)
+                })
+                .collect())
+        }
+    }
+
     #[async_trait]
     impl MessageThreadRepository for InMemoryMessageThreadRepository {
         async fn get_for_answer(
             &self,
+            form: &Allowed<ActiveForm, Read>,
             answer: &Allowed<AnswerEntry, Read>,
         ) -> Result<Allowed<MessageThread, Read>, Error> {
             let answer_id = *answer.id();
@@ -449,7 +926,8 @@ mod tests {
                 .filter(|(related_answer_id, _)| *related_answer_id == answer_id)
                 .map(|(_, message)| message.clone())
                 .collect();
-            answer.message_thread(messages).map_err(Error::from)
+            form.message_thread(answer, messages, false, false)
+                .map_err(Error::from)
         }
 
         async fn append(&self, post: Allowed<MessagePost, Create>) -> Result<(), Error> {
@@ -500,6 +978,13 @@ mod tests {
         {
             Ok(Page::new(Vec::new(), None))
         }
+
+        async fn reopen(
+            &self,
+            _reopening: Allowed<MessageThreadReopening, Create>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     struct NoopNotificator;
@@ -543,6 +1028,23 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct RecordingNotificator(Mutex<Vec<(UserId, NotificationType)>>);
+
+    #[async_trait]
+    impl Notificator for RecordingNotificator {
+        async fn notify(
+            &self,
+            recipient: UserId,
+            notification_type: NotificationType,
+            _notification_preference: &NotificationPreference,
+            _content: &NotificationContent,
+        ) -> Result<(), Error> {
+            self.0.lock().unwrap().push((recipient, notification_type));
+            Ok(())
+        }
+    }
+
     fn user() -> AccountUser {
         AccountUser::new(
             "admin".to_string(),
@@ -646,9 +1148,13 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                 &actor,
                 form_id,
                 MessageBody::new("must not be appended".to_string().try_into().unwrap()),
+                Vec::new(),
                 answer_id,
                 &notificator,
                 &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
             )
             .await;
 
@@ -705,9 +1211,13 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                 &actor,
                 form_id,
                 MessageBody::new("message".to_string().try_into().unwrap()),
+                Vec::new(),
                 answer_id,
                 &notificator,
                 &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
             )
             .await;
 
@@ -748,9 +1258,13 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                     &actor,
                     form_id,
                     MessageBody::new(body.to_string().try_into().unwrap()),
+                    Vec::new(),
                     answer_id,
                     &NoopNotificator,
                     &repositories.form_submission_restriction_repository,
+                    &MockMentionRepository::new(),
+                    &MockMessageAttachmentStorage::new(),
+                    &repositories.content_filter_repository,
                 )
                 .await
                 .unwrap();
@@ -760,13 +1274,27 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
     }
 
     #[tokio::test]
-    async fn empty_thread_reads_empty_and_unknown_message_mutations_return_message_not_found() {
+    async fn content_filter_rejects_or_flags_messages() {
         let actor = user();
         let (form, answer) = form_and_answer(&actor);
         let form_id = *form.id();
         let answer_id = *answer.id();
+        let rule = |word: &str, action| {
+            ContentFilterRule::try_new(
+                form_id,
+                ContentFilterCondition::BlockedWord(word.to_string().try_into().unwrap()),
+                action,
+                *actor.id(),
+                Utc::now(),
+            )
+            .unwrap()
+        };
         let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
         repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.content_filter_repository = InMemoryContentFilterRepository::with_rules(vec![
+            rule("spam", ContentFilterAction::Reject),
+            rule("discount", ContentFilterAction::FlagForReview),
+        ]);
         let messages = InMemoryMessageThreadRepository::default();
         let usecase = MessageUseCase {
             notification_repository: &repositories.notification_repository,
@@ -776,44 +1304,529 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
             message_thread_repository: &messages,
             application_event_publisher: None,
         };
-        let unknown_message_id = MessageId::new();
-
-        let fetched = usecase
-            .get_messages(&actor, form_id, answer_id)
-            .await
-            .unwrap();
-        let history = usecase
-            .get_history(
-                &actor,
-                form_id,
-                answer_id,
-                PageRequest::first(PageLimit::default_limit()),
-            )
-            .await
-            .unwrap();
-        let update = usecase
-            .update_message_body(
+        let mention_repository = MockMentionRepository::new();
+        let attachment_storage = MockMessageAttachmentStorage::new();
+        let post = |body: &str| {
+            usecase.post_message(
                 &actor,
                 form_id,
+                MessageBody::new(body.to_string().try_into().unwrap()),
+                Vec::new(),
                 answer_id,
-                &unknown_message_id,
-                Some(MessageBody::new("updated".to_string().try_into().unwrap())),
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &mention_repository,
+                &attachment_storage,
+                &repositories.content_filter_repository,
             )
-            .await;
-        let delete = usecase
-            .delete_message(&actor, form_id, answer_id, &unknown_message_id)
-            .await;
+        };
 
-        assert!(fetched.is_empty());
-        assert!(history.items().is_empty());
-        assert_eq!(update, Err(Error::from(MessageNotFound)));
-        assert_eq!(delete, Err(Error::from(MessageNotFound)));
+        let rejected = post("cheap spam").await;
+        post("a discount for you").await.unwrap();
+
+        assert_eq!(rejected, Err(DomainError::ContentRejectedByFilter.into()));
+        assert_eq!(messages.message_count_for(answer_id), 1);
+        let enforcements = repositories.content_filter_repository.enforcements();
+        assert!(matches!(
+            enforcements.as_slice(),
+            [enforcement] if matches!(
+                enforcement.flags().as_slice(),
+                [flag] if matches!(flag.target(), ContentFilterTarget::Message(_))
+                    && flag.answer_id() == &answer_id
+            )
+        ));
     }
 
     #[tokio::test]
-    async fn message_cud_publishes_answer_title_and_saved_body_and_skips_empty_or_equal_updates() {
-        let user = user();
-        let (form, answer) = form_and_answer(&user);
+    async fn flagged_messages_are_neither_published_nor_mentioned() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let author = user();
+        let poster = AccountUser::new(
+            "poster".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let colleague = AccountUser::new(
+            "colleague".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let (form, answer) = form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.content_filter_repository = InMemoryContentFilterRepository::with_rules(vec![
+            ContentFilterRule::try_new(
+                form_id,
+                ContentFilterCondition::BlockedWord("discount".to_string().try_into().unwrap()),
+                ContentFilterAction::FlagForReview,
+                *poster.id(),
+                Utc::now(),
+            )
+            .unwrap(),
+        ]);
+        for user in [&author, &poster, &colleague] {
+            repositories.user_repository.save_user(user.clone());
+        }
+        let messages = InMemoryMessageThreadRepository::default();
+        let notificator = RecordingNotificator::default();
+        let publisher = RecordingPublisher::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: Some(&publisher),
+        };
+
+        usecase
+            .post_message(
+                &poster,
+                form_id,
+                MessageBody::new("@colleague a discount".to_string().try_into().unwrap()),
+                Vec::new(),
+                answer_id,
+                &notificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(messages.message_count_for(answer_id), 1);
+        assert!(publisher.0.lock().unwrap().is_empty());
+        assert_eq!(
+            *notificator.0.lock().unwrap(),
+            vec![(*author.id(), NotificationType::MessageReceived)]
+        );
+    }
+
+    #[tokio::test]
+    async fn edited_message_bodies_are_screened_by_the_content_filter() {
+        let actor = user();
+        let (form, answer) = form_and_answer(&actor);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let rule = |word: &str, action| {
+            ContentFilterRule::try_new(
+                form_id,
+                ContentFilterCondition::BlockedWord(word.to_string().try_into().unwrap()),
+                action,
+                *actor.id(),
+                Utc::now(),
+            )
+            .unwrap()
+        };
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.content_filter_repository = InMemoryContentFilterRepository::with_rules(vec![
+            rule("spam", ContentFilterAction::Reject),
+            rule("discount", ContentFilterAction::FlagForReview),
+        ]);
+        let messages = InMemoryMessageThreadRepository::default();
+        let publisher = RecordingPublisher::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: Some(&publisher),
+        };
+        usecase
+            .post_message(
+                &actor,
+                form_id,
+                MessageBody::new("original".to_string().try_into().unwrap()),
+                Vec::new(),
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+        let message_id = messages.only_message_id();
+        let update = |body: &str| {
+            usecase.update_message_body(
+                &actor,
+                form_id,
+                answer_id,
+                &message_id,
+                Some(MessageBody::new(body.to_string().try_into().unwrap())),
+                &repositories.content_filter_repository,
+            )
+        };
+
+        let rejected = update("cheap spam").await;
+        let body_after_rejection = messages.stored_messages()[0].body().as_str().to_owned();
+        update("a discount for you").await.unwrap();
+
+        assert_eq!(rejected, Err(DomainError::ContentRejectedByFilter.into()));
+        assert_eq!(body_after_rejection, "original");
+        assert!(matches!(
+            repositories.content_filter_repository.enforcements().as_slice(),
+            [enforcement] if matches!(
+                enforcement.flags().as_slice(),
+                [flag] if flag.target() == &ContentFilterTarget::Message(message_id)
+            )
+        ));
+        assert!(matches!(
+            publisher.0.lock().unwrap().as_slice(),
+            [ApplicationEvent::MessageCreated { .. }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn completed_answer_rejects_messages_when_form_locks_threads_on_completion() {
+        let actor = user();
+        let (form, answer) = form_and_answer(&actor);
+        let answer_settings = form
+            .answer_settings()
+            .clone()
+            .change_message_thread_lock_policy(MessageThreadLockPolicy::LockOnCompletion);
+        let form = form.change_answer_settings(answer_settings);
+        let answer = answer.change_status(AnswerStatus::COMPLETED);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let messages = InMemoryMessageThreadRepository::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        let result = usecase
+            .post_message(
+                &actor,
+                form_id,
+                MessageBody::new("late reply".to_string().try_into().unwrap()),
+                Vec::new(),
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
+            )
+            .await;
+
+        assert_eq!(result, Err(DomainError::MessageThreadLocked.into()));
+        assert_eq!(messages.message_count_for(answer_id), 0);
+    }
+
+    #[tokio::test]
+    async fn attachments_are_stored_with_the_message_and_downloadable_from_the_thread() {
+        let actor = user();
+        let (form, answer) = form_and_answer(&actor);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let messages = InMemoryMessageThreadRepository::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+        let upload = |content_type: &str| MessageAttachmentUpload {
+            file_name: "evidence.png".to_string(),
+            content_type: content_type.to_string(),
+            content: b"png bytes".to_vec(),
+        };
+
+        let rejected = usecase
+            .post_message(
+                &actor,
+                form_id,
+                MessageBody::new("see attached".to_string().try_into().unwrap()),
+                vec![upload("text/html")],
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
+            )
+            .await;
+        assert!(rejected.is_err());
+        assert_eq!(messages.message_count_for(answer_id), 0);
+
+        let mut storage = MockMessageAttachmentStorage::new();
+        storage
+            .expect_put()
+            .withf(|attachment, content| {
+                *attachment.size() == 9 && content.as_slice() == b"png bytes"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        storage
+            .expect_get()
+            .times(1)
+            .returning(|_| Ok(b"png bytes".to_vec()));
+        usecase
+            .post_message(
+                &actor,
+                form_id,
+                MessageBody::new("see attached".to_string().try_into().unwrap()),
+                vec![upload("image/png")],
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &storage,
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+        let attachment_id = *messages.stored_messages()[0].attachments()[0].id();
+
+        let (attachment, content) = usecase
+            .get_message_attachment(&actor, form_id, answer_id, attachment_id, &storage)
+            .await
+            .unwrap();
+        let missing = usecase
+            .get_message_attachment(
+                &actor,
+                form_id,
+                answer_id,
+                MessageAttachmentId::new(),
+                &storage,
+            )
+            .await;
+
+        assert_eq!(attachment.file_name(), "evidence.png");
+        assert_eq!(content, b"png bytes");
+        assert_eq!(missing, Err(MessageAttachmentNotFound.into()));
+    }
+
+    #[tokio::test]
+    async fn stored_attachments_are_discarded_when_the_message_cannot_be_saved() {
+        let actor = user();
+        let (form, answer) = form_and_answer(&actor);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let mut messages = MockMessageThreadRepository::new();
+        messages.expect_get_for_answer().returning(|form, answer| {
+            form.message_thread(answer, Vec::new(), false, false)
+                .map_err(Error::from)
+        });
+        messages.expect_append().times(1).returning(|_| {
+            Err(errors::infra::InfraError::Unexpected {
+                cause: "database is unavailable".to_string(),
+            }
+            .into())
+        });
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+        let stored = Arc::new(Mutex::new(Vec::new()));
+        let deleted = Arc::new(Mutex::new(Vec::new()));
+        let mut storage = MockMessageAttachmentStorage::new();
+        let stored_ids = Arc::clone(&stored);
+        storage
+            .expect_put()
+            .times(2)
+            .returning(move |attachment, _| {
+                stored_ids.lock().unwrap().push(*attachment.id());
+                Ok(())
+            });
+        let deleted_ids = Arc::clone(&deleted);
+        storage
+            .expect_delete()
+            .times(2)
+            .returning(move |attachment| {
+                deleted_ids.lock().unwrap().push(*attachment.id());
+                Ok(())
+            });
+        let upload = || MessageAttachmentUpload {
+            file_name: "evidence.png".to_string(),
+            content_type: "image/png".to_string(),
+            content: b"png bytes".to_vec(),
+        };
+
+        let result = usecase
+            .post_message(
+                &actor,
+                form_id,
+                MessageBody::new("see attached".to_string().try_into().unwrap()),
+                vec![upload(), upload()],
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &storage,
+                &repositories.content_filter_repository,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(*deleted.lock().unwrap(), *stored.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn template_messages_are_rendered_for_the_answer_and_limited_to_administrators() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let administrator = user();
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let (form, answer) = form_and_answer(&author);
+        let answer = answer.with_title(AnswerTitle::new(Some(
+            "ログインできない".to_string().try_into().unwrap(),
+        )));
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories.user_repository.save_user(author.clone());
+        let template = MessageTemplate::new(
+            MessageTemplateName::new("screenshot".to_string().try_into().unwrap()),
+            MessageTemplateBody::new(
+                "$username さん、「$answer_title」のスクリーンショットを送ってください。"
+                    .to_string()
+                    .try_into()
+                    .unwrap(),
+            ),
+        );
+        let template_id = *template.id();
+        let mut templates = MockMessageTemplateRepository::new();
+        templates
+            .expect_get()
+            .returning(move |_| Ok(Some(template.clone().into())));
+        let messages = InMemoryMessageThreadRepository::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        let by_author = usecase
+            .post_message_from_template(
+                &author,
+                form_id,
+                template_id,
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &templates,
+                &MockMentionRepository::new(),
+            )
+            .await;
+        usecase
+            .post_message_from_template(
+                &administrator,
+                form_id,
+                template_id,
+                answer_id,
+                &NoopNotificator,
+                &repositories.form_submission_restriction_repository,
+                &templates,
+                &MockMentionRepository::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            by_author,
+            Err(errors::domain::DomainError::Forbidden.into())
+        );
+        let stored = messages.stored_messages();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].sender_id(), Some(administrator.id()));
+        assert_eq!(
+            stored[0].body().as_str(),
+            "author さん、「ログインできない」のスクリーンショットを送ってください。"
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_thread_reads_empty_and_unknown_message_mutations_return_message_not_found() {
+        let actor = user();
+        let (form, answer) = form_and_answer(&actor);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let messages = InMemoryMessageThreadRepository::default();
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+        let unknown_message_id = MessageId::new();
+
+        let fetched = usecase
+            .get_messages(
+                &actor,
+                form_id,
+                answer_id,
+                &InMemoryMessageReadRepository::new(&messages),
+            )
+            .await
+            .unwrap();
+        let history = usecase
+            .get_history(
+                &actor,
+                form_id,
+                answer_id,
+                PageRequest::first(PageLimit::default_limit()),
+            )
+            .await
+            .unwrap();
+        let update = usecase
+            .update_message_body(
+                &actor,
+                form_id,
+                answer_id,
+                &unknown_message_id,
+                Some(MessageBody::new("updated".to_string().try_into().unwrap())),
+                &repositories.content_filter_repository,
+            )
+            .await;
+        let delete = usecase
+            .delete_message(&actor, form_id, answer_id, &unknown_message_id)
+            .await;
+
+        assert!(fetched.is_empty());
+        assert!(history.items().is_empty());
+        assert_eq!(update, Err(Error::from(MessageNotFound)));
+        assert_eq!(delete, Err(Error::from(MessageNotFound)));
+    }
+
+    #[tokio::test]
+    async fn message_cud_publishes_answer_title_and_saved_body_and_skips_empty_or_equal_updates() {
+        let user = user();
+        let (form, answer) = form_and_answer(&user);
         let answer = answer.with_title(AnswerTitle::new(Some(
             "回答タイトル".to_string().try_into().unwrap(),
         )));
@@ -838,19 +1851,37 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                 &user,
                 form_id,
                 original.clone(),
+                Vec::new(),
                 answer_id,
                 &NoopNotificator,
                 &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
             )
             .await
             .unwrap();
         let message_id = messages.only_message_id();
         usecase
-            .update_message_body(&user, form_id, answer_id, &message_id, None)
+            .update_message_body(
+                &user,
+                form_id,
+                answer_id,
+                &message_id,
+                None,
+                &repositories.content_filter_repository,
+            )
             .await
             .unwrap();
         usecase
-            .update_message_body(&user, form_id, answer_id, &message_id, Some(original))
+            .update_message_body(
+                &user,
+                form_id,
+                answer_id,
+                &message_id,
+                Some(original),
+                &repositories.content_filter_repository,
+            )
             .await
             .unwrap();
         usecase
@@ -860,6 +1891,7 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                 answer_id,
                 &message_id,
                 Some(MessageBody::new("updated".to_string().try_into().unwrap())),
+                &repositories.content_filter_repository,
             )
             .await
             .unwrap();
@@ -932,9 +1964,13 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
                 &actor,
                 form_id,
                 MessageBody::new("saved".to_string().try_into().unwrap()),
+                Vec::new(),
                 answer_id,
                 &notificator,
                 &repositories.form_submission_restriction_repository,
+                &MockMentionRepository::new(),
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
             )
             .await;
 
@@ -955,4 +1991,243 @@ https://example.com/forms/{form_id}/answers/{answer_id}?messageId={message_id}"
             [ApplicationEvent::MessageCreated { body, .. }] if body == "saved"
         ));
     }
+
+    #[tokio::test]
+    async fn mentions_notify_readable_users_once() {
+        unsafe { std::env::set_var("FRONTEND_URL", "https://example.com") };
+        let author = user();
+        let poster = AccountUser::new(
+            "poster".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let colleague = AccountUser::new(
+            "colleague".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::Administrator,
+        );
+        let outsider = AccountUser::new(
+            "outsider".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let (form, answer) = form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        for user in [&author, &poster, &colleague, &outsider] {
+            repositories.user_repository.save_user(user.clone());
+        }
+        let messages = InMemoryMessageThreadRepository::default();
+        let notificator = RecordingNotificator::default();
+        let mut mention_repository = MockMentionRepository::new();
+        let (author_id, colleague_id) = (*author.id(), *colleague.id());
+        mention_repository
+            .expect_create()
+            .withf(move |mentions| {
+                mentions
+                    .iter()
+                    .map(|mention| *mention.mentioned_user_id())
+                    .eq([author_id, colleague_id])
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        usecase
+            .post_message(
+                &poster,
+                form_id,
+                MessageBody::new(
+                    "@ADMIN @Colleague @outsider @poster @nobody"
+                        .to_string()
+                        .try_into()
+                        .unwrap(),
+                ),
+                Vec::new(),
+                answer_id,
+                &notificator,
+                &repositories.form_submission_restriction_repository,
+                &mention_repository,
+                &MockMessageAttachmentStorage::new(),
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *notificator.0.lock().unwrap(),
+            vec![
+                (author_id, NotificationType::MessageReceived),
+                (colleague_id, NotificationType::Mentioned),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn marking_messages_as_read_exposes_read_by_for_messages_from_the_other_side() {
+        let administrator = user();
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let (form, answer) = form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        repositories
+            .user_repository
+            .save_user(administrator.clone());
+        repositories.user_repository.save_user(author.clone());
+        let from_administrator = Message::new(
+            *administrator.id(),
+            MessageBody::new("question".to_string().try_into().unwrap()),
+        );
+        let from_author = Message::new(
+            *author.id(),
+            MessageBody::new("reply".to_string().try_into().unwrap()),
+        );
+        let messages = InMemoryMessageThreadRepository::with_messages(
+            answer_id,
+            vec![from_administrator.clone(), from_author.clone()],
+        );
+        let receipts = InMemoryMessageReadRepository::new(&messages);
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        usecase
+            .mark_messages_as_read(&author, form_id, answer_id, None, &receipts)
+            .await
+            .unwrap();
+        usecase
+            .mark_messages_as_read(
+                &administrator,
+                form_id,
+                answer_id,
+                Some(*from_administrator.id()),
+                &receipts,
+            )
+            .await
+            .unwrap();
+        let unknown = usecase
+            .mark_messages_as_read(
+                &author,
+                form_id,
+                answer_id,
+                Some(MessageId::new()),
+                &receipts,
+            )
+            .await;
+        let fetched = usecase
+            .get_messages(&administrator, form_id, answer_id, &receipts)
+            .await
+            .unwrap();
+
+        assert_eq!(unknown, Err(Error::from(MessageNotFound)));
+        let read_by = fetched
+            .iter()
+            .map(|message| {
+                message
+                    .read_by
+                    .iter()
+                    .map(|user| *user.id())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(read_by, vec![vec![*author.id()], Vec::new()]);
+    }
+
+    #[tokio::test]
+    async fn unread_message_counts_skip_own_messages_and_messages_already_read() {
+        let administrator = user();
+        let author = AccountUser::new(
+            "author".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let (form, answer) = form_and_answer(&author);
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository =
+            InMemoryAnswerEntryRepository::new(vec![answer.clone()]);
+        let messages = InMemoryMessageThreadRepository::with_messages(
+            answer_id,
+            vec![
+                Message::new(
+                    *administrator.id(),
+                    MessageBody::new("question".to_string().try_into().unwrap()),
+                ),
+                Message::new(
+                    *author.id(),
+                    MessageBody::new("reply".to_string().try_into().unwrap()),
+                ),
+            ],
+        );
+        let receipts = InMemoryMessageReadRepository::new(&messages);
+        let usecase = MessageUseCase {
+            notification_repository: &repositories.notification_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+        let target_for = async |reader: &AccountUser| {
+            repositories
+                .active_form_repository
+                .get(form_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .try_read(Actor::from(reader.clone()))
+                .unwrap()
+                .read_entry(answer.clone())
+                .unwrap()
+                .unread_message_count_target()
+                .unwrap()
+        };
+        let author_target = target_for(&author).await;
+        let administrator_target = target_for(&administrator).await;
+        let unread_counts = async || {
+            let counts = receipts
+                .unread_message_counts(&[author_target, administrator_target])
+                .await
+                .unwrap();
+            (counts[&author_target], counts[&administrator_target])
+        };
+
+        assert_eq!(unread_counts().await, (1, 1));
+
+        usecase
+            .mark_messages_as_read(&author, form_id, answer_id, None, &receipts)
+            .await
+            .unwrap();
+        assert_eq!(unread_counts().await, (0, 1));
+
+        messages.0.lock().unwrap().push((
+            answer_id,
+            Message::new(
+                *administrator.id(),
+                MessageBody::new("follow-up".to_string().try_into().unwrap()),
+            ),
+        ));
+        assert_eq!(unread_counts().await, (1, 1));
+    }
 }
@@ -0,0 +1,85 @@
+use domain::{
+    account::models::AccountUser,
+    auth::Actor,
+    form::message_template::{
+        MessageTemplate, MessageTemplateBody, MessageTemplateId, MessageTemplateName,
+    },
+    repository::form::message_template_repository::MessageTemplateRepository,
+    types::authorization_guard::{AuthorizationGuard, Create},
+};
+use errors::{Error, usecase::UseCaseError::MessageTemplateNotFound};
+
+pub struct MessageTemplateUseCase<'a, MessageTemplateRepo: MessageTemplateRepository> {
+    pub message_template_repository: &'a MessageTemplateRepo,
+}
+
+impl<R: MessageTemplateRepository> MessageTemplateUseCase<'_, R> {
+    pub async fn create_template(
+        &self,
+        actor: &AccountUser,
+        name: MessageTemplateName,
+        body: MessageTemplateBody,
+    ) -> Result<MessageTemplate, Error> {
+        let template = AuthorizationGuard::<_, Create>::from(MessageTemplate::new(name, body))
+            .try_create(Actor::from(actor.clone()))?;
+        let created = template.value().to_owned();
+
+        self.message_template_repository.create(template).await?;
+
+        Ok(created)
+    }
+
+    pub async fn get_templates(&self, actor: &AccountUser) -> Result<Vec<MessageTemplate>, Error> {
+        let actor = Actor::from(actor.clone());
+
+        self.message_template_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|template| {
+                template
+                    .try_read(actor.clone())
+                    .map(|template| template.into_inner())
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
+
+    pub async fn update_template(
+        &self,
+        actor: &AccountUser,
+        id: MessageTemplateId,
+        name: Option<MessageTemplateName>,
+        body: Option<MessageTemplateBody>,
+    ) -> Result<MessageTemplate, Error> {
+        let template = self
+            .message_template_repository
+            .get(id)
+            .await?
+            .ok_or(MessageTemplateNotFound)?
+            .into_update()
+            .map(|template| template.edited(name, body))
+            .try_update(Actor::from(actor.clone()))?;
+        let updated = template.value().to_owned();
+
+        self.message_template_repository.update(template).await?;
+
+        Ok(updated)
+    }
+
+    pub async fn delete_template(
+        &self,
+        actor: &AccountUser,
+        id: MessageTemplateId,
+    ) -> Result<(), Error> {
+        let template = self
+            .message_template_repository
+            .get(id)
+            .await?
+            .ok_or(MessageTemplateNotFound)?
+            .into_delete()
+            .try_delete(Actor::from(actor.clone()))?;
+
+        self.message_template_repository.delete(template).await
+    }
+}
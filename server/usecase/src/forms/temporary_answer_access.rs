@@ -0,0 +1,498 @@
+use chrono::Utc;
+use domain::{
+    account::models::AccountUser,
+    auth::Actor,
+    form::{
+        answer::{AnswerEntry, AnswerId},
+        content_filter::ContentFilterTarget,
+        message::{Message, MessageBody},
+        models::{ActiveForm, FormId},
+        temporary_answer_access::{TemporaryAnswerAccess, TemporaryAnswerAccessToken},
+        temporary_answer_claim::TemporaryAnswerClaimCode,
+    },
+    repository::{
+        form::{
+            active_form_repository::ActiveFormRepository,
+            answer_entry_repository::AnswerEntryRepository,
+            content_filter_repository::ContentFilterRepository,
+            message_read_repository::MessageReadRepository,
+            message_thread_repository::MessageThreadRepository,
+            temporary_answer_access_repository::TemporaryAnswerAccessRepository,
+            temporary_answer_claim_repository::TemporaryAnswerClaimRepository,
+        },
+        user_repository::UserRepository,
+    },
+    types::authorization_guard::{Allowed, Read},
+};
+use errors::{
+    Error,
+    usecase::UseCaseError::{
+        AnswerNotFound, FormNotFound, TemporaryAnswerAccessNotFound,
+        TemporaryAnswerClaimCodeNotFound,
+    },
+};
+
+use crate::{
+    application_event::{ApplicationActor, ApplicationEvent, ApplicationEventPublisher},
+    forms::{
+        content_filter::{enforce_verdict, screen_content},
+        message::messages_with_senders,
+    },
+    models::MessageWithSender,
+};
+
+/// ログインせずに回答した人が、回答のアクセスリンクから回答とメッセージを扱うためのユースケース。
+pub struct TemporaryAnswerAccessUseCase<
+    'a,
+    AccessRepo: TemporaryAnswerAccessRepository,
+    FormRepo: ActiveFormRepository,
+    UserRepo: UserRepository,
+    AnswerEntryRepo: AnswerEntryRepository,
+    MessageThreadRepo: MessageThreadRepository,
+> {
+    pub temporary_answer_access_repository: &'a AccessRepo,
+    pub active_form_repository: &'a FormRepo,
+    pub user_repository: &'a UserRepo,
+    pub answer_entry_repository: &'a AnswerEntryRepo,
+    pub message_thread_repository: &'a MessageThreadRepo,
+    pub application_event_publisher: Option<&'a dyn ApplicationEventPublisher>,
+}
+
+impl<
+    R1: TemporaryAnswerAccessRepository,
+    R2: ActiveFormRepository,
+    R3: UserRepository,
+    R4: AnswerEntryRepository,
+    R5: MessageThreadRepository,
+> TemporaryAnswerAccessUseCase<'_, R1, R2, R3, R4, R5>
+{
+    /// トークンに対応するアクセスリンクを、有効なものに限って取得する。
+    ///
+    /// 存在しない・取り消されたトークンは区別せず、どちらも見つからないものとして扱う。
+    async fn find_active_access(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+    ) -> Result<TemporaryAnswerAccess, Error> {
+        self.temporary_answer_access_repository
+            .find_by_token(token)
+            .await?
+            .filter(|access| !access.is_revoked())
+            .ok_or(Error::from(TemporaryAnswerAccessNotFound))
+    }
+
+    async fn read_form_and_answer_entry(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+    ) -> Result<(Actor, Allowed<ActiveForm, Read>, Allowed<AnswerEntry, Read>), Error> {
+        let access = self.find_active_access(token).await?;
+        let actor = access.actor()?;
+
+        let form = self
+            .active_form_repository
+            .get(*access.form_id())
+            .await?
+            .ok_or(FormNotFound)?
+            .try_read(actor.clone())?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, *access.answer_id())
+            .await?
+            .ok_or(AnswerNotFound)?;
+
+        Ok((actor, form, answer))
+    }
+
+    /// アクセスリンクの回答と、その回答先のフォームを取得する。
+    pub async fn get_answer(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+    ) -> Result<(ActiveForm, AnswerEntry), Error> {
+        let (_, form, answer) = self.read_form_and_answer_entry(token).await?;
+
+        Ok((form.into_inner(), answer.into_inner()))
+    }
+
+    pub async fn get_messages(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+        message_read_repository: &impl MessageReadRepository,
+    ) -> Result<Vec<MessageWithSender>, Error> {
+        let (actor, form, answer) = self.read_form_and_answer_entry(token).await?;
+        let thread = self
+            .message_thread_repository
+            .get_for_answer(&form, &answer)
+            .await?;
+
+        messages_with_senders(
+            self.user_repository,
+            message_read_repository,
+            &actor,
+            answer.value(),
+            &thread,
+        )
+        .await
+    }
+
+    /// 一時回答者としてメッセージを投稿する。
+    ///
+    /// 回答者はアカウントを持たないため、メンションや通知は行わない。
+    pub async fn post_message(
+        &self,
+        token: &TemporaryAnswerAccessToken,
+        message_body: MessageBody,
+        content_filter_repository: &impl ContentFilterRepository,
+    ) -> Result<(), Error> {
+        let (actor, form, answer) = self.read_form_and_answer_entry(token).await?;
+        let Actor::TemporaryAnswerAuthor(author) = &actor else {
+            return Err(TemporaryAnswerAccessNotFound.into());
+        };
+        let verdict = screen_content(
+            content_filter_repository,
+            *form.id(),
+            [message_body.as_str()],
+        )
+        .await?;
+        verdict.ensure_accepted()?;
+        let post = self
+            .message_thread_repository
+            .get_for_answer(&form, &answer)
+            .await?
+            .try_into_update()?
+            .try_post_message(Message::new_from_temporary_answer_author(
+                *author.id(),
+                message_body,
+            ))?;
+
+        let filter_target = ContentFilterTarget::Message(*post.message().id());
+        let message_id = post.message().id().to_string();
+        let body = post.message().body().as_str().to_owned();
+        self.message_thread_repository.append(post).await?;
+        enforce_verdict(
+            content_filter_repository,
+            &verdict,
+            *form.id(),
+            *answer.id(),
+            filter_target,
+        )
+        .await?;
+
+        if let Some(publisher) = self.application_event_publisher {
+            publisher.publish(ApplicationEvent::MessageCreated {
+                actor: ApplicationActor::from(author),
+                form_id: form.id().to_string(),
+                answer_title: answer
+                    .title()
+                    .clone()
+                    .into_inner()
+                    .map(|title| title.into_inner()),
+                answer_id: answer.id().to_string(),
+                message_id,
+                body,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// 管理者が回答に発行したアクセスリンクを取り消す。
+    pub async fn revoke(
+        &self,
+        actor: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+    ) -> Result<(), Error> {
+        let actor = Actor::from(actor.clone());
+        let form_guard = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?;
+        let form = form_guard.clone().try_read(actor.clone())?;
+        let form_update = form_guard.into_update().try_update(actor)?;
+        let entry = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?
+            .into_inner();
+        let revocation = form_update
+            .authorize_entry_update(entry)?
+            .revoke_temporary_access(Utc::now())?;
+
+        self.temporary_answer_access_repository
+            .revoke(revocation)
+            .await
+    }
+
+    /// 引き継ぎコードで一時回答者本人であることを示し、回答をログインしているユーザーのものにする。
+    ///
+    /// 存在しないコードと使用済みのコードは区別せず、どちらも見つからないものとして扱う。
+    pub async fn claim(
+        &self,
+        actor: &AccountUser,
+        code: &TemporaryAnswerClaimCode,
+        temporary_answer_claim_repository: &impl TemporaryAnswerClaimRepository,
+    ) -> Result<(FormId, AnswerId), Error> {
+        let ticket = temporary_answer_claim_repository
+            .find_by_code(code)
+            .await?
+            .filter(|ticket| !ticket.is_claimed())
+            .ok_or(TemporaryAnswerClaimCodeNotFound)?;
+        let temporary_author = ticket.actor()?;
+
+        let form = self
+            .active_form_repository
+            .get(*ticket.form_id())
+            .await?
+            .ok_or(FormNotFound)?
+            .try_read(temporary_author)?;
+        let answer = self
+            .answer_entry_repository
+            .get(&form, *ticket.answer_id())
+            .await?
+            .ok_or(AnswerNotFound)?;
+        let claim = answer.claim_temporary_answer(actor, Utc::now())?;
+
+        if !temporary_answer_claim_repository.claim(claim).await? {
+            return Err(TemporaryAnswerClaimCodeNotFound.into());
+        }
+
+        Ok((*form.id(), *answer.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use domain::{
+        account::models::{Role, UserId},
+        form::{
+            answer::{AnswerAuthor, AnswerTitle, PostedAnswerContents, TemporaryAnswerAuthor},
+            message::MessageSender,
+            models::{AllowedUserGroups, AnswerSettings, FormDescription, FormTitle, QuestionSet},
+            question::Question,
+            temporary_answer_claim::TemporaryAnswerClaimTicket,
+        },
+        repository::form::{
+            message_thread_repository::MockMessageThreadRepository,
+            temporary_answer_access_repository::MockTemporaryAnswerAccessRepository,
+            temporary_answer_claim_repository::MockTemporaryAnswerClaimRepository,
+        },
+    };
+    use errors::usecase::UseCaseError;
+    use types::non_empty_vec::NonEmptyVec;
+    use uuid::Uuid;
+
+    use crate::test_utils::repositories::{
+        FormUseCaseTestRepositories, InMemoryAnswerEntryRepository,
+    };
+
+    fn form_accepting_temporary_answers() -> ActiveForm {
+        let question = Question::new_text(
+            "body".to_string().try_into().unwrap(),
+            0,
+            "Body".to_string().try_into().unwrap(),
+            None,
+            true,
+        )
+        .unwrap();
+
+        ActiveForm::new(
+            FormTitle::new("Form".to_string().try_into().unwrap()),
+            FormDescription::new("description".to_string()),
+            QuestionSet::try_new(NonEmptyVec::try_new(vec![question]).unwrap()).unwrap(),
+        )
+        .change_answer_settings(
+            AnswerSettings::default()
+                .try_change_audience(true, AllowedUserGroups::unrestricted())
+                .unwrap(),
+        )
+    }
+
+    fn access_repository(
+        answer: &AnswerEntry,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> MockTemporaryAnswerAccessRepository {
+        let access = unsafe {
+            TemporaryAnswerAccess::from_raw_parts(
+                *answer.id(),
+                *answer.form_id(),
+                answer.author().temporary_user().unwrap().clone(),
+                Utc::now(),
+                revoked_at,
+            )
+        };
+        let mut repository = MockTemporaryAnswerAccessRepository::new();
+        repository
+            .expect_find_by_token()
+            .returning(move |_| Ok(Some(access.clone())));
+        repository
+    }
+
+    fn message_thread_repository() -> MockMessageThreadRepository {
+        let mut repository = MockMessageThreadRepository::new();
+        repository
+            .expect_get_for_answer()
+            .returning(|form, answer| {
+                form.message_thread(answer, Vec::new(), false, true)
+                    .map_err(Error::from)
+            });
+        repository
+    }
+
+    fn token() -> TemporaryAnswerAccessToken {
+        TemporaryAnswerAccessToken::try_from("0".repeat(64)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn temporary_author_replies_as_themselves_through_an_active_access_link() {
+        let form = form_accepting_temporary_answers();
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let answer = AnswerEntry::new(
+            *form.id(),
+            AnswerAuthor::Temporary(author.clone()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        );
+        let access_repository = access_repository(&answer, None);
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let mut messages = message_thread_repository();
+        let author_id = *author.id();
+        messages
+            .expect_append()
+            .withf(move |post| {
+                post.answer_author_id().is_none()
+                    && post.message().sender() == &MessageSender::TemporaryAnswerAuthor(author_id)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let usecase = TemporaryAnswerAccessUseCase {
+            temporary_answer_access_repository: &access_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        usecase
+            .post_message(
+                &token(),
+                MessageBody::new("追記します".to_string().try_into().unwrap()),
+                &repositories.content_filter_repository,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn revoked_access_link_is_treated_as_not_found() {
+        let form = form_accepting_temporary_answers();
+        let answer = AnswerEntry::new(
+            *form.id(),
+            AnswerAuthor::Temporary(TemporaryAnswerAuthor::new(
+                "guest".to_string(),
+                "contact".to_string(),
+            )),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        );
+        let access_repository = access_repository(&answer, Some(Utc::now()));
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let mut messages = message_thread_repository();
+        messages.expect_append().never();
+        let usecase = TemporaryAnswerAccessUseCase {
+            temporary_answer_access_repository: &access_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+
+        let result = usecase
+            .post_message(
+                &token(),
+                MessageBody::new("追記します".to_string().try_into().unwrap()),
+                &repositories.content_filter_repository,
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(UseCaseError::TemporaryAnswerAccessNotFound.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_code_hands_the_answer_over_once() {
+        let form = form_accepting_temporary_answers();
+        let author = TemporaryAnswerAuthor::new("guest".to_string(), "contact".to_string());
+        let answer = AnswerEntry::new(
+            *form.id(),
+            AnswerAuthor::Temporary(author.clone()),
+            AnswerTitle::default(),
+            PostedAnswerContents::try_new(&[], Vec::new()).unwrap(),
+        );
+        let ticket = |claimed_at| unsafe {
+            TemporaryAnswerClaimTicket::from_raw_parts(
+                *answer.id(),
+                *answer.form_id(),
+                author.clone(),
+                Utc::now(),
+                claimed_at,
+            )
+        };
+        let (unused, used) = (ticket(None), ticket(Some(Utc::now())));
+        let user = AccountUser::new(
+            "player".to_string(),
+            UserId::from(Uuid::new_v4()),
+            Role::StandardUser,
+        );
+        let user_id = *user.id();
+        let temporary_user_id = *author.id();
+        let mut claim_repository = MockTemporaryAnswerClaimRepository::new();
+        claim_repository
+            .expect_find_by_code()
+            .times(1)
+            .returning(move |_| Ok(Some(unused.clone())));
+        claim_repository
+            .expect_find_by_code()
+            .returning(move |_| Ok(Some(used.clone())));
+        claim_repository
+            .expect_claim()
+            .withf(move |claim| {
+                claim.claimed_by() == &user_id
+                    && claim.temporary_author().id() == &temporary_user_id
+            })
+            .times(1)
+            .returning(|_| Ok(true));
+        let access_repository = MockTemporaryAnswerAccessRepository::new();
+        let form_id = *form.id();
+        let answer_id = *answer.id();
+        let mut repositories = FormUseCaseTestRepositories::with_active_forms(vec![form]);
+        repositories.answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
+        let messages = MockMessageThreadRepository::new();
+        let usecase = TemporaryAnswerAccessUseCase {
+            temporary_answer_access_repository: &access_repository,
+            active_form_repository: &repositories.active_form_repository,
+            user_repository: &repositories.user_repository,
+            answer_entry_repository: &repositories.answer_entry_repository,
+            message_thread_repository: &messages,
+            application_event_publisher: None,
+        };
+        let code = TemporaryAnswerClaimCode::try_from("0".repeat(64)).unwrap();
+
+        assert_eq!(
+            usecase.claim(&user, &code, &claim_repository).await,
+            Ok((form_id, answer_id))
+        );
+        assert_eq!(
+            usecase.claim(&user, &code, &claim_repository).await,
+            Err(UseCaseError::TemporaryAnswerClaimCodeNotFound.into())
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod announcement;
 pub mod application_event;
 pub mod form_submission_restriction;
 pub mod forms;
@@ -7,6 +8,8 @@ pub mod minecraft_ban;
 pub mod models;
 pub mod notification;
 pub mod search;
+pub mod submission_ip_blocklist;
+pub mod support_metrics;
 #[cfg(test)]
 pub(crate) mod test_utils;
 pub mod user;
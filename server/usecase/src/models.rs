@@ -1,18 +1,22 @@
 use chrono::{DateTime, Utc};
 use domain::{
-    account::models::{AccountUser, DiscordUser},
+    account::models::{AccountUser, DiscordUser, UserId},
+    announcement::Announcement,
     form::{
         answer::{
-            AnswerEntry, AnswerId, AnswerLabel, AnswerPublication, AnswerStatus, AnswerTitle,
-            FormAnswerContent, RedmineImportedAnswerReference, RedmineUserSnapshot,
-            TemporaryAnswerAuthor,
+            AnswerEntry, AnswerId, AnswerLabel, AnswerModerationStatus, AnswerPublication,
+            AnswerQuizScore, AnswerReference, AnswerStatus, AnswerTitle, FormAnswerContent,
+            RedmineImportedAnswerReference, RedmineUserSnapshot, TemporaryAnswerAuthor,
         },
         comment::Comment,
         message::Message,
         models::{ActiveForm, ArchivedForm, FormId, FormLabel},
         question::{Question, QuestionId},
+        temporary_answer_access::TemporaryAnswerAccessToken,
+        temporary_answer_claim::TemporaryAnswerClaimCode,
     },
 };
+use errors::Error;
 
 pub enum PublishedAnswerAuthor {
     AuthenticatedUser(AccountUser),
@@ -28,12 +32,21 @@ pub struct PublishedAnswerEntry {
     pub title: AnswerTitle,
     pub publication: AnswerPublication,
     pub status: AnswerStatus,
+    pub moderation_status: AnswerModerationStatus,
     pub contents: Vec<FormAnswerContent>,
     pub redmine_reference: Option<RedmineImportedAnswerReference>,
+    pub assignee: Option<UserId>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub quiz_score: Option<AnswerQuizScore>,
 }
 
 impl PublishedAnswerEntry {
-    pub fn new(answer: AnswerEntry, author: PublishedAnswerAuthor) -> Self {
+    pub fn new(
+        answer: AnswerEntry,
+        author: PublishedAnswerAuthor,
+        assignee: Option<UserId>,
+        due_at: Option<DateTime<Utc>>,
+    ) -> Self {
         Self {
             id: *answer.id(),
             author,
@@ -41,16 +54,33 @@ impl PublishedAnswerEntry {
             title: answer.title().to_owned(),
             publication: *answer.publication(),
             status: *answer.status(),
+            moderation_status: *answer.moderation_status(),
             contents: answer.contents().to_vec(),
             redmine_reference: answer.redmine_reference().to_owned(),
+            assignee,
+            due_at,
+            quiz_score: None,
         }
     }
+
+    /// 閲覧者に見せてよいクイズの点数を設定します。
+    pub fn with_quiz_score(self, quiz_score: Option<AnswerQuizScore>) -> Self {
+        Self { quiz_score, ..self }
+    }
 }
 
 pub struct AnswerDetails {
     pub form_id: FormId,
     pub answer: PublishedAnswerEntry,
     pub labels: Vec<AnswerLabel>,
+    /// 閲覧者から見た未読メッセージ数。メッセージスレッドを閲覧できない場合は `None`
+    pub unread_message_count: Option<u32>,
+}
+
+/// 回答の一括操作における、回答 1 件ごとの結果です。
+pub struct BulkAnswerOperationResult {
+    pub answer: AnswerReference,
+    pub result: Result<(), Error>,
 }
 
 pub struct ActiveFormWithLabels {
@@ -79,9 +109,33 @@ pub struct CrossSearchComment {
     pub comment: CommentWithAuthor,
 }
 
+/// ログインせずに投稿した回答の受付結果。
+pub struct PostedTemporaryAnswer {
+    pub quiz_score: Option<AnswerQuizScore>,
+    /// 回答のアクセスリンク用のトークン。平文を得られるのはこのときだけ。
+    pub access_token: TemporaryAnswerAccessToken,
+    /// 回答をアカウントに引き継ぐためのコード。平文を得られるのはこのときだけ。
+    pub claim_code: TemporaryAnswerClaimCode,
+}
+
+/// メッセージの送信者。一時回答の回答者はアカウントを持たない。
+pub enum MessageSenderProfile {
+    User(AccountUser),
+    TemporaryAnswerAuthor(TemporaryAnswerAuthor),
+}
+
 pub struct MessageWithSender {
     pub message: Message,
-    pub sender: AccountUser,
+    pub sender: MessageSenderProfile,
+    /// 送信者以外で、このメッセージを既読にしたユーザー
+    pub read_by: Vec<AccountUser>,
+}
+
+/// メッセージに添付するためにアップロードされたファイル
+pub struct MessageAttachmentUpload {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
 }
 
 pub struct UpsertQuestionInput {
@@ -101,4 +155,5 @@ pub struct CrossSearchOutput {
     pub label_for_forms: Vec<FormLabel>,
     pub label_for_answers: Vec<AnswerLabel>,
     pub comments: Vec<CrossSearchComment>,
+    pub announcements: Vec<Announcement>,
 }
@@ -1,6 +1,6 @@
 use domain::types::authorization_guard::{AuthorizationGuard, Create, Read};
 use domain::{
-    account::models::AccountUser,
+    account::models::{AccountUser, UserId},
     auth::Actor,
     notification::models::NotificationPreference,
     repository::{
@@ -10,6 +10,47 @@ use domain::{
 use errors::{Error, usecase::UseCaseError};
 use uuid::Uuid;
 
+/// 通知先ユーザーの通知設定を取得します。
+/// 設定がまだ作られていなければ、既定値で作成してから返します。
+pub(crate) async fn notification_preference_for<
+    NotificationRepo: NotificationRepository,
+    UserRepo: UserRepository,
+>(
+    notification_repository: &NotificationRepo,
+    user_repository: &UserRepo,
+    actor: &Actor,
+    recipient_id: UserId,
+) -> Result<NotificationPreference, Error> {
+    let fetched_notification_preference = notification_repository
+        .fetch_notification_settings(recipient_id.into_inner())
+        .await?;
+
+    match fetched_notification_preference {
+        Some(settings) => Ok(settings.try_read(Actor::System)?.into_inner()),
+        None => {
+            let recipient = user_repository
+                .find_by(recipient_id.into_inner())
+                .await?
+                .ok_or(Error::from(UseCaseError::UserNotFound))?
+                .try_read(actor.clone())?
+                .into_inner();
+
+            let preference = NotificationPreference::new(*recipient.id());
+
+            notification_repository
+                .create_notification_settings(
+                    AuthorizationGuard::<_, Create>::from(preference.clone())
+                        .try_create(Actor::from(recipient))?,
+                )
+                .await?;
+
+            Ok(AuthorizationGuard::<_, Read>::from(preference)
+                .try_read(Actor::System)?
+                .into_inner())
+        }
+    }
+}
+
 pub struct NotificationUseCase<
     'a,
     NotificationRepo: NotificationRepository,
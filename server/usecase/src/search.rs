@@ -5,6 +5,7 @@ use crate::{
     },
     user_reference_resolver::resolve_user_references,
 };
+use domain::repository::announcement_repository::AnnouncementRepository;
 use domain::repository::form::answer_entry_repository::AnswerEntryRepository;
 use domain::repository::form::answer_label_repository::AnswerLabelRepository;
 use domain::repository::form::comment_thread_repository::CommentThreadRepository;
@@ -12,9 +13,13 @@ use domain::repository::form::form_label_repository::FormLabelRepository;
 use domain::repository::user_repository::UserRepository;
 use domain::{
     account::models::AccountUser,
+    announcement::Announcement,
     auth::Actor,
     form::{
-        answer::{AnswerAuthor, AnswerAuthorDisclosure, AnswerEntry, AnswerId, AnswerStatus},
+        answer::{
+            AnswerAuthor, AnswerAuthorDisclosure, AnswerEntry, AnswerId, AnswerListFilter,
+            AnswerStatus,
+        },
         comment::Comment,
         comment_thread::CommentThread,
         models::{ActiveForm, FormId},
@@ -24,14 +29,18 @@ use domain::{
         form::active_form_repository::ActiveFormRepository, search_repository::SearchRepository,
     },
     search::models::{
-        AnswerSearchHit, AnswerTitleSearchDocument, FormAnswerComments, FormMetaData,
-        LabelForFormAnswers, LabelForForms, NumberOfRecords, NumberOfRecordsPerAggregate,
-        Operation, RealAnswers, SearchableFields, SearchableFieldsWithOperation, UserSearchHit,
-        Users,
+        AnnouncementSearchHit, Announcements, AnswerSearchHit, AnswerTitleSearchDocument,
+        FormAnswerComments, FormMetaData, LabelForFormAnswers, LabelForForms, NumberOfRecords,
+        NumberOfRecordsPerAggregate, Operation, RealAnswers, SearchableFields,
+        SearchableFieldsWithOperation, SimilarAnswerLimit, UserSearchHit, Users,
     },
     types::authorization_guard::{Allowed, AuthorizationGuard, Read},
 };
-use errors::{Error, domain::DomainError};
+use errors::{
+    Error,
+    domain::DomainError,
+    usecase::UseCaseError::{AnswerNotFound, FormNotFound},
+};
 use futures::{StreamExt, TryStreamExt, stream, try_join};
 use std::{
     collections::{HashMap, HashSet},
@@ -46,6 +55,31 @@ use tracing::Instrument;
 
 const SEARCH_DETAIL_FETCH_CONCURRENCY: usize = 10;
 
+/// 類似回答の検索クエリに使う文字数の上限。検索エンジン側でもクエリの先頭の語しか使われない。
+const SIMILAR_ANSWER_QUERY_MAX_CHARS: usize = 500;
+
+/// 回答のタイトルと回答内容をつなげて、類似回答の検索クエリを組み立てる。
+fn similar_answer_query(answer: &AnswerEntry) -> String {
+    answer
+        .title()
+        .clone()
+        .into_inner()
+        .map(|title| title.into_inner())
+        .into_iter()
+        .chain(
+            answer
+                .contents()
+                .iter()
+                .map(|content| content.answer.clone()),
+        )
+        .filter(|text| !text.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(SIMILAR_ANSWER_QUERY_MAX_CHARS)
+        .collect()
+}
+
 pub struct SearchUseCase<
     'a,
     SearchRepo: SearchRepository,
@@ -55,6 +89,7 @@ pub struct SearchUseCase<
     UserRepo: UserRepository,
     AnswerEntryRepo: AnswerEntryRepository,
     CommentThreadRepo: CommentThreadRepository,
+    AnnouncementRepo: AnnouncementRepository,
 > {
     pub search_repository: &'a SearchRepo,
     pub active_form_repository: &'a FormRepo,
@@ -63,6 +98,7 @@ pub struct SearchUseCase<
     pub user_repository: &'a UserRepo,
     pub answer_entry_repository: &'a AnswerEntryRepo,
     pub comment_thread_repository: &'a CommentThreadRepo,
+    pub announcement_repository: &'a AnnouncementRepo,
 }
 
 impl<
@@ -73,7 +109,8 @@ impl<
     R5: UserRepository,
     R6: AnswerEntryRepository,
     R7: CommentThreadRepository,
-> SearchUseCase<'_, R1, R2, R3, R4, R5, R6, R7>
+    R8: AnnouncementRepository,
+> SearchUseCase<'_, R1, R2, R3, R4, R5, R6, R7, R8>
 {
     async fn list_all_form_guards(
         &self,
@@ -91,7 +128,7 @@ impl<
         loop {
             let page = self
                 .answer_entry_repository
-                .list_all(forms, request, None)
+                .list_all(forms, request, AnswerListFilter::default())
                 .await?;
             let (items, next) = page.into_parts();
             answers.extend(items);
@@ -250,7 +287,7 @@ impl<
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Some(ActiveFormWithLabels {
-            form: form.into_inner(),
+            form: form.into_visible_form(),
             labels,
         }))
     }
@@ -310,10 +347,14 @@ impl<
             }
         };
 
+        let viewer = Actor::from(account_user.clone());
+        let assignee = answer.assignee_visible_to(&viewer);
+        let due_at = answer.due_at_visible_to(&viewer);
         Ok(Some(AnswerDetails {
             form_id,
-            answer: PublishedAnswerEntry::new(answer.into_inner(), author),
+            answer: PublishedAnswerEntry::new(answer.into_inner(), author, assignee, due_at),
             labels,
+            unread_message_count: None,
         }))
     }
 
@@ -338,6 +379,31 @@ impl<
             .await
     }
 
+    async fn visible_announcements(
+        &self,
+        actor: &Actor,
+        hits: Vec<AnnouncementSearchHit>,
+    ) -> Result<Vec<Announcement>, Error> {
+        stream::iter(hits)
+            .map(|hit| async move {
+                self.announcement_repository
+                    .get(hit.announcement_id)
+                    .await
+                    .map(|guard| {
+                        guard.and_then(|guard| {
+                            guard
+                                .try_read(actor.clone())
+                                .ok()
+                                .map(|announcement| announcement.into_inner())
+                        })
+                    })
+            })
+            .buffered(SEARCH_DETAIL_FETCH_CONCURRENCY)
+            .try_filter_map(|visible| ready(Ok(visible)))
+            .try_collect()
+            .await
+    }
+
     async fn cross_search_comments_with_authors(
         &self,
         account_user: &AccountUser,
@@ -415,20 +481,70 @@ impl<
             .await
     }
 
+    /// 指定した回答と同じフォームに投稿された、内容の似ている回答を類似度順に返す。
+    ///
+    /// 重複報告を探す用途のため、提案には閲覧を認可できる回答だけを含める。
+    pub async fn similar_answers(
+        &self,
+        account_user: &AccountUser,
+        form_id: FormId,
+        answer_id: AnswerId,
+        limit: SimilarAnswerLimit,
+    ) -> Result<Vec<AnswerDetails>, Error> {
+        let actor = Actor::from(account_user.clone());
+        let form = self
+            .active_form_repository
+            .get(form_id)
+            .await?
+            .ok_or(FormNotFound)?
+            .try_read(actor.clone())?;
+        let source = self
+            .answer_entry_repository
+            .get(&form, answer_id)
+            .await?
+            .ok_or(AnswerNotFound)?;
+
+        let query = similar_answer_query(&source);
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let hits = self
+            .search_repository
+            .search_similar_answers(&query, form_id, answer_id, limit)
+            .await?;
+        let answer_ids = unique_answer_ids(hits.iter().map(|hit| hit.answer_id));
+        let visible_answers_by_id = self
+            .answer_entry_repository
+            .find_by_ids(std::slice::from_ref(&form), answer_ids)
+            .await?
+            .into_iter()
+            .map(|answer| (*answer.id(), answer))
+            .collect();
+        let mut answers = self
+            .visible_answer_details(account_user, &actor, hits, &visible_answers_by_id)
+            .await?;
+        answers.truncate(limit.value() as usize);
+
+        Ok(answers)
+    }
+
     pub async fn cross_search(
         &self,
         account_user: &AccountUser,
         query: String,
     ) -> Result<CrossSearchOutput, Error> {
         let actor = Actor::from(account_user.clone());
-        let (forms, users, label_for_forms, label_for_answers, answers, comments) = try_join!(
-            self.search_repository.search_forms(&query),
-            self.search_repository.search_users(&query),
-            self.search_repository.search_labels_for_forms(&query),
-            self.search_repository.search_labels_for_answers(&query),
-            self.search_repository.search_answers(&query, None, None),
-            self.search_repository.search_comments(&query)
-        )?;
+        let (forms, users, label_for_forms, label_for_answers, answers, comments, announcements) =
+            try_join!(
+                self.search_repository.search_forms(&query),
+                self.search_repository.search_users(&query),
+                self.search_repository.search_labels_for_forms(&query),
+                self.search_repository.search_labels_for_answers(&query),
+                self.search_repository.search_answers(&query, None, None),
+                self.search_repository.search_comments(&query),
+                self.search_repository.search_announcements(&query)
+            )?;
 
         let actor_ref = &actor;
         let answer_ids = unique_answer_ids(
@@ -509,10 +625,9 @@ impl<
 
                     Ok::<_, Error>(
                         thread
-                            .comments()
-                            .iter()
+                            .readable_comments()
+                            .into_iter()
                             .find(|loaded| *loaded.comment_id() == comment.comment_id)
-                            .cloned()
                             .map(|comment| (form_id, comment)),
                     )
                 }
@@ -524,6 +639,7 @@ impl<
         let visible_comments = self
             .cross_search_comments_with_authors(account_user, visible_comments)
             .await?;
+        let visible_announcements = self.visible_announcements(actor_ref, announcements).await?;
 
         Ok(CrossSearchOutput {
             forms: visible_forms,
@@ -532,6 +648,7 @@ impl<
             label_for_answers: visible_label_for_answers,
             answers: visible_answers,
             comments: visible_comments,
+            announcements: visible_announcements,
         })
     }
 
@@ -603,6 +720,7 @@ impl<
             ),
             label_for_forms: NumberOfRecords(self.form_label_repository.size().await?),
             users: NumberOfRecords(self.user_repository.size().await?),
+            announcements: NumberOfRecords(self.announcement_repository.size().await?),
         };
 
         let sync_rate = search_engine_records.try_into_sync_rate(&repository_records)?;
@@ -693,6 +811,25 @@ impl<
                 })
                 .collect::<Result<Vec<_>, Error>>()?;
 
+            let announcements = self
+                .announcement_repository
+                .list()
+                .await?
+                .into_iter()
+                .map(|guard| {
+                    let announcement = guard.try_read(system.clone())?.into_inner();
+
+                    Ok((
+                        SearchableFields::Announcements(Announcements {
+                            id: *announcement.id(),
+                            title: announcement.title().to_string(),
+                            body: announcement.body().to_string(),
+                        }),
+                        Operation::Update,
+                    ))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
             let data = forms
                 .into_iter()
                 .chain(answer_documents)
@@ -700,6 +837,7 @@ impl<
                 .chain(labels_for_forms)
                 .chain(labels_for_answers)
                 .chain(users)
+                .chain(announcements)
                 .collect::<Vec<_>>();
 
             self.search_repository
@@ -737,8 +875,10 @@ fn answer_search_documents(
 ) -> Vec<SearchableFieldsWithOperation> {
     answer_entries
         .iter()
+        .map(|entry| entry.value())
+        // 確認待ちや却下された回答は検索に載せない
+        .filter(|entry| entry.moderation_status().is_approved())
         .flat_map(|entry| {
-            let entry = entry.value();
             once((
                 SearchableFields::AnswerTitle(AnswerTitleSearchDocument {
                     id: *entry.id(),
@@ -789,11 +929,12 @@ mod tests {
                 AnswerSettings, AnswerTitle, AnswerVisibility, FormAnswerContent,
                 FormAnswerContentId, PostedAnswerContents,
             },
-            comment::{Comment, CommentContent, CommentId},
+            comment::{Comment, CommentContent, CommentId, CommentVisibility},
             models::{AllowedUserGroups, FormDescription, FormSettings, FormTitle},
             question::{Question, QuestionSet},
         },
         repository::{
+            announcement_repository::MockAnnouncementRepository,
             form::{
                 answer_entry_repository::MockAnswerEntryRepository,
                 answer_label_repository::MockAnswerLabelRepository,
@@ -873,6 +1014,9 @@ mod tests {
         search_repository
             .expect_search_comments()
             .returning(|_| Ok(vec![]));
+        search_repository
+            .expect_search_announcements()
+            .returning(|_| Ok(vec![]));
 
         let active_form_repository =
             InMemoryActiveFormRepository::new(vec![hidden_form, readable_form]);
@@ -884,6 +1028,7 @@ mod tests {
         let user_repository = InMemoryUserRepository::default();
         let answer_entry_repository = InMemoryAnswerEntryRepository::default();
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -892,6 +1037,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let output = use_case
@@ -1015,6 +1161,7 @@ mod tests {
                     .collect())
             });
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1023,6 +1170,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let answers = use_case
@@ -1091,6 +1239,7 @@ mod tests {
         let user_repository = InMemoryUserRepository::default();
         let answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![answer]);
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1099,6 +1248,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let answers = use_case
@@ -1114,6 +1264,118 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn similar_answers_searches_with_the_source_answer_and_returns_readable_hits() {
+        let member_group = UserGroup::new(UserGroupName::new(
+            "members".to_string().try_into().unwrap(),
+        ));
+        let actor = AccountUser::with_groups(
+            "viewer".to_string(),
+            Uuid::from_u128(20).into(),
+            Role::StandardUser,
+            vec![member_group.clone()],
+        );
+        let reporter = AccountUser::new(
+            "reporter".to_string(),
+            Uuid::from_u128(21).into(),
+            Role::StandardUser,
+        );
+        let form = form_restricted_to("bug reports", &member_group).change_answer_settings(
+            AnswerSettings::default().change_visibility(AnswerVisibility::PUBLIC),
+        );
+        let form_id = *form.id();
+        let question_id = *form.questions().as_slice()[0].id();
+        let answer_with = |title: &str, body: &str| {
+            AnswerEntry::new(
+                form_id,
+                AnswerAuthor::AuthenticatedUser(*reporter.id()),
+                AnswerTitle::new(Some(title.to_string().try_into().unwrap())),
+                PostedAnswerContents::try_new(
+                    form.questions().as_slice(),
+                    vec![FormAnswerContent {
+                        id: FormAnswerContentId::from(Uuid::new_v4()),
+                        question_id: question_id.into(),
+                        answer: body.to_string(),
+                    }],
+                )
+                .unwrap(),
+            )
+        };
+        let source = answer_with("Server crash", "crashes on login");
+        let private_answer =
+            answer_with("Crash", "crashes on login").change_publication(AnswerPublication::PRIVATE);
+        let first_similar = answer_with("Crash again", "login crash");
+        let second_similar = answer_with("Another crash", "crash");
+        let source_id = *source.id();
+        let private_answer_id = *private_answer.id();
+        let first_similar_id = *first_similar.id();
+        let second_similar_id = *second_similar.id();
+
+        let mut search_repository = MockSearchRepository::new();
+        search_repository
+            .expect_search_similar_answers()
+            .withf(move |query, searched_form_id, exclude, _| {
+                query == "Server crash crashes on login"
+                    && *searched_form_id == form_id
+                    && *exclude == source_id
+            })
+            .return_once(move |_, _, _, _| {
+                Ok(vec![
+                    AnswerSearchHit {
+                        answer_id: private_answer_id,
+                    },
+                    AnswerSearchHit {
+                        answer_id: first_similar_id,
+                    },
+                    AnswerSearchHit {
+                        answer_id: second_similar_id,
+                    },
+                ])
+            });
+        let active_form_repository = InMemoryActiveFormRepository::new(vec![form]);
+        let mut answer_label_repository = MockAnswerLabelRepository::new();
+        answer_label_repository
+            .expect_get_labels_for_answers_by_answer_id()
+            .returning(|_| Ok(vec![]));
+        let form_label_repository = InMemoryFormLabelRepository;
+        let user_repository = InMemoryUserRepository::default();
+        user_repository.save_user(reporter.clone());
+        let answer_entry_repository = InMemoryAnswerEntryRepository::new(vec![
+            source,
+            private_answer,
+            first_similar,
+            second_similar,
+        ]);
+        let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
+        let use_case = SearchUseCase {
+            search_repository: &search_repository,
+            active_form_repository: &active_form_repository,
+            form_answer_label_repository: &answer_label_repository,
+            form_label_repository: &form_label_repository,
+            user_repository: &user_repository,
+            answer_entry_repository: &answer_entry_repository,
+            comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
+        };
+
+        let answers = use_case
+            .similar_answers(
+                &actor,
+                form_id,
+                source_id,
+                SimilarAnswerLimit::try_new(1).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let answer_ids = answers
+            .iter()
+            .map(|answer| answer.answer.id)
+            .collect::<Vec<_>>();
+        assert_eq!(answer_ids, vec![first_similar_id]);
+    }
+
     #[tokio::test]
     async fn search_answers_returns_empty_without_searching_for_a_missing_form() {
         let actor = AccountUser::new(
@@ -1129,6 +1391,7 @@ mod tests {
         let user_repository = InMemoryUserRepository::default();
         let answer_entry_repository = InMemoryAnswerEntryRepository::default();
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1137,6 +1400,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let answers = use_case
@@ -1166,6 +1430,7 @@ mod tests {
         let user_repository = InMemoryUserRepository::default();
         let answer_entry_repository = InMemoryAnswerEntryRepository::default();
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1174,6 +1439,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let answers = use_case
@@ -1254,6 +1520,9 @@ mod tests {
         search_repository
             .expect_search_comments()
             .returning(|_| Ok(vec![]));
+        search_repository
+            .expect_search_announcements()
+            .returning(|_| Ok(vec![]));
 
         let active_form_repository = InMemoryActiveFormRepository::new(vec![form]);
         let mut answer_label_repository = MockAnswerLabelRepository::new();
@@ -1266,6 +1535,7 @@ mod tests {
         let answer_entry_repository =
             InMemoryAnswerEntryRepository::new(vec![missing_author_answer, visible_answer]);
         let comment_repository = MockCommentThreadRepository::new();
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1274,6 +1544,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let output = use_case
@@ -1326,6 +1597,7 @@ mod tests {
                 CommentContent::new(content.to_string().try_into().unwrap()),
                 Utc::now(),
                 commented_by,
+                CommentVisibility::Public,
             )
         };
         let first_comment = comment(first_comment_id, *actor.id(), "first");
@@ -1375,6 +1647,9 @@ mod tests {
                     },
                 ])
             });
+        search_repository
+            .expect_search_announcements()
+            .returning(|_| Ok(vec![]));
 
         let active_form_repository = InMemoryActiveFormRepository::new(vec![form]);
         let mut answer_label_repository = MockAnswerLabelRepository::new();
@@ -1404,6 +1679,7 @@ mod tests {
                 form.comment_thread_with_comments(answer, stored_comments.clone())
                     .map_err(Error::from)
             });
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1412,6 +1688,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let output = use_case
@@ -1503,6 +1780,7 @@ mod tests {
                 CommentContent::new(content.to_string().try_into().unwrap()),
                 Utc::now(),
                 commented_by,
+                CommentVisibility::Public,
             )
         };
         let first_comment = comment(answer_a_id, first_comment_id, *actor.id(), "first");
@@ -1572,6 +1850,9 @@ mod tests {
                     },
                 ])
             });
+        search_repository
+            .expect_search_announcements()
+            .returning(|_| Ok(vec![]));
 
         let active_form_repository =
             InMemoryActiveFormRepository::new(vec![form_a, form_b, unreadable_form]);
@@ -1602,6 +1883,7 @@ mod tests {
                 form.comment_thread_with_comments(answer, comments)
                     .map_err(Error::from)
             });
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1610,6 +1892,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let output = use_case
@@ -1687,6 +1970,9 @@ mod tests {
                     answer_id,
                 }])
             });
+        search_repository
+            .expect_search_announcements()
+            .returning(|_| Ok(vec![]));
 
         let active_form_repository = InMemoryActiveFormRepository::new(vec![form]);
         let mut answer_label_repository = MockAnswerLabelRepository::new();
@@ -1704,6 +1990,7 @@ mod tests {
                 form.comment_thread_with_comments(answer, Vec::new())
                     .map_err(Error::from)
             });
+        let announcement_repository = MockAnnouncementRepository::new();
         let use_case = SearchUseCase {
             search_repository: &search_repository,
             active_form_repository: &active_form_repository,
@@ -1712,6 +1999,7 @@ mod tests {
             user_repository: &user_repository,
             answer_entry_repository: &answer_entry_repository,
             comment_thread_repository: &comment_repository,
+            announcement_repository: &announcement_repository,
         };
 
         let output = use_case
@@ -0,0 +1,186 @@
+use std::net::IpAddr;
+
+use chrono::Utc;
+use domain::{
+    account::models::AccountUser,
+    auth::Actor,
+    repository::submission_ip_blocklist_repository::SubmissionIpBlocklistRepository,
+    submission_ip_blocklist::{BlockedIpRange, SubmissionIpBlock, SubmissionIpBlockId},
+    types::authorization_guard::{AuthorizationGuard, Create},
+};
+use errors::{Error, domain::DomainError, usecase::UseCaseError::BlockedIpRangeNotFound};
+use types::non_empty_string::NonEmptyString;
+
+pub struct SubmissionIpBlocklistUseCase<'a, Repo: SubmissionIpBlocklistRepository> {
+    pub repository: &'a Repo,
+}
+
+impl<Repo: SubmissionIpBlocklistRepository> SubmissionIpBlocklistUseCase<'_, Repo> {
+    pub async fn list(&self, actor: &Actor) -> Result<Vec<SubmissionIpBlock>, Error> {
+        self.repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|block| {
+                block
+                    .try_read(actor.clone())
+                    .map(|block| block.into_inner())
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    pub async fn add(
+        &self,
+        actor: &AccountUser,
+        range: BlockedIpRange,
+        reason: Option<NonEmptyString>,
+    ) -> Result<SubmissionIpBlock, Error> {
+        let actor_ref = Actor::from(actor.clone());
+        let block = AuthorizationGuard::<_, Create>::from(SubmissionIpBlock::new(
+            range,
+            reason,
+            *actor.id(),
+            Utc::now(),
+        ))
+        .try_create(actor_ref.clone())?;
+
+        if self
+            .list(&actor_ref)
+            .await?
+            .iter()
+            .any(|existing| existing.range() == block.range())
+        {
+            return Err(DomainError::BlockedIpRangeAlreadyExists.into());
+        }
+
+        let created = block.value().clone();
+        self.repository.create(block).await?;
+
+        Ok(created)
+    }
+
+    pub async fn remove(&self, actor: &Actor, id: SubmissionIpBlockId) -> Result<(), Error> {
+        let block = self
+            .repository
+            .get(id)
+            .await?
+            .ok_or(BlockedIpRangeNotFound)?
+            .into_delete()
+            .try_delete(actor.clone())?;
+
+        self.repository.delete(block).await
+    }
+
+    /// 送信元の IP アドレスが、登録された範囲のどれかに含まれるか。
+    pub async fn is_blocked(&self, ip: IpAddr) -> Result<bool, Error> {
+        Ok(self
+            .list(&Actor::System)
+            .await?
+            .iter()
+            .any(|block| block.blocks(ip)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::{
+        account::models::{Role, UserId},
+        repository::submission_ip_blocklist_repository::MockSubmissionIpBlocklistRepository,
+        types::authorization_guard::Read,
+    };
+    use uuid::Uuid;
+
+    fn user(role: Role) -> AccountUser {
+        AccountUser::new("user".to_string(), UserId::from(Uuid::new_v4()), role)
+    }
+
+    fn blocked(range: &str) -> SubmissionIpBlock {
+        SubmissionIpBlock::new(
+            range.parse().unwrap(),
+            None,
+            UserId::from(Uuid::new_v4()),
+            Utc::now(),
+        )
+    }
+
+    fn repository_with(ranges: &'static [&'static str]) -> MockSubmissionIpBlocklistRepository {
+        let mut repository = MockSubmissionIpBlocklistRepository::new();
+        repository.expect_list().returning(move || {
+            Ok(ranges
+                .iter()
+                .map(|range| AuthorizationGuard::<_, Read>::from(blocked(range)))
+                .collect())
+        });
+        repository
+    }
+
+    #[tokio::test]
+    async fn addresses_in_a_blocked_range_are_blocked() {
+        let repository = repository_with(&["203.0.113.0/24", "2001:db8::/32"]);
+        let usecase = SubmissionIpBlocklistUseCase {
+            repository: &repository,
+        };
+
+        assert!(
+            usecase
+                .is_blocked("203.0.113.9".parse().unwrap())
+                .await
+                .unwrap()
+        );
+        assert!(
+            usecase
+                .is_blocked("2001:db8::1".parse().unwrap())
+                .await
+                .unwrap()
+        );
+        assert!(
+            !usecase
+                .is_blocked("198.51.100.1".parse().unwrap())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn administrator_cannot_block_the_same_range_twice() {
+        let mut repository = repository_with(&["203.0.113.0/24"]);
+        repository.expect_create().never();
+        let usecase = SubmissionIpBlocklistUseCase {
+            repository: &repository,
+        };
+
+        assert_eq!(
+            usecase
+                .add(
+                    &user(Role::Administrator),
+                    "203.0.113.0/24".parse().unwrap(),
+                    None,
+                )
+                .await,
+            Err(DomainError::BlockedIpRangeAlreadyExists.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn standard_user_cannot_manage_the_blocklist() {
+        let mut repository = repository_with(&["203.0.113.0/24"]);
+        repository.expect_create().never();
+        let usecase = SubmissionIpBlocklistUseCase {
+            repository: &repository,
+        };
+        let standard_user = user(Role::StandardUser);
+
+        assert_eq!(
+            usecase.list(&Actor::from(standard_user.clone())).await,
+            Err(DomainError::Forbidden.into())
+        );
+        assert_eq!(
+            usecase
+                .add(&standard_user, "198.51.100.0/24".parse().unwrap(), None)
+                .await,
+            Err(DomainError::Forbidden.into())
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    auth::Actor,
+    repository::support_metrics_repository::SupportMetricsRepository,
+    support_metrics::{SupportMetrics, SupportMetricsPeriod, ensure_support_metrics_readable},
+};
+use errors::Error;
+
+pub struct SupportMetricsUseCase<'a, Repo: SupportMetricsRepository> {
+    pub repository: &'a Repo,
+}
+
+impl<Repo: SupportMetricsRepository> SupportMetricsUseCase<'_, Repo> {
+    pub async fn get(
+        &self,
+        actor: &Actor,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<SupportMetrics, Error> {
+        ensure_support_metrics_readable(actor)?;
+        let period = SupportMetricsPeriod::try_new(from, to)?;
+
+        self.repository
+            .fetch(period)
+            .await?
+            .try_read(actor.clone())
+            .map(|metrics| metrics.into_inner())
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+    use domain::{
+        account::models::{AccountUser, Role, UserId},
+        repository::support_metrics_repository::MockSupportMetricsRepository,
+    };
+    use errors::domain::DomainError;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn actor(role: Role) -> Actor {
+        Actor::from(AccountUser::new(
+            "actor".to_string(),
+            UserId::from(Uuid::new_v4()),
+            role,
+        ))
+    }
+
+    fn repository() -> MockSupportMetricsRepository {
+        let mut repository = MockSupportMetricsRepository::new();
+        repository.expect_fetch().returning(|period| {
+            Ok(SupportMetrics::new(
+                period,
+                vec![TimeDelta::hours(1), TimeDelta::hours(3)],
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            )
+            .into())
+        });
+
+        repository
+    }
+
+    #[tokio::test]
+    async fn administrator_can_read_support_metrics() {
+        let repository = repository();
+        let usecase = SupportMetricsUseCase {
+            repository: &repository,
+        };
+        let now = Utc::now();
+
+        let metrics = usecase
+            .get(&actor(Role::Administrator), now - TimeDelta::days(7), now)
+            .await
+            .unwrap();
+
+        assert_eq!(metrics.median_first_response(), &Some(TimeDelta::hours(2)));
+        assert_eq!(metrics.median_time_to_completion(), &None);
+    }
+
+    #[tokio::test]
+    async fn standard_user_is_forbidden_before_querying() {
+        let mut repository = MockSupportMetricsRepository::new();
+        repository.expect_fetch().never();
+        let usecase = SupportMetricsUseCase {
+            repository: &repository,
+        };
+        let now = Utc::now();
+
+        let result = usecase
+            .get(&actor(Role::StandardUser), now - TimeDelta::days(7), now)
+            .await;
+
+        assert_eq!(result, Err(Error::from(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn reversed_period_is_rejected_before_querying() {
+        let repository = MockSupportMetricsRepository::new();
+        let usecase = SupportMetricsUseCase {
+            repository: &repository,
+        };
+        let now = Utc::now();
+
+        let result = usecase
+            .get(&actor(Role::Administrator), now, now - TimeDelta::days(7))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Domain {
+                source: DomainError::InvalidEntity { .. }
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn too_long_period_is_rejected_before_querying() {
+        let mut repository = MockSupportMetricsRepository::new();
+        repository.expect_fetch().never();
+        let usecase = SupportMetricsUseCase {
+            repository: &repository,
+        };
+        let now = Utc::now();
+
+        let result = usecase
+            .get(
+                &actor(Role::Administrator),
+                now - SupportMetricsPeriod::MAX_LENGTH - TimeDelta::days(1),
+                now,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Domain {
+                source: DomainError::InvalidEntity { .. }
+            })
+        ));
+    }
+}
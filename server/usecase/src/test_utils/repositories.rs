@@ -7,15 +7,26 @@ use domain::{
     form::{
         FormSubmissionRestriction, FormSubmissionRestrictionHistory, FormSubmissionRestrictionId,
         answer::{
-            AnswerEntry, AnswerId, AnswerPagePosition, AnswerPublication, AnswerReference,
-            AnswerRelation, AnswerStatus, AnswerStatusChange, AnswerStatusHistoryEntry,
+            AnswerAssignmentHistoryEntry, AnswerAssignmentHistoryPagePosition, AnswerContentCount,
+            AnswerEntry, AnswerId, AnswerListFilter, AnswerModerationStatus,
+            AnswerMoveHistoryEntry, AnswerMoveHistoryPagePosition, AnswerPagePosition,
+            AnswerPublication, AnswerReference, AnswerRelation, AnswerStatistics,
+            AnswerStatisticsScope, AnswerStatusChange, AnswerStatusHistoryEntry,
             AnswerStatusHistoryPagePosition, AnswerTitleHistoryEntry,
-            AnswerTitleHistoryPagePosition, ArchivedAnswerEntry, ReadableAnswerRelation,
+            AnswerTitleHistoryPagePosition, ArchivedAnswerEntry, DailyAnswerCount,
+            ReadableAnswerRelation, StatusAnswerCount,
+        },
+        content_filter::{
+            ContentFilterEnforcement, ContentFilterFlag, ContentFilterFlagId, ContentFilterRule,
+            ContentFilterRuleId,
         },
         models::{
             ActiveForm, ArchivedForm, ArchivedFormPagePosition, FormId, FormLabel, FormLabelId,
             FormPagePosition,
         },
+        temporary_answer_abuse::{TemporaryAnswerAbuseMetadata, TemporaryAnswerSubmissionRecord},
+        temporary_answer_access::{TemporaryAnswerAccess, TemporaryAnswerAccessToken},
+        temporary_answer_claim::{TemporaryAnswerClaimCode, TemporaryAnswerClaimTicket},
     },
     notification::models::NotificationPreference,
     pagination::{Page, PageRequest},
@@ -25,7 +36,9 @@ use domain::{
             answer_entry_repository::AnswerEntryRepository,
             answer_relation_repository::AnswerRelationRepository,
             archived_form_repository::ArchivedFormRepository,
+            content_filter_repository::ContentFilterRepository,
             form_label_repository::FormLabelRepository,
+            temporary_answer_abuse_repository::TemporaryAnswerAbuseRepository,
         },
         form_submission_restriction_repository::FormSubmissionRestrictionRepository,
         notification_repository::NotificationRepository,
@@ -34,7 +47,10 @@ use domain::{
     types::authorization_guard::{Allowed, AuthorizationGuard, Create, Delete, Read, Update},
 };
 use errors::Error;
-use std::{collections::HashMap, sync::Mutex};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+};
 use uuid::Uuid;
 
 use crate::forms::answer_relation::AnswerRelationUseCase;
@@ -57,6 +73,8 @@ pub(crate) struct FormUseCaseTestRepositories {
     pub(crate) answer_relation_repository: InMemoryAnswerRelationRepository,
     pub(crate) user_repository: InMemoryUserRepository,
     pub(crate) form_submission_restriction_repository: InMemoryFormSubmissionRestrictionRepository,
+    pub(crate) content_filter_repository: InMemoryContentFilterRepository,
+    pub(crate) temporary_answer_abuse_repository: InMemoryTemporaryAnswerAbuseRepository,
 }
 
 impl FormUseCaseTestRepositories {
@@ -278,21 +296,37 @@ impl FormLabelRepository for InMemoryFormLabelRepository {
 #[derive(Default)]
 pub(crate) struct InMemoryAnswerEntryRepository {
     answers: Mutex<Vec<AnswerEntry>>,
+    overdue_notified_answer_ids: Mutex<Vec<AnswerId>>,
+    relations: Mutex<Vec<AnswerRelation>>,
+    temporary_credentials: Mutex<Vec<(TemporaryAnswerAccess, TemporaryAnswerClaimTicket)>>,
 }
 
 impl InMemoryAnswerEntryRepository {
     pub(crate) fn new(answers: Vec<AnswerEntry>) -> Self {
         Self {
             answers: Mutex::new(answers),
+            ..Self::default()
         }
     }
+
+    /// [`AnswerEntryRepository::update_with_relation`] で保存された関連です。
+    pub(crate) fn saved_relations(&self) -> Vec<AnswerRelation> {
+        self.relations.lock().unwrap().clone()
+    }
+
+    /// [`AnswerEntryRepository::post_temporary`] で一時回答と同時に保存されたアクセスと引き継ぎコードです。
+    pub(crate) fn saved_temporary_credentials(
+        &self,
+    ) -> Vec<(TemporaryAnswerAccess, TemporaryAnswerClaimTicket)> {
+        self.temporary_credentials.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
 impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
     async fn get(
         &self,
-        _form: &Allowed<ActiveForm, Read>,
+        form: &Allowed<ActiveForm, Read>,
         answer_id: AnswerId,
     ) -> Result<Option<Allowed<AnswerEntry, Read>>, Error> {
         Ok(self
@@ -300,9 +334,9 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
             .lock()
             .unwrap()
             .iter()
-            .find(|answer| *answer.id() == answer_id)
+            .find(|answer| *answer.id() == answer_id && answer.form_id() == form.id())
             .cloned()
-            .map(|answer| _form.read_entry(answer))
+            .map(|answer| form.read_entry(answer))
             .transpose()?)
     }
 
@@ -335,33 +369,30 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         &self,
         form: &Allowed<ActiveForm, Read>,
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error> {
+        let notified = self.overdue_notified_answer_ids.lock().unwrap().clone();
         let mut answers = self
             .answers
             .lock()
             .unwrap()
             .iter()
             .filter(|answer| answer.form_id() == form.id())
-            .filter(|answer| status.is_none_or(|status| *answer.status() == status))
+            .filter(|answer| filter.matches(answer))
+            .filter(|answer| !filter.unnotified_overdue_only() || !notified.contains(answer.id()))
             .cloned()
             .filter_map(|answer| form.read_entry(answer).ok())
             .collect::<Vec<_>>();
-        answers.sort_by(|left, right| {
-            right
-                .timestamp()
-                .cmp(left.timestamp())
-                .then_with(|| right.id().into_inner().cmp(&left.id().into_inner()))
-        });
+        answers.sort_by(|left, right| filter.order().compare(left, right));
 
         if let Some(position) = request.after_position() {
-            answers.retain(|answer| position.is_followed_by(*answer.timestamp(), *answer.id()));
+            answers.retain(|answer| position.is_followed_in(filter.order(), answer));
         }
 
         Ok(Page::from_overfetched_items(
             answers,
             request.limit(),
-            |answer| AnswerPagePosition::new(*answer.timestamp(), *answer.id()),
+            |answer| AnswerPagePosition::of(answer),
         ))
     }
 
@@ -369,40 +400,37 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         &self,
         forms: &[Allowed<ActiveForm, Read>],
         request: PageRequest<AnswerPagePosition>,
-        status: Option<AnswerStatus>,
+        filter: AnswerListFilter,
     ) -> Result<Page<Allowed<AnswerEntry, Read>, AnswerPagePosition>, Error> {
         let forms_by_id = forms
             .iter()
             .map(|form| (form.id().into_inner(), form))
             .collect::<HashMap<_, _>>();
+        let notified = self.overdue_notified_answer_ids.lock().unwrap().clone();
         let mut answers = self
             .answers
             .lock()
             .unwrap()
             .iter()
+            .filter(|answer| filter.matches(answer))
+            .filter(|answer| !filter.unnotified_overdue_only() || !notified.contains(answer.id()))
             .cloned()
-            .filter(|answer| status.is_none_or(|status| *answer.status() == status))
             .filter_map(|answer| {
                 forms_by_id
                     .get(&answer.form_id().into_inner())
                     .and_then(|form| form.read_entry(answer).ok())
             })
             .collect::<Vec<_>>();
-        answers.sort_by(|left, right| {
-            right
-                .timestamp()
-                .cmp(left.timestamp())
-                .then_with(|| right.id().into_inner().cmp(&left.id().into_inner()))
-        });
+        answers.sort_by(|left, right| filter.order().compare(left, right));
 
         if let Some(position) = request.after_position() {
-            answers.retain(|answer| position.is_followed_by(*answer.timestamp(), *answer.id()));
+            answers.retain(|answer| position.is_followed_in(filter.order(), answer));
         }
 
         Ok(Page::from_overfetched_items(
             answers,
             request.limit(),
-            |answer| AnswerPagePosition::new(*answer.timestamp(), *answer.id()),
+            |answer| AnswerPagePosition::of(answer),
         ))
     }
 
@@ -418,6 +446,23 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         Ok(())
     }
 
+    async fn post_temporary(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        answer_entry: &Allowed<AnswerEntry, Create>,
+        access: Allowed<TemporaryAnswerAccess, Create>,
+        _access_token: &TemporaryAnswerAccessToken,
+        claim_ticket: Allowed<TemporaryAnswerClaimTicket, Create>,
+        _claim_code: &TemporaryAnswerClaimCode,
+    ) -> Result<(), Error> {
+        self.post(form, answer_entry).await?;
+        self.temporary_credentials
+            .lock()
+            .unwrap()
+            .push((access.into_inner(), claim_ticket.into_inner()));
+        Ok(())
+    }
+
     async fn update(
         &self,
         _form: &Allowed<ActiveForm, Update>,
@@ -430,6 +475,12 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         {
             let status_change =
                 AnswerStatusChange::new(*stored_answer.status(), *answer_entry.status());
+            if stored_answer.due_at() != answer_entry.due_at() {
+                self.overdue_notified_answer_ids
+                    .lock()
+                    .unwrap()
+                    .retain(|answer_id| answer_id != answer_entry.id());
+            }
             *stored_answer = answer_entry.value().clone();
             Ok(status_change)
         } else {
@@ -437,6 +488,24 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         }
     }
 
+    async fn update_with_relation(
+        &self,
+        form: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+        relation: AnswerRelation,
+        related: &Allowed<AnswerEntry, Update>,
+    ) -> Result<Option<AnswerStatusChange>, Error> {
+        if !relation.connects(answer_entry.value(), related.value()) {
+            return Err(errors::domain::DomainError::InvalidEntity {
+                message: "answer relation endpoints do not match authorized answers".to_string(),
+            }
+            .into());
+        }
+        let status_change = self.update(form, answer_entry).await?;
+        self.relations.lock().unwrap().push(relation);
+        Ok(status_change)
+    }
+
     async fn history(
         &self,
         _answer: &Allowed<AnswerEntry, Read>,
@@ -455,8 +524,123 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
         Ok(Page::new(Vec::new(), None))
     }
 
+    async fn assignment_history(
+        &self,
+        _answer: &Allowed<AnswerEntry, Read>,
+        _request: PageRequest<AnswerAssignmentHistoryPagePosition>,
+    ) -> Result<
+        Page<Allowed<AnswerAssignmentHistoryEntry, Read>, AnswerAssignmentHistoryPagePosition>,
+        Error,
+    > {
+        Ok(Page::new(Vec::new(), None))
+    }
+
+    async fn move_to_form(
+        &self,
+        from: &Allowed<ActiveForm, Update>,
+        _to: &Allowed<ActiveForm, Update>,
+        answer_entry: &Allowed<AnswerEntry, Update>,
+    ) -> Result<(), Error> {
+        let mut answers = self.answers.lock().unwrap();
+        match answers
+            .iter_mut()
+            .find(|stored| stored.id() == answer_entry.id() && stored.form_id() == from.id())
+        {
+            Some(stored_answer) => {
+                *stored_answer = answer_entry.value().clone();
+                Ok(())
+            }
+            None => Err(not_found_error("AnswerEntry", answer_entry.id())),
+        }
+    }
+
+    async fn move_history(
+        &self,
+        _answer: &Allowed<AnswerEntry, Read>,
+        _request: PageRequest<AnswerMoveHistoryPagePosition>,
+    ) -> Result<Page<Allowed<AnswerMoveHistoryEntry, Read>, AnswerMoveHistoryPagePosition>, Error>
+    {
+        Ok(Page::new(Vec::new(), None))
+    }
+
+    async fn mark_overdue_notified(
+        &self,
+        answer: &Allowed<AnswerEntry, Read>,
+    ) -> Result<bool, Error> {
+        let mut notified = self.overdue_notified_answer_ids.lock().unwrap();
+        if notified.contains(answer.id()) {
+            return Ok(false);
+        }
+        notified.push(*answer.id());
+        Ok(true)
+    }
+
+    async fn statistics(
+        &self,
+        form: &Allowed<ActiveForm, Read>,
+        scope: AnswerStatisticsScope,
+    ) -> Result<AnswerStatistics, Error> {
+        let answers = self
+            .answers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|answer| answer.form_id() == form.id())
+            .filter(|answer| *answer.moderation_status() == AnswerModerationStatus::APPROVED)
+            .filter(|answer| {
+                scope == AnswerStatisticsScope::All
+                    || *answer.publication() == AnswerPublication::PUBLIC
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut content_counts = HashMap::<_, u32>::new();
+        let mut daily_counts = BTreeMap::<_, u32>::new();
+        for answer in &answers {
+            for content in answer.contents() {
+                *content_counts
+                    .entry((content.question_id, content.answer.to_owned()))
+                    .or_default() += 1;
+            }
+            *daily_counts
+                .entry(answer.timestamp().date_naive())
+                .or_default() += 1;
+        }
+
+        Ok(AnswerStatistics::new(
+            form.questions().as_slice(),
+            answers.len() as u32,
+            content_counts
+                .into_iter()
+                .map(|((question_id, answer), count)| AnswerContentCount {
+                    question_id,
+                    answer,
+                    count,
+                })
+                .collect(),
+            daily_counts
+                .into_iter()
+                .map(|(date, count)| DailyAnswerCount { date, count })
+                .collect(),
+            answers
+                .iter()
+                .map(|answer| StatusAnswerCount {
+                    status: *answer.status(),
+                    count: 1,
+                })
+                .collect(),
+            Vec::new(),
+        ))
+    }
+
     async fn size(&self) -> Result<u32, Error> {
-        Ok(self.answers.lock().unwrap().len() as u32)
+        Ok(self
+            .answers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|answer| answer.moderation_status().is_approved())
+            .count() as u32)
     }
 
     async fn content_size(&self) -> Result<u32, Error> {
@@ -465,6 +649,7 @@ impl AnswerEntryRepository for InMemoryAnswerEntryRepository {
             .lock()
             .unwrap()
             .iter()
+            .filter(|answer| answer.moderation_status().is_approved())
             .map(|answer| answer.contents().len() as u32)
             .sum())
     }
@@ -477,7 +662,7 @@ mod answer_entry_repository_tests {
         account::models::Role,
         auth::Actor,
         form::{
-            answer::{AnswerAuthor, AnswerStatus, AnswerTitle},
+            answer::{AnswerAssigneeFilter, AnswerAuthor, AnswerStatus, AnswerTitle},
             models::{AnswerSettings, AnswerVisibility, FormDescription, FormTitle, QuestionSet},
             question::Question,
         },
@@ -575,7 +760,7 @@ mod answer_entry_repository_tests {
             .list_by_form(
                 &form,
                 PageRequest::first(PageLimit::try_new(1).unwrap()),
-                Some(AnswerStatus::IN_PROGRESS),
+                AnswerListFilter::default().with_status(Some(AnswerStatus::IN_PROGRESS)),
             )
             .await
             .unwrap();
@@ -584,6 +769,39 @@ mod answer_entry_repository_tests {
         assert!(next.is_some());
     }
 
+    #[tokio::test]
+    async fn list_filters_answers_by_assignee() {
+        let form = active_form("answers");
+        let timestamp = Utc.with_ymd_and_hms(2026, 8, 3, 12, 0, 0).unwrap();
+        let assignee = Uuid::from_u128(500).into();
+        let repository = InMemoryAnswerEntryRepository::new(vec![
+            unsafe { answer(&form, 1, timestamp).with_raw_assignee(Some(assignee)) },
+            answer(&form, 2, timestamp - chrono::TimeDelta::seconds(1)),
+        ]);
+        let form = AuthorizationGuard::from(form)
+            .try_read(Actor::System)
+            .unwrap();
+        let list = |assignee| {
+            repository.list_by_form(
+                &form,
+                PageRequest::first(PageLimit::try_new(10).unwrap()),
+                AnswerListFilter::default().with_assignee(Some(assignee)),
+            )
+        };
+
+        let (assigned, _) = list(AnswerAssigneeFilter::AssignedTo(assignee))
+            .await
+            .unwrap()
+            .into_parts();
+        let (unassigned, _) = list(AnswerAssigneeFilter::Unassigned)
+            .await
+            .unwrap()
+            .into_parts();
+
+        assert_eq!(ids(assigned), vec![Uuid::from_u128(1)]);
+        assert_eq!(ids(unassigned), vec![Uuid::from_u128(2)]);
+    }
+
     #[tokio::test]
     async fn list_all_preserves_global_order_across_pages() {
         let first_form = active_form("first");
@@ -604,12 +822,20 @@ mod answer_entry_repository_tests {
         let limit = PageLimit::try_new(2).unwrap();
 
         let first_page = repository
-            .list_all(&forms, PageRequest::first(limit), None)
+            .list_all(
+                &forms,
+                PageRequest::first(limit),
+                AnswerListFilter::default(),
+            )
             .await
             .unwrap();
         let (first_entries, next) = first_page.into_parts();
         let second_page = repository
-            .list_all(&forms, PageRequest::after(next.unwrap(), limit), None)
+            .list_all(
+                &forms,
+                PageRequest::after(next.unwrap(), limit),
+                AnswerListFilter::default(),
+            )
             .await
             .unwrap();
         let (second_entries, next) = second_page.into_parts();
@@ -653,7 +879,7 @@ mod answer_entry_repository_tests {
             .list_all(
                 &[form],
                 PageRequest::first(PageLimit::try_new(10).unwrap()),
-                None,
+                AnswerListFilter::default(),
             )
             .await
             .unwrap();
@@ -686,7 +912,7 @@ mod answer_entry_repository_tests {
             .list_all(
                 &[form],
                 PageRequest::first(PageLimit::try_new(2).unwrap()),
-                None,
+                AnswerListFilter::default(),
             )
             .await
             .unwrap();
@@ -721,7 +947,7 @@ mod answer_entry_repository_tests {
             .list_by_form(
                 &form,
                 PageRequest::first(PageLimit::try_new(2).unwrap()),
-                None,
+                AnswerListFilter::default(),
             )
             .await
             .unwrap();
@@ -748,12 +974,20 @@ mod answer_entry_repository_tests {
         let limit = PageLimit::try_new(2).unwrap();
 
         let first_page = repository
-            .list_by_form(&form, PageRequest::first(limit), None)
+            .list_by_form(
+                &form,
+                PageRequest::first(limit),
+                AnswerListFilter::default(),
+            )
             .await
             .unwrap();
         let (first_entries, next) = first_page.into_parts();
         let second_page = repository
-            .list_by_form(&form, PageRequest::after(next.unwrap(), limit), None)
+            .list_by_form(
+                &form,
+                PageRequest::after(next.unwrap(), limit),
+                AnswerListFilter::default(),
+            )
             .await
             .unwrap();
         let (second_entries, next) = second_page.into_parts();
@@ -826,12 +1060,8 @@ impl AnswerRelationRepository for InMemoryAnswerRelationRepository {
     ) -> Result<(), Error> {
         let authorized = relation.authorize_read_from_updates(source, target)?;
         let mut relations = self.relations.lock().unwrap();
-        if !relations
-            .iter()
-            .any(|stored| stored.value().relation() == relation)
-        {
-            relations.push(authorized);
-        }
+        relations.retain(|stored| !stored.value().relation().same_endpoints(relation));
+        relations.push(authorized);
         Ok(())
     }
 
@@ -845,7 +1075,7 @@ impl AnswerRelationRepository for InMemoryAnswerRelationRepository {
         self.relations
             .lock()
             .unwrap()
-            .retain(|stored| stored.value().relation() != relation);
+            .retain(|stored| !stored.value().relation().same_endpoints(relation));
         Ok(())
     }
 
@@ -899,7 +1129,6 @@ impl ArchivedFormRepository for InMemoryArchivedFormRepository {
             .lock()
             .unwrap()
             .iter()
-            .cloned()
             .filter(|form| match &query {
                 Some(query) => {
                     form.form()
@@ -917,6 +1146,7 @@ impl ArchivedFormRepository for InMemoryArchivedFormRepository {
                 }
                 None => true,
             })
+            .cloned()
             .collect::<Vec<_>>();
         forms.sort_by(|left, right| {
             right
@@ -1100,6 +1330,25 @@ impl UserRepository for InMemoryUserRepository {
             .collect())
     }
 
+    async fn find_by_names(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Vec<AuthorizationGuard<AccountUser, Read>>, Error> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| {
+                names
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(user.name()))
+            })
+            .cloned()
+            .map(AuthorizationGuard::from)
+            .collect())
+    }
+
     async fn upsert_user(&self, user: Allowed<AccountUser, Create>) -> Result<(), Error> {
         let user = user.into_inner();
         let mut users = self.users.lock().unwrap();
@@ -1457,3 +1706,147 @@ fn lifted_form_submission_restriction(
         )
     }
 }
+
+#[derive(Default)]
+pub(crate) struct InMemoryContentFilterRepository {
+    rules: Mutex<Vec<ContentFilterRule>>,
+    enforcements: Mutex<Vec<ContentFilterEnforcement>>,
+    flags: Mutex<Vec<ContentFilterFlag>>,
+}
+
+impl InMemoryContentFilterRepository {
+    pub(crate) fn with_rules(rules: Vec<ContentFilterRule>) -> Self {
+        Self {
+            rules: Mutex::new(rules),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn enforcements(&self) -> Vec<ContentFilterEnforcement> {
+        self.enforcements.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ContentFilterRepository for InMemoryContentFilterRepository {
+    async fn list_rules(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterRule, Read>>, Error> {
+        Ok(self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|rule| rule.form_id() == &form_id)
+            .cloned()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn get_rule(
+        &self,
+        id: ContentFilterRuleId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterRule, Read>>, Error> {
+        Ok(self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|rule| rule.id() == &id)
+            .cloned()
+            .map(Into::into))
+    }
+
+    async fn create_rule(&self, rule: Allowed<ContentFilterRule, Create>) -> Result<(), Error> {
+        self.rules.lock().unwrap().push(rule.into_inner());
+        Ok(())
+    }
+
+    async fn delete_rule(&self, rule: Allowed<ContentFilterRule, Delete>) -> Result<(), Error> {
+        self.rules
+            .lock()
+            .unwrap()
+            .retain(|stored| stored.id() != rule.id());
+        Ok(())
+    }
+
+    async fn enforce(
+        &self,
+        enforcement: Allowed<ContentFilterEnforcement, Create>,
+    ) -> Result<(), Error> {
+        let enforcement = enforcement.into_inner();
+        self.flags
+            .lock()
+            .unwrap()
+            .extend(enforcement.flags().iter().cloned());
+        self.enforcements.lock().unwrap().push(enforcement);
+        Ok(())
+    }
+
+    async fn list_flags(
+        &self,
+        form_id: FormId,
+    ) -> Result<Vec<AuthorizationGuard<ContentFilterFlag, Read>>, Error> {
+        Ok(self
+            .flags
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|flag| flag.form_id() == &form_id)
+            .cloned()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn get_flag(
+        &self,
+        id: ContentFilterFlagId,
+    ) -> Result<Option<AuthorizationGuard<ContentFilterFlag, Read>>, Error> {
+        Ok(self
+            .flags
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|flag| flag.id() == &id)
+            .cloned()
+            .map(Into::into))
+    }
+
+    async fn delete_flag(&self, flag: Allowed<ContentFilterFlag, Delete>) -> Result<(), Error> {
+        self.flags
+            .lock()
+            .unwrap()
+            .retain(|stored| stored.id() != flag.id());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct InMemoryTemporaryAnswerAbuseRepository {
+    records: Mutex<Vec<TemporaryAnswerSubmissionRecord>>,
+}
+
+impl InMemoryTemporaryAnswerAbuseRepository {
+    pub(crate) fn records(&self) -> Vec<TemporaryAnswerSubmissionRecord> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl TemporaryAnswerAbuseRepository for InMemoryTemporaryAnswerAbuseRepository {
+    async fn record(
+        &self,
+        record: Allowed<TemporaryAnswerSubmissionRecord, Create>,
+    ) -> Result<(), Error> {
+        self.records.lock().unwrap().push(record.into_inner());
+        Ok(())
+    }
+
+    async fn find_by_answer_id(
+        &self,
+        _answer_id: AnswerId,
+    ) -> Result<Option<TemporaryAnswerAbuseMetadata>, Error> {
+        Ok(None)
+    }
+}
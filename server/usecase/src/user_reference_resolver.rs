@@ -11,12 +11,21 @@ pub(crate) async fn resolve_user_references<R: UserRepository + ?Sized>(
     repo: &R,
     actor: &AccountUser,
     user_ids: Vec<UserId>,
+) -> Result<HashMap<UserId, AccountUser>, Error> {
+    resolve_user_references_as(repo, &Actor::from(actor.clone()), user_ids).await
+}
+
+/// アカウントを持たない操作者の代わりにユーザーを解決する。
+pub(crate) async fn resolve_user_references_as<R: UserRepository + ?Sized>(
+    repo: &R,
+    actor: &Actor,
+    user_ids: Vec<UserId>,
 ) -> Result<HashMap<UserId, AccountUser>, Error> {
     if user_ids.is_empty() {
         return Ok(HashMap::new());
     }
 
-    let actor_user = Actor::from(actor.clone());
+    let actor_user = actor.clone();
     let uuids = user_ids
         .into_iter()
         .map(UserId::into_inner)